@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+# Build llama.cpp's llama-server as one static executable and put it where
+# src-tauri/tauri.llama-server.conf.json bundles it from:
+# src-tauri/binaries/llama-server-<target triple>[.exe]
+#
+# The app runs it as a separate process for local refinement, because linking
+# llama.cpp into the app clashes with the ggml that whisper.cpp links.
+#
+# Usage: scripts/build-llama-server.sh <target triple>
+set -euo pipefail
+
+TARGET="${1:?usage: $0 <target triple>}"
+LLAMA_CPP_TAG="${LLAMA_CPP_TAG:-b6000}"
+ROOT="$(cd "$(dirname "$0")/.." && pwd)"
+SRC="${TMPDIR:-/tmp}/llama.cpp-$LLAMA_CPP_TAG"
+
+if [ ! -d "$SRC" ]; then
+  git clone --depth 1 --branch "$LLAMA_CPP_TAG" https://github.com/ggml-org/llama.cpp "$SRC"
+fi
+
+# Static and without CPU-specific or OpenMP builds, so the binary runs on any
+# machine of the target's architecture without extra libraries
+ARGS=(
+  -DCMAKE_BUILD_TYPE=Release
+  -DBUILD_SHARED_LIBS=OFF
+  -DGGML_NATIVE=OFF
+  -DGGML_OPENMP=OFF
+  -DLLAMA_CURL=OFF
+  -DLLAMA_BUILD_TESTS=OFF
+  -DLLAMA_BUILD_EXAMPLES=OFF
+  -DLLAMA_BUILD_SERVER=ON
+)
+case "$TARGET" in
+  aarch64-apple-darwin) ARGS+=(-DCMAKE_OSX_ARCHITECTURES=arm64) ;;
+  x86_64-apple-darwin) ARGS+=(-DCMAKE_OSX_ARCHITECTURES=x86_64) ;;
+  aarch64-pc-windows-msvc) ARGS+=(-A ARM64) ;;
+  x86_64-pc-windows-msvc) ARGS+=(-A x64) ;;
+esac
+
+BUILD="$SRC/build-$TARGET"
+cmake -S "$SRC" -B "$BUILD" "${ARGS[@]}"
+cmake --build "$BUILD" --config Release --target llama-server -j 4
+
+EXT=""
+case "$TARGET" in *windows*) EXT=".exe" ;; esac
+BUILT="$(find "$BUILD/bin" -name "llama-server$EXT" -type f | head -1)"
+if [ -z "$BUILT" ]; then
+  echo "llama-server$EXT was not built" >&2
+  exit 1
+fi
+
+mkdir -p "$ROOT/src-tauri/binaries"
+cp "$BUILT" "$ROOT/src-tauri/binaries/llama-server-$TARGET$EXT"
+echo "Built $ROOT/src-tauri/binaries/llama-server-$TARGET$EXT"
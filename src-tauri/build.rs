@@ -5,16 +5,28 @@ fn main() {
     #[cfg(target_os = "macos")]
     build_app_detection_bridge();
 
-    generate_tray_translations();
+    generate_backend_translations();
 
     tauri_build::build()
 }
 
-/// Generate tray menu translations from frontend locale files.
+/// Locale sections the backend shows itself, with the struct generated for each
+const BACKEND_SECTIONS: &[(&str, &str, &str)] = &[
+    ("tray", "TrayStrings", "TRAY_TRANSLATIONS"),
+    ("overlayMessages", "OverlayStrings", "OVERLAY_TRANSLATIONS"),
+    (
+        "announcements",
+        "AnnouncementStrings",
+        "ANNOUNCEMENT_TRANSLATIONS",
+    ),
+];
+
+/// Generate the backend's translations from frontend locale files.
 ///
 /// Source of truth: src/i18n/locales/*/translation.json
-/// The English "tray" section defines the struct fields.
-fn generate_tray_translations() {
+/// The English section defines each struct's fields; a key missing from another
+/// language falls back to the English string.
+fn generate_backend_translations() {
     use std::collections::BTreeMap;
     use std::fs;
     use std::path::Path;
@@ -25,7 +37,7 @@ fn generate_tray_translations() {
     println!("cargo:rerun-if-changed=../src/i18n/locales");
 
     // Collect all locale translations
-    let mut translations: BTreeMap<String, serde_json::Value> = BTreeMap::new();
+    let mut locales: BTreeMap<String, serde_json::Value> = BTreeMap::new();
 
     for entry in fs::read_dir(locales_dir).unwrap().flatten() {
         let path = entry.path();
@@ -40,57 +52,65 @@ fn generate_tray_translations() {
 
         let content = fs::read_to_string(&json_path).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
-
-        if let Some(tray) = parsed.get("tray").cloned() {
-            translations.insert(lang, tray);
-        }
+        locales.insert(lang, parsed);
     }
 
-    // English defines the schema
-    let english = translations.get("en").unwrap().as_object().unwrap();
-    let fields: Vec<_> = english
-        .keys()
-        .map(|k| (camel_to_snake(k), k.clone()))
-        .collect();
-
     // Generate code
     let mut out = String::from(
         "// Auto-generated from src/i18n/locales/*/translation.json - do not edit\n\n",
     );
 
-    // Struct
-    out.push_str("#[derive(Debug, Clone)]\npub struct TrayStrings {\n");
-    for (rust_field, _) in &fields {
-        out.push_str(&format!("    pub {rust_field}: String,\n"));
-    }
-    out.push_str("}\n\n");
-
-    // Static map
-    out.push_str(
-        "pub static TRANSLATIONS: Lazy<HashMap<&'static str, TrayStrings>> = Lazy::new(|| {\n",
-    );
-    out.push_str("    let mut m = HashMap::new();\n");
-
-    for (lang, tray) in &translations {
-        out.push_str(&format!("    m.insert(\"{lang}\", TrayStrings {{\n"));
-        for (rust_field, json_key) in &fields {
-            let val = tray.get(json_key).and_then(|v| v.as_str()).unwrap_or("");
-            out.push_str(&format!(
-                "        {rust_field}: \"{}\".to_string(),\n",
-                escape_string(val)
-            ));
+    for (section, struct_name, static_name) in BACKEND_SECTIONS {
+        // English defines the schema
+        let english = locales["en"][section].as_object().unwrap();
+        let fields: Vec<_> = english
+            .keys()
+            .map(|k| (camel_to_snake(k), k.clone()))
+            .collect();
+
+        // Struct
+        out.push_str(&format!(
+            "#[derive(Debug, Clone)]\npub struct {struct_name} {{\n"
+        ));
+        for (rust_field, _) in &fields {
+            out.push_str(&format!("    pub {rust_field}: String,\n"));
+        }
+        out.push_str("}\n\n");
+
+        // Static map
+        out.push_str(&format!(
+            "pub static {static_name}: Lazy<HashMap<&'static str, {struct_name}>> = Lazy::new(|| {{\n"
+        ));
+        out.push_str("    let mut m = HashMap::new();\n");
+
+        for (lang, parsed) in &locales {
+            let Some(strings) = parsed.get(section) else {
+                continue;
+            };
+            out.push_str(&format!("    m.insert(\"{lang}\", {struct_name} {{\n"));
+            for (rust_field, json_key) in &fields {
+                let val = strings
+                    .get(json_key)
+                    .and_then(|v| v.as_str())
+                    .or_else(|| english.get(json_key).and_then(|v| v.as_str()))
+                    .unwrap_or("");
+                out.push_str(&format!(
+                    "        {rust_field}: \"{}\".to_string(),\n",
+                    escape_string(val)
+                ));
+            }
+            out.push_str("    });\n");
         }
-        out.push_str("    });\n");
-    }
 
-    out.push_str("    m\n});\n");
+        out.push_str("    m\n});\n\n");
+    }
 
-    fs::write(Path::new(&out_dir).join("tray_translations.rs"), out).unwrap();
+    fs::write(Path::new(&out_dir).join("backend_translations.rs"), out).unwrap();
 
     println!(
-        "cargo:warning=Generated tray translations: {} languages, {} fields",
-        translations.len(),
-        fields.len()
+        "cargo:warning=Generated backend translations: {} languages, {} sections",
+        locales.len(),
+        BACKEND_SECTIONS.len()
     );
 }
 
@@ -353,6 +373,7 @@ fn build_app_detection_bridge() {
     );
     println!("cargo:rustc-link-search=native={}", sdk_swift_lib.display());
     println!("cargo:rustc-link-lib=framework=AppKit");
+    println!("cargo:rustc-link-lib=framework=MediaPlayer");
 
     println!("cargo:warning=Built app detection bridge for {}", target);
 }
@@ -0,0 +1,93 @@
+//! Spoken state changes for screen reader users.
+//!
+//! Recording started/stopped, refining and pasted are posted to the OS accessibility
+//! API so VoiceOver (macOS) or NVDA and Narrator (Windows) read them out, giving
+//! feedback that doesn't depend on seeing the overlay or recognising the sounds.
+//! The strings come from the "announcements" locale section, so they follow
+//! `app_language`. Linux has no equivalent API that Orca listens to, so nothing is
+//! announced there.
+
+use crate::i18n;
+use crate::settings;
+use log::debug;
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Announcement {
+    RecordingStarted,
+    RecordingStopped,
+    Refining,
+    Pasted,
+}
+
+/// Announce a state change, if announcements are on
+pub fn announce(app: &AppHandle, announcement: Announcement) {
+    let settings = settings::get_settings(app);
+    if !settings.accessibility_announcements {
+        return;
+    }
+
+    let strings = i18n::announcement_strings(app);
+    let message = match announcement {
+        Announcement::RecordingStarted => strings.recording_started,
+        Announcement::RecordingStopped => strings.recording_stopped,
+        Announcement::Refining => strings.refining,
+        Announcement::Pasted => strings.pasted,
+    };
+    debug!("Accessibility announcement: {}", message);
+    post_announcement(app, &message);
+}
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn post_accessibility_announcement(message: *const std::ffi::c_char);
+}
+
+#[cfg(target_os = "macos")]
+fn post_announcement(_app: &AppHandle, message: &str) {
+    let Ok(message) = std::ffi::CString::new(message) else {
+        return;
+    };
+    unsafe { post_accessibility_announcement(message.as_ptr()) };
+}
+
+#[cfg(target_os = "windows")]
+fn post_announcement(app: &AppHandle, message: &str) {
+    use tauri::Manager;
+    use windows::core::BSTR;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::Accessibility::{
+        NotificationKind_ActionCompleted, NotificationProcessing_ImportantMostRecent,
+        UiaHostProviderFromHwnd, UiaRaiseNotificationEvent,
+    };
+
+    // UI Automation notifications are raised on behalf of one of our windows
+    let Some(hwnd) = app
+        .get_webview_window("main")
+        .and_then(|window| window.hwnd().ok())
+    else {
+        return;
+    };
+
+    unsafe {
+        let provider = match UiaHostProviderFromHwnd(HWND(hwnd.0 as _)) {
+            Ok(provider) => provider,
+            Err(e) => {
+                debug!("Failed to get UI Automation provider: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = UiaRaiseNotificationEvent(
+            &provider,
+            NotificationKind_ActionCompleted,
+            NotificationProcessing_ImportantMostRecent,
+            &BSTR::from(message),
+            &BSTR::from("ramble-state"),
+        ) {
+            debug!("Failed to raise UI Automation notification: {}", e);
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn post_announcement(_app: &AppHandle, _message: &str) {}
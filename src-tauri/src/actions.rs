@@ -1,12 +1,28 @@
+use crate::accessibility::{announce, Announcement};
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
+use crate::audio_toolkit::apply_term_casing;
 use crate::clipboard;
+use crate::commands::prompts::{date_time_values, render_prompt};
+use crate::edits::{align_words, split_edit_notes, EditAlignment, EDIT_NOTES_INSTRUCTION};
+use crate::events::{CategoryDetected, RefinementProgress};
+use crate::guardrail::find_invented_terms;
+use crate::i18n;
+use crate::llm_client::{
+    gemini_content, gemini_generate_content, gemini_request_body, is_gemini,
+    stream_model_completion, TokenUsage,
+};
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::history::HistoryManager;
+use crate::managers::local_llm::LocalLlmManager;
+use crate::managers::stt_engine::Transcript;
 use crate::managers::transcription::TranscriptionManager;
 use crate::managers::tts::TTSManager;
+use crate::managers::usage::{record_usage, UsageManager};
+use crate::retry::{with_retry, Retryable};
 use crate::settings::{
     get_settings, inject_system_prompt, write_settings, AppSettings, DetectedApp, PromptMode,
+    MAX_DETECTED_APPS,
 };
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::{
@@ -20,7 +36,8 @@ use async_openai::types::{
     ChatCompletionRequestMessage, ChatCompletionRequestMessageContentPartImageArgs,
     ChatCompletionRequestMessageContentPartTextArgs, ChatCompletionRequestSystemMessageArgs,
     ChatCompletionRequestUserMessageArgs, ChatCompletionRequestUserMessageContent,
-    ChatCompletionRequestUserMessageContentPart, CreateChatCompletionRequestArgs,
+    ChatCompletionRequestUserMessageContentPart, CreateChatCompletionRequest,
+    CreateChatCompletionRequestArgs,
 };
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
 use log::{debug, error, info, warn};
@@ -28,8 +45,9 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager};
+use tauri_specta::Event;
 
 use crate::ManagedToggleState;
 
@@ -84,6 +102,9 @@ pub enum InteractionBehavior {
     Momentary,
 }
 
+/// System message for coherent refinement, enforcing the user-proxy persona
+pub(crate) const COHERENT_SYSTEM_PROMPT: &str = "You are an AI assistant acting as the user's proxy. You must speak **as** the user, in the first person. Do not address the user directly. Do not explain your response. Your output will be sent to another agent or system as if the user wrote it.";
+
 // Shortcut Action Trait
 pub trait ShortcutAction: Send + Sync {
     /// The style of interaction this action supports
@@ -100,7 +121,7 @@ pub trait ShortcutAction: Send + Sync {
 struct TranscribeAction;
 
 /// Extract a human-readable error message from LLM API errors
-fn extract_llm_error(error: &dyn std::error::Error, model: &str) -> String {
+pub(crate) fn extract_llm_error(error: &dyn std::error::Error, model: &str) -> String {
     let error_str = error.to_string();
     let lower_error = error_str.to_lowercase();
 
@@ -127,6 +148,14 @@ fn extract_llm_error(error: &dyn std::error::Error, model: &str) -> String {
 /// Record a detected app in the history for UI suggestions
 fn record_detected_app(app: &AppHandle, bundle_id: &str, display_name: &str) {
     let mut settings = get_settings(app);
+    if !settings.record_detected_apps
+        || settings
+            .detected_apps_excluded
+            .iter()
+            .any(|excluded| excluded.eq_ignore_ascii_case(bundle_id))
+    {
+        return;
+    }
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map(|d| d.as_secs())
@@ -150,12 +179,12 @@ fn record_detected_app(app: &AppHandle, bundle_id: &str, display_name: &str) {
         });
     }
 
-    // Limit history size to 100 most recent apps
-    if settings.detected_apps_history.len() > 100 {
+    // Limit history size to the most recent apps
+    if settings.detected_apps_history.len() > MAX_DETECTED_APPS {
         settings
             .detected_apps_history
             .sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
-        settings.detected_apps_history.truncate(100);
+        settings.detected_apps_history.truncate(MAX_DETECTED_APPS);
     }
 
     write_settings(app, settings);
@@ -242,6 +271,16 @@ impl ShortcutAction for TranscribeAction {
         let tm = app.state::<Arc<TranscriptionManager>>();
         tm.initiate_model_load();
 
+        let settings = get_settings(app);
+        let language = category_language(&settings);
+        if let Some(language) = &language {
+            debug!(
+                "Category of the frontmost app forces language '{}'",
+                language
+            );
+        }
+        tm.set_language_override(language);
+
         let binding_id = binding_id.to_string();
         change_tray_icon(app, TrayIconState::Recording);
         show_recording_overlay(app);
@@ -249,8 +288,7 @@ impl ShortcutAction for TranscribeAction {
         let rm = app.state::<Arc<AudioRecordingManager>>();
 
         // Get the microphone mode to determine audio feedback timing
-        let settings = get_settings(app);
-        let is_always_on = settings.always_on_microphone;
+        let is_always_on = crate::power::always_on_microphone(&settings);
         debug!("Microphone mode - always_on: {}", is_always_on);
 
         let mut recording_started = false;
@@ -299,6 +337,7 @@ impl ShortcutAction for TranscribeAction {
         if recording_started {
             rm.start_streaming_transcription(Arc::clone(&tm));
             debug!("Started streaming transcription session");
+            announce(app, Announcement::RecordingStarted);
         }
 
         debug!(
@@ -339,6 +378,7 @@ impl ShortcutAction for TranscribeAction {
 
         // Play audio feedback for recording stop
         play_feedback_sound(app, SoundType::Stop);
+        announce(app, Announcement::RecordingStopped);
 
         let binding_id = binding_id.to_string(); // Clone binding_id for the async task
 
@@ -353,15 +393,18 @@ impl ShortcutAction for TranscribeAction {
         );
 
         // Finish streaming transcription session and get pre-transcribed text
-        let streaming_text = rm.finish_streaming_transcription();
-        let has_streaming_text = streaming_text
+        let streaming_transcript = rm.finish_streaming_transcription();
+        let has_streaming_text = streaming_transcript
             .as_ref()
-            .map(|t| !t.is_empty())
+            .map(|t| !t.text.is_empty())
             .unwrap_or(false);
         debug!(
             "Streaming transcription finished: has_text={}, text='{}'",
             has_streaming_text,
-            streaming_text.as_deref().unwrap_or("")
+            streaming_transcript
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or("")
         );
 
         tauri::async_runtime::spawn(async move {
@@ -385,7 +428,10 @@ impl ShortcutAction for TranscribeAction {
                         // Critical failure - can't even save the audio
                         utils::show_error_overlay(
                             &ah,
-                            &format!("Failed to save recording: {}", e),
+                            &i18n::format_message(
+                                &i18n::overlay_strings(&ah).save_recording_failed,
+                                &[("error", &e.to_string())],
+                            ),
                             false,
                         );
                         utils::hide_recording_overlay(&ah);
@@ -397,23 +443,26 @@ impl ShortcutAction for TranscribeAction {
                 let transcription_time = Instant::now();
 
                 // Use streaming transcription if available, otherwise fall back to full transcription
-                let transcription = if has_streaming_text {
+                let Transcript {
+                    text: transcription,
+                    words: word_timings,
+                } = if has_streaming_text {
                     debug!("Using streaming transcription result");
-                    streaming_text.unwrap()
+                    streaming_transcript.unwrap()
                 } else {
                     debug!(
                         "No streaming transcription available, falling back to full transcription"
                     );
                     // Try transcription with fallback chain: Parakeet -> Whisper -> Chunked -> Error
-                    let transcription_result = tm.transcribe(samples.clone());
+                    let transcription_result = tm.transcribe_with_timings(samples.clone());
 
                     match transcription_result {
-                        Ok(text) => {
+                        Ok(transcript) => {
                             debug!(
                                 "Transcription succeeded in {:?}",
                                 transcription_time.elapsed()
                             );
-                            text
+                            transcript
                         }
                         Err(primary_err) => {
                             warn!(
@@ -424,9 +473,9 @@ impl ShortcutAction for TranscribeAction {
                             // Fallback 1: Try Whisper if available
                             let whisper_result = tm.transcribe_with_fallback(samples.clone()).await;
                             match whisper_result {
-                                Ok(text) => {
+                                Ok(transcript) => {
                                     info!("Whisper fallback succeeded");
-                                    text
+                                    transcript
                                 }
                                 Err(whisper_err) => {
                                     warn!(
@@ -436,9 +485,9 @@ impl ShortcutAction for TranscribeAction {
 
                                     // Fallback 2: Try chunked transcription
                                     match tm.transcribe_chunked(samples.clone()) {
-                                        Ok(text) => {
+                                        Ok(transcript) => {
                                             info!("Chunked transcription succeeded");
-                                            text
+                                            transcript
                                         }
                                         Err(chunk_err) => {
                                             // All fallbacks failed - save error and show overlay
@@ -465,7 +514,7 @@ impl ShortcutAction for TranscribeAction {
                                             // Show error overlay to user
                                             utils::show_error_overlay(
                                                 &ah,
-                                                "Transcription failed. Recording saved to history.",
+                                                &i18n::overlay_strings(&ah).transcription_failed,
                                                 false,
                                             );
                                             utils::hide_recording_overlay(&ah);
@@ -490,15 +539,19 @@ impl ShortcutAction for TranscribeAction {
                     let mut final_text = transcription.clone();
                     let mut post_processed_text: Option<String> = None;
                     let mut post_process_prompt: Option<String> = None;
+                    let mut edit_alignment: Option<EditAlignment> = None;
+                    let mut invented_terms: Vec<String> = Vec::new();
 
-                    // Check if coherent mode is enabled (unified hotkey: quick press)
-                    let coherent_mode = rm.get_coherent_mode();
+                    // Check if coherent mode is enabled (unified hotkey: quick press).
+                    // Focus mode always pastes raw text.
+                    let coherent_mode = rm.get_coherent_mode() && !settings.focus_mode;
                     let selection_context = rm.get_selection_context();
 
                     if coherent_mode {
                         // Coherent mode: route through LLM refinement
                         debug!("Coherent mode enabled - routing through ramble processing");
                         show_making_coherent_overlay(&ah);
+                        announce(&ah, Announcement::Refining);
                         // Get prompt from coherent_prompts based on selected ID
                         if let Some(prompt_id) = &settings.coherent_selected_prompt_id {
                             if let Some(p) = settings
@@ -529,8 +582,15 @@ impl ShortcutAction for TranscribeAction {
                         .await
                         {
                             Ok(Some(processed)) => {
-                                final_text = processed.clone();
-                                post_processed_text = Some(processed);
+                                if settings.show_edits {
+                                    edit_alignment = Some(EditAlignment {
+                                        spans: align_words(&transcription, &processed.text),
+                                        notes: processed.edit_notes,
+                                    });
+                                }
+                                invented_terms = processed.invented_terms;
+                                final_text = processed.text.clone();
+                                post_processed_text = Some(processed.text);
                             }
                             Ok(None) => {
                                 // Ramble processing skipped, use original
@@ -584,6 +644,27 @@ impl ShortcutAction for TranscribeAction {
                         {
                             error!("Failed to update transcription in history: {}", e);
                         }
+                        if !word_timings.is_empty() {
+                            if let Err(e) =
+                                hm_clone.save_word_timings(entry_id, &word_timings).await
+                            {
+                                error!("Failed to save word timings: {}", e);
+                            }
+                        }
+                        if let Some(alignment) = edit_alignment {
+                            if let Err(e) = hm_clone.save_edit_alignment(entry_id, &alignment).await
+                            {
+                                error!("Failed to save edit alignment: {}", e);
+                            }
+                        }
+                        if !invented_terms.is_empty() {
+                            if let Err(e) = hm_clone
+                                .flag_invented_terms(entry_id, &invented_terms)
+                                .await
+                            {
+                                error!("Failed to flag invented terms: {}", e);
+                            }
+                        }
                     });
 
                     // Paste the final text (either processed or original)
@@ -591,11 +672,16 @@ impl ShortcutAction for TranscribeAction {
                     // that would block the main event loop, preventing the app's own windows (like quick chat)
                     // from receiving the simulated paste events before the clipboard is restored.
                     let paste_time = Instant::now();
-                    match utils::paste(final_text, ah.clone()) {
-                        Ok(()) => {
-                            debug!("Text pasted successfully in {:?}", paste_time.elapsed())
+                    if utils::exceeds_paste_limit(&final_text, settings.max_paste_chars) {
+                        utils::hold_for_review(&ah, &final_text, settings.max_paste_chars);
+                    } else {
+                        match utils::paste(final_text, ah.clone()) {
+                            Ok(()) => {
+                                debug!("Text pasted successfully in {:?}", paste_time.elapsed());
+                                announce(&ah, Announcement::Pasted);
+                            }
+                            Err(e) => error!("Failed to paste transcription: {}", e),
                         }
-                        Err(e) => error!("Failed to paste transcription: {}", e),
                     }
 
                     // Perform UI updates on the main thread
@@ -649,6 +735,15 @@ impl ShortcutAction for SpeakSelectionAction {
         tauri::async_runtime::spawn(async move {
             debug!("[TTS] SpeakSelectionAction started");
 
+            // Release is ignored, so pressing again while speaking stops instead
+            if tts_manager.is_speaking() {
+                debug!("[TTS] Already speaking, stopping");
+                if let Err(e) = tts_manager.stop().await {
+                    error!("[TTS] Failed to stop: {}", e);
+                }
+                return;
+            }
+
             // 1. Get selected text
             match clipboard::get_selected_text(&app_handle) {
                 Ok(Some(text)) => {
@@ -682,6 +777,70 @@ impl ShortcutAction for SpeakSelectionAction {
     }
 }
 
+// Refine Clipboard Action - runs copied or typed text through the coherent prompt
+struct RefineClipboardAction;
+
+impl ShortcutAction for RefineClipboardAction {
+    fn interaction_behavior(&self) -> InteractionBehavior {
+        InteractionBehavior::Instant
+    }
+
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) -> bool {
+        let ah = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let text = match clipboard::get_clipboard_content(&ah) {
+                Ok(Some(text)) if !text.trim().is_empty() => text,
+                Ok(_) => {
+                    utils::show_error_overlay(
+                        &ah,
+                        &i18n::overlay_strings(&ah).clipboard_empty,
+                        false,
+                    );
+                    return;
+                }
+                Err(e) => {
+                    error!("Failed to read clipboard: {}", e);
+                    return;
+                }
+            };
+            info!("Refining {} characters from the clipboard", text.len());
+
+            let settings = get_settings(&ah);
+            change_tray_icon(&ah, TrayIconState::Transcribing);
+            show_making_coherent_overlay(&ah);
+            let refined = match process_ramble_to_coherent(&ah, &settings, &text, None).await {
+                Ok(Some(refinement)) => refinement.text,
+                Ok(None) => text,
+                Err(e) => {
+                    error!("Clipboard refinement failed: {}", e);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    utils::show_error_overlay(&ah, &e, false);
+                    return;
+                }
+            };
+            utils::hide_recording_overlay(&ah);
+            change_tray_icon(&ah, TrayIconState::Idle);
+
+            if !settings.refine_clipboard_paste {
+                match clipboard::set_clipboard_content(&ah, &refined) {
+                    Ok(()) => info!("Replaced the clipboard with its refinement"),
+                    Err(e) => error!("{}", e),
+                }
+            } else if utils::exceeds_paste_limit(&refined, settings.max_paste_chars) {
+                utils::hold_for_review(&ah, &refined, settings.max_paste_chars);
+            } else {
+                match utils::paste(refined, ah.clone()) {
+                    Ok(()) => announce(&ah, Announcement::Pasted),
+                    Err(e) => error!("Failed to paste refined clipboard text: {}", e),
+                }
+            }
+        });
+        true
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {}
+}
+
 /// Filter filler words from transcription using the configured regex pattern
 fn filter_filler_words(text: &str, pattern: Option<&str>) -> String {
     match pattern {
@@ -758,6 +917,58 @@ fn collapse_repeated_words(text: &str, enabled: bool) -> String {
     cleaned
 }
 
+/// Look up an app's category: user mappings first, then known_apps, then default category
+fn category_for_app(settings: &AppSettings, bundle_id: &str) -> String {
+    settings
+        .app_category_mappings
+        .iter()
+        .find(|m| m.bundle_identifier == bundle_id)
+        .map(|m| m.category_id.clone())
+        .or_else(|| known_apps::find_known_app(bundle_id).map(|k| k.suggested_category.clone()))
+        .unwrap_or_else(|| settings.default_category_id.clone())
+}
+
+/// Transcription language forced by the category the frontmost app is in, if any
+fn category_language(settings: &AppSettings) -> Option<String> {
+    let category_id = match settings.prompt_mode {
+        PromptMode::Dynamic => {
+            let bundle_id = app_detection::get_frontmost_application()
+                .map(|info| info.bundle_identifier)
+                .unwrap_or_default();
+            category_for_app(settings, &bundle_id)
+        }
+        PromptMode::Low => "low".to_string(),
+        PromptMode::Medium => "medium".to_string(),
+        PromptMode::High => "high".to_string(),
+    };
+    settings
+        .prompt_categories
+        .iter()
+        .find(|c| c.id == category_id)
+        .and_then(|c| c.language.clone())
+}
+
+/// Text produced by the refiner
+pub(crate) struct Refinement {
+    pub text: String,
+    /// Removals the refiner cited, only asked for in show-edits mode
+    pub edit_notes: Vec<String>,
+    /// Names and numbers the refiner added, found when verification is enabled
+    pub invented_terms: Vec<String>,
+}
+
+/// Clean up a transcription and refine it with the coherent prompt, as a tapped
+/// recording would. Used for audio that didn't come from the microphone.
+pub(crate) async fn refine_transcription(
+    app: &AppHandle,
+    settings: &AppSettings,
+    transcription: &str,
+) -> Result<Option<Refinement>, String> {
+    let filtered = filter_filler_words(transcription, settings.filler_word_filter.as_deref());
+    let filtered = collapse_repeated_words(&filtered, settings.collapse_repeated_words);
+    process_ramble_to_coherent(app, settings, &filtered, None).await
+}
+
 /// Process transcription through LLM using ramble-specific settings
 /// Returns Ok(Some(processed)) on success, Ok(None) if disabled/skipped, Err(msg) on error
 async fn process_ramble_to_coherent(
@@ -765,7 +976,7 @@ async fn process_ramble_to_coherent(
     settings: &AppSettings,
     transcription: &str,
     selection_context: Option<String>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<Refinement>, String> {
     // If the shortcut is pressed, we ALWAYS process regardless of ramble_enabled setting.
     // The setting is mostly for UI/default state.
     info!(
@@ -789,16 +1000,7 @@ async fn process_ramble_to_coherent(
                 record_detected_app(app, &bundle_id, &name);
             }
 
-            // Look up category: user mappings first, then known_apps, then default category
-            let cat_id = settings
-                .app_category_mappings
-                .iter()
-                .find(|m| m.bundle_identifier == bundle_id)
-                .map(|m| m.category_id.clone())
-                .or_else(|| {
-                    known_apps::find_known_app(&bundle_id).map(|k| k.suggested_category.clone())
-                })
-                .unwrap_or_else(|| settings.default_category_id.clone());
+            let cat_id = category_for_app(settings, &bundle_id);
 
             debug!(
                 "Dynamic mode: detected app '{}' ({}), using category '{}'",
@@ -810,9 +1012,17 @@ async fn process_ramble_to_coherent(
         PromptMode::Medium => ("medium".to_string(), "Unknown".to_string()),
         PromptMode::High => ("high".to_string(), "Unknown".to_string()),
     };
+    // A dictation reminder can pick the category for the dictation it prompted
+    let category_id = match crate::reminders::take_category_override() {
+        Some(reminder_category) => {
+            info!("Using category '{}' from a reminder", reminder_category);
+            reminder_category
+        }
+        None => category_id,
+    };
 
     // Find the prompt for this category, falling back to default category's prompt
-    let prompt = settings
+    let category = settings
         .prompt_categories
         .iter()
         .find(|c| c.id == category_id)
@@ -825,9 +1035,9 @@ async fn process_ramble_to_coherent(
                 .prompt_categories
                 .iter()
                 .find(|c| c.id == settings.default_category_id)
-        })
-        .map(|c| c.prompt.clone())
-        .unwrap_or_default();
+        });
+    let prompt = category.map(|c| c.prompt.clone()).unwrap_or_default();
+    let chaptering = category.is_some_and(|c| c.chaptering);
 
     if prompt.trim().is_empty() {
         let msg = "Prompt is empty".to_string();
@@ -835,48 +1045,34 @@ async fn process_ramble_to_coherent(
         return Err(msg);
     }
 
-    // Get the model ID to use - check for vision model if screenshots are present
-    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
-    let vision_context = audio_manager.get_vision_context();
-    let has_screenshots = !vision_context.is_empty();
-
-    // Use vision-compatible model if screenshots present and vision is enabled
-    let model_id = if has_screenshots && settings.coherent_use_vision {
-        // Use the same default model but ensure it supports vision
-        settings
-            .default_coherent_model_id
-            .as_ref()
-            .ok_or_else(|| "No coherent model configured".to_string())?
-    } else {
-        settings
-            .default_coherent_model_id
-            .as_ref()
-            .ok_or_else(|| "No coherent model configured".to_string())?
-    };
-
-    // Resolve the LLM config using the unified helper
-    let llm_config = resolve_llm_config(settings, model_id).await?;
-    let provider = llm_config.provider.clone();
-    let model = llm_config.model.model_id.clone();
-
-    // Log the model being used to the frontend
-    utils::log_to_frontend(app, "info", &format!("Using model: {}", model));
+    // The embedded model refines when no provider is configured or reachable
+    let local_model_path = settings
+        .local_llm_model_path
+        .clone()
+        .filter(|_| settings.local_llm_enabled);
+    let primary_model_id = settings.default_coherent_model_id.clone();
+    if primary_model_id.is_none() && local_model_path.is_none() {
+        return Err("No coherent model configured".to_string());
+    }
 
     info!(
-        "Starting Ramble to Coherent with provider '{}' (model: {}), category: '{}', app: '{}'",
-        provider.name, model, category_id, app_name
+        "Starting Ramble to Coherent, category: '{}', app: '{}'",
+        category_id, app_name
     );
     utils::log_to_frontend(app, "info", &format!("Using {} mode", category_id));
 
     // Emit event to update overlay icon with the detected category
-    let _ = app.emit("category-detected", &category_id);
+    let _ = CategoryDetected(category_id.clone()).emit(app);
 
     // Replace variables in the prompt
     // ${application} - The detected app name
     // ${category} - The category name
+    // ${project} - The active project's name, empty without one
     // ${selection} - Selected text captured before recording
     // ${output} - The transcribed speech
     // ${clipboard} - Current clipboard content
+    // ${date}, ${time} - The current local date and time
+    // ${window_title} - Title of the focused window
     // ${screen_context} - (REMOVED) - was OCR text from screen capture
 
     // Get clipboard content and apply cutoff if configured
@@ -901,107 +1097,367 @@ async fn process_ramble_to_coherent(
         }
     };
 
-    let processed_prompt = if let Some(selection) = selection_context {
+    let mut values = HashMap::from([
+        ("application", app_name.clone()),
+        ("category", category_id.clone()),
+        (
+            "project",
+            crate::projects::active(settings)
+                .map(|p| p.name.clone())
+                .unwrap_or_default(),
+        ),
+        ("output", transcription.to_string()),
+        ("clipboard", clipboard_content),
+    ]);
+    values.extend(date_time_values());
+    // Listing windows is comparatively slow, so only done when the prompt asks for it
+    if prompt.contains("${window_title}") {
+        let window_title = app_detection::get_focused_window_title().unwrap_or_default();
+        values.insert("window_title", window_title);
+    }
+    if let Some(selection) = selection_context {
         if prompt.contains("${selection}") {
-            // User has explicitly included ${selection} in their prompt
-            prompt
-                .replace("${application}", &app_name)
-                .replace("${category}", &category_id)
-                .replace("${output}", transcription)
-                .replace("${selection}", &selection)
-                .replace("${clipboard}", &clipboard_content)
-                .replace("${screen_context}", "")
+            values.insert("selection", selection);
         } else {
             // User hasn't included ${selection}, so we ignore it to respect "not combined" requested by user unless explicit.
             warn!("Selection context available but ${{selection}} variable missing in prompt. Ignoring selection.");
-            prompt
-                .replace("${application}", &app_name)
-                .replace("${category}", &category_id)
-                .replace("${output}", transcription)
-                .replace("${clipboard}", &clipboard_content)
-                .replace("${screen_context}", "")
         }
-    } else {
-        // No selection context, just clear the variable if it exists
-        prompt
-            .replace("${application}", &app_name)
-            .replace("${category}", &category_id)
-            .replace("${output}", transcription)
-            .replace("${selection}", "")
-            .replace("${clipboard}", &clipboard_content)
-            .replace("${screen_context}", "")
-    };
+    }
+
+    let (processed_prompt, unknown_variables) = render_prompt(&prompt, &values);
+    if !unknown_variables.is_empty() {
+        let names = unknown_variables
+            .iter()
+            .map(|name| format!("${{{}}}", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        warn!("Prompt uses unknown variables: {}", names);
+        utils::log_to_frontend(
+            app,
+            "warning",
+            &format!("Unknown prompt variables left as written: {}", names),
+        );
+    }
 
     debug!(
         "Processed prompt ({} chars):\n{}",
         processed_prompt.len(),
         processed_prompt
     );
+    // Today's earlier dictations keep names and references consistent across the day
+    let processed_prompt = match crate::day_context::prompt_section(app, settings) {
+        Some(section) => format!("{}{}", processed_prompt, section),
+        None => processed_prompt,
+    };
+    // Everything the refiner is given counts as a source for the invention check
+    let guardrail_source = settings.verify_refinement.then(|| processed_prompt.clone());
+
+    // Try the primary model, then each fallback while providers are overloaded or down
+    let mut model_ids = Vec::new();
+    if let Some(primary_model_id) = &primary_model_id {
+        model_ids.push(primary_model_id.clone());
+        model_ids.extend(
+            settings
+                .coherent_fallback_model_ids
+                .iter()
+                .filter(|id| *id != primary_model_id)
+                .cloned(),
+        );
+    }
+    let mut content = None;
+    let mut last_error = String::new();
+    for (attempt, model_id) in model_ids.iter().enumerate() {
+        if attempt > 0 {
+            utils::log_to_frontend(
+                app,
+                "warning",
+                &format!("{} - retrying with fallback model", last_error),
+            );
+        }
+        match request_refinement(app, settings, model_id, &processed_prompt, transcription).await {
+            Ok(output) => {
+                content = Some(output);
+                break;
+            }
+            Err(failure) => {
+                warn!("Refinement with '{}' failed: {}", model_id, failure.message);
+                last_error = failure.message;
+                if !failure.retryable {
+                    break;
+                }
+            }
+        }
+    }
+    if content.is_none() {
+        if let Some(model_path) = &local_model_path {
+            if !last_error.is_empty() {
+                utils::log_to_frontend(
+                    app,
+                    "warning",
+                    &format!("{} - refining with the local model", last_error),
+                );
+            }
+            match request_local_refinement(app, settings, model_path, &processed_prompt).await {
+                Ok(output) => content = Some(output),
+                Err(e) => last_error = e,
+            }
+        }
+    }
+    let Some(content) = content else {
+        return Err(last_error);
+    };
+
+    info!(
+        "Ramble to Coherent succeeded. Output length: {} chars",
+        content.len()
+    );
+    utils::log_to_frontend(app, "info", "Refinement complete");
+    let mut refinement = refined_output(settings, content, guardrail_source.as_deref());
+    if !refinement.invented_terms.is_empty() {
+        warn!(
+            "Refinement added terms not in the transcription: {:?}",
+            refinement.invented_terms
+        );
+        utils::log_to_frontend(
+            app,
+            "warning",
+            &format!(
+                "Refinement may have added content: {}",
+                refinement.invented_terms.join(", ")
+            ),
+        );
+    }
+    // Long refinements in categories with chaptering get headings and contents
+    if chaptering
+        && crate::chapters::needs_chapters(&refinement.text, settings.chaptering_min_words)
+    {
+        utils::log_to_frontend(app, "info", "Adding chapters...");
+        match crate::chapters::add_chapters(app, settings, &refinement.text).await {
+            Ok(text) => refinement.text = text,
+            Err(e) => {
+                warn!("Chaptering failed: {}", e);
+                utils::log_to_frontend(app, "warning", &format!("Couldn't add chapters: {}", e));
+            }
+        }
+    }
+    Ok(Some(refinement))
+}
 
-    // Create OpenAI-compatible client using the resolved config
-    let client = match crate::llm_client::create_client(&provider, llm_config.api_key) {
-        Ok(client) => client,
-        Err(e) => {
-            return Err(format!("Failed to create client: {}", e));
+/// Turn the refiner's answer into the text to paste: edit notes are split off, the
+/// dictionary's casing is enforced again since the model may re-case terms, and
+/// names and numbers missing from `guardrail_source` are flagged
+fn refined_output(
+    settings: &AppSettings,
+    content: String,
+    guardrail_source: Option<&str>,
+) -> Refinement {
+    let (text, edit_notes) = if settings.show_edits {
+        split_edit_notes(&content)
+    } else {
+        (content, Vec::new())
+    };
+    let text = apply_term_casing(&text, &settings.cased_terms);
+    let invented_terms = guardrail_source
+        .map(|source| find_invented_terms(&text, &[source]))
+        .unwrap_or_default();
+    Refinement {
+        text,
+        edit_notes,
+        invented_terms,
+    }
+}
+
+/// Why a refinement request failed, and whether another provider might succeed
+struct RefinementFailure {
+    message: String,
+    retryable: bool,
+}
+
+impl From<String> for RefinementFailure {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            retryable: false,
+        }
+    }
+}
+
+/// The refinement system prompt, asking for edit notes when they're shown
+fn coherent_system_prompt(settings: &AppSettings) -> String {
+    if settings.show_edits {
+        format!("{}{}", COHERENT_SYSTEM_PROMPT, EDIT_NOTES_INSTRUCTION)
+    } else {
+        COHERENT_SYSTEM_PROMPT.to_string()
+    }
+}
+
+/// Emits [`RefinementProgress`] for each streamed piece of the answer
+fn refinement_progress(app: &AppHandle) -> impl FnMut(&str) + '_ {
+    let mut streamed_chars = 0;
+    move |delta: &str| {
+        streamed_chars += delta.chars().count() as u32;
+        let _ = RefinementProgress {
+            delta: delta.to_string(),
+            chars: streamed_chars,
         }
+        .emit(app);
+    }
+}
+
+/// Refine with the embedded model. Screenshots are ignored, it only reads text.
+async fn request_local_refinement(
+    app: &AppHandle,
+    settings: &AppSettings,
+    model_path: &str,
+    processed_prompt: &str,
+) -> Result<String, String> {
+    utils::log_to_frontend(app, "info", "Using local model");
+    let manager = app.state::<Arc<LocalLlmManager>>();
+    let output = manager
+        .generate(
+            model_path,
+            &coherent_system_prompt(settings),
+            processed_prompt,
+            refinement_progress(app),
+        )
+        .await?;
+    // llama-server keeps reasoning out of the answer, but a chat template it doesn't
+    // recognise can still leave a leading thinking block
+    answer_text(&output, true).map_err(|failure| failure.message)
+}
+
+/// The refined text of a model's answer. A leading reasoning trace is stripped from
+/// models that may include one, since the text gets pasted; hiding it is only
+/// optional in chat.
+fn answer_text(content: &str, may_include_reasoning: bool) -> Result<String, RefinementFailure> {
+    let content = if may_include_reasoning {
+        crate::reasoning::strip_reasoning(content)
+    } else {
+        content.trim().to_string()
     };
+    if content.is_empty() {
+        return Err("No response from AI".to_string().into());
+    }
+    Ok(content)
+}
+
+/// Send the refinement request to one model, returning the raw model output
+async fn request_refinement(
+    app: &AppHandle,
+    settings: &AppSettings,
+    model_id: &str,
+    processed_prompt: &str,
+    transcription: &str,
+) -> Result<String, RefinementFailure> {
+    // Resolve the LLM config using the unified helper
+    let llm_config = resolve_llm_config(settings, model_id).await?;
+    let provider = llm_config.provider.clone();
+    let model = llm_config.model.model_id.clone();
+
+    // Fail fast if a self-hosted server is down instead of waiting on the connect timeout
+    crate::provider_health::ensure_available(app, &provider, &model)
+        .await
+        .map_err(|message| RefinementFailure {
+            message,
+            retryable: true,
+        })?;
+
+    // Log the model being used to the frontend
+    utils::log_to_frontend(app, "info", &format!("Using model: {}", model));
+    info!(
+        "Refining with provider '{}' (model: {})",
+        provider.name, model
+    );
+
+    // Refuse to send the request if it would go over a spend limit
+    let usage_manager = app.state::<Arc<UsageManager>>();
+    let pricing = crate::pricing::pricing_for(&provider, &llm_config.model);
+    if let Some(pricing) = &pricing {
+        let spent_today = usage_manager.spent_today().unwrap_or_else(|e| {
+            warn!("Failed to read today's spend: {}", e);
+            0.0
+        });
+        crate::pricing::check_spend_limits(
+            settings,
+            pricing,
+            &format!("{}{}", COHERENT_SYSTEM_PROMPT, processed_prompt),
+            transcription,
+            spent_today,
+        )?;
+    } else if settings.daily_spend_limit_usd.is_some()
+        || settings.per_request_spend_limit_usd.is_some()
+    {
+        warn!(
+            "No pricing known for model '{}', spend limits can't be applied",
+            model
+        );
+    }
+
+    // Create the system message to enforce proxy persona
+    let system_prompt = coherent_system_prompt(settings);
+
+    // Stream the response so the overlay can show progress on long refinements
+    let on_delta = refinement_progress(app);
 
-    // Build the chat completion request
-    // If vision is supported and a screenshot is available, use array content (vision)
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     let vision_context = audio_manager.get_vision_context();
 
-    let message = if provider.supports_vision {
-        if !vision_context.is_empty() {
+    // Gemini goes through its native API, which keeps the system instruction intact
+    if is_gemini(&provider) {
+        let images: &[String] = if provider.supports_vision {
+            &vision_context
+        } else {
+            &[]
+        };
+        if !images.is_empty() {
             info!(
                 "Vision enabled: Attaching {} screenshots to request",
-                vision_context.len()
+                images.len()
             );
             utils::log_to_frontend(app, "info", "Analyzing screenshots...");
+        }
+        let mut body = gemini_request_body(
+            Some(&system_prompt),
+            vec![gemini_content("user", processed_prompt, images)],
+        );
+        crate::gemini::apply_model_options(&mut body, &llm_config.model);
+        let response =
+            gemini_generate_content(&provider, &llm_config.api_key, &model, body, on_delta)
+                .await
+                .map_err(|e| RefinementFailure {
+                    retryable: e.retryable,
+                    message: e.message,
+                })?;
+        if let Some(usage) = &response.usage {
+            record_usage(app, &provider, &llm_config.model, "refinement", usage);
+        }
+        return answer_text(
+            &response.text,
+            crate::reasoning::may_include_reasoning(&llm_config.model),
+        );
+    }
 
-            let text_part = ChatCompletionRequestMessageContentPartTextArgs::default()
-                .text(processed_prompt)
-                .build()
-                .map_err(|e| format!("Request error (text part): {}", e))?;
-
-            let mut parts = vec![ChatCompletionRequestUserMessageContentPart::Text(text_part)];
-
-            for (i, base64_image) in vision_context.iter().enumerate() {
-                debug!(
-                    "Attaching screenshot {} ({} chars)",
-                    i + 1,
-                    base64_image.len()
-                );
-                let image_part = ChatCompletionRequestMessageContentPartImageArgs::default()
-                    .image_url(format!("data:image/png;base64,{}", base64_image))
-                    .build()
-                    .map_err(|e| format!("Request error (image part {}): {}", i, e))?;
-                parts.push(ChatCompletionRequestUserMessageContentPart::ImageUrl(
-                    image_part,
-                ));
-            }
-
-            let content = ChatCompletionRequestUserMessageContent::Array(parts);
+    // Create OpenAI-compatible client using the resolved config
+    let api_key = llm_config.api_key.clone();
+    let client = match crate::llm_client::create_client(&provider, llm_config.api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            return Err(format!("Failed to create client: {}", e).into());
+        }
+    };
 
-            ChatCompletionRequestMessage::User(
-                ChatCompletionRequestUserMessageArgs::default()
-                    .content(content)
-                    .build()
-                    .map_err(|e| format!("Request error (user message): {}", e))?,
-            )
-        } else {
+    // Screenshots are only attached for providers that read images
+    let images: &[String] = if provider.supports_vision {
+        if vision_context.is_empty() {
             warn!("Provider supports vision but no screenshot context found.");
-            // Proceed with text only
-            match ChatCompletionRequestUserMessageArgs::default()
-                .content(processed_prompt)
-                .build()
-            {
-                Ok(msg) => ChatCompletionRequestMessage::User(msg),
-                Err(e) => {
-                    return Err(format!("Request error: {}", e));
-                }
-            }
+        } else {
+            info!(
+                "Vision enabled: Attaching {} screenshots to request",
+                vision_context.len()
+            );
+            utils::log_to_frontend(app, "info", "Analyzing screenshots...");
         }
+        &vision_context
     } else {
         if !vision_context.is_empty() {
             warn!(
@@ -1015,54 +1471,91 @@ async fn process_ramble_to_coherent(
                 "Provider doesn't support images - ignoring screenshots",
             );
         }
-        match ChatCompletionRequestUserMessageArgs::default()
+        &[]
+    };
+    let request = refinement_request(&model, system_prompt, processed_prompt, images)?;
+
+    match stream_model_completion(
+        &client,
+        &provider,
+        &api_key,
+        &llm_config.model,
+        request,
+        on_delta,
+    )
+    .await
+    {
+        Ok((content, usage)) => {
+            if let Some(usage) = &usage {
+                record_usage(app, &provider, &llm_config.model, "refinement", usage);
+            }
+            answer_text(
+                &content,
+                crate::reasoning::may_include_reasoning(&llm_config.model),
+            )
+        }
+        Err(e) => Err(RefinementFailure {
+            retryable: e.is_retryable(),
+            message: extract_llm_error(&e, &model),
+        }),
+    }
+}
+
+/// The chat completion request asking `model` to refine `processed_prompt`. With
+/// screenshots the user message is sent as array content so the images go along.
+fn refinement_request(
+    model: &str,
+    system_prompt: String,
+    processed_prompt: &str,
+    images: &[String],
+) -> Result<CreateChatCompletionRequest, String> {
+    let message = if images.is_empty() {
+        ChatCompletionRequestUserMessageArgs::default()
             .content(processed_prompt)
             .build()
-        {
-            Ok(msg) => ChatCompletionRequestMessage::User(msg),
-            Err(e) => {
-                return Err(format!("Request error: {}", e));
-            }
+            .map_err(|e| format!("Request error: {}", e))?
+    } else {
+        let text_part = ChatCompletionRequestMessageContentPartTextArgs::default()
+            .text(processed_prompt)
+            .build()
+            .map_err(|e| format!("Request error (text part): {}", e))?;
+
+        let mut parts = vec![ChatCompletionRequestUserMessageContentPart::Text(text_part)];
+
+        for (i, base64_image) in images.iter().enumerate() {
+            debug!(
+                "Attaching screenshot {} ({} chars)",
+                i + 1,
+                base64_image.len()
+            );
+            let image_part = ChatCompletionRequestMessageContentPartImageArgs::default()
+                .image_url(format!("data:image/png;base64,{}", base64_image))
+                .build()
+                .map_err(|e| format!("Request error (image part {}): {}", i, e))?;
+            parts.push(ChatCompletionRequestUserMessageContentPart::ImageUrl(
+                image_part,
+            ));
         }
+
+        ChatCompletionRequestUserMessageArgs::default()
+            .content(ChatCompletionRequestUserMessageContent::Array(parts))
+            .build()
+            .map_err(|e| format!("Request error (user message): {}", e))?
     };
 
-    // Create the system message to enforce proxy persona
     let system_message = ChatCompletionRequestSystemMessageArgs::default()
-        .content("You are an AI assistant acting as the user's proxy. You must speak **as** the user, in the first person. Do not address the user directly. Do not explain your response. Your output will be sent to another agent or system as if the user wrote it.")
+        .content(system_prompt)
         .build()
         .map_err(|e| format!("Request error (system message): {}", e))?;
 
-    let request = match CreateChatCompletionRequestArgs::default()
-        .model(&model)
+    CreateChatCompletionRequestArgs::default()
+        .model(model)
         .messages(vec![
             ChatCompletionRequestMessage::System(system_message),
-            message,
+            ChatCompletionRequestMessage::User(message),
         ])
         .build()
-    {
-        Ok(req) => req,
-        Err(e) => {
-            return Err(format!("Request error: {}", e));
-        }
-    };
-
-    // Send the request
-    match client.chat().create(request).await {
-        Ok(response) => {
-            if let Some(choice) = response.choices.first() {
-                if let Some(content) = &choice.message.content {
-                    info!(
-                        "Ramble to Coherent succeeded. Output length: {} chars",
-                        content.len()
-                    );
-                    utils::log_to_frontend(app, "info", "Refinement complete");
-                    return Ok(Some(content.clone()));
-                }
-            }
-            Err("No response from AI".to_string())
-        }
-        Err(e) => Err(extract_llm_error(&e, &model)),
-    }
+        .map_err(|e| format!("Request error: {}", e))
 }
 
 // Cancel Action
@@ -1166,6 +1659,22 @@ impl ShortcutAction for QuickChatAction {
     }
 }
 
+// Toggle Shortcuts Action - pauses/resumes every other shortcut
+struct ToggleShortcutsAction;
+
+impl ShortcutAction for ToggleShortcutsAction {
+    fn interaction_behavior(&self) -> InteractionBehavior {
+        InteractionBehavior::Instant
+    }
+
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) -> bool {
+        crate::shortcut::toggle_all_shortcuts(app);
+        true
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {}
+}
+
 // Voice Command Action
 struct VoiceCommandAction;
 
@@ -1180,6 +1689,11 @@ impl ShortcutAction for VoiceCommandAction {
             binding_id
         );
 
+        if get_settings(app).focus_mode {
+            info!("Voice commands are off in focus mode");
+            return false;
+        }
+
         // Check if we're resuming from a paused state
         if is_operation_paused(app, binding_id) {
             debug!("Resuming paused voice command for binding: {}", binding_id);
@@ -1190,6 +1704,8 @@ impl ShortcutAction for VoiceCommandAction {
         // Load model in the background (for transcription)
         let tm = app.state::<Arc<TranscriptionManager>>();
         tm.initiate_model_load();
+        // Category languages only apply to dictation
+        tm.set_language_override(None);
 
         let binding_id = binding_id.to_string();
         change_tray_icon(app, TrayIconState::Recording);
@@ -1208,7 +1724,11 @@ impl ShortcutAction for VoiceCommandAction {
             rm_clone.apply_mute();
         });
 
-        rm.try_start_recording(&binding_id)
+        let recording_started = rm.try_start_recording(&binding_id);
+        if recording_started {
+            announce(app, Announcement::RecordingStarted);
+        }
+        recording_started
     }
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
@@ -1232,6 +1752,7 @@ impl ShortcutAction for VoiceCommandAction {
 
         rm.remove_mute();
         play_feedback_sound(app, SoundType::Stop);
+        announce(app, Announcement::RecordingStopped);
 
         let binding_id = binding_id.to_string();
         let samples = rm.stop_recording(&binding_id);
@@ -1257,11 +1778,15 @@ impl ShortcutAction for VoiceCommandAction {
                                             let ah_clone = ah.clone();
                                             ah.run_on_main_thread(move || {
                                                 match utils::paste(text, ah_clone.clone()) {
-                                                    Ok(()) => debug!("Command output pasted"),
+                                                    Ok(()) => {
+                                                        debug!("Command output pasted");
+                                                        announce(&ah_clone, Announcement::Pasted);
+                                                    }
                                                     Err(e) => error!("Failed to paste: {}", e),
                                                 }
                                                 utils::hide_recording_overlay(&ah_clone);
                                                 change_tray_icon(&ah_clone, TrayIconState::Idle);
+                                                listen_for_follow_up(&ah_clone, &binding_id);
                                             })
                                             .unwrap_or_else(|e| {
                                                 error!("Failed to run on main thread: {:?}", e);
@@ -1271,6 +1796,7 @@ impl ShortcutAction for VoiceCommandAction {
                                             // Show brief feedback
                                             utils::hide_recording_overlay(&ah);
                                             change_tray_icon(&ah, TrayIconState::Idle);
+                                            listen_for_follow_up(&ah, &binding_id);
                                         }
                                         crate::voice_commands::CommandResult::Error(msg) => {
                                             utils::show_error_overlay(&ah, &msg, true);
@@ -1303,10 +1829,98 @@ impl ShortcutAction for VoiceCommandAction {
     }
 }
 
-/// Process a voice command through LLM interpretation and execution
+/// Keep listening for another command after one completes, when follow-up
+/// listening is on. Runs on its own thread since starting a recording can wait for
+/// the previous one to wind down.
+fn listen_for_follow_up(app: &AppHandle, binding_id: &str) {
+    let seconds = get_settings(app).voice_command_follow_up_seconds;
+    if seconds == 0 {
+        return;
+    }
+
+    let app = app.clone();
+    let binding_id = binding_id.to_string();
+    std::thread::spawn(move || {
+        let rm = app.state::<Arc<AudioRecordingManager>>();
+        if !rm.start_follow_up(&binding_id, seconds) {
+            return;
+        }
+        debug!("Listening {}s for a follow-up command", seconds);
+        if let Some(overlay) = app.get_webview_window("recording_overlay") {
+            let _ = overlay.emit("show-overlay", "voice_command_follow_up");
+        }
+    });
+}
+
+/// How long to wait between chained steps, so an app one step opens has focus
+/// before the next step types into it
+const VOICE_COMMAND_STEP_DELAY: Duration = Duration::from_millis(500);
+
+/// Process a voice command. A chain like "open terminal and then type git status"
+/// runs as an ordered plan: each step's output is pasted before the next step runs,
+/// and the first failing step aborts the rest.
 async fn process_voice_command(
     app: &AppHandle,
     transcription: &str,
+) -> Result<crate::voice_commands::CommandResult, String> {
+    use crate::voice_commands::CommandResult;
+
+    let steps = crate::voice_commands::split_steps(transcription);
+    if steps.len() <= 1 {
+        return process_voice_step(app, transcription).await;
+    }
+
+    info!("Running voice command plan: {:?}", steps);
+    for (index, step) in steps.iter().enumerate() {
+        let failed = |reason: String| {
+            format!(
+                "Step {} of {} (\"{}\") failed: {}",
+                index + 1,
+                steps.len(),
+                step,
+                reason
+            )
+        };
+
+        let result = process_voice_step(app, step).await.map_err(failed)?;
+        info!(
+            "Voice command step {}/{} '{}': {:?}",
+            index + 1,
+            steps.len(),
+            step,
+            result
+        );
+
+        match result {
+            CommandResult::Error(reason) => return Ok(CommandResult::Error(failed(reason))),
+            // The last step's result is handled like a single command's
+            result if index == steps.len() - 1 => return Ok(result),
+            CommandResult::PasteOutput(text) => utils::paste(text, app.clone()).map_err(failed)?,
+            CommandResult::Success => {}
+        }
+
+        tokio::time::sleep(VOICE_COMMAND_STEP_DELAY).await;
+    }
+
+    Ok(CommandResult::Success)
+}
+
+/// Process a single voice command step and log how it went
+async fn process_voice_step(
+    app: &AppHandle,
+    transcription: &str,
+) -> Result<crate::voice_commands::CommandResult, String> {
+    crate::voice_command_log::begin(transcription);
+    let result = run_voice_step(app, transcription).await;
+    crate::voice_command_log::finish(app, &result);
+    result
+}
+
+/// Run a single voice command step, locally when its trigger phrase matches and
+/// through LLM interpretation otherwise
+async fn run_voice_step(
+    app: &AppHandle,
+    transcription: &str,
 ) -> Result<crate::voice_commands::CommandResult, String> {
     let settings = get_settings(app);
 
@@ -1323,6 +1937,39 @@ async fn process_voice_command(
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     let selection_context = audio_manager.get_selection_context();
 
+    // Common commands like "open chrome" run instantly, without the network
+    if let Some(intent) = crate::voice_commands::match_intent(commands, transcription) {
+        info!(
+            "Matched voice command '{}' locally (score {:.2})",
+            intent.command.name, intent.score
+        );
+        crate::voice_command_log::note_match(
+            crate::voice_command_log::CommandMatchSource::Phrase,
+            Some((&intent.command.id, &intent.command.name)),
+        );
+        return match intent.command.command_type {
+            crate::settings::VoiceCommandType::Custom => {
+                Ok(crate::voice_commands::execute_bespoke_command(
+                    intent.command,
+                    selection_context.as_deref(),
+                    Some(transcription),
+                    &intent.parameters,
+                ))
+            }
+            crate::settings::VoiceCommandType::Builtin
+            | crate::settings::VoiceCommandType::LegacyInferable => {
+                execute_builtin_command(
+                    app,
+                    &intent.command.id,
+                    transcription,
+                    Some(&intent.argument),
+                    selection_context.as_deref(),
+                )
+                .await
+            }
+        };
+    }
+
     // Let LLM interpret the command and determine what to execute
     execute_via_llm(app, &settings, transcription, selection_context).await
 }
@@ -1330,6 +1977,8 @@ async fn process_voice_command(
 fn execute_shell_command(cmd: &str) -> crate::voice_commands::CommandResult {
     use std::process::Command;
 
+    crate::voice_command_log::note_script(cmd);
+
     match Command::new("sh").arg("-c").arg(cmd).output() {
         Ok(output) => {
             if output.status.success() {
@@ -1352,6 +2001,8 @@ fn execute_shell_command(cmd: &str) -> crate::voice_commands::CommandResult {
 fn execute_applescript_command(script: &str) -> crate::voice_commands::CommandResult {
     use std::process::Command;
 
+    crate::voice_command_log::note_script(script);
+
     match Command::new("osascript").arg("-e").arg(script).output() {
         Ok(output) => {
             if output.status.success() {
@@ -1390,19 +2041,27 @@ async fn execute_via_llm(
     let transcription_lower = transcription.to_lowercase();
 
     // Pre-check: For custom commands, try direct phrase matching first
-    // This avoids LLM misinterpreting commands like "open chat" as "open app"
+    // This avoids LLM misinterpreting commands like "open chat" as "open app".
+    // Commands with parameters need the LLM to extract their values.
     for cmd in &settings.voice_commands {
-        if cmd.command_type == crate::settings::VoiceCommandType::Custom {
+        if cmd.command_type == crate::settings::VoiceCommandType::Custom
+            && cmd.parameters.is_empty()
+        {
             for phrase in &cmd.phrases {
                 if transcription_lower.contains(&phrase.to_lowercase()) {
                     debug!(
                         "Direct phrase match for custom command '{}' (phrase: '{}')",
                         cmd.name, phrase
                     );
+                    crate::voice_command_log::note_match(
+                        crate::voice_command_log::CommandMatchSource::Phrase,
+                        Some((&cmd.id, &cmd.name)),
+                    );
                     return Ok(crate::voice_commands::execute_bespoke_command(
                         cmd,
                         selection.as_deref(),
                         Some(transcription),
+                        &HashMap::new(),
                     ));
                 }
             }
@@ -1450,11 +2109,15 @@ async fn execute_via_llm(
         .build()
         .map_err(|e| format!("Failed to build request: {}", e))?;
 
-    let response = client
-        .chat()
-        .create(request)
-        .await
-        .map_err(|e| extract_llm_error(&e, &api_model))?;
+    let chat = client.chat();
+    let response = with_retry(format!("Voice command with '{}'", api_model), || {
+        chat.create(request.clone())
+    })
+    .await
+    .map_err(|e| extract_llm_error(&e, &api_model))?;
+    if let Some(usage) = response.usage.as_ref().map(TokenUsage::from) {
+        record_usage(app, &provider, &llm_config.model, "voice_command", &usage);
+    }
 
     let llm_response = response
         .choices
@@ -1486,6 +2149,15 @@ async fn execute_via_llm(
             if let Some(matched_id) = json.get("matched_command").and_then(|v| v.as_str()) {
                 // LLM matched a command, execute it
                 let command = json.get("command").and_then(|v| v.as_str()).unwrap_or("");
+                let matched_name = settings
+                    .voice_commands
+                    .iter()
+                    .find(|c| c.id == matched_id)
+                    .map_or(matched_id, |c| c.name.as_str());
+                crate::voice_command_log::note_match(
+                    crate::voice_command_log::CommandMatchSource::Llm,
+                    Some((matched_id, matched_name)),
+                );
 
                 // Check for paste execution type first (used by print/echo commands)
                 if exec_type == "paste" {
@@ -1505,10 +2177,20 @@ async fn execute_via_llm(
                         crate::settings::VoiceCommandType::Custom => {
                             // Execute user-defined script
                             debug!("Executing custom command by ID: {}", matched_id);
+                            let parameters = match crate::voice_commands::parameters_from_llm(
+                                cmd,
+                                json.get("parameters"),
+                            ) {
+                                Ok(parameters) => parameters,
+                                Err(e) => {
+                                    return Ok(crate::voice_commands::CommandResult::Error(e))
+                                }
+                            };
                             return Ok(crate::voice_commands::execute_bespoke_command(
                                 cmd,
                                 selection.as_deref(),
                                 Some(transcription),
+                                &parameters,
                             ));
                         }
                         crate::settings::VoiceCommandType::Builtin
@@ -1516,10 +2198,13 @@ async fn execute_via_llm(
                             // Execute built-in command with native handler
                             debug!("Executing built-in command: {}", matched_id);
                             return execute_builtin_command(
+                                app,
                                 matched_id,
                                 transcription,
+                                None,
                                 selection.as_deref(),
-                            );
+                            )
+                            .await;
                         }
                     }
                 }
@@ -1570,6 +2255,10 @@ async fn execute_via_llm(
                     "LLM did not match a command ID, but suggested execution type: {}",
                     exec_type
                 );
+                crate::voice_command_log::note_match(
+                    crate::voice_command_log::CommandMatchSource::Llm,
+                    None,
+                );
                 if exec_type == "paste" {
                     let output = json
                         .get("output")
@@ -1614,6 +2303,7 @@ fn type_command_in_terminal(
     let command_clean = command.replace('\n', " ").replace('\r', " ");
 
     info!("Opening terminal with shell command: {}", command_clean);
+    crate::voice_command_log::note_script(&command_clean);
 
     // Check if the configured terminal is installed
     let configured_terminal = settings.unknown_command_terminal.as_str();
@@ -1833,10 +2523,14 @@ end tell"
     Ok(crate::voice_commands::CommandResult::Success)
 }
 
-/// Execute a built-in command with native handler
-fn execute_builtin_command(
+/// Execute a built-in command with native handler. `argument` is what followed the
+/// trigger phrase when the command was matched locally; otherwise it's extracted
+/// from the transcription.
+async fn execute_builtin_command(
+    app: &AppHandle,
     command_id: &str,
     transcription: &str,
+    argument: Option<&str>,
     selection: Option<&str>,
 ) -> Result<crate::voice_commands::CommandResult, String> {
     match command_id {
@@ -1844,6 +2538,8 @@ fn execute_builtin_command(
             // Extract search query - use selection if provided, otherwise extract from transcription
             let query = if let Some(sel) = selection {
                 sel.to_string()
+            } else if let Some(argument) = argument {
+                argument.to_string()
             } else {
                 extract_search_query(transcription)
             };
@@ -1859,7 +2555,9 @@ fn execute_builtin_command(
         }
         "open_app" => {
             // Extract app name from transcription
-            let app_name = extract_app_name(transcription);
+            let app_name = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_app_name(transcription));
             if app_name.is_empty() {
                 return Ok(crate::voice_commands::CommandResult::Error(
                     "No application name provided".to_string(),
@@ -1867,11 +2565,137 @@ fn execute_builtin_command(
             }
             Ok(execute_shell_command(&format!("open -a \"{}\"", app_name)))
         }
+        "maximize_window" | "window_left_half" | "window_right_half" => {
+            let placement = match command_id {
+                "maximize_window" => crate::window_management::Placement::Maximized,
+                "window_left_half" => crate::window_management::Placement::LeftHalf,
+                _ => crate::window_management::Placement::RightHalf,
+            };
+            Ok(
+                match crate::window_management::place_front_window(placement) {
+                    Ok(()) => crate::voice_commands::CommandResult::Success,
+                    Err(e) => crate::voice_commands::CommandResult::Error(e),
+                },
+            )
+        }
+        "switch_to_app" => {
+            let app_name = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_switch_target(transcription));
+            if app_name.is_empty() {
+                return Ok(crate::voice_commands::CommandResult::Error(
+                    "No application name provided".to_string(),
+                ));
+            }
+            Ok(match crate::window_management::switch_to_app(&app_name) {
+                Ok(()) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
+        "set_timer" => {
+            let text = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_timer_request(transcription));
+            match crate::timers::parse_timer_request(&text) {
+                Some(request) => {
+                    crate::timers::start(app, request);
+                    Ok(crate::voice_commands::CommandResult::Success)
+                }
+                None => Ok(crate::voice_commands::CommandResult::Error(format!(
+                    "Couldn't tell how long the timer should be from \"{}\"",
+                    text
+                ))),
+            }
+        }
+        "list_timers" => {
+            crate::timers::list(app);
+            Ok(crate::voice_commands::CommandResult::Success)
+        }
+        "cancel_timer" => {
+            let query = argument.filter(|a| !a.trim().is_empty());
+            Ok(match crate::timers::cancel(app, query) {
+                Ok(_) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
+        "switch_project" => {
+            let name = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_project_name(transcription));
+            Ok(match crate::projects::switch_by_name(app, &name) {
+                Ok(()) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
+        "set_prompt_mode" => {
+            let text = argument.unwrap_or(transcription);
+            Ok(match crate::voice_settings::set_prompt_mode(app, text) {
+                Ok(()) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
+        "enable_refinement" | "disable_refinement" => {
+            let enabled = command_id == "enable_refinement";
+            Ok(match crate::voice_settings::set_refinement(app, enabled) {
+                Ok(()) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
+        "use_prompt" => {
+            let name = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_prompt_name(transcription));
+            Ok(match crate::voice_settings::use_prompt(app, &name) {
+                Ok(()) => crate::voice_commands::CommandResult::Success,
+                Err(e) => crate::voice_commands::CommandResult::Error(e),
+            })
+        }
         "print" => {
             // Extract text to print (everything after trigger words)
-            let text = extract_print_text(transcription);
+            let text = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_print_text(transcription));
             Ok(crate::voice_commands::CommandResult::PasteOutput(text))
         }
+        "fix_selection" => {
+            // Only the selection is proofread; what was said just picked the command
+            let Some(sel) = selection.filter(|sel| !sel.trim().is_empty()) else {
+                return Ok(crate::voice_commands::CommandResult::Error(
+                    "Select the text to fix first".to_string(),
+                ));
+            };
+            let settings = get_settings(app);
+            Ok(
+                match crate::proofread::proofread(app, &settings, sel).await {
+                    // Pasting over the still-selected text replaces it
+                    Ok(corrected) => crate::voice_commands::CommandResult::PasteOutput(corrected),
+                    Err(e) => crate::voice_commands::CommandResult::Error(e.to_string()),
+                },
+            )
+        }
+        "code_comment" | "doc_comment" => {
+            let text = argument
+                .map(str::to_string)
+                .unwrap_or_else(|| extract_comment_text(transcription));
+            if text.is_empty() {
+                return Ok(crate::voice_commands::CommandResult::Error(
+                    "No comment text provided".to_string(),
+                ));
+            }
+            let window_title = app_detection::get_focused_window_title();
+            let bundle_id =
+                app_detection::get_frontmost_application().map(|info| info.bundle_identifier);
+            let syntax = crate::code_comments::detect_syntax(
+                command_id == "doc_comment",
+                selection,
+                window_title.as_deref(),
+                bundle_id.as_deref(),
+            );
+            // The selected code is pasted back under the comment
+            Ok(crate::voice_commands::CommandResult::PasteOutput(
+                crate::code_comments::comment_for(&text, syntax, selection),
+            ))
+        }
         "refactor_code" => {
             // For refactor, we need to process selection through LLM
             // For now, just return the selection with a note
@@ -1922,6 +2746,62 @@ fn extract_app_name(transcription: &str) -> String {
     transcription.trim().to_string()
 }
 
+/// Extract the project name from transcription like "switch to project atlas"
+fn extract_project_name(transcription: &str) -> String {
+    let lower = transcription.to_lowercase();
+    let triggers = ["switch to project ", "open project ", "switch to "];
+    for trigger in triggers {
+        if let Some(pos) = lower.find(trigger) {
+            return transcription[pos + trigger.len()..].trim().to_string();
+        }
+    }
+    transcription.trim().to_string()
+}
+
+/// Extract the prompt name from transcription like "use the raw prompt"
+fn extract_prompt_name(transcription: &str) -> String {
+    let lower = transcription.to_lowercase();
+    let triggers = ["switch to ", "use "];
+    for trigger in triggers {
+        if let Some(pos) = lower.find(trigger) {
+            return transcription[pos + trigger.len()..].trim().to_string();
+        }
+    }
+    transcription.trim().to_string()
+}
+
+/// Extract the timer from transcription like "remind me in 20 minutes to stretch"
+fn extract_timer_request(transcription: &str) -> String {
+    let lower = transcription.to_lowercase();
+    let triggers = [
+        "remind me ",
+        "set a timer for ",
+        "start a timer for ",
+        "timer for ",
+    ];
+    for trigger in triggers {
+        if let Some(pos) = lower.find(trigger) {
+            return transcription[pos + trigger.len()..].trim().to_string();
+        }
+    }
+    transcription.trim().to_string()
+}
+
+/// Extract app name from transcription like "switch to slack"
+fn extract_switch_target(transcription: &str) -> String {
+    let lower = transcription.to_lowercase();
+    let triggers = ["switch to ", "bring up "];
+    for trigger in triggers {
+        if let Some(pos) = lower.find(trigger) {
+            return transcription[pos + trigger.len()..]
+                .trim()
+                .trim_end_matches(['.', '!', '?'])
+                .to_string();
+        }
+    }
+    transcription.trim().to_string()
+}
+
 // Context Chat Action
 pub struct ContextChatAction;
 
@@ -1933,6 +2813,11 @@ impl ShortcutAction for ContextChatAction {
     fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) -> bool {
         debug!("[ACTION] ContextChatAction::start called");
 
+        if get_settings(app).focus_mode {
+            info!("Context chat is off in focus mode");
+            return false;
+        }
+
         if is_operation_paused(app, binding_id) {
             resume_current_operation(app);
             return true;
@@ -1940,6 +2825,8 @@ impl ShortcutAction for ContextChatAction {
 
         let tm = app.state::<Arc<TranscriptionManager>>();
         tm.initiate_model_load();
+        // Category languages only apply to dictation
+        tm.set_language_override(None);
 
         let binding_id = binding_id.to_string();
         change_tray_icon(app, TrayIconState::Recording);
@@ -1967,7 +2854,11 @@ impl ShortcutAction for ContextChatAction {
             rm_clone.apply_mute();
         });
 
-        rm.try_start_recording(&binding_id)
+        let recording_started = rm.try_start_recording(&binding_id);
+        if recording_started {
+            announce(app, Announcement::RecordingStarted);
+        }
+        recording_started
     }
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
@@ -1985,6 +2876,7 @@ impl ShortcutAction for ContextChatAction {
 
         rm.remove_mute();
         play_feedback_sound(app, SoundType::Stop);
+        announce(app, Announcement::RecordingStopped);
 
         let binding_id = binding_id.to_string();
         let samples = rm.stop_recording(&binding_id);
@@ -2022,7 +2914,10 @@ impl ShortcutAction for ContextChatAction {
                         error!("Context chat transcription error: {}", err);
                         utils::show_error_overlay(
                             &ah,
-                            &format!("Transcription error: {}", err),
+                            &i18n::format_message(
+                                &i18n::overlay_strings(&ah).transcription_error,
+                                &[("error", &err.to_string())],
+                            ),
                             false,
                         );
                         change_tray_icon(&ah, TrayIconState::Idle);
@@ -2132,11 +3027,16 @@ async fn process_context_chat(app: &AppHandle, transcription: &str) -> Result<St
         .build()
         .map_err(|e| e.to_string())?;
 
-    let response = client
-        .chat()
-        .create(request)
-        .await
-        .map_err(|e| extract_llm_error(&e, &llm_config.model.model_id))?;
+    let chat = client.chat();
+    let response = with_retry(
+        format!("Context chat with '{}'", llm_config.model.model_id),
+        || chat.create(request.clone()),
+    )
+    .await
+    .map_err(|e| extract_llm_error(&e, &llm_config.model.model_id))?;
+    if let Some(usage) = response.usage.as_ref().map(TokenUsage::from) {
+        record_usage(app, &provider, &llm_config.model, "context_chat", &usage);
+    }
 
     let llm_response = response
         .choices
@@ -2159,6 +3059,18 @@ fn extract_print_text(transcription: &str) -> String {
     transcription.trim().to_string()
 }
 
+/// Extract comment text from a transcription like "comment retry on timeout"
+fn extract_comment_text(transcription: &str) -> String {
+    let lower = transcription.to_lowercase();
+    let triggers = ["docstring ", "doc comment ", "add a comment ", "comment "];
+    for trigger in triggers {
+        if let Some(pos) = lower.find(trigger) {
+            return transcription[pos + trigger.len()..].trim().to_string();
+        }
+    }
+    transcription.trim().to_string()
+}
+
 // Static Action Map
 pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -2166,6 +3078,10 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "transcribe".to_string(),
         Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "transcribe_system_audio".to_string(),
+        Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
+    );
     // Note: ramble_to_coherent is no longer a separate action.
     // Unified hotkey: hold transcribe key = raw, quick tap = coherent.
     map.insert(
@@ -2192,9 +3108,152 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "context_chat".to_string(),
         Arc::new(ContextChatAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "refine_clipboard".to_string(),
+        Arc::new(RefineClipboardAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "toggle_shortcuts".to_string(),
+        Arc::new(ToggleShortcutsAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "test".to_string(),
         Arc::new(TestAction) as Arc<dyn ShortcutAction>,
     );
     map
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{AudioFixture, MockLlmServer, MOCK_MODEL_ID};
+
+    /// A recorded dictation through cleanup, refinement by the mock provider and the
+    /// paste decision, the steps `TranscribeAction::stop` takes after transcribing
+    #[test]
+    fn test_dictation_pipeline_with_mock_llm() {
+        let fixture = AudioFixture::load("dictation").expect("Failed to load fixture");
+        assert!(!fixture.samples.is_empty());
+
+        let server = MockLlmServer::start("I'll send the report to Dana on Friday.");
+        let mut settings = server.settings();
+        settings.verify_refinement = true;
+
+        let filtered =
+            filter_filler_words(&fixture.transcript, settings.filler_word_filter.as_deref());
+        let filtered = collapse_repeated_words(&filtered, settings.collapse_repeated_words);
+        assert_eq!(filtered, "so I will send the report to Dana tomorrow");
+
+        let values = HashMap::from([("output", filtered.clone())]);
+        let (prompt, unknown_variables) = render_prompt("Clean this up: ${output}", &values);
+        assert!(unknown_variables.is_empty());
+
+        let mut streamed = String::new();
+        let (content, usage) = tauri::async_runtime::block_on(async {
+            let config = resolve_llm_config(&settings, MOCK_MODEL_ID).await?;
+            let client =
+                crate::llm_client::create_client(&config.provider, config.api_key.clone())?;
+            let request = refinement_request(
+                &config.model.model_id,
+                coherent_system_prompt(&settings),
+                &prompt,
+                &[],
+            )?;
+            stream_model_completion(
+                &client,
+                &config.provider,
+                &config.api_key,
+                &config.model,
+                request,
+                |delta| streamed.push_str(delta),
+            )
+            .await
+            .map_err(|e| e.to_string())
+        })
+        .expect("Refinement request failed");
+        assert_eq!(streamed, content);
+        assert!(usage.is_some_and(|u| u.completion_tokens > 0));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["model"], MOCK_MODEL_ID);
+        assert_eq!(
+            requests[0]["messages"][0]["content"],
+            COHERENT_SYSTEM_PROMPT
+        );
+        assert_eq!(
+            requests[0]["messages"][1]["content"],
+            format!("Clean this up: {}", filtered)
+        );
+
+        let text = answer_text(&content, false)
+            .map_err(|failure| failure.message)
+            .unwrap();
+        let refinement = refined_output(&settings, text, Some(&prompt));
+        assert_eq!(refinement.text, "I'll send the report to Dana on Friday.");
+        assert_eq!(refinement.invented_terms, vec!["Friday"]);
+
+        assert!(!utils::exceeds_paste_limit(
+            &refinement.text,
+            settings.max_paste_chars
+        ));
+        assert!(utils::exceeds_paste_limit(&refinement.text, 10));
+    }
+
+    /// Wait for the dictation being processed in the background to reach the paste
+    /// step, returning everything pasted so far
+    #[cfg(any(target_os = "linux", windows))]
+    fn wait_for_pastes(count: usize) -> Vec<String> {
+        let deadline = Instant::now() + std::time::Duration::from_secs(10);
+        loop {
+            let pasted = crate::test_support::pasted();
+            if pasted.len() >= count || Instant::now() > deadline {
+                return pasted;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+    }
+
+    /// The fixture dictated through `TranscribeAction`, from the shortcut's press to
+    /// the text handed to the paste step, once tapped and once held
+    #[test]
+    #[cfg(any(target_os = "linux", windows))]
+    #[cfg_attr(
+        target_os = "linux",
+        ignore = "builds the app, which needs a display (run under xvfb-run)"
+    )]
+    fn test_transcribe_action_end_to_end() {
+        let fixture = AudioFixture::load("dictation").expect("Failed to load fixture");
+        let server = MockLlmServer::start("I'll send the report to Dana on Friday.");
+        let app = crate::test_support::TestApp::new(server.settings());
+        let handle = app.handle();
+        crate::test_support::capture_pastes();
+
+        // A tap refines the dictation
+        fixture.queue();
+        assert!(TranscribeAction.start(handle, "transcribe", ""));
+        crate::shortcut::enter_refining_mode(handle, false);
+        TranscribeAction.stop(handle, "transcribe", "");
+        assert_eq!(
+            wait_for_pastes(1),
+            vec!["I'll send the report to Dana on Friday."]
+        );
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]["messages"][1]["content"]
+            .as_str()
+            .is_some_and(|prompt| prompt.contains("so I will send the report to Dana tomorrow")));
+
+        // A hold pastes what was said, without filler words and without a request
+        fixture.queue();
+        assert!(TranscribeAction.start(handle, "transcribe", ""));
+        TranscribeAction.stop(handle, "transcribe", "");
+        assert_eq!(
+            wait_for_pastes(2)[1..],
+            ["so I will send the report to Dana tomorrow"]
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        crate::test_support::take_pasted();
+    }
+}
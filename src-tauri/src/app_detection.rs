@@ -1,7 +1,8 @@
-//! macOS frontmost application detection via Swift bridge.
+//! Application detection.
 //!
 //! This module provides functionality to detect the currently focused application
-//! on macOS, used for application-aware prompt selection.
+//! on macOS (via Swift bridge), used for application-aware prompt selection, and to
+//! list installed applications on macOS, Windows and Linux for the app-mapping UI.
 
 use log::debug;
 use serde::{Deserialize, Serialize};
@@ -102,7 +103,386 @@ pub fn get_frontmost_application() -> Option<AppInfo> {
     None
 }
 
-#[cfg(not(target_os = "macos"))]
+/// Names the focused application goes by, for matching against user-configured app
+/// lists: its bundle identifier and display name on macOS, and the app name of the
+/// focused window on every platform
+pub fn get_focused_app_identifiers() -> Vec<String> {
+    let mut identifiers = Vec::new();
+    if let Some(info) = get_frontmost_application() {
+        identifiers.push(info.bundle_identifier);
+        identifiers.push(info.display_name);
+    }
+    if let Ok(windows) = xcap::Window::all() {
+        let own_pid = std::process::id();
+        if let Some(name) = windows
+            .into_iter()
+            .filter(|w| w.pid().is_ok_and(|pid| pid != own_pid))
+            .find(|w| w.is_focused().unwrap_or(false))
+            .and_then(|w| w.app_name().ok())
+        {
+            identifiers.push(name);
+        }
+    }
+    identifiers.retain(|id| !id.is_empty());
+    identifiers
+}
+
+/// Title of the focused window of another application, if the platform reports one.
+/// Ramble's own windows (chat, overlay) are skipped.
+pub fn get_focused_window_title() -> Option<String> {
+    let windows = match xcap::Window::all() {
+        Ok(windows) => windows,
+        Err(e) => {
+            debug!("Failed to list windows: {}", e);
+            return None;
+        }
+    };
+    let own_pid = std::process::id();
+    windows
+        .into_iter()
+        .filter(|w| w.pid().is_ok_and(|pid| pid != own_pid))
+        .find(|w| w.is_focused().unwrap_or(false))
+        .and_then(|w| w.title().ok())
+        .filter(|title| !title.is_empty())
+}
+
+/// Get a list of installed applications by scanning `.desktop` entries in the XDG data dirs.
+///
+/// The desktop file ID (file name without the `.desktop` suffix) is used as the bundle id.
+#[cfg(target_os = "linux")]
+pub fn get_installed_applications() -> Vec<InstalledApp> {
+    let mut apps: Vec<InstalledApp> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for dir in linux_application_dirs() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("desktop") {
+                continue;
+            }
+
+            let desktop_id = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(stem) => stem.to_string(),
+                None => continue,
+            };
+
+            // Earlier directories take precedence (user entries shadow system ones)
+            if !seen.insert(desktop_id.clone()) {
+                continue;
+            }
+
+            let contents = match std::fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    debug!("Failed to read desktop entry {:?}: {}", path, e);
+                    continue;
+                }
+            };
+
+            if let Some(name) = parse_desktop_entry(&contents) {
+                apps.push(InstalledApp {
+                    bundle_id: desktop_id,
+                    name,
+                });
+            }
+        }
+    }
+
+    apps.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    debug!("Found {} installed applications", apps.len());
+    apps
+}
+
+/// Directories that may contain `.desktop` application entries, in precedence order.
+#[cfg(target_os = "linux")]
+fn linux_application_dirs() -> Vec<std::path::PathBuf> {
+    use std::path::PathBuf;
+
+    let mut dirs = Vec::new();
+
+    let data_home = std::env::var("XDG_DATA_HOME")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var("HOME")
+                .ok()
+                .map(|h| PathBuf::from(h).join(".local/share"))
+        });
+    if let Some(data_home) = data_home {
+        dirs.push(data_home.join("applications"));
+        dirs.push(data_home.join("flatpak/exports/share/applications"));
+    }
+
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+        dirs.push(PathBuf::from(dir).join("applications"));
+    }
+
+    dirs.push(PathBuf::from("/var/lib/flatpak/exports/share/applications"));
+    dirs.push(PathBuf::from("/var/lib/snapd/desktop/applications"));
+
+    // XDG_DATA_DIRS often already lists the flatpak/snap dirs
+    let mut seen = std::collections::HashSet::new();
+    dirs.retain(|d| seen.insert(d.clone()));
+    dirs
+}
+
+/// Parse a `.desktop` file and return the display name if it is a visible application.
+///
+/// Only the `[Desktop Entry]` group is considered. Entries marked `NoDisplay`/`Hidden`
+/// or whose `Type` is not `Application` are skipped.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_desktop_entry(contents: &str) -> Option<String> {
+    let mut in_main_group = false;
+    let mut name: Option<String> = None;
+    let mut is_application = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            in_main_group = line == "[Desktop Entry]";
+            continue;
+        }
+
+        if !in_main_group {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        match (key.trim(), value.trim()) {
+            // Localized keys (Name[de]=...) are ignored in favor of the untranslated name
+            ("Name", v) => name = Some(v.to_string()),
+            ("Type", v) => is_application = v == "Application",
+            ("NoDisplay", "true") | ("Hidden", "true") => return None,
+            _ => {}
+        }
+    }
+
+    if !is_application {
+        return None;
+    }
+
+    name.filter(|n| !n.is_empty())
+}
+
+/// Get a list of installed applications from the Start Menu and the registry uninstall keys.
+///
+/// Registry entries use the executable file name (e.g. `Code.exe`) as the bundle id when
+/// `DisplayIcon` points at one; Start Menu shortcuts fall back to the shortcut name.
+#[cfg(target_os = "windows")]
+pub fn get_installed_applications() -> Vec<InstalledApp> {
+    let mut apps: Vec<InstalledApp> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    let uninstall_keys = [
+        r"HKLM\Software\Microsoft\Windows\CurrentVersion\Uninstall",
+        r"HKLM\Software\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall",
+        r"HKCU\Software\Microsoft\Windows\CurrentVersion\Uninstall",
+    ];
+
+    for key in uninstall_keys {
+        match query_registry_tree(key) {
+            Ok(output) => {
+                for app in parse_uninstall_registry_output(&output) {
+                    if seen.insert(app.name.to_lowercase()) {
+                        apps.push(app);
+                    }
+                }
+            }
+            Err(e) => debug!("Failed to query registry key {}: {}", key, e),
+        }
+    }
+
+    for dir in windows_start_menu_dirs() {
+        for app in scan_start_menu_dir(&dir) {
+            if seen.insert(app.name.to_lowercase()) {
+                apps.push(app);
+            }
+        }
+    }
+
+    apps.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    debug!("Found {} installed applications", apps.len());
+    apps
+}
+
+/// Run `reg query <key> /s` and return its stdout.
+#[cfg(target_os = "windows")]
+fn query_registry_tree(key: &str) -> Result<String, String> {
+    use std::os::windows::process::CommandExt;
+
+    // CREATE_NO_WINDOW - don't flash a console window while scanning
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let output = std::process::Command::new("reg")
+        .args(["query", key, "/s"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| format!("Failed to run reg: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "windows")]
+fn windows_start_menu_dirs() -> Vec<std::path::PathBuf> {
+    use std::path::PathBuf;
+
+    ["ProgramData", "APPDATA"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .map(|base| PathBuf::from(base).join(r"Microsoft\Windows\Start Menu\Programs"))
+        .collect()
+}
+
+/// Recursively collect `.lnk` shortcuts from a Start Menu directory.
+#[cfg(target_os = "windows")]
+fn scan_start_menu_dir(dir: &std::path::Path) -> Vec<InstalledApp> {
+    let mut apps = Vec::new();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return apps,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            apps.extend(scan_start_menu_dir(&path));
+            continue;
+        }
+
+        let is_shortcut = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.eq_ignore_ascii_case("lnk"))
+            .unwrap_or(false);
+        if !is_shortcut {
+            continue;
+        }
+
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            if is_start_menu_noise(name) {
+                continue;
+            }
+            apps.push(InstalledApp {
+                bundle_id: name.to_string(),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    apps
+}
+
+/// Start Menu folders are full of uninstallers, readmes and help links - skip those.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn is_start_menu_noise(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    [
+        "uninstall",
+        "readme",
+        "read me",
+        "help",
+        "documentation",
+        "release notes",
+    ]
+    .iter()
+    .any(|noise| lower.contains(noise))
+}
+
+/// Parse the output of `reg query <Uninstall key> /s` into installed apps.
+///
+/// Each subkey block starts with a `HKEY_...` line followed by indented
+/// `Name    REG_TYPE    Value` lines. System components and entries without a
+/// `DisplayName` (updates, runtimes) are skipped.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_uninstall_registry_output(output: &str) -> Vec<InstalledApp> {
+    fn flush(
+        apps: &mut Vec<InstalledApp>,
+        name: &mut Option<String>,
+        icon: &mut Option<String>,
+        hidden: &mut bool,
+    ) {
+        if let Some(display_name) = name.take() {
+            if !*hidden {
+                let bundle_id = icon
+                    .as_deref()
+                    .and_then(executable_name_from_icon)
+                    .unwrap_or_else(|| display_name.clone());
+                apps.push(InstalledApp {
+                    bundle_id,
+                    name: display_name,
+                });
+            }
+        }
+        *icon = None;
+        *hidden = false;
+    }
+
+    let mut apps = Vec::new();
+    let mut name: Option<String> = None;
+    let mut icon: Option<String> = None;
+    let mut hidden = false;
+
+    for line in output.lines() {
+        if line.starts_with("HKEY_") {
+            flush(&mut apps, &mut name, &mut icon, &mut hidden);
+            continue;
+        }
+
+        let mut parts = line.trim().splitn(3, "    ");
+        let (Some(value_name), Some(_value_type), Some(value)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let value = value.trim();
+
+        match value_name.trim() {
+            "DisplayName" if !value.is_empty() => name = Some(value.to_string()),
+            "DisplayIcon" => icon = Some(value.to_string()),
+            "SystemComponent" => hidden = value == "0x1",
+            "ParentKeyName" => hidden = true,
+            _ => {}
+        }
+    }
+    flush(&mut apps, &mut name, &mut icon, &mut hidden);
+
+    apps
+}
+
+/// Extract `Code.exe` from a `DisplayIcon` value like `"C:\\...\\Code.exe",0`.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn executable_name_from_icon(icon: &str) -> Option<String> {
+    let path = icon.split(',').next()?.trim().trim_matches('"');
+    let file_name = path.rsplit(['\\', '/']).next()?;
+    if file_name.to_lowercase().ends_with(".exe") {
+        Some(file_name.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
 pub fn get_installed_applications() -> Vec<InstalledApp> {
     debug!("Installed apps detection not available on this platform");
     Vec::new()
@@ -121,4 +501,36 @@ mod tests {
         assert_eq!(info.bundle_identifier, "com.example.test");
         assert_eq!(info.display_name, "Test App");
     }
+
+    #[test]
+    fn test_parse_desktop_entry() {
+        let entry = "[Desktop Entry]\nType=Application\nName=Firefox\nName[de]=Firefox Webbrowser\nExec=firefox %u\n\n[Desktop Action new-window]\nName=New Window\n";
+        assert_eq!(parse_desktop_entry(entry), Some("Firefox".to_string()));
+    }
+
+    #[test]
+    fn test_parse_desktop_entry_skips_hidden_and_non_apps() {
+        let hidden = "[Desktop Entry]\nType=Application\nName=Helper\nNoDisplay=true\n";
+        assert_eq!(parse_desktop_entry(hidden), None);
+
+        let link = "[Desktop Entry]\nType=Link\nName=Docs\nURL=https://example.com\n";
+        assert_eq!(parse_desktop_entry(link), None);
+    }
+
+    #[test]
+    fn test_parse_uninstall_registry_output() {
+        let output = "\r\nHKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\{771FD6B0}_is1\r\n    DisplayIcon    REG_SZ    C:\\Program Files\\Microsoft VS Code\\Code.exe\r\n    DisplayName    REG_SZ    Microsoft Visual Studio Code\r\n\r\nHKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\Runtime\r\n    DisplayName    REG_SZ    Some Runtime\r\n    SystemComponent    REG_DWORD    0x1\r\n\r\nHKEY_LOCAL_MACHINE\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\Slack\r\n    DisplayName    REG_SZ    Slack\r\n    DisplayIcon    REG_SZ    \"C:\\Users\\me\\AppData\\Local\\slack\\app.ico\",0\r\n";
+        let apps = parse_uninstall_registry_output(output);
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].name, "Microsoft Visual Studio Code");
+        assert_eq!(apps[0].bundle_id, "Code.exe");
+        assert_eq!(apps[1].name, "Slack");
+        assert_eq!(apps[1].bundle_id, "Slack");
+    }
+
+    #[test]
+    fn test_is_start_menu_noise() {
+        assert!(is_start_menu_noise("Uninstall Zoom"));
+        assert!(!is_start_menu_noise("Zoom"));
+    }
 }
@@ -12,13 +12,24 @@ use tauri::{AppHandle, Manager};
 pub enum SoundType {
     Start,
     Stop,
+    /// Recording cancelled because no speech was heard
+    NoSpeech,
 }
 
+/// Not part of the sound themes, so it stays recognisable whichever theme is used
+const NO_SPEECH_SOUND: &str = "resources/no_speech.wav";
+
 fn resolve_sound_path(
     app: &AppHandle,
     settings: &AppSettings,
     sound_type: SoundType,
 ) -> Option<PathBuf> {
+    if let SoundType::NoSpeech = sound_type {
+        return app
+            .path()
+            .resolve(NO_SPEECH_SOUND, tauri::path::BaseDirectory::Resource)
+            .ok();
+    }
     let sound_file = get_sound_path(settings, sound_type);
     let base_dir = get_sound_base_dir(settings);
     app.path().resolve(&sound_file, base_dir).ok()
@@ -30,6 +41,7 @@ fn get_sound_path(settings: &AppSettings, sound_type: SoundType) -> String {
         (SoundTheme::Custom, SoundType::Stop) => "custom_stop.wav".to_string(),
         (_, SoundType::Start) => settings.sound_theme.to_start_path(),
         (_, SoundType::Stop) => settings.sound_theme.to_stop_path(),
+        (_, SoundType::NoSpeech) => NO_SPEECH_SOUND.to_string(),
     }
 }
 
@@ -42,7 +54,7 @@ fn get_sound_base_dir(settings: &AppSettings) -> tauri::path::BaseDirectory {
 
 pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
-    if !settings.audio_feedback {
+    if !settings.audio_feedback || settings.focus_mode {
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
@@ -52,7 +64,7 @@ pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
 
 pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
-    if !settings.audio_feedback {
+    if !settings.audio_feedback || settings.focus_mode {
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
@@ -0,0 +1,62 @@
+//! Decoding of audio files (wav, mp3, m4a, flac, ogg) into 16 kHz mono samples,
+//! so existing recordings can go through the same transcription pipeline.
+
+use std::{fs::File, io::BufReader, path::Path, time::Duration};
+
+use rodio::{Decoder, Source};
+
+use crate::audio_toolkit::{audio::FrameResampler, constants};
+
+/// Average interleaved samples down to one channel
+fn downmix(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+/// Decode an audio file into 16 kHz mono samples
+pub fn decode_audio_file(path: &Path) -> Result<Vec<f32>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let decoder = Decoder::new(BufReader::new(file))
+        .map_err(|e| format!("Unsupported audio file {}: {}", path.display(), e))?;
+
+    let channels = decoder.channels() as usize;
+    let sample_rate = decoder.sample_rate() as usize;
+    let interleaved: Vec<f32> = decoder.collect();
+    let mono = downmix(&interleaved, channels);
+
+    let mut samples = Vec::with_capacity(
+        mono.len() * constants::WHISPER_SAMPLE_RATE as usize / sample_rate.max(1),
+    );
+    let mut resampler = FrameResampler::new(
+        sample_rate,
+        constants::WHISPER_SAMPLE_RATE as usize,
+        Duration::from_millis(30),
+    );
+    resampler.push(&mono, |frame: &[f32]| samples.extend_from_slice(frame));
+    resampler.finish(|frame: &[f32]| samples.extend_from_slice(frame));
+
+    log::debug!(
+        "Decoded {} ({} Hz, {} channels) into {} samples",
+        path.display(),
+        sample_rate,
+        channels,
+        samples.len()
+    );
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downmix() {
+        assert_eq!(downmix(&[0.5, -0.5], 1), vec![0.5, -0.5]);
+        assert_eq!(downmix(&[1.0, 0.0, 0.5, 0.5], 2), vec![0.5, 0.5]);
+    }
+}
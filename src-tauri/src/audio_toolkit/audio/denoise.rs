@@ -0,0 +1,89 @@
+//! RNNoise-based noise suppression for 16 kHz recording frames.
+//!
+//! RNNoise works on 10ms frames at 48 kHz in the i16 sample range, so frames are
+//! upsampled, denoised, and downsampled back to 16 kHz. This adds roughly 100ms of
+//! latency, which is flushed by `finish` when recording stops. A fresh suppressor is
+//! created for each recording.
+
+use std::time::Duration;
+
+use nnnoiseless::DenoiseState;
+
+use crate::audio_toolkit::{audio::FrameResampler, constants};
+
+const RNNOISE_SAMPLE_RATE: usize = 48000;
+
+pub struct NoiseSuppressor {
+    state: Box<DenoiseState<'static>>,
+    upsampler: FrameResampler,
+    downsampler: FrameResampler,
+    scratch_in: Vec<f32>,
+    scratch_out: Vec<f32>,
+}
+
+impl NoiseSuppressor {
+    /// `frame_dur` is the length of the 16 kHz frames to emit
+    pub fn new(frame_dur: Duration) -> Self {
+        Self {
+            state: DenoiseState::new(),
+            upsampler: FrameResampler::new(
+                constants::WHISPER_SAMPLE_RATE as usize,
+                RNNOISE_SAMPLE_RATE,
+                Duration::from_millis(10),
+            ),
+            downsampler: FrameResampler::new(
+                RNNOISE_SAMPLE_RATE,
+                constants::WHISPER_SAMPLE_RATE as usize,
+                frame_dur,
+            ),
+            scratch_in: vec![0.0; DenoiseState::FRAME_SIZE],
+            scratch_out: vec![0.0; DenoiseState::FRAME_SIZE],
+        }
+    }
+
+    fn denoise(
+        state: &mut DenoiseState<'static>,
+        scratch_in: &mut [f32],
+        scratch_out: &mut [f32],
+        frame: &[f32],
+    ) {
+        for (dst, &src) in scratch_in.iter_mut().zip(frame) {
+            *dst = src * i16::MAX as f32;
+        }
+        state.process_frame(scratch_out, scratch_in);
+        for s in scratch_out.iter_mut() {
+            *s /= i16::MAX as f32;
+        }
+    }
+
+    /// Denoise a 16 kHz frame, emitting cleaned 16 kHz frames as they become available
+    pub fn push(&mut self, frame: &[f32], mut emit: impl FnMut(&[f32])) {
+        let Self {
+            state,
+            upsampler,
+            downsampler,
+            scratch_in,
+            scratch_out,
+        } = self;
+        upsampler.push(frame, |frame_48k: &[f32]| {
+            Self::denoise(state, scratch_in, scratch_out, frame_48k);
+            downsampler.push(scratch_out, &mut emit);
+        });
+    }
+
+    /// Flush audio still buffered in the resamplers
+    pub fn finish(&mut self, mut emit: impl FnMut(&[f32])) {
+        let Self {
+            state,
+            upsampler,
+            downsampler,
+            scratch_in,
+            scratch_out,
+        } = self;
+        upsampler.finish(|frame_48k: &[f32]| {
+            Self::denoise(state, scratch_in, scratch_out, frame_48k);
+            downsampler.push(scratch_out, &mut emit);
+        });
+        downsampler.finish(&mut emit);
+    }
+}
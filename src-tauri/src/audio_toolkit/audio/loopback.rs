@@ -0,0 +1,232 @@
+//! Capture of the system's audio output ("loopback") so calls and videos can be
+//! transcribed alongside the microphone.
+//!
+//! - Windows: WASAPI loopback on the default output device.
+//! - Linux: the first input device that exposes a PulseAudio/PipeWire monitor source.
+//! - macOS: CoreAudio has no loopback input, so a virtual loopback device (BlackHole,
+//!   Loopback, Soundflower) must be installed and is picked up by name.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+use crate::audio_toolkit::{
+    audio::{AudioRecorder, FrameResampler},
+    constants,
+};
+
+/// 16 kHz mono samples captured from the system output, waiting to be mixed into the mic
+pub type SystemAudioBuffer = Arc<Mutex<VecDeque<f32>>>;
+
+/// Samples kept before the oldest are dropped, so the mic and output clocks can't drift
+/// more than this far apart (1s at 16 kHz)
+const MAX_BUFFERED_SAMPLES: usize = constants::WHISPER_SAMPLE_RATE as usize;
+
+#[cfg(target_os = "macos")]
+const LOOPBACK_DEVICE_NAMES: &[&str] = &["blackhole", "loopback", "soundflower"];
+#[cfg(not(target_os = "macos"))]
+const LOOPBACK_DEVICE_NAMES: &[&str] = &["monitor", "loopback"];
+
+/// Find the device to capture system output from, and whether it is an output device
+/// opened in loopback mode
+fn find_loopback_device() -> Result<(cpal::Device, bool), String> {
+    let host = crate::audio_toolkit::get_cpal_host();
+
+    if cfg!(target_os = "windows") {
+        return host
+            .default_output_device()
+            .map(|device| (device, true))
+            .ok_or_else(|| "No output device to capture system audio from".to_string());
+    }
+
+    let devices = host
+        .input_devices()
+        .map_err(|e| format!("Failed to list input devices: {}", e))?;
+    for device in devices {
+        let name = device.name().unwrap_or_default().to_lowercase();
+        if LOOPBACK_DEVICE_NAMES.iter().any(|n| name.contains(n)) {
+            return Ok((device, false));
+        }
+    }
+
+    if cfg!(target_os = "macos") {
+        Err("No loopback device found. Install BlackHole (or a similar virtual audio device) and route system output to it to capture system audio.".to_string())
+    } else {
+        Err("No monitor source found to capture system audio from".to_string())
+    }
+}
+
+/// Mix any buffered system audio into a 16 kHz mic frame
+pub fn mix_system_audio<'a>(
+    frame: &'a [f32],
+    source: Option<&SystemAudioBuffer>,
+    mix_buf: &'a mut Vec<f32>,
+) -> &'a [f32] {
+    let Some(source) = source else {
+        return frame;
+    };
+    let mut buffered = source.lock().unwrap();
+    if buffered.is_empty() {
+        return frame;
+    }
+
+    mix_buf.clear();
+    mix_buf.extend(frame.iter().map(|&mic| {
+        let system = buffered.pop_front().unwrap_or(0.0);
+        (mic + system).clamp(-1.0, 1.0)
+    }));
+    mix_buf
+}
+
+/// A running capture of the system output. Capture stops when this is dropped.
+pub struct SystemAudioCapture {
+    buffer: SystemAudioBuffer,
+    stop: Arc<AtomicBool>,
+    worker_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl SystemAudioCapture {
+    pub fn start() -> Result<Self, String> {
+        let (device, is_output) = find_loopback_device()?;
+        let buffer: SystemAudioBuffer = Arc::new(Mutex::new(VecDeque::new()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+
+        let thread_buffer = buffer.clone();
+        let thread_stop = stop.clone();
+        let worker = std::thread::spawn(move || {
+            let config = if is_output {
+                device.default_output_config()
+            } else {
+                device.default_input_config()
+            };
+            let config = match config {
+                Ok(config) => config,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(format!("Failed to get loopback config: {}", e)));
+                    return;
+                }
+            };
+
+            let channels = config.channels() as usize;
+            let (sample_tx, sample_rx) = mpsc::channel::<Vec<f32>>();
+            let stream = match config.sample_format() {
+                cpal::SampleFormat::U8 => {
+                    AudioRecorder::build_stream::<u8>(&device, &config, sample_tx, channels)
+                }
+                cpal::SampleFormat::I8 => {
+                    AudioRecorder::build_stream::<i8>(&device, &config, sample_tx, channels)
+                }
+                cpal::SampleFormat::I16 => {
+                    AudioRecorder::build_stream::<i16>(&device, &config, sample_tx, channels)
+                }
+                cpal::SampleFormat::I32 => {
+                    AudioRecorder::build_stream::<i32>(&device, &config, sample_tx, channels)
+                }
+                cpal::SampleFormat::F32 => {
+                    AudioRecorder::build_stream::<f32>(&device, &config, sample_tx, channels)
+                }
+                other => {
+                    let _ = ready_tx.send(Err(format!(
+                        "Unsupported loopback sample format: {:?}",
+                        other
+                    )));
+                    return;
+                }
+            };
+            let stream = match stream.map_err(|e| e.to_string()).and_then(|s| {
+                s.play().map_err(|e| e.to_string())?;
+                Ok(s)
+            }) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(format!("Failed to start loopback capture: {}", e)));
+                    return;
+                }
+            };
+
+            log::info!(
+                "Capturing system audio from {:?} ({} Hz, {} channels)",
+                device.name(),
+                config.sample_rate().0,
+                channels
+            );
+            let _ = ready_tx.send(Ok(()));
+
+            let mut resampler = FrameResampler::new(
+                config.sample_rate().0 as usize,
+                constants::WHISPER_SAMPLE_RATE as usize,
+                Duration::from_millis(30),
+            );
+            while !thread_stop.load(Ordering::Relaxed) {
+                let raw = match sample_rx.recv_timeout(Duration::from_millis(100)) {
+                    Ok(raw) => raw,
+                    Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                };
+                resampler.push(&raw, &mut |frame: &[f32]| {
+                    let mut buffered = thread_buffer.lock().unwrap();
+                    buffered.extend(frame);
+                    let excess = buffered.len().saturating_sub(MAX_BUFFERED_SAMPLES);
+                    buffered.drain(..excess);
+                });
+            }
+
+            drop(stream);
+            log::debug!("System audio capture stopped");
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => Ok(Self {
+                buffer,
+                stop,
+                worker_handle: Some(worker),
+            }),
+            Ok(Err(e)) => {
+                let _ = worker.join();
+                Err(e)
+            }
+            Err(_) => Err("System audio capture thread exited unexpectedly".to_string()),
+        }
+    }
+
+    /// Buffer the capture writes into, for the recorder to mix from
+    pub fn buffer(&self) -> SystemAudioBuffer {
+        self.buffer.clone()
+    }
+}
+
+impl Drop for SystemAudioCapture {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.worker_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix_system_audio() {
+        let mic = [0.5, -0.5, 0.25];
+        let mut mix_buf = Vec::new();
+
+        assert_eq!(mix_system_audio(&mic, None, &mut mix_buf), &mic);
+
+        let source: SystemAudioBuffer = Arc::new(Mutex::new(VecDeque::from(vec![0.75, 0.25])));
+        let mixed = mix_system_audio(&mic, Some(&source), &mut mix_buf);
+        // Clamped, mixed, and zero-padded when the system buffer runs short
+        assert_eq!(mixed, &[1.0, -0.25, 0.25]);
+        assert!(source.lock().unwrap().is_empty());
+    }
+}
@@ -1,11 +1,19 @@
 // Re-export all audio components
+mod decode;
+mod denoise;
 mod device;
+mod loopback;
+mod pauses;
 mod recorder;
 mod resampler;
 mod utils;
 mod visualizer;
 
+pub use decode::decode_audio_file;
+pub use denoise::NoiseSuppressor;
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
+pub use loopback::{SystemAudioBuffer, SystemAudioCapture};
+pub use pauses::split_at_pauses;
 pub use recorder::{AudioRecorder, SpeechSegment, StopResult};
 pub use resampler::FrameResampler;
 pub use utils::save_wav_file;
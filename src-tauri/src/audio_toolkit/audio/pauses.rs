@@ -0,0 +1,92 @@
+//! Splitting of a finished recording at pauses in speech, so each phrase can be
+//! decoded on its own when speakers switch language between phrases.
+
+use std::ops::Range;
+
+use crate::audio_toolkit::constants;
+
+/// 30 ms analysis frames, the same size the recorder feeds the VAD
+const FRAME_SAMPLES: usize = constants::WHISPER_SAMPLE_RATE as usize * 30 / 1000;
+
+/// Frames quieter than this RMS level count as silence
+const SILENCE_RMS: f32 = 0.01;
+
+/// A pause has to last this many silent frames (~300 ms) to split the audio
+const MIN_PAUSE_FRAMES: usize = 10;
+
+/// Pieces shorter than this (~1 s) are merged into the previous one, since engines
+/// guess languages poorly on a single word
+const MIN_PIECE_SAMPLES: usize = constants::WHISPER_SAMPLE_RATE as usize;
+
+fn rms(frame: &[f32]) -> f32 {
+    (frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32).sqrt()
+}
+
+/// Sample ranges of `samples` between pauses. Cuts are made in the middle of each
+/// pause, so the ranges cover the whole input without gaps.
+pub fn split_at_pauses(samples: &[f32]) -> Vec<Range<usize>> {
+    let mut cuts = Vec::new();
+    let mut silent_run = 0;
+    for (index, frame) in samples.chunks(FRAME_SAMPLES).enumerate() {
+        if rms(frame) < SILENCE_RMS {
+            silent_run += 1;
+            continue;
+        }
+        if silent_run >= MIN_PAUSE_FRAMES && index > silent_run {
+            cuts.push((index - silent_run / 2) * FRAME_SAMPLES);
+        }
+        silent_run = 0;
+    }
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+    for cut in cuts.into_iter().chain(std::iter::once(samples.len())) {
+        if cut <= start {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if cut - start < MIN_PIECE_SAMPLES || last.len() < MIN_PIECE_SAMPLES => {
+                last.end = cut
+            }
+            _ => ranges.push(start..cut),
+        }
+        start = cut;
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(seconds: f32) -> Vec<f32> {
+        vec![0.2; (seconds * constants::WHISPER_SAMPLE_RATE as f32) as usize]
+    }
+
+    fn silence(seconds: f32) -> Vec<f32> {
+        vec![0.0; (seconds * constants::WHISPER_SAMPLE_RATE as f32) as usize]
+    }
+
+    #[test]
+    fn test_split_at_pauses() {
+        let samples = [tone(2.0), silence(0.6), tone(2.0)].concat();
+        let ranges = split_at_pauses(&samples);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].start, 0);
+        assert_eq!(ranges[0].end, ranges[1].start);
+        assert_eq!(ranges[1].end, samples.len());
+    }
+
+    #[test]
+    fn test_short_pauses_and_pieces_are_kept_together() {
+        // A breath between words is not a pause
+        let samples = [tone(2.0), silence(0.1), tone(2.0)].concat();
+        assert_eq!(split_at_pauses(&samples), vec![0..samples.len()]);
+
+        // A lone short word is decoded with its neighbour
+        let samples = [tone(2.0), silence(0.6), tone(0.3)].concat();
+        assert_eq!(split_at_pauses(&samples), vec![0..samples.len()]);
+
+        assert!(split_at_pauses(&[]).is_empty());
+    }
+}
@@ -1,6 +1,10 @@
 use std::{
+    collections::VecDeque,
     io::Error,
-    sync::{mpsc, Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
     time::Duration,
 };
 
@@ -10,16 +14,24 @@ use cpal::{
 };
 
 use crate::audio_toolkit::{
-    audio::{AudioVisualiser, FrameResampler},
+    audio::{loopback, AudioVisualiser, FrameResampler, NoiseSuppressor, SystemAudioBuffer},
     constants,
     vad::{self, VadFrame},
     VoiceActivityDetector,
 };
 
+/// Length of the 16 kHz frames fed to the VAD
+const FRAME_DURATION: Duration = Duration::from_millis(30);
+
 #[derive(Clone, Debug)]
 pub struct SpeechSegment {
     pub index: u64,
     pub samples: Vec<f32>,
+    /// Position of the segment's first sample in the recording's samples
+    pub offset: usize,
+    /// Snapshot of a segment that is still being spoken; a later segment with the
+    /// same index supersedes it
+    pub is_partial: bool,
 }
 
 pub struct StopResult {
@@ -39,6 +51,20 @@ pub struct AudioRecorder {
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     segment_tx: Arc<Mutex<Option<mpsc::Sender<SpeechSegment>>>>,
+    /// Set once the VAD hears speech after `start`
+    speech_detected: Arc<AtomicBool>,
+    /// Frames the VAD has classified as silence since the last speech
+    trailing_silence_frames: Arc<AtomicUsize>,
+    /// System output to mix into the mic while recording
+    system_audio: Arc<Mutex<Option<SystemAudioBuffer>>>,
+    /// Run the mic through noise suppression, read at each `start`
+    noise_suppression: Arc<AtomicBool>,
+    /// Prepend the audio heard just before `start`, read at each `start`
+    pre_roll: Arc<AtomicBool>,
+    /// Share snapshots of the segment still being spoken
+    partials: Arc<AtomicBool>,
+    /// Receives the 16 kHz frames heard while not recording, e.g. for keyword spotting
+    idle_frame_tx: Arc<Mutex<Option<mpsc::Sender<Vec<f32>>>>>,
 }
 
 impl AudioRecorder {
@@ -50,6 +76,13 @@ impl AudioRecorder {
             vad: None,
             level_cb: None,
             segment_tx: Arc::new(Mutex::new(None)),
+            speech_detected: Arc::new(AtomicBool::new(false)),
+            trailing_silence_frames: Arc::new(AtomicUsize::new(0)),
+            system_audio: Arc::new(Mutex::new(None)),
+            noise_suppression: Arc::new(AtomicBool::new(false)),
+            pre_roll: Arc::new(AtomicBool::new(false)),
+            partials: Arc::new(AtomicBool::new(false)),
+            idle_frame_tx: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -66,10 +99,51 @@ impl AudioRecorder {
         self
     }
 
+    /// Whether any speech was detected since recording last started. Always true
+    /// without a VAD, since every frame is then treated as speech.
+    pub fn speech_detected(&self) -> bool {
+        self.speech_detected.load(Ordering::Relaxed)
+    }
+
+    /// How long the VAD has heard only silence since the last speech (or since
+    /// `start`). Always zero without a VAD.
+    pub fn trailing_silence(&self) -> Duration {
+        FRAME_DURATION * self.trailing_silence_frames.load(Ordering::Relaxed) as u32
+    }
+
     pub fn set_segment_sender(&self, tx: Option<mpsc::Sender<SpeechSegment>>) {
         *self.segment_tx.lock().unwrap() = tx;
     }
 
+    /// Enable noise suppression for recordings started after this call
+    pub fn set_noise_suppression(&self, enabled: bool) {
+        self.noise_suppression.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Start recordings with the ~1.5s of audio heard before `start`, so the first
+    /// syllable isn't lost while the hotkey is pressed. Only useful while the stream
+    /// stays open between recordings.
+    pub fn set_pre_roll(&self, enabled: bool) {
+        self.pre_roll.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Send partial snapshots of the in-flight segment to the segment sender, for
+    /// live transcription. Off by default, since each one gets decoded.
+    pub fn set_partials(&self, enabled: bool) {
+        self.partials.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Share the frames heard between recordings, or stop sharing with `None`. Only
+    /// an open stream hears anything.
+    pub fn set_idle_frame_sender(&self, tx: Option<mpsc::Sender<Vec<f32>>>) {
+        *self.idle_frame_tx.lock().unwrap() = tx;
+    }
+
+    /// Mix captured system audio into the recording, or stop mixing with `None`
+    pub fn set_system_audio_source(&self, source: Option<SystemAudioBuffer>) {
+        *self.system_audio.lock().unwrap() = source;
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         if self.worker_handle.is_some() {
             return Ok(()); // already open
@@ -91,6 +165,13 @@ impl AudioRecorder {
         // Move the optional level callback into the worker thread
         let level_cb = self.level_cb.clone();
         let segment_tx = self.segment_tx.clone();
+        let speech_detected = self.speech_detected.clone();
+        let trailing_silence_frames = self.trailing_silence_frames.clone();
+        let system_audio = self.system_audio.clone();
+        let noise_suppression = self.noise_suppression.clone();
+        let pre_roll = self.pre_roll.clone();
+        let partials = self.partials.clone();
+        let idle_frame_tx = self.idle_frame_tx.clone();
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -134,7 +215,21 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb, segment_tx);
+            run_consumer(
+                sample_rate,
+                vad,
+                sample_rx,
+                cmd_rx,
+                level_cb,
+                segment_tx,
+                speech_detected,
+                trailing_silence_frames,
+                system_audio,
+                noise_suppression,
+                pre_roll,
+                partials,
+                idle_frame_tx,
+            );
             // stream is dropped here, after run_consumer returns
         });
 
@@ -171,7 +266,7 @@ impl AudioRecorder {
         Ok(())
     }
 
-    fn build_stream<T>(
+    pub(super) fn build_stream<T>(
         device: &cpal::Device,
         config: &cpal::SupportedStreamConfig,
         sample_tx: mpsc::Sender<Vec<f32>>,
@@ -263,24 +358,39 @@ fn run_consumer(
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     segment_tx: Arc<Mutex<Option<mpsc::Sender<SpeechSegment>>>>,
+    speech_detected: Arc<AtomicBool>,
+    trailing_silence_frames: Arc<AtomicUsize>,
+    system_audio: Arc<Mutex<Option<SystemAudioBuffer>>>,
+    noise_suppression: Arc<AtomicBool>,
+    pre_roll: Arc<AtomicBool>,
+    partials: Arc<AtomicBool>,
+    idle_frame_tx: Arc<Mutex<Option<mpsc::Sender<Vec<f32>>>>>,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
         constants::WHISPER_SAMPLE_RATE as usize,
-        Duration::from_millis(30),
+        FRAME_DURATION,
     );
 
     let mut processed_samples = Vec::<f32>::new();
     let mut recording = false;
+    let mut mix_buf = Vec::<f32>::new();
+    let mut denoiser: Option<NoiseSuppressor> = None;
+    // Most recent frames heard while not recording, replayed when recording starts
+    let mut pre_roll_frames: VecDeque<Vec<f32>> = VecDeque::with_capacity(PRE_ROLL_FRAMES);
 
     let mut raw_full: Vec<f32> = Vec::new();
     let mut current_segment: Vec<f32> = Vec::new();
     let mut in_segment = false;
     let mut segment_index: u64 = 0;
     let mut silence_run_frames: usize = 0;
+    let mut frames_since_partial: usize = 0;
 
     const END_SILENCE_FRAMES: usize = 10; // ~300ms at 30ms/frame
     const MIN_SEGMENT_SAMPLES: usize = 16000; // ~1 second minimum
+    const PARTIAL_INTERVAL_FRAMES: usize = 50; // ~1.5s at 30ms/frame
+    const PARTIAL_WINDOW_SAMPLES: usize = 16000 * 8; // ~8s, so every partial costs the same to decode
+    const PRE_ROLL_FRAMES: usize = 50; // ~1.5s at 30ms/frame
 
     // ---------- spectrum visualisation setup ---------------------------- //
     const BUCKETS: usize = 16;
@@ -303,23 +413,55 @@ fn run_consumer(
         in_segment: &mut bool,
         segment_index: &mut u64,
         silence_run_frames: &mut usize,
+        frames_since_partial: &mut usize,
+        partials: &AtomicBool,
         segment_tx: &Arc<Mutex<Option<mpsc::Sender<SpeechSegment>>>>,
+        speech_detected: &AtomicBool,
+        trailing_silence_frames: &AtomicUsize,
     ) {
         if !recording {
             return;
         }
 
+        // Periodically share the tail of the in-flight segment so it can be decoded
+        // while still speaking
+        let mut send_partial = |current_segment: &Vec<f32>, segment_index: u64, offset: usize| {
+            if !partials.load(Ordering::Relaxed) {
+                return;
+            }
+            *frames_since_partial += 1;
+            if *frames_since_partial >= PARTIAL_INTERVAL_FRAMES
+                && current_segment.len() >= MIN_SEGMENT_SAMPLES
+            {
+                *frames_since_partial = 0;
+                if let Some(tx) = segment_tx.lock().unwrap().as_ref() {
+                    let start = current_segment.len().saturating_sub(PARTIAL_WINDOW_SAMPLES);
+                    let _ = tx.send(SpeechSegment {
+                        index: segment_index,
+                        samples: current_segment[start..].to_vec(),
+                        offset: offset + start,
+                        is_partial: true,
+                    });
+                }
+            }
+        };
+
         if let Some(vad_arc) = vad {
             let mut det = vad_arc.lock().unwrap();
             match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
                 VadFrame::Speech(buf) => {
+                    speech_detected.store(true, Ordering::Relaxed);
+                    trailing_silence_frames.store(0, Ordering::Relaxed);
                     out_buf.extend_from_slice(buf);
                     raw_full.extend_from_slice(buf);
                     current_segment.extend_from_slice(buf);
                     *in_segment = true;
                     *silence_run_frames = 0;
+                    let offset = raw_full.len() - current_segment.len();
+                    send_partial(current_segment, *segment_index, offset);
                 }
                 VadFrame::Noise => {
+                    trailing_silence_frames.fetch_add(1, Ordering::Relaxed);
                     if *in_segment {
                         *silence_run_frames += 1;
                         if *silence_run_frames >= END_SILENCE_FRAMES {
@@ -327,7 +469,9 @@ fn run_consumer(
                                 if let Some(tx) = segment_tx.lock().unwrap().as_ref() {
                                     let segment = SpeechSegment {
                                         index: *segment_index,
+                                        offset: raw_full.len() - current_segment.len(),
                                         samples: std::mem::take(current_segment),
+                                        is_partial: false,
                                     };
                                     let _ = tx.send(segment);
                                 } else {
@@ -339,16 +483,20 @@ fn run_consumer(
                             }
                             *in_segment = false;
                             *silence_run_frames = 0;
+                            *frames_since_partial = 0;
                         }
                     }
                 }
             }
         } else {
+            speech_detected.store(true, Ordering::Relaxed);
             out_buf.extend_from_slice(samples);
             raw_full.extend_from_slice(samples);
             current_segment.extend_from_slice(samples);
             *in_segment = true;
             *silence_run_frames = 0;
+            let offset = raw_full.len() - current_segment.len();
+            send_partial(current_segment, *segment_index, offset);
         }
     }
 
@@ -366,7 +514,9 @@ fn run_consumer(
         }
 
         // ---------- existing pipeline ------------------------------------ //
-        frame_resampler.push(&raw, &mut |frame: &[f32]| {
+        let system_source = system_audio.lock().unwrap().clone();
+        let mut process = |frame: &[f32]| {
+            let frame = loopback::mix_system_audio(frame, system_source.as_ref(), &mut mix_buf);
             handle_frame(
                 frame,
                 recording,
@@ -377,8 +527,36 @@ fn run_consumer(
                 &mut in_segment,
                 &mut segment_index,
                 &mut silence_run_frames,
+                &mut frames_since_partial,
+                &partials,
                 &segment_tx,
+                &speech_detected,
+                &trailing_silence_frames,
             )
+        };
+        let mut route = |frame: &[f32]| match denoiser.as_mut() {
+            Some(denoiser) if recording => denoiser.push(frame, &mut process),
+            _ => process(frame),
+        };
+        if recording {
+            for frame in pre_roll_frames.drain(..) {
+                route(&frame);
+            }
+        }
+        let idle_tx = (!recording)
+            .then(|| idle_frame_tx.lock().unwrap().clone())
+            .flatten();
+        frame_resampler.push(&raw, &mut |frame: &[f32]| {
+            if !recording {
+                if pre_roll_frames.len() >= PRE_ROLL_FRAMES {
+                    pre_roll_frames.pop_front();
+                }
+                pre_roll_frames.push_back(frame.to_vec());
+                if let Some(tx) = &idle_tx {
+                    let _ = tx.send(frame.to_vec());
+                }
+            }
+            route(frame)
         });
 
         // non-blocking check for a command
@@ -391,6 +569,15 @@ fn run_consumer(
                     in_segment = false;
                     segment_index = 0;
                     silence_run_frames = 0;
+                    frames_since_partial = 0;
+                    speech_detected.store(false, Ordering::Relaxed);
+                    trailing_silence_frames.store(0, Ordering::Relaxed);
+                    denoiser = noise_suppression
+                        .load(Ordering::Relaxed)
+                        .then(|| NoiseSuppressor::new(FRAME_DURATION));
+                    if !pre_roll.load(Ordering::Relaxed) {
+                        pre_roll_frames.clear();
+                    }
                     recording = true;
                     visualizer.reset(); // Reset visualization buffer
                     if let Some(v) = &vad {
@@ -400,7 +587,7 @@ fn run_consumer(
                 Cmd::Stop(reply_tx) => {
                     recording = false;
 
-                    frame_resampler.finish(&mut |frame: &[f32]| {
+                    let mut process = |frame: &[f32]| {
                         // we still want to process the last few frames
                         handle_frame(
                             frame,
@@ -412,16 +599,30 @@ fn run_consumer(
                             &mut in_segment,
                             &mut segment_index,
                             &mut silence_run_frames,
+                            &mut frames_since_partial,
+                            &partials,
                             &segment_tx,
+                            &speech_detected,
+                            &trailing_silence_frames,
                         )
-                    });
+                    };
+                    match denoiser.take() {
+                        Some(mut denoiser) => {
+                            frame_resampler
+                                .finish(&mut |frame: &[f32]| denoiser.push(frame, &mut process));
+                            denoiser.finish(&mut process);
+                        }
+                        None => frame_resampler.finish(&mut process),
+                    }
 
                     // Emit final segment if in_segment and current_segment is non-empty
                     if in_segment && !current_segment.is_empty() {
                         if let Some(tx) = segment_tx.lock().unwrap().as_ref() {
                             let segment = SpeechSegment {
                                 index: segment_index,
+                                offset: raw_full.len() - current_segment.len(),
                                 samples: std::mem::take(&mut current_segment),
+                                is_partial: false,
                             };
                             let _ = tx.send(segment);
                         }
@@ -432,6 +633,7 @@ fn run_consumer(
                     in_segment = false;
                     segment_index = 0;
                     silence_run_frames = 0;
+                    frames_since_partial = 0;
 
                     let _ = reply_tx.send(StopResult {
                         raw_full: std::mem::take(&mut raw_full),
@@ -3,11 +3,13 @@ pub mod constants;
 pub mod text;
 pub mod utils;
 pub mod vad;
+pub mod wake_word;
 
 pub use audio::{
-    list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
-    SpeechSegment, StopResult,
+    decode_audio_file, list_input_devices, list_output_devices, save_wav_file, split_at_pauses,
+    AudioRecorder, CpalDeviceInfo, SpeechSegment, StopResult, SystemAudioCapture,
 };
-pub use text::apply_custom_words;
+pub use text::{apply_custom_words, apply_term_casing, capitalize};
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
+pub use wake_word::{WakeWordDetector, WakeWordModels};
@@ -1,4 +1,5 @@
 use natural::phonetics::soundex;
+use regex::{Regex, RegexBuilder};
 use strsim::levenshtein;
 
 /// Applies custom word corrections to transcribed text using fuzzy matching
@@ -93,6 +94,77 @@ pub fn apply_custom_words(text: &str, custom_words: &[String], threshold: f64) -
     corrected_words.join(" ")
 }
 
+/// Enforces the exact casing of dictionary terms (e.g. "iOS", "PostgreSQL", "OAuth2")
+///
+/// Unlike `apply_custom_words`, this never changes spelling: it only rewrites
+/// case-insensitive, whole-word occurrences of each term to the casing given in
+/// `terms`. Multi-word terms match across any whitespace, and longer terms win
+/// over shorter ones they contain.
+pub fn apply_term_casing(text: &str, terms: &[String]) -> String {
+    let mut terms: Vec<&str> = terms
+        .iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if terms.is_empty() {
+        return text.to_string();
+    }
+    terms.sort_by_key(|t| std::cmp::Reverse(t.chars().count()));
+
+    let Some(pattern) = term_pattern(&terms) else {
+        return text.to_string();
+    };
+    pattern
+        .replace_all(text, |caps: &regex::Captures| {
+            let matched = &caps["term"];
+            let term = terms
+                .iter()
+                .find(|t| normalize_term(t) == normalize_term(matched))
+                .unwrap_or(&matched);
+            format!("{}{}", &caps["pre"], term)
+        })
+        .into_owned()
+}
+
+/// Lowercase with runs of whitespace collapsed, for comparing a match to its term
+fn normalize_term(term: &str) -> String {
+    term.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// One alternation over all terms. The regex crate has no lookbehind, so the
+/// character before a term is captured as `pre` and written back unchanged.
+fn term_pattern(terms: &[&str]) -> Option<Regex> {
+    let alternatives: Vec<String> = terms
+        .iter()
+        .map(|term| {
+            let body = term
+                .split_whitespace()
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+");
+            // Only require a word boundary where the term itself starts or ends with
+            // a word character, so terms like "C++" or ".NET" still match
+            let ends_in_word = term.chars().last().is_some_and(|c| c.is_alphanumeric());
+            if ends_in_word {
+                format!(r"{}\b", body)
+            } else {
+                body
+            }
+        })
+        .collect();
+
+    RegexBuilder::new(&format!(
+        r"(?P<pre>^|[^\w])(?P<term>{})",
+        alternatives.join("|")
+    ))
+    .case_insensitive(true)
+    .build()
+    .ok()
+}
+
 /// Preserves the case pattern of the original word when applying a replacement
 fn preserve_case_pattern(original: &str, replacement: &str) -> String {
     if original.chars().all(|c| c.is_uppercase()) {
@@ -132,10 +204,27 @@ fn extract_punctuation(word: &str) -> (&str, &str) {
     (prefix, suffix)
 }
 
+/// `text` with its first letter upper-cased
+pub fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_capitalize() {
+        assert_eq!(capitalize("follow up with Dana"), "Follow up with Dana");
+        assert_eq!(capitalize("ébauche"), "Ébauche");
+        assert_eq!(capitalize("iOS"), "IOS");
+        assert_eq!(capitalize(""), "");
+    }
+
     #[test]
     fn test_apply_custom_words_exact_match() {
         let text = "hello world";
@@ -166,6 +255,28 @@ mod tests {
         assert_eq!(extract_punctuation("...hello..."), ("...", "..."));
     }
 
+    #[test]
+    fn test_apply_term_casing() {
+        let terms = vec![
+            "iOS".to_string(),
+            "PostgreSQL".to_string(),
+            "OAuth2".to_string(),
+            "Visual Studio Code".to_string(),
+            "C++".to_string(),
+        ];
+        assert_eq!(
+            apply_term_casing("my ios app uses postgresql and oauth2.", &terms),
+            "my iOS app uses PostgreSQL and OAuth2."
+        );
+        assert_eq!(
+            apply_term_casing("open visual  studio code, then c++", &terms),
+            "open Visual Studio Code, then C++"
+        );
+        // Only whole words are rewritten
+        assert_eq!(apply_term_casing("biosphere", &terms), "biosphere");
+        assert_eq!(apply_term_casing("IOS", &[]), "IOS");
+    }
+
     #[test]
     fn test_empty_custom_words() {
         let text = "hello world";
@@ -0,0 +1,145 @@
+//! Keyword spotting with openWakeWord's ONNX models.
+//!
+//! openWakeWord runs three small models in a chain: a mel spectrogram of the raw
+//! audio, a shared speech embedding computed over the last 76 mel frames, and a
+//! per-phrase classifier that scores the last 16 embeddings. Audio arrives in 30ms
+//! frames from the recorder and is processed 80ms at a time, as openWakeWord does,
+//! so only the classifier differs between phrases.
+
+use anyhow::{anyhow, Result};
+use ort::session::Session;
+use ort::value::Tensor;
+use std::path::Path;
+
+/// Samples per step: 80ms at 16 kHz, which yields 8 mel frames
+const CHUNK_SAMPLES: usize = 1280;
+/// Earlier samples the mel spectrogram needs for the first frame of a chunk
+const MEL_CONTEXT_SAMPLES: usize = 480;
+const MEL_BINS: usize = 32;
+/// Mel frames per embedding
+const EMBEDDING_WINDOW: usize = 76;
+const EMBEDDING_SIZE: usize = 96;
+/// Embeddings the classifier scores at once, ~1.3s of audio
+const CLASSIFIER_WINDOW: usize = 16;
+
+/// Paths of the models making up a wake word
+pub struct WakeWordModels<'a> {
+    pub melspectrogram: &'a Path,
+    pub embedding: &'a Path,
+    /// The phrase's classifier, a built-in one or one trained with openWakeWord
+    pub classifier: &'a Path,
+}
+
+pub struct WakeWordDetector {
+    melspectrogram: Session,
+    embedding: Session,
+    classifier: Session,
+    threshold: f32,
+    /// Samples not yet processed, preceded by the mel context of the last chunk
+    samples: Vec<f32>,
+    mel_frames: Vec<[f32; MEL_BINS]>,
+    embeddings: Vec<[f32; EMBEDDING_SIZE]>,
+}
+
+impl WakeWordDetector {
+    /// `threshold` is the classifier score, from 0 to 1, above which the phrase
+    /// counts as heard
+    pub fn new(models: WakeWordModels, threshold: f32) -> Result<Self> {
+        if !(0.0..=1.0).contains(&threshold) {
+            anyhow::bail!("threshold must be between 0.0 and 1.0");
+        }
+
+        let mut detector = Self {
+            melspectrogram: load_session(models.melspectrogram)?,
+            embedding: load_session(models.embedding)?,
+            classifier: load_session(models.classifier)?,
+            threshold,
+            samples: Vec::new(),
+            mel_frames: Vec::new(),
+            embeddings: Vec::new(),
+        };
+        detector.reset();
+        Ok(detector)
+    }
+
+    /// Feed 16 kHz mono audio, returning true when the phrase has just been
+    /// heard. The detector starts over after a detection so one utterance
+    /// triggers once.
+    pub fn push_frame(&mut self, frame: &[f32]) -> Result<bool> {
+        self.samples.extend_from_slice(frame);
+
+        let mut heard = false;
+        while self.samples.len() >= MEL_CONTEXT_SAMPLES + CHUNK_SAMPLES {
+            let chunk = &self.samples[..MEL_CONTEXT_SAMPLES + CHUNK_SAMPLES];
+            let score = self.process_chunk(chunk.to_vec())?;
+            self.samples.drain(..CHUNK_SAMPLES);
+            if score.is_some_and(|score| score >= self.threshold) {
+                heard = true;
+                break;
+            }
+        }
+
+        if heard {
+            self.reset();
+        }
+        Ok(heard)
+    }
+
+    /// Forget the audio heard so far, e.g. after recording from the same stream
+    pub fn reset(&mut self) {
+        self.samples = vec![0.0; MEL_CONTEXT_SAMPLES];
+        // openWakeWord starts from a buffer of ones rather than real frames
+        self.mel_frames = vec![[1.0; MEL_BINS]; EMBEDDING_WINDOW];
+        self.embeddings.clear();
+    }
+
+    /// Run one chunk through the chain, returning the classifier's score once
+    /// enough embeddings have been heard since the last reset
+    fn process_chunk(&mut self, chunk: Vec<f32>) -> Result<Option<f32>> {
+        // The models expect 16-bit sample values
+        let audio: Vec<f32> = chunk.into_iter().map(|s| s * 32767.0).collect();
+        let audio_len = audio.len();
+        let input = Tensor::from_array(([1usize, audio_len], audio))?;
+        let outputs = self.melspectrogram.run(ort::inputs![input])?;
+        let (_, mel) = outputs[0].try_extract_tensor::<f32>()?;
+        for bins in mel.chunks_exact(MEL_BINS) {
+            let mut frame = [0.0; MEL_BINS];
+            for (out, value) in frame.iter_mut().zip(bins) {
+                // Rescaled to match the embedding model's training data
+                *out = value / 10.0 + 2.0;
+            }
+            self.mel_frames.push(frame);
+        }
+        drop(outputs);
+        let excess = self.mel_frames.len().saturating_sub(EMBEDDING_WINDOW);
+        self.mel_frames.drain(..excess);
+
+        let window: Vec<f32> = self.mel_frames.iter().flatten().copied().collect();
+        let input = Tensor::from_array(([1usize, EMBEDDING_WINDOW, MEL_BINS, 1], window))?;
+        let outputs = self.embedding.run(ort::inputs![input])?;
+        let (_, values) = outputs[0].try_extract_tensor::<f32>()?;
+        let embedding: [f32; EMBEDDING_SIZE] = values
+            .try_into()
+            .map_err(|_| anyhow!("expected a {EMBEDDING_SIZE}-value embedding"))?;
+        drop(outputs);
+        self.embeddings.push(embedding);
+        let excess = self.embeddings.len().saturating_sub(CLASSIFIER_WINDOW);
+        self.embeddings.drain(..excess);
+        if self.embeddings.len() < CLASSIFIER_WINDOW {
+            return Ok(None);
+        }
+
+        let features: Vec<f32> = self.embeddings.iter().flatten().copied().collect();
+        let input = Tensor::from_array(([1usize, CLASSIFIER_WINDOW, EMBEDDING_SIZE], features))?;
+        let outputs = self.classifier.run(ort::inputs![input])?;
+        let (_, score) = outputs[0].try_extract_tensor::<f32>()?;
+        Ok(score.first().copied())
+    }
+}
+
+fn load_session(path: &Path) -> Result<Session> {
+    Session::builder()
+        .and_then(|builder| builder.with_intra_threads(1))
+        .and_then(|builder| builder.commit_from_file(path))
+        .map_err(|e| anyhow!("Failed to load {}: {e}", path.display()))
+}
@@ -0,0 +1,187 @@
+//! Topic headings and a table of contents for long refinements, so an hour-long
+//! brain dump comes back navigable.
+//!
+//! The model isn't asked to rewrite the text, which would double the tokens and
+//! risk it getting cut off or changed. It's shown the refined text as numbered
+//! paragraphs and only answers with where each chapter starts and its heading; the
+//! headings are then inserted here.
+
+use crate::actions::resolve_llm_config;
+use crate::errors::AppError;
+use crate::settings::AppSettings;
+use crate::summaries::complete;
+use log::info;
+use tauri::AppHandle;
+
+/// Whether `text` is long enough to be split into chapters
+pub fn needs_chapters(text: &str, min_words: u32) -> bool {
+    min_words > 0 && text.split_whitespace().count() >= min_words as usize
+}
+
+/// The pieces a chapter can start at: paragraphs, or sentences when the text is a
+/// single paragraph
+fn split_units(text: &str) -> Vec<String> {
+    let paragraphs: Vec<String> = text
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect();
+    if paragraphs.len() > 1 {
+        return paragraphs;
+    }
+
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let text = text.trim();
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+        let at_break = text[end..].starts_with(char::is_whitespace);
+        if matches!(c, '.' | '!' | '?') && at_break {
+            sentences.push(text[start..end].trim().to_string());
+            start = end;
+        }
+    }
+    if !text[start..].trim().is_empty() {
+        sentences.push(text[start..].trim().to_string());
+    }
+    sentences
+}
+
+/// Read the model's `N: Heading` lines into chapter starts (0-based unit indices),
+/// in order. Out-of-range and repeated starts are dropped, and the first chapter
+/// always starts at the beginning.
+fn parse_chapter_breaks(answer: &str, unit_count: usize) -> Vec<(usize, String)> {
+    let mut breaks: Vec<(usize, String)> = answer
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().trim_start_matches(['-', '*']).trim();
+            let (number, heading) = line.split_once([':', '.', ')'])?;
+            let number: usize = number.trim().trim_matches(['[', ']']).parse().ok()?;
+            let heading = heading.trim().trim_matches(['"', '*', '#']).trim();
+            (number >= 1 && number <= unit_count && !heading.is_empty())
+                .then(|| (number - 1, heading.to_string()))
+        })
+        .collect();
+    breaks.sort_by_key(|(start, _)| *start);
+    breaks.dedup_by_key(|(start, _)| *start);
+    if let Some(first) = breaks.first_mut() {
+        first.0 = 0;
+    }
+    breaks
+}
+
+/// Put the table of contents first and a heading before each chapter
+fn insert_chapters(units: &[String], breaks: &[(usize, String)], joiner: &str) -> String {
+    let contents: Vec<String> = breaks
+        .iter()
+        .map(|(_, heading)| format!("- {}", heading))
+        .collect();
+    let mut sections = vec![format!("## Contents\n\n{}", contents.join("\n"))];
+    for (i, (start, heading)) in breaks.iter().enumerate() {
+        let end = breaks.get(i + 1).map_or(units.len(), |(next, _)| *next);
+        sections.push(format!(
+            "## {}\n\n{}",
+            heading,
+            units[*start..end].join(joiner)
+        ));
+    }
+    sections.join("\n\n")
+}
+
+/// Add topic headings and a table of contents to `text` with the default
+/// refinement model. Returns the text unchanged when the model finds fewer than two
+/// topics.
+pub async fn add_chapters(
+    app: &AppHandle,
+    settings: &AppSettings,
+    text: &str,
+) -> Result<String, AppError> {
+    let model_id = settings
+        .default_coherent_model_id
+        .clone()
+        .ok_or_else(|| AppError::not_configured("No refinement model is configured"))?;
+    let config = resolve_llm_config(settings, &model_id).await?;
+    let client = crate::llm_client::create_client(&config.provider, config.api_key.clone())?;
+
+    let units = split_units(text);
+    let joiner = if text.trim().contains("\n\n") {
+        "\n\n"
+    } else {
+        " "
+    };
+    let numbered: Vec<String> = units
+        .iter()
+        .enumerate()
+        .map(|(i, unit)| format!("[{}] {}", i + 1, unit))
+        .collect();
+    let prompt = format!(
+        "Split this text into chapters by topic. Each piece is numbered. Reply with one line per chapter, in order, as \"N: Heading\", where N is the number of the piece the chapter starts at and Heading is a short title in the text's language. Use as few chapters as cover the topics, at most one per few minutes of speech. Return only those lines.\n\n<text>\n{}\n</text>",
+        numbered.join("\n")
+    );
+    let answer = complete(app, &client, &config, prompt, "chapters").await?;
+
+    let breaks = parse_chapter_breaks(&answer, units.len());
+    info!(
+        "Found {} chapter(s) in {} piece(s) of text",
+        breaks.len(),
+        units.len()
+    );
+    if breaks.len() < 2 {
+        return Ok(text.to_string());
+    }
+    Ok(insert_chapters(&units, &breaks, joiner))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_chapters() {
+        assert!(needs_chapters("one two three", 3));
+        assert!(!needs_chapters("one two", 3));
+        assert!(!needs_chapters("one two three", 0));
+    }
+
+    #[test]
+    fn test_split_units() {
+        assert_eq!(
+            split_units("First.\n\n Second.\n\n\n"),
+            vec!["First.", "Second."]
+        );
+        assert_eq!(
+            split_units("It's 3.5 km. Far! Is it? Yes"),
+            vec!["It's 3.5 km.", "Far!", "Is it?", "Yes"]
+        );
+    }
+
+    #[test]
+    fn test_parse_chapter_breaks() {
+        let answer =
+            "Here you go:\n3: Budget\n1: \"Intro\"\n- 3: Repeat\n9: Out of range\n5. **Hiring**";
+        assert_eq!(
+            parse_chapter_breaks(answer, 6),
+            vec![
+                (0, "Intro".to_string()),
+                (2, "Budget".to_string()),
+                (4, "Hiring".to_string())
+            ]
+        );
+        // A first chapter starting late still covers the opening
+        assert_eq!(
+            parse_chapter_breaks("2: Later", 3),
+            vec![(0, "Later".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_insert_chapters() {
+        let units: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let breaks = vec![(0, "One".to_string()), (2, "Two".to_string())];
+        assert_eq!(
+            insert_chapters(&units, &breaks, "\n\n"),
+            "## Contents\n\n- One\n- Two\n\n## One\n\na\n\nb\n\n## Two\n\nc"
+        );
+    }
+}
@@ -0,0 +1,511 @@
+//! Tools the chat model can call: run a shell command, fetch a web page and read the
+//! current selection. The user confirms every call first: a fetched page can carry
+//! instructions that get the model to read the selection and send it to another
+//! URL, so reading and fetching aren't safe to do unasked either.
+//!
+//! Each provider declares tools and returns calls in its own format, so this module
+//! has a declaration and a parser per API. The request loop lives in `commands::chat`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use tauri::AppHandle;
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Model turns that may call tools before the answer is cut off
+pub const MAX_TOOL_ROUNDS: usize = 8;
+
+/// Tool output longer than this is truncated before it's sent back to the model
+const MAX_OUTPUT_CHARS: usize = 20_000;
+
+/// Bytes of a response body `web_fetch` reads before cutting it off
+const MAX_FETCH_BYTES: usize = 1024 * 1024;
+
+/// How long a shell command may run
+const SHELL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// A tool call requested by the model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    /// Provider-assigned ID used to match the result to the call. Gemini doesn't
+    /// always send one, so it falls back to the tool name there.
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+/// A tool call made while answering, shown alongside the response
+#[derive(Debug, Serialize, Deserialize, Clone, Type)]
+pub struct ToolCallRecord {
+    pub name: String,
+    /// The call's arguments as JSON
+    pub arguments: String,
+    /// What was sent back to the model
+    pub output: String,
+}
+
+struct ToolSpec {
+    name: &'static str,
+    description: &'static str,
+    parameters: fn() -> Value,
+}
+
+const TOOLS: &[ToolSpec] = &[
+    ToolSpec {
+        name: "run_shell",
+        description: "Run a shell command on the user's computer and return its output. \
+            The user is asked to confirm every command before it runs.",
+        parameters: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "command": { "type": "string", "description": "The command line to run" }
+                },
+                "required": ["command"]
+            })
+        },
+    },
+    ToolSpec {
+        name: "web_fetch",
+        description: "Download a web page and return its text content. \
+            The user is asked to confirm every URL before it's fetched.",
+        parameters: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "url": { "type": "string", "description": "An http or https URL" }
+                },
+                "required": ["url"]
+            })
+        },
+    },
+    ToolSpec {
+        name: "read_selection",
+        description: "Read the text currently selected in the user's focused app, \
+            or the clipboard if nothing is selected. The user is asked to confirm first.",
+        parameters: || json!({ "type": "object", "properties": {} }),
+    },
+];
+
+/// Tool declarations for the `tools` field of an OpenAI-compatible request
+pub fn openai_tools() -> Value {
+    TOOLS
+        .iter()
+        .map(|tool| {
+            json!({
+                "type": "function",
+                "function": {
+                    "name": tool.name,
+                    "description": tool.description,
+                    "parameters": (tool.parameters)(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Tool declarations for the `tools` field of an Anthropic Messages request
+pub fn anthropic_tools() -> Vec<Value> {
+    TOOLS
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "input_schema": (tool.parameters)(),
+            })
+        })
+        .collect()
+}
+
+/// A `tools` entry for a Gemini generateContent request
+pub fn gemini_tools() -> Value {
+    let declarations: Vec<Value> = TOOLS
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "parameters": (tool.parameters)(),
+            })
+        })
+        .collect();
+    json!({ "functionDeclarations": declarations })
+}
+
+/// Calls in an OpenAI-compatible response `message`. Arguments arrive as a JSON
+/// string; ones that don't parse are passed on as an empty object.
+pub fn parse_openai_tool_calls(message: &Value) -> Vec<ToolCall> {
+    message["tool_calls"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|call| {
+            let function = &call["function"];
+            Some(ToolCall {
+                id: call["id"].as_str()?.to_string(),
+                name: function["name"].as_str()?.to_string(),
+                arguments: function["arguments"]
+                    .as_str()
+                    .and_then(|args| serde_json::from_str(args).ok())
+                    .unwrap_or_else(|| json!({})),
+            })
+        })
+        .collect()
+}
+
+/// `tool_use` blocks of an Anthropic response's content
+pub fn parse_anthropic_tool_calls(content: &[Value]) -> Vec<ToolCall> {
+    content
+        .iter()
+        .filter(|block| block["type"].as_str() == Some("tool_use"))
+        .filter_map(|block| {
+            Some(ToolCall {
+                id: block["id"].as_str()?.to_string(),
+                name: block["name"].as_str()?.to_string(),
+                arguments: block.get("input").cloned().unwrap_or_else(|| json!({})),
+            })
+        })
+        .collect()
+}
+
+/// `functionCall` parts of a Gemini response
+pub fn parse_gemini_function_calls(parts: &[Value]) -> Vec<ToolCall> {
+    parts
+        .iter()
+        .filter_map(|part| {
+            let call = part.get("functionCall")?;
+            let name = call["name"].as_str()?.to_string();
+            Some(ToolCall {
+                id: call["id"].as_str().unwrap_or(&name).to_string(),
+                arguments: call.get("args").cloned().unwrap_or_else(|| json!({})),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Run a tool call. Failures are returned as text too, so the model can explain
+/// them or try something else.
+pub async fn execute(app: &AppHandle, call: &ToolCall) -> String {
+    let result = match call.name.as_str() {
+        "run_shell" => match call.arguments["command"].as_str() {
+            Some(command) => run_shell(app, command).await,
+            None => Err("Missing 'command' argument".to_string()),
+        },
+        "web_fetch" => match call.arguments["url"].as_str() {
+            Some(url) => web_fetch(app, url).await,
+            None => Err("Missing 'url' argument".to_string()),
+        },
+        "read_selection" => read_selection(app).await,
+        other => Err(format!("Unknown tool '{}'", other)),
+    };
+    let output = result.unwrap_or_else(|e| {
+        log::warn!("Chat tool '{}' failed: {}", call.name, e);
+        format!("Error: {}", e)
+    });
+    truncate(output)
+}
+
+/// Ask the user whether the model may go ahead, with `allow` and `deny` as the
+/// dialog's buttons
+async fn confirm(
+    app: &AppHandle,
+    title: &str,
+    message: String,
+    allow: &str,
+    deny: &str,
+) -> Result<bool, String> {
+    let dialog = app
+        .dialog()
+        .message(message)
+        .title(title)
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            allow.to_string(),
+            deny.to_string(),
+        ));
+    tokio::task::spawn_blocking(move || dialog.blocking_show())
+        .await
+        .map_err(|e| format!("Confirmation dialog failed: {}", e))
+}
+
+async fn run_shell(app: &AppHandle, command: &str) -> Result<String, String> {
+    let confirmed = confirm(
+        app,
+        "Run Command?",
+        format!("The chat model wants to run this command:\n\n{}", command),
+        "Run",
+        "Don't Run",
+    )
+    .await?;
+    if !confirmed {
+        log::info!("User declined chat shell command: {}", command);
+        return Err("The user declined to run this command".to_string());
+    }
+
+    log::info!("Running chat shell command: {}", command);
+    #[cfg(target_os = "windows")]
+    let mut process = tokio::process::Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    process.arg("/C").arg(command);
+    #[cfg(not(target_os = "windows"))]
+    let mut process = tokio::process::Command::new("sh");
+    #[cfg(not(target_os = "windows"))]
+    process.arg("-c").arg(command);
+
+    let output = tokio::time::timeout(SHELL_TIMEOUT, process.kill_on_drop(true).output())
+        .await
+        .map_err(|_| format!("Command timed out after {}s", SHELL_TIMEOUT.as_secs()))?
+        .map_err(|e| format!("Failed to run command: {}", e))?;
+
+    let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        text.push_str(&format!("\n[stderr]\n{}", stderr));
+    }
+    if !output.status.success() {
+        text.push_str(&format!("\n[exit status: {}]", output.status));
+    }
+    Ok(text)
+}
+
+async fn web_fetch(app: &AppHandle, url: &str) -> Result<String, String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!(
+            "Only http and https URLs can be fetched, got '{}'",
+            url
+        ));
+    }
+    let confirmed = confirm(
+        app,
+        "Fetch Web Page?",
+        format!("The chat model wants to fetch this URL:\n\n{}", url),
+        "Fetch",
+        "Don't Fetch",
+    )
+    .await?;
+    if !confirmed {
+        log::info!("User declined chat fetch of {}", url);
+        return Err("The user declined to fetch this URL".to_string());
+    }
+
+    log::info!("Fetching {} for chat", url);
+    let mut response = reqwest::Client::new()
+        .get(url)
+        .timeout(std::time::Duration::from_secs(30))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+    let is_html = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("html"));
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?
+    {
+        if !append_capped(&mut body, &chunk) {
+            log::info!("Stopped reading {} at {} bytes", url, MAX_FETCH_BYTES);
+            break;
+        }
+    }
+    let body = String::from_utf8_lossy(&body).into_owned();
+    Ok(if is_html { html_to_text(&body) } else { body })
+}
+
+/// Add `chunk` to `body` up to `MAX_FETCH_BYTES`. Returns false once the body is
+/// full, when the rest of the response should be left unread.
+fn append_capped(body: &mut Vec<u8>, chunk: &[u8]) -> bool {
+    let room = MAX_FETCH_BYTES.saturating_sub(body.len());
+    body.extend_from_slice(&chunk[..chunk.len().min(room)]);
+    body.len() < MAX_FETCH_BYTES
+}
+
+async fn read_selection(app: &AppHandle) -> Result<String, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let confirmed = confirm(
+        app,
+        "Share Selection?",
+        "The chat model wants to read the text you have selected, or the clipboard if \
+            nothing is selected."
+            .to_string(),
+        "Share",
+        "Don't Share",
+    )
+    .await?;
+    if !confirmed {
+        log::info!("User declined to share the selection with chat");
+        return Err("The user declined to share their selection".to_string());
+    }
+
+    let app_clone = app.clone();
+    let selection =
+        tokio::task::spawn_blocking(move || crate::clipboard::get_selected_text(&app_clone))
+            .await
+            .map_err(|e| format!("Failed to read selection: {}", e))??;
+    match selection.filter(|s| !s.trim().is_empty()) {
+        Some(text) => Ok(text),
+        None => app
+            .clipboard()
+            .read_text()
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| "Nothing is selected and the clipboard is empty".to_string()),
+    }
+}
+
+/// Readable text of an HTML page: scripts, styles and tags removed, whitespace
+/// collapsed and common entities decoded
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    let lower = html.to_ascii_lowercase();
+    let mut i = 0;
+    while i < html.len() {
+        if html[i..].starts_with('<') {
+            // Skip the contents of elements that aren't text
+            let skipped = ["script", "style", "noscript"].iter().find_map(|tag| {
+                lower[i + 1..].starts_with(tag).then(|| {
+                    let close = format!("</{}", tag);
+                    lower[i..].find(&close).map(|end| i + end + close.len())
+                })?
+            });
+            let from = skipped.unwrap_or(i);
+            i = match html[from..].find('>') {
+                Some(end) => from + end + 1,
+                None => html.len(),
+            };
+            text.push(' ');
+        } else {
+            let end = html[i..].find('<').map_or(html.len(), |end| i + end);
+            text.push_str(&html[i..end]);
+            i = end;
+        }
+    }
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate(mut output: String) -> String {
+    if output.chars().count() > MAX_OUTPUT_CHARS {
+        output = output.chars().take(MAX_OUTPUT_CHARS).collect();
+        output.push_str("\n[output truncated]");
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_openai_tool_calls() {
+        let message = json!({
+            "role": "assistant",
+            "content": null,
+            "tool_calls": [
+                {
+                    "id": "call_1",
+                    "type": "function",
+                    "function": { "name": "web_fetch", "arguments": "{\"url\":\"https://example.com\"}" }
+                },
+                {
+                    "id": "call_2",
+                    "type": "function",
+                    "function": { "name": "read_selection", "arguments": "not json" }
+                }
+            ]
+        });
+        let calls = parse_openai_tool_calls(&message);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].arguments["url"], "https://example.com");
+        assert_eq!(calls[1].arguments, json!({}));
+        assert!(parse_openai_tool_calls(&json!({ "content": "Hi" })).is_empty());
+    }
+
+    #[test]
+    fn test_parse_anthropic_tool_calls() {
+        let content = vec![
+            json!({ "type": "text", "text": "Let me check." }),
+            json!({ "type": "tool_use", "id": "toolu_1", "name": "run_shell", "input": { "command": "ls" } }),
+        ];
+        let calls = parse_anthropic_tool_calls(&content);
+        assert_eq!(
+            calls,
+            vec![ToolCall {
+                id: "toolu_1".to_string(),
+                name: "run_shell".to_string(),
+                arguments: json!({ "command": "ls" }),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_gemini_function_calls() {
+        let parts = vec![
+            json!({ "text": "Fetching" }),
+            json!({ "functionCall": { "name": "web_fetch", "args": { "url": "https://example.com" } } }),
+        ];
+        let calls = parse_gemini_function_calls(&parts);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "web_fetch");
+        assert_eq!(calls[0].arguments["url"], "https://example.com");
+    }
+
+    #[test]
+    fn test_declarations_cover_every_tool() {
+        assert_eq!(openai_tools().as_array().unwrap().len(), TOOLS.len());
+        assert_eq!(anthropic_tools().len(), TOOLS.len());
+        assert_eq!(
+            gemini_tools()["functionDeclarations"]
+                .as_array()
+                .unwrap()
+                .len(),
+            TOOLS.len()
+        );
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = "<html><head><style>p { color: red; }</style>\
+            <script>alert('x')</script></head>\
+            <body><h1>Title</h1><p>Fish &amp; chips&nbsp;today</p></body></html>";
+        assert_eq!(html_to_text(html), "Title Fish & chips today");
+    }
+
+    #[test]
+    fn test_append_capped() {
+        let mut body = Vec::new();
+        assert!(append_capped(&mut body, &[b'a'; 1000]));
+        assert!(!append_capped(&mut body, &vec![b'b'; MAX_FETCH_BYTES]));
+        assert_eq!(body.len(), MAX_FETCH_BYTES);
+        assert!(!append_capped(&mut body, b"more"));
+        assert_eq!(body.len(), MAX_FETCH_BYTES);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short".to_string()), "short");
+        let long = truncate("a".repeat(MAX_OUTPUT_CHARS + 10));
+        assert!(long.ends_with("[output truncated]"));
+        assert_eq!(long.chars().filter(|c| *c == 'a').count(), MAX_OUTPUT_CHARS);
+    }
+}
@@ -1,9 +1,11 @@
+use crate::app_detection;
 use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
+use crate::settings::{get_settings, ClipboardHandling, PasteMethod, TerminalPasteMode};
 use enigo::Enigo;
-use log::info;
-use tauri::{AppHandle, Manager};
+use log::{info, warn};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
 
 #[cfg(target_os = "linux")]
 use crate::utils::is_wayland;
@@ -134,10 +136,166 @@ fn send_paste_via_dotool(paste_method: &PasteMethod) -> Result<(), String> {
     Ok(())
 }
 
+/// The entry of `blocked` matching one of the focused app's identifiers, if any
+fn find_blocked_app<'a>(blocked: &'a [String], identifiers: &[String]) -> Option<&'a String> {
+    blocked.iter().find(|entry| {
+        identifiers
+            .iter()
+            .any(|id| id.eq_ignore_ascii_case(entry.trim()))
+    })
+}
+
+/// Terminal apps, by macOS bundle identifier or the app name other platforms report
+const TERMINAL_APPS: &[&str] = &[
+    "com.apple.Terminal",
+    "com.googlecode.iterm2",
+    "dev.warp.Warp-Stable",
+    "co.zeit.hyper",
+    "com.mitchellh.ghostty",
+    "net.kovidgoyal.kitty",
+    "org.alacritty",
+    "com.github.wez.wezterm",
+    "Terminal",
+    "iTerm2",
+    "Warp",
+    "Ghostty",
+    "kitty",
+    "Alacritty",
+    "WezTerm",
+    "gnome-terminal",
+    "gnome-terminal-server",
+    "org.gnome.Console",
+    "kgx",
+    "konsole",
+    "xfce4-terminal",
+    "tilix",
+    "terminator",
+    "foot",
+    "xterm",
+    "WindowsTerminal.exe",
+    "cmd.exe",
+    "powershell.exe",
+    "pwsh.exe",
+];
+
+/// Whether one of the focused app's identifiers is a known terminal
+fn is_terminal(identifiers: &[String]) -> bool {
+    identifiers
+        .iter()
+        .any(|id| TERMINAL_APPS.iter().any(|t| id.eq_ignore_ascii_case(t)))
+}
+
+/// `text` quoted as one shell argument: PowerShell quoting on Windows, POSIX
+/// quoting elsewhere
+fn shell_quote(text: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("'{}'", text.replace('\'', "''"))
+    } else {
+        format!("'{}'", text.replace('\'', r"'\''"))
+    }
+}
+
+/// `text` changed for pasting into a terminal according to `mode`
+pub fn prepare_for_terminal(text: &str, mode: TerminalPasteMode) -> String {
+    let one_line = || {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    match mode {
+        TerminalPasteMode::Off => text.to_string(),
+        TerminalPasteMode::StripNewlines => one_line(),
+        TerminalPasteMode::ShellEscape => shell_quote(&one_line()),
+    }
+}
+
+/// Tell the user a result wasn't pasted because the focused app is blocked
+fn notify_paste_blocked(app_handle: &AppHandle, app_name: &str) {
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title("Not pasted")
+        .body(format!(
+            "Auto-paste is blocked in {}. The text is saved in your history.",
+            app_name
+        ))
+        .show()
+    {
+        warn!("Failed to show paste blocked notification: {}", e);
+    }
+}
+
+/// Whether text is longer than the paste limit, where 0 means no limit
+pub fn exceeds_paste_limit(text: &str, max_chars: u32) -> bool {
+    max_chars > 0 && text.chars().count() > max_chars as usize
+}
+
+/// Instead of pasting an overly long result (a runaway LLM), open the history, where
+/// it's already saved, so it can be reviewed and copied by hand
+pub fn hold_for_review(app_handle: &AppHandle, text: &str, max_chars: u32) {
+    let chars = text.chars().count();
+    info!(
+        "Not pasting {} characters, over the {} character limit",
+        chars, max_chars
+    );
+    crate::show_main_window(app_handle);
+    if let Some(main_window) = app_handle.get_webview_window("main") {
+        let _ = main_window.emit("show-section", "history");
+    }
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title("Not pasted")
+        .body(format!(
+            "The result is {} characters, over your {} character limit. Review it in History.",
+            chars, max_chars
+        ))
+        .show()
+    {
+        warn!("Failed to show paste limit notification: {}", e);
+    }
+}
+
+/// Whether the test harness took the text instead of it being typed
+#[cfg(any(test, feature = "test-support"))]
+fn pasted_by_test_harness(text: &str) -> bool {
+    crate::test_support::capture_paste(text)
+}
+
+#[cfg(not(any(test, feature = "test-support")))]
+fn pasted_by_test_harness(_text: &str) -> bool {
+    false
+}
+
 pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
     let settings = get_settings(&app_handle);
     let paste_method = settings.paste_method;
 
+    // Never type into blocked apps (password managers, production terminals)
+    if !settings.paste_blocked_apps.is_empty() && paste_method != PasteMethod::None {
+        let identifiers = app_detection::get_focused_app_identifiers();
+        if let Some(blocked) = find_blocked_app(&settings.paste_blocked_apps, &identifiers) {
+            info!(
+                "Not pasting: the focused app matches blocked app '{}'",
+                blocked
+            );
+            notify_paste_blocked(&app_handle, blocked);
+            return Ok(());
+        }
+    }
+
+    // Keep a dictated command from running before it's been read
+    let text = if settings.terminal_paste_mode != TerminalPasteMode::Off
+        && paste_method != PasteMethod::None
+        && is_terminal(&app_detection::get_focused_app_identifiers())
+    {
+        prepare_for_terminal(&text, settings.terminal_paste_mode)
+    } else {
+        text
+    };
+
     // Append trailing space if setting is enabled
     let text = if settings.append_trailing_space {
         format!("{} ", text)
@@ -146,6 +304,9 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
     };
 
     info!("Using paste method: {:?}", paste_method);
+    if pasted_by_test_harness(&text) {
+        return Ok(());
+    }
 
     // Get the managed Enigo instance
     let enigo_state = app_handle
@@ -261,3 +422,67 @@ pub fn get_clipboard_content(app_handle: &AppHandle) -> Result<Option<String>, S
         Ok(Some(content))
     }
 }
+
+/// Replaces the clipboard content with `text`.
+pub fn set_clipboard_content(app_handle: &AppHandle, text: &str) -> Result<(), String> {
+    app_handle
+        .clipboard()
+        .write_text(text)
+        .map_err(|e| format!("Failed to write to clipboard: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_paste_limit() {
+        assert!(!exceeds_paste_limit("hello", 5));
+        assert!(exceeds_paste_limit("hello!", 5));
+        // Counted in characters, not bytes
+        assert!(!exceeds_paste_limit("héllo", 5));
+        assert!(!exceeds_paste_limit(&"a".repeat(100_000), 0));
+    }
+
+    #[test]
+    fn test_find_blocked_app() {
+        let blocked = vec!["com.1password.1password".to_string(), "iTerm2".to_string()];
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            find_blocked_app(&blocked, &ids(&["com.1Password.1password", "1Password"])),
+            Some(&blocked[0])
+        );
+        assert_eq!(
+            find_blocked_app(&blocked, &ids(&["com.googlecode.iterm2", "iterm2"])),
+            Some(&blocked[1])
+        );
+        assert_eq!(
+            find_blocked_app(&blocked, &ids(&["com.apple.Safari", "Safari"])),
+            None
+        );
+        assert_eq!(find_blocked_app(&blocked, &[]), None);
+    }
+
+    #[test]
+    fn test_is_terminal() {
+        assert!(is_terminal(&["com.googlecode.iterm2".to_string()]));
+        assert!(is_terminal(&["Code".to_string(), "Konsole".to_string()]));
+        assert!(!is_terminal(&["com.apple.Safari".to_string()]));
+    }
+
+    #[test]
+    fn test_prepare_for_terminal() {
+        let text = "git commit -m 'fix typo'\n";
+        assert_eq!(prepare_for_terminal(text, TerminalPasteMode::Off), text);
+        assert_eq!(
+            prepare_for_terminal("ls -la\n  | head\n\n", TerminalPasteMode::StripNewlines),
+            "ls -la | head"
+        );
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(
+            prepare_for_terminal(text, TerminalPasteMode::ShellEscape),
+            r"'git commit -m '\''fix typo'\'''"
+        );
+    }
+}
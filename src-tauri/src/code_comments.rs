@@ -0,0 +1,318 @@
+//! Dictated code comments for the "comment" and "doc comment" voice commands.
+//!
+//! The comment syntax comes from the selected code when it's already a comment, then
+//! from the file extension in the editor's window title, then from the editor itself.
+//! The text is wrapped to the line width and indented like the selected code, and the
+//! comment is put above that code (a Python docstring goes inside it).
+
+use crate::audio_toolkit::capitalize;
+
+/// Longest line of a formatted comment, indentation and markers included
+const LINE_WIDTH: usize = 80;
+
+/// Fewest characters of text per line, however deep the indentation
+const MIN_TEXT_WIDTH: usize = 30;
+
+/// How a comment is written in a language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentSyntax {
+    /// Every line starts with the marker, like `//` or `#`
+    Line(&'static str),
+    /// An opening and a closing line, with a prefix before each line of text, like
+    /// `/**`, ` * ` and ` */`
+    Block {
+        open: &'static str,
+        prefix: &'static str,
+        close: &'static str,
+    },
+}
+
+const JSDOC: CommentSyntax = CommentSyntax::Block {
+    open: "/**",
+    prefix: " * ",
+    close: " */",
+};
+
+const PYTHON_DOCSTRING: CommentSyntax = CommentSyntax::Block {
+    open: "\"\"\"",
+    prefix: "",
+    close: "\"\"\"",
+};
+
+/// Comment markers recognised at the start of selected code, longest first
+const SELECTION_MARKERS: &[&str] = &["///", "//", "#", "--", ";"];
+
+/// Editors that mostly edit one language, matched against the lowercased bundle id
+const EDITOR_EXTENSIONS: &[(&str, &str)] = &[
+    ("xcode", "swift"),
+    ("pycharm", "py"),
+    ("goland", "go"),
+    ("rustrover", "rs"),
+    ("rubymine", "rb"),
+    ("webstorm", "ts"),
+    ("phpstorm", "php"),
+    ("clion", "cpp"),
+    ("rider", "cs"),
+    ("android.studio", "kt"),
+    ("intellij", "java"),
+    ("datagrip", "sql"),
+];
+
+fn line_marker_for_extension(extension: &str) -> Option<&'static str> {
+    Some(match extension {
+        "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "java" | "js" | "jsx" | "mjs" | "ts"
+        | "tsx" | "go" | "swift" | "kt" | "kts" | "scala" | "dart" | "php" | "zig" => "//",
+        "py" | "rb" | "sh" | "bash" | "zsh" | "fish" | "pl" | "r" | "yaml" | "yml" | "toml"
+        | "nix" | "ex" | "exs" | "jl" | "ps1" | "cmake" | "tf" => "#",
+        "sql" | "lua" | "hs" | "elm" => "--",
+        "lisp" | "clj" | "el" | "scm" | "asm" | "ini" => ";",
+        "tex" | "erl" => "%",
+        _ => return None,
+    })
+}
+
+/// The comment syntax for files with `extension`, or None when it isn't known
+pub fn syntax_for_extension(extension: &str, doc: bool) -> Option<CommentSyntax> {
+    let extension = extension.to_lowercase();
+    if doc {
+        match extension.as_str() {
+            "rs" | "swift" | "cs" | "dart" => return Some(CommentSyntax::Line("///")),
+            "py" => return Some(PYTHON_DOCSTRING),
+            "js" | "jsx" | "mjs" | "ts" | "tsx" | "java" | "kt" | "kts" | "scala" | "php" | "c"
+            | "h" | "cc" | "cpp" | "hpp" => return Some(JSDOC),
+            _ => {}
+        }
+    }
+    line_marker_for_extension(&extension).map(CommentSyntax::Line)
+}
+
+/// The first known file extension in a window title like "main.rs — crate — Visual
+/// Studio Code"
+pub fn extension_in_title(title: &str) -> Option<&str> {
+    title
+        .split(|c: char| c.is_whitespace() || matches!(c, '—' | '–' | '|' | '●' | '(' | ')'))
+        .filter_map(|word| word.rsplit_once('.').map(|(_, extension)| extension))
+        .find(|extension| line_marker_for_extension(&extension.to_lowercase()).is_some())
+}
+
+/// The file extension an editor mostly edits, from its bundle id
+fn extension_for_editor(bundle_id: &str) -> Option<&'static str> {
+    let bundle_id = bundle_id.to_lowercase();
+    EDITOR_EXTENSIONS
+        .iter()
+        .find(|(name, _)| bundle_id.contains(name))
+        .map(|(_, extension)| *extension)
+}
+
+/// The marker of selected code that is already a line comment. The marker has to be
+/// followed by a space, so `#[derive]` and `#include` aren't taken for comments.
+fn marker_in_selection(selection: &str) -> Option<&'static str> {
+    let first_line = selection.lines().map(str::trim).find(|l| !l.is_empty())?;
+    SELECTION_MARKERS
+        .iter()
+        .find(|marker| {
+            first_line
+                .strip_prefix(**marker)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })
+        .copied()
+}
+
+/// Pick the comment syntax for the focused editor, falling back to `//`
+pub fn detect_syntax(
+    doc: bool,
+    selection: Option<&str>,
+    window_title: Option<&str>,
+    bundle_id: Option<&str>,
+) -> CommentSyntax {
+    if !doc {
+        if let Some(marker) = selection.and_then(marker_in_selection) {
+            return CommentSyntax::Line(marker);
+        }
+    }
+    window_title
+        .and_then(extension_in_title)
+        .or_else(|| bundle_id.and_then(extension_for_editor))
+        .and_then(|extension| syntax_for_extension(extension, doc))
+        .unwrap_or(CommentSyntax::Line("//"))
+}
+
+/// Split `text` into lines of at most `width` characters, breaking between words. A
+/// word longer than `width` gets a line of its own.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// `text` as a comment in `syntax`, wrapped to the line width with every line
+/// starting with `indent`
+pub fn format_comment(text: &str, syntax: CommentSyntax, indent: &str) -> String {
+    let text = capitalize(text.trim());
+    let text_width = |taken: usize| {
+        LINE_WIDTH
+            .saturating_sub(indent.chars().count() + taken)
+            .max(MIN_TEXT_WIDTH)
+    };
+    match syntax {
+        CommentSyntax::Line(marker) => wrap(&text, text_width(marker.len() + 1))
+            .iter()
+            .map(|line| format!("{}{} {}", indent, marker, line))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CommentSyntax::Block {
+            open,
+            prefix,
+            close,
+        } => {
+            let mut lines = vec![format!("{}{}", indent, open)];
+            lines.extend(
+                wrap(&text, text_width(prefix.chars().count()))
+                    .iter()
+                    .map(|line| format!("{}{}{}", indent, prefix, line)),
+            );
+            lines.push(format!("{}{}", indent, close));
+            lines.join("\n")
+        }
+    }
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// A Python `def` or `class` line, which its docstring goes below
+fn is_python_definition(line: &str) -> bool {
+    let line = line.trim();
+    (line.starts_with("def ") || line.starts_with("async def ") || line.starts_with("class "))
+        && line.ends_with(':')
+}
+
+/// The text to paste for a dictated comment: the comment alone, or with the selected
+/// code it describes so pasting over the selection keeps that code
+pub fn comment_for(text: &str, syntax: CommentSyntax, selection: Option<&str>) -> String {
+    let Some(selection) = selection.filter(|s| !s.trim().is_empty()) else {
+        return format_comment(text, syntax, "");
+    };
+    // Start at the selection's first line, whatever whitespace was selected before it
+    let leading_blank = &selection[..selection.len() - selection.trim_start().len()];
+    let leading_blank = &leading_blank[..leading_blank.rfind('\n').map_or(0, |i| i + 1)];
+    let code = &selection[leading_blank.len()..];
+    let first_line = code.lines().next().unwrap_or_default();
+    let indent = leading_whitespace(first_line);
+
+    if syntax == PYTHON_DOCSTRING && is_python_definition(first_line) {
+        let (definition, body) = code.split_once('\n').unwrap_or((code, ""));
+        let body_indent = body
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .map(leading_whitespace)
+            .filter(|body_indent| body_indent.len() > indent.len())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}    ", indent));
+        let docstring = format_comment(text, syntax, &body_indent);
+        return if body.is_empty() {
+            format!("{}{}\n{}", leading_blank, definition, docstring)
+        } else {
+            format!("{}{}\n{}\n{}", leading_blank, definition, docstring, body)
+        };
+    }
+
+    format!(
+        "{}{}\n{}",
+        leading_blank,
+        format_comment(text, syntax, indent),
+        code
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_in_title() {
+        assert_eq!(
+            extension_in_title("main.rs — crate — Visual Studio Code"),
+            Some("rs")
+        );
+        assert_eq!(extension_in_title("● app.py - project"), Some("py"));
+        assert_eq!(extension_in_title("v1.2 notes.txt"), None);
+    }
+
+    #[test]
+    fn test_detect_syntax() {
+        assert_eq!(
+            detect_syntax(false, Some("  # TODO"), Some("lib.rs"), None),
+            CommentSyntax::Line("#")
+        );
+        assert_eq!(
+            detect_syntax(true, None, Some("lib.rs — crate"), None),
+            CommentSyntax::Line("///")
+        );
+        assert_eq!(
+            detect_syntax(true, None, None, Some("com.jetbrains.WebStorm")),
+            JSDOC
+        );
+        assert_eq!(
+            detect_syntax(false, None, Some("Untitled"), None),
+            CommentSyntax::Line("//")
+        );
+        assert_eq!(
+            detect_syntax(false, Some("#[derive(Debug)]"), Some("lib.rs"), None),
+            CommentSyntax::Line("//")
+        );
+    }
+
+    #[test]
+    fn test_format_comment_wraps() {
+        let text = "retry the upload a few times before giving up because the \
+                    storage service drops connections under load";
+        let comment = format_comment(text, CommentSyntax::Line("//"), "    ");
+        assert!(comment.lines().all(|line| line.len() <= LINE_WIDTH));
+        assert!(comment.lines().all(|line| line.starts_with("    // ")));
+        assert!(comment.starts_with("    // Retry the upload"));
+        assert_eq!(comment.lines().count(), 2);
+
+        assert_eq!(
+            format_comment("parses the header", JSDOC, ""),
+            "/**\n * Parses the header\n */"
+        );
+    }
+
+    #[test]
+    fn test_comment_for_selection() {
+        assert_eq!(
+            comment_for(
+                "skip empty rows",
+                CommentSyntax::Line("//"),
+                Some("\n    for row in rows {\n")
+            ),
+            "\n    // Skip empty rows\n    for row in rows {\n"
+        );
+        assert_eq!(
+            comment_for(
+                "load the config",
+                PYTHON_DOCSTRING,
+                Some("def load():\n    pass")
+            ),
+            "def load():\n    \"\"\"\n    Load the config\n    \"\"\"\n    pass"
+        );
+        assert_eq!(
+            comment_for("note", CommentSyntax::Line("#"), None),
+            "# Note"
+        );
+    }
+}
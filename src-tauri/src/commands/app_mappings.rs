@@ -0,0 +1,155 @@
+use crate::errors::AppError;
+use crate::settings::{
+    get_settings, write_settings, AppCategoryMapping, DetectedApp, MAX_DETECTED_APPS,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Bumped when the export file layout changes incompatibly
+const EXPORT_VERSION: u32 = 1;
+
+/// Layout of an app mappings export file
+#[derive(Serialize, Deserialize)]
+struct AppMappingsExport {
+    version: u32,
+    app_category_mappings: Vec<AppCategoryMapping>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    detected_apps_history: Vec<DetectedApp>,
+}
+
+/// What an import changed
+#[derive(Serialize, Type)]
+pub struct AppMappingsImportResult {
+    /// Mappings added or updated
+    pub mappings: usize,
+    /// Detected apps added to the history
+    pub detected_apps: usize,
+    /// Apps whose mapping was skipped because its category doesn't exist here
+    pub skipped: Vec<String>,
+}
+
+/// Write the app-category mappings, and optionally the detected apps history, to
+/// `path` as JSON. Returns how many mappings were exported.
+#[tauri::command]
+#[specta::specta]
+pub fn export_app_mappings(
+    app: AppHandle,
+    path: String,
+    include_detected_apps: bool,
+) -> Result<usize, AppError> {
+    let settings = get_settings(&app);
+    let export = AppMappingsExport {
+        version: EXPORT_VERSION,
+        app_category_mappings: settings.app_category_mappings,
+        detected_apps_history: if include_detected_apps {
+            settings.detected_apps_history
+        } else {
+            Vec::new()
+        },
+    };
+
+    let contents = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize app mappings: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok(export.app_category_mappings.len())
+}
+
+/// Merge mappings from a file written by `export_app_mappings`. Imported mappings
+/// replace existing ones for the same app; mappings to categories that don't
+/// exist here are skipped.
+#[tauri::command]
+#[specta::specta]
+pub fn import_app_mappings(
+    app: AppHandle,
+    path: String,
+    include_detected_apps: bool,
+) -> Result<AppMappingsImportResult, AppError> {
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let import: AppMappingsExport = serde_json::from_str(&contents)
+        .map_err(|e| format!("Not an app mappings export: {}", e))?;
+    if import.version > EXPORT_VERSION {
+        return Err(AppError::invalid_input(format!(
+            "App mappings export version {} is newer than this version of Ramble supports",
+            import.version
+        )));
+    }
+
+    let mut settings = get_settings(&app);
+    let mut result = AppMappingsImportResult {
+        mappings: 0,
+        detected_apps: 0,
+        skipped: Vec::new(),
+    };
+
+    for mapping in import.app_category_mappings {
+        if !settings
+            .prompt_categories
+            .iter()
+            .any(|c| c.id == mapping.category_id)
+        {
+            result.skipped.push(mapping.display_name);
+            continue;
+        }
+        match settings
+            .app_category_mappings
+            .iter_mut()
+            .find(|m| m.bundle_identifier == mapping.bundle_identifier)
+        {
+            Some(existing) => *existing = mapping,
+            None => settings.app_category_mappings.push(mapping),
+        }
+        result.mappings += 1;
+    }
+
+    // Imported history follows the same privacy settings as recorded history
+    if include_detected_apps && settings.record_detected_apps {
+        for detected in import.detected_apps_history {
+            if settings
+                .detected_apps_excluded
+                .iter()
+                .any(|id| id.eq_ignore_ascii_case(&detected.bundle_identifier))
+            {
+                continue;
+            }
+            match settings
+                .detected_apps_history
+                .iter_mut()
+                .find(|a| a.bundle_identifier == detected.bundle_identifier)
+            {
+                Some(existing) => existing.last_seen = existing.last_seen.max(detected.last_seen),
+                None => {
+                    settings.detected_apps_history.push(detected);
+                    result.detected_apps += 1;
+                }
+            }
+        }
+        settings
+            .detected_apps_history
+            .sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        settings.detected_apps_history.truncate(MAX_DETECTED_APPS);
+    }
+
+    write_settings(&app, settings);
+    log::info!(
+        "Imported {} app mappings and {} detected apps from {} ({} skipped)",
+        result.mappings,
+        result.detected_apps,
+        path,
+        result.skipped.len()
+    );
+    Ok(result)
+}
+
+/// Forget every app recorded while dictating. Mappings are kept.
+#[tauri::command]
+#[specta::specta]
+pub fn clear_detected_apps_history(app: AppHandle) -> Result<(), AppError> {
+    let mut settings = get_settings(&app);
+    let cleared = settings.detected_apps_history.len();
+    settings.detected_apps_history.clear();
+    write_settings(&app, settings);
+    log::info!("Cleared {} apps from the detected apps history", cleared);
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use crate::audio_feedback;
 use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
-use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
-use crate::settings::{get_settings, write_settings};
+use crate::errors::AppError;
+use crate::managers::audio::{wake_word_model_paths, AudioRecordingManager, MicrophoneMode};
+use crate::settings::{get_settings, write_settings, WAKE_WORD_BINDINGS};
 use log::warn;
 use serde::{Deserialize, Serialize};
 use specta::Type;
@@ -41,34 +42,113 @@ pub struct AudioDevice {
 
 #[tauri::command]
 #[specta::specta]
-pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), String> {
+pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), AppError> {
     // Update settings
     let mut settings = get_settings(&app);
     settings.always_on_microphone = always_on;
     write_settings(&app, settings);
 
-    // Update the audio manager mode
+    // Update the audio manager mode. The battery saver keeps the microphone closed
+    // and opens it again when it turns off.
     let rm = app.state::<Arc<AudioRecordingManager>>();
-    let new_mode = if always_on {
+    let new_mode = if always_on && !crate::power::is_active() {
         MicrophoneMode::AlwaysOn
     } else {
         MicrophoneMode::OnDemand
     };
 
     rm.update_mode(new_mode)
-        .map_err(|e| format!("Failed to update microphone mode: {}", e))
+        .map_err(|e| AppError::internal(format!("Failed to update microphone mode: {}", e)))
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_microphone_mode(app: AppHandle) -> Result<bool, String> {
+pub fn get_microphone_mode(app: AppHandle) -> Result<bool, AppError> {
     let settings = get_settings(&app);
     Ok(settings.always_on_microphone)
 }
 
+/// Restart the wake word listener after one of its settings changed
+fn restart_wake_word(app: &AppHandle) -> Result<(), AppError> {
+    app.state::<Arc<AudioRecordingManager>>()
+        .update_wake_word()
+        .map_err(|e| AppError::internal(format!("Failed to start wake word listener: {}", e)))
+}
+
+/// Whether the wake word's models are installed, so it can be turned on
+#[tauri::command]
+#[specta::specta]
+pub fn is_wake_word_available(app: AppHandle) -> bool {
+    wake_word_model_paths(&app, &get_settings(&app)).is_some()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_wake_word_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings(&app);
+    if enabled && wake_word_model_paths(&app, &settings).is_none() {
+        return Err(AppError::not_configured(
+            "The wake word models aren't installed",
+        ));
+    }
+    settings.wake_word_enabled = enabled;
+    write_settings(&app, settings);
+    restart_wake_word(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_wake_word_binding_setting(app: AppHandle, binding: String) -> Result<(), AppError> {
+    if !WAKE_WORD_BINDINGS.contains(&binding.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "The wake word can't start '{}'",
+            binding
+        )));
+    }
+    let mut settings = get_settings(&app);
+    settings.wake_word_binding = binding;
+    write_settings(&app, settings);
+    restart_wake_word(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_wake_word_threshold_setting(app: AppHandle, threshold: f32) -> Result<(), AppError> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(AppError::invalid_input(
+            "Wake word threshold must be between 0 and 1",
+        ));
+    }
+    let mut settings = get_settings(&app);
+    settings.wake_word_threshold = threshold;
+    write_settings(&app, settings);
+    restart_wake_word(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_wake_word_model_path_setting(
+    app: AppHandle,
+    path: Option<String>,
+) -> Result<(), AppError> {
+    let path = path.filter(|p| !p.trim().is_empty());
+    if let Some(p) = &path {
+        if !std::path::Path::new(p).is_file() {
+            return Err(AppError::invalid_input(format!(
+                "Wake word model not found: {}",
+                p
+            )));
+        }
+    }
+    let mut settings = get_settings(&app);
+    settings.wake_word_model_path = path;
+    write_settings(&app, settings);
+    restart_wake_word(&app)
+}
+
 #[tauri::command]
 #[specta::specta]
-pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
+pub fn get_available_microphones() -> Result<Vec<AudioDevice>, AppError> {
     let devices =
         list_input_devices().map_err(|e| format!("Failed to list audio devices: {}", e))?;
 
@@ -89,7 +169,7 @@ pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
+pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
     settings.selected_microphone = if device_name == "default" {
         None
@@ -108,7 +188,7 @@ pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<()
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
+pub fn get_selected_microphone(app: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app);
     Ok(settings
         .selected_microphone
@@ -117,7 +197,7 @@ pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
+pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, AppError> {
     let devices =
         list_output_devices().map_err(|e| format!("Failed to list output devices: {}", e))?;
 
@@ -138,7 +218,7 @@ pub fn get_available_output_devices() -> Result<Vec<AudioDevice>, String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result<(), String> {
+pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
     settings.selected_output_device = if device_name == "default" {
         None
@@ -151,7 +231,7 @@ pub fn set_selected_output_device(app: AppHandle, device_name: String) -> Result
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_selected_output_device(app: AppHandle) -> Result<String, String> {
+pub fn get_selected_output_device(app: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app);
     Ok(settings
         .selected_output_device
@@ -174,7 +254,7 @@ pub async fn play_test_sound(app: AppHandle, sound_type: String) {
 
 #[tauri::command]
 #[specta::specta]
-pub fn set_clamshell_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
+pub fn set_clamshell_microphone(app: AppHandle, device_name: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
     settings.clamshell_microphone = if device_name == "default" {
         None
@@ -187,7 +267,7 @@ pub fn set_clamshell_microphone(app: AppHandle, device_name: String) -> Result<(
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_clamshell_microphone(app: AppHandle) -> Result<String, String> {
+pub fn get_clamshell_microphone(app: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app);
     Ok(settings
         .clamshell_microphone
@@ -0,0 +1,28 @@
+use crate::errors::AppError;
+use crate::managers::captures::{CaptureEntry, CaptureManager};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Recent region and full-screen captures, newest first
+#[tauri::command]
+#[specta::specta]
+pub fn list_captures(app: AppHandle) -> Result<Vec<CaptureEntry>, AppError> {
+    let manager = app.state::<Arc<CaptureManager>>();
+    manager.list().map_err(AppError::from)
+}
+
+/// A saved capture as a base64 PNG, to attach it to a chat without recapturing
+#[tauri::command]
+#[specta::specta]
+pub fn load_capture(app: AppHandle, id: i64) -> Result<String, AppError> {
+    let manager = app.state::<Arc<CaptureManager>>();
+    manager.load(id).map_err(AppError::from)
+}
+
+/// Delete a saved capture and its files
+#[tauri::command]
+#[specta::specta]
+pub fn delete_capture(app: AppHandle, id: i64) -> Result<(), AppError> {
+    let manager = app.state::<Arc<CaptureManager>>();
+    manager.delete(id).map_err(AppError::from)
+}
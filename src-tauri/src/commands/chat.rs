@@ -1,17 +1,29 @@
-use crate::llm_client::{create_client, get_api_key_for_provider_async};
+use crate::chat_tools::{self, ToolCallRecord, MAX_TOOL_ROUNDS};
+use crate::errors::{AppError, ErrorCode};
+use crate::file_attachments::{self, FileAttachment};
+use crate::llm_client::{
+    create_client, custom_headers, gemini_content, gemini_generate_content, gemini_request_body,
+    get_api_key_for_provider_async, is_gemini, stream_model_completion, TokenUsage,
+};
+use crate::managers::usage::record_usage;
+use crate::retry::with_retry;
 use crate::settings::{get_settings, get_system_prompt_content};
 use async_openai::types::{
     ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
-    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequest,
+    CreateChatCompletionRequestArgs,
 };
 use serde::{Deserialize, Serialize};
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 
 #[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
 pub struct ChatMessage {
     pub role: String,
     pub content: String,
     pub images: Option<Vec<String>>, // Base64 encoded images
+    /// Text extracted from attached files, see `attach_chat_file`
+    #[serde(default)]
+    pub files: Option<Vec<FileAttachment>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
@@ -31,6 +43,18 @@ pub struct GroundingMetadata {
 pub struct ChatResponse {
     pub content: String,
     pub grounding_metadata: Option<GroundingMetadata>,
+    /// Token usage, if reported by the provider
+    pub usage: Option<TokenUsage>,
+    /// Tools the model called before answering, in order
+    #[serde(default)]
+    pub tool_calls: Vec<ToolCallRecord>,
+}
+
+/// Emitted as `chat-chunk` for each piece of a streamed response
+#[derive(Debug, Serialize, Clone)]
+struct ChatChunk {
+    stream_id: String,
+    delta: String,
 }
 
 /// Send a chat completion request to the configured LLM provider
@@ -38,6 +62,11 @@ pub struct ChatResponse {
 /// # Arguments
 /// * `model_id` - Optional model ID to use. Falls back to `default_chat_model_id` if not provided.
 /// * `enable_grounding` - Whether to enable web search grounding (supported for Gemini and Anthropic)
+/// * `enable_tools` - Whether the model may call the tools in `chat_tools` (shell commands,
+///   web fetch, reading the selection). Not supported for Codex.
+/// * `stream_id` - If set, response text is emitted as `chat-chunk` events tagged with it
+///   while it streams in. Native Anthropic and Codex requests aren't streamed, nor are
+///   OpenAI-compatible requests with tools.
 #[tauri::command]
 #[specta::specta]
 pub async fn chat_completion(
@@ -45,40 +74,82 @@ pub async fn chat_completion(
     messages: Vec<ChatMessage>,
     model_id: Option<String>,
     enable_grounding: bool,
-) -> Result<ChatResponse, String> {
+    enable_tools: bool,
+    stream_id: Option<String>,
+) -> Result<ChatResponse, AppError> {
     let settings = get_settings(&app);
 
     // Determine which model to use
     let model_id = model_id
         .or(settings.default_chat_model_id.clone())
-        .ok_or_else(|| "No model specified and no default chat model configured".to_string())?;
+        .ok_or_else(|| {
+            AppError::not_configured("No model specified and no default chat model configured")
+                .with_hint("Choose a default chat model in the Post Process settings.")
+        })?;
 
     // Look up the model
-    let model = settings
-        .get_model(&model_id)
-        .ok_or_else(|| format!("Model '{}' not found in configured models", model_id))?;
+    let model = settings.get_model(&model_id).ok_or_else(|| {
+        AppError::not_found(format!(
+            "Model '{}' not found in configured models",
+            model_id
+        ))
+    })?;
 
     // Look up the provider for this model
     let provider = settings.get_provider(&model.provider_id).ok_or_else(|| {
-        format!(
+        AppError::not_found(format!(
             "Provider '{}' not found for model '{}'",
             model.provider_id, model_id
-        )
+        ))
     })?;
 
     // Get API key or OAuth token using the OAuth-aware helper (with auto-refresh)
     let api_key = get_api_key_for_provider_async(provider).await?;
 
+    let mut response = request_chat_completion(
+        &app,
+        provider,
+        model,
+        api_key,
+        messages,
+        enable_grounding,
+        enable_tools,
+        stream_id,
+    )
+    .await?;
+    if let Some(usage) = &response.usage {
+        record_usage(&app, provider, model, "chat", usage);
+    }
+    if crate::reasoning::hides_reasoning(model) {
+        response.content = crate::reasoning::strip_reasoning(&response.content);
+    }
+    Ok(response)
+}
+
+/// Send the request with the provider's native API where it has one
+#[allow(clippy::too_many_arguments)]
+async fn request_chat_completion(
+    app: &AppHandle,
+    provider: &crate::settings::LLMProvider,
+    model: &crate::settings::LLMModel,
+    api_key: String,
+    messages: Vec<ChatMessage>,
+    enable_grounding: bool,
+    enable_tools: bool,
+    stream_id: Option<String>,
+) -> Result<ChatResponse, AppError> {
     // Use Gemini native API for all Gemini models (supports grounding)
     // Handle both "gemini" (API key) and "gemini_oauth" (OAuth) providers
-    if provider.id == "gemini" || provider.id == "gemini_oauth" {
+    if is_gemini(provider) {
         return chat_completion_gemini_native(
-            &app,
+            app,
             provider,
             &api_key,
-            &model.model_id,
+            model,
             messages,
             enable_grounding,
+            enable_tools,
+            stream_id,
         )
         .await;
     }
@@ -86,19 +157,31 @@ pub async fn chat_completion(
     // Use Anthropic native API for Claude models (supports web search)
     if provider.id == "anthropic" {
         return chat_completion_anthropic_native(
-            &app,
+            app,
             provider,
             &api_key,
-            &model.model_id,
+            model,
             messages,
             enable_grounding,
+            enable_tools,
         )
         .await;
     }
 
     // Use Codex API for OpenAI OAuth (ChatGPT Plus/Pro subscription)
     if provider.id == "openai_oauth" {
-        return chat_completion_openai_codex(&app, &api_key, &model.model_id, messages).await;
+        if enable_tools {
+            log::warn!("Chat tools aren't supported with Codex, answering without them");
+        }
+        let reasoning_effort = model.reasoning.as_ref().and_then(|r| r.effort.as_deref());
+        return chat_completion_openai_codex(
+            app,
+            &api_key,
+            &model.model_id,
+            reasoning_effort,
+            messages,
+        )
+        .await;
     }
 
     // Create the client
@@ -108,7 +191,7 @@ pub async fn chat_completion(
     let mut openai_messages: Vec<ChatCompletionRequestMessage> = Vec::new();
 
     // Inject system prompt if configured
-    if let Some(system_prompt) = get_system_prompt_content(&app) {
+    if let Some(system_prompt) = get_system_prompt_content(app) {
         let system_msg = ChatCompletionRequestSystemMessageArgs::default()
             .content(system_prompt)
             .build()
@@ -124,54 +207,49 @@ pub async fn chat_completion(
                 .map_err(|e| e.to_string())?
                 .into(),
             "user" => {
-                if let Some(images) = msg.images {
-                    if !images.is_empty() {
-                        use async_openai::types::{
-                            ChatCompletionRequestMessageContentPartImageArgs,
-                            ChatCompletionRequestMessageContentPartTextArgs,
-                            ChatCompletionRequestUserMessageContentPart, ImageUrlArgs,
-                        };
-
-                        let mut parts: Vec<ChatCompletionRequestUserMessageContentPart> =
-                            Vec::new();
-
-                        // Add text part
+                let texts = text_parts(&msg);
+                let images = msg.images.unwrap_or_default();
+                if !images.is_empty() || texts.len() > 1 {
+                    use async_openai::types::{
+                        ChatCompletionRequestMessageContentPartImageArgs,
+                        ChatCompletionRequestMessageContentPartTextArgs,
+                        ChatCompletionRequestUserMessageContentPart, ImageUrlArgs,
+                    };
+
+                    let mut parts: Vec<ChatCompletionRequestUserMessageContentPart> = Vec::new();
+
+                    // Add the text and attached files
+                    for text in texts {
                         parts.push(
                             ChatCompletionRequestMessageContentPartTextArgs::default()
-                                .text(msg.content)
+                                .text(text)
                                 .build()
                                 .map_err(|e| e.to_string())?
                                 .into(),
                         );
+                    }
 
-                        // Add image parts
-                        for base64_image in images {
-                            parts.push(
-                                ChatCompletionRequestMessageContentPartImageArgs::default()
-                                    .image_url(
-                                        ImageUrlArgs::default()
-                                            .url(format!("data:image/png;base64,{}", base64_image))
-                                            .build()
-                                            .map_err(|e| e.to_string())?,
-                                    )
-                                    .build()
-                                    .map_err(|e| e.to_string())?
-                                    .into(),
-                            );
-                        }
-
-                        ChatCompletionRequestUserMessageArgs::default()
-                            .content(parts)
-                            .build()
-                            .map_err(|e| e.to_string())?
-                            .into()
-                    } else {
-                        ChatCompletionRequestUserMessageArgs::default()
-                            .content(msg.content)
-                            .build()
-                            .map_err(|e| e.to_string())?
-                            .into()
+                    // Add image parts
+                    for base64_image in images {
+                        parts.push(
+                            ChatCompletionRequestMessageContentPartImageArgs::default()
+                                .image_url(
+                                    ImageUrlArgs::default()
+                                        .url(format!("data:image/png;base64,{}", base64_image))
+                                        .build()
+                                        .map_err(|e| e.to_string())?,
+                                )
+                                .build()
+                                .map_err(|e| e.to_string())?
+                                .into(),
+                        );
                     }
+
+                    ChatCompletionRequestUserMessageArgs::default()
+                        .content(parts)
+                        .build()
+                        .map_err(|e| e.to_string())?
+                        .into()
                 } else {
                     ChatCompletionRequestUserMessageArgs::default()
                         .content(msg.content)
@@ -200,266 +278,335 @@ pub async fn chat_completion(
         .build()
         .map_err(|e| format!("Failed to build request: {}", e))?;
 
-    // Make the API call
-    let response = client
-        .chat()
-        .create(request)
-        .await
-        .map_err(|e| format!("Chat completion failed: {}", e))?;
+    if enable_tools {
+        return chat_completion_openai_tools(app, provider, &api_key, model, request).await;
+    }
 
-    // Extract the response content
-    let content = response
-        .choices
-        .first()
-        .and_then(|choice| choice.message.content.clone())
-        .ok_or_else(|| "No response content".to_string())?;
+    // Make the API call, forwarding text to the chat window as it streams in
+    let on_delta = |delta: &str| {
+        if let Some(stream_id) = &stream_id {
+            let _ = app.emit(
+                "chat-chunk",
+                ChatChunk {
+                    stream_id: stream_id.clone(),
+                    delta: delta.to_string(),
+                },
+            );
+        }
+    };
+    let (content, usage) =
+        stream_model_completion(&client, provider, &api_key, model, request, on_delta)
+            .await
+            .map_err(|e| AppError::from(e).with_context("Chat completion failed"))?;
+
+    if content.is_empty() {
+        return Err(AppError::internal("No response content"));
+    }
 
     Ok(ChatResponse {
         content,
         grounding_metadata: None,
+        usage,
+        tool_calls: Vec::new(),
     })
 }
 
-/// Native Gemini API call for search grounding
-async fn chat_completion_gemini_native(
+/// OpenAI-compatible request with tool declarations. Each round the model either
+/// answers or calls tools, whose results are appended before asking again.
+/// These requests aren't streamed, since tool calls only arrive in pieces there.
+async fn chat_completion_openai_tools(
     app: &AppHandle,
     provider: &crate::settings::LLMProvider,
     api_key: &str,
-    model_id: &str,
-    messages: Vec<ChatMessage>,
-    enable_grounding: bool,
-) -> Result<ChatResponse, String> {
-    use crate::settings::AuthMethod;
-
-    let mut contents = Vec::new();
-
-    // Inject system prompt as first user message if configured
-    if let Some(system_prompt) = get_system_prompt_content(app) {
-        contents.push(serde_json::json!({
-            "role": "user",
-            "parts": [{ "text": system_prompt }]
-        }));
-        // Add a model acknowledgment to maintain conversation flow
-        contents.push(serde_json::json!({
-            "role": "model",
-            "parts": [{ "text": "Understood. I will follow these instructions." }]
-        }));
+    model: &crate::settings::LLMModel,
+    request: CreateChatCompletionRequest,
+) -> Result<ChatResponse, AppError> {
+    let mut body =
+        serde_json::to_value(&request).map_err(|e| format!("Failed to encode request: {}", e))?;
+    if let serde_json::Value::Object(fields) = &mut body {
+        fields.extend(crate::openrouter::extra_body_fields(provider, model));
+        fields.extend(crate::reasoning::body_fields(provider, model));
+        fields.insert("tools".to_string(), chat_tools::openai_tools());
     }
 
-    // When grounding is enabled, add an instruction to always use Google Search
-    if enable_grounding {
-        contents.push(serde_json::json!({
-            "role": "user",
-            "parts": [{ "text": "IMPORTANT: You MUST use Google Search to find current, accurate information before responding. Always search the web first." }]
-        }));
-        contents.push(serde_json::json!({
-            "role": "model",
-            "parts": [{ "text": "Understood. I will use Google Search to find current information." }]
-        }));
-    }
-
-    for msg in messages {
-        let role = if msg.role == "assistant" {
-            "model"
-        } else {
-            "user"
-        };
-
-        let mut parts = Vec::new();
-
-        // Add text content
-        parts.push(serde_json::json!({ "text": msg.content }));
-
-        // Add images if present
-        if let Some(images) = msg.images {
-            for base64_image in images {
-                parts.push(serde_json::json!({
-                    "inline_data": {
-                        "mime_type": "image/png",
-                        "data": base64_image
-                    }
-                }));
+    let url = format!(
+        "{}/chat/completions",
+        provider.base_url.trim_end_matches('/')
+    );
+    let client = reqwest::Client::new();
+    let mut usage = None;
+    let mut tool_calls = Vec::new();
+    for _ in 0..MAX_TOOL_ROUNDS {
+        let res_json = with_retry(
+            format!("Chat with tools using '{}'", model.model_id),
+            || post_chat_completion(&client, &url, provider, api_key, &body),
+        )
+        .await
+        .map_err(|e| e.with_context("Chat completion failed"))?;
+        add_usage(&mut usage, TokenUsage::from_json(&res_json["usage"]));
+
+        let message = &res_json["choices"][0]["message"];
+        let calls = chat_tools::parse_openai_tool_calls(message);
+        if calls.is_empty() {
+            let content = message["content"].as_str().unwrap_or_default().to_string();
+            if content.is_empty() {
+                return Err(AppError::internal("No response content"));
             }
+            return Ok(ChatResponse {
+                content,
+                grounding_metadata: None,
+                usage,
+                tool_calls,
+            });
         }
 
-        contents.push(serde_json::json!({
-            "role": role,
-            "parts": parts
-        }));
-    }
-
-    let inner_request_body = if enable_grounding {
-        serde_json::json!({
-            "contents": contents,
-            "tools": [{
-                "google_search": {}
-            }]
-        })
-    } else {
-        serde_json::json!({
-            "contents": contents
-        })
-    };
-
-    // Branch based on auth method
-    if provider.auth_method == AuthMethod::OAuth {
-        // OAuth: Use Code Assist API
-        chat_completion_gemini_code_assist(api_key, model_id, inner_request_body).await
-    } else {
-        // API key: Use standard Generative Language API
-        chat_completion_gemini_api_key(api_key, model_id, inner_request_body).await
+        let mut turn = vec![message.clone()];
+        for call in &calls {
+            let output = chat_tools::execute(app, call).await;
+            turn.push(serde_json::json!({
+                "role": "tool",
+                "tool_call_id": call.id,
+                "content": output,
+            }));
+            tool_calls.push(record(call, output));
+        }
+        if let Some(messages) = body["messages"].as_array_mut() {
+            messages.extend(turn);
+        }
     }
+    Err(AppError::internal(format!(
+        "The model was still calling tools after {} rounds",
+        MAX_TOOL_ROUNDS
+    )))
 }
 
-/// Gemini API call using API key (standard Generative Language API)
-async fn chat_completion_gemini_api_key(
+/// POST a non-streamed chat completion request and return its JSON response
+async fn post_chat_completion(
+    client: &reqwest::Client,
+    url: &str,
+    provider: &crate::settings::LLMProvider,
     api_key: &str,
-    model_id: &str,
-    request_body: serde_json::Value,
-) -> Result<ChatResponse, String> {
-    let url = format!(
-        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent?key={}",
-        model_id, api_key
-    );
-
-    let client = reqwest::Client::new();
+    body: &serde_json::Value,
+) -> Result<serde_json::Value, AppError> {
     let response = client
-        .post(&url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&request_body)
+        .post(url)
+        .bearer_auth(api_key)
+        .headers(custom_headers(provider, api_key)?)
+        .json(body)
         .send()
         .await
-        .map_err(|e| format!("Request failed: {}", e))?;
-
+        .map_err(|e| AppError::from_request(&e, format!("Request failed: {}", e)))?;
     let status = response.status();
     if !status.is_success() {
-        let body = response.text().await.unwrap_or_default();
-        return Err(format!("Gemini API error {}: {}", status, body));
+        let text = response.text().await.unwrap_or_default();
+        return Err(AppError::from_status(
+            status,
+            format!("API error {}: {}", status, text),
+        ));
     }
-
-    let res_json: serde_json::Value = response
+    response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
-
-    parse_gemini_response(res_json)
+        .map_err(|e| AppError::internal(format!("Failed to parse response: {}", e)))
 }
 
-/// Gemini API call using OAuth (Code Assist API)
-async fn chat_completion_gemini_code_assist(
-    access_token: &str,
-    model_id: &str,
-    inner_request_body: serde_json::Value,
-) -> Result<ChatResponse, String> {
-    use crate::oauth::google::{
-        build_code_assist_url, ensure_project_id, unwrap_code_assist_response,
-        wrap_request_for_code_assist,
-    };
+/// A tool call as shown with the response
+fn record(call: &chat_tools::ToolCall, output: String) -> ToolCallRecord {
+    ToolCallRecord {
+        name: call.name.clone(),
+        arguments: call.arguments.to_string(),
+        output,
+    }
+}
 
-    // Get or provision project ID
-    let project_id = ensure_project_id(access_token)
-        .await
-        .map_err(|e| format!("Failed to get project ID: {}", e))?;
+/// The message text followed by the text parts of its attached files
+fn text_parts(msg: &ChatMessage) -> Vec<String> {
+    let mut parts = vec![msg.content.clone()];
+    for file in msg.files.iter().flatten() {
+        parts.extend(file.text_parts());
+    }
+    parts
+}
 
-    // Build the Code Assist URL
-    let url = build_code_assist_url("generateContent", false);
+/// Add a round's token usage to the total of a tool-calling exchange
+fn add_usage(total: &mut Option<TokenUsage>, usage: Option<TokenUsage>) {
+    let Some(usage) = usage else { return };
+    let total = total.get_or_insert_with(TokenUsage::default);
+    total.prompt_tokens += usage.prompt_tokens;
+    total.completion_tokens += usage.completion_tokens;
+    total.total_tokens += usage.total_tokens;
+}
 
-    // Wrap the request for Code Assist
-    let request_body = wrap_request_for_code_assist(&project_id, model_id, inner_request_body);
+/// Read a local text, Markdown or PDF file to attach to a chat message. The text is
+/// extracted and split into chunks here, and sent with the message's `files`.
+#[tauri::command]
+#[specta::specta]
+pub async fn attach_chat_file(path: String) -> Result<FileAttachment, AppError> {
+    let attachment =
+        tokio::task::spawn_blocking(move || file_attachments::load(std::path::Path::new(&path)))
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to read file: {}", e)))??;
+    Ok(attachment)
+}
 
-    log::info!(
-        "Code Assist API request:\n  URL: {}\n  Project: {}\n  Model: {}\n  Body: {}",
-        url,
-        project_id,
-        model_id,
-        serde_json::to_string_pretty(&request_body).unwrap_or_default()
-    );
+/// Native Gemini API call, for system instructions, search grounding and function
+/// calling. Gemini can't combine search with function declarations, so grounding is
+/// dropped when tools are enabled.
+#[allow(clippy::too_many_arguments)]
+async fn chat_completion_gemini_native(
+    app: &AppHandle,
+    provider: &crate::settings::LLMProvider,
+    api_key: &str,
+    model: &crate::settings::LLMModel,
+    messages: Vec<ChatMessage>,
+    enable_grounding: bool,
+    enable_tools: bool,
+    stream_id: Option<String>,
+) -> Result<ChatResponse, AppError> {
+    let enable_grounding = enable_grounding && !enable_tools;
+    let mut contents = Vec::new();
 
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("User-Agent", "google-api-nodejs-client/9.15.1")
-        .header("X-Goog-Api-Client", "gl-node/22.17.0")
-        .header(
-            "Client-Metadata",
-            "ideType=IDE_UNSPECIFIED,platform=PLATFORM_UNSPECIFIED,pluginType=GEMINI",
-        )
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Code Assist request failed: {}", e))?;
+    // When grounding is enabled, add an instruction to always use Google Search
+    if enable_grounding {
+        contents.push(gemini_content(
+            "user",
+            "IMPORTANT: You MUST use Google Search to find current, accurate information before responding. Always search the web first.",
+            &[],
+        ));
+        contents.push(gemini_content(
+            "model",
+            "Understood. I will use Google Search to find current information.",
+            &[],
+        ));
+    }
 
-    let status = response.status();
-    if !status.is_success() {
-        let body = response.text().await.unwrap_or_default();
-        log::error!("Code Assist API error: status={}, body={}", status, body);
-        return Err(format!("Code Assist API error {}: {}", status, body));
+    for msg in messages {
+        let role = if msg.role == "assistant" {
+            "model"
+        } else {
+            "user"
+        };
+        let mut content = gemini_content(
+            role,
+            &msg.content,
+            msg.images.as_deref().unwrap_or_default(),
+        );
+        if let Some(parts) = content["parts"].as_array_mut() {
+            let files = text_parts(&msg).into_iter().skip(1);
+            parts.extend(files.map(|text| serde_json::json!({ "text": text })));
+        }
+        contents.push(content);
     }
 
-    let res_json: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse Code Assist response: {}", e))?;
+    let system_prompt = get_system_prompt_content(app);
+    let mut request_body = gemini_request_body(system_prompt.as_deref(), contents);
+    if enable_grounding {
+        request_body["tools"] = serde_json::json!([{ "google_search": {} }]);
+    }
+    if enable_tools {
+        request_body["tools"] = serde_json::json!([chat_tools::gemini_tools()]);
+    }
+    crate::gemini::apply_model_options(&mut request_body, model);
+
+    let on_delta = |delta: &str| {
+        if let Some(stream_id) = &stream_id {
+            let _ = app.emit(
+                "chat-chunk",
+                ChatChunk {
+                    stream_id: stream_id.clone(),
+                    delta: delta.to_string(),
+                },
+            );
+        }
+    };
+    let mut usage = None;
+    let mut tool_calls = Vec::new();
+    for _ in 0..MAX_TOOL_ROUNDS {
+        let response = gemini_generate_content(
+            provider,
+            api_key,
+            &model.model_id,
+            request_body.clone(),
+            on_delta,
+        )
+        .await?;
+        add_usage(&mut usage, response.usage);
 
-    // Unwrap the Code Assist response wrapper
-    let unwrapped = unwrap_code_assist_response(res_json)
-        .map_err(|e| format!("Failed to unwrap Code Assist response: {}", e))?;
+        let calls = chat_tools::parse_gemini_function_calls(&response.function_calls);
+        if calls.is_empty() {
+            if response.text.is_empty() {
+                return Err(AppError::internal("No text in Gemini response"));
+            }
+            return Ok(ChatResponse {
+                content: response.text,
+                grounding_metadata: response
+                    .grounding_metadata
+                    .as_ref()
+                    .and_then(parse_grounding_metadata),
+                usage,
+                tool_calls,
+            });
+        }
 
-    parse_gemini_response(unwrapped)
+        let mut results = Vec::new();
+        for call in &calls {
+            let output = chat_tools::execute(app, call).await;
+            results.push(serde_json::json!({
+                "functionResponse": {
+                    "name": call.name,
+                    "response": { "output": output },
+                }
+            }));
+            tool_calls.push(record(call, output));
+        }
+        if let Some(contents) = request_body["contents"].as_array_mut() {
+            contents.push(serde_json::json!({ "role": "model", "parts": response.function_calls }));
+            contents.push(serde_json::json!({ "role": "user", "parts": results }));
+        }
+    }
+    Err(AppError::internal(format!(
+        "The model was still calling tools after {} rounds",
+        MAX_TOOL_ROUNDS
+    )))
 }
 
-/// Parse a standard Gemini API response (works for both API key and Code Assist)
-fn parse_gemini_response(res_json: serde_json::Value) -> Result<ChatResponse, String> {
-    let candidate = &res_json["candidates"][0];
-    let content = candidate["content"]["parts"][0]["text"]
-        .as_str()
-        .ok_or_else(|| "No text in Gemini response".to_string())?
-        .to_string();
-
-    // Parse grounding metadata
-    let mut grounding_metadata = None;
-    if let Some(grounding_info) = candidate.get("groundingMetadata") {
-        let mut chunks = Vec::new();
-        if let Some(grounding_chunks) = grounding_info["groundingChunks"].as_array() {
-            for chunk in grounding_chunks {
-                if let Some(web) = chunk.get("web") {
-                    let uri = web["uri"].as_str().map(|s| s.to_string());
-                    let title = web["title"].as_str().map(|s| s.to_string());
-                    log::info!("Grounding chunk - uri: {:?}, title: {:?}", uri, title);
-                    chunks.push(GroundingChunk { uri, title });
-                }
+/// Sources and the search suggestion widget from a Gemini `groundingMetadata`
+fn parse_grounding_metadata(grounding_info: &serde_json::Value) -> Option<GroundingMetadata> {
+    let mut chunks = Vec::new();
+    if let Some(grounding_chunks) = grounding_info["groundingChunks"].as_array() {
+        for chunk in grounding_chunks {
+            if let Some(web) = chunk.get("web") {
+                let uri = web["uri"].as_str().map(|s| s.to_string());
+                let title = web["title"].as_str().map(|s| s.to_string());
+                log::info!("Grounding chunk - uri: {:?}, title: {:?}", uri, title);
+                chunks.push(GroundingChunk { uri, title });
             }
         }
+    }
 
-        let search_entry_point = grounding_info["searchEntryPoint"]["renderedContent"]
-            .as_str()
-            .map(|s| s.to_string());
+    let search_entry_point = grounding_info["searchEntryPoint"]["renderedContent"]
+        .as_str()
+        .map(|s| s.to_string());
 
-        if !chunks.is_empty() || search_entry_point.is_some() {
-            grounding_metadata = Some(GroundingMetadata {
-                search_entry_point,
-                chunks,
-            });
-        }
+    if chunks.is_empty() && search_entry_point.is_none() {
+        return None;
     }
-
-    Ok(ChatResponse {
-        content,
-        grounding_metadata,
+    Some(GroundingMetadata {
+        search_entry_point,
+        chunks,
     })
 }
 
-/// Native Anthropic Messages API call with web search support
+/// Native Anthropic Messages API call with web search and tool use support
 async fn chat_completion_anthropic_native(
     app: &AppHandle,
     provider: &crate::settings::LLMProvider,
     api_key: &str,
-    model_id: &str,
+    model: &crate::settings::LLMModel,
     messages: Vec<ChatMessage>,
     enable_grounding: bool,
-) -> Result<ChatResponse, String> {
+    enable_tools: bool,
+) -> Result<ChatResponse, AppError> {
     let url = format!("{}/messages", provider.base_url);
 
     // Build messages array for Anthropic format
@@ -489,11 +636,13 @@ async fn chat_completion_anthropic_native(
             }
         }
 
-        // Add text content
-        content_parts.push(serde_json::json!({
-            "type": "text",
-            "text": msg.content
-        }));
+        // Add text content and attached files
+        for text in text_parts(msg) {
+            content_parts.push(serde_json::json!({
+                "type": "text",
+                "text": text
+            }));
+        }
 
         anthropic_messages.push(serde_json::json!({
             "role": role,
@@ -503,7 +652,7 @@ async fn chat_completion_anthropic_native(
 
     // Build request body
     let mut request_body = serde_json::json!({
-        "model": model_id,
+        "model": model.model_id,
         "max_tokens": 8192,
         "messages": anthropic_messages
     });
@@ -514,53 +663,115 @@ async fn chat_completion_anthropic_native(
     }
 
     // Add web search tool if grounding is enabled
+    let mut tools = Vec::new();
     if enable_grounding {
-        request_body["tools"] = serde_json::json!([{
+        tools.push(serde_json::json!({
             "type": "web_search_20250305",
             "name": "web_search"
-        }]);
+        }));
         // Force the model to always use web search when grounding is enabled
         request_body["tool_choice"] = serde_json::json!({
             "type": "tool",
             "name": "web_search"
         });
     }
+    if enable_tools {
+        tools.extend(chat_tools::anthropic_tools());
+    }
+    if !tools.is_empty() {
+        request_body["tools"] = serde_json::json!(tools);
+    }
 
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .header("x-api-key", api_key)
-        .header("anthropic-version", "2023-06-01")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
-
-    let status = response.status();
-    if !status.is_success() {
-        let body = response.text().await.unwrap_or_default();
-        return Err(format!("Anthropic API error {}: {}", status, body));
+    // Extended thinking, which can't be combined with a forced tool choice
+    let reasoning_fields = crate::reasoning::body_fields(provider, model);
+    if reasoning_fields.contains_key("thinking") {
+        if let Some(body) = request_body.as_object_mut() {
+            body.remove("tool_choice");
+            body.extend(reasoning_fields);
+        }
     }
 
-    let res_json: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let client = reqwest::Client::new();
+    let mut usage = None;
+    let mut tool_calls = Vec::new();
+    // Blocks of the rounds that called tools, kept for their search citations
+    let mut earlier_blocks = Vec::new();
+    let mut rounds = 0;
+    let content_blocks = loop {
+        let response = client
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("x-api-key", api_key)
+            .header("anthropic-version", "2023-06-01")
+            .headers(custom_headers(provider, api_key)?)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| AppError::from_request(&e, format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::from_status(
+                status,
+                format!("Anthropic API error {}: {}", status, body),
+            ));
+        }
 
-    // Extract text content from response
-    // Anthropic returns content as an array of blocks
-    let content_blocks = res_json["content"]
-        .as_array()
-        .ok_or_else(|| "No content in Anthropic response".to_string())?;
+        let res_json: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+        add_usage(&mut usage, TokenUsage::from_json(&res_json["usage"]));
+
+        // Anthropic returns content as an array of blocks
+        let content_blocks = res_json["content"]
+            .as_array()
+            .cloned()
+            .ok_or_else(|| "No content in Anthropic response".to_string())?;
+        let calls = chat_tools::parse_anthropic_tool_calls(&content_blocks);
+        if calls.is_empty() {
+            break content_blocks;
+        }
+        rounds += 1;
+        if rounds >= MAX_TOOL_ROUNDS {
+            return Err(AppError::internal(format!(
+                "The model was still calling tools after {} rounds",
+                MAX_TOOL_ROUNDS
+            )));
+        }
 
+        let mut results = Vec::new();
+        for call in &calls {
+            let output = chat_tools::execute(app, call).await;
+            results.push(serde_json::json!({
+                "type": "tool_result",
+                "tool_use_id": call.id,
+                "content": output,
+            }));
+            tool_calls.push(record(call, output));
+        }
+        if let Some(messages) = request_body["messages"].as_array_mut() {
+            messages.push(serde_json::json!({ "role": "assistant", "content": content_blocks }));
+            messages.push(serde_json::json!({ "role": "user", "content": results }));
+        }
+        // Search has been forced once already; now the model has to be free to answer
+        if let Some(body) = request_body.as_object_mut() {
+            body.remove("tool_choice");
+        }
+        earlier_blocks.extend(content_blocks);
+    };
+
+    // Extract text content from the final response
     let mut text_content = String::new();
     let mut grounding_chunks = Vec::new();
 
-    for block in content_blocks {
+    for (index, block) in earlier_blocks.iter().chain(&content_blocks).enumerate() {
+        // Text written before calling tools is only a preamble to the answer
+        let is_final = index >= earlier_blocks.len();
         match block["type"].as_str() {
             Some("text") => {
-                if let Some(text) = block["text"].as_str() {
+                if let Some(text) = block["text"].as_str().filter(|_| is_final) {
                     text_content.push_str(text);
                 }
                 // Check for citations in this text block
@@ -618,6 +829,8 @@ async fn chat_completion_anthropic_native(
     Ok(ChatResponse {
         content: text_content,
         grounding_metadata,
+        usage,
+        tool_calls,
     })
 }
 
@@ -627,8 +840,9 @@ async fn chat_completion_openai_codex(
     app: &AppHandle,
     access_token: &str,
     model_id: &str,
+    reasoning_effort: Option<&str>,
     messages: Vec<ChatMessage>,
-) -> Result<ChatResponse, String> {
+) -> Result<ChatResponse, AppError> {
     use crate::oauth::openai::API_ENDPOINT;
     use crate::oauth::tokens::load_tokens;
     use crate::oauth::OAuthProvider;
@@ -641,9 +855,12 @@ async fn chat_completion_openai_codex(
         .chatgpt_account_id
         .ok_or_else(|| "No ChatGPT account ID found in tokens".to_string())?;
 
-    // Get reasoning effort from settings
+    // Get reasoning effort from the model, falling back to the settings
     let settings = get_settings(app);
-    let reasoning_effort = get_valid_reasoning_effort(&settings.openai_reasoning_effort, model_id);
+    let reasoning_effort = get_valid_reasoning_effort(
+        reasoning_effort.unwrap_or(&settings.openai_reasoning_effort),
+        model_id,
+    );
 
     // Normalize model name for Codex API
     let normalized_model = normalize_codex_model(model_id);
@@ -681,10 +898,12 @@ async fn chat_completion_openai_codex(
             "input_text"
         };
 
-        content_parts.push(serde_json::json!({
-            "type": content_type,
-            "text": msg.content
-        }));
+        for text in text_parts(&msg) {
+            content_parts.push(serde_json::json!({
+                "type": content_type,
+                "text": text
+            }));
+        }
 
         // Add images if present
         if let Some(images) = msg.images {
@@ -746,13 +965,13 @@ async fn chat_completion_openai_codex(
         .json(&request_body)
         .send()
         .await
-        .map_err(|e| format!("Codex API request failed: {}", e))?;
+        .map_err(|e| AppError::from_request(&e, format!("Codex API request failed: {}", e)))?;
 
     let status = response.status();
     let response_text = response
         .text()
         .await
-        .map_err(|e| format!("Failed to read Codex response: {}", e))?;
+        .map_err(|e| AppError::from_request(&e, format!("Failed to read Codex response: {}", e)))?;
 
     log::info!(
         "Codex API response: status={}, body_length={}",
@@ -767,12 +986,15 @@ async fn chat_completion_openai_codex(
             || response_text.contains("usage_not_included")
             || response_text.contains("rate_limit_exceeded")
         {
-            return Err(
-                "ChatGPT usage limit reached. Please try again later or check your subscription."
-                    .to_string(),
-            );
+            return Err(AppError::new(
+                ErrorCode::RateLimited,
+                "ChatGPT usage limit reached. Please try again later or check your subscription.",
+            ));
         }
-        return Err(format!("Codex API error {}: {}", status, response_text));
+        return Err(AppError::from_status(
+            status,
+            format!("Codex API error {}: {}", status, response_text),
+        ));
     }
 
     // Parse SSE stream to extract final response
@@ -805,12 +1027,14 @@ async fn chat_completion_openai_codex(
     }
 
     if text_content.is_empty() {
-        return Err("No text content in Codex response".to_string());
+        return Err(AppError::internal("No text content in Codex response"));
     }
 
     Ok(ChatResponse {
         content: text_content,
         grounding_metadata: None,
+        usage: TokenUsage::from_json(&res_json["usage"]),
+        tool_calls: Vec::new(),
     })
 }
 
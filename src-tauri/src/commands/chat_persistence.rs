@@ -1,5 +1,8 @@
 use crate::commands::chat::{chat_completion, ChatMessage, ChatResponse};
+use crate::errors::AppError;
 use crate::managers::chat_persistence::{ChatPersistenceManager, ChatSummary, SavedChat};
+use crate::settings::get_settings;
+use crate::titles::{clean_title, heuristic_title, truncate_chars};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 
@@ -9,11 +12,12 @@ pub async fn save_chat(
     app: AppHandle,
     title: Option<String>,
     messages: Vec<ChatMessage>,
-) -> Result<i64, String> {
+    model_id: Option<String>,
+) -> Result<i64, AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
     manager
-        .save_chat(title, messages)
-        .map_err(|e| e.to_string())
+        .save_chat(title, messages, model_id)
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -22,46 +26,70 @@ pub async fn update_chat(
     app: AppHandle,
     id: i64,
     messages: Vec<ChatMessage>,
-) -> Result<(), String> {
+    model_id: Option<String>,
+) -> Result<(), AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
-    manager.update_chat(id, messages).map_err(|e| e.to_string())
+    manager
+        .update_chat(id, messages, model_id)
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn get_chat(app: AppHandle, id: i64) -> Result<Option<SavedChat>, String> {
+pub async fn get_chat(app: AppHandle, id: i64) -> Result<Option<SavedChat>, AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
-    manager.get_chat(id).map_err(|e| e.to_string())
+    manager.get_chat(id).map_err(AppError::from)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn list_saved_chats(app: AppHandle) -> Result<Vec<ChatSummary>, String> {
+pub async fn list_saved_chats(app: AppHandle) -> Result<Vec<ChatSummary>, AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
-    manager.list_chats().map_err(|e| e.to_string())
+    manager.list_chats().map_err(AppError::from)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_saved_chat(app: AppHandle, id: i64) -> Result<(), String> {
+pub async fn delete_saved_chat(app: AppHandle, id: i64) -> Result<(), AppError> {
+    let manager = app.state::<Arc<ChatPersistenceManager>>();
+    manager.delete_chat(id).map_err(AppError::from)
+}
+
+/// Save the start of a conversation as a new chat and open it in a window, so
+/// the fork outlives the window it came from
+#[tauri::command]
+#[specta::specta]
+pub async fn fork_chat(
+    app: AppHandle,
+    title: Option<String>,
+    messages: Vec<ChatMessage>,
+    model_id: Option<String>,
+) -> Result<String, AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
-    manager.delete_chat(id).map_err(|e| e.to_string())
+    let title = title.map(|title| format!("{} (fork)", title));
+    let id = manager
+        .save_chat(title, messages, model_id)
+        .map_err(|e| e.to_string())?;
+    crate::commands::open_saved_chat(app, id)
 }
 
+/// Title a new chat from its first exchange, with the title model when one is set
+/// and the chat model otherwise. Falls back to a title made from the user's message
+/// when the model can't be reached.
 #[tauri::command]
 #[specta::specta]
 pub async fn generate_chat_title(
     app: AppHandle,
     user_message: String,
     assistant_response: String,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let prompt = format!(
         "Generate a concise 3-5 word title for this conversation based on the following exchange.\n\
         Respond with ONLY the title, no quotes, no labels, no punctuation.\n\n\
         User: {}\n\
         Assistant: {}",
-        if user_message.len() > 500 { &user_message[..500] } else { &user_message },
-        if assistant_response.len() > 500 { &assistant_response[..500] } else { &assistant_response }
+        truncate_chars(&user_message, 500),
+        truncate_chars(&assistant_response, 500)
     );
 
     let messages = vec![
@@ -69,25 +97,39 @@ pub async fn generate_chat_title(
             role: "system".to_string(),
             content: "You are a helpful assistant that generates concise chat titles.".to_string(),
             images: None,
+            files: None,
         },
         ChatMessage {
             role: "user".to_string(),
             content: prompt,
             images: None,
+            files: None,
         },
     ];
 
-    // Use existing chat_completion logic but with our custom prompt
-    // We pass None for model_id to use the default chat model
-    let response: ChatResponse = chat_completion(app.clone(), messages, None, false).await?;
+    // Use existing chat_completion logic but with our custom prompt. With no title
+    // model, None makes it use the default chat model
+    let model_id = get_settings(&app).default_title_model_id;
+    let response: Result<ChatResponse, AppError> =
+        chat_completion(app.clone(), messages, model_id, false, false, None).await;
 
-    let title = response.content.trim().trim_matches('"').to_string();
-    Ok(title)
+    match response.map(|response| clean_title(&response.content)) {
+        Ok(Some(title)) => Ok(title),
+        Ok(None) => heuristic_title(&user_message)
+            .ok_or_else(|| AppError::internal("The model returned an empty title")),
+        Err(e) => {
+            log::warn!(
+                "Failed to generate a chat title, using the message instead: {}",
+                e
+            );
+            heuristic_title(&user_message).ok_or(e)
+        }
+    }
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn update_chat_title(app: AppHandle, id: i64, title: String) -> Result<(), String> {
+pub async fn update_chat_title(app: AppHandle, id: i64, title: String) -> Result<(), AppError> {
     let manager = app.state::<Arc<ChatPersistenceManager>>();
-    manager.update_title(id, title).map_err(|e| e.to_string())
+    manager.update_title(id, title).map_err(AppError::from)
 }
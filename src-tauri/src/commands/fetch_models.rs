@@ -5,9 +5,13 @@
 // - Gemini: GET /v1beta/models
 // - Anthropic: Mock (no API available)
 
+use crate::errors::{AppError, API_KEY_HINT};
 use crate::llm_client::get_api_key_for_provider;
-use crate::settings::{self, AuthMethod, LLMModel, LLMProvider};
+use crate::settings::{
+    self, AuthMethod, GeminiOptions, LLMModel, LLMProvider, OpenRouterRouting, ReasoningOptions,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::AppHandle;
 
 /// Fetched model from an API (normalized format)
@@ -50,7 +54,7 @@ struct GeminiModel {
 /// Returns the complete updated list of models
 #[tauri::command]
 #[specta::specta]
-pub async fn refresh_all_models(app: AppHandle) -> Result<Vec<LLMModel>, String> {
+pub async fn refresh_all_models(app: AppHandle) -> Result<Vec<LLMModel>, AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Log all providers for debugging
@@ -96,11 +100,41 @@ pub async fn refresh_all_models(app: AppHandle) -> Result<Vec<LLMModel>, String>
     );
 
     if providers_to_fetch.is_empty() {
-        return Err("No providers with API keys configured".to_string());
+        return Err(
+            AppError::not_configured("No providers with API keys configured")
+                .with_hint(API_KEY_HINT),
+        );
     }
 
-    // Remove existing models for providers we're refreshing
+    // Remove existing models for providers we're refreshing, keeping user-entered prices
+    // and routing options
     let provider_ids: Vec<String> = providers_to_fetch.iter().map(|p| p.id.clone()).collect();
+    let custom_prices: HashMap<String, (Option<f64>, Option<f64>)> = settings
+        .llm_models
+        .iter()
+        .filter(|m| m.input_price_per_million.is_some() || m.output_price_per_million.is_some())
+        .map(|m| {
+            (
+                m.id.clone(),
+                (m.input_price_per_million, m.output_price_per_million),
+            )
+        })
+        .collect();
+    let routings: HashMap<String, OpenRouterRouting> = settings
+        .llm_models
+        .iter()
+        .filter_map(|m| Some((m.id.clone(), m.openrouter_routing.clone()?)))
+        .collect();
+    let saved_gemini_options: HashMap<String, GeminiOptions> = settings
+        .llm_models
+        .iter()
+        .filter_map(|m| Some((m.id.clone(), m.gemini_options.clone()?)))
+        .collect();
+    let saved_reasoning: HashMap<String, ReasoningOptions> = settings
+        .llm_models
+        .iter()
+        .filter_map(|m| Some((m.id.clone(), m.reasoning.clone()?)))
+        .collect();
     settings
         .llm_models
         .retain(|m| !provider_ids.contains(&m.provider_id));
@@ -121,13 +155,24 @@ pub async fn refresh_all_models(app: AppHandle) -> Result<Vec<LLMModel>, String>
                 fm.display_name
             };
 
+            let id = format!("{}-{}", provider.id, fm.model_id.replace("/", "-"));
+            let (input_price_per_million, output_price_per_million) =
+                custom_prices.get(&id).copied().unwrap_or((None, None));
+            let openrouter_routing = routings.get(&id).cloned();
+            let gemini_options = saved_gemini_options.get(&id).cloned();
+            let reasoning = saved_reasoning.get(&id).cloned();
             let model = LLMModel {
-                id: format!("{}-{}", provider.id, fm.model_id.replace("/", "-")),
+                id,
                 provider_id: provider.id.clone(),
                 model_id: fm.model_id,
                 display_name,
                 supports_vision: fm.supports_vision,
                 enabled: true, // Enable all fetched models by default
+                input_price_per_million,
+                output_price_per_million,
+                openrouter_routing,
+                gemini_options,
+                reasoning,
             };
             settings.llm_models.push(model);
         }
@@ -140,7 +185,7 @@ pub async fn refresh_all_models(app: AppHandle) -> Result<Vec<LLMModel>, String>
 }
 
 /// Fetch models for a single provider (internal helper)
-async fn fetch_models_for_provider(provider: &LLMProvider) -> Result<Vec<FetchedModel>, String> {
+async fn fetch_models_for_provider(provider: &LLMProvider) -> Result<Vec<FetchedModel>, AppError> {
     log::info!(
         "fetch_models_for_provider: starting for provider id={}, name={}, auth_method={:?}",
         provider.id,
@@ -178,7 +223,7 @@ async fn fetch_models_for_provider(provider: &LLMProvider) -> Result<Vec<Fetched
         }
         Err(e) => {
             log::error!("fetch_models_for_provider: failed to get API key: {}", e);
-            return Err(e);
+            return Err(e.into());
         }
     };
 
@@ -225,7 +270,7 @@ async fn fetch_models_for_provider(provider: &LLMProvider) -> Result<Vec<Fetched
 }
 
 /// Fetch models from OpenAI API
-async fn fetch_openai_models(api_key: &str, base_url: &str) -> Result<Vec<FetchedModel>, String> {
+async fn fetch_openai_models(api_key: &str, base_url: &str) -> Result<Vec<FetchedModel>, AppError> {
     let client = reqwest::Client::new();
     let url = format!("{}/models", base_url);
 
@@ -234,18 +279,21 @@ async fn fetch_openai_models(api_key: &str, base_url: &str) -> Result<Vec<Fetche
         .header("Authorization", format!("Bearer {}", api_key))
         .send()
         .await
-        .map_err(|e| format!("Failed to fetch OpenAI models: {}", e))?;
+        .map_err(|e| AppError::from_request(&e, format!("Failed to fetch OpenAI models: {}", e)))?;
 
     if !response.status().is_success() {
         let status = response.status();
         let body = response.text().await.unwrap_or_default();
-        return Err(format!("OpenAI API error {}: {}", status, body));
+        return Err(AppError::from_status(
+            status,
+            format!("OpenAI API error {}: {}", status, body),
+        ));
     }
 
     let data: OpenAIModelsResponse = response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse OpenAI response: {}", e))?;
+        .map_err(|e| AppError::internal(format!("Failed to parse OpenAI response: {}", e)))?;
 
     // Filter to only chat models (gpt-*, o1-*, o3-*, chatgpt-*)
     let models: Vec<FetchedModel> = data
@@ -275,7 +323,7 @@ async fn fetch_openai_models(api_key: &str, base_url: &str) -> Result<Vec<Fetche
 async fn fetch_gemini_models_oauth_aware(
     api_key_or_token: &str,
     use_oauth: bool,
-) -> Result<Vec<FetchedModel>, String> {
+) -> Result<Vec<FetchedModel>, AppError> {
     log::info!(
         "fetch_gemini_models_oauth_aware: starting (use_oauth={}, token_length={})",
         use_oauth,
@@ -321,7 +369,10 @@ async fn fetch_gemini_models_oauth_aware(
         }
         Err(e) => {
             log::error!("fetch_gemini_models_oauth_aware: request failed: {}", e);
-            return Err(format!("Failed to fetch Gemini models: {}", e));
+            return Err(AppError::from_request(
+                &e,
+                format!("Failed to fetch Gemini models: {}", e),
+            ));
         }
     };
 
@@ -333,7 +384,10 @@ async fn fetch_gemini_models_oauth_aware(
             status,
             body
         );
-        return Err(format!("Gemini API error {}: {}", status, body));
+        return Err(AppError::from_status(
+            status,
+            format!("Gemini API error {}: {}", status, body),
+        ));
     }
 
     let body_text = response.text().await.map_err(|e| {
@@ -341,7 +395,7 @@ async fn fetch_gemini_models_oauth_aware(
             "fetch_gemini_models_oauth_aware: failed to read response body: {}",
             e
         );
-        format!("Failed to read Gemini response: {}", e)
+        AppError::from_request(&e, format!("Failed to read Gemini response: {}", e))
     })?;
 
     log::debug!(
@@ -354,7 +408,7 @@ async fn fetch_gemini_models_oauth_aware(
             "fetch_gemini_models_oauth_aware: failed to parse response: {}",
             e
         );
-        format!("Failed to parse Gemini response: {}", e)
+        AppError::internal(format!("Failed to parse Gemini response: {}", e))
     })?;
 
     log::info!(
@@ -403,7 +457,7 @@ async fn fetch_gemini_models_oauth_aware(
 }
 
 /// Fetch models from Gemini API (legacy, API key only)
-async fn fetch_gemini_models(api_key: &str) -> Result<Vec<FetchedModel>, String> {
+async fn fetch_gemini_models(api_key: &str) -> Result<Vec<FetchedModel>, AppError> {
     fetch_gemini_models_oauth_aware(api_key, false).await
 }
 
@@ -1,17 +1,26 @@
+use crate::edits::EditAlignment;
+use crate::errors::AppError;
+use crate::history_export::{self, HistoryExportFormat, HistoryExportRange};
+use crate::history_share::{self, ShareFormat};
+use crate::managers::embedding::EmbeddingManager;
 use crate::managers::history::{HistoryEntry, HistoryManager};
+use crate::managers::stt_engine::WordTiming;
+use crate::semantic_search::{self, SemanticMatch};
+use crate::summaries::{self, EntrySummary, SummaryStyle};
 use std::sync::Arc;
 use tauri::{AppHandle, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
 
 #[tauri::command]
 #[specta::specta]
 pub async fn get_history_entries(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
-) -> Result<Vec<HistoryEntry>, String> {
+) -> Result<Vec<HistoryEntry>, AppError> {
     history_manager
         .get_history_entries()
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -20,11 +29,11 @@ pub async fn toggle_history_entry_saved(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     id: i64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     history_manager
         .toggle_saved_status(id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -33,24 +42,163 @@ pub async fn get_audio_file_path(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     file_name: String,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     let path = history_manager.get_audio_file_path(&file_name);
     path.to_str()
-        .ok_or_else(|| "Invalid file path".to_string())
+        .ok_or_else(|| AppError::internal("Invalid file path"))
         .map(|s| s.to_string())
 }
 
+/// Word timings of a history entry's transcription, for highlighting during playback.
+/// Empty for entries transcribed before timings were recorded.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_word_timings(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Vec<WordTiming>, AppError> {
+    history_manager
+        .get_word_timings(id)
+        .await
+        .map_err(AppError::from)
+}
+
+/// How a history entry's refinement differs from its raw transcription, for the
+/// track-changes view. None unless the entry was refined with show edits enabled.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_edits(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Option<EditAlignment>, AppError> {
+    history_manager
+        .get_edit_alignment(id)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Write the entries in `range` to `path`, oldest first. Returns how many were exported.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    format: HistoryExportFormat,
+    range: HistoryExportRange,
+    path: String,
+) -> Result<usize, AppError> {
+    let mut entries: Vec<HistoryEntry> = history_manager
+        .get_history_entries()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|entry| range.contains(entry))
+        .collect();
+    entries.reverse();
+
+    let contents = history_export::render(&entries, format)?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok(entries.len())
+}
+
+/// A snippet of one entry for bug reports or team chats, with emails, phone numbers
+/// and similar details redacted when `redact` is set. Written to `path` when one is
+/// given and copied to the clipboard otherwise. Returns the snippet.
+#[tauri::command]
+#[specta::specta]
+pub async fn share_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    redact: bool,
+    format: ShareFormat,
+    path: Option<String>,
+) -> Result<String, AppError> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::not_found(format!("History entry {} not found", id)))?;
+    let snippet = history_share::render(&entry, redact, format);
+
+    if let Some(path) = path {
+        std::fs::write(&path, &snippet).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        return Ok(snippet);
+    }
+
+    let clipboard = app.clipboard();
+    let copied = match format {
+        // Rich text where the target app takes it, the plain snippet elsewhere
+        ShareFormat::Html => clipboard.write_html(
+            snippet.clone(),
+            Some(history_share::render(&entry, redact, ShareFormat::Text)),
+        ),
+        ShareFormat::Text => clipboard.write_text(snippet.clone()),
+    };
+    copied.map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+    Ok(snippet)
+}
+
+/// Summarize an entry's transcription in `style` and store the summary with it,
+/// replacing any earlier one. Meant for long recordings like meetings and lectures.
+#[tauri::command]
+#[specta::specta]
+pub async fn summarize_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    style: SummaryStyle,
+) -> Result<EntrySummary, AppError> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| AppError::not_found(format!("History entry {} not found", id)))?;
+
+    let summary = summaries::summarize(&app, &entry.transcription_text, style).await?;
+    history_manager
+        .save_summary(id, &summary)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(summary)
+}
+
+/// Results returned by a semantic search when no limit is given
+const DEFAULT_SEARCH_LIMIT: u32 = 20;
+
+/// History entries closest in meaning to `query`, closest first. Entries not yet
+/// embedded are embedded first, which takes a while on the first search.
+#[tauri::command]
+#[specta::specta]
+pub async fn semantic_search_history(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    embedding_manager: State<'_, Arc<EmbeddingManager>>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<SemanticMatch>, AppError> {
+    semantic_search::search(
+        history_manager.inner().clone(),
+        embedding_manager.inner().clone(),
+        &query,
+        limit.unwrap_or(DEFAULT_SEARCH_LIMIT) as usize,
+    )
+    .await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn delete_history_entry(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     id: i64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     history_manager
         .delete_entry(id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -59,7 +207,7 @@ pub async fn update_history_limit(
     app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     limit: usize,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = crate::settings::get_settings(&app);
     settings.history_limit = limit;
     crate::settings::write_settings(&app, settings);
@@ -77,7 +225,7 @@ pub async fn update_recording_retention_period(
     app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     period: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     use crate::settings::RecordingRetentionPeriod;
 
     let retention_period = match period.as_str() {
@@ -86,7 +234,12 @@ pub async fn update_recording_retention_period(
         "days3" => RecordingRetentionPeriod::Days3,
         "weeks2" => RecordingRetentionPeriod::Weeks2,
         "months3" => RecordingRetentionPeriod::Months3,
-        _ => return Err(format!("Invalid retention period: {}", period)),
+        _ => {
+            return Err(AppError::invalid_input(format!(
+                "Invalid retention period: {}",
+                period
+            )))
+        }
     };
 
     let mut settings = crate::settings::get_settings(&app);
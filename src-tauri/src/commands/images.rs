@@ -0,0 +1,236 @@
+//! Image generation for the chat window, through OpenAI's images API or Gemini's
+//! image models. Images are saved to app data so chats can reference them by path.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::errors::AppError;
+use crate::llm_client::{
+    custom_headers, gemini_content, gemini_generate_content, gemini_request_body,
+    get_api_key_for_provider_async, is_gemini, TokenUsage,
+};
+use crate::managers::usage::record_usage;
+use crate::settings::{get_settings, LLMModel, LLMProvider};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct GeneratedImages {
+    /// Paths of the saved image files
+    pub paths: Vec<String>,
+    /// Text the model returned alongside the images, if any
+    pub text: Option<String>,
+}
+
+/// Emitted as `images-generated` once generated images are saved
+#[derive(Serialize, Clone)]
+struct ImagesGenerated {
+    paths: Vec<String>,
+}
+
+/// A generated image before it's written to disk
+struct ImageData {
+    mime_type: String,
+    bytes: Vec<u8>,
+}
+
+/// Generate images from a prompt with an image model
+///
+/// # Arguments
+/// * `model_id` - Optional model ID to use. Falls back to `default_chat_model_id` if not provided.
+/// * `images` - Base64 PNG images to edit or draw from (Gemini only)
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_image(
+    app: AppHandle,
+    prompt: String,
+    model_id: Option<String>,
+    images: Option<Vec<String>>,
+) -> Result<GeneratedImages, AppError> {
+    let settings = get_settings(&app);
+    let model_id = model_id
+        .or(settings.default_chat_model_id.clone())
+        .ok_or_else(|| {
+            AppError::not_configured("No model specified and no default chat model configured")
+                .with_hint("Choose a default chat model in the Post Process settings.")
+        })?;
+    let model = settings
+        .get_model(&model_id)
+        .ok_or_else(|| format!("Model '{}' not found in configured models", model_id))?;
+    let provider = settings.get_provider(&model.provider_id).ok_or_else(|| {
+        format!(
+            "Provider '{}' not found for model '{}'",
+            model.provider_id, model_id
+        )
+    })?;
+    let api_key = get_api_key_for_provider_async(provider).await?;
+
+    let (generated, text, usage) = if is_gemini(provider) {
+        generate_with_gemini(
+            provider,
+            &api_key,
+            model,
+            &prompt,
+            &images.unwrap_or_default(),
+        )
+        .await?
+    } else {
+        let (generated, usage) = generate_with_openai(provider, &api_key, model, &prompt).await?;
+        (generated, None, usage)
+    };
+    if let Some(usage) = &usage {
+        record_usage(&app, provider, model, "image", usage);
+    }
+    if generated.is_empty() {
+        return Err(AppError::internal(format!(
+            "'{}' returned no image",
+            model.display_name
+        )));
+    }
+
+    let paths = save_images(&app, &generated)?;
+    let _ = app.emit(
+        "images-generated",
+        ImagesGenerated {
+            paths: paths.clone(),
+        },
+    );
+    Ok(GeneratedImages { paths, text })
+}
+
+/// Gemini image models answer generateContent with inline image parts
+async fn generate_with_gemini(
+    provider: &LLMProvider,
+    api_key: &str,
+    model: &LLMModel,
+    prompt: &str,
+    images: &[String],
+) -> Result<(Vec<ImageData>, Option<String>, Option<TokenUsage>), AppError> {
+    let mut body = gemini_request_body(None, vec![gemini_content("user", prompt, images)]);
+    body["generationConfig"]["responseModalities"] = serde_json::json!(["TEXT", "IMAGE"]);
+
+    let response =
+        gemini_generate_content(provider, api_key, &model.model_id, body, |_| {}).await?;
+    let generated = response
+        .images
+        .iter()
+        .map(|image| {
+            Ok(ImageData {
+                mime_type: image.mime_type.clone(),
+                bytes: STANDARD
+                    .decode(&image.data)
+                    .map_err(|e| format!("Invalid image data: {}", e))?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let text = Some(response.text.trim().to_string()).filter(|t| !t.is_empty());
+    Ok((generated, text, response.usage))
+}
+
+/// OpenAI-compatible `/images/generations`, as used by gpt-image and DALL·E models
+async fn generate_with_openai(
+    provider: &LLMProvider,
+    api_key: &str,
+    model: &LLMModel,
+    prompt: &str,
+) -> Result<(Vec<ImageData>, Option<TokenUsage>), AppError> {
+    let mut body = serde_json::json!({
+        "model": model.model_id,
+        "prompt": prompt,
+        "n": 1
+    });
+    // DALL·E returns URLs unless asked for base64; gpt-image always returns base64
+    if model.model_id.starts_with("dall-e") {
+        body["response_format"] = serde_json::json!("b64_json");
+    }
+
+    let url = format!(
+        "{}/images/generations",
+        provider.base_url.trim_end_matches('/')
+    );
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&url)
+        .bearer_auth(api_key)
+        .headers(custom_headers(provider, api_key)?)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| AppError::from_request(&e, format!("Image request failed: {}", e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        return Err(AppError::from_status(
+            status,
+            format!("Image API error {}: {}", status, text),
+        ));
+    }
+    let res_json: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse image response: {}", e))?;
+
+    let mut generated = Vec::new();
+    for item in res_json["data"].as_array().into_iter().flatten() {
+        let bytes = if let Some(data) = item["b64_json"].as_str() {
+            STANDARD
+                .decode(data)
+                .map_err(|e| format!("Invalid image data: {}", e))?
+        } else if let Some(image_url) = item["url"].as_str() {
+            client
+                .get(image_url)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| {
+                    AppError::from_request(&e, format!("Failed to download image: {}", e))
+                })?
+                .bytes()
+                .await
+                .map_err(|e| {
+                    AppError::from_request(&e, format!("Failed to download image: {}", e))
+                })?
+                .to_vec()
+        } else {
+            continue;
+        };
+        generated.push(ImageData {
+            mime_type: "image/png".to_string(),
+            bytes,
+        });
+    }
+    Ok((generated, TokenUsage::from_json(&res_json["usage"])))
+}
+
+fn images_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?
+        .join("generated_images");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create images directory: {}", e))?;
+    Ok(dir)
+}
+
+fn save_images(app: &AppHandle, images: &[ImageData]) -> Result<Vec<String>, String> {
+    let dir = images_dir(app)?;
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    images
+        .iter()
+        .enumerate()
+        .map(|(i, image)| {
+            let extension = match image.mime_type.as_str() {
+                "image/jpeg" => "jpg",
+                "image/webp" => "webp",
+                _ => "png",
+            };
+            let path = dir.join(format!("image-{}-{}.{}", timestamp, i + 1, extension));
+            std::fs::write(&path, &image.bytes)
+                .map_err(|e| format!("Failed to save image: {}", e))?;
+            Ok(path.to_string_lossy().to_string())
+        })
+        .collect()
+}
@@ -1,14 +1,23 @@
+pub mod app_mappings;
 pub mod audio;
+pub mod captures;
 pub mod chat;
 pub mod chat_persistence;
 pub mod fetch_models;
 pub mod history;
+pub mod images;
 pub mod models;
 pub mod oauth;
+pub mod onboarding;
+pub mod prompts;
 pub mod providers;
 pub mod transcription;
+pub mod trash;
 pub mod tts;
+pub mod usage;
+pub mod voice_command_packs;
 
+use crate::errors::AppError;
 use crate::settings::{get_settings, write_settings, AppSettings, LogLevel};
 use crate::utils::{cancel_current_operation, resume_current_operation};
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -19,13 +28,25 @@ use tauri_plugin_opener::OpenerExt;
 // Counter for unique chat window labels
 static CHAT_WINDOW_COUNTER: AtomicU32 = AtomicU32::new(0);
 
-// Storage for pending clip attachments (shared between clipping tool and chat windows)
-static PENDING_CLIP: Mutex<Option<String>> = Mutex::new(None);
+// Queue of pending clip attachments (shared between clipping tool and chat windows)
+static PENDING_CLIPS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Chat windows stay closed in focus mode
+fn ensure_chat_allowed(app: &AppHandle) -> Result<(), AppError> {
+    if get_settings(app).focus_mode {
+        return Err(
+            AppError::not_configured("Chat windows are off in focus mode")
+                .with_hint("Turn off focus mode to open chats."),
+        );
+    }
+    Ok(())
+}
 
 /// Opens a new chat window, optionally with initial context
 #[tauri::command]
 #[specta::specta]
-pub fn open_chat_window(app: AppHandle, context: Option<String>) -> Result<String, String> {
+pub fn open_chat_window(app: AppHandle, context: Option<String>) -> Result<String, AppError> {
+    ensure_chat_allowed(&app)?;
     let window_id = CHAT_WINDOW_COUNTER.fetch_add(1, Ordering::SeqCst);
     let window_label = format!("chat_{}", window_id);
 
@@ -75,85 +96,18 @@ pub fn open_chat_window(app: AppHandle, context: Option<String>) -> Result<Strin
         }
         Err(e) => {
             log::error!("Failed to create chat window: {}", e);
-            Err(format!("Failed to create chat window: {}", e))
-        }
-    }
-}
-
-/// Message structure for forking conversations
-#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, Clone)]
-pub struct ForkMessage {
-    pub role: String,
-    pub content: String,
-}
-
-/// Opens a new chat window with initial messages (for forking conversations)
-#[tauri::command]
-#[specta::specta]
-pub fn open_chat_window_with_messages(
-    app: AppHandle,
-    messages: Vec<ForkMessage>,
-) -> Result<String, String> {
-    let window_id = CHAT_WINDOW_COUNTER.fetch_add(1, Ordering::SeqCst);
-    let window_label = format!("chat_{}", window_id);
-
-    // Serialize messages to JSON and URL-encode them
-    let messages_json = serde_json::to_string(&messages)
-        .map_err(|e| format!("Failed to serialize messages: {}", e))?;
-    let encoded_messages = urlencoding::encode(&messages_json);
-    let url = format!("src/chat/index.html?messages={}", encoded_messages);
-
-    let mut builder =
-        WebviewWindowBuilder::new(&app, &window_label, tauri::WebviewUrl::App(url.into()))
-            .title("Ramble Chat")
-            .inner_size(650.0, 600.0)
-            .min_inner_size(400.0, 400.0)
-            .resizable(true)
-            .visible(true)
-            .focused(true)
-            .always_on_top(true);
-
-    #[cfg(target_os = "macos")]
-    {
-        use tauri::menu::{Menu, PredefinedMenuItem, Submenu};
-        if let Ok(menu) = Menu::with_id(&app, "chat_menu") {
-            if let Ok(edit_menu) = Submenu::with_id(&app, "edit", "Edit", true) {
-                let _ = edit_menu.append_items(&[
-                    &PredefinedMenuItem::undo(&app, None).unwrap(),
-                    &PredefinedMenuItem::redo(&app, None).unwrap(),
-                    &PredefinedMenuItem::separator(&app).unwrap(),
-                    &PredefinedMenuItem::cut(&app, None).unwrap(),
-                    &PredefinedMenuItem::copy(&app, None).unwrap(),
-                    &PredefinedMenuItem::paste(&app, None).unwrap(),
-                    &PredefinedMenuItem::select_all(&app, None).unwrap(),
-                ]);
-                let _ = menu.append(&edit_menu);
-                builder = builder.menu(menu);
-            }
-        }
-    }
-
-    match builder.build() {
-        Ok(window) => {
-            log::info!(
-                "Forked chat window '{}' created with {} messages",
-                window_label,
-                messages.len()
-            );
-            let _ = window.set_focus();
-            Ok(window_label)
-        }
-        Err(e) => {
-            log::error!("Failed to create forked chat window: {}", e);
-            Err(format!("Failed to create forked chat window: {}", e))
+            Err(AppError::internal(format!(
+                "Failed to create chat window: {}",
+                e
+            )))
         }
     }
 }
 
-/// Opens a new chat window loading a saved chat from database
 #[tauri::command]
 #[specta::specta]
-pub fn open_saved_chat(app: AppHandle, chat_id: i64) -> Result<String, String> {
+pub fn open_saved_chat(app: AppHandle, chat_id: i64) -> Result<String, AppError> {
+    ensure_chat_allowed(&app)?;
     let window_id = CHAT_WINDOW_COUNTER.fetch_add(1, Ordering::SeqCst);
     let window_label = format!("chat_{}", window_id);
 
@@ -201,7 +155,10 @@ pub fn open_saved_chat(app: AppHandle, chat_id: i64) -> Result<String, String> {
         }
         Err(e) => {
             log::error!("Failed to create saved chat window: {}", e);
-            Err(format!("Failed to create saved chat window: {}", e))
+            Err(AppError::internal(format!(
+                "Failed to create saved chat window: {}",
+                e
+            )))
         }
     }
 }
@@ -228,7 +185,7 @@ pub fn resume_operation(app: AppHandle) -> bool {
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
+pub fn get_app_dir_path(app: AppHandle) -> Result<String, AppError> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -239,7 +196,7 @@ pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, String> {
+pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, AppError> {
     // Wrap in catch_unwind to prevent app crash if serialization fails
     // This seems to be happening with serde_json::ser::format_escaped_str_contents
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -255,20 +212,22 @@ pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, String> {
         Ok(settings) => Ok(settings),
         Err(e) => {
             log::error!("get_app_settings panicked: {:?}", e);
-            Err("Failed to retrieve settings due to internal error".to_string())
+            Err(AppError::internal(
+                "Failed to retrieve settings due to internal error",
+            ))
         }
     }
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_default_settings() -> Result<AppSettings, String> {
+pub fn get_default_settings() -> Result<AppSettings, AppError> {
     Ok(crate::settings::get_default_settings())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn get_log_dir_path(app: AppHandle) -> Result<String, String> {
+pub fn get_log_dir_path(app: AppHandle) -> Result<String, AppError> {
     let log_dir = app
         .path()
         .app_log_dir()
@@ -279,7 +238,7 @@ pub fn get_log_dir_path(app: AppHandle) -> Result<String, String> {
 
 #[specta::specta]
 #[tauri::command]
-pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
+pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), AppError> {
     let tauri_log_level: tauri_plugin_log::LogLevel = level.into();
     let log_level: log::Level = tauri_log_level.into();
     // Update the file log level atomic so the filter picks up the new level
@@ -297,7 +256,7 @@ pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
 
 #[specta::specta]
 #[tauri::command]
-pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
+pub fn open_recordings_folder(app: AppHandle) -> Result<(), AppError> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -315,7 +274,7 @@ pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
 
 #[specta::specta]
 #[tauri::command]
-pub fn open_log_dir(app: AppHandle) -> Result<(), String> {
+pub fn open_log_dir(app: AppHandle) -> Result<(), AppError> {
     let log_dir = app
         .path()
         .app_log_dir()
@@ -332,7 +291,7 @@ pub fn open_log_dir(app: AppHandle) -> Result<(), String> {
 /// Opens a URL in the system's default browser
 #[specta::specta]
 #[tauri::command]
-pub fn open_external_url(app: AppHandle, url: String) -> Result<(), String> {
+pub fn open_external_url(app: AppHandle, url: String) -> Result<(), AppError> {
     app.opener()
         .open_url(&url, None::<String>)
         .map_err(|e| format!("Failed to open URL: {}", e))?;
@@ -342,7 +301,7 @@ pub fn open_external_url(app: AppHandle, url: String) -> Result<(), String> {
 
 #[specta::specta]
 #[tauri::command]
-pub fn open_app_data_dir(app: AppHandle) -> Result<(), String> {
+pub fn open_app_data_dir(app: AppHandle) -> Result<(), AppError> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -388,7 +347,7 @@ pub fn set_app_category_mapping(
     bundle_id: String,
     display_name: String,
     category_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
 
     // Check if mapping already exists for this bundle_id
@@ -417,11 +376,22 @@ pub fn set_app_category_mapping(
 /// Remove an app-to-category mapping
 #[tauri::command]
 #[specta::specta]
-pub fn remove_app_category_mapping(app: AppHandle, bundle_id: String) -> Result<(), String> {
+pub fn remove_app_category_mapping(app: AppHandle, bundle_id: String) -> Result<(), AppError> {
     let mut settings = get_settings(&app);
-    settings
+
+    if let Some(index) = settings
         .app_category_mappings
-        .retain(|m| m.bundle_identifier != bundle_id);
+        .iter()
+        .position(|m| m.bundle_identifier == bundle_id)
+    {
+        let mapping = settings.app_category_mappings.remove(index);
+        crate::trash::move_to_trash(
+            &mut settings,
+            mapping.display_name.clone(),
+            crate::settings::TrashedItem::AppMapping(mapping),
+        );
+    }
+
     write_settings(&app, settings);
     Ok(())
 }
@@ -455,7 +425,7 @@ pub fn set_chat_window_visibility(app: &AppHandle, visible: bool) {
 /// Command to capture a screenshot or region, hiding all app windows first
 #[tauri::command]
 #[specta::specta]
-pub async fn capture_screen_mode(app: AppHandle, region: bool) -> Result<String, String> {
+pub async fn capture_screen_mode(app: AppHandle, region: bool) -> Result<String, AppError> {
     // 1. Hide all chat windows and the overlay
     set_chat_window_visibility(&app, false);
     crate::overlay::set_overlay_visibility(&app, false);
@@ -465,7 +435,9 @@ pub async fn capture_screen_mode(app: AppHandle, region: bool) -> Result<String,
 
     // 2. Capture
     let result = if region {
-        return Err("Please use capture_region_command for regional capture".to_string());
+        return Err(AppError::invalid_input(
+            "Please use capture_region_command for regional capture",
+        ));
     } else {
         crate::vision::capture_screen()
     };
@@ -474,12 +446,15 @@ pub async fn capture_screen_mode(app: AppHandle, region: bool) -> Result<String,
     set_chat_window_visibility(&app, true);
     crate::overlay::set_overlay_visibility(&app, true);
 
-    result
+    if let Ok(base64) = &result {
+        crate::managers::captures::record_capture(&app, "screen", base64.clone());
+    }
+    Ok(result?)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn open_clipping_tool(app: AppHandle) -> Result<(), String> {
+pub async fn open_clipping_tool(app: AppHandle) -> Result<(), AppError> {
     let window_label = "clipping_overlay";
 
     // Always hide chat windows, overlay, AND main window first
@@ -526,14 +501,17 @@ pub async fn open_clipping_tool(app: AppHandle) -> Result<(), String> {
         }
         Err(e) => {
             log::error!("Failed to create clipping tool window: {}", e);
-            Err(format!("Failed to create clipping tool window: {}", e))
+            Err(AppError::internal(format!(
+                "Failed to create clipping tool window: {}",
+                e
+            )))
         }
     }
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn restore_app_visibility(app: AppHandle) -> Result<(), String> {
+pub fn restore_app_visibility(app: AppHandle) -> Result<(), AppError> {
     log::info!("Restoring app visibility via command");
     set_chat_window_visibility(&app, true);
     crate::overlay::set_overlay_visibility(&app, true);
@@ -542,7 +520,7 @@ pub fn restore_app_visibility(app: AppHandle) -> Result<(), String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn add_context_image(app: AppHandle, base64: String) -> Result<(), String> {
+pub fn add_context_image(app: AppHandle, base64: String) -> Result<(), AppError> {
     let audio_manager = app.state::<Arc<crate::managers::audio::AudioRecordingManager>>();
     audio_manager.add_vision_context(base64);
     Ok(())
@@ -550,7 +528,7 @@ pub fn add_context_image(app: AppHandle, base64: String) -> Result<(), String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn copy_last_voice_interaction(app: AppHandle) -> Result<(), String> {
+pub fn copy_last_voice_interaction(app: AppHandle) -> Result<(), AppError> {
     use tauri_plugin_clipboard_manager::ClipboardExt;
     let settings = crate::settings::get_settings(&app);
     if let Some(text) = settings.last_voice_interaction {
@@ -559,10 +537,14 @@ pub fn copy_last_voice_interaction(app: AppHandle) -> Result<(), String> {
             .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
         Ok(())
     } else {
-        Err("No voice interaction available to copy".to_string())
+        Err(AppError::not_found(
+            "No voice interaction available to copy",
+        ))
     }
 }
 
+/// Capture a screen region into the pending clip queue. With `keep_open` the clipping
+/// overlay comes back afterwards so more regions can be captured in the same session.
 #[tauri::command]
 #[specta::specta]
 pub async fn capture_region_command(
@@ -571,7 +553,8 @@ pub async fn capture_region_command(
     y: i32,
     width: u32,
     height: u32,
-) -> Result<String, String> {
+    keep_open: bool,
+) -> Result<String, AppError> {
     log::info!(
         "Capture region command received: {}x{} at {},{}",
         width,
@@ -593,43 +576,52 @@ pub async fn capture_region_command(
     let result = crate::vision::capture_region(x, y, width, height);
 
     // 3. Restore visibility BEFORE emitting event to ensure frontend is awake
-    log::info!("Restoring visibility before storing capture");
-    set_chat_window_visibility(&app, true);
-    crate::overlay::set_overlay_visibility(&app, true);
+    if keep_open {
+        // Multi-region session: bring the overlay back for the next region
+        if let Some(win) = app.get_webview_window("clipping_overlay") {
+            let _ = win.show();
+            let _ = win.set_focus();
+        }
+    } else {
+        log::info!("Restoring visibility before storing capture");
+        set_chat_window_visibility(&app, true);
+        crate::overlay::set_overlay_visibility(&app, true);
+    }
 
     if let Ok(ref base64) = result {
         log::info!(
-            "Storing captured clip ({} bytes) in PENDING_CLIP",
+            "Queueing captured clip ({} bytes) in PENDING_CLIPS",
             base64.len()
         );
 
-        // Store in PENDING_CLIP for ChatWindow to retrieve
-        if let Ok(mut pending) = PENDING_CLIP.lock() {
-            *pending = Some(base64.clone());
-            log::info!("Clip stored successfully");
+        // Queue in PENDING_CLIPS for ChatWindow to retrieve
+        if let Ok(mut pending) = PENDING_CLIPS.lock() {
+            pending.push(base64.clone());
+            log::info!("Clip queued successfully ({} pending)", pending.len());
         } else {
-            log::error!("Failed to lock PENDING_CLIP mutex");
+            log::error!("Failed to lock PENDING_CLIPS mutex");
         }
+        crate::managers::captures::record_capture(&app, "region", base64.clone());
     } else if let Err(ref e) = result {
         log::error!("Region capture failed: {}", e);
     }
 
-    result
+    Ok(result?)
 }
 
-/// Retrieves and clears any pending clip attachment
+/// Retrieves and clears all pending clip attachments, oldest first
 /// Called by ChatWindow to get captured images
 #[tauri::command]
 #[specta::specta]
-pub fn get_pending_clip() -> Option<String> {
-    if let Ok(mut pending) = PENDING_CLIP.lock() {
-        let clip = pending.take();
-        if clip.is_some() {
-            log::info!("Pending clip retrieved and cleared");
+pub fn get_pending_clips() -> Vec<String> {
+    if let Ok(mut pending) = PENDING_CLIPS.lock() {
+        let clips = std::mem::take(&mut *pending);
+        if !clips.is_empty() {
+            log::info!("{} pending clip(s) retrieved and cleared", clips.len());
         }
-        clip
+        clips
     } else {
-        log::error!("Failed to lock PENDING_CLIP mutex");
-        None
+        log::error!("Failed to lock PENDING_CLIPS mutex");
+        Vec::new()
     }
 }
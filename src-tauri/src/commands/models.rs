@@ -1,4 +1,6 @@
+use crate::errors::AppError;
 use crate::managers::model::{ModelInfo, ModelManager};
+use crate::managers::stt_engine::STT_SERVICES;
 use crate::managers::transcription::TranscriptionManager;
 use crate::settings::{get_settings, write_settings};
 use std::sync::Arc;
@@ -8,7 +10,7 @@ use tauri::{AppHandle, State};
 #[specta::specta]
 pub async fn get_available_models(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<Vec<ModelInfo>, String> {
+) -> Result<Vec<ModelInfo>, AppError> {
     Ok(model_manager.get_available_models())
 }
 
@@ -17,7 +19,7 @@ pub async fn get_available_models(
 pub async fn get_model_info(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<Option<ModelInfo>, String> {
+) -> Result<Option<ModelInfo>, AppError> {
     Ok(model_manager.get_model_info(&model_id))
 }
 
@@ -26,11 +28,11 @@ pub async fn get_model_info(
 pub async fn download_model(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .download_model(&model_id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -38,10 +40,10 @@ pub async fn download_model(
 pub async fn delete_model(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .delete_model(&model_id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
@@ -51,14 +53,17 @@ pub async fn set_active_model(
     model_manager: State<'_, Arc<ModelManager>>,
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     // Check if model exists and is available
     let model_info = model_manager
         .get_model_info(&model_id)
-        .ok_or_else(|| format!("Model not found: {}", model_id))?;
+        .ok_or_else(|| AppError::not_found(format!("Model not found: {}", model_id)))?;
 
     if !model_info.is_downloaded {
-        return Err(format!("Model not downloaded: {}", model_id));
+        return Err(AppError::from(format!(
+            "Model not downloaded: {}",
+            model_id
+        )));
     }
 
     // Load the model in the transcription manager
@@ -66,6 +71,9 @@ pub async fn set_active_model(
         .load_model(&model_id)
         .map_err(|e| e.to_string())?;
 
+    // The user's choice wins over the battery saver's smaller model
+    crate::power::clear_model_override();
+
     // Update settings
     let mut settings = get_settings(&app_handle);
     settings.selected_model = model_id.clone();
@@ -76,7 +84,7 @@ pub async fn set_active_model(
 
 #[tauri::command]
 #[specta::specta]
-pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String> {
+pub async fn get_current_model(app_handle: AppHandle) -> Result<String, AppError> {
     let settings = get_settings(&app_handle);
     Ok(settings.selected_model)
 }
@@ -85,7 +93,7 @@ pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String>
 #[specta::specta]
 pub async fn get_transcription_model_status(
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, AppError> {
     Ok(transcription_manager.get_current_model())
 }
 
@@ -93,17 +101,24 @@ pub async fn get_transcription_model_status(
 #[specta::specta]
 pub async fn is_model_loading(
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     // Check if transcription manager has a loaded model
     let current_model = transcription_manager.get_current_model();
     Ok(current_model.is_none())
 }
 
+/// Whether a local model can be run, i.e. llama-server is bundled or installed
+#[tauri::command]
+#[specta::specta]
+pub fn is_local_llm_available() -> bool {
+    crate::managers::local_llm::is_available()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn has_any_models_available(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let models = model_manager.get_available_models();
     Ok(models.iter().any(|m| m.is_downloaded))
 }
@@ -112,7 +127,7 @@ pub async fn has_any_models_available(
 #[specta::specta]
 pub async fn has_any_models_or_downloads(
     model_manager: State<'_, Arc<ModelManager>>,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let models = model_manager.get_available_models();
     // Return true if any models are downloaded OR if any downloads are in progress
     Ok(models.iter().any(|m| m.is_downloaded))
@@ -123,15 +138,60 @@ pub async fn has_any_models_or_downloads(
 pub async fn cancel_download(
     model_manager: State<'_, Arc<ModelManager>>,
     model_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     model_manager
         .cancel_download(&model_id)
-        .map_err(|e| e.to_string())
+        .map_err(AppError::from)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn get_recommended_first_model() -> Result<String, String> {
+pub async fn get_recommended_first_model() -> Result<String, AppError> {
     // Recommend Parakeet V3 model for first-time users - fastest and most accurate
     Ok("parakeet-tdt-0.6b-v3".to_string())
 }
+
+/// Set (or clear, with an empty key) the API key for a cloud transcription service.
+/// Cloud models become available as soon as their service has a key.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_stt_api_key(
+    app_handle: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    service: String,
+    api_key: String,
+) -> Result<(), AppError> {
+    if !STT_SERVICES.contains(&service.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "Unknown transcription service: {}",
+            service
+        )));
+    }
+
+    let mut settings = get_settings(&app_handle);
+    let api_key = api_key.trim().to_string();
+    if api_key.is_empty() {
+        settings.stt_api_keys.remove(&service);
+    } else {
+        settings.stt_api_keys.insert(service.clone(), api_key);
+    }
+    write_settings(&app_handle, settings);
+
+    model_manager
+        .update_download_status()
+        .map_err(|e| e.to_string())?;
+
+    // A loaded cloud engine holds the old key, so reload it on next use
+    let uses_service = transcription_manager
+        .get_current_model()
+        .and_then(|id| model_manager.get_model_info(&id))
+        .is_some_and(|m| m.engine_type.cloud_service() == Some(service.as_str()));
+    if uses_service {
+        transcription_manager
+            .unload_model()
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
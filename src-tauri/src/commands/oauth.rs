@@ -5,9 +5,11 @@
 use std::collections::HashMap;
 use std::sync::{LazyLock, Mutex};
 use std::time::Duration;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tauri_plugin_opener::OpenerExt;
 
+use crate::errors::{AppError, ErrorCode};
+use crate::oauth::cli_import::{self, CliCredentials};
 use crate::oauth::pkce::{generate_state, PkceChallenge};
 use crate::oauth::server::wait_for_callback;
 use crate::oauth::tokens::{delete_tokens, load_tokens};
@@ -23,9 +25,12 @@ static OAUTH_STATE: LazyLock<Mutex<HashMap<String, (OAuthProvider, String)>>> =
 /// Returns the authorization URL to open in the browser.
 #[tauri::command]
 #[specta::specta]
-pub async fn oauth_start_auth(app: AppHandle, provider: String) -> Result<AuthStartResult, String> {
+pub async fn oauth_start_auth(
+    app: AppHandle,
+    provider: String,
+) -> Result<AuthStartResult, AppError> {
     let provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     // Generate PKCE challenge and state
     let pkce = PkceChallenge::new();
@@ -61,9 +66,9 @@ pub async fn oauth_start_auth(app: AppHandle, provider: String) -> Result<AuthSt
 /// exchanges the code for tokens, and stores them securely.
 #[tauri::command]
 #[specta::specta]
-pub async fn oauth_await_callback(provider: String, state: String) -> Result<AuthResult, String> {
+pub async fn oauth_await_callback(provider: String, state: String) -> Result<AuthResult, AppError> {
     let oauth_provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     // Get the stored verifier for this state
     let verifier = {
@@ -165,9 +170,9 @@ pub async fn oauth_await_callback(provider: String, state: String) -> Result<Aut
 /// Get OAuth status for a provider
 #[tauri::command]
 #[specta::specta]
-pub fn oauth_get_status(provider: String) -> Result<OAuthStatus, String> {
+pub fn oauth_get_status(provider: String) -> Result<OAuthStatus, AppError> {
     let provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     match load_tokens(provider) {
         Ok(tokens) => Ok(OAuthStatus {
@@ -186,7 +191,7 @@ pub fn oauth_get_status(provider: String) -> Result<OAuthStatus, String> {
                 provider.as_str(),
                 e
             );
-            Err(e.to_string())
+            Err(AppError::from(e.to_string()))
         }
     }
 }
@@ -194,9 +199,9 @@ pub fn oauth_get_status(provider: String) -> Result<OAuthStatus, String> {
 /// Log out from OAuth for a provider
 #[tauri::command]
 #[specta::specta]
-pub fn oauth_logout(provider: String) -> Result<(), String> {
+pub fn oauth_logout(provider: String) -> Result<(), AppError> {
     let provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     match delete_tokens(provider) {
         Ok(()) => {
@@ -209,7 +214,7 @@ pub fn oauth_logout(provider: String) -> Result<(), String> {
         }
         Err(e) => {
             log::error!("OAuth logout failed for {}: {}", provider.as_str(), e);
-            Err(e.to_string())
+            Err(AppError::from(e.to_string()))
         }
     }
 }
@@ -217,9 +222,9 @@ pub fn oauth_logout(provider: String) -> Result<(), String> {
 /// Refresh the OAuth token for a provider
 #[tauri::command]
 #[specta::specta]
-pub async fn oauth_refresh_token(provider: String) -> Result<bool, String> {
+pub async fn oauth_refresh_token(provider: String) -> Result<bool, AppError> {
     let oauth_provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     // Load existing tokens
     let tokens = load_tokens(oauth_provider).map_err(|e| e.to_string())?;
@@ -246,9 +251,9 @@ pub async fn oauth_refresh_token(provider: String) -> Result<bool, String> {
 /// Get current access token for a provider (for making API calls)
 #[tauri::command]
 #[specta::specta]
-pub fn oauth_get_access_token(provider: String) -> Result<Option<String>, String> {
+pub fn oauth_get_access_token(provider: String) -> Result<Option<String>, AppError> {
     let provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     match load_tokens(provider) {
         Ok(tokens) => {
@@ -260,21 +265,24 @@ pub fn oauth_get_access_token(provider: String) -> Result<Option<String>, String
             }
         }
         Err(crate::oauth::tokens::TokenError::NotFound) => Ok(None),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(AppError::from(e.to_string())),
     }
 }
 
 /// Get request headers for making authenticated API calls
 #[tauri::command]
 #[specta::specta]
-pub fn oauth_get_request_headers(provider: String) -> Result<HashMap<String, String>, String> {
+pub fn oauth_get_request_headers(provider: String) -> Result<HashMap<String, String>, AppError> {
     let oauth_provider = OAuthProvider::from_str(&provider)
-        .ok_or_else(|| format!("Unknown OAuth provider: {}", provider))?;
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
 
     let tokens = load_tokens(oauth_provider).map_err(|e| e.to_string())?;
 
     if tokens.is_expired() {
-        return Err("Access token is expired. Please refresh first.".to_string());
+        return Err(AppError::new(
+            ErrorCode::Unauthorized,
+            "Access token is expired. Please refresh first.",
+        ));
     }
 
     let headers = match oauth_provider {
@@ -291,3 +299,43 @@ pub fn oauth_get_request_headers(provider: String) -> Result<HashMap<String, Str
 pub fn oauth_supports_provider(provider_id: String) -> bool {
     OAuthProvider::from_str(&provider_id).is_some()
 }
+
+/// Find Gemini CLI / Codex CLI sign-ins whose tokens can be reused
+#[tauri::command]
+#[specta::specta]
+pub fn oauth_detect_cli_credentials(app: AppHandle) -> Result<Vec<CliCredentials>, AppError> {
+    let home = app.path().home_dir().map_err(|e| e.to_string())?;
+    Ok(cli_import::detect(&home))
+}
+
+/// Import a CLI's tokens for a provider instead of running the browser flow
+#[tauri::command]
+#[specta::specta]
+pub async fn oauth_import_cli_credentials(
+    app: AppHandle,
+    provider: String,
+) -> Result<AuthResult, AppError> {
+    let provider = OAuthProvider::from_str(&provider)
+        .ok_or_else(|| AppError::invalid_input(format!("Unknown OAuth provider: {}", provider)))?;
+    let home = app.path().home_dir().map_err(|e| e.to_string())?;
+
+    match cli_import::import(provider, &home).await {
+        Ok(tokens) => Ok(AuthResult {
+            success: true,
+            email: tokens.email,
+            error: None,
+        }),
+        Err(e) => {
+            log::error!(
+                "CLI credential import failed for {}: {}",
+                provider.as_str(),
+                e
+            );
+            Ok(AuthResult {
+                success: false,
+                email: None,
+                error: Some(e),
+            })
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::errors::AppError;
+use crate::onboarding::{self, SuggestedSetup};
+use crate::settings::{get_settings, write_settings};
+use tauri::AppHandle;
+
+/// Categories and app mappings suggested from the apps installed on this computer,
+/// for the first-run setup. Nothing is saved until `apply_suggested_setup`.
+#[tauri::command]
+#[specta::specta]
+pub fn generate_suggested_setup(app: AppHandle) -> SuggestedSetup {
+    let installed = crate::app_detection::get_installed_applications();
+    onboarding::suggest(&get_settings(&app), &installed)
+}
+
+/// Save a setup from `generate_suggested_setup`, skipping anything that was added
+/// since it was generated
+#[tauri::command]
+#[specta::specta]
+pub fn apply_suggested_setup(app: AppHandle, setup: SuggestedSetup) -> Result<(), AppError> {
+    let mut settings = get_settings(&app);
+    let (categories, mappings) = onboarding::apply(&mut settings, setup);
+    write_settings(&app, settings);
+    log::info!(
+        "Applied suggested setup: {} categories and {} app mappings",
+        categories,
+        mappings
+    );
+    Ok(())
+}
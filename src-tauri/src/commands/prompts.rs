@@ -0,0 +1,395 @@
+use crate::actions::{extract_llm_error, resolve_llm_config, COHERENT_SYSTEM_PROMPT};
+use crate::errors::AppError;
+use crate::llm_client::TokenUsage;
+use crate::managers::usage::record_usage;
+use crate::retry::with_retry;
+use crate::settings::get_settings;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
+    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+use std::time::Instant;
+use tauri::AppHandle;
+
+/// Variables substituted into coherent/category prompts
+pub const PROMPT_VARIABLES: &[&str] = &[
+    "output",
+    "application",
+    "category",
+    "project",
+    "selection",
+    "clipboard",
+    "date",
+    "time",
+    "window_title",
+];
+
+/// Variables that are still replaced (with an empty string) but no longer do anything
+const DEPRECATED_PROMPT_VARIABLES: &[&str] = &["screen_context"];
+
+/// Prompts longer than this are likely to waste tokens and slow down every refinement
+const MAX_PROMPT_CHARS: usize = 12_000;
+
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptWarningKind {
+    UnknownVariable,
+    DeprecatedVariable,
+    MissingOutput,
+    UnbalancedBraces,
+    ExcessiveLength,
+}
+
+/// A problem found in a prompt template, surfaced in the editor before saving
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct PromptWarning {
+    pub kind: PromptWarningKind,
+    pub message: String,
+    /// Character offset in the prompt the warning refers to, if any
+    pub position: Option<usize>,
+}
+
+impl PromptWarning {
+    fn new(kind: PromptWarningKind, message: String, position: Option<usize>) -> Self {
+        Self {
+            kind,
+            message,
+            position,
+        }
+    }
+}
+
+/// Fill the `${name}` variables of a prompt template in one pass, so substituted text
+/// (a clipboard, say) is never expanded itself. Known variables without a value and
+/// deprecated ones become empty; unknown ones are left as written and returned so the
+/// caller can warn about them.
+pub fn render_prompt(template: &str, values: &HashMap<&str, String>) -> (String, Vec<String>) {
+    let mut output = String::with_capacity(template.len());
+    let mut unknown: Vec<String> = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(len) = after.find('}') else {
+            // Unterminated variable, kept as text
+            output.push_str(&rest[start..]);
+            return (output, unknown);
+        };
+        let name = &after[..len];
+        if let Some(value) = values.get(name) {
+            output.push_str(value);
+        } else if !PROMPT_VARIABLES.contains(&name) && !DEPRECATED_PROMPT_VARIABLES.contains(&name)
+        {
+            output.push_str(&rest[start..start + len + 3]);
+            if !unknown.iter().any(|u| u == name) {
+                unknown.push(name.to_string());
+            }
+        }
+        rest = &after[len + 1..];
+    }
+    output.push_str(rest);
+    (output, unknown)
+}
+
+/// Current local date and time for `${date}` and `${time}`
+pub fn date_time_values() -> [(&'static str, String); 2] {
+    let now = chrono::Local::now();
+    [
+        ("date", now.format("%A, %B %-d, %Y").to_string()),
+        ("time", now.format("%H:%M").to_string()),
+    ]
+}
+
+/// Check a prompt template for common mistakes.
+pub fn lint_prompt(prompt: &str) -> Vec<PromptWarning> {
+    let mut warnings = Vec::new();
+    let chars: Vec<char> = prompt.chars().collect();
+
+    // Variables and unterminated `${`
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '$' && chars.get(i + 1) == Some(&'{') {
+            let name_start = i + 2;
+            match chars[name_start..].iter().position(|&c| c == '}') {
+                Some(len) => {
+                    let name: String = chars[name_start..name_start + len].iter().collect();
+                    if DEPRECATED_PROMPT_VARIABLES.contains(&name.as_str()) {
+                        warnings.push(PromptWarning::new(
+                            PromptWarningKind::DeprecatedVariable,
+                            format!(
+                                "${{{}}} is no longer supported and is replaced with nothing",
+                                name
+                            ),
+                            Some(i),
+                        ));
+                    } else if !PROMPT_VARIABLES.contains(&name.as_str()) {
+                        warnings.push(PromptWarning::new(
+                            PromptWarningKind::UnknownVariable,
+                            format!(
+                                "Unknown variable ${{{}}}. Available: {}",
+                                name,
+                                PROMPT_VARIABLES
+                                    .iter()
+                                    .map(|v| format!("${{{}}}", v))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            Some(i),
+                        ));
+                    }
+                    i = name_start + len + 1;
+                    continue;
+                }
+                None => {
+                    warnings.push(PromptWarning::new(
+                        PromptWarningKind::UnbalancedBraces,
+                        "Variable is missing its closing '}'".to_string(),
+                        Some(i),
+                    ));
+                    break;
+                }
+            }
+        }
+        i += 1;
+    }
+
+    // Plain braces (e.g. JSON examples) should still pair up
+    let mut open_positions = Vec::new();
+    for (pos, &c) in chars.iter().enumerate() {
+        match c {
+            '{' => open_positions.push(pos),
+            '}' => {
+                if open_positions.pop().is_none() {
+                    warnings.push(PromptWarning::new(
+                        PromptWarningKind::UnbalancedBraces,
+                        "Unmatched '}'".to_string(),
+                        Some(pos),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    // An unterminated `${` was already reported above
+    let unterminated_variable = warnings
+        .iter()
+        .any(|w| w.kind == PromptWarningKind::UnbalancedBraces);
+    if !unterminated_variable {
+        for pos in open_positions {
+            warnings.push(PromptWarning::new(
+                PromptWarningKind::UnbalancedBraces,
+                "Unmatched '{'".to_string(),
+                Some(pos),
+            ));
+        }
+    }
+
+    if !prompt.contains("${output}") {
+        warnings.push(PromptWarning::new(
+            PromptWarningKind::MissingOutput,
+            "Prompt doesn't include ${output}, so the transcription will not be sent".to_string(),
+            None,
+        ));
+    }
+
+    if chars.len() > MAX_PROMPT_CHARS {
+        warnings.push(PromptWarning::new(
+            PromptWarningKind::ExcessiveLength,
+            format!(
+                "Prompt is {} characters long (recommended maximum is {})",
+                chars.len(),
+                MAX_PROMPT_CHARS
+            ),
+            None,
+        ));
+    }
+
+    warnings
+}
+
+/// Validate a prompt template, returning warnings the editor can show before saving
+#[tauri::command]
+#[specta::specta]
+pub fn validate_prompt(prompt: String) -> Vec<PromptWarning> {
+    lint_prompt(&prompt)
+}
+
+/// Result of a single test-run of a prompt
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct RefinementPreview {
+    pub output: String,
+    /// Model identifier the request was sent to
+    pub model: String,
+    /// Token usage, if reported by the provider
+    pub usage: Option<TokenUsage>,
+    pub duration_ms: u64,
+}
+
+/// Fill prompt variables for a preview run. Context that only exists during dictation
+/// (selection, clipboard, window title) is left empty so previews are reproducible.
+fn fill_preview_variables(prompt: &str, sample_text: &str) -> String {
+    let mut values = HashMap::from([
+        ("application", "Preview".to_string()),
+        ("category", "preview".to_string()),
+        ("output", sample_text.to_string()),
+    ]);
+    values.extend(date_time_values());
+    render_prompt(prompt, &values).0
+}
+
+/// Run one refinement of `sample_text` with `prompt_text` outside the dictation flow.
+/// Falls back to the default coherent model when no model is given.
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_refinement(
+    app: AppHandle,
+    prompt_text: String,
+    sample_text: String,
+    model_id: Option<String>,
+) -> Result<RefinementPreview, AppError> {
+    if sample_text.trim().is_empty() {
+        return Err(AppError::invalid_input("Sample text is empty"));
+    }
+
+    let settings = get_settings(&app);
+    let model_id = model_id
+        .or(settings.default_coherent_model_id.clone())
+        .ok_or_else(|| {
+            AppError::not_configured("No model specified and no coherent model configured")
+                .with_hint("Choose a default refinement model in the Ramble to Coherent settings.")
+        })?;
+
+    let llm_config = resolve_llm_config(&settings, &model_id).await?;
+    let model = llm_config.model.model_id.clone();
+    let client = crate::llm_client::create_client(&llm_config.provider, llm_config.api_key)?;
+
+    let prompt = crate::settings::inject_system_prompt(
+        &app,
+        &fill_preview_variables(&prompt_text, &sample_text),
+    );
+
+    let system_message = ChatCompletionRequestSystemMessageArgs::default()
+        .content(COHERENT_SYSTEM_PROMPT)
+        .build()
+        .map_err(|e| format!("Request error (system message): {}", e))?;
+    let user_message = ChatCompletionRequestUserMessageArgs::default()
+        .content(prompt)
+        .build()
+        .map_err(|e| format!("Request error: {}", e))?;
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(&model)
+        .messages(vec![
+            ChatCompletionRequestMessage::System(system_message),
+            ChatCompletionRequestMessage::User(user_message),
+        ])
+        .build()
+        .map_err(|e| format!("Request error: {}", e))?;
+
+    log::info!("Previewing refinement with model '{}'", model);
+    let started = Instant::now();
+    let chat = client.chat();
+    let response = with_retry(format!("Refinement preview with '{}'", model), || {
+        chat.create(request.clone())
+    })
+    .await
+    .map_err(|e| extract_llm_error(&e, &model))?;
+    if let Some(usage) = response.usage.as_ref().map(TokenUsage::from) {
+        record_usage(
+            &app,
+            &llm_config.provider,
+            &llm_config.model,
+            "prompt_preview",
+            &usage,
+        );
+    }
+
+    let output = response
+        .choices
+        .first()
+        .and_then(|c| c.message.content.clone())
+        .ok_or_else(|| "No response from AI".to_string())?;
+
+    Ok(RefinementPreview {
+        output,
+        model,
+        usage: response.usage.as_ref().map(TokenUsage::from),
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(prompt: &str) -> Vec<PromptWarningKind> {
+        lint_prompt(prompt).into_iter().map(|w| w.kind).collect()
+    }
+
+    #[test]
+    fn test_valid_prompt_has_no_warnings() {
+        assert!(kinds("Clean this up for ${application}:\n${output}").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_and_deprecated_variables() {
+        let warnings = lint_prompt("${output} ${outptu} ${screen_context}");
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].kind, PromptWarningKind::UnknownVariable);
+        assert_eq!(warnings[0].position, Some(10));
+        assert_eq!(warnings[1].kind, PromptWarningKind::DeprecatedVariable);
+    }
+
+    #[test]
+    fn test_missing_output() {
+        assert_eq!(kinds("Fix grammar"), vec![PromptWarningKind::MissingOutput]);
+    }
+
+    #[test]
+    fn test_unbalanced_braces() {
+        assert_eq!(
+            kinds("${output} ${selection"),
+            vec![PromptWarningKind::UnbalancedBraces]
+        );
+        assert_eq!(
+            kinds("${output} {\"a\": 1"),
+            vec![PromptWarningKind::UnbalancedBraces]
+        );
+        assert_eq!(
+            kinds("${output} }"),
+            vec![PromptWarningKind::UnbalancedBraces]
+        );
+        assert!(kinds("${output} {\"a\": {\"b\": 1}}").is_empty());
+    }
+
+    #[test]
+    fn test_excessive_length() {
+        let prompt = format!("${{output}}{}", "a".repeat(MAX_PROMPT_CHARS));
+        assert_eq!(kinds(&prompt), vec![PromptWarningKind::ExcessiveLength]);
+    }
+
+    #[test]
+    fn test_render_prompt() {
+        let values = HashMap::from([
+            ("output", "hello ${clipboard}".to_string()),
+            ("clipboard", "copied".to_string()),
+        ]);
+        let (rendered, unknown) = render_prompt(
+            "${output} / ${clipboard} / ${selection}${screen_context} / ${outptu} ${outptu}",
+            &values,
+        );
+        // Substituted text isn't expanded again, and unknown variables stay visible
+        assert_eq!(
+            rendered,
+            "hello ${clipboard} / copied /  / ${outptu} ${outptu}"
+        );
+        assert_eq!(unknown, vec!["outptu".to_string()]);
+
+        let (rendered, unknown) = render_prompt("Note: ${output", &values);
+        assert_eq!(rendered, "Note: ${output");
+        assert!(unknown.is_empty());
+    }
+}
@@ -3,7 +3,13 @@
 // This module provides Tauri commands for managing LLM providers and models.
 // It replaces the deprecated post_process_* and ramble_* settings commands.
 
-use crate::settings::{self, LLMModel, LLMProvider};
+use crate::errors::{AppError, API_KEY_HINT};
+use crate::provider_health::{self, DiscoveredServer, ProviderHealth};
+use crate::settings::{
+    self, AuthMethod, GeminiOptions, LLMModel, LLMProvider, OpenRouterRouting, ProviderCredential,
+    ReasoningOptions,
+};
+use std::collections::HashMap;
 use tauri::AppHandle;
 
 /// Get all configured LLM providers, deduplicated by ID
@@ -46,16 +52,17 @@ pub fn update_provider_api_key(
     app: AppHandle,
     provider_id: String,
     api_key: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     let provider = settings
         .llm_providers
         .iter_mut()
         .find(|p| p.id == provider_id)
-        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+        .ok_or_else(|| AppError::not_found(format!("Provider '{}' not found", provider_id)))?;
 
     provider.api_key = api_key;
+    provider.sync_active_credential();
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -63,7 +70,7 @@ pub fn update_provider_api_key(
 /// Save (create or update) an LLM provider
 #[tauri::command]
 #[specta::specta]
-pub fn save_llm_provider(app: AppHandle, provider: LLMProvider) -> Result<LLMProvider, String> {
+pub fn save_llm_provider(app: AppHandle, provider: LLMProvider) -> Result<LLMProvider, AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Check if provider already exists
@@ -79,7 +86,8 @@ pub fn save_llm_provider(app: AppHandle, provider: LLMProvider) -> Result<LLMPro
         existing.supports_vision = provider.supports_vision;
         existing.auth_method = provider.auth_method;
         existing.supports_oauth = provider.supports_oauth;
-        // Don't update is_custom - preserve the original value
+        existing.sync_active_credential();
+        // Don't update is_custom, credentials or headers - they have their own commands
     } else {
         // Add new provider
         settings.llm_providers.push(provider.clone());
@@ -92,12 +100,15 @@ pub fn save_llm_provider(app: AppHandle, provider: LLMProvider) -> Result<LLMPro
 /// Delete an LLM provider (any provider can be deleted)
 #[tauri::command]
 #[specta::specta]
-pub fn delete_llm_provider(app: AppHandle, provider_id: String) -> Result<(), String> {
+pub fn delete_llm_provider(app: AppHandle, provider_id: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Check provider exists
     if !settings.llm_providers.iter().any(|p| p.id == provider_id) {
-        return Err(format!("Provider '{}' not found", provider_id));
+        return Err(AppError::not_found(format!(
+            "Provider '{}' not found",
+            provider_id
+        )));
     }
 
     // Remove the provider
@@ -110,10 +121,221 @@ pub fn delete_llm_provider(app: AppHandle, provider_id: String) -> Result<(), St
     Ok(())
 }
 
+fn find_provider<'a>(
+    settings: &'a mut settings::AppSettings,
+    provider_id: &str,
+) -> Result<&'a mut LLMProvider, AppError> {
+    settings
+        .llm_providers
+        .iter_mut()
+        .find(|p| p.id == provider_id)
+        .ok_or_else(|| AppError::not_found(format!("Provider '{}' not found", provider_id)))
+}
+
+/// Save (create or update) a named credential for a provider. Saving the active
+/// credential applies the change immediately.
+#[tauri::command]
+#[specta::specta]
+pub fn save_provider_credential(
+    app: AppHandle,
+    provider_id: String,
+    credential: ProviderCredential,
+) -> Result<LLMProvider, AppError> {
+    if credential.name.trim().is_empty() {
+        return Err(AppError::invalid_input("Credential name cannot be empty"));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    let provider = find_provider(&mut settings, &provider_id)?;
+
+    if let Some(existing) = provider
+        .credentials
+        .iter_mut()
+        .find(|c| c.id == credential.id)
+    {
+        *existing = credential.clone();
+    } else {
+        provider.credentials.push(credential.clone());
+    }
+    if provider.active_credential_id.as_deref() == Some(credential.id.as_str()) {
+        provider.activate_credential(&credential.id)?;
+    }
+
+    let result = provider.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Delete a saved credential. Deleting the active one keeps its key in use until
+/// another credential is selected.
+#[tauri::command]
+#[specta::specta]
+pub fn delete_provider_credential(
+    app: AppHandle,
+    provider_id: String,
+    credential_id: String,
+) -> Result<LLMProvider, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let provider = find_provider(&mut settings, &provider_id)?;
+
+    provider.credentials.retain(|c| c.id != credential_id);
+    if provider.active_credential_id.as_deref() == Some(credential_id.as_str()) {
+        provider.active_credential_id = None;
+    }
+
+    let result = provider.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Switch a provider to one of its saved credentials
+#[tauri::command]
+#[specta::specta]
+pub fn set_active_provider_credential(
+    app: AppHandle,
+    provider_id: String,
+    credential_id: String,
+) -> Result<LLMProvider, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let provider = find_provider(&mut settings, &provider_id)?;
+    provider.activate_credential(&credential_id)?;
+
+    let result = provider.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Replace a provider's custom request headers. Blank names are dropped.
+#[tauri::command]
+#[specta::specta]
+pub fn update_provider_headers(
+    app: AppHandle,
+    provider_id: String,
+    headers: HashMap<String, String>,
+) -> Result<LLMProvider, AppError> {
+    let headers: HashMap<String, String> = headers
+        .into_iter()
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .collect();
+    crate::llm_client::parse_custom_headers(&headers, "")?;
+
+    let mut settings = settings::get_settings(&app);
+    let provider = find_provider(&mut settings, &provider_id)?;
+    provider.custom_headers = headers;
+
+    let result = provider.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Mark a provider as free so its calls don't count toward the spend limits
+#[tauri::command]
+#[specta::specta]
+pub fn set_provider_free(
+    app: AppHandle,
+    provider_id: String,
+    free: bool,
+) -> Result<LLMProvider, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let provider = find_provider(&mut settings, &provider_id)?;
+    provider.free = free;
+
+    let result = provider.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Latest health check results for self-hosted providers
+#[tauri::command]
+#[specta::specta]
+pub fn get_provider_health() -> Vec<ProviderHealth> {
+    provider_health::all()
+}
+
+/// Probe a self-hosted provider now, e.g. after the user starts its server
+#[tauri::command]
+#[specta::specta]
+pub async fn check_provider_health(
+    app: AppHandle,
+    provider_id: String,
+) -> Result<ProviderHealth, AppError> {
+    let provider = settings::get_settings(&app)
+        .llm_providers
+        .into_iter()
+        .find(|p| p.id == provider_id)
+        .ok_or_else(|| AppError::not_found(format!("Provider '{}' not found", provider_id)))?;
+    Ok(provider_health::check(&app, &provider).await)
+}
+
+/// Scan localhost for LM Studio, llama.cpp and Ollama servers that aren't providers yet
+#[tauri::command]
+#[specta::specta]
+pub async fn discover_local_providers(app: AppHandle) -> Vec<DiscoveredServer> {
+    let settings = settings::get_settings(&app);
+    provider_health::discover_local_servers(&settings.llm_providers).await
+}
+
+/// Create a provider for a discovered local server, with all of its models enabled
+#[tauri::command]
+#[specta::specta]
+pub fn add_discovered_provider(
+    app: AppHandle,
+    server: DiscoveredServer,
+) -> Result<LLMProvider, AppError> {
+    let mut settings = settings::get_settings(&app);
+    if settings.llm_providers.iter().any(|p| p.id == server.id) {
+        return Err(AppError::invalid_input(format!(
+            "A provider with ID '{}' already exists",
+            server.id
+        )));
+    }
+
+    let provider = LLMProvider {
+        id: server.id.clone(),
+        name: server.name.clone(),
+        base_url: server.base_url.clone(),
+        // Local servers ignore the key, but the LLM client requires a non-empty one
+        api_key: server.id.clone(),
+        supports_vision: false,
+        is_custom: true,
+        auth_method: AuthMethod::ApiKey,
+        supports_oauth: false,
+        credentials: Vec::new(),
+        active_credential_id: None,
+        custom_headers: HashMap::new(),
+        free: false,
+    };
+    settings.llm_providers.push(provider.clone());
+
+    for model_id in &server.models {
+        let id = preset_model_entry_id(&server.id, model_id);
+        if settings.llm_models.iter().any(|m| m.id == id) {
+            continue;
+        }
+        settings.llm_models.push(LLMModel {
+            id,
+            provider_id: server.id.clone(),
+            model_id: model_id.clone(),
+            display_name: model_id.clone(),
+            supports_vision: false,
+            enabled: true,
+            input_price_per_million: None,
+            output_price_per_million: None,
+            openrouter_routing: None,
+            gemini_options: None,
+            reasoning: None,
+        });
+    }
+
+    settings::write_settings(&app, settings);
+    Ok(provider)
+}
+
 /// Save (create or update) an LLM model
 #[tauri::command]
 #[specta::specta]
-pub fn save_llm_model(app: AppHandle, model: LLMModel) -> Result<LLMModel, String> {
+pub fn save_llm_model(app: AppHandle, model: LLMModel) -> Result<LLMModel, AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Validate that the provider exists
@@ -122,7 +344,10 @@ pub fn save_llm_model(app: AppHandle, model: LLMModel) -> Result<LLMModel, Strin
         .iter()
         .any(|p| p.id == model.provider_id)
     {
-        return Err(format!("Provider '{}' not found", model.provider_id));
+        return Err(AppError::not_found(format!(
+            "Provider '{}' not found",
+            model.provider_id
+        )));
     }
 
     // Check if model already exists
@@ -142,17 +367,101 @@ pub fn save_llm_model(app: AppHandle, model: LLMModel) -> Result<LLMModel, Strin
     Ok(model)
 }
 
+/// Set or clear a model's OpenRouter fallback models and provider preferences
+#[tauri::command]
+#[specta::specta]
+pub fn set_model_openrouter_routing(
+    app: AppHandle,
+    model_id: String,
+    routing: Option<OpenRouterRouting>,
+) -> Result<LLMModel, AppError> {
+    if let Some(routing) = &routing {
+        crate::openrouter::validate(routing).map_err(AppError::invalid_input)?;
+    }
+
+    let mut settings = settings::get_settings(&app);
+    let model = settings
+        .llm_models
+        .iter_mut()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| AppError::not_found(format!("Model '{}' not found", model_id)))?;
+    model.openrouter_routing = routing;
+
+    let result = model.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Set or clear a model's Gemini thinking budget and safety threshold
+#[tauri::command]
+#[specta::specta]
+pub fn set_model_gemini_options(
+    app: AppHandle,
+    model_id: String,
+    options: Option<GeminiOptions>,
+) -> Result<LLMModel, AppError> {
+    if let Some(options) = &options {
+        crate::gemini::validate(options).map_err(AppError::invalid_input)?;
+    }
+
+    let mut settings = settings::get_settings(&app);
+    let model = settings
+        .llm_models
+        .iter_mut()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| AppError::not_found(format!("Model '{}' not found", model_id)))?;
+    model.gemini_options = options;
+
+    let result = model.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
+/// Set or clear a model's reasoning effort, thinking budget and trace hiding
+#[tauri::command]
+#[specta::specta]
+pub fn set_model_reasoning(
+    app: AppHandle,
+    model_id: String,
+    reasoning: Option<ReasoningOptions>,
+) -> Result<LLMModel, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let model = settings
+        .llm_models
+        .iter_mut()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| AppError::not_found(format!("Model '{}' not found", model_id)))?;
+    if let Some(reasoning) = &reasoning {
+        let provider = settings
+            .llm_providers
+            .iter()
+            .find(|p| p.id == model.provider_id)
+            .ok_or_else(|| {
+                AppError::not_found(format!("Provider '{}' not found", model.provider_id))
+            })?;
+        crate::reasoning::validate(provider, reasoning).map_err(AppError::invalid_input)?;
+    }
+    model.reasoning = reasoning;
+
+    let result = model.clone();
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
+
 /// Delete an LLM model
 #[tauri::command]
 #[specta::specta]
-pub fn delete_llm_model(app: AppHandle, model_id: String) -> Result<(), String> {
+pub fn delete_llm_model(app: AppHandle, model_id: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     let original_len = settings.llm_models.len();
     settings.llm_models.retain(|m| m.id != model_id);
 
     if settings.llm_models.len() == original_len {
-        return Err(format!("Model '{}' not found", model_id));
+        return Err(AppError::not_found(format!(
+            "Model '{}' not found",
+            model_id
+        )));
     }
 
     // Clear default selections if this model was selected
@@ -168,6 +477,12 @@ pub fn delete_llm_model(app: AppHandle, model_id: String) -> Result<(), String>
     if settings.default_context_chat_model_id.as_ref() == Some(&model_id) {
         settings.default_context_chat_model_id = None;
     }
+    if settings.default_title_model_id.as_ref() == Some(&model_id) {
+        settings.default_title_model_id = None;
+    }
+    settings
+        .coherent_fallback_model_ids
+        .retain(|id| id != &model_id);
 
     settings::write_settings(&app, settings);
     Ok(())
@@ -180,13 +495,13 @@ pub fn set_default_model(
     app: AppHandle,
     feature: String,
     model_id: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Validate model exists if specified
     if let Some(ref id) = model_id {
         if !settings.llm_models.iter().any(|m| &m.id == id) {
-            return Err(format!("Model '{}' not found", id));
+            return Err(AppError::not_found(format!("Model '{}' not found", id)));
         }
     }
 
@@ -196,11 +511,12 @@ pub fn set_default_model(
         "coherent" => settings.default_coherent_model_id = model_id,
         "voice" => settings.default_voice_model_id = model_id,
         "context_chat" => settings.default_context_chat_model_id = model_id,
+        "title" => settings.default_title_model_id = model_id,
         _ => {
-            return Err(format!(
-                "Unknown feature '{}'. Valid: chat, coherent, voice, context_chat",
+            return Err(AppError::invalid_input(format!(
+                "Unknown feature '{}'. Valid: chat, coherent, voice, context_chat, title",
                 feature
-            ))
+            )))
         }
     }
 
@@ -208,6 +524,37 @@ pub fn set_default_model(
     Ok(())
 }
 
+/// Set the models refinement falls back to, in order, when the coherent model's
+/// provider is rate limited, overloaded or down
+#[tauri::command]
+#[specta::specta]
+pub fn set_coherent_fallback_models(
+    app: AppHandle,
+    model_ids: Vec<String>,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(missing) = model_ids
+        .iter()
+        .find(|id| !settings.llm_models.iter().any(|m| &m.id == *id))
+    {
+        return Err(AppError::not_found(format!(
+            "Model '{}' not found",
+            missing
+        )));
+    }
+
+    let mut unique = Vec::new();
+    for id in model_ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    settings.coherent_fallback_model_ids = unique;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 /// Get default model IDs for all features
 #[tauri::command]
 #[specta::specta]
@@ -218,6 +565,7 @@ pub fn get_default_models(app: AppHandle) -> DefaultModels {
         coherent: settings.default_coherent_model_id.clone(),
         voice: settings.default_voice_model_id.clone(),
         context_chat: settings.default_context_chat_model_id.clone(),
+        title: settings.default_title_model_id.clone(),
     }
 }
 
@@ -227,6 +575,7 @@ pub struct DefaultModels {
     pub coherent: Option<String>,
     pub voice: Option<String>,
     pub context_chat: Option<String>,
+    pub title: Option<String>,
 }
 
 /// Get the OpenAI reasoning effort setting
@@ -241,14 +590,14 @@ pub fn get_openai_reasoning_effort(app: AppHandle) -> String {
 /// Valid values: "none", "low", "medium", "high", "xhigh"
 #[tauri::command]
 #[specta::specta]
-pub fn set_openai_reasoning_effort(app: AppHandle, effort: String) -> Result<(), String> {
+pub fn set_openai_reasoning_effort(app: AppHandle, effort: String) -> Result<(), AppError> {
     let valid_efforts = ["none", "low", "medium", "high", "xhigh"];
     if !valid_efforts.contains(&effort.as_str()) {
-        return Err(format!(
+        return Err(AppError::invalid_input(format!(
             "Invalid reasoning effort '{}'. Valid values: {}",
             effort,
             valid_efforts.join(", ")
-        ));
+        )));
     }
 
     let mut settings = settings::get_settings(&app);
@@ -256,3 +605,197 @@ pub fn set_openai_reasoning_effort(app: AppHandle, effort: String) -> Result<(),
     settings::write_settings(&app, settings);
     Ok(())
 }
+
+/// A curated OpenAI-compatible provider with a sensible set of default models
+#[derive(serde::Serialize, specta::Type, Debug, Clone)]
+pub struct ProviderPreset {
+    /// Provider ID created by this preset (e.g., "groq")
+    pub id: String,
+    pub name: String,
+    pub base_url: String,
+    /// Whether an API key is required (false for local servers like Ollama)
+    pub requires_api_key: bool,
+    pub supports_vision: bool,
+    pub models: Vec<ProviderPresetModel>,
+}
+
+#[derive(serde::Serialize, specta::Type, Debug, Clone)]
+pub struct ProviderPresetModel {
+    pub model_id: String,
+    pub display_name: String,
+    pub supports_vision: bool,
+}
+
+fn preset_model(model_id: &str, display_name: &str, supports_vision: bool) -> ProviderPresetModel {
+    ProviderPresetModel {
+        model_id: model_id.to_string(),
+        display_name: display_name.to_string(),
+        supports_vision,
+    }
+}
+
+fn provider_presets() -> Vec<ProviderPreset> {
+    vec![
+        ProviderPreset {
+            id: "groq".to_string(),
+            name: "Groq".to_string(),
+            base_url: "https://api.groq.com/openai/v1".to_string(),
+            requires_api_key: true,
+            supports_vision: false,
+            models: vec![
+                preset_model("llama-3.3-70b-versatile", "Llama 3.3 70B Versatile", false),
+                preset_model("llama-3.1-8b-instant", "Llama 3.1 8B Instant", false),
+            ],
+        },
+        ProviderPreset {
+            id: "together".to_string(),
+            name: "Together AI".to_string(),
+            base_url: "https://api.together.xyz/v1".to_string(),
+            requires_api_key: true,
+            supports_vision: false,
+            models: vec![
+                preset_model(
+                    "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+                    "Llama 3.3 70B Instruct Turbo",
+                    false,
+                ),
+                preset_model(
+                    "Qwen/Qwen2.5-72B-Instruct-Turbo",
+                    "Qwen 2.5 72B Instruct Turbo",
+                    false,
+                ),
+            ],
+        },
+        ProviderPreset {
+            id: "mistral".to_string(),
+            name: "Mistral".to_string(),
+            base_url: "https://api.mistral.ai/v1".to_string(),
+            requires_api_key: true,
+            supports_vision: true,
+            models: vec![
+                preset_model("mistral-large-latest", "Mistral Large", false),
+                preset_model("mistral-small-latest", "Mistral Small", true),
+            ],
+        },
+        ProviderPreset {
+            id: "deepseek".to_string(),
+            name: "DeepSeek".to_string(),
+            base_url: "https://api.deepseek.com/v1".to_string(),
+            requires_api_key: true,
+            supports_vision: false,
+            models: vec![
+                preset_model("deepseek-chat", "DeepSeek Chat", false),
+                preset_model("deepseek-reasoner", "DeepSeek Reasoner", false),
+            ],
+        },
+        ProviderPreset {
+            id: "ollama".to_string(),
+            name: "Ollama (Local)".to_string(),
+            base_url: "http://localhost:11434/v1".to_string(),
+            requires_api_key: false,
+            supports_vision: true,
+            models: vec![
+                preset_model("llama3.2", "Llama 3.2", false),
+                preset_model("qwen2.5", "Qwen 2.5", false),
+            ],
+        },
+    ]
+}
+
+/// Model IDs follow the same `{provider}-{model}` scheme the provider dialog uses
+fn preset_model_entry_id(provider_id: &str, model_id: &str) -> String {
+    format!("{}-{}", provider_id, model_id.replace('/', "-"))
+}
+
+/// Get the curated provider presets available for one-click setup
+#[tauri::command]
+#[specta::specta]
+pub fn get_provider_presets() -> Vec<ProviderPreset> {
+    provider_presets()
+}
+
+/// Create (or update) a provider from a preset along with its default models.
+/// Existing models are left untouched so user-toggled `enabled` state is preserved.
+#[tauri::command]
+#[specta::specta]
+pub fn apply_provider_preset(
+    app: AppHandle,
+    preset_id: String,
+    api_key: String,
+) -> Result<LLMProvider, AppError> {
+    let preset = provider_presets()
+        .into_iter()
+        .find(|p| p.id == preset_id)
+        .ok_or_else(|| AppError::not_found(format!("Provider preset '{}' not found", preset_id)))?;
+
+    let api_key = match api_key.trim() {
+        "" if preset.requires_api_key => {
+            return Err(
+                AppError::not_configured(format!("{} requires an API key", preset.name))
+                    .with_hint(API_KEY_HINT),
+            );
+        }
+        // Local servers ignore the key, but the LLM client requires a non-empty one
+        "" => preset.id.clone(),
+        key => key.to_string(),
+    };
+
+    let mut settings = settings::get_settings(&app);
+
+    let provider = LLMProvider {
+        id: preset.id.clone(),
+        name: preset.name.clone(),
+        base_url: preset.base_url.clone(),
+        api_key,
+        supports_vision: preset.supports_vision,
+        is_custom: false,
+        auth_method: AuthMethod::ApiKey,
+        supports_oauth: false,
+        credentials: Vec::new(),
+        active_credential_id: None,
+        custom_headers: HashMap::new(),
+        free: false,
+    };
+
+    if let Some(existing) = settings
+        .llm_providers
+        .iter_mut()
+        .find(|p| p.id == provider.id)
+    {
+        existing.base_url = provider.base_url.clone();
+        existing.api_key = provider.api_key.clone();
+        existing.sync_active_credential();
+    } else {
+        settings.llm_providers.push(provider.clone());
+    }
+
+    for model in &preset.models {
+        let id = preset_model_entry_id(&preset.id, &model.model_id);
+        if settings.llm_models.iter().any(|m| m.id == id) {
+            continue;
+        }
+        settings.llm_models.push(LLMModel {
+            id,
+            provider_id: preset.id.clone(),
+            model_id: model.model_id.clone(),
+            display_name: model.display_name.clone(),
+            supports_vision: model.supports_vision,
+            enabled: true,
+            input_price_per_million: None,
+            output_price_per_million: None,
+            openrouter_routing: None,
+            gemini_options: None,
+            reasoning: None,
+        });
+    }
+
+    let result = settings
+        .llm_providers
+        .iter()
+        .find(|p| p.id == preset.id)
+        .cloned()
+        .unwrap_or(provider);
+
+    settings::write_settings(&app, settings);
+    Ok(result)
+}
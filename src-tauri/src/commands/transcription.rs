@@ -1,8 +1,17 @@
+use crate::audio_toolkit::decode_audio_file;
+use crate::edits::{align_words, EditAlignment};
+use crate::errors::AppError;
+use crate::managers::history::HistoryManager;
 use crate::managers::transcription::TranscriptionManager;
 use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
 use serde::Serialize;
 use specta::Type;
-use tauri::{AppHandle, State};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+/// Audio file extensions that can be imported for transcription
+const IMPORTABLE_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg"];
 
 #[derive(Serialize, Type)]
 pub struct ModelLoadStatus {
@@ -22,7 +31,7 @@ pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
 #[specta::specta]
 pub fn get_model_load_status(
     transcription_manager: State<TranscriptionManager>,
-) -> Result<ModelLoadStatus, String> {
+) -> Result<ModelLoadStatus, AppError> {
     Ok(ModelLoadStatus {
         is_loaded: transcription_manager.is_model_loaded(),
         current_model: transcription_manager.get_current_model(),
@@ -33,8 +42,141 @@ pub fn get_model_load_status(
 #[specta::specta]
 pub fn unload_model_manually(
     transcription_manager: State<TranscriptionManager>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     transcription_manager
         .unload_model()
-        .map_err(|e| format!("Failed to unload model: {}", e))
+        .map_err(|e| AppError::internal(format!("Failed to unload model: {}", e)))
+}
+
+/// Result of transcribing an imported audio file
+#[derive(Serialize, Type)]
+pub struct ImportedTranscription {
+    /// History entry the transcription was saved to
+    history_id: i64,
+    text: String,
+    /// Coherent refinement of `text`, when requested and it succeeded
+    refined_text: Option<String>,
+}
+
+/// Transcribe an existing audio file (e.g. a voice memo) and save it to history,
+/// optionally refining it with the coherent prompt
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_audio_file(
+    app: AppHandle,
+    path: String,
+    refine: bool,
+) -> Result<ImportedTranscription, AppError> {
+    let path = PathBuf::from(path);
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    if !IMPORTABLE_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "Unsupported audio file type '{}'. Supported: {}",
+            extension,
+            IMPORTABLE_EXTENSIONS.join(", ")
+        )));
+    }
+
+    let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
+    let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
+    tm.initiate_model_load();
+    // Imported audio has no frontmost app, so use the global language
+    tm.set_language_override(None);
+
+    let samples = tauri::async_runtime::spawn_blocking(move || decode_audio_file(&path))
+        .await
+        .map_err(|e| format!("Failed to decode audio: {}", e))??;
+    if samples.is_empty() {
+        return Err(AppError::invalid_input("The audio file contains no audio"));
+    }
+
+    // Saved first so the audio is kept even if transcription fails
+    let history_id = hm
+        .save_recording_only(&samples)
+        .await
+        .map_err(|e| format!("Failed to save recording: {}", e))?;
+
+    let transcript = {
+        let tm = Arc::clone(&tm);
+        // Long voice memos are split into chunks the engine can handle
+        tauri::async_runtime::spawn_blocking(move || tm.transcribe_chunked(samples))
+            .await
+            .map_err(|e| format!("Transcription failed: {}", e))?
+    };
+    let transcript = match transcript {
+        Ok(transcript) => transcript,
+        Err(e) => {
+            let message = format!("Transcription failed: {}", e);
+            let _ = hm
+                .update_transcription_error(history_id, message.clone())
+                .await;
+            return Err(message.into());
+        }
+    };
+
+    let settings = get_settings(&app);
+    let refinement = if refine && !transcript.text.is_empty() {
+        match crate::actions::refine_transcription(&app, &settings, &transcript.text).await {
+            Ok(refined) => refined,
+            Err(e) => {
+                log::warn!("Refining imported audio failed: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let edit_alignment = refinement
+        .as_ref()
+        .filter(|_| settings.show_edits)
+        .map(|refined| EditAlignment {
+            spans: align_words(&transcript.text, &refined.text),
+            notes: refined.edit_notes.clone(),
+        });
+    let invented_terms = refinement
+        .as_ref()
+        .map(|refined| refined.invented_terms.clone())
+        .unwrap_or_default();
+    let refined_text = refinement.map(|refined| refined.text);
+    let prompt = refined_text.as_ref().and_then(|_| {
+        settings
+            .coherent_selected_prompt_id
+            .as_ref()
+            .and_then(|id| settings.coherent_prompts.iter().find(|p| &p.id == id))
+            .map(|p| p.prompt.clone())
+    });
+
+    hm.update_transcription(
+        history_id,
+        transcript.text.clone(),
+        refined_text.clone(),
+        prompt,
+    )
+    .await
+    .map_err(|e| format!("Failed to save transcription: {}", e))?;
+    if !transcript.words.is_empty() {
+        if let Err(e) = hm.save_word_timings(history_id, &transcript.words).await {
+            log::error!("Failed to save word timings: {}", e);
+        }
+    }
+    if let Some(alignment) = edit_alignment {
+        if let Err(e) = hm.save_edit_alignment(history_id, &alignment).await {
+            log::error!("Failed to save edit alignment: {}", e);
+        }
+    }
+    if !invented_terms.is_empty() {
+        if let Err(e) = hm.flag_invented_terms(history_id, &invented_terms).await {
+            log::error!("Failed to flag invented terms: {}", e);
+        }
+    }
+
+    Ok(ImportedTranscription {
+        history_id,
+        text: transcript.text,
+        refined_text,
+    })
 }
@@ -0,0 +1,64 @@
+use crate::errors::AppError;
+use crate::settings::{self, DeletedItem};
+use crate::trash;
+use tauri::AppHandle;
+
+/// List soft-deleted items, most recently deleted first
+#[tauri::command]
+#[specta::specta]
+pub fn list_deleted_items(app: AppHandle) -> Vec<DeletedItem> {
+    let mut items = settings::get_settings(&app).deleted_items;
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    items
+}
+
+/// Restore a soft-deleted item by its trash entry ID
+#[tauri::command]
+#[specta::specta]
+pub fn restore_item(app: AppHandle, id: String) -> Result<DeletedItem, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let restored = trash::restore(&mut settings, &id)?;
+    settings::write_settings(&app, settings);
+    Ok(restored)
+}
+
+/// Permanently delete a single item from the trash
+#[tauri::command]
+#[specta::specta]
+pub fn purge_deleted_item(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    let original_len = settings.deleted_items.len();
+    settings.deleted_items.retain(|d| d.id != id);
+
+    if settings.deleted_items.len() == original_len {
+        return Err(AppError::not_found(format!(
+            "Deleted item '{}' not found",
+            id
+        )));
+    }
+
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Permanently delete everything in the trash
+#[tauri::command]
+#[specta::specta]
+pub fn empty_trash(app: AppHandle) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.deleted_items.clear();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set how many days deleted items are kept, purging anything now past the new limit
+#[tauri::command]
+#[specta::specta]
+pub fn change_trash_retention_days_setting(app: AppHandle, days: u32) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.trash_retention_days = days;
+    trash::purge_expired(&mut settings, chrono::Utc::now().timestamp());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
@@ -1,18 +1,85 @@
+use crate::errors::AppError;
 use crate::managers::tts::TTSManager;
+use crate::settings::{get_settings, write_settings};
+use crate::tts::cloud::{self, TTS_SERVICES};
+use crate::tts::speakable_text;
+use crate::tts::system;
+use crate::tts::TtsVoice;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 
+/// Read text aloud, such as a chat reply; Markdown is reduced to plain text first
 #[tauri::command]
 #[specta::specta]
 pub async fn speak_text(
     tts_manager: State<'_, Arc<TTSManager>>,
     text: String,
-) -> Result<(), String> {
-    tts_manager.speak(&text).await.map_err(|e| e.to_string())
+) -> Result<(), AppError> {
+    let text = speakable_text(&text);
+    if text.is_empty() {
+        return Ok(());
+    }
+    tts_manager.speak(&text).await.map_err(AppError::from)
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn stop_tts(tts_manager: State<'_, Arc<TTSManager>>) -> Result<(), String> {
-    tts_manager.stop().await.map_err(|e| e.to_string())
+pub async fn stop_tts(tts_manager: State<'_, Arc<TTSManager>>) -> Result<(), AppError> {
+    tts_manager.stop().await.map_err(AppError::from)
+}
+
+/// Voices the operating system's synthesizer offers, for the system backend
+#[tauri::command]
+#[specta::specta]
+pub async fn list_system_voices() -> Result<Vec<TtsVoice>, AppError> {
+    tokio::task::spawn_blocking(system::list_voices)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to list voices: {}", e)))?
+        .map_err(AppError::from)
+}
+
+/// Voices a cloud TTS service offers. ElevenLabs lists the voices in the account,
+/// so it needs the service's API key
+#[tauri::command]
+#[specta::specta]
+pub async fn list_cloud_tts_voices(
+    app: AppHandle,
+    service: String,
+) -> Result<Vec<TtsVoice>, AppError> {
+    let api_key = cloud::tts_api_key(&get_settings(&app), &service);
+    if api_key.is_none() && service == "elevenlabs" {
+        return Err(AppError::not_configured("No API key set for ElevenLabs")
+            .with_hint("Enter an ElevenLabs API key to list its voices."));
+    }
+    Ok(cloud::list_voices(&service, api_key).await?)
+}
+
+/// Set (or clear, with an empty key) the API key for a cloud TTS service
+#[tauri::command]
+#[specta::specta]
+pub async fn set_tts_api_key(
+    app: AppHandle,
+    tts_manager: State<'_, Arc<TTSManager>>,
+    service: String,
+    api_key: String,
+) -> Result<(), AppError> {
+    if !TTS_SERVICES.contains(&service.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "Unknown speech service: {}",
+            service
+        )));
+    }
+
+    let mut settings = get_settings(&app);
+    let api_key = api_key.trim().to_string();
+    if api_key.is_empty() {
+        settings.tts_api_keys.remove(&service);
+    } else {
+        settings.tts_api_keys.insert(service, api_key);
+    }
+    write_settings(&app, settings);
+
+    // A loaded cloud engine holds the old key
+    tts_manager.unload().await;
+    Ok(())
 }
@@ -0,0 +1,23 @@
+use crate::errors::AppError;
+use crate::managers::usage::{UsageManager, UsageReport, UsageSummary};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Estimated LLM spend today and this month, from the usage ledger
+#[tauri::command]
+#[specta::specta]
+pub fn get_usage_summary(app: AppHandle) -> Result<UsageSummary, AppError> {
+    let manager = app.state::<Arc<UsageManager>>();
+    manager.summary().map_err(AppError::from)
+}
+
+/// Tokens and estimated cost per provider/model for a month ("YYYY-MM", default
+/// the current one)
+#[tauri::command]
+#[specta::specta]
+pub fn get_usage_report(app: AppHandle, month: Option<String>) -> Result<UsageReport, AppError> {
+    let manager = app.state::<Arc<UsageManager>>();
+    manager
+        .monthly_report(month.as_deref())
+        .map_err(AppError::from)
+}
@@ -0,0 +1,387 @@
+use crate::errors::AppError;
+use crate::settings::{get_settings, write_settings, ScriptType, VoiceCommand, VoiceCommandType};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Bumped when the pack file layout changes incompatibly
+const PACK_VERSION: u32 = 1;
+
+/// Layout of a voice command pack file
+#[derive(Serialize, Deserialize)]
+struct VoiceCommandPack {
+    version: u32,
+    name: String,
+    commands: Vec<VoiceCommand>,
+}
+
+/// What to do with an imported command whose id or a trigger phrase is already used
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PackConflict {
+    /// Keep the existing command and drop the imported one
+    Skip,
+    /// Replace the existing command with the imported one. Built-in commands are
+    /// never replaced.
+    Replace,
+    /// Add the imported command under a new id and name
+    KeepBoth,
+}
+
+/// What importing one command of a pack does
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PackCommandOutcome {
+    /// Added without a conflict
+    Add,
+    /// Replaces an existing command
+    Replace,
+    /// Added under a new name next to the command it conflicts with
+    Rename,
+    /// Not imported
+    Skip,
+}
+
+/// A command of a pack, with the script it would run and what importing it does
+#[derive(Serialize, Type, Debug)]
+pub struct PackCommandPreview {
+    pub name: String,
+    pub phrases: Vec<String>,
+    pub script_type: ScriptType,
+    pub script: Option<String>,
+    pub outcome: PackCommandOutcome,
+    /// Name of the existing command it conflicts with
+    pub conflicts_with: Option<String>,
+}
+
+/// A pack's commands as they would be imported, shown before anything is merged
+#[derive(Serialize, Type, Debug)]
+pub struct PackPreview {
+    pub name: String,
+    pub commands: Vec<PackCommandPreview>,
+    /// Passed to `import_voice_command_pack` to confirm the import. It covers the
+    /// file and the conflict choice, so a pack changed after the preview isn't
+    /// imported.
+    pub confirmation: String,
+}
+
+/// What an import changed
+#[derive(Serialize, Type, Debug, Default)]
+pub struct PackImportResult {
+    /// Commands added without a conflict
+    pub added: usize,
+    /// Existing commands replaced by imported ones
+    pub replaced: usize,
+    /// Commands added under a new name next to the one they conflicted with
+    pub renamed: usize,
+    /// Names of commands that weren't imported
+    pub skipped: Vec<String>,
+}
+
+fn normalize_phrase(phrase: &str) -> String {
+    phrase.trim().to_lowercase()
+}
+
+/// Index of the existing command that `command` conflicts with, by id first and
+/// then by a shared trigger phrase
+fn find_conflict(existing: &[VoiceCommand], command: &VoiceCommand) -> Option<usize> {
+    existing
+        .iter()
+        .position(|c| c.id == command.id)
+        .or_else(|| {
+            existing.iter().position(|c| {
+                c.phrases.iter().any(|p| {
+                    command
+                        .phrases
+                        .iter()
+                        .any(|q| normalize_phrase(p) == normalize_phrase(q))
+                })
+            })
+        })
+}
+
+/// An id not used by any of `existing`, based on `id`
+fn unused_id(existing: &[VoiceCommand], id: &str) -> String {
+    (2..)
+        .map(|n| format!("{}_{}", id, n))
+        .find(|candidate| !existing.iter().any(|c| c.id == *candidate))
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Merge one imported command into `existing`, returning what was done and the name
+/// of the existing command it conflicted with. Only custom commands are imported,
+/// since built-in ones are part of every install.
+fn merge_command(
+    existing: &mut Vec<VoiceCommand>,
+    mut command: VoiceCommand,
+    conflict: PackConflict,
+) -> (PackCommandOutcome, Option<String>) {
+    if command.is_builtin
+        || command.command_type != VoiceCommandType::Custom
+        || crate::voice_commands::validate_parameters(&command).is_err()
+    {
+        return (PackCommandOutcome::Skip, None);
+    }
+    let Some(index) = find_conflict(existing, &command) else {
+        existing.push(command);
+        return (PackCommandOutcome::Add, None);
+    };
+
+    let conflicts_with = Some(existing[index].name.clone());
+    let outcome = match conflict {
+        PackConflict::Replace if !existing[index].is_builtin => {
+            existing[index] = command;
+            PackCommandOutcome::Replace
+        }
+        PackConflict::KeepBoth => {
+            command.id = unused_id(existing, &command.id);
+            command.name = format!("{} (imported)", command.name);
+            existing.push(command);
+            PackCommandOutcome::Rename
+        }
+        _ => PackCommandOutcome::Skip,
+    };
+    (outcome, conflicts_with)
+}
+
+/// Merge `pack` into `existing`
+fn merge_pack(
+    existing: &mut Vec<VoiceCommand>,
+    pack: Vec<VoiceCommand>,
+    conflict: PackConflict,
+) -> PackImportResult {
+    let mut result = PackImportResult::default();
+    for command in pack {
+        let name = command.name.clone();
+        match merge_command(existing, command, conflict).0 {
+            PackCommandOutcome::Add => result.added += 1,
+            PackCommandOutcome::Replace => result.replaced += 1,
+            PackCommandOutcome::Rename => result.renamed += 1,
+            PackCommandOutcome::Skip => result.skipped.push(name),
+        }
+    }
+    result
+}
+
+/// What merging `pack` into `existing` would do to each of its commands, leaving
+/// `existing` as it is
+fn preview_pack(
+    existing: &[VoiceCommand],
+    pack: &[VoiceCommand],
+    conflict: PackConflict,
+) -> Vec<PackCommandPreview> {
+    let mut merged = existing.to_vec();
+    pack.iter()
+        .map(|command| {
+            let (outcome, conflicts_with) = merge_command(&mut merged, command.clone(), conflict);
+            PackCommandPreview {
+                name: command.name.clone(),
+                phrases: command.phrases.clone(),
+                script_type: command.script_type,
+                script: command.script.clone(),
+                outcome,
+                conflicts_with,
+            }
+        })
+        .collect()
+}
+
+/// Read the pack at `path`, and the confirmation for importing it with `conflict`
+fn read_pack(path: &str, conflict: PackConflict) -> Result<(VoiceCommandPack, String), AppError> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let pack: VoiceCommandPack =
+        serde_json::from_str(&contents).map_err(|e| format!("Not a voice command pack: {}", e))?;
+    if pack.version > PACK_VERSION {
+        return Err(AppError::invalid_input(format!(
+            "Voice command pack version {} is newer than this version of Ramble supports",
+            pack.version
+        )));
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(contents.as_bytes());
+    hasher.update(format!("{:?}", conflict).as_bytes());
+    let confirmation = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Ok((pack, confirmation))
+}
+
+/// Write voice commands to `path` as a pack named `name`. With no `command_ids`,
+/// every custom command is exported; built-in commands never are. Returns how many
+/// commands were exported.
+#[tauri::command]
+#[specta::specta]
+pub fn export_voice_command_pack(
+    app: AppHandle,
+    path: String,
+    name: String,
+    command_ids: Vec<String>,
+) -> Result<usize, AppError> {
+    let commands: Vec<VoiceCommand> = get_settings(&app)
+        .voice_commands
+        .into_iter()
+        .filter(|c| !c.is_builtin && c.command_type == VoiceCommandType::Custom)
+        .filter(|c| command_ids.is_empty() || command_ids.contains(&c.id))
+        .collect();
+    if commands.is_empty() {
+        return Err(AppError::invalid_input(
+            "There are no custom voice commands to export",
+        ));
+    }
+
+    let pack = VoiceCommandPack {
+        version: PACK_VERSION,
+        name,
+        commands,
+    };
+    let contents = serde_json::to_string_pretty(&pack)
+        .map_err(|e| format!("Failed to serialize voice commands: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok(pack.commands.len())
+}
+
+/// Show what importing the pack at `path` with `conflict` would do, with the script
+/// of every command, so the user can check them before confirming the import
+#[tauri::command]
+#[specta::specta]
+pub fn preview_voice_command_pack(
+    app: AppHandle,
+    path: String,
+    conflict: PackConflict,
+) -> Result<PackPreview, AppError> {
+    let (pack, confirmation) = read_pack(&path, conflict)?;
+    let commands = preview_pack(&get_settings(&app).voice_commands, &pack.commands, conflict);
+    Ok(PackPreview {
+        name: pack.name,
+        commands,
+        confirmation,
+    })
+}
+
+/// Merge commands from a pack written by `export_voice_command_pack`, resolving
+/// commands that share an id or trigger phrase with an existing one by `conflict`.
+/// `confirmation` comes from `preview_voice_command_pack`, so only a pack whose
+/// scripts were shown is imported.
+#[tauri::command]
+#[specta::specta]
+pub fn import_voice_command_pack(
+    app: AppHandle,
+    path: String,
+    conflict: PackConflict,
+    confirmation: String,
+) -> Result<PackImportResult, AppError> {
+    let (pack, expected) = read_pack(&path, conflict)?;
+    if confirmation != expected {
+        return Err(AppError::invalid_input(
+            "The pack or conflict choice changed since it was previewed. Preview it again before importing.",
+        ));
+    }
+
+    let mut settings = get_settings(&app);
+    let result = merge_pack(&mut settings.voice_commands, pack.commands, conflict);
+    write_settings(&app, settings);
+    log::info!(
+        "Imported voice command pack '{}' from {}: {} added, {} replaced, {} renamed, {} skipped",
+        pack.name,
+        path,
+        result.added,
+        result.replaced,
+        result.renamed,
+        result.skipped.len()
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(id: &str, phrase: &str) -> VoiceCommand {
+        VoiceCommand {
+            id: id.to_string(),
+            name: id.to_string(),
+            phrases: vec![phrase.to_string()],
+            command_type: VoiceCommandType::Custom,
+            description: None,
+            script_type: ScriptType::Shell,
+            script: Some("echo hi".to_string()),
+            model_override: None,
+            is_builtin: false,
+            parameters: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_pack_adds_and_skips() {
+        let mut builtin = command("open_app", "open");
+        builtin.is_builtin = true;
+        let mut existing = vec![command("deploy", "ship it")];
+        let result = merge_pack(
+            &mut existing,
+            vec![
+                command("deploy", "deploy now"),
+                command("standup", "Ship It "),
+                command("lint", "run lint"),
+                builtin,
+            ],
+            PackConflict::Skip,
+        );
+        assert_eq!(result.added, 1);
+        assert_eq!(result.skipped, vec!["deploy", "standup", "open_app"]);
+        assert_eq!(existing.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_pack_replace() {
+        let mut builtin = command("lucky_search", "search for");
+        builtin.is_builtin = true;
+        let mut existing = vec![command("deploy", "ship it"), builtin];
+        let result = merge_pack(
+            &mut existing,
+            vec![
+                command("deploy", "deploy now"),
+                command("web", "search for"),
+            ],
+            PackConflict::Replace,
+        );
+        assert_eq!(result.replaced, 1);
+        assert_eq!(result.skipped, vec!["web"]);
+        assert_eq!(existing[0].phrases, vec!["deploy now"]);
+        assert!(existing[1].is_builtin);
+    }
+
+    #[test]
+    fn test_merge_pack_keep_both() {
+        let mut existing = vec![command("deploy", "ship it"), command("deploy_2", "x")];
+        let result = merge_pack(
+            &mut existing,
+            vec![command("deploy", "deploy now")],
+            PackConflict::KeepBoth,
+        );
+        assert_eq!(result.renamed, 1);
+        assert_eq!(existing[2].id, "deploy_3");
+        assert_eq!(existing[2].name, "deploy (imported)");
+    }
+
+    #[test]
+    fn test_preview_pack_shows_scripts_without_merging() {
+        let existing = vec![command("deploy", "ship it")];
+        let mut replacement = command("other", "Ship it");
+        replacement.script = Some("rm -rf ~/work".to_string());
+        let preview = preview_pack(
+            &existing,
+            &[replacement, command("lint", "run lint")],
+            PackConflict::Replace,
+        );
+        assert_eq!(preview[0].outcome, PackCommandOutcome::Replace);
+        assert_eq!(preview[0].conflicts_with.as_deref(), Some("deploy"));
+        assert_eq!(preview[0].script.as_deref(), Some("rm -rf ~/work"));
+        assert_eq!(preview[1].outcome, PackCommandOutcome::Add);
+        assert_eq!(existing[0].script.as_deref(), Some("echo hi"));
+    }
+}
@@ -0,0 +1,77 @@
+//! Today's earlier dictations, given to the refiner as reference so names and
+//! references ("the Henderson proposal") are spelled and expanded the same way all
+//! day.
+//!
+//! Only the most recent dictations that fit in `MAX_CONTEXT_CHARS` are used, so the
+//! window rolls forward as the day goes on and starts over at local midnight.
+
+use crate::managers::history::HistoryManager;
+use crate::managers::usage::start_of_today;
+use crate::settings::AppSettings;
+use log::warn;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Most text from earlier dictations added to a refinement request
+const MAX_CONTEXT_CHARS: usize = 4_000;
+
+/// Most earlier dictations considered
+const MAX_ENTRIES: usize = 100;
+
+/// Join the newest of `texts` (given newest first) that fit in `max_chars`, oldest
+/// first so they read in the order they were said
+fn join_recent(texts: &[String], max_chars: usize) -> String {
+    let mut used = 0;
+    let mut kept: Vec<&str> = Vec::new();
+    for text in texts.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        let chars = text.chars().count();
+        if used + chars > max_chars {
+            break;
+        }
+        used += chars;
+        kept.push(text);
+    }
+    kept.reverse();
+    kept.join("\n---\n")
+}
+
+/// Today's earlier dictations as a section to append to the refinement prompt, or
+/// None when day context is off or nothing has been dictated yet today
+pub fn prompt_section(app: &AppHandle, settings: &AppSettings) -> Option<String> {
+    if !settings.day_context_enabled {
+        return None;
+    }
+    let history = app.state::<Arc<HistoryManager>>();
+    let texts = history
+        .get_texts_since(start_of_today(), MAX_ENTRIES)
+        .map_err(|e| warn!("Failed to read today's dictations: {}", e))
+        .ok()?;
+    let context = join_recent(&texts, MAX_CONTEXT_CHARS);
+    if context.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "\n\n<earlier_today>\n{}\n</earlier_today>\nThe above is what the user dictated earlier today, for reference only. Spell names and terms the way they appear there, and when the transcript refers back to something mentioned there, keep the wording consistent. Do not include any of it in your output.",
+        context
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_recent() {
+        let texts: Vec<String> = ["newest", " ", "middle", "oldest entry"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            join_recent(&texts, 100),
+            "oldest entry\n---\nmiddle\n---\nnewest"
+        );
+        // Stops at the first text that doesn't fit, so the window stays contiguous
+        assert_eq!(join_recent(&texts, 14), "middle\n---\nnewest");
+        assert_eq!(join_recent(&texts, 3), "");
+    }
+}
@@ -0,0 +1,177 @@
+//! "Show edits" mode: what the refiner removed or changed, for a track-changes view
+//! of the raw transcription against its refinement.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Appended to the refiner's system prompt so it cites what it dropped
+pub const EDIT_NOTES_INSTRUCTION: &str = "\n\nAfter your output, if you removed or substantially changed any of the user's content (beyond fillers, repetitions and grammar), append a block starting with <removed> and ending with </removed> that lists each removal or change on its own line, quoting the user's original words. Leave the block out if nothing substantial was removed.";
+
+const NOTES_OPEN: &str = "<removed>";
+const NOTES_CLOSE: &str = "</removed>";
+
+/// Word alignments are quadratic, so longer texts fall back to a whole replacement
+const MAX_ALIGNMENT_CELLS: usize = 4_000_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum EditKind {
+    /// Kept from the raw transcription
+    Equal,
+    /// Added by the refiner
+    Insert,
+    /// Removed by the refiner
+    Delete,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct EditSpan {
+    pub kind: EditKind,
+    pub text: String,
+}
+
+/// Stored with a history entry when it was refined in show-edits mode
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct EditAlignment {
+    /// Word-level diff from the raw transcription to the refinement
+    pub spans: Vec<EditSpan>,
+    /// Substantial removals or changes as cited by the refiner
+    pub notes: Vec<String>,
+}
+
+/// Strip the refiner's `<removed>` block from its output, returning the text to use
+/// and the cited removals. Output without a block is returned unchanged.
+pub fn split_edit_notes(output: &str) -> (String, Vec<String>) {
+    let Some(start) = output.find(NOTES_OPEN) else {
+        return (output.to_string(), Vec::new());
+    };
+    let after_open = &output[start + NOTES_OPEN.len()..];
+    let (block, rest) = match after_open.find(NOTES_CLOSE) {
+        Some(end) => (&after_open[..end], &after_open[end + NOTES_CLOSE.len()..]),
+        None => (after_open, ""),
+    };
+
+    let notes = block
+        .lines()
+        .map(|line| line.trim().trim_start_matches(['-', '*', '•']).trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+    let text = format!("{}{}", output[..start].trim_end(), rest.trim_end());
+    (text, notes)
+}
+
+fn push_span(spans: &mut Vec<EditSpan>, kind: EditKind, word: &str) {
+    match spans.last_mut() {
+        Some(last) if last.kind == kind => {
+            last.text.push(' ');
+            last.text.push_str(word);
+        }
+        _ => spans.push(EditSpan {
+            kind,
+            text: word.to_string(),
+        }),
+    }
+}
+
+/// Word-level diff from `raw` to `refined` (longest common subsequence, comparing
+/// words without case or surrounding punctuation)
+pub fn align_words(raw: &str, refined: &str) -> Vec<EditSpan> {
+    let a: Vec<&str> = raw.split_whitespace().collect();
+    let b: Vec<&str> = refined.split_whitespace().collect();
+    let mut spans = Vec::new();
+
+    if a.len().saturating_mul(b.len()) > MAX_ALIGNMENT_CELLS {
+        for word in &a {
+            push_span(&mut spans, EditKind::Delete, word);
+        }
+        for word in &b {
+            push_span(&mut spans, EditKind::Insert, word);
+        }
+        return spans;
+    }
+
+    let key = |w: &str| {
+        w.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    };
+    let a_keys: Vec<String> = a.iter().map(|w| key(w)).collect();
+    let b_keys: Vec<String> = b.iter().map(|w| key(w)).collect();
+
+    // lcs[i][j] = length of the common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a_keys[i] == b_keys[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a_keys[i] == b_keys[j] {
+            // Show the refined spelling, since punctuation or case may have changed
+            push_span(&mut spans, EditKind::Equal, b[j]);
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            push_span(&mut spans, EditKind::Insert, b[j]);
+            j += 1;
+        } else {
+            push_span(&mut spans, EditKind::Delete, a[i]);
+            i += 1;
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(kind: EditKind, text: &str) -> EditSpan {
+        EditSpan {
+            kind,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_split_edit_notes() {
+        let (text, notes) = split_edit_notes(
+            "Ship it Friday.\n\n<removed>\n- \"and also the budget thing\"\n</removed>\n",
+        );
+        assert_eq!(text, "Ship it Friday.");
+        assert_eq!(notes, vec!["\"and also the budget thing\""]);
+
+        let (text, notes) = split_edit_notes("Nothing removed.");
+        assert_eq!(text, "Nothing removed.");
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn test_align_words() {
+        let spans = align_words("um so ship it on friday", "Ship it Friday.");
+        assert_eq!(
+            spans,
+            vec![
+                span(EditKind::Delete, "um so"),
+                span(EditKind::Equal, "Ship it"),
+                span(EditKind::Delete, "on"),
+                span(EditKind::Equal, "Friday."),
+            ]
+        );
+
+        assert_eq!(
+            align_words("hello", "hello world"),
+            vec![
+                span(EditKind::Equal, "hello"),
+                span(EditKind::Insert, "world")
+            ]
+        );
+        assert!(align_words("", "").is_empty());
+    }
+}
@@ -0,0 +1,388 @@
+//! The error type returned by every Tauri command.
+//!
+//! Commands fail with an [`AppError`] rather than a bare string, so the frontend
+//! can tell a missing API key from a rate limit and offer a fix or a retry button.
+//! Errors raised in the command layer pick their code explicitly, and provider
+//! requests take theirs from the HTTP status or the API's error type. Only messages
+//! that still arrive as plain strings (I/O, settings) are classified by their wording
+//! when they cross into a command.
+
+use async_openai::error::{ApiError, OpenAIError};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fmt;
+
+/// Hint for requests that need a provider's API key
+pub const API_KEY_HINT: &str = "Add an API key for the provider in the Post Process settings.";
+
+/// What kind of failure an [`AppError`] is
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The request itself was wrong: an empty name, an out-of-range value
+    InvalidInput,
+    /// The prompt, model, chat or other item asked for doesn't exist
+    NotFound,
+    /// Something has to be set up first: an API key, a downloaded model
+    NotConfigured,
+    /// The provider rejected the credentials
+    Unauthorized,
+    /// The provider is rate limiting or out of quota
+    RateLimited,
+    /// The provider or a local server couldn't be reached
+    Network,
+    /// The provider is overloaded or failed on its side
+    Unavailable,
+    /// Anything else
+    Internal,
+}
+
+/// A failed command: a code for the frontend to act on, the message to show and,
+/// where there's an obvious fix, a hint telling the user what to do
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct AppError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub hint: Option<String>,
+    /// Whether trying the same request again may succeed
+    pub retryable: bool,
+}
+
+impl AppError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        let retryable = matches!(
+            code,
+            ErrorCode::RateLimited | ErrorCode::Network | ErrorCode::Unavailable
+        );
+        Self {
+            code,
+            message: message.into(),
+            hint: default_hint(code),
+            retryable,
+        }
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidInput, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn not_configured(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotConfigured, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// An error response from a provider, coded by its HTTP status
+    pub fn from_status(status: StatusCode, message: impl Into<String>) -> Self {
+        Self::new(status_code(status.as_u16()), message)
+    }
+
+    /// A request that failed: coded by the status if a response came back, and as a
+    /// network error if the provider couldn't be reached or the connection dropped
+    pub fn from_request(error: &reqwest::Error, message: impl Into<String>) -> Self {
+        if let Some(status) = error.status() {
+            return Self::from_status(status, message);
+        }
+        let code = if error.is_builder() || error.is_decode() {
+            ErrorCode::Internal
+        } else {
+            ErrorCode::Network
+        };
+        Self::new(code, message)
+    }
+
+    /// Put `context` in front of the message, keeping the code
+    pub fn with_context(mut self, context: &str) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+
+    /// Pick a code for a message that arrived as plain text. Only whole words and
+    /// status codes reported as such ("API error 429", "HTTP 503") count, so a
+    /// number or word that happens to appear inside the message doesn't.
+    fn classify(message: String) -> Self {
+        let lower = message.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        let text = format!(" {} ", words.join(" "));
+        let has = |phrases: &[&str]| phrases.iter().any(|p| text.contains(&format!(" {} ", p)));
+
+        let code = if let Some(status) = reported_status(&words) {
+            status_code(status)
+        } else if has(&["unauthorized", "invalid api key", "token has expired"]) {
+            ErrorCode::Unauthorized
+        } else if has(&["api key", "not downloaded", "not configured"]) {
+            ErrorCode::NotConfigured
+        } else if has(&[
+            "rate limit",
+            "rate limited",
+            "too many requests",
+            "quota",
+            "resource exhausted",
+        ]) {
+            ErrorCode::RateLimited
+        } else if has(&["overloaded", "unavailable"]) {
+            ErrorCode::Unavailable
+        } else if has(&[
+            "timed out",
+            "timeout",
+            "error sending request",
+            "connection refused",
+            "dns error",
+        ]) {
+            ErrorCode::Network
+        } else if has(&["not found"]) {
+            ErrorCode::NotFound
+        } else if has(&["invalid", "cannot be empty"]) {
+            ErrorCode::InvalidInput
+        } else {
+            ErrorCode::Internal
+        };
+
+        let mut error = Self::new(code, message);
+        if code == ErrorCode::NotConfigured {
+            if has(&["api key"]) {
+                error.hint = Some(API_KEY_HINT.to_string());
+            } else if has(&["not downloaded"]) {
+                error.hint = Some("Download the model in the General settings first.".to_string());
+            }
+        }
+        error
+    }
+}
+
+/// The code for an HTTP error status
+fn status_code(status: u16) -> ErrorCode {
+    match status {
+        401 | 403 => ErrorCode::Unauthorized,
+        404 => ErrorCode::NotFound,
+        408 => ErrorCode::Network,
+        429 => ErrorCode::RateLimited,
+        400 | 422 => ErrorCode::InvalidInput,
+        500..=599 => ErrorCode::Unavailable,
+        _ => ErrorCode::Internal,
+    }
+}
+
+/// An error status a message reports right after "error", "status" or "http"
+fn reported_status(words: &[&str]) -> Option<u16> {
+    words.windows(2).find_map(|pair| {
+        if !matches!(pair[0], "error" | "status" | "http") {
+            return None;
+        }
+        pair[1]
+            .parse::<u16>()
+            .ok()
+            .filter(|status| (400..600).contains(status))
+    })
+}
+
+/// The code for an error object returned by an OpenAI-compatible API, from its
+/// `code` (which [`crate::llm_client`] sets to the HTTP status when it has one) or
+/// its `type`
+fn api_error_code(error: &ApiError) -> Option<ErrorCode> {
+    if let Some(status) = error.code.as_deref().and_then(|c| c.parse::<u16>().ok()) {
+        return Some(status_code(status));
+    }
+    [error.code.as_deref(), error.r#type.as_deref()]
+        .into_iter()
+        .flatten()
+        .find_map(|kind| match kind {
+            "invalid_api_key" | "authentication_error" | "permission_error" => {
+                Some(ErrorCode::Unauthorized)
+            }
+            "rate_limit_exceeded" | "rate_limit_error" | "insufficient_quota" => {
+                Some(ErrorCode::RateLimited)
+            }
+            "server_error" | "overloaded_error" | "api_error" => Some(ErrorCode::Unavailable),
+            "model_not_found" | "not_found_error" => Some(ErrorCode::NotFound),
+            "invalid_request_error" => Some(ErrorCode::InvalidInput),
+            _ => None,
+        })
+}
+
+/// The fix to suggest for each kind of failure, where one applies
+fn default_hint(code: ErrorCode) -> Option<String> {
+    let hint = match code {
+        ErrorCode::Unauthorized => "Check the provider's API key, or sign in to it again.",
+        ErrorCode::RateLimited => {
+            "The provider is limiting requests. Wait a moment and retry, or switch models."
+        }
+        ErrorCode::Network => {
+            "Check your internet connection, or that the local server is running."
+        }
+        ErrorCode::Unavailable => "The provider is having trouble. Retry in a moment.",
+        ErrorCode::InvalidInput
+        | ErrorCode::NotFound
+        | ErrorCode::NotConfigured
+        | ErrorCode::Internal => return None,
+    };
+    Some(hint.to_string())
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::classify(message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        Self::classify(message.to_string())
+    }
+}
+
+impl From<OpenAIError> for AppError {
+    fn from(error: OpenAIError) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl From<&OpenAIError> for AppError {
+    fn from(error: &OpenAIError) -> Self {
+        let message = error.to_string();
+        match error {
+            OpenAIError::Reqwest(e) => Self::from_request(e, message),
+            OpenAIError::ApiError(api_error) => match api_error_code(api_error) {
+                Some(code) => Self::new(code, message),
+                None => Self::classify(message),
+            },
+            OpenAIError::InvalidArgument(_) => Self::internal(message),
+            _ => Self::classify(message),
+        }
+    }
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::classify(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            ("API error 429: Too Many Requests", ErrorCode::RateLimited),
+            (
+                "API error (401): invalid x-api-key",
+                ErrorCode::Unauthorized,
+            ),
+            (
+                "No API key configured for provider 'openai'",
+                ErrorCode::NotConfigured,
+            ),
+            (
+                "Model not downloaded: parakeet-v3",
+                ErrorCode::NotConfigured,
+            ),
+            ("API error 503: overloaded", ErrorCode::Unavailable),
+            (
+                "error sending request for url (http://localhost:11434)",
+                ErrorCode::Network,
+            ),
+            ("Prompt with id 'x' not found", ErrorCode::NotFound),
+            ("Category name cannot be empty", ErrorCode::InvalidInput),
+            ("Failed to write settings", ErrorCode::Internal),
+        ];
+        for (message, code) in cases {
+            assert_eq!(AppError::from(message).code, code, "{}", message);
+        }
+    }
+
+    #[test]
+    fn test_classify_ignores_partial_matches() {
+        let cases = [
+            "Failed to parse transcript at line 4013",
+            "Model 'gpt-4-0401' returned nothing",
+            "Unknown prompt variable 'x'",
+            "The subscription expired field must be a date",
+            "Failed to write 500 bytes",
+        ];
+        for message in cases {
+            assert_eq!(
+                AppError::from(message).code,
+                ErrorCode::Internal,
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_typed_errors() {
+        let error = AppError::from_status(StatusCode::UNAUTHORIZED, "Anthropic API error");
+        assert_eq!(error.code, ErrorCode::Unauthorized);
+        assert!(!error.retryable);
+
+        let error = AppError::from_status(StatusCode::SERVICE_UNAVAILABLE, "Gemini API error");
+        assert_eq!(error.code, ErrorCode::Unavailable);
+        assert!(error.retryable);
+
+        let api_error = |code: Option<&str>, kind: Option<&str>| {
+            AppError::from(OpenAIError::ApiError(ApiError {
+                message: "Request failed".to_string(),
+                r#type: kind.map(str::to_string),
+                param: None,
+                code: code.map(str::to_string),
+            }))
+        };
+        assert_eq!(api_error(Some("429"), None).code, ErrorCode::RateLimited);
+        assert_eq!(
+            api_error(Some("invalid_api_key"), Some("invalid_request_error")).code,
+            ErrorCode::Unauthorized
+        );
+        assert_eq!(
+            api_error(None, Some("insufficient_quota")).code,
+            ErrorCode::RateLimited
+        );
+        assert_eq!(api_error(None, None).code, ErrorCode::Internal);
+
+        let error = AppError::from_status(StatusCode::NOT_FOUND, "Model missing")
+            .with_context("Chat completion failed");
+        assert_eq!(error.code, ErrorCode::NotFound);
+        assert_eq!(error.message, "Chat completion failed: Model missing");
+    }
+
+    #[test]
+    fn test_retryable_and_hints() {
+        let rate_limited = AppError::from("rate limit exceeded");
+        assert!(rate_limited.retryable);
+        assert!(rate_limited.hint.is_some());
+
+        let not_found = AppError::not_found("Chat 3 not found");
+        assert!(!not_found.retryable);
+        assert_eq!(not_found.hint, None);
+
+        let downloaded = AppError::from("Model not downloaded: kokoro-82m");
+        assert!(downloaded.hint.unwrap().contains("Download"));
+
+        let no_key = AppError::from("No API key configured for provider 'openai'");
+        assert_eq!(no_key.hint.as_deref(), Some(API_KEY_HINT));
+    }
+}
@@ -0,0 +1,93 @@
+//! Events the backend sends to the frontend.
+//!
+//! Each event is its own type deriving [`tauri_specta::Event`] and registered in
+//! `lib.rs`, so the event name and payload are exported to `bindings.ts` next to the
+//! commands. The backend emits with `SomeEvent(..).emit(&app)` and the frontend
+//! listens with `events.someEvent.listen(..)`, so neither side spells out the name
+//! or guesses at the payload's shape. The event name is the type name in
+//! kebab-case, e.g. `CategoryDetected` is sent as `category-detected`.
+
+use crate::settings::PromptMode;
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Runtime};
+use tauri_specta::Event;
+
+/// A setting was changed by the backend, so open settings views should reload it.
+/// Only toggles are announced this way
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct SettingsChanged {
+    /// The setting's field name in `AppSettings`
+    pub setting: String,
+    pub value: bool,
+}
+
+/// The prompt category chosen for the current refinement, shown as the overlay icon
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct CategoryDetected(pub String);
+
+/// A piece of the refiner's answer arrived while it streams in
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct RefinementProgress {
+    pub delta: String,
+    /// Characters received so far
+    pub chars: u32,
+}
+
+/// A screenshot was added to the current recording's context
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct VisionCaptured;
+
+/// How the current recording was started, once the key has been held or released
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingMode {
+    /// Push-to-talk: recording stops when the key is released
+    Hold,
+    /// A quick press: recording continues and is refined when stopped
+    Refining,
+}
+
+/// The current recording's mode was determined
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct ModeDetermined(pub RecordingMode);
+
+/// The prompt mode was changed from the tray menu
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct PromptModeChanged(pub PromptMode);
+
+/// Focus mode was turned on or off, possibly from the tray menu
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct FocusModeChanged(pub bool);
+
+/// The app language was changed, so every window (including the overlay) switches to it
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct AppLanguageChanged(pub String);
+
+/// The battery saver turned on or off
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct BatterySaverChanged {
+    pub active: bool,
+    /// Battery charge when it changed, if known
+    pub battery_percent: Option<u8>,
+}
+
+/// A settings sync pulled, pushed or found conflicting settings
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct SettingsSynced(pub crate::settings_sync::SyncReport);
+
+/// A companion device paired with the remote trigger endpoint
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct RemoteDevicePaired {
+    pub id: String,
+    pub name: String,
+}
+
+/// Announce that a toggle setting was changed
+pub fn emit_settings_changed<R: Runtime>(app: &AppHandle<R>, setting: &str, value: bool) {
+    let _ = SettingsChanged {
+        setting: setting.to_string(),
+        value,
+    }
+    .emit(app);
+}
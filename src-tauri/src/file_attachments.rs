@@ -0,0 +1,200 @@
+//! Text extraction for files attached to chat messages.
+//!
+//! Plain text and Markdown are read as is, PDFs go through `pdf-extract`. Long
+//! documents are split into chunks on paragraph boundaries, and each chunk is sent
+//! to the model as its own text part, labelled with the file name.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+
+/// File types that can be attached
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["txt", "md", "markdown", "pdf"];
+
+/// Size of a chunk in characters
+const CHUNK_CHARS: usize = 8_000;
+
+/// Text past this many characters is dropped, so one document can't fill the
+/// model's context on its own
+const MAX_CHARS: usize = 200_000;
+
+/// A local file attached to a chat message, as extracted text
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+pub struct FileAttachment {
+    /// File name without the directory
+    pub name: String,
+    /// The text, split into parts of at most a few thousand characters
+    pub chunks: Vec<String>,
+    /// Whether the end of the document was dropped for being too long
+    pub truncated: bool,
+}
+
+impl FileAttachment {
+    /// The chunks as text parts for a request, each saying which file and part it is
+    pub fn text_parts(&self) -> Vec<String> {
+        let total = self.chunks.len();
+        self.chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut label = if total == 1 {
+                    format!("[Attached file: {}]", self.name)
+                } else {
+                    format!(
+                        "[Attached file: {}, part {} of {}]",
+                        self.name,
+                        index + 1,
+                        total
+                    )
+                };
+                if self.truncated && index + 1 == total {
+                    label.push_str(" (the rest of the file was cut off)");
+                }
+                format!("{}\n\n{}", label, chunk)
+            })
+            .collect()
+    }
+}
+
+/// Read a file and extract its text
+pub fn load(path: &Path) -> Result<FileAttachment, String> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| format!("Invalid file path '{}'", path.display()))?;
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(format!(
+            "Unsupported file type '{}', expected one of: {}",
+            name,
+            SUPPORTED_EXTENSIONS.join(", ")
+        ));
+    }
+
+    let text = if extension == "pdf" {
+        pdf_extract::extract_text(path)
+            .map_err(|e| format!("Failed to read text from '{}': {}", name, e))?
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read '{}': {}", name, e))?
+    };
+    if text.trim().is_empty() {
+        return Err(format!("'{}' contains no text", name));
+    }
+
+    let (text, truncated) = truncate(&text, MAX_CHARS);
+    log::info!(
+        "Attached '{}' ({} characters{})",
+        name,
+        text.chars().count(),
+        if truncated { ", truncated" } else { "" }
+    );
+    Ok(FileAttachment {
+        name,
+        chunks: chunk_text(text, CHUNK_CHARS),
+        truncated,
+    })
+}
+
+fn truncate(text: &str, max_chars: usize) -> (&str, bool) {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => (&text[..end], true),
+        None => (text, false),
+    }
+}
+
+/// Split text into chunks of at most `max_chars`, breaking between paragraphs where
+/// possible, then between lines, and mid-line only for a line that's too long
+fn chunk_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0;
+
+    for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+        let pieces: Vec<String> = if paragraph.chars().count() <= max_chars {
+            vec![paragraph.to_string()]
+        } else {
+            paragraph
+                .lines()
+                .flat_map(|line| {
+                    let chars: Vec<char> = line.chars().collect();
+                    chars
+                        .chunks(max_chars)
+                        .map(|piece| piece.iter().collect::<String>())
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        };
+
+        for piece in pieces {
+            let piece_chars = piece.chars().count();
+            if current_chars > 0 && current_chars + 2 + piece_chars > max_chars {
+                chunks.push(std::mem::take(&mut current));
+                current_chars = 0;
+            }
+            if current_chars > 0 {
+                current.push_str("\n\n");
+                current_chars += 2;
+            }
+            current.push_str(&piece);
+            current_chars += piece_chars;
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_text_keeps_short_text_whole() {
+        assert_eq!(
+            chunk_text("One.\n\nTwo.", 100),
+            vec!["One.\n\nTwo.".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_chunk_text_splits_on_paragraphs() {
+        assert_eq!(
+            chunk_text("aaaaaa\n\nbbbbbb\n\ncccccc", 14),
+            vec!["aaaaaa\n\nbbbbbb".to_string(), "cccccc".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_chunk_text_splits_long_lines() {
+        let chunks = chunk_text(&"x".repeat(25), 10);
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 10));
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("héllo", 10), ("héllo", false));
+        assert_eq!(truncate("héllo", 2), ("hé", true));
+    }
+
+    #[test]
+    fn test_text_parts() {
+        let file = FileAttachment {
+            name: "notes.md".to_string(),
+            chunks: vec!["first".to_string(), "second".to_string()],
+            truncated: true,
+        };
+        assert_eq!(
+            file.text_parts(),
+            vec![
+                "[Attached file: notes.md, part 1 of 2]\n\nfirst".to_string(),
+                "[Attached file: notes.md, part 2 of 2] (the rest of the file was cut off)\n\nsecond"
+                    .to_string(),
+            ]
+        );
+    }
+}
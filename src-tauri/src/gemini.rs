@@ -0,0 +1,114 @@
+//! Gemini-only generation options: thinking budgets and safety settings, which the
+//! native generateContent API takes but the OpenAI-compatible endpoint ignores.
+
+use serde_json::{json, Value};
+
+use crate::settings::{GeminiOptions, LLMModel};
+
+const HARM_CATEGORIES: &[&str] = &[
+    "HARM_CATEGORY_HARASSMENT",
+    "HARM_CATEGORY_HATE_SPEECH",
+    "HARM_CATEGORY_SEXUALLY_EXPLICIT",
+    "HARM_CATEGORY_DANGEROUS_CONTENT",
+];
+
+const SAFETY_THRESHOLDS: &[&str] = &[
+    "BLOCK_NONE",
+    "BLOCK_ONLY_HIGH",
+    "BLOCK_MEDIUM_AND_ABOVE",
+    "BLOCK_LOW_AND_ABOVE",
+    "OFF",
+];
+
+/// Reject values Gemini would answer with a 400
+pub fn validate(options: &GeminiOptions) -> Result<(), String> {
+    if let Some(budget) = options.thinking_budget {
+        if budget < -1 {
+            return Err(format!(
+                "Invalid thinking budget {}, use -1 for dynamic or 0 to turn thinking off",
+                budget
+            ));
+        }
+    }
+    match options.safety_threshold.as_deref() {
+        Some(threshold) if !SAFETY_THRESHOLDS.contains(&threshold) => Err(format!(
+            "Unknown safety threshold '{}', expected one of: {}",
+            threshold,
+            SAFETY_THRESHOLDS.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Add `options` to a generateContent body, leaving anything unset at Gemini's default
+pub fn apply_options(body: &mut Value, options: &GeminiOptions) {
+    if let Some(budget) = options.thinking_budget {
+        body["generationConfig"]["thinkingConfig"]["thinkingBudget"] = json!(budget);
+    }
+    if let Some(threshold) = &options.safety_threshold {
+        body["safetySettings"] = HARM_CATEGORIES
+            .iter()
+            .map(|category| json!({ "category": category, "threshold": threshold }))
+            .collect();
+    }
+}
+
+/// Add `model`'s Gemini options, if it has any, to a generateContent body. Without
+/// a Gemini-specific thinking budget the model's reasoning budget is used.
+pub fn apply_model_options(body: &mut Value, model: &LLMModel) {
+    let mut options = model.gemini_options.clone().unwrap_or_default();
+    if options.thinking_budget.is_none() {
+        options.thinking_budget = model
+            .reasoning
+            .as_ref()
+            .and_then(|r| r.budget_tokens)
+            .map(|budget| i32::try_from(budget).unwrap_or(i32::MAX));
+    }
+    apply_options(body, &options);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_options() {
+        let mut body = json!({ "contents": [] });
+        apply_options(
+            &mut body,
+            &GeminiOptions {
+                thinking_budget: Some(0),
+                safety_threshold: Some("BLOCK_ONLY_HIGH".to_string()),
+            },
+        );
+        assert_eq!(
+            body["generationConfig"],
+            json!({ "thinkingConfig": { "thinkingBudget": 0 } })
+        );
+        let safety = body["safetySettings"].as_array().unwrap();
+        assert_eq!(safety.len(), HARM_CATEGORIES.len());
+        assert_eq!(safety[0]["threshold"], "BLOCK_ONLY_HIGH");
+    }
+
+    #[test]
+    fn test_default_options_add_nothing() {
+        let mut body = json!({ "contents": [] });
+        apply_options(&mut body, &GeminiOptions::default());
+        assert_eq!(body, json!({ "contents": [] }));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&GeminiOptions::default()).is_ok());
+        assert!(validate(&GeminiOptions {
+            thinking_budget: Some(-2),
+            safety_threshold: None,
+        })
+        .is_err());
+        assert!(validate(&GeminiOptions {
+            thinking_budget: Some(-1),
+            safety_threshold: Some("BLOCK_SOME".to_string()),
+        })
+        .is_err());
+    }
+}
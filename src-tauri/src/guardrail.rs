@@ -0,0 +1,146 @@
+//! Post-check that a refinement didn't invent content: flags names and numbers in
+//! the refined text that appear nowhere in what the refiner was given.
+//!
+//! This is a heuristic, not entailment: it only looks at tokens that usually carry
+//! facts (numbers and capitalised words in the middle of a sentence), so rephrasing
+//! is never flagged but an invented claim made of ordinary words is missed.
+
+use std::collections::HashSet;
+
+/// Spelled-out numbers, so "5" in the refinement matches "five" in the transcript
+const NUMBER_WORDS: &[(&str, &str)] = &[
+    ("0", "zero"),
+    ("1", "one"),
+    ("2", "two"),
+    ("3", "three"),
+    ("4", "four"),
+    ("5", "five"),
+    ("6", "six"),
+    ("7", "seven"),
+    ("8", "eight"),
+    ("9", "nine"),
+    ("10", "ten"),
+    ("11", "eleven"),
+    ("12", "twelve"),
+    ("13", "thirteen"),
+    ("14", "fourteen"),
+    ("15", "fifteen"),
+    ("16", "sixteen"),
+    ("17", "seventeen"),
+    ("18", "eighteen"),
+    ("19", "nineteen"),
+    ("20", "twenty"),
+    ("30", "thirty"),
+    ("40", "forty"),
+    ("50", "fifty"),
+    ("60", "sixty"),
+    ("70", "seventy"),
+    ("80", "eighty"),
+    ("90", "ninety"),
+    ("100", "hundred"),
+    ("1000", "thousand"),
+];
+
+/// Capitalised words that don't name anything
+const COMMON_CAPITALISED: &[&str] = &["i", "i'm", "i've", "i'll", "i'd", "ok", "okay"];
+
+/// Strip surrounding punctuation and a possessive, keeping inner characters like
+/// the "." in "3.5" or the "'" in "O'Brien"
+fn clean(word: &str) -> &str {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    word.strip_suffix("'s")
+        .or_else(|| word.strip_suffix("’s"))
+        .unwrap_or(word)
+}
+
+/// Digits of a number token with separators and units removed ("$1,200" → "1200")
+fn digits(word: &str) -> Option<String> {
+    if !word.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(
+        word.chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.')
+            .collect(),
+    )
+}
+
+fn source_vocabulary(sources: &[&str]) -> HashSet<String> {
+    let mut vocabulary = HashSet::new();
+    for word in sources.iter().flat_map(|s| s.split_whitespace()) {
+        let word = clean(word);
+        if word.is_empty() {
+            continue;
+        }
+        vocabulary.insert(word.to_lowercase());
+        if let Some(digits) = digits(word) {
+            vocabulary.insert(digits);
+        }
+        for (number, spelled) in NUMBER_WORDS {
+            if word.eq_ignore_ascii_case(spelled) {
+                vocabulary.insert(number.to_string());
+            }
+        }
+    }
+    vocabulary
+}
+
+/// Names and numbers in `refined` that appear in none of `sources`, in order of
+/// first appearance
+pub fn find_invented_terms(refined: &str, sources: &[&str]) -> Vec<String> {
+    let vocabulary = source_vocabulary(sources);
+    let mut invented: Vec<String> = Vec::new();
+    let mut sentence_start = true;
+
+    for raw_word in refined.split_whitespace() {
+        let word = clean(raw_word);
+        let starts_sentence = sentence_start;
+        sentence_start = raw_word.ends_with(['.', '!', '?', ':']);
+        if word.is_empty() {
+            continue;
+        }
+
+        let is_number = digits(word).is_some();
+        let is_name = !starts_sentence
+            && word.chars().next().is_some_and(|c| c.is_uppercase())
+            && !COMMON_CAPITALISED.contains(&word.to_lowercase().as_str());
+        if !is_number && !is_name {
+            continue;
+        }
+
+        let known = vocabulary.contains(&word.to_lowercase())
+            || digits(word).is_some_and(|d| vocabulary.contains(&d));
+        if !known && !invented.iter().any(|t| t == word) {
+            invented.push(word.to_string());
+        }
+    }
+    invented
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_new_names_and_numbers() {
+        let raw = "um tell sarah the meeting moved to five and bring the deck";
+        let refined = "Tell Sarah the meeting moved to 5pm at Acme HQ. Bring the deck for Q3.";
+        assert_eq!(
+            find_invented_terms(refined, &[raw]),
+            vec!["Acme", "HQ", "Q3"]
+        );
+    }
+
+    #[test]
+    fn test_accepts_rephrasing_and_other_sources() {
+        let raw = "i think we spent like $1,200 on it";
+        assert!(find_invented_terms("I think we spent about $1200 on it.", &[raw]).is_empty());
+
+        // Names from the selection the refiner was given aren't invented
+        let selection = "Reply to Dana Whitfield";
+        assert!(find_invented_terms("Thanks, Dana.", &["say thanks", selection]).is_empty());
+
+        // Capitalised sentence starts are ordinary words
+        assert!(find_invented_terms("Sure. Sounds good.", &["sure sounds good"]).is_empty());
+    }
+}
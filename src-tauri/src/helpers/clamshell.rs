@@ -1,3 +1,4 @@
+use crate::errors::AppError;
 #[cfg(target_os = "macos")]
 use std::process::Command;
 
@@ -32,12 +33,12 @@ pub fn is_clamshell() -> Result<bool, String> {
 #[cfg(target_os = "macos")]
 #[tauri::command]
 #[specta::specta]
-pub fn is_laptop() -> Result<bool, String> {
+pub fn is_laptop() -> Result<bool, AppError> {
     let output = Command::new("pmset")
         .arg("-g")
         .arg("batt")
         .output()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| AppError::internal(format!("Failed to execute pmset: {}", e)))?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
@@ -57,7 +58,7 @@ pub fn is_clamshell() -> Result<bool, String> {
 #[cfg(not(target_os = "macos"))]
 #[tauri::command]
 #[specta::specta]
-pub fn is_laptop() -> Result<bool, String> {
+pub fn is_laptop() -> Result<bool, AppError> {
     Ok(false)
 }
 
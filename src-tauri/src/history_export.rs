@@ -0,0 +1,179 @@
+//! Rendering of history entries to Markdown, JSON or CSV for archival or import
+//! into note-taking tools.
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::managers::history::HistoryEntry;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryExportFormat {
+    Markdown,
+    Json,
+    Csv,
+}
+
+/// Which entries to export. Bounds are unix timestamps in seconds and inclusive.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Type)]
+pub struct HistoryExportRange {
+    #[serde(default)]
+    pub from: Option<i64>,
+    #[serde(default)]
+    pub to: Option<i64>,
+    /// Only export starred entries
+    #[serde(default)]
+    pub saved_only: bool,
+}
+
+impl HistoryExportRange {
+    pub fn contains(&self, entry: &HistoryEntry) -> bool {
+        !matches!(self.from, Some(from) if entry.timestamp < from)
+            && !matches!(self.to, Some(to) if entry.timestamp > to)
+            && (!self.saved_only || entry.saved)
+    }
+}
+
+fn format_date(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|utc| utc.with_timezone(&Local).to_rfc3339())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Render `entries` (oldest first reads best in notes, so callers sort them)
+pub fn render(entries: &[HistoryEntry], format: HistoryExportFormat) -> Result<String, String> {
+    match format {
+        HistoryExportFormat::Markdown => Ok(render_markdown(entries)),
+        HistoryExportFormat::Json => serde_json::to_string_pretty(entries)
+            .map_err(|e| format!("Failed to serialize history: {}", e)),
+        HistoryExportFormat::Csv => Ok(render_csv(entries)),
+    }
+}
+
+/// The generated title when there is one, the recording time otherwise
+pub(crate) fn display_title(entry: &HistoryEntry) -> &str {
+    entry.generated_title.as_deref().unwrap_or(&entry.title)
+}
+
+fn render_markdown(entries: &[HistoryEntry]) -> String {
+    let mut out = String::from("# Ramble history\n");
+    for entry in entries {
+        out.push_str(&format!("\n## {}\n\n", display_title(entry)));
+        out.push_str(&format!("- Date: {}\n", format_date(entry.timestamp)));
+        if entry.saved {
+            out.push_str("- Saved: yes\n");
+        }
+        if entry.transcription_status != "success" {
+            out.push_str(&format!("- Status: {}\n", entry.transcription_status));
+        }
+        if let Some(error) = &entry.transcription_error {
+            out.push_str(&format!("- Error: {}\n", error));
+        }
+
+        match entry
+            .post_processed_text
+            .as_deref()
+            .filter(|t| !t.is_empty())
+        {
+            Some(refined) => {
+                out.push_str(&format!("\n{}\n", refined));
+                out.push_str("\n### Original\n\n");
+                for line in entry.transcription_text.lines() {
+                    out.push_str(&format!("> {}\n", line));
+                }
+            }
+            None => out.push_str(&format!("\n{}\n", entry.transcription_text)),
+        }
+    }
+    out
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(entries: &[HistoryEntry]) -> String {
+    let mut out =
+        String::from("id,date,title,saved,status,transcription,post_processed,prompt,words\n");
+    for entry in entries {
+        let fields = [
+            entry.id.to_string(),
+            format_date(entry.timestamp),
+            display_title(entry).to_string(),
+            entry.saved.to_string(),
+            entry.transcription_status.clone(),
+            entry.transcription_text.clone(),
+            entry.post_processed_text.clone().unwrap_or_default(),
+            entry.post_process_prompt.clone().unwrap_or_default(),
+            entry.stats.word_count.to_string(),
+        ];
+        let row: Vec<String> = fields.iter().map(String::as_str).map(csv_field).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_stats::TextStats;
+
+    fn entry(id: i64, timestamp: i64, text: &str, refined: Option<&str>) -> HistoryEntry {
+        HistoryEntry {
+            id,
+            file_name: format!("ramble-{}.wav", timestamp),
+            timestamp,
+            saved: false,
+            title: format!("Recording {}", id),
+            generated_title: None,
+            transcription_text: text.to_string(),
+            post_processed_text: refined.map(String::from),
+            post_process_prompt: None,
+            transcription_status: "success".to_string(),
+            transcription_error: None,
+            stats: TextStats::compute(text, refined),
+            invented_terms: Vec::new(),
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let range = HistoryExportRange {
+            from: Some(100),
+            to: Some(200),
+            saved_only: false,
+        };
+        assert!(range.contains(&entry(1, 100, "a", None)));
+        assert!(!range.contains(&entry(1, 201, "a", None)));
+        assert!(HistoryExportRange::default().contains(&entry(1, 0, "a", None)));
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+
+        let csv = render_csv(&[entry(7, 0, "line one\nline two", None)]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("id,date,title"));
+        assert!(lines[1].starts_with("7,"));
+        assert!(csv.contains("\"line one\nline two\""));
+    }
+
+    #[test]
+    fn test_markdown_shows_original_under_refined() {
+        let md = render_markdown(&[entry(1, 0, "um the raw", Some("The refined."))]);
+        assert!(md.starts_with("# Ramble history\n"));
+        assert!(md.contains("\nThe refined.\n"));
+        assert!(md.contains("### Original\n\n> um the raw\n"));
+    }
+}
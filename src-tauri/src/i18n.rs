@@ -0,0 +1,97 @@
+//! Strings the backend shows itself: the tray menu, overlay messages and screen
+//! reader announcements
+//!
+//! Everything is auto-generated at compile time by build.rs from the
+//! frontend locale files (src/i18n/locales/*/translation.json), so these
+//! strings follow `app_language` along with the frontend.
+//!
+//! The English translation.json is the single source of truth:
+//! - TrayStrings fields are derived from the English "tray" keys
+//! - OverlayStrings fields are derived from the English "overlayMessages" keys
+//! - AnnouncementStrings fields are derived from the English "announcements" keys
+//! - All languages are auto-discovered from the locales directory
+//!
+//! To add a new string:
+//! 1. Add the key to en/translation.json under "tray", "overlayMessages" or
+//!    "announcements"
+//! 2. Add translations to other locale files (English is used until then)
+//! 3. Use the new field (e.g., strings.new_field), filling `{{placeholders}}`
+//!    with `format_message`
+
+use crate::settings;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use tauri::AppHandle;
+
+// Include the auto-generated structs and their TRANSLATIONS statics
+include!(concat!(env!("OUT_DIR"), "/backend_translations.rs"));
+
+/// Get the language code from a locale string (e.g., "en-US" -> "en")
+fn get_language_code(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or("en")
+}
+
+/// Look up `locale`'s strings, falling back to English
+fn lookup<T: Clone>(translations: &HashMap<&'static str, T>, locale: Option<&str>) -> T {
+    let lang = locale.map(get_language_code).unwrap_or("en");
+
+    translations
+        .get(lang)
+        .or_else(|| translations.get("en"))
+        .cloned()
+        .expect("English translations must exist")
+}
+
+/// Get localized tray menu strings for a locale
+pub fn get_tray_translations(locale: Option<String>) -> TrayStrings {
+    lookup(&TRAY_TRANSLATIONS, locale.as_deref())
+}
+
+/// Get overlay messages in the app's language
+pub fn overlay_strings(app: &AppHandle) -> OverlayStrings {
+    let settings = settings::get_settings(app);
+    lookup(&OVERLAY_TRANSLATIONS, Some(&settings.app_language))
+}
+
+/// Get screen reader announcements in the app's language
+pub fn announcement_strings(app: &AppHandle) -> AnnouncementStrings {
+    let settings = settings::get_settings(app);
+    lookup(&ANNOUNCEMENT_TRANSLATIONS, Some(&settings.app_language))
+}
+
+/// Fill a message's i18next-style `{{name}}` placeholders
+pub fn format_message(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{{{name}}}}}"), value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_message() {
+        assert_eq!(
+            format_message("Transcription error: {{error}}", &[("error", "timeout")]),
+            "Transcription error: timeout"
+        );
+        assert_eq!(
+            format_message("No placeholders", &[("error", "x")]),
+            "No placeholders"
+        );
+    }
+
+    #[test]
+    fn test_lookup_falls_back_to_english() {
+        let english = lookup(&OVERLAY_TRANSLATIONS, Some("en"));
+        let unknown = lookup(&OVERLAY_TRANSLATIONS, Some("xx-YY"));
+        assert_eq!(english.transcription_failed, unknown.transcription_failed);
+        assert_eq!(
+            lookup(&TRAY_TRANSLATIONS, Some("de_DE")).quit,
+            TRAY_TRANSLATIONS["de"].quit
+        );
+    }
+}
@@ -25,10 +25,14 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Instant;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event as _;
 
 use crate::actions::ACTION_MAP;
+use crate::events::{RecordingMode, VisionCaptured};
+use crate::keyboard_layout;
 use crate::managers::audio::AudioRecordingManager;
+use crate::shortcut_log::{self, ShortcutEventResolution, ShortcutEventSource};
 use crate::ManagedToggleState;
 
 // ============================================================================
@@ -221,6 +225,16 @@ pub fn resume_raw_binding(binding_id: &str) {
     }
 }
 
+/// Whether `binding_id`'s keys are still held down for the current recording
+pub fn is_key_held(binding_id: &str) -> bool {
+    get_state().lock().is_ok_and(|guard| {
+        matches!(
+            &guard.state,
+            ListenerState::Recording { binding_id: id, key_released: false, .. } if id == binding_id
+        )
+    })
+}
+
 /// Force reset to idle state (e.g., after error or cancel)
 pub fn force_reset_state() {
     if let Ok(mut guard) = get_state().lock() {
@@ -294,15 +308,70 @@ fn handle_key_press(key: Key) {
     }
 
     // 3. Handle Passive Keys during Recording (S, P, Esc)
-    // Only if not already handled by a binding
-    match key {
-        Key::Escape => handle_cancel(),
-        Key::KeyS => handle_vision(),
-        Key::KeyP => handle_pause(),
+    // Only if not already handled by a binding. Letters match the key label on the
+    // user's layout rather than the physical position.
+    if key == Key::Escape {
+        handle_cancel();
+        return;
+    }
+    match key_label(key).as_deref() {
+        Some("s") => handle_vision(),
+        Some("p") => handle_pause(),
         _ => {}
     }
 }
 
+/// US QWERTY name of a character key at its physical position
+fn physical_key_name(key: Key) -> Option<&'static str> {
+    let name = match key {
+        Key::KeyA => "a",
+        Key::KeyB => "b",
+        Key::KeyC => "c",
+        Key::KeyD => "d",
+        Key::KeyE => "e",
+        Key::KeyF => "f",
+        Key::KeyG => "g",
+        Key::KeyH => "h",
+        Key::KeyI => "i",
+        Key::KeyJ => "j",
+        Key::KeyK => "k",
+        Key::KeyL => "l",
+        Key::KeyM => "m",
+        Key::KeyN => "n",
+        Key::KeyO => "o",
+        Key::KeyP => "p",
+        Key::KeyQ => "q",
+        Key::KeyR => "r",
+        Key::KeyS => "s",
+        Key::KeyT => "t",
+        Key::KeyU => "u",
+        Key::KeyV => "v",
+        Key::KeyW => "w",
+        Key::KeyX => "x",
+        Key::KeyY => "y",
+        Key::KeyZ => "z",
+        Key::SemiColon => ";",
+        Key::Quote => "'",
+        Key::Comma => ",",
+        Key::Dot => ".",
+        Key::Slash => "/",
+        Key::BackSlash => "\\",
+        Key::LeftBracket => "[",
+        Key::RightBracket => "]",
+        Key::Minus => "-",
+        Key::Equal => "=",
+        Key::BackQuote => "`",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Label of a character key on the active keyboard layout
+fn key_label(key: Key) -> Option<String> {
+    physical_key_name(key)
+        .map(|name| keyboard_layout::physical_to_label(name, keyboard_layout::active_layout()))
+}
+
 fn handle_key_release(key: Key) {
     let mut current_modifiers = Vec::new();
 
@@ -365,13 +434,17 @@ fn key_to_binding_string_chord(key: Key, modifiers: &[&str]) -> Option<String> {
         Key::ShiftRight => "right_shift",
         Key::ControlLeft | Key::ControlRight => "ctrl",
         Key::Space => "space",
-        Key::KeyQ => "q",
-        Key::KeyA => "a",
-        Key::KeyS => "s",
-        Key::KeyZ => "z",
+        Key::KeyQ | Key::KeyA | Key::KeyS | Key::KeyZ => {
+            return key_label(key).map(|label| chord_string(&label, modifiers));
+        }
         _ => return None, // Add more as needed
     };
 
+    Some(chord_string(key_name, modifiers))
+}
+
+/// Prefix `key_name` with the held modifiers, e.g. "shift+right_command"
+fn chord_string(key_name: &str, modifiers: &[&str]) -> String {
     // If the key itself is a modifier, we want the chord WITHOUT it in the prefix
     // e.g. Chord = ["shift", "right_command"], Key = "right_command" -> "shift+right_command"
     let modifier_prefix: Vec<String> = modifiers
@@ -381,14 +454,31 @@ fn key_to_binding_string_chord(key: Key, modifiers: &[&str]) -> Option<String> {
         .collect();
 
     if modifier_prefix.is_empty() {
-        Some(key_name.to_string())
+        key_name.to_string()
     } else {
-        Some(format!("{}+{}", modifier_prefix.join("+"), key_name))
+        format!("{}+{}", modifier_prefix.join("+"), key_name)
     }
 }
 
 // Replaced by key_to_binding_string_chord and behavior handlers
 
+fn log_raw_event(
+    binding_id: &str,
+    binding_string: &str,
+    pressed: bool,
+    resolution: ShortcutEventResolution,
+    detail: Option<&str>,
+) {
+    shortcut_log::record(
+        ShortcutEventSource::RawKey,
+        binding_id,
+        binding_string,
+        pressed,
+        resolution,
+        detail,
+    );
+}
+
 // ============================================================================
 // State Machine Transitions
 // ============================================================================
@@ -411,6 +501,13 @@ fn handle_behavior_press(binding_string: &str) -> bool {
         };
 
         if guard.suspended.contains(&binding.binding_id) {
+            log_raw_event(
+                &binding.binding_id,
+                binding_string,
+                true,
+                ShortcutEventResolution::Suppressed,
+                Some("binding suspended while editing"),
+            );
             return false;
         }
 
@@ -454,10 +551,32 @@ fn handle_behavior_press(binding_string: &str) -> bool {
                     guard.state = ListenerState::Idle;
                     (app, binding.binding_id.clone(), behavior)
                 } else {
+                    log_raw_event(
+                        &binding.binding_id,
+                        binding_string,
+                        true,
+                        ShortcutEventResolution::Suppressed,
+                        Some("another binding is recording"),
+                    );
                     return false;
                 }
             }
-            _ => return false,
+            ListenerState::Recording { binding_id, .. } => {
+                // Key is still held from the press that started recording (auto-repeat)
+                let resolution = if binding_id == binding.binding_id {
+                    ShortcutEventResolution::DuplicateFiltered
+                } else {
+                    ShortcutEventResolution::Suppressed
+                };
+                log_raw_event(
+                    &binding.binding_id,
+                    binding_string,
+                    true,
+                    resolution,
+                    Some("key already held"),
+                );
+                return false;
+            }
         }
     };
 
@@ -465,7 +584,12 @@ fn handle_behavior_press(binding_string: &str) -> bool {
     match behavior {
         InteractionBehavior::Instant => {
             if let Some(action) = ACTION_MAP.get(&binding_id) {
-                action.start(&app, &binding_id, binding_string);
+                let resolution = if action.start(&app, &binding_id, binding_string) {
+                    ShortcutEventResolution::Matched
+                } else {
+                    ShortcutEventResolution::StartFailed
+                };
+                log_raw_event(&binding_id, binding_string, true, resolution, None);
             }
             true
         }
@@ -477,10 +601,30 @@ fn handle_behavior_press(binding_string: &str) -> bool {
             };
 
             if is_stop {
+                if crate::shortcut::is_double_press_cancel(&app, &binding_id) {
+                    log_raw_event(
+                        &binding_id,
+                        binding_string,
+                        true,
+                        ShortcutEventResolution::Matched,
+                        Some("double-press cancel"),
+                    );
+                    info!("Cancel recording triggered via double-press");
+                    crate::utils::cancel_current_operation(&app);
+                    return true;
+                }
+
                 // Stop action (Toggle off)
                 if let Ok(mut states) = app.state::<ManagedToggleState>().lock() {
                     states.active_toggles.insert(binding_id.clone(), false);
                 }
+                log_raw_event(
+                    &binding_id,
+                    binding_string,
+                    true,
+                    ShortcutEventResolution::Matched,
+                    Some("toggle stop"),
+                );
                 if let Some(action) = ACTION_MAP.get(&binding_id) {
                     action.stop(&app, &binding_id, binding_string);
                 }
@@ -491,7 +635,19 @@ fn handle_behavior_press(binding_string: &str) -> bool {
                 }
                 if let Some(action) = ACTION_MAP.get(&binding_id) {
                     let started = action.start(&app, &binding_id, binding_string);
+                    log_raw_event(
+                        &binding_id,
+                        binding_string,
+                        true,
+                        if started {
+                            ShortcutEventResolution::Matched
+                        } else {
+                            ShortcutEventResolution::StartFailed
+                        },
+                        Some("start"),
+                    );
                     if started {
+                        crate::shortcut::note_recording_started(&binding_id);
                         if behavior == InteractionBehavior::Hybrid {
                             spawn_hold_timer(app.clone(), binding_id.clone());
                         }
@@ -533,8 +689,8 @@ fn handle_behavior_release(binding_string: &str) {
                 };
                 let behavior = action.interaction_behavior();
                 let threshold = get_hold_threshold(&app);
-                let held_ms = press_time.elapsed().as_millis() as u64;
-                let is_long_hold = held_ms >= threshold;
+                let is_long_hold =
+                    crate::shortcut::is_hold(press_time.elapsed().as_millis(), threshold);
 
                 match behavior {
                     InteractionBehavior::Instant => {
@@ -569,6 +725,18 @@ fn handle_behavior_release(binding_string: &str) {
         }
     };
 
+    log_raw_event(
+        &binding_id,
+        binding_string,
+        false,
+        ShortcutEventResolution::Matched,
+        Some(if is_long_hold {
+            "push-to-talk stop"
+        } else {
+            "tap"
+        }),
+    );
+
     // Execute outside of lock
     match behavior {
         InteractionBehavior::Hybrid => {
@@ -577,10 +745,17 @@ fn handle_behavior_release(binding_string: &str) {
                 if let Ok(mut states) = app.state::<ManagedToggleState>().lock() {
                     states.active_toggles.insert(binding_id.clone(), false);
                 }
+                // The coherent flag has to be set before stopping for a refined hold
+                let refines = crate::shortcut::binding_refines(&app, &binding_id, true);
+                if refines {
+                    crate::shortcut::enter_refining_mode(&app, false);
+                }
                 if let Some(action) = ACTION_MAP.get(&binding_id) {
                     action.stop(&app, &binding_id, binding_string);
                 }
-                crate::overlay::emit_mode_determined(&app, "hold");
+                if !refines {
+                    crate::overlay::emit_mode_determined(&app, RecordingMode::Hold);
+                }
             } else {
                 // Toggle ON - Tap
                 // Check which action this is - voice commands should NOT switch to refining mode
@@ -615,21 +790,12 @@ fn handle_behavior_release(binding_string: &str) {
                             }
                         }
                     });
-                } else {
+                } else if crate::shortcut::binding_refines(&app, &binding_id, false) {
                     // Regular transcribe action - switch to refining mode
-                    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
-                    audio_manager.set_coherent_mode(true);
-                    crate::utils::show_ramble_recording_overlay(&app);
-                    crate::overlay::emit_mode_determined(&app, "refining");
-
-                    let app_clone = app.clone();
-                    let _ = app.run_on_main_thread(move || {
-                        if let Ok(Some(text)) = crate::clipboard::get_selected_text(&app_clone) {
-                            if let Some(mgr) = app_clone.try_state::<Arc<AudioRecordingManager>>() {
-                                mgr.set_selection_context(text);
-                            }
-                        }
-                    });
+                    crate::shortcut::enter_refining_mode(&app, true);
+                } else {
+                    // This binding keeps taps raw
+                    crate::overlay::emit_mode_determined(&app, RecordingMode::Hold);
                 }
             }
         }
@@ -712,7 +878,7 @@ fn handle_vision() {
                 Ok(base64) => {
                     let audio_manager = app_clone.state::<Arc<AudioRecordingManager>>();
                     audio_manager.add_vision_context(base64);
-                    let _ = app_clone.emit("vision-captured", ());
+                    let _ = VisionCaptured.emit(&app_clone);
                 }
                 Err(e) => error!("Vision capture failed: {}", e),
             }
@@ -770,9 +936,15 @@ fn spawn_hold_timer(app: AppHandle, binding_id: String) {
             })
             .unwrap_or(false);
 
-        if should_emit {
+        if !should_emit {
+            return;
+        }
+        if crate::shortcut::binding_refines(&app, &binding_id, true) {
+            debug!("Hold threshold reached - binding refines holds");
+            crate::shortcut::enter_refining_mode(&app, false);
+        } else {
             debug!("Hold threshold reached - emitting hold mode");
-            crate::overlay::emit_mode_determined(&app, "hold");
+            crate::overlay::emit_mode_determined(&app, RecordingMode::Hold);
         }
     });
 }
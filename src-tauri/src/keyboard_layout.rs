@@ -0,0 +1,367 @@
+//! Keyboard layout handling for shortcut strings.
+//!
+//! Global shortcut registration and the raw key listener both identify keys by their
+//! physical position, named after the US QWERTY layout. Bindings are written using the
+//! labels printed on the user's own keyboard, so on AZERTY, QWERTZ and Dvorak layouts the
+//! character keys need translating between the two before registering or matching.
+
+use crate::settings::KeyboardLayout;
+use log::{debug, info};
+use std::sync::Mutex;
+
+/// Layout used for translation, resolved from the setting (Auto → detected system layout)
+static ACTIVE_LAYOUT: Mutex<KeyboardLayout> = Mutex::new(KeyboardLayout::Qwerty);
+
+/// (label on the user's keyboard, US QWERTY key at the same physical position)
+const AZERTY: &[(&str, &str)] = &[
+    ("a", "q"),
+    ("q", "a"),
+    ("z", "w"),
+    ("w", "z"),
+    ("m", ";"),
+    (",", "m"),
+    (";", ","),
+    (":", "."),
+    ("!", "/"),
+    ("ù", "'"),
+    ("^", "["),
+    ("$", "]"),
+    ("*", "\\"),
+    (")", "-"),
+];
+
+const QWERTZ: &[(&str, &str)] = &[
+    ("y", "z"),
+    ("z", "y"),
+    ("ö", ";"),
+    ("ä", "'"),
+    ("ü", "["),
+    ("+", "]"),
+    ("#", "\\"),
+    ("ß", "-"),
+    ("´", "="),
+    ("^", "`"),
+    ("-", "/"),
+];
+
+const DVORAK: &[(&str, &str)] = &[
+    ("[", "-"),
+    ("]", "="),
+    ("'", "q"),
+    (",", "w"),
+    (".", "e"),
+    ("p", "r"),
+    ("y", "t"),
+    ("f", "y"),
+    ("g", "u"),
+    ("c", "i"),
+    ("r", "o"),
+    ("l", "p"),
+    ("/", "["),
+    ("=", "]"),
+    ("o", "s"),
+    ("e", "d"),
+    ("u", "f"),
+    ("i", "g"),
+    ("d", "h"),
+    ("h", "j"),
+    ("t", "k"),
+    ("n", "l"),
+    ("s", ";"),
+    ("-", "'"),
+    (";", "z"),
+    ("q", "x"),
+    ("j", "c"),
+    ("k", "v"),
+    ("x", "b"),
+    ("b", "n"),
+    ("w", ","),
+    ("v", "."),
+    ("z", "/"),
+];
+
+fn layout_table(layout: KeyboardLayout) -> &'static [(&'static str, &'static str)] {
+    match layout {
+        KeyboardLayout::Azerty => AZERTY,
+        KeyboardLayout::Qwertz => QWERTZ,
+        KeyboardLayout::Dvorak => DVORAK,
+        KeyboardLayout::Auto | KeyboardLayout::Qwerty => &[],
+    }
+}
+
+/// Translate a key label on the given layout to the US key at the same position
+pub fn label_to_physical(key: &str, layout: KeyboardLayout) -> String {
+    let lower = key.to_lowercase();
+    layout_table(layout)
+        .iter()
+        .find(|(label, _)| *label == lower)
+        .map(|(_, physical)| physical.to_string())
+        .unwrap_or(lower)
+}
+
+/// Translate a US-named physical key to the label it has on the given layout
+pub fn physical_to_label(key: &str, layout: KeyboardLayout) -> String {
+    let lower = key.to_lowercase();
+    layout_table(layout)
+        .iter()
+        .find(|(_, physical)| *physical == lower)
+        .map(|(label, _)| label.to_string())
+        .unwrap_or(lower)
+}
+
+/// Map every part of a `mod+mod+key` shortcut string with `f`, leaving modifiers
+/// and named keys (e.g. "space", "f5") untouched.
+fn map_shortcut_keys(shortcut: &str, f: impl Fn(&str) -> String) -> String {
+    shortcut
+        .split('+')
+        .map(|part| {
+            let trimmed = part.trim();
+            if trimmed.chars().count() == 1 {
+                f(trimmed)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Convert a shortcut written with the user's key labels into US-named physical keys
+/// for registration. A lone "+" key can't be expressed in this format and is left as is.
+pub fn to_physical_shortcut(shortcut: &str, layout: KeyboardLayout) -> String {
+    map_shortcut_keys(shortcut, |k| label_to_physical(k, layout))
+}
+
+/// Convert a shortcut recorded from physical key positions into the user's key labels
+pub fn to_label_shortcut(shortcut: &str, layout: KeyboardLayout) -> String {
+    map_shortcut_keys(shortcut, |k| physical_to_label(k, layout))
+}
+
+/// Guess the layout family from a platform layout identifier such as
+/// "com.apple.keylayout.French" (macOS), "fr" / "us dvorak" (XKB) or "0000040c" (Windows KLID).
+pub fn layout_from_identifier(identifier: &str) -> KeyboardLayout {
+    let lower = identifier.to_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect();
+    let has = |names: &[&str]| tokens.iter().any(|t| names.contains(t));
+
+    if lower.contains("dvorak") {
+        return KeyboardLayout::Dvorak;
+    }
+
+    // Windows keyboard layout identifiers (KLID), e.g. 0000040c
+    if let Some(klid) = tokens
+        .iter()
+        .find(|t| t.len() == 8 && t.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        let (variant, language) = klid.split_at(4);
+        return match language {
+            "0409" if variant != "0000" => KeyboardLayout::Dvorak,
+            "040c" | "080c" => KeyboardLayout::Azerty,
+            "0407" | "0807" | "0c07" | "1007" | "1407" | "100c" | "0405" | "040e" | "041b"
+            | "0424" | "041a" => KeyboardLayout::Qwertz,
+            _ => KeyboardLayout::Qwerty,
+        };
+    }
+
+    // Swiss layouts are QWERTZ even in their French variant
+    if has(&[
+        "swiss",
+        "ch",
+        "german",
+        "de",
+        "austrian",
+        "at",
+        "czech",
+        "cz",
+        "hungarian",
+        "hu",
+        "slovak",
+        "sk",
+        "slovenian",
+        "si",
+        "croatian",
+        "hr",
+        "qwertz",
+    ]) {
+        return KeyboardLayout::Qwertz;
+    }
+    if has(&["french", "fr", "belgian", "be", "azerty"]) && !has(&["canadian", "ca"]) {
+        return KeyboardLayout::Azerty;
+    }
+
+    KeyboardLayout::Qwerty
+}
+
+/// Read the current system layout identifier, if the platform exposes one
+fn system_layout_identifier() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("defaults")
+            .args([
+                "read",
+                "com.apple.HIToolbox",
+                "AppleCurrentKeyboardLayoutInputSourceID",
+            ])
+            .output()
+            .ok()?;
+        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!id.is_empty()).then_some(id)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+        let output = std::process::Command::new("reg")
+            .args(["query", r"HKCU\Keyboard Layout\Preload", "/v", "1"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find(|l| l.contains("REG_SZ"))
+            .and_then(|l| l.split_whitespace().last())
+            .map(String::from)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(output) = std::process::Command::new("setxkbmap")
+            .arg("-query")
+            .output()
+        {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let field = |name: &str| {
+                stdout
+                    .lines()
+                    .find_map(|l| l.strip_prefix(name))
+                    .map(|v| v.trim().to_string())
+            };
+            if let Some(layout) = field("layout:") {
+                // Only the first layout in a "us,de" list is active by default
+                let layout = layout.split(',').next().unwrap_or_default().to_string();
+                let variant = field("variant:").unwrap_or_default();
+                return Some(format!("{} {}", layout, variant).trim().to_string());
+            }
+        }
+        std::env::var("XKB_DEFAULT_LAYOUT").ok()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+/// Detect the system keyboard layout, falling back to QWERTY
+pub fn detect_system_layout() -> KeyboardLayout {
+    match system_layout_identifier() {
+        Some(id) => {
+            let layout = layout_from_identifier(&id);
+            debug!("Detected keyboard layout '{}' -> {:?}", id, layout);
+            layout
+        }
+        None => {
+            debug!("Could not detect keyboard layout, assuming QWERTY");
+            KeyboardLayout::Qwerty
+        }
+    }
+}
+
+/// Make `layout` the active layout for shortcut translation. Auto has to be resolved
+/// with `detect_system_layout` first, which runs a command, so it's taken as QWERTY
+/// here.
+pub fn set_active_layout(layout: KeyboardLayout) {
+    let resolved = match layout {
+        KeyboardLayout::Auto => KeyboardLayout::Qwerty,
+        other => other,
+    };
+    info!("Using {:?} keyboard layout for shortcuts", resolved);
+    if let Ok(mut active) = ACTIVE_LAYOUT.lock() {
+        *active = resolved;
+    }
+}
+
+/// The layout currently used for shortcut translation
+pub fn active_layout() -> KeyboardLayout {
+    ACTIVE_LAYOUT
+        .lock()
+        .map(|l| *l)
+        .unwrap_or(KeyboardLayout::Qwerty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_physical_shortcut() {
+        assert_eq!(
+            to_physical_shortcut("ctrl+a", KeyboardLayout::Azerty),
+            "ctrl+q"
+        );
+        assert_eq!(
+            to_physical_shortcut("ctrl+;", KeyboardLayout::Azerty),
+            "ctrl+,"
+        );
+        assert_eq!(
+            to_physical_shortcut("ctrl+z", KeyboardLayout::Qwertz),
+            "ctrl+y"
+        );
+        assert_eq!(
+            to_physical_shortcut("ctrl+shift+space", KeyboardLayout::Dvorak),
+            "ctrl+shift+space"
+        );
+        assert_eq!(
+            to_physical_shortcut("ctrl+;", KeyboardLayout::Qwerty),
+            "ctrl+;"
+        );
+    }
+
+    #[test]
+    fn test_label_round_trip() {
+        for layout in [
+            KeyboardLayout::Azerty,
+            KeyboardLayout::Qwertz,
+            KeyboardLayout::Dvorak,
+        ] {
+            for (label, _) in layout_table(layout) {
+                let physical = label_to_physical(label, layout);
+                assert_eq!(&physical_to_label(&physical, layout), label);
+            }
+        }
+    }
+
+    #[test]
+    fn test_layout_from_identifier() {
+        assert_eq!(
+            layout_from_identifier("com.apple.keylayout.French"),
+            KeyboardLayout::Azerty
+        );
+        assert_eq!(
+            layout_from_identifier("com.apple.keylayout.German"),
+            KeyboardLayout::Qwertz
+        );
+        assert_eq!(
+            layout_from_identifier("com.apple.keylayout.Dvorak"),
+            KeyboardLayout::Dvorak
+        );
+        assert_eq!(layout_from_identifier("fr"), KeyboardLayout::Azerty);
+        assert_eq!(layout_from_identifier("us dvorak"), KeyboardLayout::Dvorak);
+        assert_eq!(layout_from_identifier("0000040c"), KeyboardLayout::Azerty);
+        assert_eq!(layout_from_identifier("00000407"), KeyboardLayout::Qwertz);
+        assert_eq!(layout_from_identifier("00010409"), KeyboardLayout::Dvorak);
+        assert_eq!(
+            layout_from_identifier("com.apple.keylayout.ABC"),
+            KeyboardLayout::Qwerty
+        );
+        assert_eq!(
+            layout_from_identifier("com.apple.keylayout.Canadian-French"),
+            KeyboardLayout::Qwerty
+        );
+    }
+}
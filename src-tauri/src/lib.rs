@@ -1,45 +1,90 @@
+mod accessibility;
 mod actions;
 mod app_detection;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
+mod chapters;
+mod chat_tools;
 #[cfg(target_os = "macos")]
 mod chats_menu;
 mod clipboard;
+mod code_comments;
 mod commands;
-
+mod day_context;
+mod edits;
+mod errors;
+mod events;
+mod file_attachments;
+mod gemini;
+
+mod guardrail;
 mod helpers;
+mod history_export;
+mod history_share;
+mod i18n;
 mod input;
 #[cfg(target_os = "macos")]
 mod key_listener;
+mod keyboard_layout;
 mod known_apps;
 mod llm_client;
 #[cfg(target_os = "macos")]
 mod macos_input;
 mod managers;
+mod media_keys;
 mod oauth;
+mod onboarding;
+mod openrouter;
 mod overlay;
+mod power;
+mod presentation;
+mod pricing;
+mod projects;
+mod proofread;
+mod provider_health;
+mod reasoning;
+mod reminders;
+mod remote_trigger;
+mod retry;
+mod semantic_search;
 mod settings;
+mod settings_sync;
 mod shortcut;
+mod shortcut_log;
 mod signal_handle;
+mod summaries;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+mod text_stats;
+mod timers;
+mod titles;
+mod trash;
 mod tray;
-mod tray_i18n;
 mod utils;
 mod vision;
 
 mod tts;
+mod voice_command_log;
 mod voice_commands;
+mod voice_settings;
+mod window_management;
 use specta_typescript::{BigIntExportBehavior, Typescript};
-use tauri_specta::{collect_commands, Builder};
+use tauri_specta::{collect_commands, collect_events, Builder};
 
 use env_filter::Builder as EnvFilterBuilder;
+use errors::AppError;
 use managers::audio::AudioRecordingManager;
+use managers::captures::CaptureManager;
 use managers::chat_persistence::ChatPersistenceManager;
+use managers::embedding::EmbeddingManager;
 use managers::history::HistoryManager;
+use managers::local_llm::LocalLlmManager;
 use managers::model::ModelManager;
 use managers::transcription::TranscriptionManager;
 use managers::tts::TTSManager;
+use managers::usage::UsageManager;
 #[cfg(unix)]
 use signal_hook::consts::SIGUSR2;
 #[cfg(unix)]
@@ -103,7 +148,7 @@ struct ShortcutToggleStates {
 
 type ManagedToggleState = Mutex<ShortcutToggleStates>;
 
-fn show_main_window(app: &AppHandle) {
+pub(crate) fn show_main_window(app: &AppHandle) {
     if let Some(main_window) = app.get_webview_window("main") {
         // First, ensure the window is visible
         if let Err(e) = main_window.show() {
@@ -155,6 +200,14 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             .expect("Failed to initialize chat persistence manager"),
     );
     let tts_manager = Arc::new(TTSManager::new(app_handle, model_manager.clone()));
+    let usage_manager =
+        Arc::new(UsageManager::new(app_handle).expect("Failed to initialize usage manager"));
+    let local_llm_manager = Arc::new(LocalLlmManager::default());
+    let embedding_manager = Arc::new(
+        EmbeddingManager::new(app_handle).expect("Failed to initialize embedding manager"),
+    );
+    let capture_manager =
+        Arc::new(CaptureManager::new(app_handle).expect("Failed to initialize capture manager"));
 
     // Add managers to Tauri's managed state
     app_handle.manage(recording_manager.clone());
@@ -163,6 +216,10 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(history_manager.clone());
     app_handle.manage(chat_persistence_manager.clone());
     app_handle.manage(tts_manager.clone());
+    app_handle.manage(usage_manager.clone());
+    app_handle.manage(local_llm_manager.clone());
+    app_handle.manage(embedding_manager.clone());
+    app_handle.manage(capture_manager.clone());
 
     // Initialize the unified key listener (for standalone modifier key bindings on macOS)
     #[cfg(target_os = "macos")]
@@ -170,6 +227,17 @@ fn initialize_core_logic(app_handle: &AppHandle) {
 
     // Initialize the shortcuts
     shortcut::init_shortcuts(app_handle);
+    let _ = media_keys::update(app_handle);
+    let _ = remote_trigger::update(app_handle);
+
+    // Purge expired items from the trash now and periodically
+    trash::start_purge_scheduler(app_handle);
+    provider_health::start_health_monitor(app_handle);
+    reminders::start_scheduler(app_handle);
+    timers::start_scheduler(app_handle);
+    presentation::start_watcher(app_handle);
+    power::start_watcher(app_handle);
+    settings_sync::start_watcher(app_handle);
 
     #[cfg(unix)]
     let signals = Signals::new(&[SIGUSR2]).unwrap();
@@ -224,6 +292,13 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             "quit" => {
                 app.exit(0);
             }
+            "pause_shortcuts" => {
+                shortcut::toggle_all_shortcuts(app);
+            }
+            "focus_mode" => {
+                let enabled = !settings::get_settings(app).focus_mode;
+                shortcut::set_focus_mode(app, enabled);
+            }
             // Prompt mode selections
             "mode_dynamic" => {
                 tray::set_prompt_mode(app, settings::PromptMode::Dynamic);
@@ -309,13 +384,13 @@ fn initialize_core_logic(app_handle: &AppHandle) {
 
 #[tauri::command]
 #[specta::specta]
-fn trigger_update_check(app: AppHandle) -> Result<(), String> {
+fn trigger_update_check(app: AppHandle) -> Result<(), AppError> {
     let settings = settings::get_settings(&app);
     if !settings.update_checks_enabled {
         return Ok(());
     }
     app.emit("check-for-updates", ())
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| AppError::internal(e.to_string()))?;
     Ok(())
 }
 
@@ -325,169 +400,306 @@ pub fn run() {
     // when the variable is unset
     let console_filter = build_console_filter();
 
-    let specta_builder = Builder::<tauri::Wry>::new().commands(collect_commands![
-        shortcut::change_binding,
-        shortcut::reset_binding,
-        shortcut::change_ptt_setting,
-        shortcut::change_audio_feedback_setting,
-        shortcut::change_audio_feedback_volume_setting,
-        shortcut::change_sound_theme_setting,
-        shortcut::change_start_hidden_setting,
-        shortcut::change_autostart_setting,
-        shortcut::change_translate_to_english_setting,
-        shortcut::change_selected_language_setting,
-        shortcut::change_overlay_position_setting,
-        shortcut::change_debug_mode_setting,
-        shortcut::change_word_correction_threshold_setting,
-        shortcut::change_quick_chat_initial_prompt_setting,
-        shortcut::change_paste_method_setting,
-        shortcut::change_clipboard_handling_setting,
-        shortcut::change_post_process_enabled_setting,
-        shortcut::change_post_process_base_url_setting,
-        shortcut::change_post_process_api_key_setting,
-        shortcut::change_post_process_model_setting,
-        shortcut::set_post_process_provider,
-        shortcut::fetch_post_process_models,
-        shortcut::add_post_process_prompt,
-        shortcut::update_post_process_prompt,
-        shortcut::delete_post_process_prompt,
-        shortcut::set_post_process_selected_prompt,
-        shortcut::update_custom_words,
-        shortcut::suspend_binding,
-        shortcut::resume_binding,
-        shortcut::change_mute_while_recording_setting,
-        shortcut::change_append_trailing_space_setting,
-        shortcut::change_app_language_setting,
-        shortcut::change_ramble_enabled_setting,
-        shortcut::change_llm_provider_setting,
-        shortcut::change_ramble_provider_setting,
-        shortcut::change_ramble_model_setting,
-        shortcut::change_ramble_prompt_setting,
-        shortcut::change_ramble_use_vision_model_setting,
-        shortcut::change_ramble_vision_model_setting,
-        shortcut::change_context_chat_prompt_setting,
-        shortcut::change_system_prompt_file_setting,
-        shortcut::reset_ramble_prompt_to_default,
-        shortcut::change_hold_threshold_setting,
-        shortcut::change_clipboard_content_cutoff_setting,
-        shortcut::change_update_checks_setting,
-        shortcut::change_prompt_mode_setting,
-        shortcut::update_prompt_category,
-        shortcut::reset_prompt_category_to_default,
-        shortcut::change_default_category_setting,
-        shortcut::add_prompt_category,
-        shortcut::delete_prompt_category,
-        shortcut::update_prompt_category_details,
-        shortcut::update_prompt_category_model_override,
-        shortcut::change_voice_commands_enabled_setting,
-        shortcut::change_voice_command_default_model_setting,
-        shortcut::reset_voice_commands_to_default,
-        shortcut::add_voice_command,
-        shortcut::update_voice_command,
-        shortcut::delete_voice_command,
-        shortcut::change_filler_word_filter_setting,
-        shortcut::change_collapse_repeated_words_setting,
-        shortcut::change_unknown_command_template_setting,
-        shortcut::change_unknown_command_terminal_setting,
-        trigger_update_check,
-        commands::cancel_operation,
-        commands::pause_operation,
-        commands::resume_operation,
-        commands::get_app_dir_path,
-        commands::get_app_settings,
-        commands::get_default_settings,
-        commands::get_log_dir_path,
-        commands::set_log_level,
-        commands::open_recordings_folder,
-        commands::open_log_dir,
-        commands::open_app_data_dir,
-        commands::open_external_url,
-        commands::models::get_available_models,
-        commands::models::get_model_info,
-        commands::models::download_model,
-        commands::models::delete_model,
-        commands::models::cancel_download,
-        commands::models::set_active_model,
-        commands::models::get_current_model,
-        commands::models::get_transcription_model_status,
-        commands::models::is_model_loading,
-        commands::models::has_any_models_available,
-        commands::models::has_any_models_or_downloads,
-        commands::models::get_recommended_first_model,
-        commands::audio::update_microphone_mode,
-        commands::audio::get_microphone_mode,
-        commands::audio::get_available_microphones,
-        commands::audio::set_selected_microphone,
-        commands::audio::get_selected_microphone,
-        commands::audio::get_available_output_devices,
-        commands::audio::set_selected_output_device,
-        commands::audio::get_selected_output_device,
-        commands::audio::play_test_sound,
-        commands::audio::check_custom_sounds,
-        commands::audio::set_clamshell_microphone,
-        commands::audio::get_clamshell_microphone,
-        commands::audio::is_recording,
-        commands::add_context_image,
-        commands::copy_last_voice_interaction,
-        commands::transcription::set_model_unload_timeout,
-        commands::transcription::get_model_load_status,
-        commands::transcription::unload_model_manually,
-        commands::history::get_history_entries,
-        commands::history::toggle_history_entry_saved,
-        commands::history::get_audio_file_path,
-        commands::history::delete_history_entry,
-        commands::history::update_history_limit,
-        commands::history::update_recording_retention_period,
-        helpers::clamshell::is_laptop,
-        // App-to-prompt category mapping commands
-        commands::get_known_applications,
-        commands::get_installed_applications,
-        commands::get_app_category_mappings,
-        commands::set_app_category_mapping,
-        commands::remove_app_category_mapping,
-        // Chat commands
-        commands::chat::chat_completion,
-        commands::open_chat_window,
-        commands::open_chat_window_with_messages,
-        commands::open_saved_chat,
-        commands::capture_screen_mode,
-        commands::capture_region_command,
-        commands::open_clipping_tool,
-        commands::restore_app_visibility,
-        commands::get_pending_clip,
-        // Unified provider/model commands
-        commands::providers::get_llm_providers,
-        commands::providers::get_llm_models,
-        commands::providers::update_provider_api_key,
-        commands::providers::save_llm_provider,
-        commands::providers::delete_llm_provider,
-        commands::providers::save_llm_model,
-        commands::providers::delete_llm_model,
-        commands::providers::set_default_model,
-        commands::providers::get_default_models,
-        commands::providers::get_openai_reasoning_effort,
-        commands::providers::set_openai_reasoning_effort,
-        // Dynamic model fetching
-        commands::fetch_models::refresh_all_models,
-        commands::chat_persistence::save_chat,
-        commands::chat_persistence::update_chat,
-        commands::chat_persistence::get_chat,
-        commands::chat_persistence::list_saved_chats,
-        commands::chat_persistence::delete_saved_chat,
-        commands::chat_persistence::generate_chat_title,
-        commands::chat_persistence::update_chat_title,
-        commands::tts::speak_text,
-        commands::tts::stop_tts,
-        // OAuth commands
-        commands::oauth::oauth_start_auth,
-        commands::oauth::oauth_await_callback,
-        commands::oauth::oauth_get_status,
-        commands::oauth::oauth_logout,
-        commands::oauth::oauth_refresh_token,
-        commands::oauth::oauth_get_access_token,
-        commands::oauth::oauth_get_request_headers,
-        commands::oauth::oauth_supports_provider,
-    ]);
+    let specta_builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![
+            shortcut::change_binding,
+            shortcut::reset_binding,
+            shortcut::change_ptt_setting,
+            shortcut::change_audio_feedback_setting,
+            shortcut::change_audio_feedback_volume_setting,
+            shortcut::change_sound_theme_setting,
+            shortcut::change_start_hidden_setting,
+            shortcut::change_autostart_setting,
+            shortcut::change_translate_to_english_setting,
+            shortcut::change_code_switching_setting,
+            shortcut::change_show_edits_setting,
+            shortcut::change_verify_refinement_setting,
+            shortcut::change_day_context_enabled_setting,
+            shortcut::change_chaptering_min_words_setting,
+            shortcut::change_history_titles_enabled_setting,
+            shortcut::change_refine_clipboard_paste_setting,
+            shortcut::change_selected_language_setting,
+            shortcut::change_overlay_position_setting,
+            shortcut::change_debug_mode_setting,
+            shortcut::change_word_correction_threshold_setting,
+            shortcut::change_quick_chat_initial_prompt_setting,
+            shortcut::change_paste_method_setting,
+            shortcut::change_clipboard_handling_setting,
+            shortcut::change_post_process_enabled_setting,
+            shortcut::change_post_process_base_url_setting,
+            shortcut::change_post_process_api_key_setting,
+            shortcut::change_post_process_model_setting,
+            shortcut::set_post_process_provider,
+            shortcut::fetch_post_process_models,
+            shortcut::add_post_process_prompt,
+            shortcut::update_post_process_prompt,
+            shortcut::delete_post_process_prompt,
+            shortcut::set_post_process_selected_prompt,
+            shortcut::update_custom_words,
+            shortcut::update_cased_terms,
+            shortcut::suspend_binding,
+            shortcut::resume_binding,
+            shortcut::change_keyboard_layout_setting,
+            shortcut::shortcut_to_layout_labels,
+            shortcut::get_shortcut_event_log,
+            shortcut::clear_shortcut_event_log,
+            shortcut::get_shortcuts_pause_state,
+            shortcut::set_shortcuts_paused,
+            shortcut::change_shortcuts_pause_minutes_setting,
+            shortcut::change_mute_while_recording_setting,
+            shortcut::change_live_transcription_setting,
+            shortcut::change_append_trailing_space_setting,
+            shortcut::change_app_language_setting,
+            shortcut::change_ramble_enabled_setting,
+            shortcut::change_llm_provider_setting,
+            shortcut::change_ramble_provider_setting,
+            shortcut::change_ramble_model_setting,
+            shortcut::change_ramble_prompt_setting,
+            shortcut::change_ramble_use_vision_model_setting,
+            shortcut::change_ramble_vision_model_setting,
+            shortcut::change_context_chat_prompt_setting,
+            shortcut::change_system_prompt_file_setting,
+            shortcut::change_local_llm_enabled_setting,
+            shortcut::change_local_llm_model_path_setting,
+            shortcut::reset_ramble_prompt_to_default,
+            shortcut::change_hold_threshold_setting,
+            shortcut::change_double_press_cancel_setting,
+            shortcut::change_double_press_cancel_window_setting,
+            shortcut::change_no_speech_timeout_setting,
+            shortcut::change_binding_auto_stop_setting,
+            shortcut::change_binding_tap_hold_mode_setting,
+            shortcut::change_daily_spend_limit_setting,
+            shortcut::change_per_request_spend_limit_setting,
+            shortcut::change_noise_suppression_setting,
+            shortcut::change_accessibility_announcements_setting,
+            shortcut::change_battery_saver_enabled_setting,
+            shortcut::change_battery_saver_threshold_setting,
+            shortcut::change_media_key_trigger_setting,
+            shortcut::change_media_key_binding_setting,
+            shortcut::change_remote_trigger_enabled_setting,
+            shortcut::change_remote_trigger_port_setting,
+            shortcut::change_remote_trigger_lan_access_setting,
+            shortcut::change_sync_folder_setting,
+            shortcut::change_sync_passphrase_setting,
+            shortcut::change_clipboard_content_cutoff_setting,
+            shortcut::change_update_checks_setting,
+            shortcut::change_prompt_mode_setting,
+            shortcut::update_prompt_category,
+            shortcut::reset_prompt_category_to_default,
+            shortcut::change_default_category_setting,
+            shortcut::change_record_detected_apps_setting,
+            shortcut::change_detected_apps_excluded_setting,
+            shortcut::add_prompt_category,
+            shortcut::delete_prompt_category,
+            shortcut::duplicate_prompt_category,
+            shortcut::get_prompt_category_templates,
+            shortcut::add_prompt_category_from_template,
+            commands::prompts::validate_prompt,
+            commands::prompts::preview_refinement,
+            shortcut::update_prompt_category_details,
+            shortcut::update_prompt_category_model_override,
+            shortcut::update_prompt_category_language,
+            shortcut::update_prompt_category_chaptering,
+            shortcut::change_voice_commands_enabled_setting,
+            shortcut::change_voice_command_default_model_setting,
+            shortcut::change_voice_command_follow_up_setting,
+            shortcut::reset_voice_commands_to_default,
+            shortcut::add_voice_command,
+            shortcut::update_voice_command,
+            shortcut::delete_voice_command,
+            shortcut::get_voice_command_history,
+            shortcut::clear_voice_command_history,
+            shortcut::add_reminder,
+            shortcut::update_reminder,
+            shortcut::delete_reminder,
+            shortcut::add_project,
+            shortcut::update_project,
+            shortcut::delete_project,
+            shortcut::set_active_project,
+            shortcut::change_filler_word_filter_setting,
+            shortcut::change_collapse_repeated_words_setting,
+            shortcut::change_focus_mode_setting,
+            shortcut::change_hide_when_presenting_setting,
+            shortcut::change_paste_blocked_apps_setting,
+            shortcut::change_max_paste_chars_setting,
+            shortcut::change_terminal_paste_mode_setting,
+            shortcut::change_chat_read_aloud_setting,
+            shortcut::change_tts_backend_setting,
+            shortcut::change_tts_system_voice_setting,
+            shortcut::change_tts_cloud_voice_setting,
+            shortcut::change_unknown_command_template_setting,
+            shortcut::change_unknown_command_terminal_setting,
+            trigger_update_check,
+            commands::cancel_operation,
+            commands::pause_operation,
+            commands::resume_operation,
+            commands::get_app_dir_path,
+            commands::get_app_settings,
+            commands::get_default_settings,
+            commands::get_log_dir_path,
+            commands::set_log_level,
+            commands::open_recordings_folder,
+            commands::open_log_dir,
+            commands::open_app_data_dir,
+            commands::open_external_url,
+            commands::models::get_available_models,
+            commands::models::get_model_info,
+            commands::models::download_model,
+            commands::models::delete_model,
+            commands::models::cancel_download,
+            commands::models::set_active_model,
+            commands::models::get_current_model,
+            commands::models::get_transcription_model_status,
+            commands::models::is_model_loading,
+            commands::models::is_local_llm_available,
+            commands::models::has_any_models_available,
+            commands::models::has_any_models_or_downloads,
+            commands::models::get_recommended_first_model,
+            commands::models::set_stt_api_key,
+            commands::audio::update_microphone_mode,
+            commands::audio::get_microphone_mode,
+            commands::audio::is_wake_word_available,
+            commands::audio::change_wake_word_enabled_setting,
+            commands::audio::change_wake_word_binding_setting,
+            commands::audio::change_wake_word_threshold_setting,
+            commands::audio::change_wake_word_model_path_setting,
+            commands::audio::get_available_microphones,
+            commands::audio::set_selected_microphone,
+            commands::audio::get_selected_microphone,
+            commands::audio::get_available_output_devices,
+            commands::audio::set_selected_output_device,
+            commands::audio::get_selected_output_device,
+            commands::audio::play_test_sound,
+            commands::audio::check_custom_sounds,
+            commands::audio::set_clamshell_microphone,
+            commands::audio::get_clamshell_microphone,
+            commands::audio::is_recording,
+            commands::add_context_image,
+            commands::copy_last_voice_interaction,
+            commands::transcription::set_model_unload_timeout,
+            commands::transcription::get_model_load_status,
+            commands::transcription::unload_model_manually,
+            commands::transcription::transcribe_audio_file,
+            commands::history::get_history_entries,
+            commands::history::toggle_history_entry_saved,
+            commands::history::get_audio_file_path,
+            commands::history::get_history_word_timings,
+            commands::history::get_history_edits,
+            commands::history::export_history,
+            commands::history::share_history_entry,
+            commands::history::delete_history_entry,
+            commands::history::summarize_history_entry,
+            commands::history::semantic_search_history,
+            commands::history::update_history_limit,
+            commands::history::update_recording_retention_period,
+            helpers::clamshell::is_laptop,
+            power::is_battery_saver_active,
+            remote_trigger::start_remote_pairing,
+            remote_trigger::cancel_remote_pairing,
+            remote_trigger::remove_remote_device,
+            settings_sync::sync_settings_now,
+            // App-to-prompt category mapping commands
+            commands::get_known_applications,
+            commands::get_installed_applications,
+            commands::get_app_category_mappings,
+            commands::set_app_category_mapping,
+            commands::remove_app_category_mapping,
+            commands::app_mappings::export_app_mappings,
+            commands::app_mappings::import_app_mappings,
+            commands::app_mappings::clear_detected_apps_history,
+            commands::onboarding::generate_suggested_setup,
+            commands::onboarding::apply_suggested_setup,
+            commands::voice_command_packs::export_voice_command_pack,
+            commands::voice_command_packs::preview_voice_command_pack,
+            commands::voice_command_packs::import_voice_command_pack,
+            // Chat commands
+            commands::chat::chat_completion,
+            commands::chat::attach_chat_file,
+            commands::images::generate_image,
+            commands::open_chat_window,
+            commands::open_saved_chat,
+            commands::capture_screen_mode,
+            commands::capture_region_command,
+            commands::open_clipping_tool,
+            commands::restore_app_visibility,
+            commands::get_pending_clips,
+            commands::captures::list_captures,
+            commands::captures::load_capture,
+            commands::captures::delete_capture,
+            // Unified provider/model commands
+            commands::providers::get_llm_providers,
+            commands::providers::get_llm_models,
+            commands::providers::update_provider_api_key,
+            commands::providers::save_llm_provider,
+            commands::providers::delete_llm_provider,
+            commands::providers::save_provider_credential,
+            commands::providers::delete_provider_credential,
+            commands::providers::set_active_provider_credential,
+            commands::providers::update_provider_headers,
+            commands::providers::set_provider_free,
+            commands::providers::get_provider_health,
+            commands::providers::check_provider_health,
+            commands::providers::discover_local_providers,
+            commands::providers::add_discovered_provider,
+            commands::providers::save_llm_model,
+            commands::providers::set_model_openrouter_routing,
+            commands::providers::set_model_gemini_options,
+            commands::providers::set_model_reasoning,
+            commands::providers::delete_llm_model,
+            commands::providers::set_default_model,
+            commands::providers::set_coherent_fallback_models,
+            commands::providers::get_default_models,
+            commands::providers::get_openai_reasoning_effort,
+            commands::providers::set_openai_reasoning_effort,
+            commands::providers::get_provider_presets,
+            commands::providers::apply_provider_preset,
+            commands::trash::list_deleted_items,
+            commands::usage::get_usage_summary,
+            commands::usage::get_usage_report,
+            commands::trash::restore_item,
+            commands::trash::purge_deleted_item,
+            commands::trash::empty_trash,
+            commands::trash::change_trash_retention_days_setting,
+            // Dynamic model fetching
+            commands::fetch_models::refresh_all_models,
+            commands::chat_persistence::save_chat,
+            commands::chat_persistence::update_chat,
+            commands::chat_persistence::get_chat,
+            commands::chat_persistence::list_saved_chats,
+            commands::chat_persistence::delete_saved_chat,
+            commands::chat_persistence::fork_chat,
+            commands::chat_persistence::generate_chat_title,
+            commands::chat_persistence::update_chat_title,
+            commands::tts::speak_text,
+            commands::tts::stop_tts,
+            commands::tts::list_system_voices,
+            commands::tts::list_cloud_tts_voices,
+            commands::tts::set_tts_api_key,
+            // OAuth commands
+            commands::oauth::oauth_start_auth,
+            commands::oauth::oauth_await_callback,
+            commands::oauth::oauth_get_status,
+            commands::oauth::oauth_logout,
+            commands::oauth::oauth_refresh_token,
+            commands::oauth::oauth_get_access_token,
+            commands::oauth::oauth_get_request_headers,
+            commands::oauth::oauth_supports_provider,
+            commands::oauth::oauth_detect_cli_credentials,
+            commands::oauth::oauth_import_cli_credentials,
+        ])
+        .events(collect_events![
+            events::SettingsChanged,
+            events::CategoryDetected,
+            events::RefinementProgress,
+            events::VisionCaptured,
+            events::ModeDetermined,
+            events::PromptModeChanged,
+            events::FocusModeChanged,
+            events::AppLanguageChanged,
+            events::BatterySaverChanged,
+            events::RemoteDevicePaired,
+            events::SettingsSynced,
+        ]);
 
     #[cfg(debug_assertions)] // <- Only export on non-release builds
     specta_builder
@@ -497,6 +709,8 @@ pub fn run() {
         )
         .expect("Failed to export typescript bindings");
 
+    let invoke_handler = specta_builder.invoke_handler();
+
     let mut builder = tauri::Builder::default().plugin(
         LogBuilder::new()
             .level(log::LevelFilter::Trace) // Set to most verbose level globally
@@ -537,6 +751,7 @@ pub fn run() {
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_os::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_macos_permissions::init())
         .plugin(tauri_plugin_opener::init())
@@ -548,6 +763,8 @@ pub fn run() {
         ))
         .manage(Mutex::new(ShortcutToggleStates::default()))
         .setup(move |app| {
+            specta_builder.mount_events(app);
+
             let settings = get_settings(&app.handle());
             let tauri_log_level: tauri_plugin_log::LogLevel = settings.log_level.into();
             let file_log_level: log::Level = tauri_log_level.into();
@@ -599,7 +816,13 @@ pub fn run() {
             }
             _ => {}
         })
-        .invoke_handler(specta_builder.invoke_handler())
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .invoke_handler(invoke_handler)
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // The local model server is a separate process, which would outlive the app
+            if let tauri::RunEvent::Exit = event {
+                app.state::<Arc<LocalLlmManager>>().unload();
+            }
+        });
 }
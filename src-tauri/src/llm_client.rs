@@ -1,6 +1,20 @@
+use crate::errors::AppError;
 use crate::oauth::{google, openai as openai_oauth, tokens::load_tokens, OAuthProvider};
-use crate::settings::{AuthMethod, LLMProvider};
-use async_openai::{config::OpenAIConfig, Client};
+use crate::retry::Retry;
+use crate::settings::{AuthMethod, LLMModel, LLMProvider};
+use async_openai::{
+    config::OpenAIConfig,
+    error::{ApiError, OpenAIError},
+    types::{ChatCompletionStreamOptions, CompletionUsage, CreateChatCompletionRequest},
+    Client,
+};
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use specta::Type;
+use std::collections::HashMap;
 
 /// Get the API key to use for a provider (sync version, no auto-refresh)
 ///
@@ -226,6 +240,500 @@ pub async fn get_api_key_for_provider_async(provider: &LLMProvider) -> Result<St
     }
 }
 
+/// Token counts reported by the provider for a single completion
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, Type)]
+pub struct TokenUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+impl From<&CompletionUsage> for TokenUsage {
+    fn from(usage: &CompletionUsage) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
+}
+
+impl TokenUsage {
+    /// Read the usage object of a native (non OpenAI-compatible) API response:
+    /// Anthropic and Codex use `input_tokens`/`output_tokens`, Gemini's
+    /// `usageMetadata` uses `promptTokenCount`/`candidatesTokenCount`
+    pub fn from_json(usage: &serde_json::Value) -> Option<Self> {
+        let count = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| usage.get(*key).and_then(|v| v.as_u64()))
+                .map(|v| v as u32)
+        };
+        let prompt_tokens = count(&["prompt_tokens", "input_tokens", "promptTokenCount"])?;
+        let completion_tokens =
+            count(&["completion_tokens", "output_tokens", "candidatesTokenCount"]).unwrap_or(0);
+        Some(Self {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: count(&["total_tokens", "totalTokenCount"])
+                .unwrap_or(prompt_tokens + completion_tokens),
+        })
+    }
+}
+
+/// Stream a chat completion, calling `on_delta` with each piece of text as it
+/// arrives. Returns the whole text and the token usage if the provider reports it.
+pub async fn stream_chat_completion(
+    client: &Client<OpenAIConfig>,
+    mut request: CreateChatCompletionRequest,
+    mut on_delta: impl FnMut(&str),
+) -> Result<(String, Option<TokenUsage>), OpenAIError> {
+    request.stream = Some(true);
+    request.stream_options = Some(ChatCompletionStreamOptions {
+        include_usage: true,
+    });
+
+    let mut stream = client.chat().create_stream(request).await?;
+    let mut content = String::new();
+    let mut usage = None;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        // With include_usage the last chunk has the usage and no choices
+        if let Some(chunk_usage) = &chunk.usage {
+            usage = Some(TokenUsage::from(chunk_usage));
+        }
+        for choice in &chunk.choices {
+            if let Some(delta) = &choice.delta.content {
+                content.push_str(delta);
+                on_delta(delta);
+            }
+        }
+    }
+    Ok((content, usage))
+}
+
+/// Stream a chat completion for `model`, adding its OpenRouter routing and reasoning
+/// fields to the body when it has any. The typed request can't carry those fields,
+/// so such requests are sent as raw JSON. Rate limits and server errors are retried
+/// with backoff as long as no text has been streamed yet.
+pub async fn stream_model_completion(
+    client: &Client<OpenAIConfig>,
+    provider: &LLMProvider,
+    api_key: &str,
+    model: &LLMModel,
+    request: CreateChatCompletionRequest,
+    mut on_delta: impl FnMut(&str),
+) -> Result<(String, Option<TokenUsage>), OpenAIError> {
+    let mut extra_fields = crate::openrouter::extra_body_fields(provider, model);
+    extra_fields.extend(crate::reasoning::body_fields(provider, model));
+
+    let mut retry = Retry::new(format!("Completion with '{}'", model.model_id));
+    loop {
+        let mut streamed = false;
+        let mut forward = |delta: &str| {
+            streamed = true;
+            on_delta(delta);
+        };
+        let result = if extra_fields.is_empty() {
+            stream_chat_completion(client, request.clone(), &mut forward).await
+        } else {
+            stream_with_extra_fields(
+                provider,
+                api_key,
+                request.clone(),
+                extra_fields.clone(),
+                &mut forward,
+            )
+            .await
+        };
+        match result {
+            Ok(output) => {
+                retry.succeeded();
+                return Ok(output);
+            }
+            // Text already shown can't be taken back, so a failed stream isn't retried
+            Err(e) if !streamed && retry.should_retry(&e).await => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn api_error(message: String) -> OpenAIError {
+    OpenAIError::ApiError(ApiError {
+        message,
+        r#type: None,
+        param: None,
+        code: None,
+    })
+}
+
+/// An error response, with the HTTP status as its code so [`AppError`] can tell a
+/// bad key from a rate limit without reading the message
+fn status_error(status: StatusCode, text: String) -> OpenAIError {
+    OpenAIError::ApiError(ApiError {
+        message: text,
+        r#type: None,
+        param: None,
+        code: Some(status.as_u16().to_string()),
+    })
+}
+
+/// Splits a server-sent events byte stream into the payloads of its `data:` lines
+#[derive(Default)]
+struct SseDecoder {
+    buffer: Vec<u8>,
+}
+
+impl SseDecoder {
+    /// Add received bytes, returning the data of every line they complete
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(data) = line.trim().strip_prefix("data:") {
+                events.push(data.trim().to_string());
+            }
+        }
+        events
+    }
+}
+
+async fn stream_with_extra_fields(
+    provider: &LLMProvider,
+    api_key: &str,
+    request: CreateChatCompletionRequest,
+    extra_fields: Map<String, Value>,
+    mut on_delta: impl FnMut(&str),
+) -> Result<(String, Option<TokenUsage>), OpenAIError> {
+    let mut body = serde_json::to_value(&request)
+        .map_err(|e| OpenAIError::InvalidArgument(format!("Failed to encode request: {}", e)))?;
+    if let Value::Object(fields) = &mut body {
+        fields.extend(extra_fields);
+        fields.insert("stream".to_string(), Value::Bool(true));
+        fields.insert(
+            "stream_options".to_string(),
+            json!({ "include_usage": true }),
+        );
+    }
+
+    let url = format!(
+        "{}/chat/completions",
+        provider.base_url.trim_end_matches('/')
+    );
+    let response = reqwest::Client::new()
+        .post(&url)
+        .bearer_auth(api_key)
+        .headers(custom_headers(provider, api_key).map_err(OpenAIError::InvalidArgument)?)
+        .json(&body)
+        .send()
+        .await
+        .map_err(OpenAIError::Reqwest)?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        return Err(status_error(status, text));
+    }
+
+    // Server-sent events: one `data:` line per chunk, ending with `data: [DONE]`
+    let mut stream = response.bytes_stream();
+    let mut events = SseDecoder::default();
+    let mut content = String::new();
+    let mut usage = None;
+    while let Some(bytes) = stream.next().await {
+        for data in events.push(&bytes.map_err(OpenAIError::Reqwest)?) {
+            if data == "[DONE]" {
+                return Ok((content, usage));
+            }
+
+            let chunk: Value = serde_json::from_str(&data)
+                .map_err(|e| api_error(format!("Invalid stream chunk: {}", e)))?;
+            if let Some(error) = chunk.get("error") {
+                // OpenAI sends its error code as a string, OpenRouter the HTTP status
+                let code = match &error["code"] {
+                    Value::String(code) => Some(code.clone()),
+                    Value::Number(code) => Some(code.to_string()),
+                    _ => None,
+                };
+                return Err(OpenAIError::ApiError(ApiError {
+                    message: error["message"]
+                        .as_str()
+                        .unwrap_or("Stream failed")
+                        .to_string(),
+                    r#type: error["type"].as_str().map(str::to_string),
+                    param: None,
+                    code,
+                }));
+            }
+            if let Some(chunk_usage) = TokenUsage::from_json(&chunk["usage"]) {
+                usage = Some(chunk_usage);
+            }
+            for choice in chunk["choices"].as_array().into_iter().flatten() {
+                if let Some(delta) = choice["delta"]["content"].as_str() {
+                    content.push_str(delta);
+                    on_delta(delta);
+                }
+            }
+        }
+    }
+    Ok((content, usage))
+}
+
+const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+
+/// Gemini providers are sent to the native generateContent API: the OpenAI-compatible
+/// endpoint drops system instructions, safety settings and thinking budgets
+pub fn is_gemini(provider: &LLMProvider) -> bool {
+    provider.id == "gemini" || provider.id == "gemini_oauth"
+}
+
+/// A Gemini `contents` entry: the text followed by base64 PNG images.
+/// `role` is "user" or "model".
+pub fn gemini_content(role: &str, text: &str, images: &[String]) -> Value {
+    let mut parts = vec![json!({ "text": text })];
+    parts.extend(images.iter().map(|image| {
+        json!({
+            "inline_data": {
+                "mime_type": "image/png",
+                "data": image
+            }
+        })
+    }));
+    json!({ "role": role, "parts": parts })
+}
+
+/// A generateContent body with the system prompt as a real `systemInstruction`
+/// instead of a leading user turn
+pub fn gemini_request_body(system_instruction: Option<&str>, contents: Vec<Value>) -> Value {
+    let mut body = json!({ "contents": contents });
+    if let Some(instruction) = system_instruction.filter(|s| !s.trim().is_empty()) {
+        body["systemInstruction"] = json!({ "parts": [{ "text": instruction }] });
+    }
+    body
+}
+
+/// The answer of a native Gemini request
+#[derive(Debug, Default)]
+pub struct GeminiResponse {
+    pub text: String,
+    pub usage: Option<TokenUsage>,
+    /// The candidate's `groundingMetadata`, present when Google Search was used
+    pub grounding_metadata: Option<Value>,
+    /// Why generation stopped, e.g. "STOP", "MAX_TOKENS" or "SAFETY"
+    pub finish_reason: Option<String>,
+    /// Images returned by image models, base64 encoded
+    pub images: Vec<GeminiImage>,
+    /// `functionCall` parts, kept whole so they can be sent back with the results
+    pub function_calls: Vec<Value>,
+}
+
+/// An image part of a Gemini response
+#[derive(Debug, Clone)]
+pub struct GeminiImage {
+    pub mime_type: String,
+    pub data: String,
+}
+
+/// Send a generateContent request, streamed so `on_delta` gets text as it arrives.
+/// API-key providers use the Generative Language API, OAuth providers the Code
+/// Assist API, which takes the same body wrapped with the project. Rate limits and
+/// server errors are retried with backoff as long as no text has been streamed yet.
+pub async fn gemini_generate_content(
+    provider: &LLMProvider,
+    api_key: &str,
+    model_id: &str,
+    body: Value,
+    mut on_delta: impl FnMut(&str),
+) -> Result<GeminiResponse, AppError> {
+    let mut retry = Retry::new(format!("Gemini request with '{}'", model_id));
+    loop {
+        let mut streamed = false;
+        let forward = |delta: &str| {
+            streamed = true;
+            on_delta(delta);
+        };
+        let result =
+            gemini_generate_content_once(provider, api_key, model_id, body.clone(), forward).await;
+        match result {
+            Ok(response) => {
+                retry.succeeded();
+                return Ok(response);
+            }
+            Err(e) if !streamed && retry.should_retry(&e).await => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+async fn gemini_generate_content_once(
+    provider: &LLMProvider,
+    api_key: &str,
+    model_id: &str,
+    body: Value,
+    mut on_delta: impl FnMut(&str),
+) -> Result<GeminiResponse, AppError> {
+    let client = reqwest::Client::new();
+    let request = if provider.auth_method == AuthMethod::OAuth {
+        let project_id = google::ensure_project_id(api_key)
+            .await
+            .map_err(|e| format!("Failed to get project ID: {}", e))?;
+        client
+            .post(google::build_code_assist_url("streamGenerateContent", true))
+            .bearer_auth(api_key)
+            .header("User-Agent", "google-api-nodejs-client/9.15.1")
+            .header("X-Goog-Api-Client", "gl-node/22.17.0")
+            .header(
+                "Client-Metadata",
+                "ideType=IDE_UNSPECIFIED,platform=PLATFORM_UNSPECIFIED,pluginType=GEMINI",
+            )
+            .json(&google::wrap_request_for_code_assist(
+                &project_id,
+                model_id,
+                body,
+            ))
+    } else {
+        client
+            .post(format!(
+                "{}/{}:streamGenerateContent?alt=sse",
+                GEMINI_API_BASE, model_id
+            ))
+            .header("x-goog-api-key", api_key)
+            .json(&body)
+    };
+    let response = request
+        .headers(custom_headers(provider, api_key)?)
+        .send()
+        .await
+        .map_err(|e| AppError::from_request(&e, format!("Gemini request failed: {}", e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        return Err(AppError::from_status(
+            status,
+            format!("Gemini API error {}: {}", status, text),
+        ));
+    }
+
+    let mut stream = response.bytes_stream();
+    let mut events = SseDecoder::default();
+    let mut result = GeminiResponse::default();
+    while let Some(bytes) = stream.next().await {
+        let bytes = bytes
+            .map_err(|e| AppError::from_request(&e, format!("Gemini stream failed: {}", e)))?;
+        for data in events.push(&bytes) {
+            let chunk: Value = serde_json::from_str(&data)
+                .map_err(|e| AppError::internal(format!("Invalid Gemini stream chunk: {}", e)))?;
+            let chunk = google::unwrap_code_assist_response(chunk)?;
+            if let Some(error) = chunk.get("error") {
+                let message = format!(
+                    "Gemini API error: {}",
+                    error["message"].as_str().unwrap_or("Stream failed")
+                );
+                let status = error["code"]
+                    .as_u64()
+                    .and_then(|code| StatusCode::from_u16(code as u16).ok());
+                return Err(match status {
+                    Some(status) => AppError::from_status(status, message),
+                    None => AppError::from(message),
+                });
+            }
+            // A prompt blocked by the safety filters gets no candidates at all
+            if let Some(reason) = chunk["promptFeedback"]["blockReason"].as_str() {
+                return Err(AppError::invalid_input(format!(
+                    "Gemini blocked the prompt ({})",
+                    reason
+                )));
+            }
+            if let Some(mut usage) = TokenUsage::from_json(&chunk["usageMetadata"]) {
+                // Thinking tokens are billed as output but reported separately
+                let thoughts = chunk["usageMetadata"]["thoughtsTokenCount"].as_u64();
+                usage.completion_tokens += thoughts.unwrap_or(0) as u32;
+                result.usage = Some(usage);
+            }
+
+            let candidate = &chunk["candidates"][0];
+            if let Some(grounding) = candidate.get("groundingMetadata") {
+                result.grounding_metadata = Some(grounding.clone());
+            }
+            if let Some(reason) = candidate["finishReason"].as_str() {
+                result.finish_reason = Some(reason.to_string());
+            }
+            for part in candidate["content"]["parts"]
+                .as_array()
+                .into_iter()
+                .flatten()
+            {
+                // Thought summaries aren't part of the answer
+                if part["thought"].as_bool() == Some(true) {
+                    continue;
+                }
+                if let Some(text) = part["text"].as_str() {
+                    result.text.push_str(text);
+                    on_delta(text);
+                }
+                if part.get("functionCall").is_some() {
+                    result.function_calls.push(part.clone());
+                }
+                if let Some(data) = part["inlineData"]["data"].as_str() {
+                    result.images.push(GeminiImage {
+                        mime_type: part["inlineData"]["mimeType"]
+                            .as_str()
+                            .unwrap_or("image/png")
+                            .to_string(),
+                        data: data.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Safety and recitation stops can cut the answer off before any text
+    if result.text.is_empty() && result.images.is_empty() && result.function_calls.is_empty() {
+        if let Some(reason) = result
+            .finish_reason
+            .as_deref()
+            .filter(|r| *r != "STOP" && *r != "MAX_TOKENS")
+        {
+            return Err(AppError::internal(format!(
+                "Gemini returned no text (finish reason: {})",
+                reason
+            )));
+        }
+    }
+    Ok(result)
+}
+
+/// Parse user-defined headers into a header map. `{api_key}` in a value is replaced
+/// with the provider's key, for gateways that expect it in their own header.
+pub fn parse_custom_headers(
+    headers: &HashMap<String, String>,
+    api_key: &str,
+) -> Result<HeaderMap, String> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.trim().as_bytes())
+            .map_err(|e| format!("Invalid header name '{}': {}", name, e))?;
+        let header_value = HeaderValue::from_str(&value.replace("{api_key}", api_key))
+            .map_err(|e| format!("Invalid header value for '{}': {}", name, e))?;
+        map.insert(header_name, header_value);
+    }
+    Ok(map)
+}
+
+/// The provider's custom headers (e.g. `x-org-id` for a LiteLLM or corporate gateway)
+pub fn custom_headers(provider: &LLMProvider, api_key: &str) -> Result<HeaderMap, String> {
+    parse_custom_headers(&provider.custom_headers, api_key)
+}
+
+fn http_client_with_headers(headers: HeaderMap) -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
+
 /// Create an OpenAI-compatible client configured for the given provider
 pub fn create_client(
     provider: &LLMProvider,
@@ -235,25 +743,21 @@ pub fn create_client(
     let config = OpenAIConfig::new()
         .with_api_base(base_url)
         .with_api_key(api_key.clone());
+    let extra_headers = custom_headers(provider, &api_key)?;
 
     // Create client with provider-specific headers
     let client = if provider.id == "anthropic" {
         // Anthropic requires a version header
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            "anthropic-version",
-            reqwest::header::HeaderValue::from_static("2023-06-01"),
-        );
-
-        let http_client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        let mut headers = HeaderMap::new();
+        headers.insert("anthropic-version", HeaderValue::from_static("2023-06-01"));
+        headers.extend(extra_headers);
 
-        Client::with_config(config).with_http_client(http_client)
+        Client::with_config(config).with_http_client(http_client_with_headers(headers)?)
     } else if provider.auth_method == AuthMethod::OAuth {
         // OAuth providers need specific headers
-        create_oauth_client(provider, &config, &api_key)?
+        create_oauth_client(provider, &config, &api_key, extra_headers)?
+    } else if !extra_headers.is_empty() {
+        Client::with_config(config).with_http_client(http_client_with_headers(extra_headers)?)
     } else {
         Client::with_config(config)
     };
@@ -266,6 +770,7 @@ fn create_oauth_client(
     provider: &LLMProvider,
     config: &OpenAIConfig,
     access_token: &str,
+    extra_headers: HeaderMap,
 ) -> Result<Client<OpenAIConfig>, String> {
     let oauth_provider = OAuthProvider::from_str(&provider.id).ok_or_else(|| {
         format!(
@@ -286,19 +791,15 @@ fn create_oauth_client(
     };
 
     // Convert HashMap to reqwest HeaderMap
-    let mut headers = reqwest::header::HeaderMap::new();
+    let mut headers = HeaderMap::new();
     for (key, value) in headers_map {
-        let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+        let header_name = HeaderName::from_bytes(key.as_bytes())
             .map_err(|e| format!("Invalid header name '{}': {}", key, e))?;
-        let header_value = reqwest::header::HeaderValue::from_str(&value)
+        let header_value = HeaderValue::from_str(&value)
             .map_err(|e| format!("Invalid header value for '{}': {}", key, e))?;
         headers.insert(header_name, header_value);
     }
+    headers.extend(extra_headers);
 
-    let http_client = reqwest::Client::builder()
-        .default_headers(headers)
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
-
-    Ok(Client::with_config(config.clone()).with_http_client(http_client))
+    Ok(Client::with_config(config.clone()).with_http_client(http_client_with_headers(headers)?))
 }
@@ -1,12 +1,16 @@
 use crate::audio_toolkit::{
     list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad, SpeechSegment,
+    SystemAudioCapture, WakeWordDetector, WakeWordModels,
 };
 use crate::helpers::clamshell;
+use crate::managers::stt_engine::Transcript;
 use crate::managers::transcription::TranscriptionManager;
 use crate::settings::{get_settings, AppSettings};
 use crate::utils;
 use log::{debug, error, info};
 use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
@@ -104,28 +108,84 @@ fn set_mute(mute: bool) {
 
 const WHISPER_SAMPLE_RATE: usize = 16000;
 
+/// Binding that records system output mixed with the microphone
+pub const SYSTEM_AUDIO_BINDING: &str = "transcribe_system_audio";
+
+/// Seconds of silence that end a wake word recording whose binding doesn't set
+/// its own auto-stop, since no key will be pressed to stop it
+const WAKE_WORD_SILENCE_SECONDS: u32 = 2;
+
 /* ──────────────────────────────────────────────────────────────── */
 
+/// Join finished segments with the text of the segment still being spoken
+fn live_preview_text(segments: &BTreeMap<u64, String>, in_flight: Option<&str>) -> String {
+    segments
+        .values()
+        .map(|s| s.as_str())
+        .chain(in_flight.filter(|t| !t.is_empty()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub struct StreamingTranscriptionSession {
     segment_tx: mpsc::Sender<SpeechSegment>,
-    result_rx: mpsc::Receiver<(u64, anyhow::Result<String>)>,
+    result_rx: mpsc::Receiver<(u64, anyhow::Result<Transcript>)>,
     worker_handle: Option<JoinHandle<()>>,
-    segments_text: BTreeMap<u64, String>,
+    segments: BTreeMap<u64, Transcript>,
 }
 
 impl StreamingTranscriptionSession {
     pub fn new(transcription_manager: Arc<TranscriptionManager>) -> Self {
         let (segment_tx, segment_rx) = mpsc::channel::<SpeechSegment>();
-        let (result_tx, result_rx) = mpsc::channel::<(u64, anyhow::Result<String>)>();
+        let (result_tx, result_rx) = mpsc::channel::<(u64, anyhow::Result<Transcript>)>();
 
         let worker_handle = thread::spawn(move || {
-            while let Ok(segment) = segment_rx.recv() {
+            let live_enabled = transcription_manager.live_transcription_enabled();
+            // Finished segments decoded so far, used to build the live preview text
+            let mut live_text: BTreeMap<u64, String> = BTreeMap::new();
+
+            while let Ok(mut segment) = segment_rx.recv() {
+                if segment.is_partial {
+                    // Only decode the newest snapshot; a finished segment always wins
+                    while let Ok(next) = segment_rx.try_recv() {
+                        let is_final = !next.is_partial;
+                        segment = next;
+                        if is_final {
+                            break;
+                        }
+                    }
+                }
+
+                if segment.is_partial {
+                    if !live_enabled || !transcription_manager.engine_supports_partials() {
+                        continue;
+                    }
+                    match transcription_manager.transcribe_partial(segment.samples) {
+                        Ok(text) => {
+                            transcription_manager
+                                .emit_partial(live_preview_text(&live_text, Some(&text)), true);
+                        }
+                        Err(e) => debug!("Partial transcription failed: {}", e),
+                    }
+                    continue;
+                }
+
                 debug!(
                     "Streaming transcription: processing segment {} ({} samples)",
                     segment.index,
                     segment.samples.len()
                 );
-                let result = transcription_manager.transcribe(segment.samples);
+                let result = transcription_manager
+                    .transcribe_with_timings(segment.samples)
+                    .map(|t| t.offset_by(segment.offset));
+                if live_enabled {
+                    if let Ok(transcript) = &result {
+                        if !transcript.text.is_empty() {
+                            live_text.insert(segment.index, transcript.text.clone());
+                        }
+                    }
+                    transcription_manager.emit_partial(live_preview_text(&live_text, None), false);
+                }
                 if result_tx.send((segment.index, result)).is_err() {
                     break;
                 }
@@ -137,7 +197,7 @@ impl StreamingTranscriptionSession {
             segment_tx,
             result_rx,
             worker_handle: Some(worker_handle),
-            segments_text: BTreeMap::new(),
+            segments: BTreeMap::new(),
         }
     }
 
@@ -145,7 +205,7 @@ impl StreamingTranscriptionSession {
         self.segment_tx.clone()
     }
 
-    pub fn finish(mut self) -> String {
+    pub fn finish(mut self) -> Transcript {
         drop(self.segment_tx);
 
         if let Some(handle) = self.worker_handle.take() {
@@ -153,15 +213,18 @@ impl StreamingTranscriptionSession {
         }
 
         while let Ok((index, result)) = self.result_rx.try_recv() {
-            if let Ok(text) = result {
-                if !text.is_empty() {
-                    self.segments_text.insert(index, text);
+            if let Ok(transcript) = result {
+                if !transcript.text.is_empty() {
+                    self.segments.insert(index, transcript);
                 }
             }
         }
 
-        let combined: Vec<&str> = self.segments_text.values().map(|s| s.as_str()).collect();
-        combined.join(" ")
+        let combined: Vec<&str> = self.segments.values().map(|t| t.text.as_str()).collect();
+        Transcript {
+            text: combined.join(" "),
+            words: self.segments.into_values().flat_map(|t| t.words).collect(),
+        }
     }
 }
 
@@ -207,6 +270,28 @@ fn create_audio_recorder(
 
 /* ──────────────────────────────────────────────────────────────── */
 
+/// Whether the test harness has queued canned audio for the next recording
+#[cfg(any(test, feature = "test-support"))]
+fn fixture_audio_queued() -> bool {
+    crate::test_support::fixture_audio_queued()
+}
+
+#[cfg(not(any(test, feature = "test-support")))]
+fn fixture_audio_queued() -> bool {
+    false
+}
+
+/// Canned audio queued by the test harness, returned in place of the recording
+#[cfg(any(test, feature = "test-support"))]
+fn take_fixture_audio() -> Option<Vec<f32>> {
+    crate::test_support::take_fixture_audio()
+}
+
+#[cfg(not(any(test, feature = "test-support")))]
+fn take_fixture_audio() -> Option<Vec<f32>> {
+    None
+}
+
 #[derive(Clone)]
 pub struct AudioRecordingManager {
     state: Arc<Mutex<RecordingState>>,
@@ -230,6 +315,44 @@ pub struct AudioRecordingManager {
     vision_context: Arc<Mutex<Vec<String>>>,
     /// Active streaming transcription session (transcribes segments while recording)
     streaming_session: Arc<Mutex<Option<StreamingTranscriptionSession>>>,
+    /// Incremented on every recording start so a stale no-speech watchdog can tell
+    /// that the recording it was watching is over
+    recording_generation: Arc<AtomicU64>,
+    /// System output capture, running while a `transcribe_system_audio` recording is active
+    system_audio: Arc<Mutex<Option<SystemAudioCapture>>>,
+    /// Whether a wake word listener is fed by the recorder
+    wake_word_active: Arc<Mutex<bool>>,
+    /// Set by the wake word listener so the recording it starts stops on silence
+    hands_free: Arc<AtomicBool>,
+    /// Seconds a follow-up recording waits for speech, set while one is starting
+    follow_up_seconds: Arc<AtomicU32>,
+}
+
+/// Paths of the wake word's mel spectrogram, embedding and classifier models, or
+/// None while any of them is missing. There's no trained "Hey Ramble" classifier to
+/// bundle yet, so the wake word is only available once the models are installed.
+pub fn wake_word_model_paths(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+) -> Option<[PathBuf; 3]> {
+    let resolve = |name: &str| {
+        app.path()
+            .resolve(
+                format!("resources/models/wake_word/{name}"),
+                tauri::path::BaseDirectory::Resource,
+            )
+            .ok()
+    };
+    let classifier = match &settings.wake_word_model_path {
+        Some(path) => PathBuf::from(path),
+        None => resolve("hey_ramble.onnx")?,
+    };
+    let paths = [
+        resolve("melspectrogram.onnx")?,
+        resolve("embedding_model.onnx")?,
+        classifier,
+    ];
+    paths.iter().all(|path| path.is_file()).then_some(paths)
 }
 
 impl AudioRecordingManager {
@@ -258,11 +381,19 @@ impl AudioRecordingManager {
             coherent_mode: Arc::new(Mutex::new(false)),
             vision_context: Arc::new(Mutex::new(Vec::new())),
             streaming_session: Arc::new(Mutex::new(None)),
+            recording_generation: Arc::new(AtomicU64::new(0)),
+            system_audio: Arc::new(Mutex::new(None)),
+            wake_word_active: Arc::new(Mutex::new(false)),
+            hands_free: Arc::new(AtomicBool::new(false)),
+            follow_up_seconds: Arc::new(AtomicU32::new(0)),
         };
 
         // Always-on?  Open immediately.
         if matches!(mode, MicrophoneMode::AlwaysOn) {
             manager.start_microphone_stream()?;
+            if let Err(e) = manager.update_wake_word() {
+                error!("Failed to start wake word listener: {e}");
+            }
         }
 
         Ok(manager)
@@ -414,9 +545,79 @@ impl AudioRecordingManager {
         }
 
         *self.mode.lock().unwrap() = new_mode;
+        self.update_wake_word()
+    }
+
+    /* ---------- wake word --------------------------------------------------- */
+
+    /// Start or stop listening for the wake word to match the settings. It's only
+    /// heard on the always-on stream, between recordings.
+    pub fn update_wake_word(&self) -> Result<(), anyhow::Error> {
+        self.stop_wake_word();
+
+        let settings = get_settings(&self.app_handle);
+        let always_on = matches!(*self.mode.lock().unwrap(), MicrophoneMode::AlwaysOn);
+        if !settings.wake_word_enabled || !always_on {
+            return Ok(());
+        }
+
+        let [melspectrogram, embedding, classifier] =
+            wake_word_model_paths(&self.app_handle, &settings)
+                .ok_or_else(|| anyhow::anyhow!("The wake word models aren't installed"))?;
+        let mut detector = WakeWordDetector::new(
+            WakeWordModels {
+                melspectrogram: &melspectrogram,
+                embedding: &embedding,
+                classifier: &classifier,
+            },
+            settings.wake_word_threshold,
+        )?;
+
+        let (frame_tx, frame_rx) = mpsc::channel::<Vec<f32>>();
+        match self.recorder.lock().unwrap().as_ref() {
+            Some(rec) => rec.set_idle_frame_sender(Some(frame_tx)),
+            None => return Err(anyhow::anyhow!("Recorder not available")),
+        }
+        *self.wake_word_active.lock().unwrap() = true;
+
+        let binding_id = settings.wake_word_binding;
+        let manager = self.clone();
+        thread::spawn(move || {
+            // Ends once the recorder drops the sender
+            while let Ok(frame) = frame_rx.recv() {
+                match detector.push_frame(&frame) {
+                    Ok(true) => {
+                        info!("Wake word heard, starting '{}'", binding_id);
+                        manager.hands_free.store(true, Ordering::SeqCst);
+                        utils::start_hands_free(&manager.app_handle, &binding_id);
+                        manager.hands_free.store(false, Ordering::SeqCst);
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("Wake word detection failed: {e}");
+                        break;
+                    }
+                }
+            }
+            debug!("Wake word listener exiting");
+        });
+
+        info!("Listening for the wake word");
         Ok(())
     }
 
+    fn stop_wake_word(&self) {
+        let mut active = self.wake_word_active.lock().unwrap();
+        if !*active {
+            return;
+        }
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_idle_frame_sender(None);
+        }
+        *active = false;
+        debug!("Stopped listening for the wake word");
+    }
+
     /* ---------- recording --------------------------------------------------- */
 
     pub fn try_start_recording(&self, binding_id: &str) -> bool {
@@ -444,6 +645,16 @@ impl AudioRecordingManager {
                 // Clear any previous vision context
                 self.vision_context.lock().unwrap().clear();
 
+                // Test fixtures stand in for the microphone, which is never opened
+                if fixture_audio_queued() {
+                    debug!("[AUDIO] Recording canned fixture audio for binding {binding_id}");
+                    *self.is_recording.lock().unwrap() = true;
+                    *state = RecordingState::Recording {
+                        binding_id: binding_id.to_string(),
+                    };
+                    return true;
+                }
+
                 // Ensure microphone is open in on-demand mode
                 if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
                     if let Err(e) = self.start_microphone_stream() {
@@ -452,13 +663,32 @@ impl AudioRecordingManager {
                     }
                 }
 
+                if binding_id == SYSTEM_AUDIO_BINDING {
+                    if let Err(e) = self.start_system_audio() {
+                        error!("Failed to capture system audio: {e}");
+                        utils::show_error_overlay(&self.app_handle, &e, false);
+                        return false;
+                    }
+                }
+
                 if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                    rec.set_noise_suppression(get_settings(&self.app_handle).noise_suppression);
+                    // Only an always-on stream has been listening before the hotkey
+                    rec.set_pre_roll(matches!(
+                        *self.mode.lock().unwrap(),
+                        MicrophoneMode::AlwaysOn
+                    ));
                     if rec.start().is_ok() {
                         *self.is_recording.lock().unwrap() = true;
                         *state = RecordingState::Recording {
                             binding_id: binding_id.to_string(),
                         };
                         debug!("[AUDIO] Recording started successfully for binding {binding_id}");
+                        self.spawn_no_speech_watchdog();
+                        self.spawn_silence_auto_stop(
+                            binding_id,
+                            self.hands_free.load(Ordering::SeqCst),
+                        );
                         return true;
                     }
                 }
@@ -484,6 +714,128 @@ impl AudioRecordingManager {
         false
     }
 
+    /// Start listening for a follow-up to the voice command that just ran. The
+    /// recording stops on silence like a wake word one, and ends quietly if nothing
+    /// is said within `seconds`.
+    pub fn start_follow_up(&self, binding_id: &str, seconds: u32) -> bool {
+        self.hands_free.store(true, Ordering::SeqCst);
+        self.follow_up_seconds.store(seconds, Ordering::SeqCst);
+        utils::start_hands_free(&self.app_handle, binding_id);
+        self.follow_up_seconds.store(0, Ordering::SeqCst);
+        self.hands_free.store(false, Ordering::SeqCst);
+        self.is_recording()
+    }
+
+    /// Cancel the recording if the VAD hasn't heard any speech within the configured
+    /// timeout, rather than sending silence through transcription
+    fn spawn_no_speech_watchdog(&self) {
+        let follow_up_secs = self.follow_up_seconds.load(Ordering::SeqCst);
+        let timeout_secs = if follow_up_secs > 0 {
+            follow_up_secs
+        } else {
+            get_settings(&self.app_handle).no_speech_timeout_seconds
+        };
+        let generation = self.recording_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        if timeout_secs == 0 {
+            return;
+        }
+
+        let manager = self.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(timeout_secs as u64));
+
+            if manager.recording_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            // A paused recording isn't capturing, and one resumed after a pause
+            // already holds audio from before it
+            let still_recording = matches!(
+                *manager.state.lock().unwrap(),
+                RecordingState::Recording { .. }
+            );
+            if !still_recording || !manager.paused_samples.lock().unwrap().is_empty() {
+                return;
+            }
+            let speech_detected = manager
+                .recorder
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map_or(true, |rec| rec.speech_detected());
+            if speech_detected {
+                return;
+            }
+
+            if follow_up_secs > 0 {
+                utils::end_follow_up(&manager.app_handle);
+                return;
+            }
+            info!(
+                "No speech detected after {}s, cancelling recording",
+                timeout_secs
+            );
+            utils::cancel_for_no_speech(&manager.app_handle);
+        });
+    }
+
+    /// Stop a toggled-on recording once the VAD has heard the binding's configured
+    /// seconds of silence after speech. Push-to-talk holds are left alone, and a
+    /// `hands_free` recording always stops on silence.
+    fn spawn_silence_auto_stop(&self, binding_id: &str, hands_free: bool) {
+        let Some(silence_secs) = get_settings(&self.app_handle)
+            .bindings
+            .get(binding_id)
+            .map(|b| b.auto_stop_silence_seconds)
+            .filter(|&secs| secs > 0)
+            .or(hands_free.then_some(WAKE_WORD_SILENCE_SECONDS))
+        else {
+            return;
+        };
+        let limit = Duration::from_secs(silence_secs as u64);
+        // Bumped by the no-speech watchdog just before this
+        let generation = self.recording_generation.load(Ordering::SeqCst);
+        let binding_id = binding_id.to_string();
+
+        let manager = self.clone();
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_millis(250));
+
+            if manager.recording_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            match &*manager.state.lock().unwrap() {
+                RecordingState::Recording { binding_id: active } if *active == binding_id => {}
+                // Pausing keeps the session alive without capturing
+                RecordingState::Paused { .. } => continue,
+                _ => return,
+            }
+
+            let toggled_on = manager
+                .app_handle
+                .state::<crate::ManagedToggleState>()
+                .lock()
+                .is_ok_and(|s| s.active_toggles.get(&binding_id).copied().unwrap_or(false));
+            if !toggled_on || crate::shortcut::is_binding_held(&binding_id) {
+                continue;
+            }
+
+            let silent_long_enough = manager
+                .recorder
+                .lock()
+                .unwrap()
+                .as_ref()
+                .is_some_and(|rec| rec.speech_detected() && rec.trailing_silence() >= limit);
+            if silent_long_enough {
+                info!(
+                    "{}s of silence in toggle recording for '{}', stopping",
+                    silence_secs, binding_id
+                );
+                utils::auto_stop_recording(&manager.app_handle, &binding_id);
+                return;
+            }
+        });
+    }
+
     pub fn update_selected_device(&self) -> Result<(), anyhow::Error> {
         // If currently open, restart the microphone stream to use the new device
         if *self.is_open.lock().unwrap() {
@@ -493,6 +845,24 @@ impl AudioRecordingManager {
         Ok(())
     }
 
+    /// Start capturing system output and mix it into the recorder
+    fn start_system_audio(&self) -> Result<(), String> {
+        let capture = SystemAudioCapture::start()?;
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_system_audio_source(Some(capture.buffer()));
+        }
+        *self.system_audio.lock().unwrap() = Some(capture);
+        Ok(())
+    }
+
+    fn stop_system_audio(&self) {
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_system_audio_source(None);
+        }
+        // Dropping the capture stops its stream
+        let _ = self.system_audio.lock().unwrap().take();
+    }
+
     pub fn stop_recording(&self, binding_id: &str) -> Option<Vec<f32>> {
         let mut state = self.state.lock().unwrap();
 
@@ -508,7 +878,9 @@ impl AudioRecordingManager {
                 drop(state);
 
                 // Get current samples from recorder
-                let current_samples = if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                let current_samples = if let Some(samples) = take_fixture_audio() {
+                    samples
+                } else if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                     match rec.stop() {
                         Ok(result) => result.raw_full,
                         Err(e) => {
@@ -520,6 +892,7 @@ impl AudioRecordingManager {
                     error!("Recorder not available");
                     Vec::new()
                 };
+                self.stop_system_audio();
 
                 // Prepend any samples from before pause
                 let mut paused = self.paused_samples.lock().unwrap();
@@ -609,6 +982,8 @@ impl AudioRecordingManager {
         if let RecordingState::Paused { binding_id } = state.clone() {
             // Start recording again
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                // Audio from while paused must not leak into the recording
+                rec.set_pre_roll(false);
                 if rec.start().is_ok() {
                     *self.is_recording.lock().unwrap() = true;
                     *self.is_paused.lock().unwrap() = false;
@@ -650,6 +1025,7 @@ impl AudioRecordingManager {
                     rec.set_segment_sender(None);
                     let _ = rec.stop(); // Discard the result
                 }
+                self.stop_system_audio();
                 let _ = self.streaming_session.lock().unwrap().take();
 
                 // Clear the paused samples buffer
@@ -713,10 +1089,13 @@ impl AudioRecordingManager {
     /// Starts a streaming transcription session that will transcribe audio segments
     /// as they are detected during recording.
     pub fn start_streaming_transcription(&self, transcription_manager: Arc<TranscriptionManager>) {
+        let live = transcription_manager.live_transcription_enabled()
+            && transcription_manager.engine_supports_partials();
         let session = StreamingTranscriptionSession::new(transcription_manager);
         let segment_sender = session.get_segment_sender();
 
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_partials(live);
             rec.set_segment_sender(Some(segment_sender));
         }
 
@@ -726,19 +1105,19 @@ impl AudioRecordingManager {
 
     /// Stops the streaming transcription session and returns the accumulated transcription.
     /// This should be called after stop_recording() to get the pre-transcribed text.
-    pub fn finish_streaming_transcription(&self) -> Option<String> {
+    pub fn finish_streaming_transcription(&self) -> Option<Transcript> {
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
             rec.set_segment_sender(None);
         }
 
         let session = self.streaming_session.lock().unwrap().take();
         if let Some(session) = session {
-            let text = session.finish();
+            let transcript = session.finish();
             debug!(
                 "Streaming transcription session finished: {} chars",
-                text.len()
+                transcript.text.len()
             );
-            Some(text)
+            Some(transcript)
         } else {
             None
         }
@@ -0,0 +1,249 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::Utc;
+use log::{debug, info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+use rusqlite_migration::{Migrations, M};
+use serde::Serialize;
+use specta::Type;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Captures kept before the oldest are deleted
+const MAX_CAPTURES: u32 = 50;
+
+/// Longest side of a capture's thumbnail, in pixels
+const THUMBNAIL_SIZE: u32 = 240;
+
+/// Database migrations for the capture history.
+static MIGRATIONS: &[M] = &[
+    M::up(
+        "CREATE TABLE IF NOT EXISTS captures (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            kind TEXT NOT NULL,
+            source_app TEXT,
+            width INTEGER NOT NULL,
+            height INTEGER NOT NULL,
+            file_name TEXT NOT NULL,
+            thumbnail_name TEXT NOT NULL
+        );",
+    ),
+    M::up("CREATE INDEX IF NOT EXISTS idx_captures_timestamp ON captures(timestamp DESC);"),
+];
+
+/// A saved screen capture
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct CaptureEntry {
+    pub id: i64,
+    /// Unix seconds
+    pub timestamp: i64,
+    /// "region" or "screen"
+    pub kind: String,
+    /// App in front when the capture was taken, if known
+    pub source_app: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    /// Full-size PNG
+    pub path: String,
+    /// Small PNG preview for the browser
+    pub thumbnail_path: String,
+}
+
+pub struct CaptureManager {
+    db_path: PathBuf,
+    captures_dir: PathBuf,
+}
+
+/// Save a capture from anywhere in the app, off the calling thread. Failures are
+/// only logged so history never breaks a capture.
+pub fn record_capture(app: &AppHandle, kind: &'static str, png_base64: String) {
+    let source_app = crate::app_detection::get_frontmost_application()
+        .filter(|info| info.bundle_identifier != app.config().identifier)
+        .map(|info| info.display_name);
+    let manager = app.state::<Arc<CaptureManager>>().inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = manager.save(kind, source_app, &png_base64) {
+            warn!("Failed to save {} capture to history: {}", kind, e);
+        }
+    });
+}
+
+impl CaptureManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = app_handle.path().app_data_dir()?;
+        let captures_dir = app_data_dir.join("captures");
+        std::fs::create_dir_all(&captures_dir)?;
+
+        let manager = Self {
+            db_path: app_data_dir.join("captures.db"),
+            captures_dir,
+        };
+        manager.init_database()?;
+
+        Ok(manager)
+    }
+
+    fn init_database(&self) -> Result<()> {
+        info!("Initializing capture database at {:?}", self.db_path);
+
+        let mut conn = Connection::open(&self.db_path)?;
+        let migrations = Migrations::new(MIGRATIONS.to_vec());
+
+        #[cfg(debug_assertions)]
+        migrations.validate().expect("Invalid capture migrations");
+
+        migrations.to_latest(&mut conn)?;
+
+        Ok(())
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    fn entry_from_row(&self, row: &rusqlite::Row) -> rusqlite::Result<CaptureEntry> {
+        let file_name: String = row.get("file_name")?;
+        let thumbnail_name: String = row.get("thumbnail_name")?;
+        Ok(CaptureEntry {
+            id: row.get("id")?,
+            timestamp: row.get("timestamp")?,
+            kind: row.get("kind")?,
+            source_app: row.get("source_app")?,
+            width: row.get("width")?,
+            height: row.get("height")?,
+            path: self.file_path(&file_name),
+            thumbnail_path: self.file_path(&thumbnail_name),
+        })
+    }
+
+    fn file_path(&self, name: &str) -> String {
+        self.captures_dir.join(name).to_string_lossy().to_string()
+    }
+
+    /// Store a base64 PNG with a thumbnail, then drop the oldest captures over the cap
+    pub fn save(
+        &self,
+        kind: &str,
+        source_app: Option<String>,
+        png_base64: &str,
+    ) -> Result<CaptureEntry> {
+        let bytes = STANDARD.decode(png_base64)?;
+        let image = image::load_from_memory(&bytes)?;
+        let timestamp = Utc::now();
+        let stem = format!("capture-{}", timestamp.timestamp_millis());
+        let file_name = format!("{}.png", stem);
+        let thumbnail_name = format!("{}-thumb.png", stem);
+
+        std::fs::write(self.captures_dir.join(&file_name), &bytes)?;
+        image
+            .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+            .save_with_format(
+                self.captures_dir.join(&thumbnail_name),
+                image::ImageFormat::Png,
+            )?;
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO captures (timestamp, kind, source_app, width, height, file_name, thumbnail_name)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                timestamp.timestamp(),
+                kind,
+                source_app,
+                image.width(),
+                image.height(),
+                file_name,
+                thumbnail_name
+            ],
+        )?;
+        let id = conn.last_insert_rowid();
+        debug!(
+            "Saved {} capture {} ({}x{})",
+            kind,
+            id,
+            image.width(),
+            image.height()
+        );
+        self.prune(&conn)?;
+
+        Ok(CaptureEntry {
+            id,
+            timestamp: timestamp.timestamp(),
+            kind: kind.to_string(),
+            source_app,
+            width: image.width(),
+            height: image.height(),
+            path: self.file_path(&file_name),
+            thumbnail_path: self.file_path(&thumbnail_name),
+        })
+    }
+
+    /// Saved captures, newest first
+    pub fn list(&self) -> Result<Vec<CaptureEntry>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp, kind, source_app, width, height, file_name, thumbnail_name
+             FROM captures ORDER BY timestamp DESC, id DESC",
+        )?;
+        let entries = stmt
+            .query_map([], |row| self.entry_from_row(row))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(entries)
+    }
+
+    /// A capture's full-size image as base64 PNG, for attaching it again
+    pub fn load(&self, id: i64) -> Result<String> {
+        let conn = self.get_connection()?;
+        let file_name: Option<String> = conn
+            .query_row(
+                "SELECT file_name FROM captures WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let file_name = file_name.ok_or_else(|| anyhow!("Capture {} not found", id))?;
+        let bytes = std::fs::read(self.captures_dir.join(file_name))?;
+        Ok(STANDARD.encode(bytes))
+    }
+
+    pub fn delete(&self, id: i64) -> Result<()> {
+        let conn = self.get_connection()?;
+        self.delete_with(&conn, id)
+    }
+
+    fn delete_with(&self, conn: &Connection, id: i64) -> Result<()> {
+        let files: Option<(String, String)> = conn
+            .query_row(
+                "SELECT file_name, thumbnail_name FROM captures WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((file_name, thumbnail_name)) = files else {
+            return Err(anyhow!("Capture {} not found", id));
+        };
+        for name in [file_name, thumbnail_name] {
+            if let Err(e) = std::fs::remove_file(self.captures_dir.join(&name)) {
+                warn!("Failed to delete capture file {}: {}", name, e);
+            }
+        }
+        conn.execute("DELETE FROM captures WHERE id = ?1", params![id])?;
+        debug!("Deleted capture {}", id);
+        Ok(())
+    }
+
+    fn prune(&self, conn: &Connection) -> Result<()> {
+        let mut stmt = conn.prepare(
+            "SELECT id FROM captures ORDER BY timestamp DESC, id DESC LIMIT -1 OFFSET ?1",
+        )?;
+        let expired = stmt
+            .query_map(params![MAX_CAPTURES], |row| row.get::<_, i64>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        for id in expired {
+            self.delete_with(conn, id)?;
+        }
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ static MIGRATIONS: &[M] = &[
         );",
     ),
     M::up("CREATE INDEX IF NOT EXISTS idx_chats_updated_at ON chats(updated_at DESC);"),
+    M::up("ALTER TABLE chats ADD COLUMN model_id TEXT;"),
 ];
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -31,6 +32,8 @@ pub struct SavedChat {
     pub created_at: i64,
     pub updated_at: i64,
     pub messages: Vec<ChatMessage>,
+    /// Model the chat was last used with
+    pub model_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -40,6 +43,31 @@ pub struct ChatSummary {
     pub created_at: i64,
     pub updated_at: i64,
     pub message_count: usize,
+    pub model_id: Option<String>,
+    /// Images attached across all messages
+    pub attachment_count: usize,
+}
+
+/// The chat window only sends the newest message's images, so keep the ones
+/// already stored for earlier, unchanged messages. Attached files are kept the same way.
+fn carry_over_attachments(stored: &[ChatMessage], messages: &mut [ChatMessage]) {
+    for (message, old) in messages.iter_mut().zip(stored) {
+        if message.role != old.role || message.content != old.content {
+            continue;
+        }
+        if message.images.is_none() {
+            message.images = old.images.clone();
+        }
+        if message.files.is_none() {
+            message.files = old.files.clone();
+        }
+    }
+}
+
+fn parse_messages(messages_json: &str) -> rusqlite::Result<Vec<ChatMessage>> {
+    serde_json::from_str(messages_json).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+    })
 }
 
 pub struct ChatPersistenceManager {
@@ -80,15 +108,20 @@ impl ChatPersistenceManager {
         Ok(Connection::open(&self.db_path)?)
     }
 
-    pub fn save_chat(&self, title: Option<String>, messages: Vec<ChatMessage>) -> Result<i64> {
+    pub fn save_chat(
+        &self,
+        title: Option<String>,
+        messages: Vec<ChatMessage>,
+        model_id: Option<String>,
+    ) -> Result<i64> {
         let conn = self.get_connection()?;
         let now = Utc::now().timestamp();
         let messages_json = serde_json::to_string(&messages)?;
         let title = title.unwrap_or_else(|| "New Chat".to_string());
 
         conn.execute(
-            "INSERT INTO chats (title, created_at, updated_at, messages_json) VALUES (?1, ?2, ?3, ?4)",
-            params![title, now, now, messages_json],
+            "INSERT INTO chats (title, created_at, updated_at, messages_json, model_id) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![title, now, now, messages_json, model_id],
         )?;
 
         let id = conn.last_insert_rowid();
@@ -100,14 +133,23 @@ impl ChatPersistenceManager {
         Ok(id)
     }
 
-    pub fn update_chat(&self, id: i64, messages: Vec<ChatMessage>) -> Result<()> {
+    pub fn update_chat(
+        &self,
+        id: i64,
+        mut messages: Vec<ChatMessage>,
+        model_id: Option<String>,
+    ) -> Result<()> {
+        if let Some(stored) = self.get_chat(id)? {
+            carry_over_attachments(&stored.messages, &mut messages);
+        }
+
         let conn = self.get_connection()?;
         let now = Utc::now().timestamp();
         let messages_json = serde_json::to_string(&messages)?;
 
         conn.execute(
-            "UPDATE chats SET messages_json = ?1, updated_at = ?2 WHERE id = ?3",
-            params![messages_json, now, id],
+            "UPDATE chats SET messages_json = ?1, updated_at = ?2, model_id = ?3 WHERE id = ?4",
+            params![messages_json, now, model_id, id],
         )?;
 
         debug!("Updated chat with id: {}", id);
@@ -131,20 +173,13 @@ impl ChatPersistenceManager {
     pub fn get_chat(&self, id: i64) -> Result<Option<SavedChat>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, updated_at, messages_json FROM chats WHERE id = ?1",
+            "SELECT id, title, created_at, updated_at, messages_json, model_id FROM chats WHERE id = ?1",
         )?;
 
         let chat = stmt
             .query_row([id], |row| {
                 let messages_json: String = row.get("messages_json")?;
-                let messages: Vec<ChatMessage> =
-                    serde_json::from_str(&messages_json).map_err(|e| {
-                        rusqlite::Error::FromSqlConversionFailure(
-                            0,
-                            rusqlite::types::Type::Text,
-                            Box::new(e),
-                        )
-                    })?;
+                let messages = parse_messages(&messages_json)?;
 
                 Ok(SavedChat {
                     id: row.get("id")?,
@@ -152,6 +187,7 @@ impl ChatPersistenceManager {
                     created_at: row.get("created_at")?,
                     updated_at: row.get("updated_at")?,
                     messages,
+                    model_id: row.get("model_id")?,
                 })
             })
             .optional()?;
@@ -162,21 +198,25 @@ impl ChatPersistenceManager {
     pub fn list_chats(&self) -> Result<Vec<ChatSummary>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, updated_at, messages_json FROM chats ORDER BY updated_at DESC",
+            "SELECT id, title, created_at, updated_at, messages_json, model_id FROM chats ORDER BY updated_at DESC",
         )?;
 
         let rows = stmt.query_map([], |row| {
             let messages_json: String = row.get("messages_json")?;
-            let message_count = serde_json::from_str::<Vec<serde_json::Value>>(&messages_json)
-                .map(|v| v.len())
-                .unwrap_or(0);
+            let messages = parse_messages(&messages_json).unwrap_or_default();
+            let attachment_count = messages
+                .iter()
+                .map(|m| m.images.as_ref().map_or(0, |images| images.len()))
+                .sum();
 
             Ok(ChatSummary {
                 id: row.get("id")?,
                 title: row.get("title")?,
                 created_at: row.get("created_at")?,
                 updated_at: row.get("updated_at")?,
-                message_count,
+                message_count: messages.len(),
+                model_id: row.get("model_id")?,
+                attachment_count,
             })
         })?;
 
@@ -197,3 +237,58 @@ impl ChatPersistenceManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_attachments::FileAttachment;
+
+    fn message(role: &str, content: &str, images: Option<Vec<&str>>) -> ChatMessage {
+        ChatMessage {
+            role: role.to_string(),
+            content: content.to_string(),
+            images: images.map(|i| i.into_iter().map(String::from).collect()),
+            files: None,
+        }
+    }
+
+    #[test]
+    fn test_carry_over_attachments() {
+        let stored = vec![
+            message("system", "prompt", None),
+            message("user", "what's this?", Some(vec!["aGVsbG8="])),
+            message("assistant", "a greeting", None),
+        ];
+        let mut messages = vec![
+            message("system", "prompt", None),
+            message("user", "what's this?", None),
+            message("assistant", "a greeting", None),
+            message("user", "and this?", Some(vec!["d29ybGQ="])),
+        ];
+        carry_over_attachments(&stored, &mut messages);
+        assert_eq!(messages[1].images, Some(vec!["aGVsbG8=".to_string()]));
+        assert_eq!(messages[3].images, Some(vec!["d29ybGQ=".to_string()]));
+
+        // An edited message doesn't inherit the old one's images
+        let mut edited = vec![
+            message("system", "prompt", None),
+            message("user", "what's that?", None),
+        ];
+        carry_over_attachments(&stored, &mut edited);
+        assert_eq!(edited[1].images, None);
+
+        let file = FileAttachment {
+            name: "notes.txt".to_string(),
+            chunks: vec!["Buy milk".to_string()],
+            truncated: false,
+        };
+        let mut stored = vec![message("user", "summarize", None)];
+        stored[0].files = Some(vec![file.clone()]);
+        let mut messages = vec![
+            message("user", "summarize", None),
+            message("assistant", "Buy milk", None),
+        ];
+        carry_over_attachments(&stored, &mut messages);
+        assert_eq!(messages[0].files, Some(vec![file]));
+    }
+}
@@ -0,0 +1,83 @@
+//! Local sentence embeddings for finding history entries by meaning.
+//!
+//! Uses fastembed's ONNX build of multilingual-e5-small, which handles the languages
+//! Ramble transcribes. The model is downloaded to the app's models folder on first
+//! use and kept loaded afterwards.
+
+use anyhow::Result;
+use fastembed::{EmbeddingModel, TextEmbedding, TextInitOptions};
+use log::info;
+use once_cell::sync::OnceCell;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// Texts embedded per inference batch
+const BATCH_SIZE: usize = 32;
+
+/// e5 models are trained with these prefixes to tell questions from documents
+const QUERY_PREFIX: &str = "query: ";
+const PASSAGE_PREFIX: &str = "passage: ";
+
+pub struct EmbeddingManager {
+    cache_dir: PathBuf,
+    /// Loaded on first use. A load that fails or panics leaves it empty for the next
+    /// call to retry, and callers arriving meanwhile wait for the load instead of
+    /// starting another download.
+    model: OnceCell<Mutex<TextEmbedding>>,
+}
+
+impl EmbeddingManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let cache_dir = app_handle
+            .path()
+            .app_data_dir()?
+            .join("models")
+            .join("embeddings");
+        if !cache_dir.exists() {
+            fs::create_dir_all(&cache_dir)?;
+        }
+        Ok(Self {
+            cache_dir,
+            model: OnceCell::new(),
+        })
+    }
+
+    /// Embed history texts for the index. Blocks while the model runs, and on first
+    /// use while it downloads.
+    pub fn embed_passages(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, String> {
+        self.embed(
+            texts
+                .iter()
+                .map(|t| format!("{}{}", PASSAGE_PREFIX, t))
+                .collect(),
+        )
+    }
+
+    /// Embed a search query
+    pub fn embed_query(&self, query: &str) -> Result<Vec<f32>, String> {
+        self.embed(vec![format!("{}{}", QUERY_PREFIX, query)])?
+            .pop()
+            .ok_or_else(|| "The embedding model returned nothing".to_string())
+    }
+
+    fn load(&self) -> Result<Mutex<TextEmbedding>, String> {
+        info!("Loading embedding model into {:?}", self.cache_dir);
+        let options = TextInitOptions::new(EmbeddingModel::MultilingualE5Small)
+            .with_cache_dir(self.cache_dir.clone())
+            .with_show_download_progress(false);
+        TextEmbedding::try_new(options)
+            .map(Mutex::new)
+            .map_err(|e| format!("Failed to load the embedding model: {}", e))
+    }
+
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, String> {
+        let model = self.model.get_or_try_init(|| self.load())?;
+        model
+            .lock()
+            .map_err(|_| "The embedding model crashed, restart Ramble".to_string())?
+            .embed(texts, Some(BATCH_SIZE))
+            .map_err(|e| format!("Failed to embed text: {}", e))
+    }
+}
@@ -10,6 +10,12 @@ use std::path::PathBuf;
 use tauri::{AppHandle, Emitter, Manager};
 
 use crate::audio_toolkit::save_wav_file;
+use crate::edits::EditAlignment;
+use crate::managers::stt_engine::WordTiming;
+use crate::semantic_search;
+use crate::summaries::EntrySummary;
+use crate::text_stats::TextStats;
+use crate::titles;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -36,6 +42,18 @@ static MIGRATIONS: &[M] = &[
         "ALTER TABLE transcription_history ADD COLUMN transcription_status TEXT DEFAULT 'success';",
     ),
     M::up("ALTER TABLE transcription_history ADD COLUMN transcription_error TEXT;"),
+    // Migration 6: Per-word timings (JSON array) for highlighting during playback
+    M::up("ALTER TABLE transcription_history ADD COLUMN word_timings TEXT;"),
+    // Migration 7: Raw-to-refined alignment (JSON) for the show-edits view
+    M::up("ALTER TABLE transcription_history ADD COLUMN edit_alignment TEXT;"),
+    // Migration 8: Names and numbers the refiner added (JSON array), for a warning
+    M::up("ALTER TABLE transcription_history ADD COLUMN invented_terms TEXT;"),
+    // Migration 9: Summary (JSON) of a long entry, written on request
+    M::up("ALTER TABLE transcription_history ADD COLUMN summary TEXT;"),
+    // Migration 10: Sentence embedding (little-endian f32s) for semantic search
+    M::up("ALTER TABLE transcription_history ADD COLUMN embedding BLOB;"),
+    // Migration 11: Short title describing what was said, generated after transcription
+    M::up("ALTER TABLE transcription_history ADD COLUMN generated_title TEXT;"),
 ];
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -45,11 +63,35 @@ pub struct HistoryEntry {
     pub timestamp: i64,
     pub saved: bool,
     pub title: String,
+    /// Short description of what was said, generated after transcription
+    pub generated_title: Option<String>,
     pub transcription_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
     pub transcription_status: String,
     pub transcription_error: Option<String>,
+    /// Computed from the texts when the entry is loaded
+    pub stats: TextStats,
+    /// Names and numbers in the refinement that weren't in the transcription
+    pub invented_terms: Vec<String>,
+    /// Summary of the transcription, if one was asked for
+    pub summary: Option<EntrySummary>,
+}
+
+/// Emitted as `transcription-stats` whenever an entry's transcription is stored
+#[derive(Clone, Debug, Serialize)]
+pub struct TranscriptionStatsEvent {
+    pub history_id: i64,
+    pub stats: TextStats,
+}
+
+fn parse_invented_terms(json: Option<String>) -> Vec<String> {
+    json.and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn parse_summary(json: Option<String>) -> Option<EntrySummary> {
+    json.and_then(|json| serde_json::from_str(&json).ok())
 }
 
 pub struct HistoryManager {
@@ -219,14 +261,32 @@ impl HistoryManager {
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
     ) -> Result<()> {
+        let stats = TextStats::compute(&transcription_text, post_processed_text.as_deref());
         let conn = self.get_connection()?;
         conn.execute(
-            "UPDATE transcription_history SET transcription_text = ?1, post_processed_text = ?2, post_process_prompt = ?3, transcription_status = 'success', transcription_error = NULL WHERE id = ?4",
+            "UPDATE transcription_history SET transcription_text = ?1, post_processed_text = ?2, post_process_prompt = ?3, transcription_status = 'success', transcription_error = NULL, embedding = NULL, generated_title = NULL WHERE id = ?4",
             params![transcription_text, post_processed_text, post_process_prompt, id],
         )?;
 
         debug!("Updated transcription for entry {}", id);
 
+        let text = post_processed_text
+            .filter(|text| !text.trim().is_empty())
+            .unwrap_or(transcription_text);
+        if !text.trim().is_empty() {
+            titles::spawn_for_entry(self.app_handle.clone(), id, text);
+        }
+
+        if let Err(e) = self.app_handle.emit(
+            "transcription-stats",
+            TranscriptionStatsEvent {
+                history_id: id,
+                stats,
+            },
+        ) {
+            error!("Failed to emit transcription-stats event: {}", e);
+        }
+
         // Clean up old entries
         self.cleanup_old_entries()?;
 
@@ -238,6 +298,105 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Store the word timings of an entry's transcription
+    pub async fn save_word_timings(&self, id: i64, words: &[WordTiming]) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET word_timings = ?1 WHERE id = ?2",
+            params![serde_json::to_string(words)?, id],
+        )?;
+        debug!("Saved {} word timings for entry {}", words.len(), id);
+        Ok(())
+    }
+
+    /// Word timings of an entry, empty if none were recorded
+    pub async fn get_word_timings(&self, id: i64) -> Result<Vec<WordTiming>> {
+        let conn = self.get_connection()?;
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT word_timings FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        match json {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Store how an entry's refinement differs from its raw transcription
+    pub async fn save_edit_alignment(&self, id: i64, alignment: &EditAlignment) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET edit_alignment = ?1 WHERE id = ?2",
+            params![serde_json::to_string(alignment)?, id],
+        )?;
+        debug!("Saved edit alignment for entry {}", id);
+        Ok(())
+    }
+
+    /// Edit alignment of an entry, None if it wasn't refined in show-edits mode
+    pub async fn get_edit_alignment(&self, id: i64) -> Result<Option<EditAlignment>> {
+        let conn = self.get_connection()?;
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT edit_alignment FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        match json {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Flag an entry whose refinement added names or numbers not in its transcription
+    pub async fn flag_invented_terms(&self, id: i64, terms: &[String]) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET invented_terms = ?1 WHERE id = ?2",
+            params![serde_json::to_string(terms)?, id],
+        )?;
+        debug!("Flagged {} invented terms for entry {}", terms.len(), id);
+
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+        Ok(())
+    }
+
+    pub async fn save_generated_title(&self, id: i64, title: &str) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET generated_title = ?1 WHERE id = ?2",
+            params![title, id],
+        )?;
+        debug!("Titled entry {}: {}", id, title);
+
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+        Ok(())
+    }
+
+    pub async fn save_summary(&self, id: i64, summary: &EntrySummary) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET summary = ?1 WHERE id = ?2",
+            params![serde_json::to_string(summary)?, id],
+        )?;
+        debug!("Saved {:?} summary for entry {}", summary.style, id);
+
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+        Ok(())
+    }
+
     /// Update an existing entry with transcription error.
     pub async fn update_transcription_error(&self, id: i64, error_message: String) -> Result<()> {
         let conn = self.get_connection()?;
@@ -382,23 +541,30 @@ impl HistoryManager {
     pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, transcription_status, transcription_error FROM transcription_history ORDER BY timestamp DESC"
+            "SELECT id, file_name, timestamp, saved, title, generated_title, transcription_text, post_processed_text, post_process_prompt, transcription_status, transcription_error, invented_terms, summary FROM transcription_history ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
+            let transcription_text: String = row.get("transcription_text")?;
+            let post_processed_text: Option<String> = row.get("post_processed_text")?;
+            let stats = TextStats::compute(&transcription_text, post_processed_text.as_deref());
             Ok(HistoryEntry {
                 id: row.get("id")?,
                 file_name: row.get("file_name")?,
                 timestamp: row.get("timestamp")?,
                 saved: row.get("saved")?,
                 title: row.get("title")?,
-                transcription_text: row.get("transcription_text")?,
-                post_processed_text: row.get("post_processed_text")?,
+                generated_title: row.get("generated_title")?,
+                transcription_text,
+                post_processed_text,
                 post_process_prompt: row.get("post_process_prompt")?,
                 transcription_status: row
                     .get::<_, Option<String>>("transcription_status")?
                     .unwrap_or_else(|| "success".to_string()),
                 transcription_error: row.get("transcription_error")?,
+                stats,
+                invented_terms: parse_invented_terms(row.get("invented_terms")?),
+                summary: parse_summary(row.get("summary")?),
             })
         })?;
 
@@ -444,25 +610,32 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, transcription_status, transcription_error
+            "SELECT id, file_name, timestamp, saved, title, generated_title, transcription_text, post_processed_text, post_process_prompt, transcription_status, transcription_error, invented_terms, summary
              FROM transcription_history WHERE id = ?1",
         )?;
 
         let entry = stmt
             .query_row([id], |row| {
+                let transcription_text: String = row.get("transcription_text")?;
+                let post_processed_text: Option<String> = row.get("post_processed_text")?;
+                let stats = TextStats::compute(&transcription_text, post_processed_text.as_deref());
                 Ok(HistoryEntry {
                     id: row.get("id")?,
                     file_name: row.get("file_name")?,
                     timestamp: row.get("timestamp")?,
                     saved: row.get("saved")?,
                     title: row.get("title")?,
-                    transcription_text: row.get("transcription_text")?,
-                    post_processed_text: row.get("post_processed_text")?,
+                    generated_title: row.get("generated_title")?,
+                    transcription_text,
+                    post_processed_text,
                     post_process_prompt: row.get("post_process_prompt")?,
                     transcription_status: row
                         .get::<_, Option<String>>("transcription_status")?
                         .unwrap_or_else(|| "success".to_string()),
                     transcription_error: row.get("transcription_error")?,
+                    stats,
+                    invented_terms: parse_invented_terms(row.get("invented_terms")?),
+                    summary: parse_summary(row.get("summary")?),
                 })
             })
             .optional()?;
@@ -524,6 +697,77 @@ impl HistoryManager {
         .ok()
     }
 
+    /// Up to `limit` successful entries not yet embedded for semantic search, with the
+    /// text to embed (the post-processed text when there is one)
+    pub fn get_unembedded_texts(&self, limit: usize) -> Result<Vec<(i64, String)>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, transcription_text, post_processed_text FROM transcription_history
+             WHERE transcription_status = 'success' AND transcription_text != ''
+               AND embedding IS NULL
+             ORDER BY timestamp DESC LIMIT ?1",
+        )?;
+        let texts = stmt
+            .query_map(params![limit as i64], |row| {
+                let transcription_text: String = row.get("transcription_text")?;
+                let post_processed_text: Option<String> = row.get("post_processed_text")?;
+                Ok((
+                    row.get("id")?,
+                    post_processed_text
+                        .filter(|s| !s.is_empty())
+                        .unwrap_or(transcription_text),
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(texts)
+    }
+
+    pub fn save_embedding(&self, id: i64, embedding: &[f32]) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET embedding = ?1 WHERE id = ?2",
+            params![semantic_search::encode_embedding(embedding), id],
+        )?;
+        Ok(())
+    }
+
+    /// Every stored embedding with its entry id
+    pub fn get_embeddings(&self) -> Result<Vec<(i64, Vec<f32>)>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, embedding FROM transcription_history WHERE embedding IS NOT NULL",
+        )?;
+        let embeddings = stmt
+            .query_map([], |row| {
+                let bytes: Vec<u8> = row.get("embedding")?;
+                Ok((row.get("id")?, semantic_search::decode_embedding(&bytes)))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(embeddings)
+    }
+
+    /// Texts of successful transcriptions since the unix timestamp `since`, newest
+    /// first, preferring the post-processed text
+    pub fn get_texts_since(&self, since: i64, limit: usize) -> Result<Vec<String>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT transcription_text, post_processed_text FROM transcription_history
+             WHERE transcription_status = 'success' AND transcription_text != ''
+               AND timestamp >= ?1
+             ORDER BY timestamp DESC LIMIT ?2",
+        )?;
+        let texts = stmt
+            .query_map(params![since, limit as i64], |row| {
+                let transcription_text: String = row.get("transcription_text")?;
+                let post_processed_text: Option<String> = row.get("post_processed_text")?;
+                Ok(post_processed_text
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or(transcription_text))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(texts)
+    }
+
     fn format_timestamp_title(&self, timestamp: i64) -> String {
         if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
             // Convert UTC to local timezone
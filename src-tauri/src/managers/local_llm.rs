@@ -0,0 +1,283 @@
+//! Refinement with a local GGUF model, without any provider or API key.
+//!
+//! The model runs in llama.cpp's `llama-server`, a separate process, because linking
+//! llama.cpp into the app would bring a second copy of ggml that clashes with the one
+//! whisper.cpp links. Release builds bundle the server next to the app's executable
+//! (see `tauri.llama-server.conf.json`); other builds use a `llama-server` on the PATH.
+//! The server is started with the chosen model on first use and kept running until a
+//! different file is chosen, the local model is turned off or the app quits.
+
+use async_openai::config::OpenAIConfig;
+use async_openai::types::{
+    ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestUserMessageArgs,
+    CreateChatCompletionRequestArgs,
+};
+use async_openai::Client;
+use log::{debug, info};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::process::{Child, Command};
+
+/// Context window for refinement prompts; long dictations go to a provider instead
+const CONTEXT_TOKENS: u32 = 4096;
+
+/// Upper bound on generated tokens
+const MAX_OUTPUT_TOKENS: u32 = 1024;
+
+/// How long loading a model may take before giving up on the server
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+
+const SERVER_FILE_NAME: &str = if cfg!(windows) {
+    "llama-server.exe"
+} else {
+    "llama-server"
+};
+
+/// Path of the `llama-server` to run: the one bundled with the app, or else one on
+/// the PATH
+pub fn server_binary() -> Option<PathBuf> {
+    let bundled = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(SERVER_FILE_NAME)));
+    bundled.filter(|path| path.is_file()).or_else(|| {
+        let paths = std::env::var_os("PATH")?;
+        std::env::split_paths(&paths)
+            .map(|dir| dir.join(SERVER_FILE_NAME))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Whether a local model can be run at all
+pub fn is_available() -> bool {
+    server_binary().is_some()
+}
+
+/// Command-line arguments that serve `model_path` on `port`. Greedy sampling, since
+/// refinement wants the most likely rewrite rather than variety, and any reasoning
+/// kept out of the answer.
+fn server_args(model_path: &str, port: u16) -> Vec<String> {
+    [
+        "--model",
+        model_path,
+        "--host",
+        "127.0.0.1",
+        "--port",
+        &port.to_string(),
+        "--ctx-size",
+        &CONTEXT_TOKENS.to_string(),
+        "--n-predict",
+        &MAX_OUTPUT_TOKENS.to_string(),
+        "--temp",
+        "0",
+        "--jinja",
+        "--reasoning-format",
+        "deepseek",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+/// A port nothing is listening on right now
+fn free_port() -> Result<u16, String> {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| format!("No free port for the local model: {}", e))
+}
+
+struct Server {
+    model_path: String,
+    port: u16,
+    /// Killed when dropped
+    process: Child,
+}
+
+fn start_server(model_path: &str) -> Result<Server, String> {
+    let binary = server_binary()
+        .ok_or_else(|| "llama-server wasn't found, so the local model can't be run".to_string())?;
+    if !Path::new(model_path).is_file() {
+        return Err(format!("Local model file not found: {}", model_path));
+    }
+
+    let port = free_port()?;
+    info!(
+        "Starting {} for local model {} on port {}",
+        binary.display(),
+        model_path,
+        port
+    );
+    let mut command = Command::new(&binary);
+    command
+        .args(server_args(model_path, port))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+    #[cfg(windows)]
+    {
+        // CREATE_NO_WINDOW - don't open a console window for the server
+        command.creation_flags(0x0800_0000);
+    }
+    let process = command
+        .spawn()
+        .map_err(|e| format!("Failed to start llama-server: {}", e))?;
+
+    Ok(Server {
+        model_path: model_path.to_string(),
+        port,
+        process,
+    })
+}
+
+#[derive(Default)]
+pub struct LocalLlmManager {
+    server: Mutex<Option<Server>>,
+}
+
+impl LocalLlmManager {
+    /// Stop the server, freeing the model's memory
+    pub fn unload(&self) {
+        if let Ok(mut server) = self.server.lock() {
+            if server.take().is_some() {
+                info!("Stopped the local model server");
+            }
+        }
+    }
+
+    /// Port of a server running `model_path`, starting one (and stopping a server
+    /// running another model) if needed
+    fn server_port(&self, model_path: &str) -> Result<u16, String> {
+        let mut server = self
+            .server
+            .lock()
+            .map_err(|_| "Local model state is unavailable".to_string())?;
+        let running = server.as_mut().is_some_and(|s| {
+            s.model_path == model_path && matches!(s.process.try_wait(), Ok(None))
+        });
+        if !running {
+            // Stop the old server first, so two models are never loaded at once
+            *server = None;
+            *server = Some(start_server(model_path)?);
+        }
+        Ok(server.as_ref().map(|s| s.port).unwrap_or_default())
+    }
+
+    /// Stop the server on `port`, unless another one has replaced it since
+    fn stop_server_on(&self, port: u16) {
+        if let Ok(mut server) = self.server.lock() {
+            if server.as_ref().is_some_and(|s| s.port == port) {
+                *server = None;
+            }
+        }
+    }
+
+    /// Whether the server for `port` has exited, e.g. because it couldn't load the model
+    fn server_exited(&self, port: u16) -> bool {
+        match self.server.lock() {
+            Ok(mut server) => server
+                .as_mut()
+                .filter(|s| s.port == port)
+                .is_none_or(|s| !matches!(s.process.try_wait(), Ok(None))),
+            Err(_) => true,
+        }
+    }
+
+    /// Wait for the server on `port` to finish loading its model
+    async fn wait_until_ready(&self, port: u16) -> Result<(), String> {
+        let url = format!("http://127.0.0.1:{}/health", port);
+        let client = reqwest::Client::new();
+        let started = Instant::now();
+        loop {
+            // The server answers 503 while the model is still loading
+            if let Ok(response) = client.get(&url).send().await {
+                if response.status().is_success() {
+                    return Ok(());
+                }
+            }
+            if self.server_exited(port) {
+                self.stop_server_on(port);
+                return Err("llama-server stopped while loading the local model".to_string());
+            }
+            if started.elapsed() > STARTUP_TIMEOUT {
+                self.stop_server_on(port);
+                return Err("The local model took too long to load".to_string());
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+    }
+
+    /// Generate a reply to `prompt` with the model at `model_path`, calling `on_delta`
+    /// with each piece of text as it's produced
+    pub async fn generate(
+        &self,
+        model_path: &str,
+        system_prompt: &str,
+        prompt: &str,
+        on_delta: impl FnMut(&str),
+    ) -> Result<String, String> {
+        let port = self.server_port(model_path)?;
+        self.wait_until_ready(port).await?;
+
+        let config = OpenAIConfig::new()
+            .with_api_base(format!("http://127.0.0.1:{}/v1", port))
+            .with_api_key("");
+        let client = Client::with_config(config);
+        let request = CreateChatCompletionRequestArgs::default()
+            // The server answers with whichever model it was started with
+            .model("local")
+            .messages(vec![
+                ChatCompletionRequestSystemMessageArgs::default()
+                    .content(system_prompt)
+                    .build()
+                    .map_err(|e| format!("Invalid prompt: {}", e))?
+                    .into(),
+                ChatCompletionRequestUserMessageArgs::default()
+                    .content(prompt)
+                    .build()
+                    .map_err(|e| format!("Invalid prompt: {}", e))?
+                    .into(),
+            ])
+            .build()
+            .map_err(|e| format!("Invalid prompt: {}", e))?;
+
+        let (text, _) = crate::llm_client::stream_chat_completion(&client, request, on_delta)
+            .await
+            .map_err(|e| format!("Local model failed: {}", e))?;
+        debug!("Local model wrote {} characters", text.len());
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_args() {
+        let args = server_args("/models/qwen.gguf", 8123);
+        let value = |flag: &str| {
+            let index = args.iter().position(|a| a == flag).unwrap();
+            args[index + 1].as_str()
+        };
+        assert_eq!(value("--model"), "/models/qwen.gguf");
+        assert_eq!(value("--host"), "127.0.0.1");
+        assert_eq!(value("--port"), "8123");
+        assert_eq!(value("--ctx-size"), "4096");
+    }
+
+    #[test]
+    fn test_missing_model_file() {
+        let manager = LocalLlmManager::default();
+        let result = tauri::async_runtime::block_on(manager.generate(
+            "/nonexistent/model.gguf",
+            "system",
+            "prompt",
+            |_| {},
+        ));
+        assert!(result.is_err());
+        assert!(manager.server.lock().unwrap().is_none());
+    }
+}
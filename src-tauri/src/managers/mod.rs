@@ -1,6 +1,11 @@
 pub mod audio;
+pub mod captures;
 pub mod chat_persistence;
+pub mod embedding;
 pub mod history;
+pub mod local_llm;
 pub mod model;
+pub mod stt_engine;
 pub mod transcription;
 pub mod tts;
+pub mod usage;
@@ -1,3 +1,4 @@
+use crate::managers::stt_engine::stt_api_key;
 use crate::settings::{get_settings, write_settings};
 use anyhow::Result;
 use flate2::read::GzDecoder;
@@ -19,6 +20,21 @@ pub enum EngineType {
     Whisper,
     Parakeet,
     TTS,
+    /// OpenAI's hosted transcription API
+    OpenAiCloud,
+    /// Deepgram's hosted transcription API
+    DeepgramCloud,
+}
+
+impl EngineType {
+    /// Service whose API key a cloud engine needs, or None for local engines
+    pub fn cloud_service(&self) -> Option<&'static str> {
+        match self {
+            EngineType::OpenAiCloud => Some("openai"),
+            EngineType::DeepgramCloud => Some("deepgram"),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -207,6 +223,45 @@ impl ModelManager {
             },
         );
 
+        // Cloud transcription (no download, available once an API key is set)
+        available_models.insert(
+            "openai-whisper-api".to_string(),
+            ModelInfo {
+                id: "openai-whisper-api".to_string(),
+                name: "OpenAI Whisper API".to_string(),
+                description: "Cloud transcription. Requires an OpenAI API key.".to_string(),
+                filename: String::new(),
+                url: None,
+                size_mb: 0,
+                is_downloaded: false,
+                is_downloading: false,
+                partial_size: 0,
+                is_directory: false,
+                engine_type: EngineType::OpenAiCloud,
+                accuracy_score: 0.85,
+                speed_score: 0.75,
+            },
+        );
+
+        available_models.insert(
+            "deepgram-nova-3".to_string(),
+            ModelInfo {
+                id: "deepgram-nova-3".to_string(),
+                name: "Deepgram Nova-3".to_string(),
+                description: "Cloud transcription. Requires a Deepgram API key.".to_string(),
+                filename: String::new(),
+                url: None,
+                size_mb: 0,
+                is_downloaded: false,
+                is_downloading: false,
+                partial_size: 0,
+                is_directory: false,
+                engine_type: EngineType::DeepgramCloud,
+                accuracy_score: 0.85,
+                speed_score: 0.90,
+            },
+        );
+
         let manager = Self {
             app_handle: app_handle.clone(),
             models_dir,
@@ -262,11 +317,17 @@ impl ModelManager {
         Ok(())
     }
 
-    fn update_download_status(&self) -> Result<()> {
+    /// Re-check which models are available, e.g. after a cloud API key changed
+    pub fn update_download_status(&self) -> Result<()> {
+        let settings = get_settings(&self.app_handle);
         let mut models = self.available_models.lock().unwrap();
 
         for model in models.values_mut() {
-            if model.is_directory {
+            if let Some(service) = model.engine_type.cloud_service() {
+                model.is_downloaded = stt_api_key(&settings, service).is_some();
+                model.is_downloading = false;
+                model.partial_size = 0;
+            } else if model.is_directory {
                 // For directory-based models, check if the directory exists
                 let model_path = self.models_dir.join(&model.filename);
                 let partial_path = self.models_dir.join(format!("{}.partial", &model.filename));
@@ -344,6 +405,13 @@ impl ModelManager {
         let model_info =
             model_info.ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
 
+        if model_info.engine_type.cloud_service().is_some() {
+            return Err(anyhow::anyhow!(
+                "{} is a cloud model and needs an API key instead of a download",
+                model_info.name
+            ));
+        }
+
         let url = model_info
             .url
             .ok_or_else(|| anyhow::anyhow!("No download URL for model"))?;
@@ -624,6 +692,10 @@ impl ModelManager {
 
         debug!("ModelManager: Found model info: {:?}", model_info);
 
+        if model_info.engine_type.cloud_service().is_some() {
+            return Err(anyhow::anyhow!("Cloud models have no files to delete"));
+        }
+
         let model_path = self.models_dir.join(&model_info.filename);
         let partial_path = self
             .models_dir
@@ -0,0 +1,636 @@
+//! Speech-to-text backends behind a common `SttEngine` trait.
+//!
+//! Local models (Whisper, Parakeet) run in-process through transcribe-rs. Cloud backends
+//! (OpenAI's transcription API, Deepgram) upload the recording as WAV and need an API key,
+//! but no download, which makes them a better fit for machines too slow for local models.
+
+use crate::managers::model::EngineType;
+use crate::settings::{AppSettings, AuthMethod};
+use anyhow::Result;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::Cursor;
+use std::time::Duration;
+use transcribe_rs::{
+    engines::{
+        parakeet::{ParakeetEngine, ParakeetInferenceParams, TimestampGranularity},
+        whisper::{WhisperEngine, WhisperInferenceParams},
+    },
+    TranscriptionEngine, TranscriptionSegment,
+};
+
+/// Sample rate of the audio handed to every engine
+const SAMPLE_RATE: u32 = 16000;
+
+/// Upper bound for a single cloud transcription request
+const CLOUD_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+const OPENAI_TRANSCRIPTION_URL: &str = "https://api.openai.com/v1/audio";
+const DEEPGRAM_LISTEN_URL: &str = "https://api.deepgram.com/v1/listen";
+
+/// Cloud services that can be given an STT API key
+pub const STT_SERVICES: &[&str] = &["openai", "deepgram"];
+
+/// Per-request options derived from the user's settings
+#[derive(Debug, Clone, Default)]
+pub struct SttOptions {
+    /// ISO 639-1 language code, or None to auto-detect
+    pub language: Option<String>,
+    /// Translate the speech to English instead of transcribing it
+    pub translate: bool,
+    /// The audio may mix languages, so never force a single one
+    pub code_switching: bool,
+}
+
+/// Whisper and the cloud APIs use ISO 639-1 codes, so drop the script suffix
+fn iso_language(code: &str) -> Option<String> {
+    match code {
+        "auto" => None,
+        "zh-Hans" | "zh-Hant" => Some("zh".to_string()),
+        other => Some(other.to_string()),
+    }
+}
+
+impl SttOptions {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            language: if settings.code_switching {
+                None
+            } else {
+                iso_language(&settings.selected_language)
+            },
+            translate: settings.translate_to_english,
+            code_switching: settings.code_switching,
+        }
+    }
+
+    /// Use `language` instead of the global one, e.g. from the active prompt category.
+    /// Ignored in code-switching mode, where each phrase is detected on its own.
+    pub fn with_language_override(mut self, language: Option<&str>) -> Self {
+        if self.code_switching {
+            return self;
+        }
+        if let Some(language) = language {
+            self.language = iso_language(language);
+        }
+        self
+    }
+}
+
+/// When a recognised word was spoken, in seconds from the start of the audio
+#[derive(Serialize, Deserialize, Type, Debug, Clone, PartialEq)]
+pub struct WordTiming {
+    pub word: String,
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Text recognised by an engine, with per-word timings when the engine provides them
+#[derive(Debug, Clone, Default)]
+pub struct Transcript {
+    pub text: String,
+    pub words: Vec<WordTiming>,
+}
+
+impl Transcript {
+    /// Shift all word timings later by `samples`, for audio cut from a longer recording
+    pub fn offset_by(mut self, samples: usize) -> Self {
+        let seconds = samples as f32 / SAMPLE_RATE as f32;
+        for word in &mut self.words {
+            word.start += seconds;
+            word.end += seconds;
+        }
+        self
+    }
+}
+
+/// Split timed `(start, end, text)` segments into words. Engines that only time whole
+/// segments get each word's time interpolated by its share of the segment's characters.
+fn words_from_segments<'a>(
+    segments: impl IntoIterator<Item = (f32, f32, &'a str)>,
+) -> Vec<WordTiming> {
+    let mut words = Vec::new();
+    for (start, end, text) in segments {
+        let parts: Vec<&str> = text.split_whitespace().collect();
+        let total_chars: usize = parts.iter().map(|w| w.chars().count()).sum();
+        if total_chars == 0 {
+            continue;
+        }
+        let duration = (end - start).max(0.0);
+        let mut elapsed = 0;
+        for part in parts {
+            let chars = part.chars().count();
+            words.push(WordTiming {
+                word: part.to_string(),
+                start: start + duration * elapsed as f32 / total_chars as f32,
+                end: start + duration * (elapsed + chars) as f32 / total_chars as f32,
+            });
+            elapsed += chars;
+        }
+    }
+    words
+}
+
+fn engine_word_timings(segments: Option<&[TranscriptionSegment]>) -> Vec<WordTiming> {
+    words_from_segments(
+        segments
+            .unwrap_or_default()
+            .iter()
+            .map(|s| (s.start, s.end, s.text.as_str())),
+    )
+}
+
+/// A loaded speech-to-text backend
+pub trait SttEngine: Send {
+    /// Transcribe 16kHz mono samples
+    fn transcribe(&mut self, audio: Vec<f32>, options: &SttOptions) -> Result<Transcript>;
+
+    /// Whether it's worth decoding the in-progress audio for a live preview, which
+    /// happens every ~1.5s while recording
+    fn supports_partials(&self) -> bool {
+        true
+    }
+
+    /// Release any resources held by the engine before it is dropped
+    fn unload(&mut self) {}
+}
+
+impl SttEngine for WhisperEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, options: &SttOptions) -> Result<Transcript> {
+        let params = WhisperInferenceParams {
+            language: options.language.clone(),
+            translate: options.translate,
+            ..Default::default()
+        };
+
+        let result = self
+            .transcribe_samples(audio, Some(params))
+            .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?;
+        Ok(Transcript {
+            words: engine_word_timings(result.segments.as_deref()),
+            text: result.text,
+        })
+    }
+
+    fn unload(&mut self) {
+        self.unload_model();
+    }
+}
+
+impl SttEngine for ParakeetEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, _options: &SttOptions) -> Result<Transcript> {
+        let params = ParakeetInferenceParams {
+            timestamp_granularity: TimestampGranularity::Word,
+            ..Default::default()
+        };
+
+        let result = self
+            .transcribe_samples(audio, Some(params))
+            .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?;
+        Ok(Transcript {
+            words: engine_word_timings(result.segments.as_deref()),
+            text: result.text,
+        })
+    }
+
+    fn unload(&mut self) {
+        self.unload_model();
+    }
+}
+
+/// Find the API key for a cloud STT service. OpenAI falls back to the key of the
+/// OpenAI LLM provider so users don't have to enter it twice.
+pub fn stt_api_key(settings: &AppSettings, service: &str) -> Option<String> {
+    if let Some(key) = settings
+        .stt_api_keys
+        .get(service)
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty())
+    {
+        return Some(key.to_string());
+    }
+
+    if service == "openai" {
+        return settings
+            .llm_providers
+            .iter()
+            .find(|p| p.id == "openai" && p.auth_method == AuthMethod::ApiKey)
+            .map(|p| p.api_key.trim().to_string())
+            .filter(|k| !k.is_empty());
+    }
+
+    None
+}
+
+/// Create the engine for a cloud model using the API key from settings
+pub fn create_cloud_engine(
+    engine_type: &EngineType,
+    settings: &AppSettings,
+) -> Result<Box<dyn SttEngine>> {
+    let service = engine_type
+        .cloud_service()
+        .ok_or_else(|| anyhow::anyhow!("{:?} is not a cloud engine", engine_type))?;
+    let api_key = stt_api_key(settings, service)
+        .ok_or_else(|| anyhow::anyhow!("No API key set for {}", service))?;
+
+    Ok(match engine_type {
+        EngineType::DeepgramCloud => Box::new(DeepgramSttEngine::new(api_key)),
+        _ => Box::new(OpenAiSttEngine::new(api_key)),
+    })
+}
+
+/// OpenAI's hosted Whisper (`/audio/transcriptions` and `/audio/translations`)
+pub struct OpenAiSttEngine {
+    api_key: String,
+    model: String,
+}
+
+impl OpenAiSttEngine {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            model: "whisper-1".to_string(),
+        }
+    }
+}
+
+impl SttEngine for OpenAiSttEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, options: &SttOptions) -> Result<Transcript> {
+        let wav = encode_wav(&audio)?;
+        let endpoint = if options.translate {
+            "translations"
+        } else {
+            "transcriptions"
+        };
+        let url = format!("{}/{}", OPENAI_TRANSCRIPTION_URL, endpoint);
+
+        let mut fields = vec![
+            ("model", self.model.clone()),
+            ("response_format", "verbose_json".to_string()),
+        ];
+        // The translations endpoint always outputs English and only has segment timings
+        if !options.translate {
+            fields.push(("timestamp_granularities[]", "word".to_string()));
+            if let Some(language) = &options.language {
+                fields.push(("language", language.clone()));
+            }
+        }
+        let boundary = format!("ramble-{}", chrono::Utc::now().timestamp_millis());
+        let body = multipart_body(&boundary, &fields, "audio.wav", &wav);
+
+        let api_key = self.api_key.clone();
+        let json = block_on_request(async move {
+            let response = http_client()?
+                .post(&url)
+                .bearer_auth(api_key)
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    format!("multipart/form-data; boundary={}", boundary),
+                )
+                .body(body)
+                .send()
+                .await?;
+            read_json(response, "OpenAI").await
+        })?;
+
+        parse_openai_response(&json)
+    }
+
+    /// Each partial would be a billed request holding the engine lock
+    fn supports_partials(&self) -> bool {
+        false
+    }
+}
+
+/// Deepgram's pre-recorded `/listen` endpoint
+pub struct DeepgramSttEngine {
+    api_key: String,
+    model: String,
+}
+
+impl DeepgramSttEngine {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            model: "nova-3".to_string(),
+        }
+    }
+}
+
+impl SttEngine for DeepgramSttEngine {
+    fn transcribe(&mut self, audio: Vec<f32>, options: &SttOptions) -> Result<Transcript> {
+        if options.translate {
+            warn!("Deepgram does not support translation, transcribing instead");
+        }
+        let wav = encode_wav(&audio)?;
+
+        let mut query = vec![
+            ("model", self.model.clone()),
+            ("smart_format", "true".to_string()),
+        ];
+        // Nova-3's "multi" language transcribes mixed-language speech in one pass
+        match &options.language {
+            _ if options.code_switching => query.push(("language", "multi".to_string())),
+            Some(language) => query.push(("language", language.clone())),
+            None => query.push(("detect_language", "true".to_string())),
+        }
+
+        let api_key = self.api_key.clone();
+        let json = block_on_request(async move {
+            let response = http_client()?
+                .post(DEEPGRAM_LISTEN_URL)
+                .query(&query)
+                .header(reqwest::header::AUTHORIZATION, format!("Token {}", api_key))
+                .header(reqwest::header::CONTENT_TYPE, "audio/wav")
+                .body(wav)
+                .send()
+                .await?;
+            read_json(response, "Deepgram").await
+        })?;
+
+        parse_deepgram_response(&json)
+    }
+
+    /// Billed per request, like OpenAI
+    fn supports_partials(&self) -> bool {
+        false
+    }
+}
+
+fn http_client() -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .timeout(CLOUD_REQUEST_TIMEOUT)
+        .build()?)
+}
+
+async fn read_json(response: reqwest::Response, service: &str) -> Result<serde_json::Value> {
+    let status = response.status();
+    let text = response.text().await?;
+    if !status.is_success() {
+        // Both APIs return {"error": {"message": ...}} or {"err_msg": ...} on failure
+        let message = serde_json::from_str::<serde_json::Value>(&text)
+            .ok()
+            .and_then(|v| {
+                v.pointer("/error/message")
+                    .or_else(|| v.get("err_msg"))
+                    .and_then(|m| m.as_str())
+                    .map(String::from)
+            })
+            .unwrap_or(text);
+        return Err(anyhow::anyhow!(
+            "{} transcription failed ({}): {}",
+            service,
+            status,
+            message
+        ));
+    }
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Run a request on the async runtime and wait for it. Transcription is synchronous,
+/// and callers may already be on a runtime thread, so the request can't be driven here.
+fn block_on_request<F>(future: F) -> Result<serde_json::Value>
+where
+    F: std::future::Future<Output = Result<serde_json::Value>> + Send + 'static,
+{
+    let (tx, rx) = std::sync::mpsc::channel();
+    tauri::async_runtime::spawn(async move {
+        let _ = tx.send(future.await);
+    });
+    rx.recv()
+        .map_err(|_| anyhow::anyhow!("Cloud transcription request was dropped"))?
+}
+
+/// Encode samples as 16-bit mono WAV in memory
+fn encode_wav(samples: &[f32]) -> Result<Vec<u8>> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut cursor = Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut cursor, spec)?;
+        for sample in samples {
+            let clamped = sample.clamp(-1.0, 1.0);
+            writer.write_sample((clamped * i16::MAX as f32) as i16)?;
+        }
+        writer.finalize()?;
+    }
+    debug!("Encoded {} samples as WAV for upload", samples.len());
+    Ok(cursor.into_inner())
+}
+
+/// Build a multipart/form-data body with text fields followed by one WAV file
+fn multipart_body(
+    boundary: &str,
+    fields: &[(&str, String)],
+    filename: &str,
+    file: &[u8],
+) -> Vec<u8> {
+    let mut body = Vec::with_capacity(file.len() + 512);
+    for (name, value) in fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                boundary, name, value
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: audio/wav\r\n\r\n",
+            boundary, filename
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(file);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+/// Read `[{<word_key>, start, end}]` word timings from a JSON array
+fn parse_json_words(words: Option<&serde_json::Value>, word_keys: &[&str]) -> Vec<WordTiming> {
+    words
+        .and_then(|w| w.as_array())
+        .map(|words| {
+            words
+                .iter()
+                .filter_map(|w| {
+                    let word = word_keys
+                        .iter()
+                        .find_map(|key| w.get(*key).and_then(|v| v.as_str()))?;
+                    Some(WordTiming {
+                        word: word.trim().to_string(),
+                        start: w.get("start")?.as_f64()? as f32,
+                        end: w.get("end")?.as_f64()? as f32,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_openai_response(json: &serde_json::Value) -> Result<Transcript> {
+    let text = json
+        .get("text")
+        .and_then(|t| t.as_str())
+        .map(|t| t.trim().to_string())
+        .ok_or_else(|| anyhow::anyhow!("OpenAI response did not contain any text"))?;
+
+    let mut words = parse_json_words(json.get("words"), &["word"]);
+    if words.is_empty() {
+        // Translations only come with segment timings
+        let segments = json.get("segments").and_then(|s| s.as_array());
+        words = words_from_segments(segments.into_iter().flatten().filter_map(|s| {
+            Some((
+                s.get("start")?.as_f64()? as f32,
+                s.get("end")?.as_f64()? as f32,
+                s.get("text")?.as_str()?,
+            ))
+        }));
+    }
+
+    Ok(Transcript { text, words })
+}
+
+fn parse_deepgram_response(json: &serde_json::Value) -> Result<Transcript> {
+    let alternative = json
+        .pointer("/results/channels/0/alternatives/0")
+        .ok_or_else(|| anyhow::anyhow!("Deepgram response did not contain a transcript"))?;
+    let text = alternative
+        .get("transcript")
+        .and_then(|t| t.as_str())
+        .map(|t| t.trim().to_string())
+        .ok_or_else(|| anyhow::anyhow!("Deepgram response did not contain a transcript"))?;
+
+    Ok(Transcript {
+        text,
+        words: parse_json_words(alternative.get("words"), &["punctuated_word", "word"]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_wav_header() {
+        let wav = encode_wav(&[0.0, 0.5, -0.5, 2.0]).unwrap();
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        // 44-byte header followed by four 16-bit samples
+        assert_eq!(wav.len(), 44 + 8);
+        // Out-of-range samples are clamped rather than wrapping around
+        assert_eq!(i16::from_le_bytes([wav[50], wav[51]]), i16::MAX);
+    }
+
+    #[test]
+    fn test_cloud_engines_skip_partials() {
+        assert!(!OpenAiSttEngine::new("key".to_string()).supports_partials());
+        assert!(!DeepgramSttEngine::new("key".to_string()).supports_partials());
+    }
+
+    #[test]
+    fn test_language_override() {
+        let options = SttOptions {
+            language: Some("en".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            options
+                .clone()
+                .with_language_override(None)
+                .language
+                .as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            options
+                .clone()
+                .with_language_override(Some("zh-Hant"))
+                .language
+                .as_deref(),
+            Some("zh")
+        );
+        assert_eq!(options.with_language_override(Some("auto")).language, None);
+
+        let code_switching = SttOptions {
+            code_switching: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            code_switching.with_language_override(Some("es")).language,
+            None
+        );
+    }
+
+    #[test]
+    fn test_multipart_body() {
+        let body = multipart_body(
+            "b",
+            &[("model", "whisper-1".to_string())],
+            "audio.wav",
+            b"DATA",
+        );
+        let text = String::from_utf8(body).unwrap();
+        assert!(text.starts_with(
+            "--b\r\nContent-Disposition: form-data; name=\"model\"\r\n\r\nwhisper-1\r\n"
+        ));
+        assert!(text.contains(
+            "name=\"file\"; filename=\"audio.wav\"\r\nContent-Type: audio/wav\r\n\r\nDATA"
+        ));
+        assert!(text.ends_with("\r\n--b--\r\n"));
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let openai = serde_json::json!({
+            "text": " Hello world. ",
+            "words": [
+                { "word": "Hello", "start": 0.0, "end": 0.4 },
+                { "word": "world", "start": 0.5, "end": 0.9 }
+            ]
+        });
+        let transcript = parse_openai_response(&openai).unwrap();
+        assert_eq!(transcript.text, "Hello world.");
+        assert_eq!(transcript.words.len(), 2);
+        assert_eq!(transcript.words[1].word, "world");
+
+        let deepgram = serde_json::json!({
+            "results": { "channels": [{ "alternatives": [{
+                "transcript": "Hi there.",
+                "words": [
+                    { "word": "hi", "punctuated_word": "Hi", "start": 0.1, "end": 0.3 },
+                    { "word": "there", "punctuated_word": "there.", "start": 0.3, "end": 0.6 }
+                ]
+            }] }] }
+        });
+        let transcript = parse_deepgram_response(&deepgram).unwrap();
+        assert_eq!(transcript.text, "Hi there.");
+        assert_eq!(transcript.words[1].word, "there.");
+
+        assert!(parse_deepgram_response(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_words_from_segments() {
+        let words = words_from_segments([(1.0, 2.0, " ab  abc")]);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "ab");
+        assert_eq!(words[0].start, 1.0);
+        assert!((words[0].end - 1.4).abs() < 1e-6);
+        assert_eq!(words[1].end, 2.0);
+
+        let shifted = Transcript {
+            text: String::new(),
+            words,
+        }
+        .offset_by(SAMPLE_RATE as usize * 10);
+        assert_eq!(shifted.words[0].start, 11.0);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::audio_toolkit::apply_custom_words;
+use crate::audio_toolkit::{apply_custom_words, apply_term_casing, split_at_pauses};
 use crate::managers::model::{EngineType, ModelManager};
+use crate::managers::stt_engine::{create_cloud_engine, SttEngine, SttOptions, Transcript};
 use crate::settings::{get_settings, ModelUnloadTimeout};
 use anyhow::Result;
 use log::{debug, error, info, warn};
@@ -11,10 +12,8 @@ use std::time::{Duration, SystemTime};
 use tauri::{AppHandle, Emitter};
 use transcribe_rs::{
     engines::{
-        parakeet::{
-            ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
-        },
-        whisper::{WhisperEngine, WhisperInferenceParams},
+        parakeet::{ParakeetEngine, ParakeetModelParams},
+        whisper::WhisperEngine,
     },
     TranscriptionEngine,
 };
@@ -27,14 +26,29 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
-enum LoadedEngine {
-    Whisper(WhisperEngine),
-    Parakeet(ParakeetEngine),
+/// Live transcription of the current recording, emitted as `transcription-partial`
+#[derive(Clone, Debug, Serialize)]
+pub struct TranscriptionPartialEvent {
+    /// Everything decoded so far, including the segment still being spoken
+    pub text: String,
+    /// True while the end of `text` may still change as the speaker continues
+    pub is_provisional: bool,
+}
+
+/// Transcript queued by the test harness, returned in place of running the model
+#[cfg(any(test, feature = "test-support"))]
+fn take_fixture_transcript() -> Option<String> {
+    crate::test_support::take_fixture_transcript()
+}
+
+#[cfg(not(any(test, feature = "test-support")))]
+fn take_fixture_transcript() -> Option<String> {
+    None
 }
 
 #[derive(Clone)]
 pub struct TranscriptionManager {
-    engine: Arc<Mutex<Option<LoadedEngine>>>,
+    engine: Arc<Mutex<Option<Box<dyn SttEngine>>>>,
     model_manager: Arc<ModelManager>,
     app_handle: AppHandle,
     current_model_id: Arc<Mutex<Option<String>>>,
@@ -43,6 +57,8 @@ pub struct TranscriptionManager {
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
+    /// Language forced by the prompt category of the current recording
+    language_override: Arc<Mutex<Option<String>>>,
 }
 
 impl TranscriptionManager {
@@ -62,6 +78,7 @@ impl TranscriptionManager {
             watcher_handle: Arc::new(Mutex::new(None)),
             is_loading: Arc::new(Mutex::new(false)),
             loading_condvar: Arc::new(Condvar::new()),
+            language_override: Arc::new(Mutex::new(None)),
         };
 
         // Start the idle watcher
@@ -79,11 +96,11 @@ impl TranscriptionManager {
                     }
 
                     let settings = get_settings(&app_handle_cloned);
-                    let timeout_seconds = settings.model_unload_timeout.to_seconds();
+                    let unload_timeout = crate::power::model_unload_timeout(&settings);
 
-                    if let Some(limit_seconds) = timeout_seconds {
+                    if let Some(limit_seconds) = unload_timeout.to_seconds() {
                         // Skip polling-based unloading for immediate timeout since it's handled directly in transcribe()
-                        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
+                        if unload_timeout == ModelUnloadTimeout::Immediately {
                             continue;
                         }
 
@@ -139,10 +156,7 @@ impl TranscriptionManager {
         {
             let mut engine = self.engine.lock().unwrap();
             if let Some(ref mut loaded_engine) = *engine {
-                match loaded_engine {
-                    LoadedEngine::Whisper(ref mut whisper) => whisper.unload_model(),
-                    LoadedEngine::Parakeet(ref mut parakeet) => parakeet.unload_model(),
-                }
+                loaded_engine.unload();
             }
             *engine = None; // Drop the engine to free memory
         }
@@ -204,46 +218,43 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!(error_msg));
         }
 
-        let model_path = self.model_manager.get_model_path(model_id)?;
+        let emit_failure = |error_msg: String| {
+            let _ = self.app_handle.emit(
+                "model-state-changed",
+                ModelStateEvent {
+                    event_type: "loading_failed".to_string(),
+                    model_id: Some(model_id.to_string()),
+                    model_name: Some(model_info.name.clone()),
+                    error: Some(error_msg.clone()),
+                },
+            );
+            anyhow::anyhow!(error_msg)
+        };
 
         // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
+        let loaded_engine: Box<dyn SttEngine> = match model_info.engine_type {
             EngineType::Whisper => {
+                let model_path = self.model_manager.get_model_path(model_id)?;
                 let mut engine = WhisperEngine::new();
                 engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
+                    emit_failure(format!("Failed to load whisper model {}: {}", model_id, e))
                 })?;
-                LoadedEngine::Whisper(engine)
+                Box::new(engine)
             }
             EngineType::Parakeet => {
+                let model_path = self.model_manager.get_model_path(model_id)?;
                 let mut engine = ParakeetEngine::new();
                 engine
                     .load_model_with_params(&model_path, ParakeetModelParams::int8())
                     .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
+                        emit_failure(format!("Failed to load parakeet model {}: {}", model_id, e))
                     })?;
-                LoadedEngine::Parakeet(engine)
+                Box::new(engine)
+            }
+            EngineType::OpenAiCloud | EngineType::DeepgramCloud => {
+                let settings = get_settings(&self.app_handle);
+                create_cloud_engine(&model_info.engine_type, &settings)
+                    .map_err(|e| emit_failure(format!("Failed to set up {}: {}", model_id, e)))?
             }
             EngineType::TTS => {
                 let error_msg = format!("Engine type TTS is not supported for transcription");
@@ -292,7 +303,8 @@ impl TranscriptionManager {
         let self_clone = self.clone();
         thread::spawn(move || {
             let settings = get_settings(&self_clone.app_handle);
-            if let Err(e) = self_clone.load_model(&settings.selected_model) {
+            let model_id = crate::power::transcription_model(&settings);
+            if let Err(e) = self_clone.load_model(&model_id) {
                 error!("Failed to load model: {}", e);
             }
             let mut is_loading = self_clone.is_loading.lock().unwrap();
@@ -301,12 +313,99 @@ impl TranscriptionManager {
         });
     }
 
+    /// Transcribe in `language` instead of the global language until changed again.
+    /// Set at the start of each recording from its prompt category.
+    pub fn set_language_override(&self, language: Option<String>) {
+        *self.language_override.lock().unwrap() = language;
+    }
+
     pub fn get_current_model(&self) -> Option<String> {
         let current_model = self.current_model_id.lock().unwrap();
         current_model.clone()
     }
 
     pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        self.run_transcription(audio, false).map(|t| t.text)
+    }
+
+    /// Transcribe and keep the engine's word timings. Timings refer to the words the
+    /// engine recognised, before custom-word correction and filler filtering.
+    pub fn transcribe_with_timings(&self, audio: Vec<f32>) -> Result<Transcript> {
+        if let Some(text) = take_fixture_transcript() {
+            debug!("Returning the fixture transcript queued by the test harness");
+            return Ok(Transcript {
+                text,
+                words: Vec::new(),
+            });
+        }
+        if get_settings(&self.app_handle).code_switching {
+            return self.transcribe_by_phrase(audio);
+        }
+        self.run_transcription(audio, false)
+    }
+
+    /// Code-switching mode: decode each phrase between pauses separately so the engine
+    /// detects its language on its own, then merge the phrases back in order. Forcing
+    /// one language on mixed speech makes engines translate or garble the other one.
+    fn transcribe_by_phrase(&self, audio: Vec<f32>) -> Result<Transcript> {
+        let ranges = split_at_pauses(&audio);
+        if ranges.len() <= 1 {
+            return self.run_transcription(audio, false);
+        }
+        debug!("Code-switching transcription of {} phrases", ranges.len());
+
+        let mut texts = Vec::new();
+        let mut words = Vec::new();
+        let last = ranges.len() - 1;
+        for (index, range) in ranges.into_iter().enumerate() {
+            let offset = range.start;
+            // Only the last phrase counts as the finished transcription, so the model
+            // isn't unloaded between phrases
+            let transcript = self
+                .run_transcription(audio[range].to_vec(), index < last)?
+                .offset_by(offset);
+            words.extend(transcript.words);
+            if !transcript.text.is_empty() {
+                texts.push(transcript.text);
+            }
+        }
+
+        Ok(Transcript {
+            text: texts.join(" "),
+            words,
+        })
+    }
+
+    /// Decode audio that is still being recorded for a live preview. Unlike `transcribe`,
+    /// this never unloads the model afterwards and only logs at debug level.
+    pub fn transcribe_partial(&self, audio: Vec<f32>) -> Result<String> {
+        self.run_transcription(audio, true).map(|t| t.text)
+    }
+
+    pub fn live_transcription_enabled(&self) -> bool {
+        get_settings(&self.app_handle).live_transcription
+    }
+
+    /// Whether the loaded engine decodes partials, see `SttEngine::supports_partials`.
+    /// True while no engine is loaded yet, so check again before each partial.
+    pub fn engine_supports_partials(&self) -> bool {
+        match self.engine.lock().unwrap().as_ref() {
+            Some(engine) => engine.supports_partials(),
+            None => true,
+        }
+    }
+
+    pub fn emit_partial(&self, text: String, is_provisional: bool) {
+        let _ = self.app_handle.emit(
+            "transcription-partial",
+            TranscriptionPartialEvent {
+                text,
+                is_provisional,
+            },
+        );
+    }
+
+    fn run_transcription(&self, audio: Vec<f32>, partial: bool) -> Result<Transcript> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -322,7 +421,7 @@ impl TranscriptionManager {
 
         if audio.len() == 0 {
             debug!("Empty audio vector");
-            return Ok(String::new());
+            return Ok(Transcript::default());
         }
 
         // Check if model is loaded, if not try to load it
@@ -343,7 +442,10 @@ impl TranscriptionManager {
         let settings = get_settings(&self.app_handle);
 
         // Perform transcription with the appropriate engine
-        let result = {
+        let Transcript {
+            text: result,
+            words,
+        } = {
             let mut engine_guard = self.engine.lock().unwrap();
             let engine = engine_guard.as_mut().ok_or_else(|| {
                 anyhow::anyhow!(
@@ -351,57 +453,24 @@ impl TranscriptionManager {
                 )
             })?;
 
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    // Normalize language code for Whisper
-                    // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else {
-                        let normalized = if settings.selected_language == "zh-Hans"
-                            || settings.selected_language == "zh-Hant"
-                        {
-                            "zh".to_string()
-                        } else {
-                            settings.selected_language.clone()
-                        };
-                        Some(normalized)
-                    };
-
-                    let params = WhisperInferenceParams {
-                        language: whisper_language,
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
-
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => {
-                    let params = ParakeetInferenceParams {
-                        timestamp_granularity: TimestampGranularity::Segment,
-                        ..Default::default()
-                    };
-
-                    parakeet_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
-                }
-            }
+            let language_override = self.language_override.lock().unwrap().clone();
+            let options = SttOptions::from_settings(&settings)
+                .with_language_override(language_override.as_deref());
+            engine.transcribe(audio, &options)?
         };
 
-        // Apply word correction if custom words are configured
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &result.text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
+        // Apply word correction if custom words are configured, including those of
+        // the active project
+        let custom_words = crate::projects::vocabulary(&settings);
+        let corrected_result = if !custom_words.is_empty() {
+            apply_custom_words(&result, &custom_words, settings.word_correction_threshold)
         } else {
-            result.text
+            result
         };
 
+        // Enforce the exact casing of dictionary terms
+        let corrected_result = apply_term_casing(&corrected_result, &settings.cased_terms);
+
         // Apply filler word filter if configured
         let filtered_result = if let Some(ref pattern) = settings.filler_word_filter {
             if !pattern.is_empty() {
@@ -472,14 +541,26 @@ impl TranscriptionManager {
         } else {
             ""
         };
+        let final_result = collapsed_result.trim().to_string();
+
+        if partial {
+            debug!(
+                "Partial transcription completed in {}ms: {}",
+                (et - st).as_millis(),
+                final_result
+            );
+            return Ok(Transcript {
+                text: final_result,
+                words,
+            });
+        }
+
         info!(
             "Transcription completed in {}ms{}",
             (et - st).as_millis(),
             translation_note
         );
 
-        let final_result = collapsed_result.trim().to_string();
-
         if final_result.is_empty() {
             info!("Transcription result is empty");
         } else {
@@ -487,19 +568,22 @@ impl TranscriptionManager {
         }
 
         // Check if we should immediately unload the model after transcription
-        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
+        if crate::power::model_unload_timeout(&settings) == ModelUnloadTimeout::Immediately {
             info!("Immediately unloading model after transcription");
             if let Err(e) = self.unload_model() {
                 error!("Failed to immediately unload model: {}", e);
             }
         }
 
-        Ok(final_result)
+        Ok(Transcript {
+            text: final_result,
+            words,
+        })
     }
 
     /// Try Whisper fallback if available
     /// This attempts to load and use a Whisper model if the primary transcription failed
-    pub async fn transcribe_with_fallback(&self, audio: Vec<f32>) -> Result<String> {
+    pub async fn transcribe_with_fallback(&self, audio: Vec<f32>) -> Result<Transcript> {
         // Check if there's a Whisper model available
         let whisper_model = self
             .model_manager
@@ -519,7 +603,7 @@ impl TranscriptionManager {
                 self.load_model(&model_id)?;
 
                 // Try transcription with Whisper
-                let result = self.transcribe(audio);
+                let result = self.transcribe_with_timings(audio);
 
                 // Restore original model if there was one
                 if let Some(ref original) = original_model {
@@ -534,14 +618,14 @@ impl TranscriptionManager {
 
     /// Transcribe audio in chunks to avoid ORT memory errors on long recordings
     /// Splits audio into ~2 minute segments and transcribes each separately
-    pub fn transcribe_chunked(&self, audio: Vec<f32>) -> Result<String> {
+    pub fn transcribe_chunked(&self, audio: Vec<f32>) -> Result<Transcript> {
         // 2 minutes at 16kHz = 1,920,000 samples
         // But our audio is at the model's sample rate (usually 16kHz)
         const CHUNK_DURATION_SAMPLES: usize = 1_920_000; // 2 minutes at 16kHz
 
         if audio.len() <= CHUNK_DURATION_SAMPLES {
             // Audio is short enough, try normal transcription
-            return self.transcribe(audio);
+            return self.transcribe_with_timings(audio);
         }
 
         info!(
@@ -551,6 +635,7 @@ impl TranscriptionManager {
         );
 
         let mut transcriptions = Vec::new();
+        let mut words = Vec::new();
         let mut start = 0;
 
         while start < audio.len() {
@@ -559,10 +644,12 @@ impl TranscriptionManager {
 
             debug!("Transcribing chunk: samples {}-{}", start, end);
 
-            match self.transcribe(chunk) {
-                Ok(text) => {
-                    if !text.is_empty() {
-                        transcriptions.push(text);
+            match self.transcribe_with_timings(chunk) {
+                Ok(transcript) => {
+                    let transcript = transcript.offset_by(start);
+                    words.extend(transcript.words);
+                    if !transcript.text.is_empty() {
+                        transcriptions.push(transcript.text);
                     }
                 }
                 Err(e) => {
@@ -585,7 +672,10 @@ impl TranscriptionManager {
             combined.len()
         );
 
-        Ok(combined)
+        Ok(Transcript {
+            text: combined,
+            words,
+        })
     }
 }
 
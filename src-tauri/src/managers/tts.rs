@@ -1,24 +1,36 @@
 use crate::managers::model::ModelManager;
 use crate::overlay::{hide_recording_overlay, show_speaking_overlay};
-use crate::settings::get_settings;
+use crate::settings::{get_settings, AppSettings, TtsBackend};
+use crate::tts::cloud::{self, CloudTtsEngine};
 use crate::tts::kokoro::KokoroEngine;
+use crate::tts::system::SystemEngine;
 use crate::tts::TTSEngine;
 use anyhow::Result;
 use log::{info, warn};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 use tokio::sync::Mutex;
 
 // kokorox expects a ZIP archive with NPZ voice data, not raw .bin files
 const KOKORO_VOICES_URL: &str =
     "https://github.com/thewh1teagle/kokoro-onnx/releases/download/model-files-v1.0/voices-v1.0.bin";
 const KOKORO_VOICES_FILENAME: &str = "kokoro-voices-v1.0.bin";
+const KOKORO_MODEL_ID: &str = "kokoro-82m";
+
+/// Event emitted with `true` when speech starts and `false` once it has finished
+/// or been stopped, so windows can show a stop control
+pub const TTS_PLAYBACK_EVENT: &str = "tts-playback";
 
 pub struct TTSManager {
     app_handle: AppHandle,
     model_manager: Arc<ModelManager>,
     engine: Arc<Mutex<Option<Box<dyn TTSEngine>>>>,
+    /// Which backend the loaded engine is; never `Auto`
+    loaded_backend: std::sync::Mutex<Option<TtsBackend>>,
+    /// The loaded engine's cancel flag, reachable while `speak` holds the engine
+    cancel: std::sync::Mutex<Option<Arc<AtomicBool>>>,
 }
 
 impl TTSManager {
@@ -27,6 +39,8 @@ impl TTSManager {
             app_handle: app_handle.clone(),
             model_manager,
             engine: Arc::new(Mutex::new(None)),
+            loaded_backend: std::sync::Mutex::new(None),
+            cancel: std::sync::Mutex::new(None),
         }
     }
 
@@ -36,16 +50,12 @@ impl TTSManager {
             return Ok(());
         }
 
-        let model_id = settings
-            .tts_selected_model
-            .as_deref()
-            .unwrap_or("kokoro-82m");
-
         // Ensure engine is loaded
-        self.ensure_engine_loaded(model_id).await?;
+        self.ensure_engine_loaded(&settings).await?;
 
         // Show the speaking overlay
         show_speaking_overlay(&self.app_handle);
+        let _ = self.app_handle.emit(TTS_PLAYBACK_EVENT, true);
 
         {
             let mut engine_guard = self.engine.lock().await;
@@ -69,6 +79,7 @@ impl TTSManager {
                     if !engine.is_playing() {
                         drop(engine_guard);
                         hide_recording_overlay(&app_handle_clone);
+                        let _ = app_handle_clone.emit(TTS_PLAYBACK_EVENT, false);
                         info!("TTS playback finished, hiding overlay");
                         break;
                     }
@@ -76,6 +87,7 @@ impl TTSManager {
                     // Engine not loaded, hide overlay
                     drop(engine_guard);
                     hide_recording_overlay(&app_handle_clone);
+                    let _ = app_handle_clone.emit(TTS_PLAYBACK_EVENT, false);
                     break;
                 }
             }
@@ -84,13 +96,29 @@ impl TTSManager {
         Ok(())
     }
 
+    /// Whether speech is being generated or played
+    pub fn is_speaking(&self) -> bool {
+        match self.engine.try_lock() {
+            Ok(engine_guard) => engine_guard
+                .as_ref()
+                .is_some_and(|engine| engine.is_playing()),
+            // `speak` holds the engine while it loads and generates
+            Err(_) => true,
+        }
+    }
+
     pub async fn stop(&self) -> Result<()> {
+        // Stop generating first: a long reply keeps the engine locked until then
+        if let Some(cancel) = self.cancel.lock().unwrap().as_ref() {
+            cancel.store(true, Ordering::SeqCst);
+        }
         let engine_guard = self.engine.lock().await;
         if let Some(engine) = engine_guard.as_ref() {
             engine.stop().await?;
         }
         // Hide overlay when stopped
         hide_recording_overlay(&self.app_handle);
+        let _ = self.app_handle.emit(TTS_PLAYBACK_EVENT, false);
         Ok(())
     }
 
@@ -112,9 +140,69 @@ impl TTSManager {
         Ok(voices_path)
     }
 
-    async fn ensure_engine_loaded(&self, model_id: &str) -> Result<()> {
+    /// Drop the loaded engine, so the next speech loads one for the current
+    /// backend and voice settings
+    pub async fn unload(&self) {
+        if self.is_speaking() {
+            let _ = self.stop().await;
+        }
+        *self.engine.lock().await = None;
+        *self.loaded_backend.lock().unwrap() = None;
+        *self.cancel.lock().unwrap() = None;
+    }
+
+    /// The backend to speak with: `Auto` picks Kokoro once its model is downloaded
+    fn resolve_backend(&self, settings: &AppSettings, model_id: &str) -> TtsBackend {
+        match settings.tts_backend {
+            TtsBackend::Auto => {
+                let downloaded = self
+                    .model_manager
+                    .get_model_info(model_id)
+                    .is_some_and(|info| info.is_downloaded);
+                if downloaded {
+                    TtsBackend::Kokoro
+                } else {
+                    TtsBackend::System
+                }
+            }
+            backend => backend,
+        }
+    }
+
+    async fn ensure_engine_loaded(&self, settings: &AppSettings) -> Result<()> {
+        let model_id = settings
+            .tts_selected_model
+            .as_deref()
+            .unwrap_or(KOKORO_MODEL_ID);
+        let backend = self.resolve_backend(settings, model_id);
+
         let mut engine_guard = self.engine.lock().await;
-        if engine_guard.is_some() {
+        if engine_guard.is_some() && *self.loaded_backend.lock().unwrap() == Some(backend) {
+            return Ok(());
+        }
+
+        if backend == TtsBackend::System {
+            info!("Loading system TTS engine");
+            let engine = SystemEngine::new(settings.tts_system_voice.clone());
+            *self.cancel.lock().unwrap() = Some(engine.cancel_flag());
+            *engine_guard = Some(Box::new(engine) as Box<dyn TTSEngine>);
+            *self.loaded_backend.lock().unwrap() = Some(backend);
+            return Ok(());
+        }
+
+        if let Some(service) = backend.cloud_service() {
+            info!("Loading {} TTS engine", service);
+            let api_key = cloud::tts_api_key(settings, service)
+                .ok_or_else(|| anyhow::anyhow!("No API key set for {} speech", service))?;
+            let voice = settings
+                .tts_cloud_voices
+                .get(service)
+                .cloned()
+                .unwrap_or_else(|| cloud::default_voice(service).to_string());
+            let engine = CloudTtsEngine::new(service, api_key, voice)?;
+            *self.cancel.lock().unwrap() = Some(engine.cancel_flag());
+            *engine_guard = Some(Box::new(engine) as Box<dyn TTSEngine>);
+            *self.loaded_backend.lock().unwrap() = Some(backend);
             return Ok(());
         }
 
@@ -141,7 +229,9 @@ impl TTSManager {
         let mut kokoro = KokoroEngine::new();
         kokoro.load_model(model_path, voices_path).await?;
 
+        *self.cancel.lock().unwrap() = Some(kokoro.cancel_flag());
         *engine_guard = Some(Box::new(kokoro) as Box<dyn TTSEngine>);
+        *self.loaded_backend.lock().unwrap() = Some(backend);
         info!("TTS engine loaded successfully");
 
         Ok(())
@@ -0,0 +1,268 @@
+use anyhow::Result;
+use chrono::{Datelike, Local, Timelike, Utc};
+use log::{debug, info, warn};
+use rusqlite::{params, Connection};
+use rusqlite_migration::{Migrations, M};
+use serde::Serialize;
+use specta::Type;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::llm_client::TokenUsage;
+use crate::settings::{LLMModel, LLMProvider};
+
+/// Database migrations for the LLM usage ledger.
+static MIGRATIONS: &[M] = &[
+    M::up(
+        "CREATE TABLE IF NOT EXISTS usage_records (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            provider_id TEXT NOT NULL,
+            model_id TEXT NOT NULL,
+            source TEXT NOT NULL,
+            prompt_tokens INTEGER NOT NULL,
+            completion_tokens INTEGER NOT NULL,
+            cost_usd REAL
+        );",
+    ),
+    M::up("CREATE INDEX IF NOT EXISTS idx_usage_timestamp ON usage_records(timestamp);"),
+];
+
+/// Spend so far, for display next to the spend limits
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageSummary {
+    pub today_cost_usd: f64,
+    pub today_requests: u32,
+    pub month_cost_usd: f64,
+    /// Requests this month whose model had no known price, so their cost isn't counted
+    pub month_unpriced_requests: u32,
+}
+
+/// Usage of one provider/model over a month
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageReportRow {
+    pub provider_id: String,
+    pub model_id: String,
+    pub requests: u32,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub cost_usd: f64,
+    /// Requests whose model had no known price, so their cost isn't counted
+    pub unpriced_requests: u32,
+}
+
+/// Usage per provider/model for one calendar month, most expensive first
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageReport {
+    /// "YYYY-MM"
+    pub month: String,
+    pub rows: Vec<UsageReportRow>,
+    pub total_cost_usd: f64,
+}
+
+pub struct UsageManager {
+    db_path: PathBuf,
+}
+
+/// Unix timestamp of local midnight today
+pub(crate) fn start_of_today() -> i64 {
+    let now = Local::now();
+    now.timestamp() - now.num_seconds_from_midnight() as i64
+}
+
+/// Unix timestamp of local midnight on the first of this month
+fn start_of_month() -> i64 {
+    let now = Local::now();
+    start_of_today() - (now.day0() as i64) * 24 * 60 * 60
+}
+
+/// Local start and end (exclusive) of a "YYYY-MM" month as unix timestamps
+fn month_bounds(month: &str) -> Option<(i64, i64)> {
+    let (year, month) = month.split_once('-')?;
+    let (year, month): (i32, u32) = (year.parse().ok()?, month.parse().ok()?);
+    let start = chrono::NaiveDate::from_ymd_opt(year, month, 1)?;
+    let end = if month == 12 {
+        chrono::NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        chrono::NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    let local = |date: chrono::NaiveDate| {
+        date.and_hms_opt(0, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|t| t.timestamp())
+    };
+    Some((local(start)?, local(end)?))
+}
+
+/// Record a completed LLM call from anywhere in the app, priced from the model's
+/// known rates. Failures are only logged so tracking never breaks a request.
+pub fn record_usage(
+    app: &AppHandle,
+    provider: &LLMProvider,
+    model: &LLMModel,
+    source: &str,
+    usage: &TokenUsage,
+) {
+    let cost = crate::pricing::pricing_for(provider, model)
+        .map(|p| p.cost(usage.prompt_tokens, usage.completion_tokens));
+    let manager = app.state::<Arc<UsageManager>>();
+    if let Err(e) = manager.record(&provider.id, &model.model_id, source, usage, cost) {
+        warn!("Failed to record {} usage: {}", source, e);
+    }
+}
+
+impl UsageManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = app_handle.path().app_data_dir()?;
+        let db_path = app_data_dir.join("usage.db");
+
+        let manager = Self { db_path };
+        manager.init_database()?;
+
+        Ok(manager)
+    }
+
+    fn init_database(&self) -> Result<()> {
+        info!("Initializing usage database at {:?}", self.db_path);
+
+        let mut conn = Connection::open(&self.db_path)?;
+        let migrations = Migrations::new(MIGRATIONS.to_vec());
+
+        #[cfg(debug_assertions)]
+        migrations.validate().expect("Invalid usage migrations");
+
+        migrations.to_latest(&mut conn)?;
+
+        Ok(())
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    /// Record one completed LLM call. `cost_usd` is None when the model has no known price.
+    pub fn record(
+        &self,
+        provider_id: &str,
+        model_id: &str,
+        source: &str,
+        usage: &TokenUsage,
+        cost_usd: Option<f64>,
+    ) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO usage_records (timestamp, provider_id, model_id, source, prompt_tokens, completion_tokens, cost_usd)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                Utc::now().timestamp(),
+                provider_id,
+                model_id,
+                source,
+                usage.prompt_tokens,
+                usage.completion_tokens,
+                cost_usd
+            ],
+        )?;
+        debug!(
+            "Recorded {} usage for {}: {} prompt + {} completion tokens (${:.5})",
+            source,
+            model_id,
+            usage.prompt_tokens,
+            usage.completion_tokens,
+            cost_usd.unwrap_or(0.0)
+        );
+        Ok(())
+    }
+
+    /// Total known cost of calls made since `since` (unix seconds)
+    pub fn spent_since(&self, since: i64) -> Result<f64> {
+        let conn = self.get_connection()?;
+        let total: f64 = conn.query_row(
+            "SELECT COALESCE(SUM(cost_usd), 0.0) FROM usage_records WHERE timestamp >= ?1",
+            params![since],
+            |row| row.get(0),
+        )?;
+        Ok(total)
+    }
+
+    /// Known cost of calls made since local midnight
+    pub fn spent_today(&self) -> Result<f64> {
+        self.spent_since(start_of_today())
+    }
+
+    /// Usage per provider/model in a "YYYY-MM" month, or the current month if None
+    pub fn monthly_report(&self, month: Option<&str>) -> Result<UsageReport> {
+        let month = month
+            .map(String::from)
+            .unwrap_or_else(|| Local::now().format("%Y-%m").to_string());
+        let (start, end) = month_bounds(&month)
+            .ok_or_else(|| anyhow::anyhow!("Invalid month '{}', expected YYYY-MM", month))?;
+
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT provider_id, model_id, COUNT(*), SUM(prompt_tokens), SUM(completion_tokens),
+                    COALESCE(SUM(cost_usd), 0.0), COUNT(*) - COUNT(cost_usd)
+             FROM usage_records WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY provider_id, model_id
+             ORDER BY 6 DESC, 3 DESC",
+        )?;
+        let rows = stmt
+            .query_map(params![start, end], |row| {
+                Ok(UsageReportRow {
+                    provider_id: row.get(0)?,
+                    model_id: row.get(1)?,
+                    requests: row.get(2)?,
+                    prompt_tokens: row.get(3)?,
+                    completion_tokens: row.get(4)?,
+                    cost_usd: row.get(5)?,
+                    unpriced_requests: row.get(6)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        Ok(UsageReport {
+            total_cost_usd: rows.iter().map(|r| r.cost_usd).sum(),
+            month,
+            rows,
+        })
+    }
+
+    pub fn summary(&self) -> Result<UsageSummary> {
+        let conn = self.get_connection()?;
+        let (today_cost_usd, today_requests): (f64, u32) = conn.query_row(
+            "SELECT COALESCE(SUM(cost_usd), 0.0), COUNT(*) FROM usage_records WHERE timestamp >= ?1",
+            params![start_of_today()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let (month_cost_usd, month_unpriced_requests): (f64, u32) = conn.query_row(
+            "SELECT COALESCE(SUM(cost_usd), 0.0), COUNT(*) - COUNT(cost_usd) FROM usage_records WHERE timestamp >= ?1",
+            params![start_of_month()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        Ok(UsageSummary {
+            today_cost_usd,
+            today_requests,
+            month_cost_usd,
+            month_unpriced_requests,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_bounds() {
+        let (start, end) = month_bounds("2024-02").unwrap();
+        // February 2024 has 29 days; allow an hour either way for DST changes
+        assert!((end - start - 29 * 24 * 60 * 60).abs() <= 60 * 60);
+        let (_, december_end) = month_bounds("2024-12").unwrap();
+        assert_eq!(december_end, month_bounds("2025-01").unwrap().0);
+        assert!(month_bounds("2024-13").is_none());
+        assert!(month_bounds("last month").is_none());
+    }
+}
@@ -0,0 +1,154 @@
+//! The play/pause media key as a dictation trigger.
+//!
+//! With `AppSettings::media_key_trigger_enabled` on, the play/pause key is taken
+//! away from the music player and starts or stops `media_key_binding` instead. That
+//! includes the play/pause button of Bluetooth headsets, so dictation works from
+//! earbuds without a keyboard.
+//!
+//! Headset buttons send press and release together, so a press toggles the
+//! recording. A keyboard key held past the hold threshold works as push-to-talk,
+//! stopping on release. On macOS the keyboard key comes from an event tap in the
+//! Swift bridge and headset buttons from the remote command center, which is how
+//! the system delivers them; elsewhere the key is registered as a global shortcut.
+//! Either way the key is handled on a thread of its own, since starting a
+//! recording can take a while and the tap runs on the main run loop.
+
+use crate::settings;
+use log::{debug, info, warn};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Instant;
+use tauri::AppHandle;
+
+static APP: OnceLock<AppHandle> = OnceLock::new();
+
+/// Presses and releases waiting for the key thread, which handles them in order
+static KEY_EVENTS: OnceLock<Sender<bool>> = OnceLock::new();
+
+/// When the key went down, while it's down and started a recording
+static PRESSED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+fn handle_key(pressed: bool) {
+    let Some(app) = APP.get() else {
+        return;
+    };
+    if crate::shortcut::shortcuts_paused() {
+        debug!("Ignoring media key while shortcuts are paused");
+        return;
+    }
+
+    let settings = settings::get_settings(app);
+    let binding_id = settings.media_key_binding.as_str();
+    let active = crate::utils::is_toggle_active(app, binding_id);
+
+    if pressed {
+        if active {
+            info!("Media key stopping '{}'", binding_id);
+            crate::utils::stop_toggled_recording(app, binding_id);
+        } else {
+            info!("Media key starting '{}'", binding_id);
+            crate::utils::start_hands_free(app, binding_id);
+            if crate::utils::is_toggle_active(app, binding_id) {
+                *PRESSED_AT.lock().unwrap() = Some(Instant::now());
+            }
+        }
+        return;
+    }
+
+    // Released: only a long hold of the key that started the recording stops it
+    let Some(pressed_at) = PRESSED_AT.lock().unwrap().take() else {
+        return;
+    };
+    let held_ms = pressed_at.elapsed().as_millis();
+    if active && held_ms >= settings.hold_threshold_ms as u128 {
+        info!(
+            "Media key released after {}ms, stopping '{}'",
+            held_ms, binding_id
+        );
+        crate::utils::stop_toggled_recording(app, binding_id);
+    }
+}
+
+/// Hand a press or release to the key thread, starting it on first use
+fn dispatch_key(pressed: bool) {
+    let sender = KEY_EVENTS.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for pressed in receiver {
+                handle_key(pressed);
+            }
+        });
+        sender
+    });
+    let _ = sender.send(pressed);
+}
+
+#[cfg(target_os = "macos")]
+extern "C" {
+    fn start_media_key_tap(callback: extern "C" fn(bool)) -> bool;
+    fn stop_media_key_tap();
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_media_key(pressed: bool) {
+    dispatch_key(pressed);
+}
+
+#[cfg(target_os = "macos")]
+fn set_listening(_app: &AppHandle, enabled: bool) -> Result<(), String> {
+    if !enabled {
+        unsafe { stop_media_key_tap() };
+        return Ok(());
+    }
+    if unsafe { start_media_key_tap(on_media_key) } {
+        Ok(())
+    } else {
+        Err("Couldn't listen for the play/pause key. Is accessibility access granted?".to_string())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_listening(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Shortcut, ShortcutState};
+
+    let shortcut = Shortcut::new(None, Code::MediaPlayPause);
+    let global_shortcut = app.global_shortcut();
+    let registered = global_shortcut.is_registered(shortcut);
+
+    if !enabled {
+        if registered {
+            global_shortcut
+                .unregister(shortcut)
+                .map_err(|e| e.to_string())?;
+        }
+        return Ok(());
+    }
+    if registered {
+        return Ok(());
+    }
+    global_shortcut
+        .on_shortcut(shortcut, |_app, _shortcut, event| {
+            dispatch_key(event.state == ShortcutState::Pressed);
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Start or stop taking over the play/pause key, following the settings
+pub fn update(app: &AppHandle) -> Result<(), String> {
+    let _ = APP.set(app.clone());
+    let settings = settings::get_settings(app);
+    if !settings.media_key_trigger_enabled {
+        PRESSED_AT.lock().unwrap().take();
+    }
+
+    let result = set_listening(app, settings.media_key_trigger_enabled);
+    match &result {
+        Ok(()) if settings.media_key_trigger_enabled => {
+            info!("Play/pause key triggers '{}'", settings.media_key_binding)
+        }
+        Ok(()) => {}
+        Err(e) => warn!("Failed to update media key trigger: {}", e),
+    }
+    result
+}
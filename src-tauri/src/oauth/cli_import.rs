@@ -0,0 +1,338 @@
+//! Reuse of credentials from the Gemini CLI and Codex CLI
+//!
+//! Users who already signed in to those CLIs have tokens on disk for the same OAuth
+//! clients this module mimics, so they can be imported instead of running a second
+//! browser flow:
+//! - Gemini CLI: `~/.gemini/oauth_creds.json` (plus `google_accounts.json` for the email)
+//! - Codex CLI: `$CODEX_HOME/auth.json`, defaulting to `~/.codex/auth.json`
+//!
+//! The files are only read. Refresh tokens only work with the OAuth client they were
+//! issued to, so an import is refused unless Ramble is configured with the CLI's client,
+//! and the tokens are refreshed once to check they work. OpenAI rotates refresh tokens
+//! on every refresh, so the Codex CLI has to sign in again after its tokens are imported.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+
+use super::tokens::{extract_chatgpt_account_id, parse_jwt_claims, store_tokens, StoredTokens};
+use super::{google, openai, OAuthProvider};
+
+/// OAuth client the Gemini CLI signs in with, for credentials whose ID token doesn't say
+const GEMINI_CLI_CLIENT_ID: &str =
+    "681255809395-oo8ft2oprdrnp9e3aqf6av3hmdib135j.apps.googleusercontent.com";
+
+/// CLI credentials found on disk that can be imported
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CliCredentials {
+    pub provider: OAuthProvider,
+    /// File the credentials were read from
+    pub source_path: String,
+    pub email: Option<String>,
+    /// Access token expiration (Unix seconds)
+    pub expires_at: i64,
+    /// Importing rotates the refresh token, so the CLI has to sign in again
+    pub signs_out_cli: bool,
+}
+
+/// Tokens read from a CLI's credentials file
+struct CliTokens {
+    path: PathBuf,
+    tokens: StoredTokens,
+    /// OAuth client the tokens were issued to
+    client_id: String,
+}
+
+#[derive(Deserialize)]
+struct GeminiCreds {
+    access_token: String,
+    refresh_token: String,
+    /// Unix milliseconds
+    expiry_date: Option<i64>,
+    id_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GeminiAccounts {
+    active: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CodexAuth {
+    tokens: Option<CodexTokens>,
+}
+
+#[derive(Deserialize)]
+struct CodexTokens {
+    access_token: String,
+    refresh_token: String,
+    id_token: Option<String>,
+    account_id: Option<String>,
+}
+
+fn jwt_string_claim(token: &str, claim: &str) -> Option<String> {
+    parse_jwt_claims(token)?
+        .get(claim)?
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+fn jwt_expiry(token: &str) -> Option<i64> {
+    parse_jwt_claims(token)?.get("exp")?.as_i64()
+}
+
+/// Client an ID token was issued to. `aud` may be a list, whose first entry is the client.
+fn jwt_audience(token: &str) -> Option<String> {
+    let claims = parse_jwt_claims(token)?;
+    match claims.get("aud")? {
+        serde_json::Value::String(aud) => Some(aud.clone()),
+        serde_json::Value::Array(auds) => auds.first()?.as_str().map(|s| s.to_string()),
+        _ => None,
+    }
+}
+
+fn cli_name(provider: OAuthProvider) -> &'static str {
+    match provider {
+        OAuthProvider::Google => "Gemini CLI",
+        OAuthProvider::OpenAI => "Codex CLI",
+    }
+}
+
+/// OAuth client Ramble refreshes `provider`'s tokens with
+fn configured_client_id(provider: OAuthProvider) -> Result<String, String> {
+    match provider {
+        OAuthProvider::Google => google::client_id().map_err(|e| e.to_string()),
+        OAuthProvider::OpenAI => Ok(openai::client_id()),
+    }
+}
+
+/// Refuse tokens issued to another client, since refreshing them would fail
+fn check_client_id(provider: OAuthProvider, cli: &str, configured: &str) -> Result<(), String> {
+    if cli == configured {
+        return Ok(());
+    }
+    Err(format!(
+        "The {} signed in with OAuth client {}, but Ramble is set up with {}, so its tokens \
+         can't be refreshed here. Sign in with the button instead.",
+        cli_name(provider),
+        cli,
+        configured
+    ))
+}
+
+/// Parse Gemini CLI `oauth_creds.json`, returning the tokens and their client
+fn parse_gemini_creds(json: &str) -> Result<(StoredTokens, String), String> {
+    let creds: GeminiCreds =
+        serde_json::from_str(json).map_err(|e| format!("Invalid Gemini CLI credentials: {}", e))?;
+    if creds.refresh_token.is_empty() {
+        return Err("Gemini CLI credentials have no refresh token".to_string());
+    }
+
+    let client_id = creds
+        .id_token
+        .as_deref()
+        .and_then(jwt_audience)
+        .unwrap_or_else(|| GEMINI_CLI_CLIENT_ID.to_string());
+    let tokens = StoredTokens {
+        email: creds
+            .id_token
+            .as_deref()
+            .and_then(|t| jwt_string_claim(t, "email")),
+        access_token: creds.access_token,
+        refresh_token: creds.refresh_token,
+        // A missing expiry is treated as expired so the first use refreshes it
+        expires_at: creds.expiry_date.map(|ms| ms / 1000).unwrap_or(0),
+        chatgpt_account_id: None,
+    };
+    Ok((tokens, client_id))
+}
+
+/// Parse Codex CLI `auth.json`, returning the tokens and their client
+fn parse_codex_auth(json: &str) -> Result<(StoredTokens, String), String> {
+    let auth: CodexAuth =
+        serde_json::from_str(json).map_err(|e| format!("Invalid Codex CLI credentials: {}", e))?;
+    let tokens = auth
+        .tokens
+        .ok_or("Codex CLI is signed in with an API key, not a ChatGPT account")?;
+    if tokens.refresh_token.is_empty() {
+        return Err("Codex CLI credentials have no refresh token".to_string());
+    }
+
+    // Codex access tokens name their client; ID tokens only have it as the audience
+    let client_id = jwt_string_claim(&tokens.access_token, "client_id")
+        .or_else(|| tokens.id_token.as_deref().and_then(jwt_audience))
+        .unwrap_or_else(|| openai::DEFAULT_CLIENT_ID.to_string());
+    let tokens = StoredTokens {
+        email: tokens
+            .id_token
+            .as_deref()
+            .and_then(|t| jwt_string_claim(t, "email")),
+        expires_at: jwt_expiry(&tokens.access_token).unwrap_or(0),
+        chatgpt_account_id: tokens
+            .account_id
+            .or_else(|| extract_chatgpt_account_id(&tokens.access_token)),
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+    };
+    Ok((tokens, client_id))
+}
+
+fn credentials_path(provider: OAuthProvider, home: &Path) -> PathBuf {
+    match provider {
+        OAuthProvider::Google => home.join(".gemini").join("oauth_creds.json"),
+        OAuthProvider::OpenAI => std::env::var_os("CODEX_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".codex"))
+            .join("auth.json"),
+    }
+}
+
+/// Read a CLI's credentials for `provider`
+fn read_cli_tokens(provider: OAuthProvider, home: &Path) -> Result<CliTokens, String> {
+    let path = credentials_path(provider, home);
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let (mut tokens, client_id) = match provider {
+        OAuthProvider::Google => parse_gemini_creds(&json)?,
+        OAuthProvider::OpenAI => parse_codex_auth(&json)?,
+    };
+
+    if provider == OAuthProvider::Google && tokens.email.is_none() {
+        tokens.email = std::fs::read_to_string(home.join(".gemini").join("google_accounts.json"))
+            .ok()
+            .and_then(|json| serde_json::from_str::<GeminiAccounts>(&json).ok())
+            .and_then(|accounts| accounts.active);
+    }
+
+    Ok(CliTokens {
+        path,
+        tokens,
+        client_id,
+    })
+}
+
+/// Find importable CLI credentials for every OAuth provider
+pub fn detect(home: &Path) -> Vec<CliCredentials> {
+    [OAuthProvider::Google, OAuthProvider::OpenAI]
+        .into_iter()
+        .filter_map(|provider| match read_cli_tokens(provider, home) {
+            Ok(cli) => Some(CliCredentials {
+                provider,
+                source_path: cli.path.to_string_lossy().to_string(),
+                email: cli.tokens.email,
+                expires_at: cli.tokens.expires_at,
+                signs_out_cli: provider == OAuthProvider::OpenAI,
+            }),
+            Err(e) => {
+                log::debug!("No CLI credentials for {}: {}", provider.as_str(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Copy a CLI's credentials into the token store, refreshing them once so a sign-in
+/// that can't be refreshed is refused rather than failing on first use
+pub async fn import(provider: OAuthProvider, home: &Path) -> Result<StoredTokens, String> {
+    let cli = read_cli_tokens(provider, home)?;
+    check_client_id(provider, &cli.client_id, &configured_client_id(provider)?)?;
+
+    // Refreshing stores the new tokens
+    let refreshed = match provider {
+        OAuthProvider::Google => google::refresh_token(&cli.tokens.refresh_token).await,
+        OAuthProvider::OpenAI => openai::refresh_token(&cli.tokens.refresh_token).await,
+    };
+    let mut tokens = refreshed.map_err(|e| {
+        format!(
+            "The {} sign-in could not be refreshed ({}). Sign in with the button instead.",
+            cli_name(provider),
+            e
+        )
+    })?;
+    if tokens.email.is_none() && cli.tokens.email.is_some() {
+        tokens.email = cli.tokens.email;
+        store_tokens(provider, &tokens).map_err(|e| e.to_string())?;
+    }
+
+    log::info!(
+        "Imported {} OAuth tokens from {}",
+        provider.as_str(),
+        cli.path.display()
+    );
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+    fn jwt(claims: serde_json::Value) -> String {
+        format!(
+            "header.{}.signature",
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    #[test]
+    fn test_parse_gemini_creds() {
+        let json = serde_json::json!({
+            "access_token": "ya29.token",
+            "refresh_token": "1//refresh",
+            "scope": "openid",
+            "token_type": "Bearer",
+            "id_token": jwt(serde_json::json!({ "email": "me@example.com" })),
+            "expiry_date": 1_700_000_000_000i64,
+        });
+        let (tokens, client_id) = parse_gemini_creds(&json.to_string()).unwrap();
+        assert_eq!(client_id, GEMINI_CLI_CLIENT_ID);
+        assert_eq!(tokens.refresh_token, "1//refresh");
+        assert_eq!(tokens.expires_at, 1_700_000_000);
+        assert_eq!(tokens.email.as_deref(), Some("me@example.com"));
+    }
+
+    #[test]
+    fn test_parse_codex_auth() {
+        let access = jwt(serde_json::json!({
+            "exp": 1_700_000_000i64,
+            "client_id": "app_codex",
+        }));
+        let json = serde_json::json!({
+            "OPENAI_API_KEY": null,
+            "tokens": {
+                "access_token": access,
+                "refresh_token": "rt",
+                "account_id": "acct_123",
+            },
+        });
+        let (tokens, client_id) = parse_codex_auth(&json.to_string()).unwrap();
+        assert_eq!(client_id, "app_codex");
+        assert_eq!(tokens.expires_at, 1_700_000_000);
+        assert_eq!(tokens.chatgpt_account_id.as_deref(), Some("acct_123"));
+
+        let api_key_only = serde_json::json!({ "OPENAI_API_KEY": "sk-test" });
+        assert!(parse_codex_auth(&api_key_only.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_client_id_from_id_token_audience() {
+        let id_token = jwt(serde_json::json!({ "aud": ["app_other", "https://api"] }));
+        assert_eq!(jwt_audience(&id_token).as_deref(), Some("app_other"));
+
+        let json = serde_json::json!({
+            "access_token": "ya29.token",
+            "refresh_token": "1//refresh",
+            "id_token": jwt(serde_json::json!({ "aud": "123.apps.googleusercontent.com" })),
+        });
+        let (_, client_id) = parse_gemini_creds(&json.to_string()).unwrap();
+        assert_eq!(client_id, "123.apps.googleusercontent.com");
+    }
+
+    #[test]
+    fn test_check_client_id() {
+        assert!(check_client_id(OAuthProvider::OpenAI, "app_a", "app_a").is_ok());
+        let err = check_client_id(OAuthProvider::Google, "cli.apps", "mine.apps").unwrap_err();
+        assert!(err.contains("Gemini CLI") && err.contains("mine.apps"));
+    }
+}
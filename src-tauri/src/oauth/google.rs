@@ -37,7 +37,7 @@ fn get_project_cache() -> &'static Mutex<Option<String>> {
     CACHED_PROJECT_ID.get_or_init(|| Mutex::new(None))
 }
 
-fn client_id() -> Result<String, TokenError> {
+pub(super) fn client_id() -> Result<String, TokenError> {
     get_google_client_id()
 }
 
@@ -6,6 +6,7 @@
 //!
 //! Anthropic does not support OAuth and continues to use API keys only.
 
+pub mod cli_import;
 pub mod config;
 pub mod google;
 pub mod openai;
@@ -18,7 +18,7 @@ pub const AUTHORIZE_URL: &str = "https://auth.openai.com/oauth/authorize";
 pub const TOKEN_URL: &str = "https://auth.openai.com/oauth/token";
 pub const SCOPES: &str = "openid profile email offline_access";
 
-fn client_id() -> String {
+pub(super) fn client_id() -> String {
     match get_openai_client_id() {
         Ok(Some(value)) => value,
         Ok(None) => DEFAULT_CLIENT_ID.to_string(),
@@ -0,0 +1,272 @@
+//! First-run setup suggested from the applications installed on this computer.
+//!
+//! Kinds of apps that benefit from their own style (team chat, code editors, issue
+//! trackers, git clients) each get a category made from a starter template when one
+//! of them is installed and not mapped yet, and those apps are mapped to it. Other
+//! well-known apps are mapped to the category `known_apps` suggests for them, when
+//! that isn't the default one anyway.
+
+use crate::app_detection::InstalledApp;
+use crate::known_apps;
+use crate::settings::{self, AppCategoryMapping, AppSettings, PromptCategory};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A kind of app that gets its own category when one is installed
+struct Tailoring {
+    template_id: &'static str,
+    /// Bundle identifier prefixes (containing a dot) and app names, compared
+    /// case-insensitively
+    apps: &'static [&'static str],
+}
+
+const TAILORINGS: &[Tailoring] = &[
+    Tailoring {
+        template_id: "slack_reply",
+        apps: &[
+            "com.tinyspeck.slackmacgap",
+            "com.hnc.discord",
+            "com.microsoft.teams",
+            "slack",
+            "discord",
+            "microsoft teams",
+        ],
+    },
+    Tailoring {
+        template_id: "code_notes",
+        apps: &[
+            "com.apple.dt.xcode",
+            "com.microsoft.vscode",
+            "com.todesktop.230313mzl4w4u92",
+            "dev.zed.zed",
+            "com.jetbrains.",
+            "com.sublimehq.sublime-text",
+            "xcode",
+            "visual studio code",
+            "cursor",
+            "zed",
+        ],
+    },
+    Tailoring {
+        template_id: "bug_report",
+        apps: &["com.linear", "com.atlassian.jira", "linear", "jira"],
+    },
+    Tailoring {
+        template_id: "pr_description",
+        apps: &[
+            "com.github.githubclient",
+            "com.fournova.tower",
+            "com.danpristupov.fork",
+            "github desktop",
+            "tower",
+            "fork",
+        ],
+    },
+];
+
+/// Categories and app mappings suggested for this computer
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct SuggestedSetup {
+    /// New categories for the kinds of apps that are installed
+    pub categories: Vec<PromptCategory>,
+    /// Mappings for installed apps that aren't mapped yet
+    pub app_mappings: Vec<AppCategoryMapping>,
+}
+
+fn matches(app: &InstalledApp, patterns: &[&str]) -> bool {
+    let bundle_id = app.bundle_id.to_lowercase();
+    patterns.iter().any(|pattern| {
+        if pattern.contains('.') {
+            bundle_id.starts_with(pattern)
+        } else {
+            app.name.eq_ignore_ascii_case(pattern)
+        }
+    })
+}
+
+/// Suggest categories and mappings for the `installed` apps, leaving out anything
+/// `settings` already has
+pub fn suggest(settings: &AppSettings, installed: &[InstalledApp]) -> SuggestedSetup {
+    let templates = settings::prompt_category_templates();
+    let mut setup = SuggestedSetup {
+        categories: Vec::new(),
+        app_mappings: Vec::new(),
+    };
+    let is_mapped = |setup: &SuggestedSetup, bundle_id: &str| {
+        settings
+            .app_category_mappings
+            .iter()
+            .chain(&setup.app_mappings)
+            .any(|m| m.bundle_identifier == bundle_id)
+    };
+
+    for tailoring in TAILORINGS {
+        let apps: Vec<&InstalledApp> = installed
+            .iter()
+            .filter(|app| matches(app, tailoring.apps) && !is_mapped(&setup, &app.bundle_id))
+            .collect();
+        if apps.is_empty() {
+            continue;
+        }
+        let Some(template) = templates.iter().find(|t| t.id == tailoring.template_id) else {
+            continue;
+        };
+
+        // A category already made from this template is reused rather than doubled
+        let category_id = match settings
+            .prompt_categories
+            .iter()
+            .find(|c| c.name == template.name)
+        {
+            Some(existing) => existing.id.clone(),
+            None => {
+                let category = PromptCategory {
+                    id: crate::shortcut::unique_category_id(settings, &template.name),
+                    name: template.name.clone(),
+                    icon: template.icon.clone(),
+                    prompt: template.prompt.clone(),
+                    is_builtin: false,
+                    model_override: None,
+                    language: None,
+                    chaptering: false,
+                };
+                let id = category.id.clone();
+                setup.categories.push(category);
+                id
+            }
+        };
+
+        for app in apps {
+            setup.app_mappings.push(AppCategoryMapping {
+                bundle_identifier: app.bundle_id.clone(),
+                display_name: app.name.clone(),
+                category_id: category_id.clone(),
+            });
+        }
+    }
+
+    for app in installed {
+        let Some(known) = known_apps::find_known_app(&app.bundle_id) else {
+            continue;
+        };
+        if known.suggested_category == settings.default_category_id
+            || !settings
+                .prompt_categories
+                .iter()
+                .any(|c| c.id == known.suggested_category)
+            || is_mapped(&setup, &app.bundle_id)
+        {
+            continue;
+        }
+        setup.app_mappings.push(AppCategoryMapping {
+            bundle_identifier: app.bundle_id.clone(),
+            display_name: app.name.clone(),
+            category_id: known.suggested_category,
+        });
+    }
+
+    setup
+}
+
+/// Add `setup` to `settings`. Categories whose id is taken and mappings for apps
+/// that are already mapped, or to categories that don't exist, are skipped. Returns
+/// how many categories and mappings were added.
+pub fn apply(settings: &mut AppSettings, setup: SuggestedSetup) -> (usize, usize) {
+    let mut categories = 0;
+    for category in setup.categories {
+        if settings
+            .prompt_categories
+            .iter()
+            .any(|c| c.id == category.id)
+        {
+            continue;
+        }
+        settings.prompt_categories.push(category);
+        categories += 1;
+    }
+
+    let mut mappings = 0;
+    for mapping in setup.app_mappings {
+        let category_exists = settings
+            .prompt_categories
+            .iter()
+            .any(|c| c.id == mapping.category_id);
+        let already_mapped = settings
+            .app_category_mappings
+            .iter()
+            .any(|m| m.bundle_identifier == mapping.bundle_identifier);
+        if !category_exists || already_mapped {
+            continue;
+        }
+        settings.app_category_mappings.push(mapping);
+        mappings += 1;
+    }
+    (categories, mappings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn installed(apps: &[(&str, &str)]) -> Vec<InstalledApp> {
+        apps.iter()
+            .map(|(bundle_id, name)| InstalledApp {
+                bundle_id: bundle_id.to_string(),
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_suggest_tailors_categories_to_installed_apps() {
+        let settings = get_default_settings();
+        let apps = installed(&[
+            ("com.apple.dt.Xcode", "Xcode"),
+            ("com.tinyspeck.slackmacgap", "Slack"),
+            ("com.jetbrains.goland", "GoLand"),
+            ("com.apple.Safari", "Safari"),
+        ]);
+        let setup = suggest(&settings, &apps);
+
+        let names: Vec<&str> = setup.categories.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Slack reply", "Code notes"]);
+        let category_of = |bundle_id: &str| {
+            setup
+                .app_mappings
+                .iter()
+                .find(|m| m.bundle_identifier == bundle_id)
+                .map(|m| m.category_id.as_str())
+        };
+        assert_eq!(
+            category_of("com.tinyspeck.slackmacgap"),
+            Some("slack_reply")
+        );
+        assert_eq!(category_of("com.apple.dt.Xcode"), Some("code_notes"));
+        assert_eq!(category_of("com.jetbrains.goland"), Some("code_notes"));
+    }
+
+    #[test]
+    fn test_suggest_skips_what_exists() {
+        let mut settings = get_default_settings();
+        let apps = installed(&[
+            ("com.linear", "Linear"),
+            ("com.tinyspeck.slackmacgap", "Slack"),
+        ]);
+        settings.app_category_mappings.push(AppCategoryMapping {
+            bundle_identifier: "com.tinyspeck.slackmacgap".to_string(),
+            display_name: "Slack".to_string(),
+            category_id: "low".to_string(),
+        });
+
+        let setup = suggest(&settings, &apps);
+        let names: Vec<&str> = setup.categories.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Bug report"]);
+        assert_eq!(apply(&mut settings, setup), (1, 1));
+
+        // Applying again adds nothing
+        let setup = suggest(&settings, &apps);
+        assert!(setup.categories.is_empty() && setup.app_mappings.is_empty());
+        assert_eq!(apply(&mut settings, setup), (0, 0));
+    }
+}
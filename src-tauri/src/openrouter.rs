@@ -0,0 +1,116 @@
+//! OpenRouter-specific request fields: fallback models and provider preferences,
+//! which OpenRouter reads from the chat completion body.
+
+use serde_json::{json, Map, Value};
+
+use crate::settings::{LLMModel, LLMProvider, OpenRouterRouting};
+
+const SORT_OPTIONS: &[&str] = &["price", "throughput", "latency"];
+
+pub fn is_openrouter(provider: &LLMProvider) -> bool {
+    provider.id == "openrouter" || provider.base_url.contains("openrouter.ai")
+}
+
+/// Reject sort values OpenRouter doesn't know, which it would answer with a 400
+pub fn validate(routing: &OpenRouterRouting) -> Result<(), String> {
+    match routing.sort.as_deref() {
+        Some(sort) if !SORT_OPTIONS.contains(&sort) => Err(format!(
+            "Unknown sort '{}', expected one of: {}",
+            sort,
+            SORT_OPTIONS.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Body fields for OpenRouter's `models` fallback list and `provider` preferences
+pub fn routing_fields(model_id: &str, routing: &OpenRouterRouting) -> Map<String, Value> {
+    let mut fields = Map::new();
+
+    let fallbacks: Vec<&str> = routing
+        .fallback_models
+        .iter()
+        .map(|m| m.trim())
+        .filter(|m| !m.is_empty() && *m != model_id)
+        .collect();
+    if !fallbacks.is_empty() {
+        // OpenRouter tries `models` in order, so the primary model goes first
+        let models: Vec<&str> = std::iter::once(model_id).chain(fallbacks).collect();
+        fields.insert("models".to_string(), json!(models));
+    }
+
+    let mut provider = Map::new();
+    let order: Vec<&str> = routing
+        .provider_order
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if !order.is_empty() {
+        provider.insert("order".to_string(), json!(order));
+    }
+    if !routing.allow_fallbacks {
+        provider.insert("allow_fallbacks".to_string(), json!(false));
+    }
+    if let Some(sort) = &routing.sort {
+        provider.insert("sort".to_string(), json!(sort));
+    }
+    if !provider.is_empty() {
+        fields.insert("provider".to_string(), Value::Object(provider));
+    }
+
+    fields
+}
+
+/// Extra body fields to send for `model`, empty unless it's an OpenRouter model
+/// with routing options
+pub fn extra_body_fields(provider: &LLMProvider, model: &LLMModel) -> Map<String, Value> {
+    match &model.openrouter_routing {
+        Some(routing) if is_openrouter(provider) => routing_fields(&model.model_id, routing),
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routing_fields() {
+        let routing = OpenRouterRouting {
+            fallback_models: vec![
+                "anthropic/claude-3.5-haiku".to_string(),
+                " ".to_string(),
+                "openai/gpt-4o-mini".to_string(),
+            ],
+            provider_order: vec!["Together".to_string()],
+            allow_fallbacks: false,
+            sort: Some("throughput".to_string()),
+        };
+        let fields = routing_fields("openai/gpt-4o-mini", &routing);
+        assert_eq!(
+            Value::Object(fields),
+            json!({
+                "models": ["openai/gpt-4o-mini", "anthropic/claude-3.5-haiku"],
+                "provider": {
+                    "order": ["Together"],
+                    "allow_fallbacks": false,
+                    "sort": "throughput"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_default_routing_adds_nothing() {
+        assert!(routing_fields("openai/gpt-4o", &OpenRouterRouting::default()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_sort() {
+        let mut routing = OpenRouterRouting::default();
+        assert!(validate(&routing).is_ok());
+        routing.sort = Some("cheapest".to_string());
+        assert!(validate(&routing).is_err());
+    }
+}
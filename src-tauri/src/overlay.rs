@@ -1,8 +1,11 @@
+use crate::events::{CategoryDetected, ModeDetermined, RecordingMode};
 use crate::input;
+use crate::presentation;
 use crate::settings;
 use crate::settings::{OverlayPosition, PromptMode};
 use crate::{app_detection, known_apps};
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize};
+use tauri_specta::Event;
 
 #[cfg(not(target_os = "macos"))]
 use log::debug;
@@ -141,7 +144,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
             "recording_overlay",
             tauri::WebviewUrl::App("src/overlay/index.html".into()),
         )
-        .title("Recording")
+        .title(crate::i18n::overlay_strings(app_handle).window_title)
         .position(x, y)
         .resizable(false)
         .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
@@ -176,7 +179,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
         // The window remains registered, so get_webview_window() still works.
         match PanelBuilder::<_, RecordingOverlayPanel>::new(app_handle, "recording_overlay")
             .url(WebviewUrl::App("src/overlay/index.html".into()))
-            .title("Recording")
+            .title(crate::i18n::overlay_strings(app_handle).window_title)
             .position(tauri::Position::Logical(tauri::LogicalPosition { x, y }))
             .level(PanelLevel::Status)
             .size(tauri::Size::Logical(tauri::LogicalSize {
@@ -205,11 +208,20 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// In focus mode only recording gets an overlay (a minimal indicator); progress
+/// states hide it instead. Errors still show, so failed pastes aren't missed.
+fn hidden_by_focus_mode(app_handle: &AppHandle, settings: &settings::AppSettings) -> bool {
+    if settings.focus_mode {
+        hide_recording_overlay(app_handle);
+    }
+    settings.focus_mode
+}
+
 /// Shows the recording overlay window with fade-in animation
 pub fn show_recording_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -227,7 +239,12 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to trigger fade-in animation with recording state
-        let _ = overlay_window.emit("show-overlay", "recording");
+        let state = if settings.focus_mode {
+            "focus_recording"
+        } else {
+            "recording"
+        };
+        let _ = overlay_window.emit("show-overlay", state);
     }
 }
 
@@ -235,7 +252,7 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
 pub fn show_ramble_recording_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -253,7 +270,12 @@ pub fn show_ramble_recording_overlay(app_handle: &AppHandle) {
         force_overlay_topmost(&overlay_window);
 
         // Emit event to trigger fade-in animation with ramble_recording state
-        let _ = overlay_window.emit("show-overlay", "ramble_recording");
+        let state = if settings.focus_mode {
+            "focus_recording"
+        } else {
+            "ramble_recording"
+        };
+        let _ = overlay_window.emit("show-overlay", state);
     }
 }
 
@@ -261,7 +283,7 @@ pub fn show_ramble_recording_overlay(app_handle: &AppHandle) {
 pub fn show_voice_command_recording_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -287,7 +309,7 @@ pub fn show_voice_command_recording_overlay(app_handle: &AppHandle) {
 pub fn show_context_chat_recording_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -313,7 +335,10 @@ pub fn show_context_chat_recording_overlay(app_handle: &AppHandle) {
 pub fn show_transcribing_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None
+        || hidden_by_focus_mode(app_handle, &settings)
+        || presentation::suppress_overlay()
+    {
         return;
     }
 
@@ -335,7 +360,7 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
 pub fn show_voice_command_transcribing_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -357,7 +382,7 @@ pub fn show_voice_command_transcribing_overlay(app_handle: &AppHandle) {
 pub fn show_context_chat_processing_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -379,7 +404,10 @@ pub fn show_context_chat_processing_overlay(app_handle: &AppHandle) {
 pub fn show_making_coherent_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None
+        || hidden_by_focus_mode(app_handle, &settings)
+        || presentation::suppress_overlay()
+    {
         return;
     }
 
@@ -401,7 +429,10 @@ pub fn show_making_coherent_overlay(app_handle: &AppHandle) {
 pub fn show_speaking_overlay(app_handle: &AppHandle) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None
+        || hidden_by_focus_mode(app_handle, &settings)
+        || presentation::suppress_overlay()
+    {
         return;
     }
 
@@ -423,7 +454,7 @@ pub fn show_speaking_overlay(app_handle: &AppHandle) {
 pub fn show_paused_overlay(app_handle: &AppHandle, is_ramble: bool) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -442,11 +473,45 @@ pub fn show_paused_overlay(app_handle: &AppHandle, is_ramble: bool) {
     }
 }
 
+/// Briefly tells the user a recording was cancelled because no speech was heard
+pub fn show_no_speech_overlay(app_handle: &AppHandle) {
+    let settings = settings::get_settings(app_handle);
+    if settings.overlay_position == OverlayPosition::None
+        || hidden_by_focus_mode(app_handle, &settings)
+        || presentation::suppress_overlay()
+    {
+        return;
+    }
+
+    update_overlay_position(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.show();
+
+        #[cfg(target_os = "windows")]
+        force_overlay_topmost(&overlay_window);
+
+        let _ = overlay_window.emit("show-overlay", "no_speech");
+    }
+
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(2000));
+        // Don't hide a recording that was started in the meantime
+        let recording = app_handle
+            .try_state::<std::sync::Arc<crate::managers::audio::AudioRecordingManager>>()
+            .is_some_and(|m| m.is_recording());
+        if !recording {
+            hide_recording_overlay(&app_handle);
+        }
+    });
+}
+
 /// Shows an error overlay with a message that the user must dismiss
 pub fn show_error_overlay(app_handle: &AppHandle, error_message: &str, is_voice_command: bool) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if settings.overlay_position == OverlayPosition::None || presentation::suppress_overlay() {
         return;
     }
 
@@ -489,6 +554,7 @@ pub fn update_overlay_position(app_handle: &AppHandle) {
 pub fn hide_recording_overlay(app_handle: &AppHandle) {
     // Always hide the overlay regardless of settings - if setting was changed while recording,
     // we still want to hide it properly
+    presentation::forget_overlay();
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         // Emit event to trigger fade-out animation
         let _ = overlay_window.emit("hide-overlay", ());
@@ -540,15 +606,15 @@ pub fn log_to_frontend(app_handle: &AppHandle, level: &str, message: &str) {
     }
 }
 
-/// Emits mode-determined event to the overlay to show/hide pause button
-/// mode should be "quick_press" for toggle mode (pause button shown)
-pub fn emit_mode_determined(app_handle: &AppHandle, mode: &str) {
+/// Tells the overlay how the recording was started, so it can show the pause button
+/// for a quick press
+pub fn emit_mode_determined(app_handle: &AppHandle, mode: RecordingMode) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
-        let _ = overlay_window.emit("mode-determined", mode);
+        let _ = ModeDetermined(mode).emit(&overlay_window);
 
         // When entering "refining" mode, also detect and emit the category
         // so the overlay can show the correct icon
-        if mode == "refining" {
+        if mode == RecordingMode::Refining {
             let settings = settings::get_settings(app_handle);
             let category_id = match settings.prompt_mode {
                 PromptMode::Dynamic => {
@@ -574,7 +640,7 @@ pub fn emit_mode_determined(app_handle: &AppHandle, mode: &str) {
                 PromptMode::Medium => "medium".to_string(),
                 PromptMode::High => "high".to_string(),
             };
-            let _ = overlay_window.emit("category-detected", &category_id);
+            let _ = CategoryDetected(category_id).emit(&overlay_window);
         }
     }
 }
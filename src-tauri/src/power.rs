@@ -0,0 +1,326 @@
+//! Battery saver for laptops.
+//!
+//! A background task checks the battery every minute. Once the laptop is on battery
+//! at or below `AppSettings::battery_saver_threshold` (and `battery_saver_enabled` is
+//! on), the always-on microphone is closed, transcription switches to the smallest
+//! downloaded local model and the model unload timeout is lengthened so the model
+//! isn't reloaded as often. None of this is written to the settings, so everything
+//! goes back once the laptop is plugged in or charged past the threshold. The UI is
+//! told through `BatterySaverChanged`.
+
+use crate::errors::AppError;
+use crate::events::BatterySaverChanged;
+use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
+use crate::managers::model::ModelManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{self, AppSettings, ModelUnloadTimeout};
+use log::{debug, error, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// How often the battery is checked
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Percentage points the battery must charge past the threshold before the saver
+/// turns off while still unplugged, so it doesn't flap around the threshold
+const HYSTERESIS_PERCENT: u8 = 5;
+
+/// Whether the battery saver is currently active
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Smaller model used for transcription while the saver is active
+static MODEL_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BatteryStatus {
+    percent: u8,
+    on_battery: bool,
+}
+
+/// Whether the battery saver is currently active
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Whether the microphone should stay open between recordings
+pub fn always_on_microphone(settings: &AppSettings) -> bool {
+    settings.always_on_microphone && !is_active()
+}
+
+/// Model to load for transcription, a smaller one while the saver is active
+pub fn transcription_model(settings: &AppSettings) -> String {
+    MODEL_OVERRIDE
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| settings.selected_model.clone())
+}
+
+/// How long the model stays loaded when idle, longer while the saver is active
+pub fn model_unload_timeout(settings: &AppSettings) -> ModelUnloadTimeout {
+    if is_active() {
+        settings.model_unload_timeout.lengthened()
+    } else {
+        settings.model_unload_timeout
+    }
+}
+
+/// Called when the user picks a model, which then wins over the saver's choice
+pub fn clear_model_override() {
+    MODEL_OVERRIDE.lock().unwrap().take();
+}
+
+/// Whether the saver should be on for this battery state. Once on, it stays on
+/// until the battery has charged a little past the threshold.
+fn should_save(status: BatteryStatus, threshold: u8, active: bool) -> bool {
+    if !status.on_battery {
+        return false;
+    }
+    let limit = if active {
+        threshold.saturating_add(HYSTERESIS_PERCENT)
+    } else {
+        threshold
+    };
+    status.percent <= limit
+}
+
+/// Parse `pmset -g batt`, e.g.
+/// "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1)\t18%; discharging; ..."
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_pmset(output: &str) -> Option<BatteryStatus> {
+    let line = output.lines().find(|l| l.contains("InternalBattery"))?;
+    let percent = line
+        .split(|c: char| c.is_whitespace() || c == ';')
+        .find_map(|token| token.strip_suffix('%')?.parse().ok())?;
+    Some(BatteryStatus {
+        percent,
+        on_battery: output.contains("'Battery Power'"),
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn read_battery() -> Option<BatteryStatus> {
+    let output = std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .ok()?;
+    parse_pmset(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(target_os = "linux")]
+fn read_battery() -> Option<BatteryStatus> {
+    let supplies = std::fs::read_dir("/sys/class/power_supply").ok()?;
+    supplies.flatten().find_map(|supply| {
+        let read = |name: &str| std::fs::read_to_string(supply.path().join(name)).ok();
+        if read("type")?.trim() != "Battery" {
+            return None;
+        }
+        Some(BatteryStatus {
+            percent: read("capacity")?.trim().parse().ok()?,
+            on_battery: read("status")?.trim() == "Discharging",
+        })
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn read_battery() -> Option<BatteryStatus> {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+    unsafe { GetSystemPowerStatus(&mut status) }.ok()?;
+    // 128 means there's no battery, 255 that its charge is unknown
+    if status.BatteryFlag & 128 != 0 || status.BatteryLifePercent > 100 {
+        return None;
+    }
+    Some(BatteryStatus {
+        percent: status.BatteryLifePercent,
+        on_battery: status.ACLineStatus == 0,
+    })
+}
+
+/// The smallest downloaded local model that's smaller than the selected one
+fn smaller_model(app: &AppHandle, settings: &AppSettings) -> Option<String> {
+    let model_manager = app.state::<Arc<ModelManager>>();
+    let selected = model_manager.get_model_info(&settings.selected_model)?;
+    if selected.engine_type.cloud_service().is_some() {
+        // Cloud transcription already costs the laptop next to nothing
+        return None;
+    }
+    model_manager
+        .get_available_models()
+        .into_iter()
+        .filter(|m| m.is_downloaded && m.engine_type.cloud_service().is_none())
+        .filter(|m| m.size_mb > 0 && m.size_mb < selected.size_mb)
+        .min_by_key(|m| m.size_mb)
+        .map(|m| m.id)
+}
+
+/// Load `model_id` in the background if a different model is loaded now. An
+/// unloaded model is left alone, it's loaded with `transcription_model` when needed.
+fn swap_loaded_model(app: &AppHandle, model_id: String) {
+    let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
+    if !tm.is_model_loaded() || tm.get_current_model().as_deref() == Some(model_id.as_str()) {
+        return;
+    }
+    std::thread::spawn(move || {
+        if let Err(e) = tm.load_model(&model_id) {
+            error!("Failed to switch to model {}: {}", model_id, e);
+        }
+    });
+}
+
+fn set_microphone_mode(app: &AppHandle, mode: MicrophoneMode) {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    if let Err(e) = rm.update_mode(mode) {
+        error!("Failed to update microphone mode: {}", e);
+    }
+}
+
+fn activate(app: &AppHandle, settings: &AppSettings, status: BatteryStatus) {
+    info!("Battery at {}%, turning on battery saver", status.percent);
+    ACTIVE.store(true, Ordering::SeqCst);
+
+    if settings.always_on_microphone {
+        set_microphone_mode(app, MicrophoneMode::OnDemand);
+    }
+    if let Some(model_id) = smaller_model(app, settings) {
+        info!("Battery saver transcribes with {}", model_id);
+        *MODEL_OVERRIDE.lock().unwrap() = Some(model_id.clone());
+        swap_loaded_model(app, model_id);
+    }
+
+    let _ = BatterySaverChanged {
+        active: true,
+        battery_percent: Some(status.percent),
+    }
+    .emit(app);
+}
+
+fn deactivate(app: &AppHandle, settings: &AppSettings, status: Option<BatteryStatus>) {
+    info!("Turning off battery saver");
+    ACTIVE.store(false, Ordering::SeqCst);
+
+    if settings.always_on_microphone {
+        set_microphone_mode(app, MicrophoneMode::AlwaysOn);
+    }
+    if MODEL_OVERRIDE.lock().unwrap().take().is_some() {
+        swap_loaded_model(app, settings.selected_model.clone());
+    }
+
+    let _ = BatterySaverChanged {
+        active: false,
+        battery_percent: status.map(|s| s.percent),
+    }
+    .emit(app);
+}
+
+/// Check the battery and turn the saver on or off. Also called right after its
+/// settings change.
+pub fn check(app: &AppHandle) {
+    let settings = settings::get_settings(app);
+    let status = read_battery();
+    debug!("Battery status: {:?}", status);
+
+    let active = is_active();
+    let save = settings.battery_saver_enabled
+        && status.is_some_and(|s| should_save(s, settings.battery_saver_threshold, active));
+    match (save, status) {
+        (true, Some(status)) if !active => activate(app, &settings, status),
+        (false, status) if active => deactivate(app, &settings, status),
+        _ => {}
+    }
+}
+
+/// Whether the battery saver is on right now, for the settings page
+#[tauri::command]
+#[specta::specta]
+pub fn is_battery_saver_active() -> Result<bool, AppError> {
+    Ok(is_active())
+}
+
+pub fn start_watcher(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let check_app = app.clone();
+            // pmset is a separate process, so don't block the async runtime on it
+            let _ = tokio::task::spawn_blocking(move || check(&check_app)).await;
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+    info!("Started battery watcher");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pmset() {
+        let on_battery = "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=4653155)\t18%; discharging; 1:02 remaining present: true\n";
+        assert_eq!(
+            parse_pmset(on_battery),
+            Some(BatteryStatus {
+                percent: 18,
+                on_battery: true
+            })
+        );
+
+        let charging = "Now drawing from 'AC Power'\n -InternalBattery-0 (id=4653155)\t100%; charged; 0:00 remaining present: true\n";
+        assert_eq!(
+            parse_pmset(charging),
+            Some(BatteryStatus {
+                percent: 100,
+                on_battery: false
+            })
+        );
+
+        // Desktops have no internal battery
+        assert_eq!(parse_pmset("Now drawing from 'AC Power'\n"), None);
+    }
+
+    #[test]
+    fn test_should_save() {
+        let battery = |percent| BatteryStatus {
+            percent,
+            on_battery: true,
+        };
+        assert!(should_save(battery(20), 20, false));
+        assert!(!should_save(battery(21), 20, false));
+        // Stays on until charged past the threshold by a margin
+        assert!(should_save(battery(24), 20, true));
+        assert!(!should_save(battery(26), 20, true));
+        // Plugged in
+        assert!(!should_save(
+            BatteryStatus {
+                percent: 5,
+                on_battery: false
+            },
+            20,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_lengthened_unload_timeout() {
+        assert_eq!(
+            ModelUnloadTimeout::Immediately.lengthened(),
+            ModelUnloadTimeout::Min2
+        );
+        assert_eq!(
+            ModelUnloadTimeout::Min5.lengthened(),
+            ModelUnloadTimeout::Min10
+        );
+        assert_eq!(
+            ModelUnloadTimeout::Hour1.lengthened(),
+            ModelUnloadTimeout::Hour1
+        );
+        assert_eq!(
+            ModelUnloadTimeout::Never.lengthened(),
+            ModelUnloadTimeout::Never
+        );
+    }
+}
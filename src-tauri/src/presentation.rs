@@ -0,0 +1,214 @@
+//! Hiding Ramble's windows while the user is presenting.
+//!
+//! A background task checks every couple of seconds whether another app is full
+//! screen or the screen is being shared. While that's the case (and
+//! `AppSettings::hide_when_presenting` is on) the recording overlay and chat windows
+//! are hidden, and shown again once the presentation ends. There's no portable API
+//! for screen sharing, so it's recognized by the indicator windows that Zoom, Teams,
+//! browsers and others put up while sharing.
+
+use crate::settings;
+use log::{debug, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// How often full-screen apps and screen sharing are checked for
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Lowercase fragments of window titles shown only while sharing the screen
+const SCREEN_SHARE_TITLES: &[&str] = &[
+    "is sharing your screen",
+    "is sharing a window",
+    "is sharing this tab",
+    "zoom share toolbar",
+    "zoom share statusbar",
+    "sharing control bar",
+    "you are screen sharing",
+    "screen sharing meeting controls",
+];
+
+/// Whether the windows are currently hidden for a presentation
+static PRESENTING: AtomicBool = AtomicBool::new(false);
+
+/// Whether the overlay was wanted while hidden, so it's shown again afterwards
+static OVERLAY_SUPPRESSED: AtomicBool = AtomicBool::new(false);
+
+/// Chat windows hidden for the presentation
+static HIDDEN_CHATS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Why the windows should be hidden
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Presentation {
+    FullScreen,
+    ScreenSharing,
+}
+
+/// Called by the overlay before it shows: while presenting the overlay stays hidden
+/// and is remembered, so it comes back when the presentation ends
+pub fn suppress_overlay() -> bool {
+    let presenting = PRESENTING.load(Ordering::SeqCst);
+    if presenting {
+        OVERLAY_SUPPRESSED.store(true, Ordering::SeqCst);
+    }
+    presenting
+}
+
+/// Called when the overlay is hidden for good, so it isn't brought back
+pub fn forget_overlay() {
+    OVERLAY_SUPPRESSED.store(false, Ordering::SeqCst);
+}
+
+/// Whether a window title belongs to a screen sharing indicator
+fn is_screen_share_title(title: &str) -> bool {
+    let title = title.to_lowercase();
+    SCREEN_SHARE_TITLES
+        .iter()
+        .any(|fragment| title.contains(fragment))
+}
+
+/// Whether a window at (x, y) of the given size covers the whole monitor, menu bar
+/// and taskbar included, unlike a maximized window
+fn covers_monitor(window: (i32, i32, u32, u32), monitor: (i32, i32, u32, u32)) -> bool {
+    let (wx, wy, ww, wh) = window;
+    let (mx, my, mw, mh) = monitor;
+    ww > 0
+        && wh > 0
+        && wx <= mx
+        && wy <= my
+        && wx as i64 + ww as i64 >= mx as i64 + mw as i64
+        && wy as i64 + wh as i64 >= my as i64 + mh as i64
+}
+
+/// Look at the other apps' windows for a sharing indicator or a full-screen window
+fn detect() -> Option<Presentation> {
+    let windows = match xcap::Window::all() {
+        Ok(windows) => windows,
+        Err(e) => {
+            debug!("Failed to list windows: {}", e);
+            return None;
+        }
+    };
+    let own_pid = std::process::id();
+    let others: Vec<_> = windows
+        .into_iter()
+        .filter(|w| w.pid().is_ok_and(|pid| pid != own_pid))
+        .filter(|w| !w.is_minimized().unwrap_or(false))
+        .collect();
+
+    if others
+        .iter()
+        .any(|w| w.title().is_ok_and(|title| is_screen_share_title(&title)))
+    {
+        return Some(Presentation::ScreenSharing);
+    }
+
+    let focused = others.iter().find(|w| w.is_focused().unwrap_or(false))?;
+    let monitor = focused.current_monitor().ok()?;
+    let window_rect = (
+        focused.x().ok()?,
+        focused.y().ok()?,
+        focused.width().ok()?,
+        focused.height().ok()?,
+    );
+    let monitor_rect = (
+        monitor.x().ok()?,
+        monitor.y().ok()?,
+        monitor.width().ok()?,
+        monitor.height().ok()?,
+    );
+    covers_monitor(window_rect, monitor_rect).then_some(Presentation::FullScreen)
+}
+
+fn hide_windows(app: &AppHandle, reason: Presentation) {
+    info!("Hiding overlay and chat windows ({:?} detected)", reason);
+    PRESENTING.store(true, Ordering::SeqCst);
+
+    if let Some(overlay) = app.get_webview_window("recording_overlay") {
+        if overlay.is_visible().unwrap_or(false) {
+            OVERLAY_SUPPRESSED.store(true, Ordering::SeqCst);
+            let _ = overlay.hide();
+        }
+    }
+
+    let mut hidden = HIDDEN_CHATS.lock().unwrap();
+    for (label, window) in app.webview_windows() {
+        if label.starts_with("chat_") && window.is_visible().unwrap_or(false) {
+            let _ = window.hide();
+            hidden.push(label);
+        }
+    }
+}
+
+fn restore_windows(app: &AppHandle) {
+    info!("Presentation ended, restoring hidden windows");
+    PRESENTING.store(false, Ordering::SeqCst);
+
+    if OVERLAY_SUPPRESSED.swap(false, Ordering::SeqCst) {
+        crate::overlay::set_overlay_visibility(app, true);
+    }
+
+    // Chats stay hidden if focus mode was turned on in the meantime
+    let focus_mode = settings::get_settings(app).focus_mode;
+    for label in HIDDEN_CHATS.lock().unwrap().drain(..) {
+        if let Some(window) = app.get_webview_window(&label) {
+            if !focus_mode {
+                let _ = window.show();
+            }
+        }
+    }
+}
+
+fn check(app: &AppHandle) {
+    let presentation = if settings::get_settings(app).hide_when_presenting {
+        detect()
+    } else {
+        None
+    };
+    let presenting = PRESENTING.load(Ordering::SeqCst);
+    match presentation {
+        Some(reason) if !presenting => hide_windows(app, reason),
+        None if presenting => restore_windows(app),
+        _ => {}
+    }
+}
+
+pub fn start_watcher(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let check_app = app.clone();
+            // Listing windows goes through the platform's window server, which can block
+            let _ = tokio::task::spawn_blocking(move || check(&check_app)).await;
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+    info!("Started presentation watcher");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_screen_share_title() {
+        assert!(is_screen_share_title(
+            "meet.google.com is sharing your screen."
+        ));
+        assert!(is_screen_share_title("Zoom Share Toolbar Window"));
+        assert!(!is_screen_share_title("Screen Sharing Tips - Notes"));
+        assert!(!is_screen_share_title("Inbox - Mail"));
+    }
+
+    #[test]
+    fn test_covers_monitor() {
+        let monitor = (0, 0, 2560, 1440);
+        assert!(covers_monitor((0, 0, 2560, 1440), monitor));
+        // Maximized below the menu bar
+        assert!(!covers_monitor((0, 25, 2560, 1415), monitor));
+        // Full screen on a second monitor
+        assert!(covers_monitor((2560, 0, 1920, 1080), (2560, 0, 1920, 1080)));
+        assert!(!covers_monitor((0, 0, 0, 0), monitor));
+    }
+}
@@ -0,0 +1,257 @@
+//! Model pricing and spend limits for cloud LLM calls.
+//!
+//! Costs are estimates: prices come from the model's own `*_price_per_million` fields when
+//! set, otherwise from a built-in table of common models. Providers on this machine,
+//! those the user marked free and OAuth (subscription) providers are treated as free.
+
+use crate::settings::{AppSettings, AuthMethod, LLMModel, LLMProvider};
+use serde::Serialize;
+use specta::Type;
+
+/// USD per million tokens
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl ModelPricing {
+    const FREE: ModelPricing = ModelPricing {
+        input_per_million: 0.0,
+        output_per_million: 0.0,
+    };
+
+    /// Cost in USD of a call with the given token counts
+    pub fn cost(&self, prompt_tokens: u32, completion_tokens: u32) -> f64 {
+        (prompt_tokens as f64 * self.input_per_million
+            + completion_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// Built-in prices, matched as a substring of the model identifier. More specific names
+/// come first so "gpt-4o-mini" isn't priced as "gpt-4o".
+const KNOWN_PRICING: &[(&str, f64, f64)] = &[
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-5-nano", 0.05, 0.40),
+    ("gpt-5-mini", 0.25, 2.00),
+    ("gpt-5", 1.25, 10.00),
+    ("o4-mini", 1.10, 4.40),
+    ("o3-mini", 1.10, 4.40),
+    ("claude-3-5-haiku", 0.80, 4.00),
+    ("claude-haiku-4", 1.00, 5.00),
+    ("claude-3-7-sonnet", 3.00, 15.00),
+    ("claude-sonnet-4", 3.00, 15.00),
+    ("claude-opus-4", 15.00, 75.00),
+    ("gemini-2.0-flash-lite", 0.075, 0.30),
+    ("gemini-2.0-flash", 0.10, 0.40),
+    ("gemini-2.5-flash-lite", 0.10, 0.40),
+    ("gemini-2.5-flash", 0.30, 2.50),
+    ("gemini-2.5-pro", 1.25, 10.00),
+];
+
+/// Whether a provider runs on this machine. Other private addresses may well be a
+/// paid gateway, so those are only free when the user marks the provider so.
+pub(crate) fn is_loopback_endpoint(base_url: &str) -> bool {
+    let authority = base_url
+        .split("://")
+        .nth(1)
+        .unwrap_or(base_url)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let host = match authority.strip_prefix('[') {
+        // IPv6 literal, e.g. `[::1]:8080`
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Price a model, or None if it isn't known and the user hasn't entered a price
+pub fn pricing_for(provider: &LLMProvider, model: &LLMModel) -> Option<ModelPricing> {
+    if provider.free
+        || provider.auth_method == AuthMethod::OAuth
+        || is_loopback_endpoint(&provider.base_url)
+    {
+        return Some(ModelPricing::FREE);
+    }
+
+    let lower = model.model_id.to_lowercase();
+    let known = KNOWN_PRICING
+        .iter()
+        .find(|(name, _, _)| lower.contains(name))
+        .map(|&(_, input, output)| (input, output));
+
+    match (
+        model.input_price_per_million,
+        model.output_price_per_million,
+    ) {
+        (None, None) => known.map(|(input, output)| ModelPricing {
+            input_per_million: input,
+            output_per_million: output,
+        }),
+        (input, output) => Some(ModelPricing {
+            input_per_million: input.or(known.map(|k| k.0)).unwrap_or(0.0),
+            output_per_million: output.or(known.map(|k| k.1)).unwrap_or(0.0),
+        }),
+    }
+}
+
+/// Rough token count for text that hasn't been sent yet (~4 characters per token)
+pub fn estimate_tokens(text: &str) -> u32 {
+    (text.chars().count() as u32).div_ceil(4)
+}
+
+/// Check a refinement against the configured spend limits before sending it.
+///
+/// `prompt` is the full text sent to the model; the reply is assumed to be about as long
+/// as `transcription`. Returns the message to show the user when a limit is hit.
+pub fn check_spend_limits(
+    settings: &AppSettings,
+    pricing: &ModelPricing,
+    prompt: &str,
+    transcription: &str,
+    spent_today: f64,
+) -> Result<(), String> {
+    let estimate = pricing.cost(estimate_tokens(prompt), estimate_tokens(transcription));
+
+    if let Some(limit) = settings.per_request_spend_limit_usd {
+        if estimate > limit {
+            return Err(format!(
+                "Refinement would cost about ${:.4}, over the ${:.2} per-request limit. Pasted raw text instead.",
+                estimate, limit
+            ));
+        }
+    }
+
+    if let Some(limit) = settings.daily_spend_limit_usd {
+        if spent_today + estimate > limit {
+            return Err(format!(
+                "Daily spend limit of ${:.2} reached (${:.2} spent today). Pasted raw text instead.",
+                limit, spent_today
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+    use std::collections::HashMap;
+
+    fn provider(base_url: &str) -> LLMProvider {
+        LLMProvider {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            base_url: base_url.to_string(),
+            api_key: String::new(),
+            supports_vision: false,
+            is_custom: true,
+            auth_method: AuthMethod::ApiKey,
+            supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
+        }
+    }
+
+    fn model(model_id: &str) -> LLMModel {
+        LLMModel {
+            id: model_id.to_string(),
+            provider_id: "test".to_string(),
+            model_id: model_id.to_string(),
+            display_name: model_id.to_string(),
+            supports_vision: false,
+            enabled: true,
+            input_price_per_million: None,
+            output_price_per_million: None,
+            openrouter_routing: None,
+            gemini_options: None,
+            reasoning: None,
+        }
+    }
+
+    #[test]
+    fn test_pricing_lookup() {
+        let cloud = provider("https://api.openai.com/v1");
+        let mini = pricing_for(&cloud, &model("gpt-4o-mini")).unwrap();
+        assert_eq!(mini.input_per_million, 0.15);
+        let routed = pricing_for(&cloud, &model("openai/gpt-4o")).unwrap();
+        assert_eq!(routed.input_per_million, 2.50);
+        assert!(pricing_for(&cloud, &model("some-new-model")).is_none());
+
+        let local = provider("http://localhost:11434/v1");
+        assert_eq!(
+            pricing_for(&local, &model("llama3")),
+            Some(ModelPricing::FREE)
+        );
+    }
+
+    #[test]
+    fn test_only_loopback_or_marked_providers_are_free() {
+        for url in [
+            "http://127.0.0.1:8080/v1",
+            "http://[::1]:1234/v1",
+            "http://LOCALHOST",
+        ] {
+            assert!(is_loopback_endpoint(url), "{}", url);
+        }
+        for url in [
+            "http://10.0.0.5:4000/v1",
+            "http://192.168.1.20/v1",
+            "http://172.16.0.2:8000/v1",
+            "https://localhost.example.com/v1",
+        ] {
+            assert!(!is_loopback_endpoint(url), "{}", url);
+        }
+
+        let mut gateway = provider("http://10.0.0.5:4000/v1");
+        let priced = pricing_for(&gateway, &model("gpt-4o")).unwrap();
+        assert!(priced.input_per_million > 0.0);
+        gateway.free = true;
+        assert_eq!(
+            pricing_for(&gateway, &model("gpt-4o")),
+            Some(ModelPricing::FREE)
+        );
+    }
+
+    #[test]
+    fn test_model_price_overrides_table() {
+        let mut m = model("gpt-4o");
+        m.output_price_per_million = Some(1.0);
+        let pricing = pricing_for(&provider("https://api.openai.com/v1"), &m).unwrap();
+        assert_eq!(pricing.input_per_million, 2.50);
+        assert_eq!(pricing.output_per_million, 1.0);
+    }
+
+    #[test]
+    fn test_spend_limits() {
+        let pricing = ModelPricing {
+            input_per_million: 1_000_000.0,
+            output_per_million: 0.0,
+        };
+        let mut settings = get_default_settings();
+        // 8 chars = 2 tokens = $2
+        assert!(check_spend_limits(&settings, &pricing, "abcdefgh", "", 100.0).is_ok());
+
+        settings.per_request_spend_limit_usd = Some(1.0);
+        assert!(check_spend_limits(&settings, &pricing, "abcdefgh", "", 0.0).is_err());
+
+        settings.per_request_spend_limit_usd = None;
+        settings.daily_spend_limit_usd = Some(5.0);
+        assert!(check_spend_limits(&settings, &pricing, "abcdefgh", "", 2.0).is_ok());
+        assert!(check_spend_limits(&settings, &pricing, "abcdefgh", "", 4.0).is_err());
+    }
+}
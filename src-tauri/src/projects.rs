@@ -0,0 +1,193 @@
+//! Projects to dictate for, switched by voice with "switch to project Atlas".
+//!
+//! Activating a project applies its prompt mode and default category, and its
+//! vocabulary is used alongside `AppSettings::custom_words` until another project
+//! is chosen. The active project's name is available to prompts as `${project}`.
+
+use crate::settings::{self, AppSettings, Project};
+use log::{info, warn};
+use strsim::normalized_levenshtein;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+/// How closely a spoken project name must match a saved one (0 to 1)
+const MIN_NAME_SIMILARITY: f64 = 0.7;
+
+/// Reject projects that couldn't be switched to by name
+pub fn validate(project: &Project, settings: &AppSettings) -> Result<(), String> {
+    let name = project.name.trim();
+    if name.is_empty() {
+        return Err("Project needs a name".to_string());
+    }
+    if settings
+        .projects
+        .iter()
+        .any(|p| p.id != project.id && p.name.trim().eq_ignore_ascii_case(name))
+    {
+        return Err(format!("A project called '{}' already exists", name));
+    }
+    if let Some(category_id) = &project.default_category_id {
+        if !settings
+            .prompt_categories
+            .iter()
+            .any(|c| &c.id == category_id)
+        {
+            return Err(format!("Category with id '{}' not found", category_id));
+        }
+    }
+    Ok(())
+}
+
+/// The project whose name is closest to what was said, ignoring a leading "the"
+/// and a trailing "project"
+pub fn find_by_name<'a>(projects: &'a [Project], spoken: &str) -> Option<&'a Project> {
+    let spoken = spoken
+        .trim()
+        .trim_end_matches(['.', ',', '!', '?'])
+        .to_lowercase();
+    let spoken = spoken.strip_prefix("the ").unwrap_or(&spoken);
+    let spoken = spoken.strip_suffix(" project").unwrap_or(spoken).trim();
+    if spoken.is_empty() {
+        return None;
+    }
+
+    projects
+        .iter()
+        .map(|project| {
+            let similarity = normalized_levenshtein(&project.name.to_lowercase(), spoken);
+            (project, similarity)
+        })
+        .filter(|(_, similarity)| *similarity >= MIN_NAME_SIMILARITY)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(project, _)| project)
+}
+
+/// The active project, if one is chosen and still exists
+pub fn active(settings: &AppSettings) -> Option<&Project> {
+    let id = settings.active_project_id.as_ref()?;
+    settings.projects.iter().find(|p| &p.id == id)
+}
+
+/// Custom words plus the active project's vocabulary
+pub fn vocabulary(settings: &AppSettings) -> Vec<String> {
+    let mut words = settings.custom_words.clone();
+    if let Some(project) = active(settings) {
+        for word in &project.custom_words {
+            if !words.iter().any(|w| w.eq_ignore_ascii_case(word)) {
+                words.push(word.clone());
+            }
+        }
+    }
+    words
+}
+
+/// Make `project_id` the active project, or leave projects with None, and apply the
+/// project's prompt mode and default category
+pub fn activate(app: &AppHandle, project_id: Option<&str>) -> Result<(), String> {
+    let mut settings = settings::get_settings(app);
+    let project = match project_id {
+        Some(id) => Some(
+            settings
+                .projects
+                .iter()
+                .find(|p| p.id == id)
+                .cloned()
+                .ok_or_else(|| format!("Project with id '{}' not found", id))?,
+        ),
+        None => None,
+    };
+
+    settings.active_project_id = project.as_ref().map(|p| p.id.clone());
+    if let Some(category_id) = project.as_ref().and_then(|p| p.default_category_id.clone()) {
+        settings.default_category_id = category_id;
+    }
+    settings::write_settings(app, settings);
+
+    // The tray shows the prompt mode, so it's switched the way the tray does it
+    if let Some(mode) = project.as_ref().and_then(|p| p.prompt_mode) {
+        crate::tray::set_prompt_mode(app, mode);
+    }
+
+    match &project {
+        Some(project) => info!("Switched to project '{}'", project.name),
+        None => info!("Left the active project"),
+    }
+    Ok(())
+}
+
+/// Switch to the project named by voice and confirm it with a notification
+pub fn switch_by_name(app: &AppHandle, spoken: &str) -> Result<(), String> {
+    let settings = settings::get_settings(app);
+    let project = find_by_name(&settings.projects, spoken)
+        .ok_or_else(|| format!("No project is called \"{}\"", spoken.trim()))?;
+    activate(app, Some(&project.id))?;
+    crate::events::emit_settings_changed(app, "active_project_id", true);
+
+    let body = format!("Switched to project {}", project.name);
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Ramble")
+        .body(&body)
+        .show()
+    {
+        warn!("Failed to show project notification: {}", e);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: &str, name: &str, words: &[&str]) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            prompt_mode: None,
+            default_category_id: None,
+            custom_words: words.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_find_by_name() {
+        let projects = vec![
+            project("atlas", "Atlas", &[]),
+            project("site", "Marketing Site", &[]),
+        ];
+
+        let find = |spoken| find_by_name(&projects, spoken).map(|p| p.id.as_str());
+        assert_eq!(find("atlas"), Some("atlas"));
+        assert_eq!(find("the Atlas project."), Some("atlas"));
+        assert_eq!(find("marketing sight"), Some("site"));
+        assert_eq!(find("billing"), None);
+        assert_eq!(find(""), None);
+    }
+
+    #[test]
+    fn test_vocabulary_adds_active_project_words() {
+        let mut settings = settings::get_default_settings();
+        settings.custom_words = vec!["Ramble".to_string()];
+        settings.projects = vec![project("atlas", "Atlas", &["Kubernetes", "ramble"])];
+
+        assert_eq!(vocabulary(&settings), vec!["Ramble"]);
+
+        settings.active_project_id = Some("atlas".to_string());
+        assert_eq!(vocabulary(&settings), vec!["Ramble", "Kubernetes"]);
+
+        // A deleted project no longer applies
+        settings.projects.clear();
+        assert_eq!(vocabulary(&settings), vec!["Ramble"]);
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_names() {
+        let mut settings = settings::get_default_settings();
+        settings.projects = vec![project("atlas", "Atlas", &[])];
+
+        assert!(validate(&project("other", " atlas ", &[]), &settings).is_err());
+        assert!(validate(&project("atlas", "Atlas", &[]), &settings).is_ok());
+        assert!(validate(&project("new", "  ", &[]), &settings).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Proofreading selected text for the "fix this" voice command.
+//!
+//! Only spelling, grammar and punctuation are corrected; the wording is otherwise
+//! kept, so the result can replace the selection without surprises.
+
+use crate::actions::resolve_llm_config;
+use crate::errors::AppError;
+use crate::settings::AppSettings;
+use crate::summaries::complete;
+use tauri::AppHandle;
+
+/// Drop `<text>` tags the model copied from the prompt
+fn strip_text_tags(answer: &str) -> &str {
+    let answer = answer.trim();
+    answer
+        .strip_prefix("<text>")
+        .and_then(|rest| rest.strip_suffix("</text>"))
+        .map(str::trim)
+        .unwrap_or(answer)
+}
+
+/// `corrected` with the leading and trailing whitespace of `original`, so replacing
+/// a selection that ends in a newline doesn't join it to the next line
+fn keep_outer_whitespace(original: &str, corrected: &str) -> String {
+    let leading = &original[..original.len() - original.trim_start().len()];
+    let trailing = &original[original.trim_end().len()..];
+    format!("{}{}{}", leading, corrected.trim(), trailing)
+}
+
+/// Correct the spelling, grammar and punctuation of `text` with the refinement
+/// model
+pub async fn proofread(
+    app: &AppHandle,
+    settings: &AppSettings,
+    text: &str,
+) -> Result<String, AppError> {
+    let model_id = settings
+        .default_coherent_model_id
+        .clone()
+        .ok_or_else(|| AppError::not_configured("No refinement model is configured"))?;
+    let config = resolve_llm_config(settings, &model_id).await?;
+    let client = crate::llm_client::create_client(&config.provider, config.api_key.clone())?;
+
+    let prompt = format!(
+        "Correct the spelling, grammar and punctuation of the text below. Keep its wording, meaning, tone, language and formatting (line breaks, lists, markdown, code) as they are otherwise. Reply with only the corrected text, without the tags.\n\n<text>\n{}\n</text>",
+        text.trim()
+    );
+    let answer = complete(app, &client, &config, prompt, "proofread").await?;
+    Ok(keep_outer_whitespace(text, strip_text_tags(&answer)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_text_tags() {
+        assert_eq!(strip_text_tags("<text>\nFixed.\n</text>"), "Fixed.");
+        assert_eq!(strip_text_tags("  Fixed.\n"), "Fixed.");
+    }
+
+    #[test]
+    fn test_keep_outer_whitespace() {
+        assert_eq!(
+            keep_outer_whitespace("  teh cat\n", "The cat."),
+            "  The cat.\n"
+        );
+        assert_eq!(keep_outer_whitespace("teh cat", "The cat.\n"), "The cat.");
+    }
+}
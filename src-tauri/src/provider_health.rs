@@ -0,0 +1,349 @@
+//! Health checks for self-hosted LLM providers (Ollama, LM Studio, vLLM, llama.cpp).
+//!
+//! Custom and local providers are probed in the background by listing their models.
+//! Changes are emitted as `provider-health-changed` so the UI can show server status,
+//! and refinement checks the latest result first so a stopped local server fails in
+//! about a second instead of after the client's connect timeout.
+//!
+//! The same probe is used to discover servers on well-known localhost ports that
+//! haven't been added as providers yet.
+
+use crate::pricing::is_loopback_endpoint;
+use crate::settings::{self, AuthMethod, LLMProvider};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// How often monitored providers are probed in the background
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A cached result older than this is re-probed before refinement relies on it
+const MAX_RESULT_AGE_SECS: i64 = 30;
+
+/// Self-hosted servers answer `/models` quickly; anything slower counts as down
+const PROBE_TIMEOUT: Duration = Duration::from_millis(1500);
+
+static HEALTH: OnceLock<Mutex<HashMap<String, ProviderHealth>>> = OnceLock::new();
+
+fn health_cache() -> &'static Mutex<HashMap<String, ProviderHealth>> {
+    HEALTH.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Latest health check result for a provider
+#[derive(Serialize, Type, Debug, Clone, PartialEq)]
+pub struct ProviderHealth {
+    pub provider_id: String,
+    /// Whether the server answered the model listing
+    pub reachable: bool,
+    /// Models the server reported as available
+    pub models: Vec<String>,
+    pub error: Option<String>,
+    /// Unix timestamp of the check
+    pub checked_at: i64,
+}
+
+#[derive(Deserialize)]
+struct ModelList {
+    data: Vec<ModelEntry>,
+}
+
+#[derive(Deserialize)]
+struct ModelEntry {
+    id: String,
+}
+
+/// Whether a provider is self-hosted and worth probing
+pub fn is_monitored(provider: &LLMProvider) -> bool {
+    provider.auth_method != AuthMethod::OAuth
+        && (provider.is_custom || is_loopback_endpoint(&provider.base_url))
+        && provider.base_url.starts_with("http")
+}
+
+/// Port of an `http(s)://host:port/...` URL, if it has an explicit one
+fn url_port(base_url: &str) -> Option<&str> {
+    base_url
+        .split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .and_then(|host| host.rsplit_once(':'))
+        .map(|(_, port)| port)
+}
+
+/// Name of the server that usually listens on a provider's port, for error hints
+fn server_name(base_url: &str) -> &'static str {
+    match url_port(base_url) {
+        Some("1234") => "LM Studio",
+        Some("11434") => "Ollama",
+        Some("8000") => "vLLM",
+        Some("8080") => "the llama.cpp server",
+        _ => "the server",
+    }
+}
+
+/// List the models an OpenAI-compatible server reports
+async fn list_models(base_url: &str, api_key: &str) -> Result<Vec<String>, String> {
+    let url = format!("{}/models", base_url.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(PROBE_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut request = client.get(&url);
+    if !api_key.is_empty() {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    // Servers without a model listing are still up
+    Ok(response
+        .json::<ModelList>()
+        .await
+        .map(|list| list.data.into_iter().map(|m| m.id).collect())
+        .unwrap_or_default())
+}
+
+/// Probe a provider by listing its models
+pub async fn probe(provider: &LLMProvider) -> ProviderHealth {
+    let (reachable, models, error) = match list_models(&provider.base_url, &provider.api_key).await
+    {
+        Ok(models) => (true, models, None),
+        Err(e) => (false, Vec::new(), Some(e)),
+    };
+    ProviderHealth {
+        provider_id: provider.id.clone(),
+        reachable,
+        models,
+        error,
+        checked_at: chrono::Utc::now().timestamp(),
+    }
+}
+
+/// Store a result, emitting `provider-health-changed` if the status changed
+fn record(app: &AppHandle, health: ProviderHealth) {
+    let changed = {
+        let mut cache = health_cache().lock().unwrap();
+        let changed = match cache.get(&health.provider_id) {
+            Some(previous) => {
+                previous.reachable != health.reachable || previous.models != health.models
+            }
+            None => true,
+        };
+        cache.insert(health.provider_id.clone(), health.clone());
+        changed
+    };
+    if changed {
+        debug!(
+            "Provider '{}' health changed: reachable={}, {} models",
+            health.provider_id,
+            health.reachable,
+            health.models.len()
+        );
+        let _ = app.emit("provider-health-changed", &health);
+    }
+}
+
+/// Probe a provider now and record the result
+pub async fn check(app: &AppHandle, provider: &LLMProvider) -> ProviderHealth {
+    let health = probe(provider).await;
+    record(app, health.clone());
+    health
+}
+
+/// Latest recorded results for all monitored providers
+pub fn all() -> Vec<ProviderHealth> {
+    health_cache().lock().unwrap().values().cloned().collect()
+}
+
+/// Explain why a request to `model_id` would fail, given a health result
+fn unavailable_reason(
+    provider: &LLMProvider,
+    model_id: &str,
+    health: &ProviderHealth,
+) -> Option<String> {
+    if !health.reachable {
+        return Some(format!(
+            "Local server not running at {}. Start {}?",
+            provider.base_url,
+            server_name(&provider.base_url)
+        ));
+    }
+    if !health.models.is_empty() && !health.models.iter().any(|m| m == model_id) {
+        return Some(format!(
+            "Model '{}' isn't available on {}. Load it in {} or pick another model.",
+            model_id,
+            provider.name,
+            server_name(&provider.base_url)
+        ));
+    }
+    None
+}
+
+/// Fail fast when a self-hosted provider is down or doesn't have the model.
+/// Other providers always pass.
+pub async fn ensure_available(
+    app: &AppHandle,
+    provider: &LLMProvider,
+    model_id: &str,
+) -> Result<(), String> {
+    if !is_monitored(provider) {
+        return Ok(());
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let cached = health_cache()
+        .lock()
+        .unwrap()
+        .get(&provider.id)
+        .filter(|h| now - h.checked_at <= MAX_RESULT_AGE_SECS)
+        .cloned();
+    let health = match cached {
+        Some(health) => health,
+        None => check(app, provider).await,
+    };
+
+    match unavailable_reason(provider, model_id, &health) {
+        Some(reason) => Err(reason),
+        None => Ok(()),
+    }
+}
+
+/// An OpenAI-compatible server found on a well-known localhost port
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+pub struct DiscoveredServer {
+    /// Suggested provider ID (e.g., "lmstudio")
+    pub id: String,
+    pub name: String,
+    pub base_url: String,
+    pub models: Vec<String>,
+}
+
+/// Ports scanned for local servers, with the provider each one usually is
+const DISCOVERY_PORTS: &[(&str, &str, &str)] = &[
+    ("1234", "lmstudio", "LM Studio"),
+    ("8080", "llamacpp", "llama.cpp"),
+    ("11434", "ollama", "Ollama"),
+];
+
+/// Scan well-known localhost ports for OpenAI-compatible servers that no
+/// configured provider points at yet
+pub async fn discover_local_servers(configured: &[LLMProvider]) -> Vec<DiscoveredServer> {
+    let candidates = DISCOVERY_PORTS.iter().filter(|(port, _, _)| {
+        !configured
+            .iter()
+            .any(|p| is_loopback_endpoint(&p.base_url) && url_port(&p.base_url) == Some(port))
+    });
+
+    let probes = candidates.map(|&(port, id, name)| async move {
+        let base_url = format!("http://localhost:{}/v1", port);
+        match list_models(&base_url, "").await {
+            Ok(models) => {
+                info!("Found {} at {} ({} models)", name, base_url, models.len());
+                Some(DiscoveredServer {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    base_url,
+                    models,
+                })
+            }
+            Err(e) => {
+                debug!("No server at {}: {}", base_url, e);
+                None
+            }
+        }
+    });
+
+    futures_util::future::join_all(probes)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Probe monitored providers in the background every `CHECK_INTERVAL`
+pub fn start_health_monitor(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let providers: Vec<LLMProvider> = settings::get_settings(&app)
+                .llm_providers
+                .into_iter()
+                .filter(is_monitored)
+                .collect();
+            for provider in &providers {
+                check(&app, provider).await;
+            }
+
+            // Forget providers that were removed or are no longer self-hosted
+            health_cache()
+                .lock()
+                .unwrap()
+                .retain(|id, _| providers.iter().any(|p| &p.id == id));
+
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+    info!("Started self-hosted provider health monitor");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(base_url: &str) -> LLMProvider {
+        LLMProvider {
+            id: "local".to_string(),
+            name: "Local".to_string(),
+            base_url: base_url.to_string(),
+            api_key: String::new(),
+            supports_vision: false,
+            is_custom: true,
+            auth_method: AuthMethod::ApiKey,
+            supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
+        }
+    }
+
+    fn health(reachable: bool, models: &[&str]) -> ProviderHealth {
+        ProviderHealth {
+            provider_id: "local".to_string(),
+            reachable,
+            models: models.iter().map(|m| m.to_string()).collect(),
+            error: None,
+            checked_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_server_name_from_port() {
+        assert_eq!(server_name("http://localhost:1234/v1"), "LM Studio");
+        assert_eq!(server_name("http://127.0.0.1:11434/v1"), "Ollama");
+        assert_eq!(server_name("https://gateway.example.com/v1"), "the server");
+    }
+
+    #[test]
+    fn test_url_port() {
+        assert_eq!(url_port("http://localhost:8080/v1"), Some("8080"));
+        assert_eq!(url_port("http://127.0.0.1:1234"), Some("1234"));
+        assert_eq!(url_port("https://api.openai.com/v1"), None);
+    }
+
+    #[test]
+    fn test_unavailable_reason() {
+        let p = provider("http://localhost:1234/v1");
+        let down = unavailable_reason(&p, "qwen", &health(false, &[])).unwrap();
+        assert!(down.contains("Start LM Studio?"));
+
+        assert!(unavailable_reason(&p, "qwen", &health(true, &["qwen"])).is_none());
+        assert!(unavailable_reason(&p, "qwen", &health(true, &["llama"])).is_some());
+        // Servers that don't list models can't be checked for the model
+        assert!(unavailable_reason(&p, "qwen", &health(true, &[])).is_none());
+    }
+}
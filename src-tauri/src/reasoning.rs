@@ -0,0 +1,172 @@
+//! Reasoning-model options (effort and thinking budget) and removal of reasoning
+//! traces, so a model's thinking never ends up pasted into the user's app.
+
+use serde_json::{json, Map, Value};
+
+use crate::settings::{LLMModel, LLMProvider, ReasoningOptions};
+
+const EFFORT_OPTIONS: &[&str] = &["minimal", "low", "medium", "high"];
+
+/// Tags models wrap their reasoning in when it's returned inline with the answer
+const REASONING_TAGS: &[&str] = &["think", "thinking", "reasoning"];
+
+/// Anthropic requires at least this many thinking tokens
+const MIN_ANTHROPIC_BUDGET: u32 = 1024;
+
+/// Room left for the answer on top of Anthropic's thinking budget, which counts
+/// against `max_tokens`
+const ANTHROPIC_ANSWER_TOKENS: u32 = 8192;
+
+/// Reject values `provider` would answer with a 400
+pub fn validate(provider: &LLMProvider, options: &ReasoningOptions) -> Result<(), String> {
+    if let Some(effort) = options.effort.as_deref() {
+        if !EFFORT_OPTIONS.contains(&effort) {
+            return Err(format!(
+                "Unknown reasoning effort '{}', expected one of: {}",
+                effort,
+                EFFORT_OPTIONS.join(", ")
+            ));
+        }
+    }
+    match options.budget_tokens {
+        Some(budget) if provider.id == "anthropic" && budget < MIN_ANTHROPIC_BUDGET => {
+            Err(format!(
+                "Anthropic needs a thinking budget of at least {} tokens",
+                MIN_ANTHROPIC_BUDGET
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether `model`'s reasoning should be kept out of chat responses. Models without
+/// reasoning options hide it.
+pub fn hides_reasoning(model: &LLMModel) -> bool {
+    model.reasoning.as_ref().is_none_or(|r| r.hide_reasoning)
+}
+
+/// Whether refinements by `model` may start with a reasoning trace. Only models set
+/// up with reasoning options are expected to think out loud; stripping any other
+/// answer could eat dictated text that mentions the tags.
+pub fn may_include_reasoning(model: &LLMModel) -> bool {
+    model.reasoning.is_some()
+}
+
+/// Chat completion body fields for `model`'s reasoning options, in the dialect
+/// of its provider: OpenRouter's `reasoning` object, Anthropic's `thinking` or
+/// OpenAI's `reasoning_effort`
+pub fn body_fields(provider: &LLMProvider, model: &LLMModel) -> Map<String, Value> {
+    let mut fields = Map::new();
+    let Some(options) = &model.reasoning else {
+        return fields;
+    };
+
+    if crate::openrouter::is_openrouter(provider) {
+        let mut reasoning = Map::new();
+        // OpenRouter takes either an effort or a token budget, not both
+        if let Some(effort) = &options.effort {
+            reasoning.insert("effort".to_string(), json!(effort));
+        } else if let Some(budget) = options.budget_tokens {
+            reasoning.insert("max_tokens".to_string(), json!(budget));
+        }
+        if options.hide_reasoning {
+            reasoning.insert("exclude".to_string(), json!(true));
+        }
+        if !reasoning.is_empty() {
+            fields.insert("reasoning".to_string(), Value::Object(reasoning));
+        }
+    } else if provider.id == "anthropic" {
+        if let Some(budget) = options.budget_tokens {
+            fields.insert(
+                "thinking".to_string(),
+                json!({ "type": "enabled", "budget_tokens": budget }),
+            );
+            fields.insert(
+                "max_tokens".to_string(),
+                json!(budget + ANTHROPIC_ANSWER_TOKENS),
+            );
+        }
+    } else if let Some(effort) = &options.effort {
+        fields.insert("reasoning_effort".to_string(), json!(effort));
+    }
+    fields
+}
+
+/// Remove the `<think>` style block a model's answer starts with, or the whole answer
+/// if that block was cut off before its closing tag. Tags anywhere else are kept, as
+/// they're part of the answer.
+pub fn strip_reasoning(text: &str) -> String {
+    let text = text.trim_start();
+    // ASCII lowercasing keeps byte offsets, so they index `text` too
+    let lower = text.to_ascii_lowercase();
+    for tag in REASONING_TAGS {
+        if !lower.starts_with(&format!("<{}>", tag)) {
+            continue;
+        }
+        let close = format!("</{}>", tag);
+        return match lower.find(&close) {
+            Some(end) => text[end + close.len()..].trim().to_string(),
+            None => String::new(),
+        };
+    }
+    text.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_reasoning() {
+        assert_eq!(
+            strip_reasoning("<think>\nThe user wants a list.\n</think>\n\nBuy milk."),
+            "Buy milk."
+        );
+        assert_eq!(
+            strip_reasoning("  <Thinking>plan</Thinking>Hello <reasoning>x</reasoning>there"),
+            "Hello <reasoning>x</reasoning>there"
+        );
+        // Trace cut off by the token limit
+        assert_eq!(strip_reasoning("<think>more thoughts"), "");
+        assert_eq!(strip_reasoning("No reasoning here."), "No reasoning here.");
+        // Tags that are part of the dictation stay
+        assert_eq!(
+            strip_reasoning("Wrap the plan in a <think> tag"),
+            "Wrap the plan in a <think> tag"
+        );
+        assert_eq!(
+            strip_reasoning("Close it with </think> and move on."),
+            "Close it with </think> and move on."
+        );
+    }
+
+    fn default_provider(id: &str) -> LLMProvider {
+        crate::settings::get_default_settings()
+            .llm_providers
+            .into_iter()
+            .find(|p| p.id == id)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let anthropic = default_provider("anthropic");
+        let mut options = ReasoningOptions {
+            effort: Some("high".to_string()),
+            budget_tokens: Some(2048),
+            hide_reasoning: true,
+        };
+        assert!(validate(&anthropic, &options).is_ok());
+        options.effort = Some("extreme".to_string());
+        assert!(validate(&anthropic, &options).is_err());
+        options.effort = None;
+        options.budget_tokens = Some(100);
+        assert!(validate(&anthropic, &options).is_err());
+
+        // Only Anthropic has a minimum; Gemini takes 0 to turn thinking off
+        let gemini = default_provider("gemini");
+        assert!(validate(&gemini, &options).is_ok());
+        options.budget_tokens = Some(0);
+        assert!(validate(&gemini, &options).is_ok());
+    }
+}
@@ -0,0 +1,207 @@
+//! Scheduled and recurring dictation reminders.
+//!
+//! A background task checks `AppSettings::reminders` every few seconds and shows a
+//! notification when one is due. A reminder with a category makes the next dictation
+//! (within a few minutes) use that category instead of the detected one.
+
+use crate::settings::{self, Reminder};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use log::{info, warn};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+/// How often due reminders are checked for
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long a reminder's category applies to the next dictation
+const CATEGORY_OVERRIDE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Minute each reminder last fired in, so a reminder fires once per occurrence
+static LAST_FIRED: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+/// Category the next dictation uses, set by a reminder
+static CATEGORY_OVERRIDE: Mutex<Option<(String, Instant)>> = Mutex::new(None);
+
+fn parse_time(time: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(time, "%H:%M").ok()
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Reject reminders the scheduler couldn't fire
+pub fn validate(reminder: &Reminder) -> Result<(), String> {
+    if reminder.label.trim().is_empty() {
+        return Err("Reminder needs a label".to_string());
+    }
+    if parse_time(&reminder.time).is_none() {
+        return Err(format!(
+            "Invalid reminder time '{}', expected HH:MM",
+            reminder.time
+        ));
+    }
+    if let Some(date) = &reminder.date {
+        if parse_date(date).is_none() {
+            return Err(format!(
+                "Invalid reminder date '{}', expected YYYY-MM-DD",
+                date
+            ));
+        }
+    }
+    if let Some(day) = reminder.days.iter().find(|d| !(1..=7).contains(*d)) {
+        return Err(format!(
+            "Invalid weekday {}, expected 1 (Monday) to 7 (Sunday)",
+            day
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `reminder` is scheduled for the minute containing `now`
+pub fn is_due(reminder: &Reminder, now: NaiveDateTime) -> bool {
+    if !reminder.enabled {
+        return false;
+    }
+    let Some(time) = parse_time(&reminder.time) else {
+        return false;
+    };
+    if now.hour() != time.hour() || now.minute() != time.minute() {
+        return false;
+    }
+    match &reminder.date {
+        Some(date) => parse_date(date) == Some(now.date()),
+        None => {
+            reminder.days.is_empty() || reminder.days.contains(&now.weekday().number_from_monday())
+        }
+    }
+}
+
+/// The category set by the last reminder, if it hasn't expired. Taken, so it only
+/// applies to one dictation.
+pub fn take_category_override() -> Option<String> {
+    let (category_id, set_at) = CATEGORY_OVERRIDE.lock().unwrap().take()?;
+    (set_at.elapsed() < CATEGORY_OVERRIDE_TTL).then_some(category_id)
+}
+
+fn fire(app: &AppHandle, reminder: &Reminder) {
+    info!("Reminder '{}' is due", reminder.label);
+    let settings = settings::get_settings(app);
+    let category = reminder
+        .category_id
+        .as_ref()
+        .and_then(|id| settings.prompt_categories.iter().find(|c| &c.id == id));
+
+    let body = match category {
+        Some(category) => {
+            *CATEGORY_OVERRIDE.lock().unwrap() = Some((category.id.clone(), Instant::now()));
+            format!("Your next dictation uses the {} category", category.name)
+        }
+        None => "Time to dictate".to_string(),
+    };
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(&reminder.label)
+        .body(body)
+        .show()
+    {
+        warn!("Failed to show reminder notification: {}", e);
+    }
+
+    // One-off reminders are done once shown
+    if reminder.date.is_some() {
+        let mut settings = settings;
+        if let Some(r) = settings.reminders.iter_mut().find(|r| r.id == reminder.id) {
+            r.enabled = false;
+        }
+        settings::write_settings(app, settings);
+    }
+}
+
+fn check(app: &AppHandle) {
+    let now = Local::now().naive_local();
+    let minute = now.format("%Y-%m-%d %H:%M").to_string();
+    let due: Vec<Reminder> = settings::get_settings(app)
+        .reminders
+        .into_iter()
+        .filter(|r| is_due(r, now))
+        .collect();
+
+    for reminder in due {
+        let already_fired = {
+            let mut last_fired = LAST_FIRED.lock().unwrap();
+            let last_fired = last_fired.get_or_insert_with(HashMap::new);
+            last_fired
+                .insert(reminder.id.clone(), minute.clone())
+                .as_ref()
+                == Some(&minute)
+        };
+        if !already_fired {
+            fire(app, &reminder);
+        }
+    }
+}
+
+pub fn start_scheduler(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            check(&app);
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+    info!("Started reminder scheduler");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(time: &str, days: Vec<u32>, date: Option<&str>) -> Reminder {
+        Reminder {
+            id: "standup".to_string(),
+            label: "Dictate your standup".to_string(),
+            time: time.to_string(),
+            days,
+            date: date.map(String::from),
+            category_id: None,
+            enabled: true,
+        }
+    }
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::new(parse_date(date).unwrap(), parse_time(time).unwrap())
+    }
+
+    #[test]
+    fn test_is_due() {
+        // 2026-10-12 is a Monday
+        let weekdays = reminder("09:25", vec![1, 2, 3, 4, 5], None);
+        assert!(is_due(&weekdays, at("2026-10-12", "09:25")));
+        assert!(!is_due(&weekdays, at("2026-10-12", "09:26")));
+        assert!(!is_due(&weekdays, at("2026-10-11", "09:25")));
+
+        let daily = reminder("09:25", Vec::new(), None);
+        assert!(is_due(&daily, at("2026-10-11", "09:25")));
+
+        let once = reminder("17:00", vec![1], Some("2026-10-11"));
+        assert!(is_due(&once, at("2026-10-11", "17:00")));
+        assert!(!is_due(&once, at("2026-10-12", "17:00")));
+
+        let mut disabled = daily.clone();
+        disabled.enabled = false;
+        assert!(!is_due(&disabled, at("2026-10-11", "09:25")));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&reminder("09:25", vec![1, 7], None)).is_ok());
+        assert!(validate(&reminder("9:25am", Vec::new(), None)).is_err());
+        assert!(validate(&reminder("09:25", vec![0], None)).is_err());
+        assert!(validate(&reminder("09:25", Vec::new(), Some("10/11/2026"))).is_err());
+    }
+}
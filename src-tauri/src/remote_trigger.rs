@@ -0,0 +1,548 @@
+//! Remote trigger endpoint for companion devices.
+//!
+//! With `AppSettings::remote_trigger_enabled` on, a small HTTP server listens on
+//! `remote_trigger_port` so a phone, watch or iOS Shortcut can start and stop
+//! recordings. Requests go through the same hands-free path as the wake word and
+//! media key, so they behave like tapping the binding's shortcut.
+//!
+//! The server only listens on this computer unless `remote_trigger_lan_access` is
+//! on. Other devices need that, but it exposes the endpoint to everyone on the
+//! network: the server speaks plain HTTP, so tokens and pairing codes can be read by
+//! anyone watching the network, and anyone can try pairing codes while pairing is
+//! open. Wrong codes are slowed down and close pairing after a few tries.
+//!
+//! A device pairs once: the settings page shows a six-digit code, and the device
+//! sends it with `POST /pair` to get a token. The token's hash is kept in
+//! `remote_trigger_devices`, and every later request carries it as a bearer token:
+//!
+//! - `POST /pair` `{"code": "123456", "name": "Apple Watch"}` returns `{"token": ...}`
+//! - `POST /trigger` `{"action": "toggle" | "start" | "stop", "binding": "transcribe"}`
+//! - `GET /status` returns `{"recording": bool}`
+
+use crate::errors::AppError;
+use crate::events::RemoteDevicePaired;
+use crate::settings::{self, PairedDevice};
+use log::{debug, info, warn};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use specta::Type;
+use std::io::{Cursor, Read};
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tauri_specta::Event;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// How long a pairing code can be used
+const PAIRING_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Wrong codes allowed before the pairing code is thrown away
+const MAX_PAIRING_ATTEMPTS: u8 = 5;
+
+/// How long after a wrong code the next one is refused without being checked
+const PAIRING_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Request bodies are tiny, anything bigger is refused
+const MAX_BODY_BYTES: u64 = 4096;
+
+/// The running server, unblocked to stop it
+static SERVER: Mutex<Option<RunningServer>> = Mutex::new(None);
+
+/// The pairing code currently shown in the settings page
+static PAIRING: Mutex<Option<Pairing>> = Mutex::new(None);
+
+struct RunningServer {
+    server: Arc<Server>,
+    thread: JoinHandle<()>,
+}
+
+struct Pairing {
+    code: String,
+    expires_at: Instant,
+    attempts: u8,
+    last_wrong_at: Option<Instant>,
+}
+
+#[derive(Debug, PartialEq)]
+enum PairingError {
+    NotOpen,
+    WrongCode,
+    TooSoon,
+}
+
+/// A pairing code for the settings page to show, with where to send it
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct RemotePairing {
+    pub code: String,
+    /// Addresses the endpoint can be reached on, this computer's local network
+    /// addresses with LAN access on
+    pub addresses: Vec<String>,
+    pub port: u16,
+    pub expires_in_secs: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct PairRequest {
+    code: String,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+enum TriggerAction {
+    #[default]
+    Toggle,
+    Start,
+    Stop,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct TriggerRequest {
+    #[serde(default)]
+    action: TriggerAction,
+    #[serde(default)]
+    binding: Option<String>,
+}
+
+fn random_hex(bytes: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..bytes)
+        .map(|_| format!("{:02x}", rng.gen::<u8>()))
+        .collect()
+}
+
+fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Check a code against the open pairing. The pairing closes once it's used, once it
+/// expires and after too many wrong codes. Codes sent too soon after a wrong one
+/// aren't checked, so they can't be guessed quickly.
+fn redeem_pairing(
+    pairing: &mut Option<Pairing>,
+    code: &str,
+    now: Instant,
+) -> Result<(), PairingError> {
+    let Some(open) = pairing.as_mut() else {
+        return Err(PairingError::NotOpen);
+    };
+    if now >= open.expires_at {
+        pairing.take();
+        return Err(PairingError::NotOpen);
+    }
+    if open
+        .last_wrong_at
+        .is_some_and(|at| now < at + PAIRING_RETRY_DELAY)
+    {
+        return Err(PairingError::TooSoon);
+    }
+    if open.code != code.trim() {
+        open.attempts += 1;
+        open.last_wrong_at = Some(now);
+        if open.attempts >= MAX_PAIRING_ATTEMPTS {
+            pairing.take();
+        }
+        return Err(PairingError::WrongCode);
+    }
+    pairing.take();
+    Ok(())
+}
+
+/// The token from an `Authorization: Bearer <token>` header value
+fn parse_bearer(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+fn json_response(status: u16, body: serde_json::Value) -> Response<Cursor<Vec<u8>>> {
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("static header is valid");
+    Response::from_data(body.to_string().into_bytes())
+        .with_status_code(status)
+        .with_header(content_type)
+}
+
+fn error_response(status: u16, message: &str) -> Response<Cursor<Vec<u8>>> {
+    json_response(status, serde_json::json!({ "error": message }))
+}
+
+fn read_body<T: for<'de> Deserialize<'de> + Default>(request: &mut Request) -> Result<T, String> {
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY_BYTES)
+        .read_to_string(&mut body)
+        .map_err(|e| e.to_string())?;
+    if body.trim().is_empty() {
+        return Ok(T::default());
+    }
+    serde_json::from_str(&body).map_err(|e| e.to_string())
+}
+
+/// The paired device the request's bearer token belongs to
+fn authorize(app: &AppHandle, request: &Request) -> Option<PairedDevice> {
+    let header = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Authorization"))?;
+    let token_hash = hash_token(parse_bearer(header.value.as_str())?);
+    settings::get_settings(app)
+        .remote_trigger_devices
+        .into_iter()
+        .find(|d| d.token_hash == token_hash)
+}
+
+fn handle_pair(app: &AppHandle, request: &mut Request) -> Response<Cursor<Vec<u8>>> {
+    let body = match read_body::<Option<PairRequest>>(request) {
+        Ok(Some(body)) => body,
+        Ok(None) => return error_response(400, "Missing pairing code"),
+        Err(e) => return error_response(400, &e),
+    };
+
+    let Ok(mut pairing) = PAIRING.lock() else {
+        return error_response(500, "Pairing is unavailable, restart Ramble");
+    };
+    let redeemed = redeem_pairing(&mut pairing, &body.code, Instant::now());
+    drop(pairing);
+    match redeemed {
+        Ok(()) => {}
+        Err(PairingError::NotOpen) => {
+            return error_response(403, "Pairing isn't open, start it from Ramble's settings");
+        }
+        Err(PairingError::WrongCode) => return error_response(401, "Wrong pairing code"),
+        Err(PairingError::TooSoon) => {
+            return error_response(429, "Too many pairing attempts, try again shortly");
+        }
+    }
+
+    let token = random_hex(32);
+    let device = PairedDevice {
+        id: random_hex(8),
+        name: body
+            .name
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| "Companion device".to_string()),
+        token_hash: hash_token(&token),
+        paired_at: chrono::Utc::now().timestamp(),
+    };
+    info!("Paired remote trigger device '{}'", device.name);
+
+    let mut settings = settings::get_settings(app);
+    settings.remote_trigger_devices.push(device.clone());
+    settings::write_settings(app, settings);
+    let _ = RemoteDevicePaired {
+        id: device.id,
+        name: device.name,
+    }
+    .emit(app);
+
+    json_response(200, serde_json::json!({ "token": token }))
+}
+
+fn handle_trigger(
+    app: &AppHandle,
+    request: &mut Request,
+    device: &PairedDevice,
+) -> Response<Cursor<Vec<u8>>> {
+    let body = match read_body::<TriggerRequest>(request) {
+        Ok(body) => body,
+        Err(e) => return error_response(400, &e),
+    };
+    let binding_id = body.binding.as_deref().unwrap_or("transcribe");
+    if !settings::REMOTE_TRIGGER_BINDINGS.contains(&binding_id) {
+        return error_response(400, &format!("Can't trigger '{}'", binding_id));
+    }
+    if crate::shortcut::shortcuts_paused() {
+        return error_response(409, "Shortcuts are paused");
+    }
+
+    let active = crate::utils::is_toggle_active(app, binding_id);
+    let start = match body.action {
+        TriggerAction::Toggle => !active,
+        TriggerAction::Start => true,
+        TriggerAction::Stop => false,
+    };
+    if start && !active {
+        info!("'{}' starting '{}' remotely", device.name, binding_id);
+        crate::utils::start_hands_free(app, binding_id);
+    } else if !start && active {
+        info!("'{}' stopping '{}' remotely", device.name, binding_id);
+        crate::utils::stop_toggled_recording(app, binding_id);
+    }
+
+    json_response(
+        200,
+        serde_json::json!({
+            "binding": binding_id,
+            "recording": crate::utils::is_toggle_active(app, binding_id),
+        }),
+    )
+}
+
+fn handle_request(app: &AppHandle, mut request: Request) {
+    let method = request.method().clone();
+    let path = request.url().split('?').next().unwrap_or("").to_string();
+    debug!("Remote trigger request: {} {}", method, path);
+
+    let response = match (&method, path.as_str()) {
+        (Method::Post, "/pair") => handle_pair(app, &mut request),
+        (_, "/trigger" | "/status") => match authorize(app, &request) {
+            None => error_response(401, "Unknown device, pair it first"),
+            Some(device) if method == Method::Post && path == "/trigger" => {
+                handle_trigger(app, &mut request, &device)
+            }
+            Some(_) if method == Method::Get && path == "/status" => json_response(
+                200,
+                serde_json::json!({
+                    "recording": settings::REMOTE_TRIGGER_BINDINGS
+                        .iter()
+                        .any(|b| crate::utils::is_toggle_active(app, b)),
+                }),
+            ),
+            Some(_) => error_response(405, "Method not allowed"),
+        },
+        _ => error_response(404, "Not found"),
+    };
+
+    if let Err(e) = request.respond(response) {
+        debug!("Failed to answer remote trigger request: {}", e);
+    }
+}
+
+fn lock_error<T>(_: T) -> String {
+    "Remote trigger state is unavailable, restart Ramble".to_string()
+}
+
+/// Stop the server and wait for its thread, so its port is free again
+fn stop_server() -> Result<(), String> {
+    let running = SERVER.lock().map_err(lock_error)?.take();
+    if let Some(running) = running {
+        running.server.unblock();
+        if running.thread.join().is_err() {
+            warn!("Remote trigger endpoint thread panicked");
+        }
+        info!("Stopped remote trigger endpoint");
+    }
+    Ok(())
+}
+
+/// Start or stop the endpoint, following the settings
+pub fn update(app: &AppHandle) -> Result<(), String> {
+    stop_server()?;
+    let settings = settings::get_settings(app);
+    if !settings.remote_trigger_enabled {
+        PAIRING.lock().map_err(lock_error)?.take();
+        return Ok(());
+    }
+
+    let host = if settings.remote_trigger_lan_access {
+        "0.0.0.0"
+    } else {
+        "127.0.0.1"
+    };
+    let addr = format!("{}:{}", host, settings.remote_trigger_port);
+    let server = match Server::http(&addr) {
+        Ok(server) => Arc::new(server),
+        Err(e) => {
+            warn!("Failed to start remote trigger endpoint on {}: {}", addr, e);
+            return Err(format!(
+                "Couldn't listen on port {}: {}",
+                settings.remote_trigger_port, e
+            ));
+        }
+    };
+
+    let app = app.clone();
+    let thread = std::thread::spawn({
+        let server = Arc::clone(&server);
+        move || {
+            // Ends once the server is unblocked
+            for request in server.incoming_requests() {
+                handle_request(&app, request);
+            }
+        }
+    });
+    *SERVER.lock().map_err(lock_error)? = Some(RunningServer { server, thread });
+    info!("Remote trigger endpoint listening on {}", addr);
+    Ok(())
+}
+
+/// This computer's address on the local network, found by asking the OS which
+/// interface it would route through. Nothing is sent.
+fn local_addresses() -> Vec<String> {
+    UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("192.0.2.1:9")?;
+            socket.local_addr()
+        })
+        .map(|addr| vec![addr.ip().to_string()])
+        .unwrap_or_default()
+}
+
+/// Open pairing and get the code a device must send, replacing any earlier code
+#[tauri::command]
+#[specta::specta]
+pub fn start_remote_pairing(app: AppHandle) -> Result<RemotePairing, AppError> {
+    let settings = settings::get_settings(&app);
+    let running = SERVER
+        .lock()
+        .map_err(|e| AppError::internal(lock_error(e)))?
+        .is_some();
+    if !settings.remote_trigger_enabled || !running {
+        return Err(AppError::invalid_input(
+            "Turn on the remote trigger before pairing a device",
+        ));
+    }
+
+    let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+    *PAIRING
+        .lock()
+        .map_err(|e| AppError::internal(lock_error(e)))? = Some(Pairing {
+        code: code.clone(),
+        expires_at: Instant::now() + PAIRING_TTL,
+        attempts: 0,
+        last_wrong_at: None,
+    });
+    let addresses = if settings.remote_trigger_lan_access {
+        local_addresses()
+    } else {
+        vec!["127.0.0.1".to_string()]
+    };
+    Ok(RemotePairing {
+        code,
+        addresses,
+        port: settings.remote_trigger_port,
+        expires_in_secs: PAIRING_TTL.as_secs(),
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_remote_pairing() -> Result<(), AppError> {
+    PAIRING
+        .lock()
+        .map_err(|e| AppError::internal(lock_error(e)))?
+        .take();
+    Ok(())
+}
+
+/// Forget a paired device, so its token stops working
+#[tauri::command]
+#[specta::specta]
+pub fn remove_remote_device(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    let count = settings.remote_trigger_devices.len();
+    settings.remote_trigger_devices.retain(|d| d.id != id);
+    if settings.remote_trigger_devices.len() == count {
+        return Err(AppError::not_found(format!("No paired device '{}'", id)));
+    }
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_pairing(code: &str, now: Instant) -> Option<Pairing> {
+        Some(Pairing {
+            code: code.to_string(),
+            expires_at: now + PAIRING_TTL,
+            attempts: 0,
+            last_wrong_at: None,
+        })
+    }
+
+    #[test]
+    fn test_redeem_pairing() {
+        let now = Instant::now();
+        let mut pairing = open_pairing("123456", now);
+        assert_eq!(redeem_pairing(&mut pairing, " 123456 ", now), Ok(()));
+        // A code only works once
+        assert_eq!(
+            redeem_pairing(&mut pairing, "123456", now),
+            Err(PairingError::NotOpen)
+        );
+
+        let mut pairing = open_pairing("123456", now);
+        assert_eq!(
+            redeem_pairing(&mut pairing, "123456", now + PAIRING_TTL),
+            Err(PairingError::NotOpen)
+        );
+        assert!(pairing.is_none());
+    }
+
+    #[test]
+    fn test_redeem_pairing_closes_after_wrong_codes() {
+        let mut now = Instant::now();
+        let mut pairing = open_pairing("123456", now);
+        for _ in 0..MAX_PAIRING_ATTEMPTS {
+            assert_eq!(
+                redeem_pairing(&mut pairing, "000000", now),
+                Err(PairingError::WrongCode)
+            );
+            now += PAIRING_RETRY_DELAY;
+        }
+        assert_eq!(
+            redeem_pairing(&mut pairing, "123456", now),
+            Err(PairingError::NotOpen)
+        );
+    }
+
+    #[test]
+    fn test_redeem_pairing_slows_down_guesses() {
+        let now = Instant::now();
+        let mut pairing = open_pairing("123456", now);
+        assert_eq!(
+            redeem_pairing(&mut pairing, "000000", now),
+            Err(PairingError::WrongCode)
+        );
+        // Even the right code is refused until the delay has passed, without counting
+        assert_eq!(
+            redeem_pairing(&mut pairing, "123456", now + Duration::from_millis(500)),
+            Err(PairingError::TooSoon)
+        );
+        assert_eq!(pairing.as_ref().map(|p| p.attempts), Some(1));
+        assert_eq!(
+            redeem_pairing(&mut pairing, "123456", now + PAIRING_RETRY_DELAY),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_parse_bearer() {
+        assert_eq!(parse_bearer("Bearer abc123"), Some("abc123"));
+        assert_eq!(parse_bearer("bearer  abc123 "), Some("abc123"));
+        assert_eq!(parse_bearer("Basic abc123"), None);
+        assert_eq!(parse_bearer("Bearer "), None);
+        assert_eq!(parse_bearer("abc123"), None);
+    }
+
+    #[test]
+    fn test_trigger_request_defaults_to_toggle() {
+        let request: TriggerRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(request.action, TriggerAction::Toggle);
+        assert!(request.binding.is_none());
+
+        let request: TriggerRequest =
+            serde_json::from_str(r#"{"action": "stop", "binding": "voice_command"}"#).unwrap();
+        assert_eq!(request.action, TriggerAction::Stop);
+        assert_eq!(request.binding.as_deref(), Some("voice_command"));
+    }
+
+    #[test]
+    fn test_hash_token() {
+        assert_eq!(hash_token("abc"), hash_token("abc"));
+        assert_ne!(hash_token("abc"), hash_token("abd"));
+        assert_eq!(hash_token("abc").len(), 64);
+    }
+}
@@ -0,0 +1,155 @@
+//! Retries for LLM requests that failed on rate limits or server errors, with
+//! jittered exponential backoff so parallel requests don't retry in lockstep.
+
+use crate::errors::AppError;
+use async_openai::error::OpenAIError;
+use log::{info, warn};
+use rand::Rng;
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+/// Attempts per request, including the first
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry, doubled for each one after it
+const BASE_DELAY_MS: u64 = 500;
+
+/// Longest wait between attempts
+const MAX_DELAY_MS: u64 = 8_000;
+
+/// An error that knows whether it's worth retrying: rate limits, overload and
+/// server errors, as opposed to bad keys or requests. The answer comes from the
+/// HTTP status or the API's error type, never from numbers in the message.
+pub trait Retryable: Display {
+    fn is_retryable(&self) -> bool;
+}
+
+impl Retryable for AppError {
+    fn is_retryable(&self) -> bool {
+        self.retryable
+    }
+}
+
+impl Retryable for OpenAIError {
+    fn is_retryable(&self) -> bool {
+        AppError::from(self).retryable
+    }
+}
+
+/// The wait before retry number `retry` (starting at 1): a random point in the
+/// upper half of the exponential delay
+fn backoff_delay(retry: u32) -> Duration {
+    let exponential = BASE_DELAY_MS
+        .saturating_mul(1 << (retry - 1).min(16))
+        .min(MAX_DELAY_MS);
+    let jittered = rand::thread_rng().gen_range(exponential / 2..=exponential);
+    Duration::from_millis(jittered)
+}
+
+/// Tracks the attempts of one request. Call [`Retry::should_retry`] after each
+/// failure, and [`Retry::succeeded`] once the request goes through.
+pub struct Retry {
+    label: String,
+    attempt: u32,
+}
+
+impl Retry {
+    /// `label` names the request in the logs, e.g. "refinement with gpt-4o"
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            attempt: 1,
+        }
+    }
+
+    /// Whether to try again after `error`. Retryable errors wait out the backoff
+    /// before this returns true, until the attempts run out.
+    pub async fn should_retry(&mut self, error: &impl Retryable) -> bool {
+        if !error.is_retryable() {
+            return false;
+        }
+        if self.attempt >= MAX_ATTEMPTS {
+            warn!(
+                "{} failed after {} attempts: {}",
+                self.label, self.attempt, error
+            );
+            return false;
+        }
+        let delay = backoff_delay(self.attempt);
+        warn!(
+            "{} failed (attempt {}/{}), retrying in {}ms: {}",
+            self.label,
+            self.attempt,
+            MAX_ATTEMPTS,
+            delay.as_millis(),
+            error
+        );
+        tokio::time::sleep(delay).await;
+        self.attempt += 1;
+        true
+    }
+
+    /// Log a success that needed more than one attempt
+    pub fn succeeded(&self) {
+        if self.attempt > 1 {
+            info!("{} succeeded on attempt {}", self.label, self.attempt);
+        }
+    }
+}
+
+/// Run `request` until it succeeds, fails with an error that isn't worth
+/// retrying, or runs out of attempts
+pub async fn with_retry<T, E, F, Fut>(label: impl Into<String>, mut request: F) -> Result<T, E>
+where
+    E: Retryable,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut retry = Retry::new(label);
+    loop {
+        match request().await {
+            Ok(value) => {
+                retry.succeeded();
+                return Ok(value);
+            }
+            Err(e) if retry.should_retry(&e).await => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_openai::error::ApiError;
+
+    #[test]
+    fn test_is_retryable() {
+        let api_error = |code: &str, message: &str| {
+            OpenAIError::ApiError(ApiError {
+                message: message.to_string(),
+                r#type: None,
+                param: None,
+                code: Some(code.to_string()),
+            })
+        };
+        assert!(api_error("429", "Too Many Requests").is_retryable());
+        assert!(api_error("503", "UNAVAILABLE").is_retryable());
+        assert!(!api_error("401", "Unauthorized").is_retryable());
+        // Status-like numbers in a rejected request don't make it retryable
+        assert!(!api_error("400", "max_tokens 1500 exceeds the limit of 500").is_retryable());
+        assert!(!AppError::invalid_input("max_tokens 1500 is too large").is_retryable());
+        assert!(AppError::from("Gemini API error 503: UNAVAILABLE").is_retryable());
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        for retry in 1..=3 {
+            let expected = BASE_DELAY_MS << (retry - 1);
+            let delay = backoff_delay(retry).as_millis() as u64;
+            assert!(delay >= expected / 2 && delay <= expected);
+        }
+        assert!(backoff_delay(30).as_millis() as u64 <= MAX_DELAY_MS);
+    }
+}
@@ -0,0 +1,147 @@
+//! Finding history entries by meaning rather than by keyword.
+//!
+//! Each entry's text is embedded once with the local model and the vector stored
+//! with the entry. Entries are embedded when a search runs, so the first search
+//! after installing (or after a batch of dictations) catches the index up before
+//! ranking. A query is embedded the same way and entries are ranked by cosine
+//! similarity.
+
+use crate::errors::AppError;
+use crate::managers::embedding::EmbeddingManager;
+use crate::managers::history::{HistoryEntry, HistoryManager};
+use log::info;
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+
+/// Entries embedded per pass while catching the index up
+const INDEX_BATCH: usize = 64;
+
+/// A history entry found by a semantic search
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct SemanticMatch {
+    pub entry: HistoryEntry,
+    /// Cosine similarity to the query, higher is closer
+    pub score: f32,
+}
+
+pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+pub fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// The `limit` ids closest to `query`, closest first
+fn rank(query: &[f32], candidates: &[(i64, Vec<f32>)], limit: usize) -> Vec<(i64, f32)> {
+    let mut scored: Vec<(i64, f32)> = candidates
+        .iter()
+        .map(|(id, embedding)| (*id, cosine_similarity(query, embedding)))
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.truncate(limit);
+    scored
+}
+
+/// Embed entries that don't have an embedding yet. Returns how many were embedded.
+fn index_missing(history: &HistoryManager, embedder: &EmbeddingManager) -> Result<usize, String> {
+    let mut indexed = 0;
+    loop {
+        let batch = history
+            .get_unembedded_texts(INDEX_BATCH)
+            .map_err(|e| e.to_string())?;
+        if batch.is_empty() {
+            return Ok(indexed);
+        }
+        let texts: Vec<String> = batch.iter().map(|(_, text)| text.clone()).collect();
+        let embeddings = embedder.embed_passages(&texts)?;
+        for ((id, _), embedding) in batch.iter().zip(&embeddings) {
+            history
+                .save_embedding(*id, embedding)
+                .map_err(|e| e.to_string())?;
+        }
+        indexed += batch.len();
+    }
+}
+
+/// Find the `limit` history entries closest in meaning to `query`
+pub async fn search(
+    history: Arc<HistoryManager>,
+    embedder: Arc<EmbeddingManager>,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SemanticMatch>, AppError> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Err(AppError::invalid_input("Enter something to search for"));
+    }
+
+    // Embedding runs the model synchronously, so keep it off the async runtime
+    let index_history = history.clone();
+    let ranked = tokio::task::spawn_blocking(move || -> Result<Vec<(i64, f32)>, String> {
+        let indexed = index_missing(&index_history, &embedder)?;
+        if indexed > 0 {
+            info!("Embedded {} history entries for semantic search", indexed);
+        }
+        let query_embedding = embedder.embed_query(&query)?;
+        let candidates = index_history.get_embeddings().map_err(|e| e.to_string())?;
+        Ok(rank(&query_embedding, &candidates, limit))
+    })
+    .await
+    .map_err(|e| format!("Semantic search failed: {}", e))??;
+
+    let mut matches = Vec::with_capacity(ranked.len());
+    for (id, score) in ranked {
+        if let Some(entry) = history
+            .get_entry_by_id(id)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            matches.push(SemanticMatch { entry, score });
+        }
+    }
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_round_trip() {
+        let embedding = vec![0.5, -1.25, 3.0e-7];
+        assert_eq!(decode_embedding(&encode_embedding(&embedding)), embedding);
+    }
+
+    #[test]
+    fn test_rank() {
+        let candidates = vec![
+            (1, vec![0.0, 1.0]),
+            (2, vec![1.0, 0.1]),
+            (3, vec![-1.0, 0.0]),
+            (4, vec![0.0, 0.0, 1.0]),
+        ];
+        let ranked = rank(&[1.0, 0.0], &candidates, 3);
+        let ids: Vec<i64> = ranked.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![2, 1, 4]);
+        assert!((ranked[1].1).abs() < 1e-6);
+    }
+}
@@ -83,6 +83,42 @@ pub struct ShortcutBinding {
     pub description: String,
     pub default_binding: String,
     pub current_binding: String,
+    /// Stop a toggle-mode recording after this many seconds of silence following
+    /// speech, as if the shortcut were tapped again (0 = disabled)
+    #[serde(default)]
+    pub auto_stop_silence_seconds: u32,
+    /// Which recordings started with this shortcut are refined: taps, holds, all or
+    /// none
+    #[serde(default)]
+    pub tap_hold_mode: TapHoldMode,
+}
+
+/// Whether a tap or a hold of a recording shortcut refines the transcription
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TapHoldMode {
+    /// A tap refines and a hold pastes the raw transcription (default)
+    #[default]
+    TapRefines,
+    /// A hold refines and a tap pastes the raw transcription
+    HoldRefines,
+    /// Taps and holds both refine
+    AlwaysRefined,
+    /// Taps and holds both paste the raw transcription
+    AlwaysRaw,
+}
+
+impl TapHoldMode {
+    /// Whether a recording started with a hold, or with a tap when `hold` is false,
+    /// is refined
+    pub fn refines(self, hold: bool) -> bool {
+        match self {
+            TapHoldMode::TapRefines => !hold,
+            TapHoldMode::HoldRefines => hold,
+            TapHoldMode::AlwaysRefined => true,
+            TapHoldMode::AlwaysRaw => false,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -129,6 +165,63 @@ pub struct LLMProvider {
     /// Whether this provider supports OAuth authentication
     #[serde(default)]
     pub supports_oauth: bool,
+    /// Saved API key/endpoint pairs the user can switch between
+    #[serde(default)]
+    pub credentials: Vec<ProviderCredential>,
+    /// Credential currently copied into `api_key` and `base_url`, if any
+    #[serde(default)]
+    pub active_credential_id: Option<String>,
+    /// Extra headers sent with every request, e.g. `x-org-id` for a gateway.
+    /// `{api_key}` in a value is replaced with the API key.
+    #[serde(default)]
+    pub custom_headers: HashMap<String, String>,
+    /// Count this provider as free toward the spend limits, e.g. a self-hosted
+    /// server elsewhere on the network
+    #[serde(default)]
+    pub free: bool,
+}
+
+/// A named API key for a provider, e.g. "Personal" or "Work gateway"
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct ProviderCredential {
+    /// Unique identifier (UUID string)
+    pub id: String,
+    /// Display name
+    pub name: String,
+    pub api_key: String,
+    /// Endpoint to use with this key (a company gateway may differ from the provider's)
+    pub base_url: String,
+}
+
+impl LLMProvider {
+    /// Make a saved credential the one used for requests
+    pub fn activate_credential(&mut self, credential_id: &str) -> Result<(), String> {
+        let credential = self
+            .credentials
+            .iter()
+            .find(|c| c.id == credential_id)
+            .ok_or_else(|| format!("Credential '{}' not found", credential_id))?;
+        self.api_key = credential.api_key.clone();
+        self.base_url = credential.base_url.clone();
+        self.active_credential_id = Some(credential.id.clone());
+        Ok(())
+    }
+
+    /// Copy an edited `api_key` back into the active credential. Preset providers keep
+    /// the credential's endpoint, since their edit dialog always sends the preset URL.
+    pub fn sync_active_credential(&mut self) {
+        let Some(active_id) = &self.active_credential_id else {
+            return;
+        };
+        if let Some(credential) = self.credentials.iter_mut().find(|c| &c.id == active_id) {
+            credential.api_key = self.api_key.clone();
+            if self.is_custom {
+                credential.base_url = self.base_url.clone();
+            } else {
+                self.base_url = credential.base_url.clone();
+            }
+        }
+    }
 }
 
 /// Model configuration for a specific provider
@@ -148,12 +241,93 @@ pub struct LLMModel {
     /// Whether this model is enabled and should appear in model selectors
     #[serde(default = "default_model_enabled")]
     pub enabled: bool,
+    /// Price in USD per million prompt tokens, overriding the built-in pricing table
+    #[serde(default)]
+    pub input_price_per_million: Option<f64>,
+    /// Price in USD per million completion tokens, overriding the built-in pricing table
+    #[serde(default)]
+    pub output_price_per_million: Option<f64>,
+    /// OpenRouter fallback models and provider preferences, only sent to OpenRouter
+    #[serde(default)]
+    pub openrouter_routing: Option<OpenRouterRouting>,
+    /// Thinking budget and safety settings, only sent through the native Gemini API
+    #[serde(default)]
+    pub gemini_options: Option<GeminiOptions>,
+    /// Effort and thinking budget for reasoning models
+    #[serde(default)]
+    pub reasoning: Option<ReasoningOptions>,
 }
 
 fn default_model_enabled() -> bool {
     true
 }
 
+/// OpenRouter's reliability options for a model, sent as extra request body fields
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct OpenRouterRouting {
+    /// Models to fall back to, in order, if this one fails (OpenRouter's `models`)
+    #[serde(default)]
+    pub fallback_models: Vec<String>,
+    /// Upstream providers to try first, in order (`provider.order`)
+    #[serde(default)]
+    pub provider_order: Vec<String>,
+    /// Whether OpenRouter may route to providers outside `provider_order`
+    #[serde(default = "default_allow_fallbacks")]
+    pub allow_fallbacks: bool,
+    /// Prefer the cheapest ("price"), fastest ("throughput") or quickest to
+    /// respond ("latency") provider instead of OpenRouter's load balancing
+    #[serde(default)]
+    pub sort: Option<String>,
+}
+
+fn default_allow_fallbacks() -> bool {
+    true
+}
+
+impl Default for OpenRouterRouting {
+    fn default() -> Self {
+        Self {
+            fallback_models: Vec::new(),
+            provider_order: Vec::new(),
+            allow_fallbacks: default_allow_fallbacks(),
+            sort: None,
+        }
+    }
+}
+
+/// Gemini generation options, sent as `generationConfig.thinkingConfig` and
+/// `safetySettings`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct GeminiOptions {
+    /// Tokens the model may spend thinking: 0 turns thinking off, -1 lets the model
+    /// decide. Unset keeps the model's default.
+    #[serde(default)]
+    pub thinking_budget: Option<i32>,
+    /// Block threshold for every harm category, e.g. "BLOCK_ONLY_HIGH".
+    /// Unset keeps Google's default filtering.
+    #[serde(default)]
+    pub safety_threshold: Option<String>,
+}
+
+/// Reasoning controls for o-series, Claude thinking and Gemini thinking models
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct ReasoningOptions {
+    /// "minimal", "low", "medium" or "high", for OpenAI-style `reasoning_effort`
+    #[serde(default)]
+    pub effort: Option<String>,
+    /// Tokens the model may spend thinking, for Claude and Gemini
+    #[serde(default)]
+    pub budget_tokens: Option<u32>,
+    /// Keep the reasoning trace out of chat responses (and ask OpenRouter not to send
+    /// it). Traces are always stripped from refined text before it's pasted.
+    #[serde(default = "default_hide_reasoning")]
+    pub hide_reasoning: bool,
+}
+
+fn default_hide_reasoning() -> bool {
+    true
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "lowercase")]
 pub enum OverlayPosition {
@@ -162,6 +336,37 @@ pub enum OverlayPosition {
     Bottom,
 }
 
+/// Which engine reads text aloud
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TtsBackend {
+    /// Kokoro once its model is downloaded, the system voice until then
+    #[default]
+    Auto,
+    /// The local Kokoro model
+    Kokoro,
+    /// The operating system's synthesizer: `say` on macOS, SAPI on Windows,
+    /// espeak-ng on Linux
+    System,
+    /// OpenAI's hosted speech API
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// ElevenLabs' hosted speech API
+    #[serde(rename = "elevenlabs")]
+    ElevenLabs,
+}
+
+impl TtsBackend {
+    /// The service a cloud backend's key and voice are stored under
+    pub fn cloud_service(&self) -> Option<&'static str> {
+        match self {
+            TtsBackend::OpenAi => Some("openai"),
+            TtsBackend::ElevenLabs => Some("elevenlabs"),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelUnloadTimeout {
@@ -185,6 +390,33 @@ pub enum PasteMethod {
     CtrlShiftV,
 }
 
+/// How results pasted into a terminal are changed, so a line break the refiner added
+/// doesn't run a dictated command before it's been read
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalPasteMode {
+    /// Pasted as is
+    #[default]
+    Off,
+    /// Joined onto one line, without line breaks before or after
+    StripNewlines,
+    /// Joined onto one line and quoted as a single shell argument
+    ShellEscape,
+}
+
+/// Keyboard layout used to interpret character keys in shortcut strings
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyboardLayout {
+    /// Detect from the system keyboard layout
+    #[default]
+    Auto,
+    Qwerty,
+    Azerty,
+    Qwertz,
+    Dvorak,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum ClipboardHandling {
@@ -261,6 +493,13 @@ pub struct PromptCategory {
     /// Optional model override for this category (None = use default coherent model)
     #[serde(default)]
     pub model_override: Option<String>,
+    /// Transcription language forced while this category is active, overriding
+    /// `selected_language` (None = use the global language)
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Add topic headings and a table of contents to long refinements
+    #[serde(default)]
+    pub chaptering: bool,
 }
 
 /// Maps an application to a category
@@ -271,6 +510,9 @@ pub struct AppCategoryMapping {
     pub category_id: String,
 }
 
+/// Most apps kept in `detected_apps_history`, most recently seen first
+pub const MAX_DETECTED_APPS: usize = 100;
+
 /// Detected app info (for tracking history)
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct DetectedApp {
@@ -330,6 +572,117 @@ pub struct VoiceCommand {
     /// Whether this is a built-in command
     #[serde(default)]
     pub is_builtin: bool,
+    /// Named values the script takes as `${name}`, extracted from what was said
+    /// (custom commands)
+    #[serde(default)]
+    pub parameters: Vec<CommandParameter>,
+}
+
+/// Type of a custom command's parameter
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterType {
+    #[default]
+    String,
+    Number,
+    /// One of `CommandParameter::options`
+    Enum,
+}
+
+/// A named parameter of a custom voice command, e.g. `level` in "set volume to {level}"
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct CommandParameter {
+    /// Name used in trigger phrases as `{name}` and in the script as `${name}`
+    pub name: String,
+    #[serde(default)]
+    pub param_type: ParameterType,
+    /// Allowed values (enum parameters)
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// What the value means, to help the LLM extract it
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// A scheduled prompt to dictate something, e.g. a standup at 9:25 on weekdays
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct Reminder {
+    pub id: String,
+    /// Shown in the notification, e.g. "Dictate your standup"
+    pub label: String,
+    /// Local time of day as "HH:MM"
+    pub time: String,
+    /// ISO weekdays to repeat on (1 = Monday … 7 = Sunday), every day if empty.
+    /// Ignored for one-off reminders.
+    #[serde(default)]
+    pub days: Vec<u32>,
+    /// "YYYY-MM-DD" for a one-off reminder, which disables itself once shown
+    #[serde(default)]
+    pub date: Option<String>,
+    /// Prompt category the next dictation uses after this reminder
+    #[serde(default)]
+    pub category_id: Option<String>,
+    #[serde(default = "default_reminder_enabled")]
+    pub enabled: bool,
+}
+
+fn default_reminder_enabled() -> bool {
+    true
+}
+
+/// A countdown started by voice, e.g. "remind me in 20 minutes to check the oven"
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct VoiceTimer {
+    pub id: String,
+    /// What to be reminded of, if anything was said
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Length of the timer as asked for
+    pub duration_secs: u64,
+    /// Unix timestamp of when the timer runs out
+    pub fires_at: i64,
+}
+
+/// A project to dictate for, switched by voice ("switch to project Atlas")
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    /// Prompt mode to switch to when the project is activated
+    #[serde(default)]
+    pub prompt_mode: Option<PromptMode>,
+    /// Category to make the default when the project is activated
+    #[serde(default)]
+    pub default_category_id: Option<String>,
+    /// Words recognized while the project is active, on top of the custom words
+    #[serde(default)]
+    pub custom_words: Vec<String>,
+}
+
+/// A deleted item and the data needed to restore it
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum TrashedItem {
+    CoherentPrompt(LLMPrompt),
+    PromptCategory {
+        category: PromptCategory,
+        /// App mappings that pointed at the category when it was deleted
+        app_mappings: Vec<AppCategoryMapping>,
+    },
+    VoiceCommand(VoiceCommand),
+    AppMapping(AppCategoryMapping),
+}
+
+/// An entry in the trash, kept until restored or purged after the retention period
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct DeletedItem {
+    /// Unique identifier of the trash entry (not the item's own ID)
+    pub id: String,
+    /// Display name of the deleted item
+    pub name: String,
+    /// Unix timestamp (seconds) when the item was deleted
+    pub deleted_at: i64,
+    pub item: TrashedItem,
 }
 
 impl Default for ModelUnloadTimeout {
@@ -376,6 +729,20 @@ impl ModelUnloadTimeout {
             _ => self.to_minutes().map(|m| m * 60),
         }
     }
+
+    /// The next longer timeout, used on battery so the model isn't reloaded as often
+    pub fn lengthened(self) -> Self {
+        match self {
+            ModelUnloadTimeout::Immediately => ModelUnloadTimeout::Min2,
+            ModelUnloadTimeout::Min2 => ModelUnloadTimeout::Min5,
+            ModelUnloadTimeout::Min5 => ModelUnloadTimeout::Min10,
+            ModelUnloadTimeout::Min10 => ModelUnloadTimeout::Min15,
+            ModelUnloadTimeout::Min15 | ModelUnloadTimeout::Hour1 => ModelUnloadTimeout::Hour1,
+            ModelUnloadTimeout::Never => ModelUnloadTimeout::Never,
+            // Debug only, left alone so it stays quick to test
+            ModelUnloadTimeout::Sec5 => ModelUnloadTimeout::Sec5,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -404,6 +771,18 @@ impl SoundTheme {
     }
 }
 
+/// A companion device allowed to start and stop recordings over the network
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct PairedDevice {
+    pub id: String,
+    /// Name the device gave when pairing, e.g. "Apple Watch"
+    pub name: String,
+    /// SHA-256 of the device's access token, hex encoded
+    pub token_hash: String,
+    /// Unix timestamp of when the device was paired
+    pub paired_at: i64,
+}
+
 /* still handy for composing the initial JSON in the store ------------- */
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct AppSettings {
@@ -434,6 +813,9 @@ pub struct AppSettings {
     pub translate_to_english: bool,
     #[serde(default = "default_selected_language")]
     pub selected_language: String,
+    /// Detect the language of each phrase separately, for speech that mixes languages
+    #[serde(default)]
+    pub code_switching: bool,
     #[serde(default = "default_overlay_position")]
     pub overlay_position: OverlayPosition,
     #[serde(default = "default_debug_mode")]
@@ -442,6 +824,29 @@ pub struct AppSettings {
     pub log_level: LogLevel,
     #[serde(default)]
     pub custom_words: Vec<String>,
+    /// Terms whose exact casing is enforced after transcription and refinement
+    #[serde(default)]
+    pub cased_terms: Vec<String>,
+    /// Ask the refiner to cite substantial removals and keep a raw-to-refined diff
+    #[serde(default)]
+    pub show_edits: bool,
+    /// Check refinements for names and numbers that weren't in the transcription
+    #[serde(default)]
+    pub verify_refinement: bool,
+    /// Give the refiner today's earlier dictations so names and references stay
+    /// consistent across the day
+    #[serde(default)]
+    pub day_context_enabled: bool,
+    /// Words a refinement needs before categories with chaptering get topic headings
+    #[serde(default = "default_chaptering_min_words")]
+    pub chaptering_min_words: u32,
+    /// Give new history entries a short title describing what was said
+    #[serde(default = "default_history_titles_enabled")]
+    pub history_titles_enabled: bool,
+    /// Paste the refined clipboard text; when off, the refinement replaces the
+    /// clipboard content instead
+    #[serde(default = "default_refine_clipboard_paste")]
+    pub refine_clipboard_paste: bool,
     #[serde(default)]
     pub model_unload_timeout: ModelUnloadTimeout,
     #[serde(default = "default_word_correction_threshold")]
@@ -464,9 +869,24 @@ pub struct AppSettings {
     /// Default model ID for coherent/ramble mode
     #[serde(default)]
     pub default_coherent_model_id: Option<String>,
+    /// Models to retry refinement with, in order, when the coherent model's provider
+    /// is rate limited, overloaded or down
+    #[serde(default)]
+    pub coherent_fallback_model_ids: Vec<String>,
+    /// Refine with the embedded llama.cpp model when no coherent model is configured
+    /// or every provider fails
+    #[serde(default)]
+    pub local_llm_enabled: bool,
+    /// GGUF file for the embedded model
+    #[serde(default)]
+    pub local_llm_model_path: Option<String>,
     /// Default model ID for voice commands
     #[serde(default)]
     pub default_voice_model_id: Option<String>,
+    /// Model for history entry and chat titles. History titles are made from the
+    /// text itself when unset, and chats fall back to the chat model.
+    #[serde(default)]
+    pub default_title_model_id: Option<String>,
     /// OpenAI OAuth reasoning effort level (none, low, medium, high, xhigh)
     #[serde(default = "default_openai_reasoning_effort")]
     pub openai_reasoning_effort: String,
@@ -509,6 +929,12 @@ pub struct AppSettings {
     /// History of detected applications (for dropdown suggestions)
     #[serde(default)]
     pub detected_apps_history: Vec<DetectedApp>,
+    /// Whether apps detected during dictation are added to `detected_apps_history`
+    #[serde(default = "default_record_detected_apps")]
+    pub record_detected_apps: bool,
+    /// Bundle identifiers never added to `detected_apps_history` (e.g. private browsers)
+    #[serde(default)]
+    pub detected_apps_excluded: Vec<String>,
     /// Default category for apps not in known_apps or user mappings
     #[serde(default = "default_category_id")]
     pub default_category_id: String,
@@ -519,9 +945,44 @@ pub struct AppSettings {
     /// Default model for voice command execution
     #[serde(default = "default_voice_command_model")]
     pub voice_command_default_model: String,
+    /// Seconds to keep listening for a follow-up after a voice command completes
+    /// (0 = off)
+    #[serde(default)]
+    pub voice_command_follow_up_seconds: u32,
     /// User-defined voice commands
     #[serde(default = "default_voice_commands")]
     pub voice_commands: Vec<VoiceCommand>,
+    /// Scheduled dictation reminders
+    #[serde(default)]
+    pub reminders: Vec<Reminder>,
+    /// Running voice timers, soonest first
+    #[serde(default)]
+    pub timers: Vec<VoiceTimer>,
+    /// Projects that can be switched to by voice
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    /// Project whose vocabulary is in use and whose name fills `${project}`
+    #[serde(default)]
+    pub active_project_id: Option<String>,
+    /// Distraction-free dictation: raw output only, no sounds, no chat windows and
+    /// no LLM calls, with just a small dot in the overlay while recording
+    #[serde(default)]
+    pub focus_mode: bool,
+    /// Hide the recording overlay and chat windows while another app is full screen
+    /// or the screen is being shared
+    #[serde(default)]
+    pub hide_when_presenting: bool,
+    /// Apps (bundle identifiers or names) that results are never pasted into; the
+    /// result stays in history and a notification is shown instead
+    #[serde(default = "default_paste_blocked_apps")]
+    pub paste_blocked_apps: Vec<String>,
+    /// Longest result that's pasted; longer ones are held in history for review.
+    /// 0 disables the limit
+    #[serde(default = "default_max_paste_chars")]
+    pub max_paste_chars: u32,
+    /// How results pasted into a known terminal app are changed
+    #[serde(default)]
+    pub terminal_paste_mode: TerminalPasteMode,
     // TTS Settings
     #[serde(default = "default_tts_enabled")]
     pub tts_enabled: bool,
@@ -532,6 +993,21 @@ pub struct AppSettings {
     #[serde(default = "default_tts_volume")]
     pub tts_volume: f32,
     #[serde(default)]
+    pub tts_backend: TtsBackend,
+    /// Voice for the system backend, by id from `list_system_voices`; `None` uses
+    /// the system default
+    #[serde(default)]
+    pub tts_system_voice: Option<String>,
+    /// API keys for cloud TTS, keyed by service ("openai", "elevenlabs")
+    #[serde(default)]
+    pub tts_api_keys: HashMap<String, String>,
+    /// Chosen voice for each cloud TTS service, by voice id
+    #[serde(default)]
+    pub tts_cloud_voices: HashMap<String, String>,
+    /// Read chat replies aloud as they arrive
+    #[serde(default)]
+    pub chat_read_aloud: bool,
+    #[serde(default)]
     pub filler_word_filter: Option<String>,
     /// Whether to collapse repeated words (e.g., "I I I am" → "I am")
     #[serde(default = "default_collapse_repeated_words")]
@@ -566,6 +1042,150 @@ pub struct AppSettings {
     /// Path to a system prompt file that will be injected into all LLM calls
     #[serde(default)]
     pub system_prompt_file: Option<String>,
+    /// Soft-deleted prompts, categories, voice commands and app mappings
+    #[serde(default)]
+    pub deleted_items: Vec<DeletedItem>,
+    /// Days to keep deleted items before they are purged (0 = purge immediately)
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
+    /// Keyboard layout for shortcut key labels (Auto = detect from the system).
+    /// Settings saved before this existed have QWERTY key names in their bindings,
+    /// so they default to QWERTY rather than Auto.
+    #[serde(default = "default_keyboard_layout")]
+    pub keyboard_layout: KeyboardLayout,
+    /// Decode speech while still recording and emit `transcription-partial` events
+    #[serde(default = "default_live_transcription")]
+    pub live_transcription: bool,
+    /// Minutes before paused shortcuts are re-enabled automatically (0 = until resumed)
+    #[serde(default = "default_shortcuts_pause_minutes")]
+    pub shortcuts_pause_minutes: u32,
+    /// API keys for cloud transcription models, keyed by service ("openai", "deepgram")
+    #[serde(default)]
+    pub stt_api_keys: HashMap<String, String>,
+    /// Cancel a toggle recording when its key is pressed twice in quick succession
+    #[serde(default)]
+    pub double_press_cancel: bool,
+    /// Maximum time between the two presses of a double-press cancel
+    #[serde(default = "default_double_press_cancel_window_ms")]
+    pub double_press_cancel_window_ms: u64,
+    /// Cancel a recording if no speech is detected within this many seconds (0 = never)
+    #[serde(default)]
+    pub no_speech_timeout_seconds: u32,
+    /// Block cloud refinement once today's estimated spend reaches this many USD
+    #[serde(default)]
+    pub daily_spend_limit_usd: Option<f64>,
+    /// Block a single cloud refinement whose estimated cost exceeds this many USD
+    #[serde(default)]
+    pub per_request_spend_limit_usd: Option<f64>,
+    /// Run the microphone through RNNoise before VAD and transcription
+    #[serde(default)]
+    pub noise_suppression: bool,
+    /// Listen for the wake word on the always-on microphone
+    #[serde(default)]
+    pub wake_word_enabled: bool,
+    /// Binding started when the wake word is heard, `transcribe` or `voice_command`
+    #[serde(default = "default_wake_word_binding")]
+    pub wake_word_binding: String,
+    /// Classifier score, from 0 to 1, above which the wake word counts as heard
+    #[serde(default = "default_wake_word_threshold")]
+    pub wake_word_threshold: f32,
+    /// openWakeWord classifier for a custom phrase, instead of the bundled "Hey Ramble"
+    #[serde(default)]
+    pub wake_word_model_path: Option<String>,
+    /// Have the screen reader announce recording, refining and paste state changes
+    #[serde(default = "default_accessibility_announcements")]
+    pub accessibility_announcements: bool,
+    /// Save power on battery below `battery_saver_threshold`: a smaller model, no
+    /// always-on microphone and longer model unload timeouts
+    #[serde(default = "default_battery_saver_enabled")]
+    pub battery_saver_enabled: bool,
+    /// Battery percentage at or below which the battery saver kicks in
+    #[serde(default = "default_battery_saver_threshold")]
+    pub battery_saver_threshold: u8,
+    /// Take over the play/pause key, including Bluetooth headset buttons, to start
+    /// and stop `media_key_binding`
+    #[serde(default)]
+    pub media_key_trigger_enabled: bool,
+    /// Binding the play/pause key triggers, one of `MEDIA_KEY_BINDINGS`
+    #[serde(default = "default_media_key_binding")]
+    pub media_key_binding: String,
+    /// Accept start/stop requests from paired devices
+    #[serde(default)]
+    pub remote_trigger_enabled: bool,
+    /// Port the remote trigger endpoint listens on
+    #[serde(default = "default_remote_trigger_port")]
+    pub remote_trigger_port: u16,
+    /// Listen on the local network rather than only on this computer. Requests are
+    /// plain HTTP, so anyone on the network can see tokens and try pairing codes.
+    #[serde(default)]
+    pub remote_trigger_lan_access: bool,
+    /// Devices paired with the remote trigger endpoint
+    #[serde(default)]
+    pub remote_trigger_devices: Vec<PairedDevice>,
+    /// Folder (e.g. in iCloud Drive or Dropbox) that settings are synced through
+    #[serde(default)]
+    pub sync_folder: Option<String>,
+    /// Whether the sync passphrase is saved in the OS keychain. The passphrase
+    /// itself is never written to the settings.
+    #[serde(default)]
+    pub sync_passphrase_saved: bool,
+}
+
+/// Bindings the wake word can start
+pub const WAKE_WORD_BINDINGS: &[&str] = &["transcribe", "voice_command"];
+
+/// Bindings the play/pause media key can trigger
+pub const MEDIA_KEY_BINDINGS: &[&str] = &["transcribe", "voice_command"];
+
+/// Bindings paired devices can trigger
+pub const REMOTE_TRIGGER_BINDINGS: &[&str] = &["transcribe", "voice_command"];
+
+fn default_wake_word_binding() -> String {
+    "transcribe".to_string()
+}
+
+fn default_wake_word_threshold() -> f32 {
+    0.5
+}
+
+fn default_accessibility_announcements() -> bool {
+    true
+}
+
+fn default_battery_saver_enabled() -> bool {
+    true
+}
+
+fn default_battery_saver_threshold() -> u8 {
+    20
+}
+
+fn default_media_key_binding() -> String {
+    "transcribe".to_string()
+}
+
+fn default_remote_trigger_port() -> u16 {
+    47613
+}
+
+fn default_trash_retention_days() -> u32 {
+    30
+}
+
+fn default_live_transcription() -> bool {
+    false
+}
+
+fn default_keyboard_layout() -> KeyboardLayout {
+    KeyboardLayout::Qwerty
+}
+
+fn default_double_press_cancel_window_ms() -> u64 {
+    400
+}
+
+fn default_shortcuts_pause_minutes() -> u32 {
+    60
 }
 
 fn default_model() -> String {
@@ -657,6 +1277,27 @@ fn default_hold_threshold_ms() -> u64 {
     500 // 500ms feels more natural - fast enough for PTT, slow enough for accidental taps
 }
 
+fn default_record_detected_apps() -> bool {
+    true
+}
+
+fn default_max_paste_chars() -> u32 {
+    5000
+}
+
+fn default_paste_blocked_apps() -> Vec<String> {
+    [
+        "com.1password.1password",
+        "com.agilebits.onepassword7",
+        "com.bitwarden.desktop",
+        "org.keepassxc.keepassxc",
+        "com.apple.keychainaccess",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
 fn default_category_id() -> String {
     "medium".to_string()
 }
@@ -675,6 +1316,18 @@ fn default_collapse_repeated_words() -> bool {
     true
 }
 
+fn default_chaptering_min_words() -> u32 {
+    1500
+}
+
+fn default_history_titles_enabled() -> bool {
+    true
+}
+
+fn default_refine_clipboard_paste() -> bool {
+    true
+}
+
 fn default_quick_chat_initial_prompt() -> String {
     "You are a helpful assistant. You are given some context from the user's screen or selection to help you answer their questions.\n\nCONTEXT FROM USER SELECTION:\n${selection}".to_string()
 }
@@ -742,6 +1395,7 @@ fn default_voice_commands() -> Vec<VoiceCommand> {
             script: None,
             model_override: None,
             is_builtin: true,
+            parameters: Vec::new(),
         },
         VoiceCommand {
             id: "web_search".to_string(),
@@ -757,6 +1411,7 @@ fn default_voice_commands() -> Vec<VoiceCommand> {
             script: None,
             model_override: None,
             is_builtin: true,
+            parameters: Vec::new(),
         },
         VoiceCommand {
             id: "refactor_code".to_string(),
@@ -775,6 +1430,7 @@ fn default_voice_commands() -> Vec<VoiceCommand> {
             script: None,
             model_override: Some("gpt-4o".to_string()), // Needs reasoning capability
             is_builtin: true,
+            parameters: Vec::new(),
         },
         VoiceCommand {
             id: "print".to_string(),
@@ -794,6 +1450,272 @@ fn default_voice_commands() -> Vec<VoiceCommand> {
             script: None,
             model_override: None,
             is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "fix_selection".to_string(),
+            name: "Fix Selection".to_string(),
+            phrases: vec![
+                "fix this".to_string(),
+                "proofread this".to_string(),
+                "fix the grammar".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Corrects the spelling, grammar and punctuation of the selected text and replaces the selection with the result."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "code_comment".to_string(),
+            name: "Code Comment".to_string(),
+            phrases: vec!["comment".to_string(), "add a comment".to_string()],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Types the text that follows as a wrapped code comment in the editor's language, above the selected code when there is any."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "doc_comment".to_string(),
+            name: "Doc Comment".to_string(),
+            phrases: vec!["doc comment".to_string(), "docstring".to_string()],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Types the text that follows as a documentation comment (a docstring in Python) for the selected code."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "maximize_window".to_string(),
+            name: "Maximize Window".to_string(),
+            phrases: vec![
+                "maximize window".to_string(),
+                "maximize".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some("Maximizes the frontmost window.".to_string()),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "window_left_half".to_string(),
+            name: "Move Window to Left Half".to_string(),
+            phrases: vec![
+                "move to left half".to_string(),
+                "move window left".to_string(),
+                "snap left".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Moves the frontmost window to the left half of the screen.".to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "window_right_half".to_string(),
+            name: "Move Window to Right Half".to_string(),
+            phrases: vec![
+                "move to right half".to_string(),
+                "move window right".to_string(),
+                "snap right".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Moves the frontmost window to the right half of the screen.".to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "switch_to_app".to_string(),
+            name: "Switch to Application".to_string(),
+            phrases: vec![
+                "switch to".to_string(),
+                "bring up".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Brings an already open application to the front. The user will specify which app."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "set_timer".to_string(),
+            name: "Set Timer".to_string(),
+            phrases: vec![
+                "remind me".to_string(),
+                "set a timer for".to_string(),
+                "start a timer for".to_string(),
+                "timer for".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Starts a countdown and shows a notification when it runs out, e.g. 'remind me in 20 minutes to check the oven'."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "list_timers".to_string(),
+            name: "List Timers".to_string(),
+            phrases: vec![
+                "list timers".to_string(),
+                "show timers".to_string(),
+                "what timers are running".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some("Shows the running timers and how long each has left.".to_string()),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "cancel_timer".to_string(),
+            name: "Cancel Timer".to_string(),
+            phrases: vec![
+                "cancel timer".to_string(),
+                "cancel the timer".to_string(),
+                "cancel reminder".to_string(),
+                "cancel the reminder".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Cancels a running timer, the one whose label the user names, or all of them."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "set_prompt_mode".to_string(),
+            name: "Switch Prompt Mode".to_string(),
+            phrases: vec![
+                "switch to {mode} mode".to_string(),
+                "use {mode} mode".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Changes Ramble's prompt mode to dynamic, low, medium or high, e.g. 'switch to writing mode'."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "enable_refinement".to_string(),
+            name: "Enable Refinement".to_string(),
+            phrases: vec![
+                "enable refinement".to_string(),
+                "turn on refinement".to_string(),
+                "turn refinement on".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some("Turns on LLM refinement of transcriptions.".to_string()),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "disable_refinement".to_string(),
+            name: "Disable Refinement".to_string(),
+            phrases: vec![
+                "disable refinement".to_string(),
+                "turn off refinement".to_string(),
+                "turn refinement off".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Turns off LLM refinement, so transcriptions are pasted as spoken."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "use_prompt".to_string(),
+            name: "Use Prompt".to_string(),
+            phrases: vec![
+                "use the {prompt} prompt".to_string(),
+                "switch to the {prompt} prompt".to_string(),
+                "use {prompt} prompt".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Selects the refinement prompt the user names; 'use the raw prompt' turns refinement off."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
+        },
+        VoiceCommand {
+            id: "switch_project".to_string(),
+            name: "Switch Project".to_string(),
+            phrases: vec![
+                "switch to project".to_string(),
+                "switch to the {project} project".to_string(),
+                "open project".to_string(),
+            ],
+            command_type: VoiceCommandType::Builtin,
+            description: Some(
+                "Makes the project the user names active, applying its prompt mode, default category and vocabulary."
+                    .to_string(),
+            ),
+            script_type: ScriptType::Shell,
+            script: None,
+            model_override: None,
+            is_builtin: true,
+            parameters: Vec::new(),
         },
         VoiceCommand {
             id: "lucky_search".to_string(),
@@ -824,6 +1746,7 @@ fn default_voice_commands() -> Vec<VoiceCommand> {
 end tell"#.to_string()),
             model_override: None,
             is_builtin: true,
+            parameters: Vec::new(),
         },
     ]
 }
@@ -836,6 +1759,8 @@ fn default_prompt_categories() -> Vec<PromptCategory> {
             icon: "▁".to_string(),
             is_builtin: true,
             model_override: None,
+            language: None,
+            chaptering: false,
             prompt: "You are cleaning up speech-to-text for a casual chat message.
 
 **Context:** The user is in ${application} (${category} mode). The output is a message to another human.
@@ -884,6 +1809,8 @@ ${output}
             icon: "▃".to_string(),
             is_builtin: true,
             model_override: None,
+            language: None,
+            chaptering: false,
             prompt: "You are transforming rambling speech into polished written prose.
 
 **Context:** The user is in ${application} (${category} mode). The output is written content for human readers.
@@ -931,6 +1858,8 @@ ${output}
             icon: "▅".to_string(),
             is_builtin: true,
             model_override: None,
+            language: None,
+            chaptering: false,
             prompt: "You are an aggressive editor transforming rambling speech into clean, focused text.
 
 **Context:** The user is in ${application} (${category} mode). The output will be used in developer tools or sent to AI assistants.
@@ -1017,6 +1946,118 @@ ${output}
     ]
 }
 
+/// Starter template for creating a new prompt category
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct PromptCategoryTemplate {
+    pub id: String,
+    pub name: String,
+    pub icon: String,
+    /// Short description shown in the template picker
+    pub description: String,
+    pub prompt: String,
+}
+
+fn prompt_template(
+    id: &str,
+    name: &str,
+    icon: &str,
+    description: &str,
+    instructions: &str,
+) -> PromptCategoryTemplate {
+    PromptCategoryTemplate {
+        id: id.to_string(),
+        name: name.to_string(),
+        icon: icon.to_string(),
+        description: description.to_string(),
+        prompt: format!(
+            "{}\n\nPreserve the user's perspective: do not change pronouns or perspective.\n\nReturn ONLY the resulting text. No preamble.\n\n---\n\n<selection>\n${{selection}}\n</selection>\n\n<transcript>\n${{output}}\n</transcript>",
+            instructions
+        ),
+    }
+}
+
+/// Starter templates offered when creating a new category, so users don't start from a blank prompt.
+pub fn prompt_category_templates() -> Vec<PromptCategoryTemplate> {
+    vec![
+        prompt_template(
+            "bug_report",
+            "Bug report",
+            "🐞",
+            "Turn a spoken description of a problem into a structured bug report",
+            "You are turning a rambling spoken description of a software problem into a clear bug report.
+
+**Context:** The user is in ${application} (${category} mode).
+
+Structure the output with these markdown sections, omitting any the user gave no information for:
+- **Summary** - one sentence
+- **Steps to reproduce** - numbered list
+- **Expected behavior**
+- **Actual behavior**
+- **Environment** - versions, OS, device, if mentioned
+
+Keep technical terms, error messages, file names and identifiers exactly as spoken. Do not invent steps or details the user did not mention.",
+        ),
+        prompt_template(
+            "pr_description",
+            "PR description",
+            "🔀",
+            "Write a pull request description from a spoken summary of the change",
+            "You are writing a pull request description from the user's spoken summary of their change.
+
+**Context:** The user is in ${application} (${category} mode).
+
+Format as markdown:
+- Open with 1-2 plain sentences saying what the change does and why
+- A short bullet list of notable changes
+- A **Testing** section describing how it was verified, only if the user mentioned it
+
+Be concise. Keep code identifiers in backticks. Do not invent changes, tests, or issue numbers.",
+        ),
+        prompt_template(
+            "code_notes",
+            "Code notes",
+            "⌨️",
+            "Turn speech into concise code comments, commit messages and technical notes",
+            "You are turning the user's speech into text for a code editor: a code comment, commit message or short technical note.
+
+**Context:** The user is in ${application} (${category} mode).
+
+- Concise, plain technical language; no greetings or sign-offs
+- Keep code identifiers, file names, commands and error messages exactly as spoken, in backticks only when the user is writing markdown
+- Remove filler words and false starts, but keep every technical detail
+- Do not invent behavior, reasons, or issue numbers the user did not mention",
+        ),
+        prompt_template(
+            "sales_email",
+            "Sales email",
+            "📧",
+            "Polish spoken notes into a friendly, professional outreach email",
+            "You are turning the user's spoken notes into a short, professional sales or outreach email.
+
+**Context:** The user is in ${application} (${category} mode).
+
+- Warm but professional tone, no hype or filler
+- Short paragraphs, one clear call to action at the end
+- Include a greeting and sign-off only if the user mentions a recipient or their own name
+- Keep every product name, price, and date exactly as spoken; never invent offers or claims",
+        ),
+        prompt_template(
+            "slack_reply",
+            "Slack reply",
+            "💬",
+            "Tidy speech into a brief, casual chat reply",
+            "You are cleaning up speech into a brief reply for a team chat like Slack.
+
+**Context:** The user is in ${application} (${category} mode).
+
+- Casual, friendly tone matching how the user speaks
+- Keep it short; split into at most a few short lines
+- Use Slack-style formatting only if asked (`code`, *bold*, bullet lists)
+- Remove filler words and false starts, but keep all content and meaning",
+        ),
+    ]
+}
+
 fn default_llm_providers() -> Vec<LLMProvider> {
     let mut providers = vec![
         // API Key providers (original)
@@ -1029,6 +2070,10 @@ fn default_llm_providers() -> Vec<LLMProvider> {
             is_custom: false,
             auth_method: AuthMethod::ApiKey,
             supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
         },
         LLMProvider {
             id: "anthropic".to_string(),
@@ -1039,6 +2084,10 @@ fn default_llm_providers() -> Vec<LLMProvider> {
             is_custom: false,
             auth_method: AuthMethod::ApiKey,
             supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
         },
         LLMProvider {
             id: "gemini".to_string(),
@@ -1049,6 +2098,10 @@ fn default_llm_providers() -> Vec<LLMProvider> {
             is_custom: false,
             auth_method: AuthMethod::ApiKey,
             supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
         },
         // Note: OAuth providers (openai_oauth, gemini_oauth) are NOT included in defaults.
         // They are available as presets in the "Add Provider" dialog and will be created
@@ -1067,6 +2120,10 @@ fn default_llm_providers() -> Vec<LLMProvider> {
                 is_custom: false,
                 auth_method: AuthMethod::ApiKey,
                 supports_oauth: false,
+                credentials: Vec::new(),
+                active_credential_id: None,
+                custom_headers: HashMap::new(),
+                free: false,
             });
         }
     }
@@ -1248,6 +2305,20 @@ pub fn get_default_settings() -> AppSettings {
             description: "Converts your speech into text.".to_string(),
             default_binding: default_shortcut.to_string(),
             current_binding: default_shortcut.to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
+        },
+    );
+    bindings.insert(
+        "transcribe_system_audio".to_string(),
+        ShortcutBinding {
+            id: "transcribe_system_audio".to_string(),
+            name: "Transcribe System Audio".to_string(),
+            description: "Transcribes your computer's audio output (calls, videos) along with your microphone.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1258,6 +2329,8 @@ pub fn get_default_settings() -> AppSettings {
             description: "Cancels the current recording.".to_string(),
             default_binding: "escape".to_string(),
             current_binding: "escape".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1268,6 +2341,8 @@ pub fn get_default_settings() -> AppSettings {
             description: "Pauses/Resumes recording.".to_string(),
             default_binding: "Option+Shift+P".to_string(),
             current_binding: "Option+Shift+P".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1278,6 +2353,8 @@ pub fn get_default_settings() -> AppSettings {
             description: "Activates voice command mode to control your computer.".to_string(),
             default_binding: "right_command".to_string(),
             current_binding: "right_command".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1288,6 +2365,8 @@ pub fn get_default_settings() -> AppSettings {
             description: "Opens a new AI chat window.".to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1295,9 +2374,12 @@ pub fn get_default_settings() -> AppSettings {
         ShortcutBinding {
             id: "speak_selection".to_string(),
             name: "Speak Selection".to_string(),
-            description: "Reads the currently selected text aloud using AI.".to_string(),
+            description: "Reads the currently selected text aloud using AI. Press again to stop."
+                .to_string(),
             default_binding: "Option+S".to_string(),
             current_binding: "Option+S".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
     bindings.insert(
@@ -1309,6 +2391,33 @@ pub fn get_default_settings() -> AppSettings {
                 .to_string(),
             default_binding: "left_shift+right_command".to_string(),
             current_binding: "left_shift+right_command".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
+        },
+    );
+    bindings.insert(
+        "refine_clipboard".to_string(),
+        ShortcutBinding {
+            id: "refine_clipboard".to_string(),
+            name: "Refine Clipboard".to_string(),
+            description: "Refines the copied text and pastes the result.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
+        },
+    );
+    bindings.insert(
+        "toggle_shortcuts".to_string(),
+        ShortcutBinding {
+            id: "toggle_shortcuts".to_string(),
+            name: "Pause All Shortcuts".to_string(),
+            description: "Temporarily disables every other shortcut. Press again to re-enable."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            auto_stop_silence_seconds: 0,
+            tap_hold_mode: TapHoldMode::default(),
         },
     );
 
@@ -1331,10 +2440,18 @@ pub fn get_default_settings() -> AppSettings {
         selected_output_device: None,
         translate_to_english: false,
         selected_language: "auto".to_string(),
+        code_switching: false,
         overlay_position: default_overlay_position(),
         debug_mode: false,
         log_level: default_log_level(),
         custom_words: Vec::new(),
+        cased_terms: Vec::new(),
+        show_edits: false,
+        verify_refinement: false,
+        day_context_enabled: false,
+        chaptering_min_words: default_chaptering_min_words(),
+        history_titles_enabled: default_history_titles_enabled(),
+        refine_clipboard_paste: default_refine_clipboard_paste(),
         model_unload_timeout: ModelUnloadTimeout::Never,
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
@@ -1344,7 +2461,11 @@ pub fn get_default_settings() -> AppSettings {
         llm_models: default_llm_models(),
         default_chat_model_id: Some("gemini-flash".to_string()),
         default_coherent_model_id: Some("gemini-flash".to_string()),
+        coherent_fallback_model_ids: Vec::new(),
+        local_llm_enabled: false,
+        local_llm_model_path: None,
         default_voice_model_id: Some("gemini-flash".to_string()),
+        default_title_model_id: None,
         default_context_chat_model_id: None,
         openai_reasoning_effort: default_openai_reasoning_effort(),
         // Other settings
@@ -1363,16 +2484,33 @@ pub fn get_default_settings() -> AppSettings {
         prompt_categories: default_prompt_categories(),
         app_category_mappings: Vec::new(),
         detected_apps_history: Vec::new(),
+        record_detected_apps: default_record_detected_apps(),
+        detected_apps_excluded: Vec::new(),
         default_category_id: default_category_id(),
         // Voice command settings
         voice_commands_enabled: false,
         voice_command_default_model: default_voice_command_model(),
+        voice_command_follow_up_seconds: 0,
         voice_commands: default_voice_commands(),
+        reminders: Vec::new(),
+        timers: Vec::new(),
+        projects: Vec::new(),
+        active_project_id: None,
+        focus_mode: false,
+        hide_when_presenting: false,
+        paste_blocked_apps: default_paste_blocked_apps(),
+        max_paste_chars: default_max_paste_chars(),
+        terminal_paste_mode: TerminalPasteMode::default(),
         // TTS Settings
         tts_enabled: default_tts_enabled(),
         tts_selected_model: None,
         tts_speed: default_tts_speed(),
         tts_volume: default_tts_volume(),
+        tts_backend: TtsBackend::default(),
+        tts_system_voice: None,
+        tts_api_keys: HashMap::new(),
+        tts_cloud_voices: HashMap::new(),
+        chat_read_aloud: false,
         filler_word_filter: default_filler_word_filter(),
         collapse_repeated_words: default_collapse_repeated_words(),
         quick_chat_initial_prompt: default_quick_chat_initial_prompt(),
@@ -1386,6 +2524,33 @@ pub fn get_default_settings() -> AppSettings {
         last_voice_interaction: None,
         // System prompt file
         system_prompt_file: None,
+        deleted_items: Vec::new(),
+        trash_retention_days: default_trash_retention_days(),
+        keyboard_layout: KeyboardLayout::Auto,
+        live_transcription: default_live_transcription(),
+        shortcuts_pause_minutes: default_shortcuts_pause_minutes(),
+        stt_api_keys: HashMap::new(),
+        double_press_cancel: false,
+        double_press_cancel_window_ms: default_double_press_cancel_window_ms(),
+        no_speech_timeout_seconds: 0,
+        daily_spend_limit_usd: None,
+        per_request_spend_limit_usd: None,
+        noise_suppression: false,
+        wake_word_enabled: false,
+        wake_word_binding: default_wake_word_binding(),
+        wake_word_threshold: default_wake_word_threshold(),
+        wake_word_model_path: None,
+        accessibility_announcements: default_accessibility_announcements(),
+        battery_saver_enabled: default_battery_saver_enabled(),
+        battery_saver_threshold: default_battery_saver_threshold(),
+        media_key_trigger_enabled: false,
+        media_key_binding: default_media_key_binding(),
+        remote_trigger_enabled: false,
+        remote_trigger_port: default_remote_trigger_port(),
+        remote_trigger_lan_access: false,
+        remote_trigger_devices: Vec::new(),
+        sync_folder: None,
+        sync_passphrase_saved: false,
     }
 }
 
@@ -1624,3 +2789,21 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
     let settings = get_settings(app);
     settings.recording_retention_period
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_hold_mode_refines() {
+        assert!(TapHoldMode::TapRefines.refines(false));
+        assert!(!TapHoldMode::TapRefines.refines(true));
+        assert!(!TapHoldMode::HoldRefines.refines(false));
+        assert!(TapHoldMode::HoldRefines.refines(true));
+        assert!(TapHoldMode::AlwaysRefined.refines(false));
+        assert!(TapHoldMode::AlwaysRefined.refines(true));
+        assert!(!TapHoldMode::AlwaysRaw.refines(false));
+        assert!(!TapHoldMode::AlwaysRaw.refines(true));
+        assert_eq!(TapHoldMode::default(), TapHoldMode::TapRefines);
+    }
+}
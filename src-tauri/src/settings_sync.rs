@@ -0,0 +1,503 @@
+//! Encrypted settings sync through a shared folder.
+//!
+//! With `AppSettings::sync_folder` and a passphrase set, the prompts, voice
+//! commands, categories, custom words and other preferences in `SYNCED_KEYS` are
+//! written to one encrypted file in that folder, so iCloud Drive, Dropbox or
+//! Syncthing can carry them to the user's other computers. API keys, paired devices
+//! and hardware choices stay on each computer.
+//!
+//! The file is encrypted with XChaCha20-Poly1305 under a key derived from the
+//! passphrase, so the sync service only ever sees ciphertext. The passphrase is kept
+//! in the OS keychain rather than the settings file. Every minute (and on
+//! demand) the file is merged with the local settings key by key, against hashes of
+//! what both sides agreed on at the last sync: a key changed on one side only takes
+//! that side's value, and a key changed on both keeps this computer's value and is
+//! reported as a conflict. A computer joining a folder that already has settings
+//! takes them.
+
+use crate::errors::AppError;
+use crate::events::SettingsSynced;
+use crate::settings::{self, AppSettings};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use log::{debug, info, warn};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+use tauri_specta::Event;
+
+/// Name of the encrypted file in the sync folder
+const SYNC_FILE: &str = "ramble-settings.sync";
+
+/// Local bookkeeping of the last sync, never synced itself
+const SYNC_STATE_STORE: &str = "sync_state.json";
+
+/// How often the folder is synced
+const SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Keychain account the passphrase is saved under, in the app identifier's service
+const KEYCHAIN_ACCOUNT: &str = "settings-sync-passphrase";
+
+/// PBKDF2 rounds for deriving the key from the passphrase
+const KEY_ROUNDS: u32 = 210_000;
+
+const SYNC_FORMAT_VERSION: u32 = 1;
+
+/// Settings that follow the user between computers
+const SYNCED_KEYS: &[&str] = &[
+    "app_language",
+    "append_trailing_space",
+    "app_category_mappings",
+    "audio_feedback",
+    "cased_terms",
+    "chat_read_aloud",
+    "clipboard_handling",
+    "code_switching",
+    "coherent_enabled",
+    "coherent_prompts",
+    "coherent_selected_prompt_id",
+    "coherent_use_vision",
+    "collapse_repeated_words",
+    "context_chat_prompt",
+    "custom_words",
+    "default_category_id",
+    "double_press_cancel",
+    "double_press_cancel_window_ms",
+    "filler_word_filter",
+    "hold_threshold_ms",
+    "max_paste_chars",
+    "no_speech_timeout_seconds",
+    "overlay_position",
+    "paste_blocked_apps",
+    "paste_method",
+    "prompt_categories",
+    "projects",
+    "prompt_mode",
+    "push_to_talk",
+    "quick_chat_initial_prompt",
+    "reminders",
+    "selected_language",
+    "show_edits",
+    "sound_theme",
+    "translate_to_english",
+    "unknown_command_template",
+    "verify_refinement",
+    "voice_commands",
+    "voice_commands_enabled",
+    "word_correction_threshold",
+];
+
+/// Only one sync runs at a time
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+/// The file in the sync folder
+#[derive(Serialize, Deserialize, Debug)]
+struct Envelope {
+    version: u32,
+    /// Computer that last wrote the file
+    device: String,
+    /// Unix timestamp in milliseconds of the last write
+    modified_at: i64,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// What a sync did
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct SyncReport {
+    /// Settings taken from the sync folder
+    pub pulled: Vec<String>,
+    /// Whether this computer's settings were written to the folder
+    pub pushed: bool,
+    /// Settings changed on this computer and elsewhere; this computer's value was kept
+    pub conflicts: Vec<String>,
+}
+
+/// Result of merging local and synced settings
+#[derive(Debug, Default, PartialEq)]
+struct Merge {
+    values: Map<String, Value>,
+    pulled: Vec<String>,
+    conflicts: Vec<String>,
+    /// Whether the merged settings differ from the folder's
+    needs_push: bool,
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Key {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, KEY_ROUNDS, &mut key);
+    Key::from(key)
+}
+
+fn encrypt(passphrase: &str, plaintext: &[u8], device: String) -> Result<Envelope, String> {
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 24];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, &salt));
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), plaintext)
+        .map_err(|_| "Failed to encrypt settings".to_string())?;
+    Ok(Envelope {
+        version: SYNC_FORMAT_VERSION,
+        device,
+        modified_at: chrono::Utc::now().timestamp_millis(),
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    })
+}
+
+fn decrypt(passphrase: &str, envelope: &Envelope) -> Result<Vec<u8>, String> {
+    if envelope.version > SYNC_FORMAT_VERSION {
+        return Err("The synced settings come from a newer version of Ramble".to_string());
+    }
+    let decode = |field: &str| STANDARD.decode(field).map_err(|e| e.to_string());
+    let salt = decode(&envelope.salt)?;
+    let nonce = decode(&envelope.nonce)?;
+    if nonce.len() != 24 {
+        return Err("The synced settings file is damaged".to_string());
+    }
+
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, &salt));
+    cipher
+        .decrypt(
+            XNonce::from_slice(&nonce),
+            decode(&envelope.ciphertext)?.as_slice(),
+        )
+        .map_err(|_| "Couldn't decrypt the synced settings, check the passphrase".to_string())
+}
+
+fn hash_value(value: &Value) -> String {
+    Sha256::digest(value.to_string().as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn synced_values(settings: &AppSettings) -> Result<Map<String, Value>, String> {
+    let Value::Object(mut all) = serde_json::to_value(settings).map_err(|e| e.to_string())? else {
+        return Err("Settings aren't an object".to_string());
+    };
+    Ok(SYNCED_KEYS
+        .iter()
+        .filter_map(|key| Some((key.to_string(), all.remove(*key)?)))
+        .collect())
+}
+
+/// Merge key by key against the hashes both sides had at the last sync. Without a
+/// last sync, the folder's settings win.
+fn merge_values(
+    local: &Map<String, Value>,
+    remote: Option<&Map<String, Value>>,
+    base: &HashMap<String, String>,
+) -> Merge {
+    let Some(remote) = remote else {
+        return Merge {
+            values: local.clone(),
+            needs_push: true,
+            ..Default::default()
+        };
+    };
+
+    let mut merge = Merge::default();
+    for (key, local_value) in local {
+        let Some(remote_value) = remote.get(key) else {
+            // Written by a version that didn't sync this key yet
+            merge.values.insert(key.clone(), local_value.clone());
+            merge.needs_push = true;
+            continue;
+        };
+        let local_hash = hash_value(local_value);
+        let remote_hash = hash_value(remote_value);
+        if local_hash == remote_hash {
+            merge.values.insert(key.clone(), local_value.clone());
+            continue;
+        }
+
+        let local_changed = base.get(key) != Some(&local_hash);
+        let remote_changed = base.get(key) != Some(&remote_hash);
+        if base.is_empty() || (remote_changed && !local_changed) {
+            merge.values.insert(key.clone(), remote_value.clone());
+            merge.pulled.push(key.clone());
+        } else {
+            if remote_changed {
+                merge.conflicts.push(key.clone());
+            }
+            merge.values.insert(key.clone(), local_value.clone());
+            merge.needs_push = true;
+        }
+    }
+    merge
+}
+
+fn load_base(app: &AppHandle, folder: &str) -> HashMap<String, String> {
+    let Ok(store) = app.store(SYNC_STATE_STORE) else {
+        return HashMap::new();
+    };
+    // Hashes from another folder say nothing about this one
+    if store
+        .get("folder")
+        .and_then(|f| f.as_str().map(String::from))
+        != Some(folder.to_string())
+    {
+        return HashMap::new();
+    }
+    store
+        .get("base")
+        .and_then(|base| serde_json::from_value(base).ok())
+        .unwrap_or_default()
+}
+
+fn save_base(app: &AppHandle, folder: &str, values: &Map<String, Value>) {
+    let base: HashMap<String, String> = values
+        .iter()
+        .map(|(key, value)| (key.clone(), hash_value(value)))
+        .collect();
+    match app.store(SYNC_STATE_STORE) {
+        Ok(store) => {
+            store.set("folder", folder);
+            store.set("base", serde_json::json!(base));
+        }
+        Err(e) => warn!("Failed to save sync state: {}", e),
+    }
+}
+
+fn read_envelope(path: &Path) -> Result<Option<Envelope>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| format!("The synced settings file is damaged: {}", e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to read synced settings: {}", e)),
+    }
+}
+
+/// Write through a temporary file, so sync clients never upload half a file
+fn write_envelope(path: &Path, envelope: &Envelope) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(envelope).map_err(|e| e.to_string())?;
+    let temp = path.with_extension("sync.tmp");
+    let write_error = |e: std::io::Error| format!("Failed to write synced settings: {}", e);
+    std::fs::write(&temp, contents).map_err(write_error)?;
+    std::fs::rename(&temp, path).map_err(write_error)
+}
+
+fn apply(app: &AppHandle, settings: AppSettings, merge: &Merge) -> Result<(), String> {
+    let mut value = serde_json::to_value(&settings).map_err(|e| e.to_string())?;
+    for key in &merge.pulled {
+        value[key] = merge.values[key].clone();
+    }
+    let updated: AppSettings = serde_json::from_value(value).map_err(|e| {
+        format!(
+            "The synced settings don't fit this version of Ramble: {}",
+            e
+        )
+    })?;
+    settings::write_settings(app, updated);
+    Ok(())
+}
+
+fn keychain_entry(app: &AppHandle) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(&app.config().identifier, KEYCHAIN_ACCOUNT)
+        .map_err(|e| format!("Couldn't open the keychain: {}", e))
+}
+
+/// Save the sync passphrase in the OS keychain, or remove it with `None`
+pub fn save_passphrase(app: &AppHandle, passphrase: Option<&str>) -> Result<(), String> {
+    let entry = keychain_entry(app)?;
+    let result = match passphrase {
+        Some(passphrase) => entry.set_password(passphrase),
+        None => match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            result => result,
+        },
+    };
+    result.map_err(|e| format!("Couldn't save the sync passphrase in the keychain: {}", e))
+}
+
+fn load_passphrase(app: &AppHandle) -> Result<String, String> {
+    keychain_entry(app)?.get_password().map_err(|e| match e {
+        keyring::Error::NoEntry => {
+            "The sync passphrase is missing from the keychain, enter it again".to_string()
+        }
+        e => format!("Couldn't read the sync passphrase from the keychain: {}", e),
+    })
+}
+
+/// Sync with the folder now. Does nothing when sync isn't set up.
+pub fn sync(app: &AppHandle) -> Result<SyncReport, String> {
+    let _guard = SYNC_LOCK.lock().unwrap();
+    let settings = settings::get_settings(app);
+    let Some(folder) = settings.sync_folder.clone() else {
+        return Ok(SyncReport::default());
+    };
+    if !settings.sync_passphrase_saved {
+        return Ok(SyncReport::default());
+    }
+    let passphrase = load_passphrase(app)?;
+    let path = PathBuf::from(&folder).join(SYNC_FILE);
+
+    let remote = match read_envelope(&path)? {
+        Some(envelope) => {
+            let plaintext = decrypt(&passphrase, &envelope)?;
+            let values: Map<String, Value> = serde_json::from_slice(&plaintext)
+                .map_err(|e| format!("The synced settings file is damaged: {}", e))?;
+            debug!("Synced settings were last written by {}", envelope.device);
+            Some(values)
+        }
+        None => None,
+    };
+
+    let local = synced_values(&settings)?;
+    let merge = merge_values(&local, remote.as_ref(), &load_base(app, &folder));
+
+    if !merge.pulled.is_empty() {
+        info!("Taking synced settings: {}", merge.pulled.join(", "));
+        apply(app, settings, &merge)?;
+    }
+    if !merge.conflicts.is_empty() {
+        warn!(
+            "Settings changed here and elsewhere, keeping this computer's: {}",
+            merge.conflicts.join(", ")
+        );
+    }
+    if merge.needs_push {
+        let plaintext = serde_json::to_vec(&merge.values).map_err(|e| e.to_string())?;
+        write_envelope(
+            &path,
+            &encrypt(&passphrase, &plaintext, tauri_plugin_os::hostname())?,
+        )?;
+        info!("Wrote settings to the sync folder");
+    }
+    save_base(app, &folder, &merge.values);
+
+    let report = SyncReport {
+        pulled: merge.pulled,
+        pushed: merge.needs_push,
+        conflicts: merge.conflicts,
+    };
+    if report != SyncReport::default() {
+        let _ = SettingsSynced(report.clone()).emit(app);
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn sync_settings_now(app: AppHandle) -> Result<SyncReport, AppError> {
+    let settings = settings::get_settings(&app);
+    if settings.sync_folder.is_none() || !settings.sync_passphrase_saved {
+        return Err(AppError::invalid_input(
+            "Choose a sync folder and passphrase first",
+        ));
+    }
+    sync(&app).map_err(AppError::internal)
+}
+
+pub fn start_watcher(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let sync_app = app.clone();
+            // Deriving the key and reading the folder are slow, keep them off the runtime
+            let result = tokio::task::spawn_blocking(move || sync(&sync_app)).await;
+            if let Ok(Err(e)) = result {
+                warn!("Settings sync failed: {}", e);
+            }
+            tokio::time::sleep(SYNC_INTERVAL).await;
+        }
+    });
+    info!("Started settings sync");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn values(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    fn base_of(value: Value) -> HashMap<String, String> {
+        values(value)
+            .iter()
+            .map(|(key, value)| (key.clone(), hash_value(value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_encryption_round_trip() {
+        let envelope = encrypt("correct horse", b"{\"custom_words\":[]}", "mac".into()).unwrap();
+        assert!(!envelope.ciphertext.contains("custom_words"));
+        assert_eq!(
+            decrypt("correct horse", &envelope).unwrap(),
+            b"{\"custom_words\":[]}"
+        );
+        assert!(decrypt("wrong horse", &envelope).is_err());
+    }
+
+    #[test]
+    fn test_merge_takes_one_sided_changes() {
+        let base = base_of(json!({ "custom_words": ["a"], "push_to_talk": false }));
+        let local = values(json!({ "custom_words": ["a", "b"], "push_to_talk": false }));
+        let remote = values(json!({ "custom_words": ["a"], "push_to_talk": true }));
+
+        let merged = merge_values(&local, Some(&remote), &base);
+        assert_eq!(merged.values["custom_words"], json!(["a", "b"]));
+        assert_eq!(merged.values["push_to_talk"], json!(true));
+        assert_eq!(merged.pulled, vec!["push_to_talk"]);
+        assert!(merged.conflicts.is_empty());
+        assert!(merged.needs_push);
+    }
+
+    #[test]
+    fn test_merge_keeps_local_value_on_conflict() {
+        let base = base_of(json!({ "custom_words": ["a"] }));
+        let local = values(json!({ "custom_words": ["local"] }));
+        let remote = values(json!({ "custom_words": ["remote"] }));
+
+        let merged = merge_values(&local, Some(&remote), &base);
+        assert_eq!(merged.values["custom_words"], json!(["local"]));
+        assert_eq!(merged.conflicts, vec!["custom_words"]);
+        assert!(merged.pulled.is_empty());
+    }
+
+    #[test]
+    fn test_merge_first_sync() {
+        let local = values(json!({ "custom_words": ["local"] }));
+        // A new folder gets this computer's settings
+        let merged = merge_values(&local, None, &HashMap::new());
+        assert!(merged.needs_push);
+        assert_eq!(merged.values, local);
+
+        // Joining a folder takes its settings
+        let remote = values(json!({ "custom_words": ["remote"] }));
+        let merged = merge_values(&local, Some(&remote), &HashMap::new());
+        assert_eq!(merged.pulled, vec!["custom_words"]);
+        assert!(!merged.needs_push);
+    }
+
+    #[test]
+    fn test_merge_without_changes() {
+        let both = values(json!({ "custom_words": ["a"] }));
+        let merged = merge_values(
+            &both,
+            Some(&both),
+            &base_of(json!({ "custom_words": ["a"] })),
+        );
+        assert!(!merged.needs_push);
+        assert!(merged.pulled.is_empty());
+    }
+}
@@ -2,20 +2,29 @@ use log::{debug, error, warn};
 use serde::Serialize;
 use specta::Type;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_autostart::ManagerExt;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_specta::Event;
 
-use crate::actions::ACTION_MAP;
+use crate::actions::{InteractionBehavior, ACTION_MAP};
+use crate::errors::AppError;
+use crate::events::{self, AppLanguageChanged, FocusModeChanged, RecordingMode, VisionCaptured};
+use crate::keyboard_layout;
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::local_llm::LocalLlmManager;
+use crate::managers::tts::TTSManager;
 use crate::overlay;
 use crate::settings::ShortcutBinding;
 use crate::settings::{
     self, get_settings, ClipboardHandling, LLMPrompt, OverlayPosition, PasteMethod, SoundTheme,
     APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
+use crate::shortcut_log::{self, ShortcutEventResolution, ShortcutEventSource};
+use crate::trash;
 use crate::tray;
 use crate::ManagedToggleState;
 
@@ -29,10 +38,115 @@ fn get_press_timestamps() -> &'static Mutex<HashMap<String, Instant>> {
     PRESS_TIMESTAMPS.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+/// When each toggle binding last started recording, for double-press cancel
+static START_TIMESTAMPS: OnceLock<Mutex<HashMap<String, Instant>>> = OnceLock::new();
+
+fn get_start_timestamps() -> &'static Mutex<HashMap<String, Instant>> {
+    START_TIMESTAMPS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Remember that `binding_id` just started recording
+pub fn note_recording_started(binding_id: &str) {
+    if let Ok(mut timestamps) = get_start_timestamps().lock() {
+        timestamps.insert(binding_id.to_string(), Instant::now());
+    }
+}
+
+/// Whether the shortcut for `binding_id` is physically held down, i.e. the
+/// recording is push-to-talk rather than toggled on
+pub fn is_binding_held(binding_id: &str) -> bool {
+    let held = get_press_timestamps()
+        .lock()
+        .is_ok_and(|t| t.contains_key(binding_id));
+    #[cfg(target_os = "macos")]
+    let held = held || key_listener::is_key_held(binding_id);
+    held
+}
+
+/// Whether a press held for `held_ms` is a hold rather than a tap. A release right
+/// at the threshold counts as a hold, like the timer that shows hold mode.
+pub fn is_hold(held_ms: u128, threshold_ms: u64) -> bool {
+    held_ms >= u128::from(threshold_ms)
+}
+
+/// Whether a recording `binding_id` started with a hold, or with a tap when `hold`
+/// is false, is refined, following the binding's tap/hold mode
+pub fn binding_refines(app: &AppHandle, binding_id: &str, hold: bool) -> bool {
+    get_settings(app)
+        .bindings
+        .get(binding_id)
+        .map(|b| b.tap_hold_mode)
+        .unwrap_or_default()
+        .refines(hold)
+}
+
+/// Switch the current recording to refining mode and show it on the overlay.
+/// `capture_selection` copies the selection as context for the refinement, which is
+/// only safe once the shortcut's keys are released.
+pub fn enter_refining_mode(app: &AppHandle, capture_selection: bool) {
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    audio_manager.set_coherent_mode(true);
+
+    // Update the overlay synchronously so it shows the 'Refined' label
+    crate::utils::show_ramble_recording_overlay(app);
+    overlay::emit_mode_determined(app, RecordingMode::Refining);
+
+    if capture_selection {
+        let app_clone = app.clone();
+        let audio_manager_clone = Arc::clone(&audio_manager);
+        // Run on main thread to prevent crash on macOS (TSM/Enigo requirements)
+        let _ = app.run_on_main_thread(move || {
+            if let Ok(Some(text)) = crate::clipboard::get_selected_text(&app_clone) {
+                debug!("Captured selection context: {} chars", text.len());
+                audio_manager_clone.set_selection_context(text);
+            }
+        });
+    }
+}
+
+/// Whether a press that would stop `binding_id` followed the press that started it
+/// closely enough to cancel the recording instead (if enabled in settings)
+pub fn is_double_press_cancel(app: &AppHandle, binding_id: &str) -> bool {
+    let started = get_start_timestamps()
+        .lock()
+        .ok()
+        .and_then(|mut t| t.remove(binding_id));
+    let settings = get_settings(app);
+    settings.double_press_cancel
+        && started.is_some_and(|t| {
+            t.elapsed() <= Duration::from_millis(settings.double_press_cancel_window_ms)
+        })
+}
+
+/// Binding that pauses/resumes all other shortcuts; stays registered while paused
+const TOGGLE_SHORTCUTS_BINDING: &str = "toggle_shortcuts";
+
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct ShortcutsPauseState {
+    pub paused: bool,
+    /// Unix timestamp (seconds) when shortcuts are re-enabled automatically, if any
+    pub resume_at: Option<i64>,
+}
+
+static SHORTCUTS_PAUSE: Mutex<ShortcutsPauseState> = Mutex::new(ShortcutsPauseState {
+    paused: false,
+    resume_at: None,
+});
+
+/// Bumped on every pause/resume so an outdated auto-resume timer does nothing
+static PAUSE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 pub fn init_shortcuts(app: &AppHandle) {
     let default_bindings = settings::get_default_settings().bindings;
     let user_settings = settings::load_or_create_app_settings(app);
 
+    // Resolve the keyboard layout before any character keys are translated. A
+    // detected layout is applied when detection finishes, re-registering the bindings.
+    match user_settings.keyboard_layout {
+        settings::KeyboardLayout::Auto => detect_layout_in_background(app),
+        layout => keyboard_layout::set_active_layout(layout),
+    }
+
     // Register all default shortcuts, applying user customizations
     for (id, default_binding) in default_bindings {
         let binding = user_settings
@@ -59,6 +173,187 @@ pub fn init_shortcuts(app: &AppHandle) {
     }
 }
 
+/// Re-register every binding with the character keys translated for `layout`
+fn reregister_with_layout(app: &AppHandle, layout: settings::KeyboardLayout) {
+    let bindings: Vec<ShortcutBinding> = settings::get_settings(app)
+        .bindings
+        .values()
+        .cloned()
+        .collect();
+
+    // Unregister with the old layout so the physical keys match what was registered
+    for binding in &bindings {
+        if binding.id != "cancel" {
+            let _ = unregister_shortcut(app, binding.clone());
+        }
+    }
+
+    keyboard_layout::set_active_layout(layout);
+
+    if shortcuts_paused() {
+        // Only the toggle binding stays registered while shortcuts are paused
+        register_bindings(
+            app,
+            bindings
+                .into_iter()
+                .filter(|b| b.id == TOGGLE_SHORTCUTS_BINDING),
+        );
+    } else {
+        register_bindings(app, bindings);
+    }
+}
+
+/// Detect the system keyboard layout on a background thread, since that runs a
+/// command, then re-register the bindings if it differs from the active layout
+fn detect_layout_in_background(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let detected = keyboard_layout::detect_system_layout();
+        let app_clone = app.clone();
+        let _ = app.run_on_main_thread(move || {
+            // The setting may have changed while detecting
+            let still_auto = settings::get_settings(&app_clone).keyboard_layout
+                == settings::KeyboardLayout::Auto;
+            if still_auto && detected != keyboard_layout::active_layout() {
+                reregister_with_layout(&app_clone, detected);
+            }
+        });
+    });
+}
+
+/// Register bindings the same way `init_shortcuts` does (cancel is left to the listener)
+fn register_bindings(app: &AppHandle, bindings: impl IntoIterator<Item = ShortcutBinding>) {
+    for binding in bindings {
+        if binding.id == "cancel" {
+            continue;
+        }
+        if binding.id == "vision_capture" || binding.id == "pause_toggle" {
+            register_swallowing_shortcuts(app, binding);
+            continue;
+        }
+        if let Err(e) = register_shortcut(app, binding.clone()) {
+            error!("Failed to register shortcut {}: {}", binding.id, e);
+        }
+    }
+}
+
+pub fn shortcuts_pause_state() -> ShortcutsPauseState {
+    SHORTCUTS_PAUSE
+        .lock()
+        .map(|s| s.clone())
+        .unwrap_or(ShortcutsPauseState {
+            paused: false,
+            resume_at: None,
+        })
+}
+
+pub fn shortcuts_paused() -> bool {
+    shortcuts_pause_state().paused
+}
+
+/// Show the paused state in the tray unless a recording is using the tray icon
+fn refresh_tray_for_pause(app: &AppHandle) {
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    if !audio_manager.is_recording() {
+        tray::change_tray_icon(app, tray::TrayIconState::Idle);
+    }
+}
+
+/// Unregister every shortcut except the toggle binding. With `minutes` of 0 shortcuts stay
+/// paused until resumed; `None` uses the configured default duration.
+pub fn pause_all_shortcuts(app: &AppHandle, minutes: Option<u32>) {
+    if shortcuts_paused() {
+        return;
+    }
+    let settings = settings::get_settings(app);
+    let minutes = minutes.unwrap_or(settings.shortcuts_pause_minutes);
+
+    if let Err(e) = app.global_shortcut().unregister_all() {
+        warn!("Failed to unregister shortcuts while pausing: {}", e);
+    }
+    #[cfg(target_os = "macos")]
+    for id in settings.bindings.keys() {
+        if id != TOGGLE_SHORTCUTS_BINDING {
+            key_listener::suspend_raw_binding(id);
+        }
+    }
+
+    // Keep the toggle binding so the same keys bring shortcuts back
+    if let Some(toggle) = settings.bindings.get(TOGGLE_SHORTCUTS_BINDING) {
+        if !toggle.current_binding.is_empty() {
+            if let Err(e) = register_shortcut(app, toggle.clone()) {
+                warn!("Failed to keep toggle shortcut while paused: {}", e);
+            }
+        }
+    }
+
+    let resume_at = (minutes > 0).then(|| chrono::Utc::now().timestamp() + i64::from(minutes) * 60);
+    if let Ok(mut state) = SHORTCUTS_PAUSE.lock() {
+        state.paused = true;
+        state.resume_at = resume_at;
+    }
+    let generation = PAUSE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    if minutes > 0 {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(u64::from(minutes) * 60));
+            if PAUSE_GENERATION.load(Ordering::SeqCst) == generation {
+                debug!("Shortcut pause expired, re-enabling shortcuts");
+                resume_all_shortcuts(&app);
+            }
+        });
+    }
+
+    log::info!(
+        "All shortcuts paused{}",
+        if minutes > 0 {
+            format!(" for {} minutes", minutes)
+        } else {
+            String::new()
+        }
+    );
+    let _ = app.emit("shortcuts-paused-changed", shortcuts_pause_state());
+    refresh_tray_for_pause(app);
+}
+
+/// Re-register every shortcut after `pause_all_shortcuts`
+pub fn resume_all_shortcuts(app: &AppHandle) {
+    if let Ok(mut state) = SHORTCUTS_PAUSE.lock() {
+        if !state.paused {
+            return;
+        }
+        state.paused = false;
+        state.resume_at = None;
+    }
+    PAUSE_GENERATION.fetch_add(1, Ordering::SeqCst);
+
+    let settings = settings::get_settings(app);
+    if let Some(toggle) = settings.bindings.get(TOGGLE_SHORTCUTS_BINDING) {
+        if !toggle.current_binding.is_empty() {
+            let _ = unregister_shortcut(app, toggle.clone());
+        }
+    }
+    #[cfg(target_os = "macos")]
+    for id in settings.bindings.keys() {
+        key_listener::resume_raw_binding(id);
+    }
+    register_bindings(app, settings.bindings.into_values());
+
+    log::info!("Shortcuts re-enabled");
+    let _ = app.emit("shortcuts-paused-changed", shortcuts_pause_state());
+    refresh_tray_for_pause(app);
+}
+
+/// Pause shortcuts for the configured duration, or resume them if already paused
+pub fn toggle_all_shortcuts(app: &AppHandle) {
+    if shortcuts_paused() {
+        resume_all_shortcuts(app);
+    } else {
+        pause_all_shortcuts(app, None);
+    }
+}
+
 #[derive(Serialize, Type)]
 pub struct BindingResponse {
     success: bool,
@@ -72,7 +367,7 @@ pub fn change_binding(
     app: AppHandle,
     id: String,
     binding: String,
-) -> Result<BindingResponse, String> {
+) -> Result<BindingResponse, AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Get the binding to modify
@@ -118,7 +413,7 @@ pub fn change_binding(
     // Validate the new shortcut before we touch the current registration
     if let Err(e) = validate_shortcut_string(&binding) {
         warn!("change_binding validation error: {}", e);
-        return Err(e);
+        return Err(e.into());
     }
 
     // Create an updated binding
@@ -152,7 +447,7 @@ pub fn change_binding(
 
 #[tauri::command]
 #[specta::specta]
-pub fn reset_binding(app: AppHandle, id: String) -> Result<BindingResponse, String> {
+pub fn reset_binding(app: AppHandle, id: String) -> Result<BindingResponse, AppError> {
     let binding = settings::get_stored_binding(&app, &id);
 
     return change_binding(app, id, binding.default_binding);
@@ -160,7 +455,7 @@ pub fn reset_binding(app: AppHandle, id: String) -> Result<BindingResponse, Stri
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // TODO if the setting is currently false, we probably want to
@@ -174,7 +469,7 @@ pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.audio_feedback = enabled;
     settings::write_settings(&app, settings);
@@ -183,7 +478,7 @@ pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<()
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_audio_feedback_volume_setting(app: AppHandle, volume: f32) -> Result<(), String> {
+pub fn change_audio_feedback_volume_setting(app: AppHandle, volume: f32) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.audio_feedback_volume = volume;
     settings::write_settings(&app, settings);
@@ -192,7 +487,7 @@ pub fn change_audio_feedback_volume_setting(app: AppHandle, volume: f32) -> Resu
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_sound_theme_setting(app: AppHandle, theme: String) -> Result<(), String> {
+pub fn change_sound_theme_setting(app: AppHandle, theme: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     let parsed = match theme.as_str() {
         "marimba" => SoundTheme::Marimba,
@@ -210,7 +505,7 @@ pub fn change_sound_theme_setting(app: AppHandle, theme: String) -> Result<(), S
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_translate_to_english_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_translate_to_english_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.translate_to_english = enabled;
     settings::write_settings(&app, settings);
@@ -219,7 +514,79 @@ pub fn change_translate_to_english_setting(app: AppHandle, enabled: bool) -> Res
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_selected_language_setting(app: AppHandle, language: String) -> Result<(), String> {
+pub fn change_code_switching_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.code_switching = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_show_edits_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.show_edits = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_verify_refinement_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.verify_refinement = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_day_context_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.day_context_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_chaptering_min_words_setting(app: AppHandle, words: u32) -> Result<(), AppError> {
+    if words == 0 {
+        return Err(AppError::invalid_input("The word count must be at least 1"));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.chaptering_min_words = words;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_titles_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.history_titles_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_refine_clipboard_paste_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.refine_clipboard_paste = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_selected_language_setting(app: AppHandle, language: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.selected_language = language;
     settings::write_settings(&app, settings);
@@ -228,7 +595,7 @@ pub fn change_selected_language_setting(app: AppHandle, language: String) -> Res
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Result<(), String> {
+pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     let parsed = match position.as_str() {
         "none" => OverlayPosition::None,
@@ -250,45 +617,31 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.debug_mode = enabled;
     settings::write_settings(&app, settings);
 
-    // Emit event to notify frontend of debug mode change
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "debug_mode",
-            "value": enabled
-        }),
-    );
+    events::emit_settings_changed(&app, "debug_mode", enabled);
 
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_start_hidden_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_start_hidden_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.start_hidden = enabled;
     settings::write_settings(&app, settings);
 
-    // Notify frontend
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "start_hidden",
-            "value": enabled
-        }),
-    );
+    events::emit_settings_changed(&app, "start_hidden", enabled);
 
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.autostart_enabled = enabled;
     settings::write_settings(&app, settings);
@@ -301,51 +654,53 @@ pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), Str
         let _ = autostart_manager.disable();
     }
 
-    // Notify frontend
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "autostart_enabled",
-            "value": enabled
-        }),
-    );
+    events::emit_settings_changed(&app, "autostart_enabled", enabled);
 
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.update_checks_enabled = enabled;
     settings::write_settings(&app, settings);
 
-    let _ = app.emit(
-        "settings-changed",
-        serde_json::json!({
-            "setting": "update_checks_enabled",
-            "value": enabled
-        }),
-    );
+    events::emit_settings_changed(&app, "update_checks_enabled", enabled);
 
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), String> {
+pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.custom_words = words;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn update_cased_terms(app: AppHandle, terms: Vec<String>) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    let mut cased_terms: Vec<String> = Vec::new();
+    for term in terms.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if !cased_terms.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+            cased_terms.push(term.to_string());
+        }
+    }
+    settings.cased_terms = cased_terms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_word_correction_threshold_setting(
     app: AppHandle,
     threshold: f64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.word_correction_threshold = threshold;
     settings::write_settings(&app, settings);
@@ -357,7 +712,7 @@ pub fn change_word_correction_threshold_setting(
 pub fn change_quick_chat_initial_prompt_setting(
     app: AppHandle,
     prompt: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.quick_chat_initial_prompt = prompt;
     settings::write_settings(&app, settings);
@@ -369,7 +724,7 @@ pub fn change_quick_chat_initial_prompt_setting(
 pub fn change_unknown_command_template_setting(
     app: AppHandle,
     template: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.unknown_command_template = template;
     settings::write_settings(&app, settings);
@@ -381,7 +736,7 @@ pub fn change_unknown_command_template_setting(
 pub fn change_unknown_command_terminal_setting(
     app: AppHandle,
     terminal: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.unknown_command_terminal = terminal;
     settings::write_settings(&app, settings);
@@ -390,7 +745,7 @@ pub fn change_unknown_command_terminal_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(), String> {
+pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     let parsed = match method.as_str() {
         "ctrl_v" => PasteMethod::CtrlV,
@@ -410,7 +765,7 @@ pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(),
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Result<(), String> {
+pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     let parsed = match handling.as_str() {
         "dont_modify" => ClipboardHandling::DontModify,
@@ -430,7 +785,7 @@ pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Re
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.coherent_enabled = enabled;
     settings::write_settings(&app, settings);
@@ -443,7 +798,7 @@ pub fn change_post_process_base_url_setting(
     app: AppHandle,
     provider_id: String,
     base_url: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Find the provider in llm_providers
@@ -451,14 +806,14 @@ pub fn change_post_process_base_url_setting(
         .llm_providers
         .iter_mut()
         .find(|p| p.id == provider_id)
-        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+        .ok_or_else(|| AppError::not_found(format!("Provider '{}' not found", provider_id)))?;
 
     // Only allow editing custom providers
     if !provider.is_custom {
-        return Err(format!(
+        return Err(AppError::invalid_input(format!(
             "Provider '{}' does not allow editing the base URL",
             provider.name
-        ));
+        )));
     }
 
     provider.base_url = base_url;
@@ -472,7 +827,7 @@ pub fn change_post_process_api_key_setting(
     app: AppHandle,
     provider_id: String,
     api_key: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Find the provider in llm_providers and update its API key
@@ -480,7 +835,7 @@ pub fn change_post_process_api_key_setting(
         .llm_providers
         .iter_mut()
         .find(|p| p.id == provider_id)
-        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+        .ok_or_else(|| AppError::not_found(format!("Provider '{}' not found", provider_id)))?;
 
     provider.api_key = api_key;
     settings::write_settings(&app, settings);
@@ -493,7 +848,7 @@ pub fn change_post_process_model_setting(
     _app: AppHandle,
     _provider_id: String,
     _model: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     // Deprecated: Model is now set via llm_models and default_*_model_id
     // This command is kept for API compatibility but does nothing
     Ok(())
@@ -501,7 +856,7 @@ pub fn change_post_process_model_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn set_post_process_provider(_app: AppHandle, _provider_id: String) -> Result<(), String> {
+pub fn set_post_process_provider(_app: AppHandle, _provider_id: String) -> Result<(), AppError> {
     // Deprecated: Provider is now selected via model selection (models link to providers)
     // This command is kept for API compatibility but does nothing
     Ok(())
@@ -513,7 +868,7 @@ pub fn add_post_process_prompt(
     app: AppHandle,
     name: String,
     prompt: String,
-) -> Result<LLMPrompt, String> {
+) -> Result<LLMPrompt, AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Generate unique ID using timestamp and random component
@@ -538,7 +893,7 @@ pub fn update_post_process_prompt(
     id: String,
     name: String,
     prompt: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     if let Some(existing_prompt) = settings.coherent_prompts.iter_mut().find(|p| p.id == id) {
@@ -547,27 +902,35 @@ pub fn update_post_process_prompt(
         settings::write_settings(&app, settings);
         Ok(())
     } else {
-        Err(format!("Prompt with id '{}' not found", id))
+        Err(AppError::not_found(format!(
+            "Prompt with id '{}' not found",
+            id
+        )))
     }
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), String> {
+pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Don't allow deleting the last prompt
     if settings.coherent_prompts.len() <= 1 {
-        return Err("Cannot delete the last prompt".to_string());
+        return Err(AppError::invalid_input("Cannot delete the last prompt"));
     }
 
     // Find and remove the prompt
-    let original_len = settings.coherent_prompts.len();
-    settings.coherent_prompts.retain(|p| p.id != id);
-
-    if settings.coherent_prompts.len() == original_len {
-        return Err(format!("Prompt with id '{}' not found", id));
-    }
+    let index = settings
+        .coherent_prompts
+        .iter()
+        .position(|p| p.id == id)
+        .ok_or_else(|| AppError::not_found(format!("Prompt with id '{}' not found", id)))?;
+    let removed = settings.coherent_prompts.remove(index);
+    trash::move_to_trash(
+        &mut settings,
+        removed.name.clone(),
+        settings::TrashedItem::CoherentPrompt(removed),
+    );
 
     // If the deleted prompt was selected, select the first one or None
     if settings.coherent_selected_prompt_id.as_ref() == Some(&id) {
@@ -584,7 +947,7 @@ pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), Stri
 pub async fn fetch_post_process_models(
     app: AppHandle,
     provider_id: String,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, AppError> {
     log::info!(
         "fetch_post_process_models: called with provider_id={}",
         provider_id
@@ -602,7 +965,7 @@ pub async fn fetch_post_process_models(
                 "fetch_post_process_models: provider '{}' not found",
                 provider_id
             );
-            format!("Provider '{}' not found", provider_id)
+            AppError::not_found(format!("Provider '{}' not found", provider_id))
         })?;
 
     log::info!(
@@ -625,7 +988,7 @@ pub async fn fetch_post_process_models(
             log::error!(
                 "fetch_post_process_models: Apple Intelligence not available on this platform"
             );
-            return Err("Apple Intelligence is only available on Apple silicon Macs running macOS 15 or later.".to_string());
+            return Err(AppError::invalid_input("Apple Intelligence is only available on Apple silicon Macs running macOS 15 or later."));
         }
     }
 
@@ -650,7 +1013,7 @@ pub async fn fetch_post_process_models(
         }
         Err(e) => {
             log::error!("fetch_post_process_models: failed to get API key: {}", e);
-            return Err(e);
+            return Err(e.into());
         }
     };
 
@@ -691,7 +1054,7 @@ fn get_hardcoded_oauth_models(provider_id: &str) -> Vec<String> {
 async fn fetch_models_manual(
     provider: &crate::settings::LLMProvider,
     api_key: String,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, AppError> {
     use crate::oauth::{google, openai as openai_oauth, tokens::load_tokens, OAuthProvider};
     use crate::settings::AuthMethod;
 
@@ -824,6 +1187,9 @@ async fn fetch_models_manual(
         );
     }
 
+    // User-defined headers go last so they can replace the defaults above
+    headers.extend(crate::llm_client::custom_headers(provider, &api_key)?);
+
     let http_client = reqwest::Client::builder()
         .default_headers(headers)
         .build()
@@ -836,7 +1202,7 @@ async fn fetch_models_manual(
     log::info!("fetch_models_manual: sending HTTP request...");
     let response = http_client.get(&endpoint).send().await.map_err(|e| {
         log::error!("fetch_models_manual: HTTP request failed: {}", e);
-        format!("Failed to fetch models: {}", e)
+        AppError::from_request(&e, format!("Failed to fetch models: {}", e))
     })?;
 
     let status = response.status();
@@ -852,16 +1218,16 @@ async fn fetch_models_manual(
             status,
             error_text
         );
-        return Err(format!(
-            "Model list request failed ({}): {}",
-            status, error_text
+        return Err(AppError::from_status(
+            status,
+            format!("Model list request failed ({}): {}", status, error_text),
         ));
     }
 
     // Parse the response
     let response_text = response.text().await.map_err(|e| {
         log::error!("fetch_models_manual: failed to read response body: {}", e);
-        format!("Failed to read response: {}", e)
+        AppError::from_request(&e, format!("Failed to read response: {}", e))
     })?;
 
     log::debug!(
@@ -948,12 +1314,15 @@ async fn fetch_models_manual(
 
 #[tauri::command]
 #[specta::specta]
-pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<(), String> {
+pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
 
     // Verify the prompt exists
     if !settings.coherent_prompts.iter().any(|p| p.id == id) {
-        return Err(format!("Prompt with id '{}' not found", id));
+        return Err(AppError::not_found(format!(
+            "Prompt with id '{}' not found",
+            id
+        )));
     }
 
     settings.coherent_selected_prompt_id = Some(id);
@@ -963,7 +1332,7 @@ pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<()
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.mute_while_recording = enabled;
     settings::write_settings(&app, settings);
@@ -973,7 +1342,17 @@ pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Res
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_live_transcription_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.live_transcription = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.append_trailing_space = enabled;
     settings::write_settings(&app, settings);
@@ -983,13 +1362,14 @@ pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Re
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_app_language_setting(app: AppHandle, language: String) -> Result<(), String> {
+pub fn change_app_language_setting(app: AppHandle, language: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.app_language = language.clone();
     settings::write_settings(&app, settings);
 
     // Refresh the tray menu with the new language
     tray::update_tray_menu(&app, &tray::TrayIconState::Idle, Some(&language));
+    let _ = AppLanguageChanged(language).emit(&app);
 
     Ok(())
 }
@@ -998,7 +1378,7 @@ pub fn change_app_language_setting(app: AppHandle, language: String) -> Result<(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_ramble_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.coherent_enabled = enabled;
     settings::write_settings(&app, settings);
@@ -1007,7 +1387,10 @@ pub fn change_ramble_enabled_setting(app: AppHandle, enabled: bool) -> Result<()
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_provider_setting(_app: AppHandle, _provider_id: String) -> Result<(), String> {
+pub fn change_ramble_provider_setting(
+    _app: AppHandle,
+    _provider_id: String,
+) -> Result<(), AppError> {
     // Deprecated: Provider is now selected via default_coherent_model_id
     Ok(())
 }
@@ -1016,21 +1399,24 @@ pub fn change_ramble_provider_setting(_app: AppHandle, _provider_id: String) ->
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_llm_provider_setting(_app: AppHandle, _provider_id: String) -> Result<(), String> {
+pub fn change_llm_provider_setting(_app: AppHandle, _provider_id: String) -> Result<(), AppError> {
     // Deprecated: Provider is now selected via model selection
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_model_setting(_app: AppHandle, _model: String) -> Result<(), String> {
+pub fn change_ramble_model_setting(_app: AppHandle, _model: String) -> Result<(), AppError> {
     // Deprecated: Model is now set via default_coherent_model_id
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_use_vision_model_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_ramble_use_vision_model_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.coherent_use_vision = enabled;
     settings::write_settings(&app, settings);
@@ -1039,28 +1425,28 @@ pub fn change_ramble_use_vision_model_setting(app: AppHandle, enabled: bool) ->
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_vision_model_setting(_app: AppHandle, _model: String) -> Result<(), String> {
+pub fn change_ramble_vision_model_setting(_app: AppHandle, _model: String) -> Result<(), AppError> {
     // Deprecated: Vision model is now the same as coherent model (supports_vision flag)
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ramble_prompt_setting(_app: AppHandle, _prompt: String) -> Result<(), String> {
+pub fn change_ramble_prompt_setting(_app: AppHandle, _prompt: String) -> Result<(), AppError> {
     // Deprecated: Prompts are now managed via coherent_prompts
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn reset_ramble_prompt_to_default(_app: AppHandle) -> Result<String, String> {
+pub fn reset_ramble_prompt_to_default(_app: AppHandle) -> Result<String, AppError> {
     // Deprecated: Prompts are now managed via coherent_prompts
     Ok(String::new())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_context_chat_prompt_setting(app: AppHandle, prompt: String) -> Result<(), String> {
+pub fn change_context_chat_prompt_setting(app: AppHandle, prompt: String) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.context_chat_prompt = prompt;
     settings::write_settings(&app, settings);
@@ -1069,7 +1455,7 @@ pub fn change_context_chat_prompt_setting(app: AppHandle, prompt: String) -> Res
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_hold_threshold_setting(app: AppHandle, threshold_ms: u64) -> Result<(), String> {
+pub fn change_hold_threshold_setting(app: AppHandle, threshold_ms: u64) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.hold_threshold_ms = threshold_ms;
     settings::write_settings(&app, settings);
@@ -1078,123 +1464,524 @@ pub fn change_hold_threshold_setting(app: AppHandle, threshold_ms: u64) -> Resul
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_system_prompt_file_setting(
-    app: AppHandle,
-    path: Option<String>,
-) -> Result<(), String> {
+pub fn change_double_press_cancel_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-    settings.system_prompt_file = path;
+    settings.double_press_cancel = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_clipboard_content_cutoff_setting(app: AppHandle, cutoff: u32) -> Result<(), String> {
+pub fn change_double_press_cancel_window_setting(
+    app: AppHandle,
+    window_ms: u64,
+) -> Result<(), AppError> {
+    if !(100..=1000).contains(&window_ms) {
+        return Err(AppError::invalid_input(
+            "Double-press window must be between 100 and 1000 ms",
+        ));
+    }
     let mut settings = settings::get_settings(&app);
-    settings.clipboard_content_cutoff = cutoff;
+    settings.double_press_cancel_window_ms = window_ms;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
-// Prompt mode and category commands
+#[tauri::command]
+#[specta::specta]
+pub fn change_no_speech_timeout_setting(app: AppHandle, seconds: u32) -> Result<(), AppError> {
+    if seconds > 120 {
+        return Err(AppError::invalid_input(
+            "No-speech timeout must be at most 120 seconds",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.no_speech_timeout_seconds = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
+/// Set how many seconds of silence end a toggle-mode recording for a binding (0 = off)
 #[tauri::command]
 #[specta::specta]
-pub fn change_prompt_mode_setting(
+pub fn change_binding_auto_stop_setting(
     app: AppHandle,
-    mode: settings::PromptMode,
-) -> Result<(), String> {
-    tray::set_prompt_mode(&app, mode);
+    id: String,
+    seconds: u32,
+) -> Result<(), AppError> {
+    if seconds > 60 {
+        return Err(AppError::invalid_input(
+            "Auto-stop silence must be at most 60 seconds",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    let binding = settings
+        .bindings
+        .get_mut(&id)
+        .ok_or_else(|| AppError::not_found(format!("Binding with id '{}' not found", id)))?;
+    binding.auto_stop_silence_seconds = seconds;
+    settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Set whether taps, holds, both or neither refine recordings started by a binding
 #[tauri::command]
 #[specta::specta]
-pub fn update_prompt_category(app: AppHandle, id: String, prompt: String) -> Result<(), String> {
+pub fn change_binding_tap_hold_mode_setting(
+    app: AppHandle,
+    id: String,
+    mode: settings::TapHoldMode,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
+    let binding = settings
+        .bindings
+        .get_mut(&id)
+        .ok_or_else(|| AppError::not_found(format!("Binding with id '{}' not found", id)))?;
+    binding.tap_hold_mode = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
-    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
-        category.prompt = prompt;
-        settings::write_settings(&app, settings);
-        Ok(())
-    } else {
-        Err(format!("Category with id '{}' not found", id))
+fn validate_spend_limit(limit: Option<f64>) -> Result<(), String> {
+    match limit {
+        Some(l) if !l.is_finite() || l <= 0.0 => {
+            Err("Spend limit must be a positive amount".to_string())
+        }
+        _ => Ok(()),
     }
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn reset_prompt_category_to_default(app: AppHandle, id: String) -> Result<String, String> {
+pub fn change_daily_spend_limit_setting(
+    app: AppHandle,
+    limit: Option<f64>,
+) -> Result<(), AppError> {
+    validate_spend_limit(limit).map_err(AppError::invalid_input)?;
     let mut settings = settings::get_settings(&app);
-    let defaults = settings::get_default_settings();
-
-    // Find the default prompt for this category
-    let default_prompt = defaults
-        .prompt_categories
-        .iter()
-        .find(|c| c.id == id)
-        .map(|c| c.prompt.clone())
-        .ok_or_else(|| format!("Default prompt for category '{}' not found", id))?;
-
-    // Update the current settings
-    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
-        category.prompt = default_prompt.clone();
-        settings::write_settings(&app, settings);
-        Ok(default_prompt)
-    } else {
-        Err(format!("Category with id '{}' not found", id))
-    }
+    settings.daily_spend_limit_usd = limit;
+    settings::write_settings(&app, settings);
+    Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_default_category_setting(app: AppHandle, category_id: String) -> Result<(), String> {
+pub fn change_per_request_spend_limit_setting(
+    app: AppHandle,
+    limit: Option<f64>,
+) -> Result<(), AppError> {
+    validate_spend_limit(limit).map_err(AppError::invalid_input)?;
     let mut settings = settings::get_settings(&app);
+    settings.per_request_spend_limit_usd = limit;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
-    // Verify the category exists
-    if !settings
-        .prompt_categories
-        .iter()
-        .any(|c| c.id == category_id)
-    {
-        return Err(format!("Category with id '{}' not found", category_id));
-    }
-
-    settings.default_category_id = category_id;
+#[tauri::command]
+#[specta::specta]
+pub fn change_noise_suppression_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.noise_suppression = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
-/// Create a new custom prompt category
 #[tauri::command]
 #[specta::specta]
-pub fn add_prompt_category(
+pub fn change_accessibility_announcements_setting(
     app: AppHandle,
-    name: String,
-    icon: String,
-    prompt: String,
-) -> Result<settings::PromptCategory, String> {
+    enabled: bool,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
+    settings.accessibility_announcements = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
-    // Generate unique ID from name
-    let base_id = name.to_lowercase().replace(' ', "_");
-    let mut id = base_id.clone();
-    let mut counter = 1;
-
-    // Ensure unique ID
-    while settings.prompt_categories.iter().any(|c| c.id == id) {
-        id = format!("{}_{}", base_id, counter);
-        counter += 1;
+#[tauri::command]
+#[specta::specta]
+pub fn change_battery_saver_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.battery_saver_enabled = enabled;
+    settings::write_settings(&app, settings);
+    std::thread::spawn(move || crate::power::check(&app));
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_battery_saver_threshold_setting(
+    app: AppHandle,
+    threshold: u8,
+) -> Result<(), AppError> {
+    if threshold > 100 {
+        return Err(AppError::invalid_input(
+            "Battery saver threshold must be a percentage",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.battery_saver_threshold = threshold;
+    settings::write_settings(&app, settings);
+    std::thread::spawn(move || crate::power::check(&app));
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_media_key_trigger_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.media_key_trigger_enabled = enabled;
+    settings::write_settings(&app, settings);
+    crate::media_keys::update(&app).map_err(AppError::internal)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_media_key_binding_setting(app: AppHandle, binding: String) -> Result<(), AppError> {
+    if !settings::MEDIA_KEY_BINDINGS.contains(&binding.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "The play/pause key can't trigger '{}'",
+            binding
+        )));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.media_key_binding = binding;
+    settings::write_settings(&app, settings);
+    crate::media_keys::update(&app).map_err(AppError::internal)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_trigger_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.remote_trigger_enabled = enabled;
+    settings::write_settings(&app, settings);
+    crate::remote_trigger::update(&app).map_err(AppError::internal)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_trigger_port_setting(app: AppHandle, port: u16) -> Result<(), AppError> {
+    if port < 1024 {
+        return Err(AppError::invalid_input(
+            "Remote trigger port must be 1024 or higher",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.remote_trigger_port = port;
+    settings::write_settings(&app, settings);
+    crate::remote_trigger::update(&app).map_err(AppError::internal)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_trigger_lan_access_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.remote_trigger_lan_access = enabled;
+    settings::write_settings(&app, settings);
+    crate::remote_trigger::update(&app).map_err(AppError::internal)
+}
+
+/// Sync right away after the sync setup changes, so problems show up immediately
+fn sync_settings_soon(app: AppHandle) {
+    std::thread::spawn(move || {
+        if let Err(e) = crate::settings_sync::sync(&app) {
+            warn!("Settings sync failed: {}", e);
+        }
+    });
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_sync_folder_setting(app: AppHandle, path: Option<String>) -> Result<(), AppError> {
+    let path = path.filter(|p| !p.trim().is_empty());
+    if let Some(p) = &path {
+        if !std::path::Path::new(p).is_dir() {
+            return Err(AppError::invalid_input(format!("Not a folder: {}", p)));
+        }
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.sync_folder = path;
+    settings::write_settings(&app, settings);
+    sync_settings_soon(app);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_sync_passphrase_setting(
+    app: AppHandle,
+    passphrase: Option<String>,
+) -> Result<(), AppError> {
+    let passphrase = passphrase.filter(|p| !p.is_empty());
+    if passphrase.as_ref().is_some_and(|p| p.chars().count() < 8) {
+        return Err(AppError::invalid_input(
+            "The sync passphrase needs at least 8 characters",
+        ));
+    }
+    crate::settings_sync::save_passphrase(&app, passphrase.as_deref())
+        .map_err(AppError::internal)?;
+    let mut settings = settings::get_settings(&app);
+    settings.sync_passphrase_saved = passphrase.is_some();
+    settings::write_settings(&app, settings);
+    sync_settings_soon(app);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_system_prompt_file_setting(
+    app: AppHandle,
+    path: Option<String>,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.system_prompt_file = path;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_local_llm_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    if enabled && !crate::managers::local_llm::is_available() {
+        return Err(AppError::not_configured(
+            "llama-server wasn't found, so the local model can't be run",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.local_llm_enabled = enabled;
+    settings::write_settings(&app, settings);
+    if !enabled {
+        app.state::<Arc<LocalLlmManager>>().unload();
     }
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_local_llm_model_path_setting(
+    app: AppHandle,
+    path: Option<String>,
+) -> Result<(), AppError> {
+    if let Some(path) = &path {
+        if !path.to_lowercase().ends_with(".gguf") {
+            return Err(AppError::invalid_input("Local models must be GGUF files"));
+        }
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.local_llm_model_path = path;
+    settings::write_settings(&app, settings);
+    app.state::<Arc<LocalLlmManager>>().unload();
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_clipboard_content_cutoff_setting(
+    app: AppHandle,
+    cutoff: u32,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.clipboard_content_cutoff = cutoff;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+// Prompt mode and category commands
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_prompt_mode_setting(
+    app: AppHandle,
+    mode: settings::PromptMode,
+) -> Result<(), AppError> {
+    tray::set_prompt_mode(&app, mode);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_prompt_category(app: AppHandle, id: String, prompt: String) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.prompt = prompt;
+        settings::write_settings(&app, settings);
+        Ok(())
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn reset_prompt_category_to_default(app: AppHandle, id: String) -> Result<String, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let defaults = settings::get_default_settings();
+
+    // Find the default prompt for this category
+    let default_prompt = defaults
+        .prompt_categories
+        .iter()
+        .find(|c| c.id == id)
+        .map(|c| c.prompt.clone())
+        .ok_or_else(|| {
+            AppError::not_found(format!("Default prompt for category '{}' not found", id))
+        })?;
+
+    // Update the current settings
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.prompt = default_prompt.clone();
+        settings::write_settings(&app, settings);
+        Ok(default_prompt)
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_default_category_setting(
+    app: AppHandle,
+    category_id: String,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    // Verify the category exists
+    if !settings
+        .prompt_categories
+        .iter()
+        .any(|c| c.id == category_id)
+    {
+        return Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            category_id
+        )));
+    }
+
+    settings.default_category_id = category_id;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_record_detected_apps_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.record_detected_apps = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the apps kept out of the detected apps history, dropping any already in it
+#[tauri::command]
+#[specta::specta]
+pub fn change_detected_apps_excluded_setting(
+    app: AppHandle,
+    bundle_ids: Vec<String>,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    let bundle_ids: Vec<String> = bundle_ids
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    settings.detected_apps_history.retain(|detected| {
+        !bundle_ids
+            .iter()
+            .any(|id| id.eq_ignore_ascii_case(&detected.bundle_identifier))
+    });
+    settings.detected_apps_excluded = bundle_ids;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Create a new custom prompt category
+#[tauri::command]
+#[specta::specta]
+pub fn add_prompt_category(
+    app: AppHandle,
+    name: String,
+    icon: String,
+    prompt: String,
+) -> Result<settings::PromptCategory, AppError> {
+    let mut settings = settings::get_settings(&app);
+    let id = unique_category_id(&settings, &name);
 
     let new_category = settings::PromptCategory {
-        id: id.clone(),
+        id,
         name,
         icon,
         prompt,
         is_builtin: false,
         model_override: None,
+        language: None,
+        chaptering: false,
+    };
+
+    settings.prompt_categories.push(new_category.clone());
+    settings::write_settings(&app, settings);
+
+    Ok(new_category)
+}
+
+/// Generate a unique category ID from its name
+pub(crate) fn unique_category_id(settings: &settings::AppSettings, name: &str) -> String {
+    let base_id = name.to_lowercase().replace(' ', "_");
+    let mut id = base_id.clone();
+    let mut counter = 1;
+
+    while settings.prompt_categories.iter().any(|c| c.id == id) {
+        id = format!("{}_{}", base_id, counter);
+        counter += 1;
+    }
+    id
+}
+
+/// Create a custom copy of an existing category (built-in or custom) under a new name
+#[tauri::command]
+#[specta::specta]
+pub fn duplicate_prompt_category(
+    app: AppHandle,
+    id: String,
+    new_name: String,
+) -> Result<settings::PromptCategory, AppError> {
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() {
+        return Err(AppError::invalid_input("Category name cannot be empty"));
+    }
+
+    let mut settings = settings::get_settings(&app);
+
+    let source = settings
+        .prompt_categories
+        .iter()
+        .find(|c| c.id == id)
+        .cloned()
+        .ok_or_else(|| AppError::not_found(format!("Category with id '{}' not found", id)))?;
+
+    let new_category = settings::PromptCategory {
+        id: unique_category_id(&settings, &new_name),
+        name: new_name,
+        icon: source.icon,
+        prompt: source.prompt,
+        is_builtin: false,
+        model_override: source.model_override,
+        language: source.language,
+        chaptering: source.chaptering,
     };
 
     settings.prompt_categories.push(new_category.clone());
@@ -1203,135 +1990,419 @@ pub fn add_prompt_category(
     Ok(new_category)
 }
 
-/// Delete a custom prompt category
+/// Get the starter templates available for new prompt categories
+#[tauri::command]
+#[specta::specta]
+pub fn get_prompt_category_templates() -> Vec<settings::PromptCategoryTemplate> {
+    settings::prompt_category_templates()
+}
+
+/// Create a new custom prompt category from a starter template
+#[tauri::command]
+#[specta::specta]
+pub fn add_prompt_category_from_template(
+    app: AppHandle,
+    template_id: String,
+) -> Result<settings::PromptCategory, AppError> {
+    let template = settings::prompt_category_templates()
+        .into_iter()
+        .find(|t| t.id == template_id)
+        .ok_or_else(|| AppError::not_found(format!("Template '{}' not found", template_id)))?;
+
+    add_prompt_category(app, template.name, template.icon, template.prompt)
+}
+
+/// Delete a custom prompt category
+#[tauri::command]
+#[specta::specta]
+pub fn delete_prompt_category(app: AppHandle, id: String) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    // Find the category
+    let category = settings.prompt_categories.iter().find(|c| c.id == id);
+
+    match category {
+        None => {
+            return Err(AppError::not_found(format!(
+                "Category with id '{}' not found",
+                id
+            )))
+        }
+        Some(cat) if cat.is_builtin => {
+            return Err(AppError::invalid_input("Cannot delete built-in categories"))
+        }
+        _ => {}
+    }
+
+    // Check if this category is the default
+    if settings.default_category_id == id {
+        // Reset default to "development"
+        settings.default_category_id = "development".to_string();
+    }
+
+    // Remove any app mappings that use this category (kept with the category in the trash)
+    let (app_mappings, remaining): (Vec<_>, Vec<_>) = settings
+        .app_category_mappings
+        .drain(..)
+        .partition(|m| m.category_id == id);
+    settings.app_category_mappings = remaining;
+
+    // Remove the category
+    if let Some(index) = settings.prompt_categories.iter().position(|c| c.id == id) {
+        let category = settings.prompt_categories.remove(index);
+        trash::move_to_trash(
+            &mut settings,
+            category.name.clone(),
+            settings::TrashedItem::PromptCategory {
+                category,
+                app_mappings,
+            },
+        );
+    }
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Update a category's name and icon (not prompt - use update_prompt_category for that)
+#[tauri::command]
+#[specta::specta]
+pub fn update_prompt_category_details(
+    app: AppHandle,
+    id: String,
+    name: String,
+    icon: String,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.name = name;
+        category.icon = icon;
+        settings::write_settings(&app, settings);
+        Ok(())
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+/// Update a category's model override (None = use default coherent model)
+#[tauri::command]
+#[specta::specta]
+pub fn update_prompt_category_model_override(
+    app: AppHandle,
+    id: String,
+    model_id: Option<String>,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.model_override = model_id;
+        settings::write_settings(&app, settings);
+        Ok(())
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+/// Update a category's transcription language (None = use the global language)
+#[tauri::command]
+#[specta::specta]
+pub fn update_prompt_category_language(
+    app: AppHandle,
+    id: String,
+    language: Option<String>,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.language = language.filter(|l| !l.is_empty() && l != "auto");
+        settings::write_settings(&app, settings);
+        Ok(())
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+/// Turn topic headings and a table of contents for long refinements on or off for a
+/// category
+#[tauri::command]
+#[specta::specta]
+pub fn update_prompt_category_chaptering(
+    app: AppHandle,
+    id: String,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
+        category.chaptering = enabled;
+        settings::write_settings(&app, settings);
+        Ok(())
+    } else {
+        Err(AppError::not_found(format!(
+            "Category with id '{}' not found",
+            id
+        )))
+    }
+}
+
+/// Change the keyboard layout used for shortcut key labels and re-register all bindings
+#[tauri::command]
+#[specta::specta]
+pub fn change_keyboard_layout_setting(
+    app: AppHandle,
+    layout: settings::KeyboardLayout,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.keyboard_layout = layout;
+    settings::write_settings(&app, settings);
+
+    match layout {
+        settings::KeyboardLayout::Auto => detect_layout_in_background(&app),
+        layout => reregister_with_layout(&app, layout),
+    }
+    Ok(())
+}
+
+/// Convert a shortcut recorded from physical key positions into the labels
+/// shown on the user's keyboard, so the stored binding reads as labeled.
+#[tauri::command]
+#[specta::specta]
+pub fn shortcut_to_layout_labels(shortcut: String) -> String {
+    keyboard_layout::to_label_shortcut(&shortcut, keyboard_layout::active_layout())
+}
+
+// Voice command settings commands
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_commands_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_commands_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_default_model_setting(
+    app: AppHandle,
+    model: String,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_default_model = model;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set how long to keep listening for a follow-up after a voice command (0 = off)
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_follow_up_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), AppError> {
+    if seconds > 60 {
+        return Err(AppError::invalid_input(
+            "Follow-up listening must be at most 60 seconds",
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_follow_up_seconds = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn reset_voice_commands_to_default(
+    app: AppHandle,
+) -> Result<Vec<settings::VoiceCommand>, AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_commands = settings::get_default_settings().voice_commands;
+    let commands = settings.voice_commands.clone();
+    settings::write_settings(&app, settings);
+    Ok(commands)
+}
+
 #[tauri::command]
 #[specta::specta]
-pub fn delete_prompt_category(app: AppHandle, id: String) -> Result<(), String> {
+pub fn change_filler_word_filter_setting(
+    app: AppHandle,
+    pattern: Option<String>,
+) -> Result<(), AppError> {
+    // Validate regex if provided
+    if let Some(ref p) = pattern {
+        if !p.is_empty() {
+            regex::Regex::new(p)
+                .map_err(|e| AppError::invalid_input(format!("Invalid regex pattern: {}", e)))?;
+        }
+    }
     let mut settings = settings::get_settings(&app);
+    settings.filler_word_filter = pattern;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
-    // Find the category
-    let category = settings.prompt_categories.iter().find(|c| c.id == id);
+/// Turn focus mode on or off. Open chat windows are hidden while it's on.
+pub fn set_focus_mode(app: &AppHandle, enabled: bool) {
+    let mut settings = settings::get_settings(app);
+    settings.focus_mode = enabled;
+    settings::write_settings(app, settings);
 
-    match category {
-        None => return Err(format!("Category with id '{}' not found", id)),
-        Some(cat) if cat.is_builtin => return Err("Cannot delete built-in categories".to_string()),
-        _ => {}
+    for (label, window) in app.webview_windows() {
+        if label.starts_with("chat_") {
+            let _ = if enabled {
+                window.hide()
+            } else {
+                window.show()
+            };
+        }
     }
+    log::info!(
+        "Focus mode {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
 
-    // Check if this category is the default
-    if settings.default_category_id == id {
-        // Reset default to "development"
-        settings.default_category_id = "development".to_string();
-    }
+    let _ = FocusModeChanged(enabled).emit(app);
+    tray::update_tray_menu(app, &tray::TrayIconState::Idle, None);
+}
 
-    // Remove any app mappings that use this category
-    settings
-        .app_category_mappings
-        .retain(|m| m.category_id != id);
+#[tauri::command]
+#[specta::specta]
+pub fn change_focus_mode_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    set_focus_mode(&app, enabled);
+    Ok(())
+}
 
-    // Remove the category
-    settings.prompt_categories.retain(|c| c.id != id);
+#[tauri::command]
+#[specta::specta]
+pub fn change_hide_when_presenting_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.hide_when_presenting = enabled;
     settings::write_settings(&app, settings);
+    Ok(())
+}
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_chat_read_aloud_setting(app: AppHandle, enabled: bool) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.chat_read_aloud = enabled;
+    settings::write_settings(&app, settings);
     Ok(())
 }
 
-/// Update a category's name and icon (not prompt - use update_prompt_category for that)
+/// Choose the text-to-speech engine; the next speech loads it
 #[tauri::command]
 #[specta::specta]
-pub fn update_prompt_category_details(
+pub async fn change_tts_backend_setting(
     app: AppHandle,
-    id: String,
-    name: String,
-    icon: String,
-) -> Result<(), String> {
+    backend: settings::TtsBackend,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-
-    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
-        category.name = name;
-        category.icon = icon;
-        settings::write_settings(&app, settings);
-        Ok(())
-    } else {
-        Err(format!("Category with id '{}' not found", id))
-    }
+    settings.tts_backend = backend;
+    settings::write_settings(&app, settings);
+    app.state::<Arc<TTSManager>>().unload().await;
+    Ok(())
 }
 
-/// Update a category's model override (None = use default coherent model)
 #[tauri::command]
 #[specta::specta]
-pub fn update_prompt_category_model_override(
+pub async fn change_tts_system_voice_setting(
     app: AppHandle,
-    id: String,
-    model_id: Option<String>,
-) -> Result<(), String> {
+    voice: Option<String>,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-
-    if let Some(category) = settings.prompt_categories.iter_mut().find(|c| c.id == id) {
-        category.model_override = model_id;
-        settings::write_settings(&app, settings);
-        Ok(())
-    } else {
-        Err(format!("Category with id '{}' not found", id))
-    }
+    settings.tts_system_voice = voice;
+    settings::write_settings(&app, settings);
+    app.state::<Arc<TTSManager>>().unload().await;
+    Ok(())
 }
 
-// Voice command settings commands
-
+/// Choose the voice for a cloud TTS service
 #[tauri::command]
 #[specta::specta]
-pub fn change_voice_commands_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub async fn change_tts_cloud_voice_setting(
+    app: AppHandle,
+    service: String,
+    voice: String,
+) -> Result<(), AppError> {
+    if !crate::tts::cloud::TTS_SERVICES.contains(&service.as_str()) {
+        return Err(AppError::invalid_input(format!(
+            "Unknown speech service: {}",
+            service
+        )));
+    }
     let mut settings = settings::get_settings(&app);
-    settings.voice_commands_enabled = enabled;
+    // An empty voice goes back to the service's default
+    if voice.is_empty() {
+        settings.tts_cloud_voices.remove(&service);
+    } else {
+        settings.tts_cloud_voices.insert(service, voice);
+    }
     settings::write_settings(&app, settings);
+    app.state::<Arc<TTSManager>>().unload().await;
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_voice_command_default_model_setting(
-    app: AppHandle,
-    model: String,
-) -> Result<(), String> {
+pub fn change_max_paste_chars_setting(app: AppHandle, max_chars: u32) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-    settings.voice_command_default_model = model;
+    settings.max_paste_chars = max_chars;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Set the apps that results are never pasted into
 #[tauri::command]
 #[specta::specta]
-pub fn reset_voice_commands_to_default(
+pub fn change_paste_blocked_apps_setting(
     app: AppHandle,
-) -> Result<Vec<settings::VoiceCommand>, String> {
+    apps: Vec<String>,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-    settings.voice_commands = settings::get_default_settings().voice_commands;
-    let commands = settings.voice_commands.clone();
+    settings.paste_blocked_apps = apps
+        .into_iter()
+        .map(|app| app.trim().to_string())
+        .filter(|app| !app.is_empty())
+        .collect();
     settings::write_settings(&app, settings);
-    Ok(commands)
+    Ok(())
 }
 
+/// Set how results pasted into a terminal are changed
 #[tauri::command]
 #[specta::specta]
-pub fn change_filler_word_filter_setting(
+pub fn change_terminal_paste_mode_setting(
     app: AppHandle,
-    pattern: Option<String>,
-) -> Result<(), String> {
-    // Validate regex if provided
-    if let Some(ref p) = pattern {
-        if !p.is_empty() {
-            regex::Regex::new(p).map_err(|e| format!("Invalid regex pattern: {}", e))?;
-        }
-    }
+    mode: settings::TerminalPasteMode,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
-    settings.filler_word_filter = pattern;
+    settings.terminal_paste_mode = mode;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_collapse_repeated_words_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn change_collapse_repeated_words_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
     let mut settings = settings::get_settings(&app);
     settings.collapse_repeated_words = enabled;
     settings::write_settings(&app, settings);
@@ -1343,12 +2414,16 @@ pub fn change_collapse_repeated_words_setting(app: AppHandle, enabled: bool) ->
 pub fn add_voice_command(
     app: AppHandle,
     command: settings::VoiceCommand,
-) -> Result<Vec<settings::VoiceCommand>, String> {
+) -> Result<Vec<settings::VoiceCommand>, AppError> {
+    crate::voice_commands::validate_parameters(&command).map_err(AppError::invalid_input)?;
     let mut settings = settings::get_settings(&app);
 
     // Check for duplicate ID
     if settings.voice_commands.iter().any(|c| c.id == command.id) {
-        return Err(format!("Command with ID '{}' already exists", command.id));
+        return Err(AppError::invalid_input(format!(
+            "Command with ID '{}' already exists",
+            command.id
+        )));
     }
 
     settings.voice_commands.push(command);
@@ -1362,7 +2437,8 @@ pub fn add_voice_command(
 pub fn update_voice_command(
     app: AppHandle,
     command: settings::VoiceCommand,
-) -> Result<Vec<settings::VoiceCommand>, String> {
+) -> Result<Vec<settings::VoiceCommand>, AppError> {
+    crate::voice_commands::validate_parameters(&command).map_err(AppError::invalid_input)?;
     let mut settings = settings::get_settings(&app);
 
     // Find and update the command
@@ -1373,7 +2449,10 @@ pub fn update_voice_command(
     {
         *existing = command;
     } else {
-        return Err(format!("Command with ID '{}' not found", command.id));
+        return Err(AppError::not_found(format!(
+            "Command with ID '{}' not found",
+            command.id
+        )));
     }
 
     let commands = settings.voice_commands.clone();
@@ -1386,21 +2465,185 @@ pub fn update_voice_command(
 pub fn delete_voice_command(
     app: AppHandle,
     command_id: String,
-) -> Result<Vec<settings::VoiceCommand>, String> {
+) -> Result<Vec<settings::VoiceCommand>, AppError> {
     let mut settings = settings::get_settings(&app);
 
-    let original_len = settings.voice_commands.len();
-    settings.voice_commands.retain(|c| c.id != command_id);
-
-    if settings.voice_commands.len() == original_len {
-        return Err(format!("Command with ID '{}' not found", command_id));
-    }
+    let index = settings
+        .voice_commands
+        .iter()
+        .position(|c| c.id == command_id)
+        .ok_or_else(|| {
+            AppError::not_found(format!("Command with ID '{}' not found", command_id))
+        })?;
+    let removed = settings.voice_commands.remove(index);
+    trash::move_to_trash(
+        &mut settings,
+        removed.name.clone(),
+        settings::TrashedItem::VoiceCommand(removed),
+    );
 
     let commands = settings.voice_commands.clone();
     settings::write_settings(&app, settings);
     Ok(commands)
 }
 
+/// Logged voice command runs with what matched and what ran, oldest first
+#[tauri::command]
+#[specta::specta]
+pub fn get_voice_command_history(app: AppHandle) -> Vec<crate::voice_command_log::VoiceCommandRun> {
+    crate::voice_command_log::runs(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn clear_voice_command_history(app: AppHandle) {
+    crate::voice_command_log::clear(&app);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn add_reminder(
+    app: AppHandle,
+    reminder: settings::Reminder,
+) -> Result<Vec<settings::Reminder>, AppError> {
+    crate::reminders::validate(&reminder)?;
+    let mut settings = settings::get_settings(&app);
+
+    if settings.reminders.iter().any(|r| r.id == reminder.id) {
+        return Err(AppError::invalid_input(format!(
+            "Reminder with ID '{}' already exists",
+            reminder.id
+        )));
+    }
+
+    settings.reminders.push(reminder);
+    let reminders = settings.reminders.clone();
+    settings::write_settings(&app, settings);
+    Ok(reminders)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_reminder(
+    app: AppHandle,
+    reminder: settings::Reminder,
+) -> Result<Vec<settings::Reminder>, AppError> {
+    crate::reminders::validate(&reminder)?;
+    let mut settings = settings::get_settings(&app);
+
+    let existing = settings
+        .reminders
+        .iter_mut()
+        .find(|r| r.id == reminder.id)
+        .ok_or_else(|| {
+            AppError::not_found(format!("Reminder with ID '{}' not found", reminder.id))
+        })?;
+    *existing = reminder;
+
+    let reminders = settings.reminders.clone();
+    settings::write_settings(&app, settings);
+    Ok(reminders)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_reminder(
+    app: AppHandle,
+    reminder_id: String,
+) -> Result<Vec<settings::Reminder>, AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    let index = settings
+        .reminders
+        .iter()
+        .position(|r| r.id == reminder_id)
+        .ok_or_else(|| {
+            AppError::not_found(format!("Reminder with ID '{}' not found", reminder_id))
+        })?;
+    settings.reminders.remove(index);
+
+    let reminders = settings.reminders.clone();
+    settings::write_settings(&app, settings);
+    Ok(reminders)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn add_project(
+    app: AppHandle,
+    project: settings::Project,
+) -> Result<Vec<settings::Project>, AppError> {
+    let mut settings = settings::get_settings(&app);
+    crate::projects::validate(&project, &settings)?;
+
+    if settings.projects.iter().any(|p| p.id == project.id) {
+        return Err(AppError::invalid_input(format!(
+            "Project with ID '{}' already exists",
+            project.id
+        )));
+    }
+
+    settings.projects.push(project);
+    let projects = settings.projects.clone();
+    settings::write_settings(&app, settings);
+    Ok(projects)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_project(
+    app: AppHandle,
+    project: settings::Project,
+) -> Result<Vec<settings::Project>, AppError> {
+    let mut settings = settings::get_settings(&app);
+    crate::projects::validate(&project, &settings)?;
+
+    let existing = settings
+        .projects
+        .iter_mut()
+        .find(|p| p.id == project.id)
+        .ok_or_else(|| {
+            AppError::not_found(format!("Project with ID '{}' not found", project.id))
+        })?;
+    *existing = project;
+
+    let projects = settings.projects.clone();
+    settings::write_settings(&app, settings);
+    Ok(projects)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_project(
+    app: AppHandle,
+    project_id: String,
+) -> Result<Vec<settings::Project>, AppError> {
+    let mut settings = settings::get_settings(&app);
+
+    let index = settings
+        .projects
+        .iter()
+        .position(|p| p.id == project_id)
+        .ok_or_else(|| {
+            AppError::not_found(format!("Project with ID '{}' not found", project_id))
+        })?;
+    settings.projects.remove(index);
+    if settings.active_project_id.as_ref() == Some(&project_id) {
+        settings.active_project_id = None;
+    }
+
+    let projects = settings.projects.clone();
+    settings::write_settings(&app, settings);
+    Ok(projects)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_active_project(app: AppHandle, project_id: Option<String>) -> Result<(), AppError> {
+    crate::projects::activate(&app, project_id.as_deref())?;
+    Ok(())
+}
+
 /// Determine whether a shortcut string contains at least one non-modifier key.
 /// We allow single non-modifier keys (e.g. "f5" or "space") but disallow
 /// modifier-only combos (e.g. "ctrl" or "ctrl+shift").
@@ -1433,6 +2676,10 @@ fn validate_shortcut_string(raw: &str) -> Result<(), String> {
         "left_command",
         "right_command",
     ];
+    for part in raw.split('+') {
+        normalize_key_name(part)?;
+    }
+
     let has_non_modifier = raw
         .split('+')
         .any(|part| !modifiers.contains(&part.trim().to_lowercase().as_str()));
@@ -1443,11 +2690,69 @@ fn validate_shortcut_string(raw: &str) -> Result<(), String> {
     }
 }
 
+/// Map a key name as recorded by the UI (e.g. "numpad 1", "media play pause") to the
+/// name the global shortcut parser understands. Other keys are returned unchanged.
+fn normalize_key_name(part: &str) -> Result<String, String> {
+    let key = part.trim();
+    let compact: String = key
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect();
+
+    if let Some(rest) = compact.strip_prefix("numpad") {
+        if rest.len() == 1 && rest.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(compact);
+        }
+        let name = match rest {
+            "add" | "plus" => "numpadadd",
+            "subtract" | "minus" | "-" => "numpadsubtract",
+            "multiply" | "*" => "numpadmultiply",
+            "divide" | "/" => "numpaddivide",
+            "decimal" | "." => "numpaddecimal",
+            "enter" => "numpadenter",
+            "equal" | "=" => "numpadequal",
+            _ => return Err(format!("Unsupported numpad key '{}'", key)),
+        };
+        return Ok(name.to_string());
+    }
+
+    let media = match compact.as_str() {
+        "mediaplaypause" | "playpause" => Some("mediaplaypause"),
+        "mediaplay" => Some("mediaplay"),
+        "mediapause" => Some("mediapause"),
+        "mediastop" => Some("mediastop"),
+        "mediatracknext" | "medianext" => Some("mediatracknext"),
+        "mediatrackprevious" | "mediatrackprev" | "mediaprevious" => Some("mediatrackprev"),
+        "audiovolumeup" | "volumeup" => Some("audiovolumeup"),
+        "audiovolumedown" | "volumedown" => Some("audiovolumedown"),
+        "audiovolumemute" | "volumemute" => Some("audiovolumemute"),
+        _ => None,
+    };
+    if let Some(name) = media {
+        return Ok(name.to_string());
+    }
+    if compact.starts_with("media") {
+        return Err(format!("Unsupported media key '{}'", key));
+    }
+
+    if let Some(n) = compact
+        .strip_prefix('f')
+        .and_then(|n| n.parse::<u32>().ok())
+    {
+        if !(1..=24).contains(&n) {
+            return Err(format!("Unsupported function key 'F{}'", n));
+        }
+    }
+
+    Ok(key.to_string())
+}
+
 /// Temporarily unregister a binding while the user is editing it in the UI.
 /// This avoids firing the action while keys are being recorded.
 #[tauri::command]
 #[specta::specta]
-pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), String> {
+pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), AppError> {
     // Also suspend raw bindings on macOS
     #[cfg(target_os = "macos")]
     key_listener::suspend_raw_binding(&id);
@@ -1461,7 +2766,7 @@ pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), String> {
 
         if let Err(e) = unregister_shortcut(&app, b) {
             error!("suspend_binding error for id '{}': {}", id, e);
-            return Err(e);
+            return Err(e.into());
         }
     }
     Ok(())
@@ -1470,7 +2775,12 @@ pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), String> {
 /// Re-register the binding after the user has finished editing.
 #[tauri::command]
 #[specta::specta]
-pub fn resume_binding(app: AppHandle, id: String) -> Result<(), String> {
+pub fn resume_binding(app: AppHandle, id: String) -> Result<(), AppError> {
+    // Paused shortcuts are re-registered together when the pause ends
+    if shortcuts_paused() && id != TOGGLE_SHORTCUTS_BINDING {
+        return Ok(());
+    }
+
     // Also resume raw bindings on macOS
     #[cfg(target_os = "macos")]
     key_listener::resume_raw_binding(&id);
@@ -1478,12 +2788,80 @@ pub fn resume_binding(app: AppHandle, id: String) -> Result<(), String> {
     if let Some(b) = settings::get_bindings(&app).get(&id).cloned() {
         if let Err(e) = register_shortcut(&app, b) {
             error!("resume_binding error for id '{}': {}", id, e);
-            return Err(e);
+            return Err(e.into());
         }
     }
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn get_shortcuts_pause_state() -> ShortcutsPauseState {
+    shortcuts_pause_state()
+}
+
+/// Pause or resume all shortcuts. `minutes` overrides the configured pause duration.
+#[tauri::command]
+#[specta::specta]
+pub fn set_shortcuts_paused(
+    app: AppHandle,
+    paused: bool,
+    minutes: Option<u32>,
+) -> Result<ShortcutsPauseState, AppError> {
+    if paused {
+        pause_all_shortcuts(&app, minutes);
+    } else {
+        resume_all_shortcuts(&app);
+    }
+    Ok(shortcuts_pause_state())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_shortcuts_pause_minutes_setting(
+    app: AppHandle,
+    minutes: u32,
+) -> Result<(), AppError> {
+    let mut settings = settings::get_settings(&app);
+    settings.shortcuts_pause_minutes = minutes;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Recent shortcut events and how each was resolved, oldest first
+#[tauri::command]
+#[specta::specta]
+pub fn get_shortcut_event_log() -> Vec<shortcut_log::ShortcutEvent> {
+    shortcut_log::events()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn clear_shortcut_event_log() {
+    shortcut_log::clear();
+}
+
+/// Parse a shortcut written with the user's key labels, normalizing numpad/media key
+/// names and translating character keys to their physical position for the active
+/// keyboard layout.
+fn parse_layout_shortcut(raw: &str) -> Result<Shortcut, String> {
+    let normalized = raw
+        .split('+')
+        .map(normalize_key_name)
+        .collect::<Result<Vec<_>, _>>()?
+        .join("+");
+    let physical =
+        keyboard_layout::to_physical_shortcut(&normalized, keyboard_layout::active_layout());
+    if physical != raw {
+        debug!(
+            "Translated shortcut '{}' to physical keys '{}'",
+            raw, physical
+        );
+    }
+    physical.parse::<Shortcut>().map_err(|e| e.to_string())
+}
+
 pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
     // Validate human-level rules first
     if let Err(e) = validate_shortcut_string(&binding.current_binding) {
@@ -1501,7 +2879,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
     }
 
     // Parse shortcut and return error if it fails
-    let shortcut = match binding.current_binding.parse::<Shortcut>() {
+    let shortcut = match parse_layout_shortcut(&binding.current_binding) {
         Ok(s) => s,
         Err(e) => {
             let error_msg = format!(
@@ -1546,16 +2924,39 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                     "[KEY] Shortcut event received: shortcut='{}' binding_id='{}' state={:?}",
                     shortcut_string, binding_id_for_closure, event.state
                 );
+                let log_event = |resolution: ShortcutEventResolution, detail: Option<&str>| {
+                    shortcut_log::record(
+                        ShortcutEventSource::GlobalShortcut,
+                        &binding_id_for_closure,
+                        &shortcut_string,
+                        event.state == ShortcutState::Pressed,
+                        resolution,
+                        detail,
+                    );
+                };
 
                 if let Some(action) = ACTION_MAP.get(&binding_id_for_closure) {
                     if binding_id_for_closure == "cancel" {
                         if event.state == ShortcutState::Pressed {
                             debug!("[KEY] Cancel shortcut activated");
+                            log_event(ShortcutEventResolution::Matched, Some("cancel"));
                             action.start(ah, &binding_id_for_closure, &shortcut_string);
                         }
                         return;
                     }
 
+                    // Instant actions fire once on press and have no tap/hold handling
+                    if action.interaction_behavior() == InteractionBehavior::Instant {
+                        if event.state == ShortcutState::Pressed {
+                            if action.start(ah, &binding_id_for_closure, &shortcut_string) {
+                                log_event(ShortcutEventResolution::Matched, None);
+                            } else {
+                                log_event(ShortcutEventResolution::StartFailed, None);
+                            }
+                        }
+                        return;
+                    }
+
                     // Smart tap/hold detection for all other bindings
                     match event.state {
                         ShortcutState::Pressed => {
@@ -1587,11 +2988,21 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                 if *is_active {
                                     // Already recording - this is a toggle-off tap
                                     *is_active = false;
+                                    drop(states);
+                                    if is_double_press_cancel(ah, &binding_id_for_closure) {
+                                        debug!(
+                                            "[TOGGLE] Shortcut {} double-pressed, cancelling recording",
+                                            shortcut_string
+                                        );
+                                        log_event(ShortcutEventResolution::Matched, Some("double-press cancel"));
+                                        crate::utils::cancel_current_operation(ah);
+                                        return;
+                                    }
                                     debug!(
                                         "[TOGGLE] Shortcut {} toggle stop (tap while active)",
                                         shortcut_string
                                     );
-                                    drop(states);
+                                    log_event(ShortcutEventResolution::Matched, Some("toggle stop"));
                                     action.stop(ah, &binding_id_for_closure, &shortcut_string);
                                     return;
                                 }
@@ -1609,6 +3020,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
 
                             // If start failed, reset the toggle state
                             if !started {
+                                log_event(ShortcutEventResolution::StartFailed, None);
                                 debug!(
                                     "[TOGGLE] action.start() returned false, resetting active_toggles['{}'] = false",
                                     binding_id_for_closure
@@ -1618,6 +3030,8 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                     states.active_toggles.insert(binding_id_for_closure.clone(), false);
                                 };
                             } else {
+                                log_event(ShortcutEventResolution::Matched, Some("start"));
+                                note_recording_started(&binding_id_for_closure);
                                 // Successfully started recording - spawn a timer to emit "hold" mode after threshold
                                 // This allows the "Raw" label to appear while user is still holding
                                 let settings = get_settings(ah);
@@ -1644,8 +3058,13 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
 
                                         if is_still_physically_pressed && is_still_active {
                                             // User has been holding for threshold ms - this is "hold" mode
-                                            debug!("[TOGGLE] Threshold passed while still holding - emitting hold mode");
-                                            overlay::emit_mode_determined(&ah_clone, "hold");
+                                            if binding_refines(&ah_clone, &binding_id_clone, true) {
+                                                debug!("[TOGGLE] Threshold passed while still holding - binding refines holds");
+                                                enter_refining_mode(&ah_clone, false);
+                                            } else {
+                                                debug!("[TOGGLE] Threshold passed while still holding - emitting hold mode");
+                                                overlay::emit_mode_determined(&ah_clone, RecordingMode::Hold);
+                                            }
                                         }
                                     });
                             }
@@ -1666,14 +3085,14 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
 
                             // Get threshold from settings
                             let settings = get_settings(ah);
-                            let threshold = settings.hold_threshold_ms as u128;
+                            let threshold = settings.hold_threshold_ms;
 
                             debug!(
                                 "[TOGGLE] hold_duration={}ms threshold={}ms",
                                 hold_duration_ms, threshold
                             );
 
-                            if hold_duration_ms >= threshold {
+                            if is_hold(hold_duration_ms, threshold) {
                                 // Long hold - PTT behavior, stop immediately
                                 let toggle_state_manager = ah.state::<ManagedToggleState>();
                                 {
@@ -1691,9 +3110,15 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                     shortcut_string, hold_duration_ms
                                 );
 
-                                // Emit hold mode so UI can show "Raw" briefly before transitioning
-                                overlay::emit_mode_determined(ah, "hold");
+                                if binding_refines(ah, &binding_id_for_closure, true) {
+                                    // Also covers a release right at the threshold, before the timer ran
+                                    enter_refining_mode(ah, false);
+                                } else {
+                                    // Emit hold mode so UI can show "Raw" briefly before transitioning
+                                    overlay::emit_mode_determined(ah, RecordingMode::Hold);
+                                }
 
+                                log_event(ShortcutEventResolution::Matched, Some("push-to-talk stop"));
                                 action.stop(ah, &binding_id_for_closure, &shortcut_string);
                             } else {
                                 // Quick tap - toggle mode = COHERENT mode in unified UX
@@ -1712,27 +3137,16 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                     shortcut_string, hold_duration_ms, is_still_active
                                 );
 
-                                if is_still_active {
+                                if !is_still_active {
+                                    log_event(ShortcutEventResolution::Suppressed, Some("released while not recording"));
+                                } else if binding_refines(ah, &binding_id_for_closure, false) {
+                                    log_event(ShortcutEventResolution::Matched, Some("tap, refining mode"));
                                     // Quick press = coherent mode (unified hotkey UX)
-                                    let audio_manager = ah.state::<Arc<AudioRecordingManager>>();
-                                    audio_manager.set_coherent_mode(true);
-
-                                    // Emit refining mode and update overlay SYNCHRONOUSLY
-                                    // Ensure the state becomes 'ramble_recording' so UI shows 'Refined' label
-                                    crate::utils::show_ramble_recording_overlay(ah);
-                                    overlay::emit_mode_determined(ah, "refining");
-
-                                    // Spawn async ONLY for clipboard copy
-                                    let ah_clone = ah.clone();
-                                    let audio_manager_clone = Arc::clone(&audio_manager);
-                                    // Run on main thread to prevent crash on macOS (TSM/Enigo requirements)
-                                    let _ = ah.run_on_main_thread(move || {
-                                        // Capture selection context for coherent processing
-                                        if let Ok(Some(text)) = crate::clipboard::get_selected_text(&ah_clone) {
-                                            debug!("Captured selection context: {} chars", text.len());
-                                            audio_manager_clone.set_selection_context(text);
-                                        }
-                                    });
+                                    enter_refining_mode(ah, true);
+                                } else {
+                                    // This binding keeps taps raw - the recording toggles on as is
+                                    log_event(ShortcutEventResolution::Matched, Some("tap, raw mode"));
+                                    overlay::emit_mode_determined(ah, RecordingMode::Hold);
                                 }
                             }
                         }
@@ -1744,6 +3158,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
 
                     if !is_active && binding_id_for_closure != "cancel" {
                         debug!("[KEY] Ignoring contextual shortcut '{}' - not recording or paused", binding_id_for_closure);
+                        log_event(ShortcutEventResolution::Suppressed, Some("not recording or paused"));
                         return;
                     }
 
@@ -1751,6 +3166,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                         "pause_toggle" => {
                             if event.state == ShortcutState::Pressed {
                                 debug!("[KEY] Pause toggle shortcut activated");
+                                log_event(ShortcutEventResolution::Matched, Some("pause"));
                                 let app_handle = ah.clone();
                                 tauri::async_runtime::spawn(async move {
                                     crate::commands::pause_operation(app_handle);
@@ -1760,6 +3176,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                         "vision_capture" => {
                             if event.state == ShortcutState::Pressed {
                                 debug!("[KEY] Vision capture shortcut activated");
+                                log_event(ShortcutEventResolution::Matched, Some("vision capture"));
                                 let app_handle = ah.clone();
                                 tauri::async_runtime::spawn(async move {
                                     match crate::vision::capture_screen() {
@@ -1767,7 +3184,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                             let audio_manager = app_handle.state::<Arc<AudioRecordingManager>>();
                                             audio_manager.add_vision_context(base64);
                                             // Pulse the overlay to show feedback
-                                            let _ = app_handle.emit("vision-captured", ());
+                                            let _ = VisionCaptured.emit(&app_handle);
                                         }
                                         Err(e) => {
                                             error!("Vision capture failed: {}", e);
@@ -1781,6 +3198,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                                 "No action defined in ACTION_MAP for shortcut ID '{}'. Shortcut: '{}', State: {:?}",
                                 binding_id_for_closure, shortcut_string, event.state
                             );
+                            log_event(ShortcutEventResolution::Suppressed, Some("no action defined"));
                         }
                     }
                 }
@@ -1802,7 +3220,7 @@ pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<
         return key_listener::unregister_raw_binding(&binding.current_binding);
     }
 
-    let shortcut = match binding.current_binding.parse::<Shortcut>() {
+    let shortcut = match parse_layout_shortcut(&binding.current_binding) {
         Ok(s) => s,
         Err(e) => {
             let error_msg = format!(
@@ -1863,3 +3281,28 @@ fn register_swallowing_shortcuts(app: &AppHandle, binding: ShortcutBinding) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::TapHoldMode;
+
+    #[test]
+    fn test_is_hold() {
+        assert!(!is_hold(0, 300));
+        assert!(!is_hold(299, 300));
+        assert!(is_hold(300, 300));
+        assert!(is_hold(2_000, 300));
+        // With no threshold every press is a hold
+        assert!(is_hold(0, 0));
+    }
+
+    #[test]
+    fn test_tap_and_hold_refine_per_mode() {
+        let refines = |mode: TapHoldMode, held_ms: u128| mode.refines(is_hold(held_ms, 300));
+        assert!(refines(TapHoldMode::TapRefines, 120));
+        assert!(!refines(TapHoldMode::TapRefines, 800));
+        assert!(!refines(TapHoldMode::HoldRefines, 120));
+        assert!(refines(TapHoldMode::HoldRefines, 800));
+    }
+}
@@ -0,0 +1,129 @@
+//! Activity log of shortcut events for diagnosing bindings that "did nothing".
+//!
+//! Every event from a registered global shortcut or raw-key binding is recorded with how
+//! it was resolved. Only keys that match a registered binding are logged, never arbitrary
+//! typing. The log is an in-memory ring buffer and is cleared on restart.
+
+use serde::Serialize;
+use specta::Type;
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+/// Number of events kept before the oldest are dropped
+const MAX_EVENTS: usize = 200;
+
+static EVENT_LOG: OnceLock<Mutex<VecDeque<ShortcutEvent>>> = OnceLock::new();
+
+fn get_event_log() -> &'static Mutex<VecDeque<ShortcutEvent>> {
+    EVENT_LOG.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_EVENTS)))
+}
+
+/// Where a shortcut event came from
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutEventSource {
+    /// Registered through the global shortcut plugin
+    GlobalShortcut,
+    /// Matched by the low-level key listener (raw modifier bindings)
+    RawKey,
+}
+
+/// What happened to a shortcut event
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutEventResolution {
+    /// The binding's action was triggered
+    Matched,
+    /// The binding matched but was ignored in the current state
+    Suppressed,
+    /// A repeated press of a key that is already held down
+    DuplicateFiltered,
+    /// The binding's action refused to start (e.g. microphone unavailable)
+    StartFailed,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct ShortcutEvent {
+    /// Unix timestamp in milliseconds
+    pub timestamp: i64,
+    pub source: ShortcutEventSource,
+    pub binding_id: String,
+    pub shortcut: String,
+    /// True for key press, false for key release
+    pub pressed: bool,
+    pub resolution: ShortcutEventResolution,
+    /// Why the event was resolved this way, e.g. "not recording"
+    pub detail: Option<String>,
+}
+
+fn push_event(log: &mut VecDeque<ShortcutEvent>, event: ShortcutEvent) {
+    while log.len() >= MAX_EVENTS {
+        log.pop_front();
+    }
+    log.push_back(event);
+}
+
+/// Record a shortcut event and its resolution
+pub fn record(
+    source: ShortcutEventSource,
+    binding_id: &str,
+    shortcut: &str,
+    pressed: bool,
+    resolution: ShortcutEventResolution,
+    detail: Option<&str>,
+) {
+    let event = ShortcutEvent {
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        source,
+        binding_id: binding_id.to_string(),
+        shortcut: shortcut.to_string(),
+        pressed,
+        resolution,
+        detail: detail.map(String::from),
+    };
+    if let Ok(mut log) = get_event_log().lock() {
+        push_event(&mut log, event);
+    }
+}
+
+/// Recorded events, oldest first
+pub fn events() -> Vec<ShortcutEvent> {
+    get_event_log()
+        .lock()
+        .map(|log| log.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn clear() {
+    if let Ok(mut log) = get_event_log().lock() {
+        log.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(binding_id: &str) -> ShortcutEvent {
+        ShortcutEvent {
+            timestamp: 0,
+            source: ShortcutEventSource::GlobalShortcut,
+            binding_id: binding_id.to_string(),
+            shortcut: "ctrl+space".to_string(),
+            pressed: true,
+            resolution: ShortcutEventResolution::Matched,
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut log = VecDeque::new();
+        for i in 0..MAX_EVENTS + 5 {
+            push_event(&mut log, event(&i.to_string()));
+        }
+        assert_eq!(log.len(), MAX_EVENTS);
+        assert_eq!(log.front().unwrap().binding_id, "5");
+        assert_eq!(log.back().unwrap().binding_id, (MAX_EVENTS + 4).to_string());
+    }
+}
@@ -0,0 +1,211 @@
+//! Summaries of long history entries, such as meetings and lectures transcribed from
+//! an audio file.
+//!
+//! A transcript longer than one request comfortably holds is first condensed into
+//! notes chunk by chunk, and the notes are then summarized in the chosen style. The
+//! summary is stored with the entry so it survives a restart.
+
+use crate::actions::{extract_llm_error, resolve_llm_config, ResolvedLLMConfig};
+use crate::errors::AppError;
+use crate::llm_client::TokenUsage;
+use crate::managers::usage::record_usage;
+use crate::retry::with_retry;
+use crate::settings::get_settings;
+use async_openai::config::OpenAIConfig;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
+    CreateChatCompletionRequestArgs,
+};
+use async_openai::Client;
+use log::info;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Longest stretch of transcript sent in one request, in characters (roughly 6k tokens)
+const CHUNK_CHARS: usize = 24_000;
+
+/// What kind of summary to write
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStyle {
+    /// The main points as a bulleted list
+    Bullets,
+    /// Tasks, owners and deadlines that were agreed on
+    ActionItems,
+    /// Two or three sentences
+    Tldr,
+}
+
+impl SummaryStyle {
+    fn instructions(self) -> &'static str {
+        match self {
+            SummaryStyle::Bullets => {
+                "Summarize the transcript as a bulleted list of its main points, in the order they came up. Use \"- \" for bullets and keep each to one or two sentences."
+            }
+            SummaryStyle::ActionItems => {
+                "List the action items from the transcript: tasks someone agreed or was asked to do, with the owner and deadline when they were mentioned. Use \"- [ ] \" for each item. If there are none, say \"No action items.\""
+            }
+            SummaryStyle::Tldr => {
+                "Write a TL;DR of the transcript in two or three plain sentences."
+            }
+        }
+    }
+}
+
+/// A summary stored alongside a history entry
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct EntrySummary {
+    pub style: SummaryStyle,
+    pub text: String,
+    /// Model identifier the summary was written by
+    pub model: String,
+    /// Unix timestamp of when the summary was written
+    pub created_at: i64,
+}
+
+/// Split `text` into pieces of at most `max_chars`, preferring to break after a
+/// sentence and otherwise between words
+pub fn split_into_chunks(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text.trim();
+
+    while rest.chars().count() > max_chars {
+        let limit = rest
+            .char_indices()
+            .nth(max_chars)
+            .map_or(rest.len(), |(i, _)| i);
+        let window = &rest[..limit];
+        let cut = window
+            .rfind(['.', '!', '?'])
+            .map(|i| i + 1)
+            .filter(|&i| i > limit / 2)
+            .or_else(|| window.rfind(char::is_whitespace).filter(|&i| i > 0))
+            .unwrap_or(limit);
+        chunks.push(rest[..cut].trim().to_string());
+        rest = rest[cut..].trim_start();
+    }
+    if !rest.is_empty() {
+        chunks.push(rest.to_string());
+    }
+    chunks
+}
+
+/// Send a single-message request, recording its usage under `source`
+pub(crate) async fn complete(
+    app: &AppHandle,
+    client: &Client<OpenAIConfig>,
+    config: &ResolvedLLMConfig,
+    prompt: String,
+    source: &str,
+) -> Result<String, AppError> {
+    let model = &config.model.model_id;
+    let message = ChatCompletionRequestUserMessageArgs::default()
+        .content(prompt)
+        .build()
+        .map_err(|e| format!("Request error: {}", e))?;
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(model)
+        .messages(vec![ChatCompletionRequestMessage::User(message)])
+        .build()
+        .map_err(|e| format!("Request error: {}", e))?;
+
+    let chat = client.chat();
+    let response = with_retry(format!("{} request to '{}'", source, model), || {
+        chat.create(request.clone())
+    })
+    .await
+    .map_err(|e| extract_llm_error(&e, model))?;
+    if let Some(usage) = response.usage.as_ref().map(TokenUsage::from) {
+        record_usage(app, &config.provider, &config.model, source, &usage);
+    }
+
+    response
+        .choices
+        .first()
+        .and_then(|c| c.message.content.clone())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .ok_or_else(|| AppError::internal("No response from AI"))
+}
+
+/// Summarize a transcript in `style` with the default refinement model
+pub async fn summarize(
+    app: &AppHandle,
+    transcript: &str,
+    style: SummaryStyle,
+) -> Result<EntrySummary, AppError> {
+    if transcript.trim().is_empty() {
+        return Err(AppError::invalid_input(
+            "There's no transcription to summarize",
+        ));
+    }
+
+    let settings = get_settings(app);
+    let model_id = settings.default_coherent_model_id.clone().ok_or_else(|| {
+        AppError::not_configured("No refinement model is configured")
+            .with_hint("Choose a default refinement model in the Ramble to Coherent settings.")
+    })?;
+    let config = resolve_llm_config(&settings, &model_id).await?;
+    let client = crate::llm_client::create_client(&config.provider, config.api_key.clone())?;
+
+    let chunks = split_into_chunks(transcript, CHUNK_CHARS);
+    info!(
+        "Summarizing {} characters in {} chunk(s) as {:?}",
+        transcript.len(),
+        chunks.len(),
+        style
+    );
+
+    let source = if chunks.len() == 1 {
+        transcript.trim().to_string()
+    } else {
+        let mut notes = Vec::with_capacity(chunks.len());
+        for (i, chunk) in chunks.iter().enumerate() {
+            let prompt = format!(
+                "This is part {} of {} of a long transcript. Write detailed notes on it: every topic, decision, task, name and number, in order. Return only the notes.\n\n<transcript>\n{}\n</transcript>",
+                i + 1,
+                chunks.len(),
+                chunk
+            );
+            notes.push(complete(app, &client, &config, prompt, "summary").await?);
+        }
+        notes.join("\n\n")
+    };
+
+    let prompt = format!(
+        "{} Use the transcript's language. Return only the summary.\n\n<transcript>\n{}\n</transcript>",
+        style.instructions(),
+        source
+    );
+    let text = complete(app, &client, &config, prompt, "summary").await?;
+
+    Ok(EntrySummary {
+        style,
+        text,
+        model: config.model.model_id.clone(),
+        created_at: chrono::Utc::now().timestamp(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_chunks() {
+        assert!(split_into_chunks("  ", 10).is_empty());
+        assert_eq!(split_into_chunks("Short.", 10), vec!["Short."]);
+
+        // Breaks after the last sentence that fits
+        let chunks = split_into_chunks("One two. Three four. Five six seven.", 22);
+        assert_eq!(chunks, vec!["One two. Three four.", "Five six seven."]);
+
+        // Without a sentence end, breaks between words
+        let chunks = split_into_chunks("alpha beta gamma delta", 12);
+        assert_eq!(chunks, vec!["alpha beta", "gamma delta"]);
+
+        // A single word longer than a chunk is cut
+        assert_eq!(split_into_chunks("abcdefgh", 5), vec!["abcde", "fgh"]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
+use crate::managers::model::ModelManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::managers::usage::UsageManager;
+use crate::settings::{self, AppSettings, OverlayPosition, PromptMode, TerminalPasteMode};
+use crate::ShortcutToggleStates;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{App, AppHandle, Manager};
+
+/// The app with the managers a dictation goes through, and no windows or tray.
+/// Its data lives in a directory of its own, removed when it's dropped.
+pub struct TestApp {
+    app: App,
+    data_dir: PathBuf,
+}
+
+impl TestApp {
+    /// Build the app with `settings`, changed so nothing is shown or played, the
+    /// microphone is only opened on demand (which fixture audio skips) and the result
+    /// doesn't depend on the focused app
+    pub fn new(mut settings: AppSettings) -> Self {
+        settings.always_on_microphone = false;
+        settings.audio_feedback = false;
+        settings.overlay_position = OverlayPosition::None;
+        settings.accessibility_announcements = false;
+        settings.prompt_mode = PromptMode::Medium;
+        settings.paste_blocked_apps.clear();
+        settings.terminal_paste_mode = TerminalPasteMode::Off;
+        settings.append_trailing_space = false;
+
+        let mut context = tauri::generate_context!();
+        let config = context.config_mut();
+        // A separate identifier keeps the user's settings and history out of reach
+        config.identifier = format!("{}.test-{}", config.identifier, std::process::id());
+        config.app.windows.clear();
+
+        let app = tauri::Builder::default()
+            .any_thread()
+            .plugin(tauri_plugin_store::Builder::default().build())
+            .plugin(tauri_plugin_clipboard_manager::init())
+            .manage(Mutex::new(ShortcutToggleStates::default()))
+            .build(context)
+            .expect("Failed to build the test app");
+        let handle = app.handle().clone();
+        let data_dir = handle
+            .path()
+            .app_data_dir()
+            .expect("Test app has no data directory");
+        settings::write_settings(&handle, settings);
+
+        let model_manager =
+            Arc::new(ModelManager::new(&handle).expect("Failed to create model manager"));
+        let transcription_manager = Arc::new(
+            TranscriptionManager::new(&handle, model_manager.clone())
+                .expect("Failed to create transcription manager"),
+        );
+        handle.manage(Arc::new(
+            AudioRecordingManager::new(&handle).expect("Failed to create recording manager"),
+        ));
+        handle.manage(model_manager);
+        handle.manage(transcription_manager);
+        handle.manage(Arc::new(
+            HistoryManager::new(&handle).expect("Failed to create history manager"),
+        ));
+        handle.manage(Arc::new(
+            UsageManager::new(&handle).expect("Failed to create usage manager"),
+        ));
+
+        Self { app, data_dir }
+    }
+
+    pub fn handle(&self) -> &AppHandle {
+        self.app.handle()
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::audio_toolkit::decode_audio_file;
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Samples the next recording returns in place of the microphone's
+static QUEUED_AUDIO: Mutex<Option<Vec<f32>>> = Mutex::new(None);
+
+/// Text the next transcription returns in place of running the model
+static QUEUED_TRANSCRIPT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Text handed to the paste step while a test captures pastes, in place of typing it
+static PASTED: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// A recorded phrase and what it says
+pub struct AudioFixture {
+    /// 16 kHz mono samples, as the recorder produces them
+    pub samples: Vec<f32>,
+    /// The words spoken, used in place of a transcription
+    pub transcript: String,
+}
+
+impl AudioFixture {
+    /// Load `tests/fixtures/<name>.wav` and its transcript, `<name>.txt`
+    pub fn load(name: &str) -> Result<Self> {
+        let dir = fixtures_dir();
+        // Decoded the way imported recordings are, so any rate or channel count works
+        let samples =
+            decode_audio_file(&dir.join(format!("{}.wav", name))).map_err(|e| anyhow!(e))?;
+        let transcript_path = dir.join(format!("{}.txt", name));
+        let transcript = std::fs::read_to_string(&transcript_path)
+            .with_context(|| format!("Failed to read {}", transcript_path.display()))?;
+        Ok(Self {
+            samples,
+            transcript: transcript.trim().to_string(),
+        })
+    }
+
+    /// Have the next recording return this audio and its transcription return the
+    /// transcript
+    pub fn queue(&self) {
+        queue_fixture_audio(self.samples.clone());
+        queue_fixture_transcript(&self.transcript);
+    }
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// Have the next recording return these samples without opening the microphone
+pub fn queue_fixture_audio(samples: Vec<f32>) {
+    *QUEUED_AUDIO.lock().unwrap() = Some(samples);
+}
+
+/// Have the next transcription return this text without loading a model
+pub fn queue_fixture_transcript(text: &str) {
+    *QUEUED_TRANSCRIPT.lock().unwrap() = Some(text.to_string());
+}
+
+/// Record pasted text instead of typing it, until [`take_pasted`] is called
+pub fn capture_pastes() {
+    *PASTED.lock().unwrap() = Some(Vec::new());
+}
+
+/// Text pasted since [`capture_pastes`], oldest first
+pub fn pasted() -> Vec<String> {
+    PASTED.lock().unwrap().clone().unwrap_or_default()
+}
+
+/// Stop capturing, returning what was pasted. Pastes are typed again after this.
+pub fn take_pasted() -> Vec<String> {
+    PASTED.lock().unwrap().take().unwrap_or_default()
+}
+
+pub(crate) fn fixture_audio_queued() -> bool {
+    QUEUED_AUDIO.lock().unwrap().is_some()
+}
+
+pub(crate) fn take_fixture_audio() -> Option<Vec<f32>> {
+    QUEUED_AUDIO.lock().unwrap().take()
+}
+
+pub(crate) fn take_fixture_transcript() -> Option<String> {
+    QUEUED_TRANSCRIPT.lock().unwrap().take()
+}
+
+/// Record `text` if a test is capturing pastes, returning whether it was captured
+pub(crate) fn capture_paste(text: &str) -> bool {
+    match PASTED.lock().unwrap().as_mut() {
+        Some(pasted) => {
+            pasted.push(text.to_string());
+            true
+        }
+        None => false,
+    }
+}
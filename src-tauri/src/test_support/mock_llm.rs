@@ -0,0 +1,205 @@
+use crate::settings::{get_default_settings, AppSettings, AuthMethod, LLMModel, LLMProvider};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use tiny_http::{Header, Request, Response, Server};
+
+/// Model id the mock provider lists and answers as
+pub const MOCK_MODEL_ID: &str = "mock-model";
+const MOCK_PROVIDER_ID: &str = "mock";
+
+/// An OpenAI-compatible chat completions server on localhost that answers every
+/// request with the same reply. Stops when dropped.
+pub struct MockLlmServer {
+    server: Arc<Server>,
+    base_url: String,
+    requests: Arc<Mutex<Vec<Value>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockLlmServer {
+    /// Start the server on a free port, answering with `reply`
+    pub fn start(reply: &str) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("Failed to start mock LLM"));
+        let port = server
+            .server_addr()
+            .to_ip()
+            .expect("Mock LLM isn't listening on an IP address")
+            .port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let thread = {
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+            let reply = reply.to_string();
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(request, &reply, &requests);
+                }
+            })
+        };
+
+        Self {
+            server,
+            base_url: format!("http://127.0.0.1:{}/v1", port),
+            requests,
+            thread: Some(thread),
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Bodies of the chat completion requests received so far
+    pub fn requests(&self) -> Vec<Value> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A custom provider pointed at this server
+    pub fn provider(&self) -> LLMProvider {
+        LLMProvider {
+            id: MOCK_PROVIDER_ID.to_string(),
+            name: "Mock".to_string(),
+            base_url: self.base_url.clone(),
+            api_key: "test-key".to_string(),
+            supports_vision: false,
+            is_custom: true,
+            auth_method: AuthMethod::default(),
+            supports_oauth: false,
+            credentials: Vec::new(),
+            active_credential_id: None,
+            custom_headers: HashMap::new(),
+            free: false,
+        }
+    }
+
+    /// The mock provider's one model
+    pub fn model(&self) -> LLMModel {
+        LLMModel {
+            id: MOCK_MODEL_ID.to_string(),
+            provider_id: MOCK_PROVIDER_ID.to_string(),
+            model_id: MOCK_MODEL_ID.to_string(),
+            display_name: "Mock Model".to_string(),
+            supports_vision: false,
+            enabled: true,
+            input_price_per_million: None,
+            output_price_per_million: None,
+            openrouter_routing: None,
+            gemini_options: None,
+            reasoning: None,
+        }
+    }
+
+    /// Default settings with the mock provider and model added and used for refinement
+    pub fn settings(&self) -> AppSettings {
+        let mut settings = get_default_settings();
+        settings.llm_providers.push(self.provider());
+        settings.llm_models.push(self.model());
+        settings.default_coherent_model_id = Some(MOCK_MODEL_ID.to_string());
+        settings.coherent_fallback_model_ids.clear();
+        settings
+    }
+}
+
+impl Drop for MockLlmServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn handle(mut request: Request, reply: &str, requests: &Mutex<Vec<Value>>) {
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+    let path = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    let response = if path.ends_with("/chat/completions") {
+        let body: Value = serde_json::from_str(&body).unwrap_or(Value::Null);
+        let stream = body["stream"].as_bool().unwrap_or(false);
+        let model = body["model"].as_str().unwrap_or(MOCK_MODEL_ID).to_string();
+        requests.lock().unwrap().push(body);
+        if stream {
+            text_response("text/event-stream", stream_body(&model, reply))
+        } else {
+            json_response(completion(&model, reply))
+        }
+    } else if path.ends_with("/models") {
+        json_response(json!({
+            "object": "list",
+            "data": [{ "id": MOCK_MODEL_ID, "object": "model", "created": 0, "owned_by": "mock" }],
+        }))
+    } else {
+        text_response("text/plain", "Not found".to_string()).with_status_code(404)
+    };
+    let _ = request.respond(response);
+}
+
+fn usage(reply: &str) -> Value {
+    let completion_tokens = reply.split_whitespace().count();
+    json!({
+        "prompt_tokens": 10,
+        "completion_tokens": completion_tokens,
+        "total_tokens": 10 + completion_tokens,
+    })
+}
+
+fn completion(model: &str, reply: &str) -> Value {
+    json!({
+        "id": "mock-completion",
+        "object": "chat.completion",
+        "created": 0,
+        "model": model,
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": reply },
+            "finish_reason": "stop",
+        }],
+        "usage": usage(reply),
+    })
+}
+
+/// The reply as server-sent events, a word per chunk, then a usage chunk and `[DONE]`
+fn stream_body(model: &str, reply: &str) -> String {
+    let chunk = |choices: Value, usage: Value| {
+        let chunk = json!({
+            "id": "mock-completion",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": model,
+            "choices": choices,
+            "usage": usage,
+        });
+        format!("data: {}\n\n", chunk)
+    };
+
+    let mut body = String::new();
+    for word in reply.split_inclusive(' ') {
+        body.push_str(&chunk(
+            json!([{ "index": 0, "delta": { "content": word }, "finish_reason": null }]),
+            Value::Null,
+        ));
+    }
+    body.push_str(&chunk(json!([]), usage(reply)));
+    body.push_str("data: [DONE]\n\n");
+    body
+}
+
+fn json_response(body: Value) -> Response<Cursor<Vec<u8>>> {
+    text_response("application/json", body.to_string())
+}
+
+fn text_response(content_type: &str, body: String) -> Response<Cursor<Vec<u8>>> {
+    let header = Header::from_bytes(&b"Content-Type"[..], content_type.as_bytes())
+        .expect("Invalid content type header");
+    Response::from_string(body).with_header(header)
+}
@@ -0,0 +1,34 @@
+//! Test doubles for running the dictation pipeline without devices or API keys.
+//!
+//! Built with the `test-support` feature, and always for this crate's own tests:
+//!
+//! - [`MockLlmServer`] is an OpenAI-compatible server on localhost that answers every
+//!   chat completion with a canned reply, streamed the way the real APIs stream it.
+//!   Settings from [`MockLlmServer::settings`] point refinement at it, so requests go
+//!   through `llm_client` unchanged.
+//! - [`AudioFixture`] loads a WAV file from `tests/fixtures` along with the text it
+//!   says. [`AudioFixture::queue`] hands the samples to `AudioRecordingManager`, whose
+//!   next recording returns them instead of opening the microphone, and the text to
+//!   `TranscriptionManager`, which returns it instead of running a model.
+//! - [`capture_pastes`] makes `clipboard::paste` record the text it was given rather
+//!   than type it, read back with [`pasted`].
+//! - [`TestApp`] is the app with the managers a dictation goes through, so actions
+//!   can be run from start to stop. Linux and Windows only, where an app can be built
+//!   off the main thread.
+
+#[cfg(any(target_os = "linux", windows))]
+mod app;
+mod fixtures;
+mod mock_llm;
+
+#[cfg(any(target_os = "linux", windows))]
+pub use app::TestApp;
+pub use fixtures::{
+    capture_pastes, pasted, queue_fixture_audio, queue_fixture_transcript, take_pasted,
+    AudioFixture,
+};
+pub use mock_llm::{MockLlmServer, MOCK_MODEL_ID};
+
+pub(crate) use fixtures::{
+    capture_paste, fixture_audio_queued, take_fixture_audio, take_fixture_transcript,
+};
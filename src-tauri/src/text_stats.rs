@@ -0,0 +1,78 @@
+//! Size statistics of a transcription and its refinement, so users can see how much
+//! the refiner compresses or expands their speech.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Average adult silent reading speed
+const READING_WORDS_PER_MINUTE: f32 = 238.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct TextStats {
+    /// Words in the final text (the refinement if there is one)
+    pub word_count: usize,
+    /// Estimated time to read the final text, in seconds
+    pub reading_time_seconds: u32,
+    /// Words in the raw transcription
+    pub raw_word_count: usize,
+    pub raw_chars: usize,
+    /// Characters in the refinement, None if the text wasn't refined
+    pub refined_chars: Option<usize>,
+    /// How much longer (positive) or shorter (negative) the refinement is than the
+    /// raw transcription, in percent of its characters
+    pub size_delta_percent: Option<i32>,
+}
+
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+impl TextStats {
+    pub fn compute(raw: &str, refined: Option<&str>) -> Self {
+        let refined = refined.filter(|r| !r.trim().is_empty());
+        let final_text = refined.unwrap_or(raw);
+        let word_count = count_words(final_text);
+        let raw_chars = raw.chars().count();
+        let refined_chars = refined.map(|r| r.chars().count());
+
+        Self {
+            word_count,
+            reading_time_seconds: (word_count as f32 / READING_WORDS_PER_MINUTE * 60.0).ceil()
+                as u32,
+            raw_word_count: count_words(raw),
+            raw_chars,
+            refined_chars,
+            size_delta_percent: refined_chars.filter(|_| raw_chars > 0).map(|refined| {
+                ((refined as f32 - raw_chars as f32) / raw_chars as f32 * 100.0).round() as i32
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_only() {
+        let stats = TextStats::compute("one two three", None);
+        assert_eq!(stats.word_count, 3);
+        assert_eq!(stats.raw_word_count, 3);
+        assert_eq!(stats.reading_time_seconds, 1);
+        assert_eq!(stats.refined_chars, None);
+        assert_eq!(stats.size_delta_percent, None);
+    }
+
+    #[test]
+    fn test_refined_delta() {
+        let stats = TextStats::compute("um so basically the plan is done", Some("Plan done."));
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.raw_word_count, 7);
+        assert_eq!(stats.refined_chars, Some(10));
+        assert_eq!(stats.size_delta_percent, Some(-69));
+
+        // An empty refinement counts as no refinement
+        assert_eq!(TextStats::compute("", Some(" ")).size_delta_percent, None);
+        assert_eq!(TextStats::compute("", None).reading_time_seconds, 0);
+    }
+}
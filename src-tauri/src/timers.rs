@@ -0,0 +1,436 @@
+//! Countdown timers set by voice, e.g. "remind me in 20 minutes to check the oven".
+//!
+//! Timers are kept in `AppSettings::timers`, so they survive a restart, and mirrored
+//! in memory so the scheduler can check them every second without reading the
+//! settings. A notification is shown when one runs out, and the "list timers" and
+//! "cancel timer" voice commands answer with a notification too.
+
+use crate::settings::{self, VoiceTimer};
+use chrono::{Local, TimeZone, Utc};
+use log::{info, warn};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+/// How often running timers are checked
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest timer that can be set by voice
+const MAX_TIMER: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Running timers, soonest first
+static TIMERS: Mutex<Vec<VoiceTimer>> = Mutex::new(Vec::new());
+
+/// A timer asked for by voice
+#[derive(Debug, PartialEq)]
+pub struct TimerRequest {
+    pub duration: Duration,
+    /// What to be reminded of, if anything was said
+    pub label: Option<String>,
+}
+
+fn number_word(word: &str) -> Option<u64> {
+    const UNITS: &[&str] = &[
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: &[(&str, u64)] = &[
+        ("twenty", 20),
+        ("thirty", 30),
+        ("forty", 40),
+        ("fifty", 50),
+        ("sixty", 60),
+        ("seventy", 70),
+        ("eighty", 80),
+        ("ninety", 90),
+    ];
+    match word {
+        "a" | "an" => Some(1),
+        _ => UNITS
+            .iter()
+            .position(|u| *u == word)
+            .map(|n| n as u64)
+            .or_else(|| TENS.iter().find(|(t, _)| *t == word).map(|(_, n)| *n)),
+    }
+}
+
+/// A number at the start of `words`, and how many words it took, e.g. "25",
+/// "twenty five" or "twenty-five"
+fn parse_amount(words: &[String]) -> Option<(u64, usize)> {
+    let first = words.first()?;
+    if let Ok(n) = first.parse::<u64>() {
+        return Some((n, 1));
+    }
+    if let Some((tens, units)) = first.split_once('-') {
+        return Some((number_word(tens)? + number_word(units)?, 1));
+    }
+    let n = number_word(first)?;
+    // "twenty five"
+    if n >= 20 && n % 10 == 0 {
+        if let Some(units) = words.get(1).and_then(|w| number_word(w)) {
+            if (1..10).contains(&units) && !matches!(words[1].as_str(), "a" | "an") {
+                return Some((n + units, 2));
+            }
+        }
+    }
+    Some((n, 1))
+}
+
+fn unit_seconds(word: &str) -> Option<u64> {
+    match word {
+        "second" | "seconds" | "sec" | "secs" => Some(1),
+        "minute" | "minutes" | "min" | "mins" => Some(60),
+        "hour" | "hours" | "hr" | "hrs" => Some(60 * 60),
+        _ => None,
+    }
+}
+
+/// Whether `words` start with a duration: "half an hour", or a number and a unit
+fn starts_with_duration(words: &[String]) -> bool {
+    (words.len() >= 3 && words[..3] == ["half", "an", "hour"])
+        || parse_amount(words)
+            .is_some_and(|(_, used)| words.get(used).and_then(|w| unit_seconds(w)).is_some())
+}
+
+/// A duration at the start of `words`, like "1 hour and 20 minutes", "half an hour"
+/// or "an hour and a half", and how many words it took. None if there's no duration
+/// there, or it's too long to count in seconds.
+fn parse_duration(words: &[String]) -> Option<(u64, usize)> {
+    let starts_with = |i: usize, phrase: &[&str]| {
+        words.len() >= i + phrase.len() && phrase.iter().zip(&words[i..]).all(|(p, w)| p == w)
+    };
+
+    let mut total: u64 = 0;
+    let mut i = 0;
+    loop {
+        if starts_with(i, &["half", "an", "hour"]) {
+            total = total.checked_add(30 * 60)?;
+            i += 3;
+        } else {
+            let (amount, used) = parse_amount(&words[i..])?;
+            let unit = unit_seconds(words.get(i + used)?)?;
+            total = total.checked_add(amount.checked_mul(unit)?)?;
+            i += used + 1;
+            if starts_with(i, &["and", "a", "half"]) {
+                total = total.checked_add(unit / 2)?;
+                i += 3;
+            }
+        }
+
+        // "1 hour and 20 minutes" or "1 hour 20 minutes"
+        let next = if words.get(i).map(String::as_str) == Some("and") {
+            i + 1
+        } else {
+            i
+        };
+        if starts_with_duration(&words[next..]) {
+            i = next;
+        } else {
+            return Some((total, i));
+        }
+    }
+}
+
+fn label_from(words: &[&str]) -> Option<String> {
+    let words = match words.first().map(|w| w.to_lowercase()) {
+        Some(first) if matches!(first.as_str(), "to" | "for" | "that" | "about") => &words[1..],
+        _ => words,
+    };
+    let label = words
+        .join(" ")
+        .trim_end_matches(['.', ',', '!', '?'])
+        .to_string();
+    (!label.is_empty()).then_some(label)
+}
+
+/// Read a timer from what was said after the trigger phrase, e.g. "in 20 minutes to
+/// check the oven", "to call mom in an hour" or "10 minutes"
+pub fn parse_timer_request(text: &str) -> Option<TimerRequest> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let normalized: Vec<String> = words
+        .iter()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .collect();
+
+    let skip = usize::from(matches!(
+        normalized.first().map(String::as_str),
+        Some("in" | "for")
+    ));
+    let (seconds, label) = if let Some((seconds, used)) = parse_duration(&normalized[skip..]) {
+        (seconds, label_from(&words[skip + used..]))
+    } else {
+        // The duration comes last: "to call mom in an hour"
+        let at = normalized.iter().rposition(|w| w == "in")?;
+        let (seconds, used) = parse_duration(&normalized[at + 1..])?;
+        if at + 1 + used != words.len() {
+            return None;
+        }
+        (seconds, label_from(&words[..at]))
+    };
+
+    let duration = Duration::from_secs(seconds);
+    (!duration.is_zero() && duration <= MAX_TIMER).then_some(TimerRequest { duration, label })
+}
+
+/// "1 hour 5 minutes", "30 seconds"
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let parts = [
+        (seconds / 3600, "hour"),
+        (seconds % 3600 / 60, "minute"),
+        (seconds % 60, "second"),
+    ];
+    let formatted: Vec<String> = parts
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{} {}{}", n, unit, if *n == 1 { "" } else { "s" }))
+        .collect();
+    if formatted.is_empty() {
+        "0 seconds".to_string()
+    } else {
+        formatted.join(" ")
+    }
+}
+
+fn notify(app: &AppHandle, title: &str, body: &str) {
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        warn!("Failed to show timer notification: {}", e);
+    }
+}
+
+fn save(app: &AppHandle, timers: &[VoiceTimer]) {
+    let mut settings = settings::get_settings(app);
+    settings.timers = timers.to_vec();
+    settings::write_settings(app, settings);
+}
+
+fn describe(timer: &VoiceTimer) -> String {
+    timer.label.clone().unwrap_or_else(|| {
+        let duration = Duration::from_secs(timer.duration_secs);
+        format!("{} timer", format_duration(duration))
+    })
+}
+
+fn time_of_day(timestamp: i64) -> String {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|t| t.format("%H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Start a timer and confirm it with a notification
+pub fn start(app: &AppHandle, request: TimerRequest) {
+    let timer = VoiceTimer {
+        id: format!("timer-{}", Utc::now().timestamp_millis()),
+        label: request.label,
+        duration_secs: request.duration.as_secs(),
+        fires_at: Utc::now().timestamp() + request.duration.as_secs() as i64,
+    };
+    info!("Starting {}", describe(&timer));
+
+    let confirmation = format!(
+        "{}, due at {}",
+        describe(&timer),
+        time_of_day(timer.fires_at)
+    );
+    let mut timers = TIMERS.lock().unwrap();
+    timers.push(timer);
+    timers.sort_by_key(|t| t.fires_at);
+    save(app, &timers);
+    drop(timers);
+
+    notify(app, "Timer set", &confirmation);
+}
+
+/// Show the running timers in a notification
+pub fn list(app: &AppHandle) {
+    let timers = TIMERS.lock().unwrap().clone();
+    let now = Utc::now().timestamp();
+    let body = if timers.is_empty() {
+        "No timers running".to_string()
+    } else {
+        timers
+            .iter()
+            .map(|t| {
+                let left = Duration::from_secs((t.fires_at - now).max(0) as u64);
+                format!("{}: {} left", describe(t), format_duration(left))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    notify(app, "Timers", &body);
+}
+
+/// Cancel the timer whose label contains `query`, all of them for "all", or the one
+/// due soonest without a query. Returns what was cancelled.
+pub fn cancel(app: &AppHandle, query: Option<&str>) -> Result<String, String> {
+    let query = query
+        .and_then(|q| label_from(&q.split_whitespace().collect::<Vec<_>>()))
+        .map(|q| q.to_lowercase());
+
+    let mut timers = TIMERS.lock().unwrap();
+    if timers.is_empty() {
+        return Err("No timers running".to_string());
+    }
+    let cancelled: Vec<VoiceTimer> = match query.as_deref() {
+        Some("all" | "all timers" | "everything") => std::mem::take(&mut *timers),
+        Some(query) => {
+            let index = timers
+                .iter()
+                .position(|t| describe(t).to_lowercase().contains(query))
+                .ok_or_else(|| format!("No timer matches \"{}\"", query))?;
+            vec![timers.remove(index)]
+        }
+        None => vec![timers.remove(0)],
+    };
+    save(app, &timers);
+    drop(timers);
+
+    let summary = cancelled
+        .iter()
+        .map(describe)
+        .collect::<Vec<_>>()
+        .join(", ");
+    info!("Cancelled {}", summary);
+    notify(app, "Timer cancelled", &summary);
+    Ok(summary)
+}
+
+fn check(app: &AppHandle) {
+    let now = Utc::now().timestamp();
+    let mut timers = TIMERS.lock().unwrap();
+    let due: Vec<VoiceTimer> = timers
+        .iter()
+        .filter(|t| t.fires_at <= now)
+        .cloned()
+        .collect();
+    if due.is_empty() {
+        return;
+    }
+    timers.retain(|t| t.fires_at > now);
+    save(app, &timers);
+    drop(timers);
+
+    for timer in due {
+        info!("{} is up", describe(&timer));
+        let title = timer
+            .label
+            .clone()
+            .unwrap_or_else(|| "Timer done".to_string());
+        let body = format!(
+            "{} timer is up",
+            format_duration(Duration::from_secs(timer.duration_secs))
+        );
+        notify(app, &title, &body);
+    }
+}
+
+pub fn start_scheduler(app: &AppHandle) {
+    // Timers that ran out while the app was closed fire on the first check
+    *TIMERS.lock().unwrap() = settings::get_settings(app).timers;
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            check(&app);
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+    info!("Started timer scheduler");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(minutes: u64, label: Option<&str>) -> Option<TimerRequest> {
+        Some(TimerRequest {
+            duration: Duration::from_secs(minutes * 60),
+            label: label.map(String::from),
+        })
+    }
+
+    #[test]
+    fn test_parse_timer_request() {
+        assert_eq!(
+            parse_timer_request("in 20 minutes to check the oven."),
+            request(20, Some("check the oven"))
+        );
+        assert_eq!(
+            parse_timer_request("to call Mom in an hour"),
+            request(60, Some("call Mom"))
+        );
+        assert_eq!(parse_timer_request("ten minutes"), request(10, None));
+        assert_eq!(
+            parse_timer_request("in twenty-five minutes"),
+            request(25, None)
+        );
+        assert_eq!(parse_timer_request("seventy minutes"), request(70, None));
+        assert_eq!(
+            parse_timer_request("in eighty five minutes"),
+            request(85, None)
+        );
+        assert_eq!(
+            parse_timer_request("for 1 hour and 30 minutes"),
+            request(90, None)
+        );
+        assert_eq!(
+            parse_timer_request("in an hour and a half to stretch"),
+            request(90, Some("stretch"))
+        );
+        assert_eq!(parse_timer_request("half an hour"), request(30, None));
+        assert_eq!(
+            parse_timer_request("in thirty seconds").map(|r| r.duration),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_parse_timer_request_rejects_unclear_durations() {
+        assert_eq!(parse_timer_request("to call mom"), None);
+        assert_eq!(parse_timer_request("in a while"), None);
+        assert_eq!(parse_timer_request("in 0 minutes"), None);
+        assert_eq!(parse_timer_request("in 900 hours"), None);
+        // Too long to count in seconds, rather than wrapping around
+        assert_eq!(parse_timer_request("in 18446744073709551615 hours"), None);
+        assert_eq!(
+            parse_timer_request("in 1 minute and 18446744073709551615 seconds"),
+            None
+        );
+        // "in" has to end the utterance when the duration comes last
+        assert_eq!(parse_timer_request("to check in 5 minutes with Sam"), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(30)), "30 seconds");
+        assert_eq!(format_duration(Duration::from_secs(60)), "1 minute");
+        assert_eq!(
+            format_duration(Duration::from_secs(3900)),
+            "1 hour 5 minutes"
+        );
+    }
+}
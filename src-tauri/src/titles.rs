@@ -0,0 +1,179 @@
+//! Short titles for history entries and chats, so lists read "Follow-up email to
+//! vendor about pricing" rather than the first few words of what was said.
+//!
+//! Titles come from the title model when one is set. Otherwise, or when the model
+//! can't be reached, they're made from the text itself: its first sentence with the
+//! filler trimmed off.
+
+use crate::actions::resolve_llm_config;
+use crate::audio_toolkit::capitalize;
+use crate::errors::AppError;
+use crate::managers::history::HistoryManager;
+use crate::settings::{get_settings, AppSettings};
+use crate::summaries::complete;
+use log::warn;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Most words in a title made from the text
+const MAX_HEURISTIC_WORDS: usize = 8;
+
+/// Most words kept from a model's answer, in case it rambles
+const MAX_TITLE_WORDS: usize = 12;
+
+/// Most characters of the text sent to the title model
+const MAX_PROMPT_CHARS: usize = 2_000;
+
+/// Words dropped from the start of a sentence before it becomes a title
+const LEADING_FILLERS: &[&str] = &[
+    "so",
+    "um",
+    "uh",
+    "umm",
+    "uhh",
+    "er",
+    "okay",
+    "ok",
+    "well",
+    "like",
+    "and",
+    "right",
+    "alright",
+    "basically",
+];
+
+/// At most `max` characters of `text`
+pub fn truncate_chars(text: &str, max: usize) -> &str {
+    match text.char_indices().nth(max) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// A title made from the first sentence of `text`, or None when it has no words
+pub fn heuristic_title(text: &str) -> Option<String> {
+    let first_sentence = text
+        .split(['.', '!', '?', '\n'])
+        .map(str::trim)
+        .find(|sentence| !sentence.is_empty())?;
+    let words: Vec<&str> = first_sentence
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| c == ',' || c == ';' || c == ':'))
+        .skip_while(|word| LEADING_FILLERS.contains(&word.to_lowercase().as_str()))
+        .filter(|word| !word.is_empty())
+        .take(MAX_HEURISTIC_WORDS)
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(capitalize(&words.join(" ")))
+}
+
+/// The title in a model's answer, without quotes, labels or trailing punctuation
+pub fn clean_title(answer: &str) -> Option<String> {
+    let line = answer
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    let line = line
+        .strip_prefix("Title:")
+        .or_else(|| line.strip_prefix("title:"))
+        .unwrap_or(line);
+    let line = line
+        .trim()
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '*' | '#' | '`'))
+        .trim_end_matches(|c: char| matches!(c, '.' | '!' | ','))
+        .trim();
+    let words: Vec<&str> = line.split_whitespace().take(MAX_TITLE_WORDS).collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(words.join(" "))
+}
+
+async fn model_title(
+    app: &AppHandle,
+    settings: &AppSettings,
+    model_id: &str,
+    text: &str,
+) -> Result<String, AppError> {
+    let config = resolve_llm_config(settings, model_id).await?;
+    let client = crate::llm_client::create_client(&config.provider, config.api_key.clone())?;
+    let prompt = format!(
+        "Write a title of at most eight words for this dictation, in its language, saying what it is about (for example \"Follow-up email to vendor about pricing\"). Reply with only the title.\n\n<text>\n{}\n</text>",
+        truncate_chars(text, MAX_PROMPT_CHARS)
+    );
+    let answer = complete(app, &client, &config, prompt, "titles").await?;
+    clean_title(&answer).ok_or_else(|| AppError::internal("The model returned an empty title"))
+}
+
+/// A title for `text`, from the title model when one is set and from the text
+/// itself otherwise
+pub async fn title_for_text(app: &AppHandle, settings: &AppSettings, text: &str) -> Option<String> {
+    if let Some(model_id) = &settings.default_title_model_id {
+        match model_title(app, settings, model_id, text).await {
+            Ok(title) => return Some(title),
+            Err(e) => warn!("Failed to generate a title, using the text instead: {}", e),
+        }
+    }
+    heuristic_title(text)
+}
+
+/// Title a history entry in the background once its transcription is saved
+pub fn spawn_for_entry(app: AppHandle, id: i64, text: String) {
+    let settings = get_settings(&app);
+    if !settings.history_titles_enabled {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        let Some(title) = title_for_text(&app, &settings, &text).await else {
+            return;
+        };
+        let history = app.state::<Arc<HistoryManager>>();
+        if let Err(e) = history.save_generated_title(id, &title).await {
+            warn!("Failed to save title for entry {}: {}", id, e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heuristic_title() {
+        assert_eq!(
+            heuristic_title("So, um, follow up with the vendor about pricing. Then lunch.")
+                .as_deref(),
+            Some("Follow up with the vendor about pricing")
+        );
+        assert_eq!(
+            heuristic_title(
+                "okay we need to decide who owns the migration before the end of the week"
+            )
+            .as_deref(),
+            Some("We need to decide who owns the migration")
+        );
+        assert_eq!(heuristic_title("  ...  "), None);
+        assert_eq!(heuristic_title("Um."), None);
+    }
+
+    #[test]
+    fn test_clean_title() {
+        assert_eq!(
+            clean_title("Title: \"Vendor pricing follow-up.\"\n").as_deref(),
+            Some("Vendor pricing follow-up")
+        );
+        assert_eq!(
+            clean_title("\n  **Quarterly plan**"),
+            Some("Quarterly plan".to_string())
+        );
+        assert_eq!(clean_title("  \n"), None);
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("héllo", 2), "hé");
+        assert_eq!(truncate_chars("hi", 5), "hi");
+    }
+}
@@ -0,0 +1,250 @@
+//! Soft-delete support for user-editable settings items.
+//!
+//! Deleting a coherent prompt, prompt category, voice command or app mapping moves it
+//! into `AppSettings::deleted_items` instead of dropping it, so it can be restored until
+//! it is purged after `trash_retention_days`.
+
+use crate::settings::{self, AppSettings, DeletedItem, TrashedItem};
+use log::{debug, info};
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// How often the background purge runs
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Move an item into the trash. With a retention of 0 days the item is dropped instead.
+pub fn move_to_trash(settings: &mut AppSettings, name: String, item: TrashedItem) {
+    if settings.trash_retention_days == 0 {
+        debug!("Trash retention is 0 days, permanently deleting '{}'", name);
+        return;
+    }
+
+    let now = chrono::Utc::now();
+    let base_id = format!("trash_{}", now.timestamp_millis());
+    let mut id = base_id.clone();
+    let mut counter = 1;
+    while settings.deleted_items.iter().any(|d| d.id == id) {
+        id = format!("{}_{}", base_id, counter);
+        counter += 1;
+    }
+
+    debug!("Moving '{}' to trash as {}", name, id);
+    settings.deleted_items.push(DeletedItem {
+        id,
+        name,
+        deleted_at: now.timestamp(),
+        item,
+    });
+}
+
+/// Restore a trashed item back into settings, removing it from the trash.
+///
+/// Fails if an item with the same ID already exists again (e.g. a built-in voice command
+/// that was re-added on startup), or if an app mapping's category no longer exists.
+pub fn restore(settings: &mut AppSettings, trash_id: &str) -> Result<DeletedItem, String> {
+    let index = settings
+        .deleted_items
+        .iter()
+        .position(|d| d.id == trash_id)
+        .ok_or_else(|| format!("Deleted item '{}' not found", trash_id))?;
+
+    match &settings.deleted_items[index].item {
+        TrashedItem::CoherentPrompt(prompt) => {
+            if settings.coherent_prompts.iter().any(|p| p.id == prompt.id) {
+                return Err(format!("Prompt '{}' already exists", prompt.name));
+            }
+            settings.coherent_prompts.push(prompt.clone());
+        }
+        TrashedItem::PromptCategory {
+            category,
+            app_mappings,
+        } => {
+            if settings
+                .prompt_categories
+                .iter()
+                .any(|c| c.id == category.id)
+            {
+                return Err(format!("Category '{}' already exists", category.name));
+            }
+            settings.prompt_categories.push(category.clone());
+
+            // Only bring back mappings for apps that haven't been re-mapped since
+            for mapping in app_mappings {
+                if !settings
+                    .app_category_mappings
+                    .iter()
+                    .any(|m| m.bundle_identifier == mapping.bundle_identifier)
+                {
+                    settings.app_category_mappings.push(mapping.clone());
+                }
+            }
+        }
+        TrashedItem::VoiceCommand(command) => {
+            if settings.voice_commands.iter().any(|c| c.id == command.id) {
+                return Err(format!("Voice command '{}' already exists", command.name));
+            }
+            settings.voice_commands.push(command.clone());
+        }
+        TrashedItem::AppMapping(mapping) => {
+            if !settings
+                .prompt_categories
+                .iter()
+                .any(|c| c.id == mapping.category_id)
+            {
+                return Err(format!(
+                    "Category '{}' for {} no longer exists",
+                    mapping.category_id, mapping.display_name
+                ));
+            }
+            if settings
+                .app_category_mappings
+                .iter()
+                .any(|m| m.bundle_identifier == mapping.bundle_identifier)
+            {
+                return Err(format!("{} is already mapped", mapping.display_name));
+            }
+            settings.app_category_mappings.push(mapping.clone());
+        }
+    }
+
+    Ok(settings.deleted_items.remove(index))
+}
+
+/// Remove trashed items older than the retention period. Returns the number purged.
+pub fn purge_expired(settings: &mut AppSettings, now: i64) -> usize {
+    let cutoff = now - i64::from(settings.trash_retention_days) * 24 * 60 * 60;
+    let original_len = settings.deleted_items.len();
+    settings.deleted_items.retain(|d| d.deleted_at > cutoff);
+    original_len - settings.deleted_items.len()
+}
+
+/// Purge expired items now and then periodically in the background.
+pub fn start_purge_scheduler(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        let mut settings = settings::get_settings(&app);
+        let purged = purge_expired(&mut settings, chrono::Utc::now().timestamp());
+        if purged > 0 {
+            info!("Purged {} expired item(s) from trash", purged);
+            settings::write_settings(&app, settings);
+        }
+        std::thread::sleep(PURGE_INTERVAL);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{get_default_settings, AppCategoryMapping, LLMPrompt};
+
+    fn prompt(id: &str) -> LLMPrompt {
+        LLMPrompt {
+            id: id.to_string(),
+            name: id.to_string(),
+            prompt: "${output}".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_move_to_trash_and_restore() {
+        let mut settings = get_default_settings();
+        let original = settings.coherent_prompts.len();
+
+        let removed = settings.coherent_prompts.remove(0);
+        move_to_trash(
+            &mut settings,
+            removed.name.clone(),
+            TrashedItem::CoherentPrompt(removed),
+        );
+        assert_eq!(settings.deleted_items.len(), 1);
+
+        let trash_id = settings.deleted_items[0].id.clone();
+        restore(&mut settings, &trash_id).unwrap();
+        assert_eq!(settings.coherent_prompts.len(), original);
+        assert!(settings.deleted_items.is_empty());
+    }
+
+    #[test]
+    fn test_trash_ids_are_unique() {
+        let mut settings = get_default_settings();
+        move_to_trash(
+            &mut settings,
+            "a".into(),
+            TrashedItem::CoherentPrompt(prompt("a")),
+        );
+        move_to_trash(
+            &mut settings,
+            "b".into(),
+            TrashedItem::CoherentPrompt(prompt("b")),
+        );
+        assert_ne!(settings.deleted_items[0].id, settings.deleted_items[1].id);
+    }
+
+    #[test]
+    fn test_zero_retention_skips_trash() {
+        let mut settings = get_default_settings();
+        settings.trash_retention_days = 0;
+        move_to_trash(
+            &mut settings,
+            "a".into(),
+            TrashedItem::CoherentPrompt(prompt("a")),
+        );
+        assert!(settings.deleted_items.is_empty());
+    }
+
+    #[test]
+    fn test_restore_conflict_keeps_item_in_trash() {
+        let mut settings = get_default_settings();
+        let existing = settings.coherent_prompts[0].clone();
+        move_to_trash(
+            &mut settings,
+            existing.name.clone(),
+            TrashedItem::CoherentPrompt(existing),
+        );
+
+        let trash_id = settings.deleted_items[0].id.clone();
+        assert!(restore(&mut settings, &trash_id).is_err());
+        assert_eq!(settings.deleted_items.len(), 1);
+    }
+
+    #[test]
+    fn test_restore_mapping_requires_category() {
+        let mut settings = get_default_settings();
+        let mapping = AppCategoryMapping {
+            bundle_identifier: "com.example.app".to_string(),
+            display_name: "Example".to_string(),
+            category_id: "missing".to_string(),
+        };
+        move_to_trash(
+            &mut settings,
+            "Example".into(),
+            TrashedItem::AppMapping(mapping),
+        );
+
+        let trash_id = settings.deleted_items[0].id.clone();
+        assert!(restore(&mut settings, &trash_id).is_err());
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let mut settings = get_default_settings();
+        settings.trash_retention_days = 30;
+        move_to_trash(
+            &mut settings,
+            "old".into(),
+            TrashedItem::CoherentPrompt(prompt("old")),
+        );
+        move_to_trash(
+            &mut settings,
+            "new".into(),
+            TrashedItem::CoherentPrompt(prompt("new")),
+        );
+
+        let now = chrono::Utc::now().timestamp();
+        settings.deleted_items[0].deleted_at = now - 31 * 24 * 60 * 60;
+
+        assert_eq!(purge_expired(&mut settings, now), 1);
+        assert_eq!(settings.deleted_items.len(), 1);
+        assert_eq!(settings.deleted_items[0].name, "new");
+    }
+}
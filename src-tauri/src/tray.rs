@@ -1,11 +1,13 @@
+use crate::events::PromptModeChanged;
+use crate::i18n::get_tray_translations;
 use crate::managers::chat_persistence::ChatPersistenceManager;
 use crate::settings::{self, PromptMode};
-use crate::tray_i18n::get_tray_translations;
 use std::sync::Arc;
 use tauri::image::Image;
 use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIcon;
 use tauri::{AppHandle, Manager, Theme};
+use tauri_specta::Event;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TrayIconState {
@@ -58,20 +60,40 @@ pub fn get_icon_path(theme: AppTheme, state: TrayIconState) -> &'static str {
     }
 }
 
+/// Fade an icon so the tray visibly shows that shortcuts are paused
+fn dimmed_icon(image: &Image<'_>) -> Image<'static> {
+    let mut rgba = image.rgba().to_vec();
+    for pixel in rgba.chunks_exact_mut(4) {
+        pixel[3] = (pixel[3] as f32 * 0.35) as u8;
+    }
+    Image::new_owned(rgba, image.width(), image.height())
+}
+
 pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
-    let tray = app.state::<TrayIcon>();
+    // The test app has no tray
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let theme = get_current_theme(app);
 
     let icon_path = get_icon_path(theme, icon.clone());
 
-    let _ = tray.set_icon(Some(
-        Image::from_path(
-            app.path()
-                .resolve(icon_path, tauri::path::BaseDirectory::Resource)
-                .expect("failed to resolve"),
-        )
-        .expect("failed to set icon"),
-    ));
+    let image = Image::from_path(
+        app.path()
+            .resolve(icon_path, tauri::path::BaseDirectory::Resource)
+            .expect("failed to resolve"),
+    )
+    .expect("failed to set icon");
+
+    let shortcuts_paused = icon == TrayIconState::Idle && crate::shortcut::shortcuts_paused();
+    if shortcuts_paused {
+        let strings = get_tray_translations(Some(settings::get_settings(app).app_language));
+        let _ = tray.set_icon(Some(dimmed_icon(&image)));
+        let _ = tray.set_tooltip(Some(&strings.tooltip_shortcuts_paused));
+    } else {
+        let _ = tray.set_icon(Some(image));
+        let _ = tray.set_tooltip(Some("Ramble"));
+    }
 
     // Update menu based on state
     update_tray_menu(app, &icon, None);
@@ -79,14 +101,12 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
 
 /// Set the prompt mode and update the tray menu
 pub fn set_prompt_mode(app: &AppHandle, mode: PromptMode) {
-    use tauri::Emitter;
-
     let mut settings = settings::get_settings(app);
     settings.prompt_mode = mode;
     settings::write_settings(app, settings);
 
     // Emit event for overlay/frontend to update
-    let _ = app.emit("prompt-mode-changed", mode);
+    let _ = PromptModeChanged(mode).emit(app);
 
     // Refresh the tray menu to update checkmarks
     update_tray_menu(app, &TrayIconState::Idle, None);
@@ -180,6 +200,26 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
     )
     .expect("failed to create high mode item");
 
+    let pause_shortcuts_i = CheckMenuItem::with_id(
+        app,
+        "pause_shortcuts",
+        &strings.pause_shortcuts,
+        true,
+        crate::shortcut::shortcuts_paused(),
+        None::<&str>,
+    )
+    .expect("failed to create pause shortcuts item");
+
+    let focus_mode_i = CheckMenuItem::with_id(
+        app,
+        "focus_mode",
+        &strings.focus_mode,
+        true,
+        settings.focus_mode,
+        None::<&str>,
+    )
+    .expect("failed to create focus mode item");
+
     // Create the "Copy Last Transcription" menu item
     let copy_last_i = MenuItem::with_id(
         app,
@@ -261,6 +301,8 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
                     &mode_medium,
                     &mode_high,
                     &separator(),
+                    &focus_mode_i,
+                    &pause_shortcuts_i,
                     &settings_i,
                     &check_updates_i,
                     &separator(),
@@ -285,6 +327,8 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
                 &mode_medium,
                 &mode_high,
                 &separator(),
+                &focus_mode_i,
+                &pause_shortcuts_i,
                 &settings_i,
                 &check_updates_i,
                 &separator(),
@@ -0,0 +1,347 @@
+//! Hosted text-to-speech from OpenAI and ElevenLabs.
+//!
+//! Both APIs can return raw 24 kHz 16-bit PCM, which is appended to the playback
+//! queue as it arrives so speech starts before the whole reply is synthesized.
+//! Long text is sent in several requests, split between sentences.
+
+use crate::managers::stt_engine::stt_api_key;
+use crate::settings::AppSettings;
+use crate::tts::{SendWrapper, TTSEngine, TtsVoice};
+use anyhow::{anyhow, Result};
+use futures_util::StreamExt;
+use log::{error, info};
+use rodio::{OutputStreamBuilder, Sink};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Services with a cloud TTS backend, as used for keys and voices in settings
+pub const TTS_SERVICES: &[&str] = &["openai", "elevenlabs"];
+
+const OPENAI_SPEECH_URL: &str = "https://api.openai.com/v1/audio/speech";
+const OPENAI_TTS_MODEL: &str = "tts-1";
+const ELEVENLABS_URL: &str = "https://api.elevenlabs.io/v1";
+const ELEVENLABS_MODEL: &str = "eleven_flash_v2_5";
+
+/// Voices `tts-1` offers; OpenAI has no endpoint listing them
+const OPENAI_VOICES: &[&str] = &[
+    "alloy", "ash", "coral", "echo", "fable", "nova", "onyx", "sage", "shimmer",
+];
+
+/// Voice used until one is chosen
+pub fn default_voice(service: &str) -> &'static str {
+    match service {
+        // "Rachel", one of the premade voices every account has
+        "elevenlabs" => "21m00Tcm4TlvDzKARREv",
+        _ => "alloy",
+    }
+}
+
+const SAMPLE_RATE: u32 = 24000;
+/// Longest text sent in one request; OpenAI accepts 4096 characters
+const MAX_REQUEST_CHARS: usize = 4000;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Find the API key for a cloud TTS service. OpenAI falls back to the transcription
+/// and LLM provider keys so users don't have to enter it again.
+pub fn tts_api_key(settings: &AppSettings, service: &str) -> Option<String> {
+    if let Some(key) = settings
+        .tts_api_keys
+        .get(service)
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty())
+    {
+        return Some(key.to_string());
+    }
+
+    if service == "openai" {
+        return stt_api_key(settings, service);
+    }
+
+    None
+}
+
+pub struct CloudTtsEngine {
+    service: &'static str,
+    api_key: String,
+    voice: String,
+    client: reqwest::Client,
+    _stream_handle: Option<SendWrapper<rodio::OutputStream>>,
+    sink: Option<Sink>,
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CloudTtsEngine {
+    pub fn new(service: &'static str, api_key: String, voice: String) -> Result<Self> {
+        let stream_handle = match OutputStreamBuilder::from_default_device() {
+            Ok(builder) => match builder.open_stream() {
+                Ok(handle) => Some(handle),
+                Err(e) => {
+                    error!("Failed to open audio stream for cloud TTS: {}", e);
+                    None
+                }
+            },
+            Err(e) => {
+                error!("Failed to create audio stream builder for cloud TTS: {}", e);
+                None
+            }
+        };
+        Ok(Self {
+            service,
+            api_key,
+            voice,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()?,
+            _stream_handle: stream_handle.map(SendWrapper),
+            sink: None,
+            is_cancelled: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn request(&self, text: &str, speed: f32) -> reqwest::RequestBuilder {
+        match self.service {
+            "elevenlabs" => self
+                .client
+                .post(format!(
+                    "{}/text-to-speech/{}/stream?output_format=pcm_{}",
+                    ELEVENLABS_URL, self.voice, SAMPLE_RATE
+                ))
+                .header("xi-api-key", &self.api_key)
+                .json(&serde_json::json!({
+                    "text": text,
+                    "model_id": ELEVENLABS_MODEL,
+                    // ElevenLabs only allows small speed changes
+                    "voice_settings": { "speed": speed.clamp(0.7, 1.2) },
+                })),
+            _ => self
+                .client
+                .post(OPENAI_SPEECH_URL)
+                .bearer_auth(&self.api_key)
+                .json(&serde_json::json!({
+                    "model": OPENAI_TTS_MODEL,
+                    "input": text,
+                    "voice": self.voice,
+                    "response_format": "pcm",
+                    "speed": speed.clamp(0.25, 4.0),
+                })),
+        }
+    }
+
+    /// Stream one request's audio into the sink, returning early if cancelled
+    async fn stream_part(&self, sink: &Sink, text: &str, speed: f32) -> Result<()> {
+        let response = self.request(text, speed).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("{} TTS error {}: {}", self.service, status, body));
+        }
+
+        let mut stream = response.bytes_stream();
+        let mut pending = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            if self.is_cancelled.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+            pending.extend_from_slice(&chunk?);
+            let samples = pcm_to_samples(&mut pending);
+            if !samples.is_empty() {
+                sink.append(rodio::buffer::SamplesBuffer::new(1, SAMPLE_RATE, samples));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decode the complete little-endian 16-bit samples at the front of `pending`,
+/// leaving a trailing odd byte for the next chunk
+fn pcm_to_samples(pending: &mut Vec<u8>) -> Vec<f32> {
+    let complete = pending.len() - pending.len() % 2;
+    let samples = pending[..complete]
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32)
+        .collect();
+    pending.drain(..complete);
+    samples
+}
+
+/// Split text into parts of at most `max_chars`, between sentences where possible
+fn split_for_requests(text: &str, max_chars: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    for sentence in text.split_inclusive(['.', '!', '?', '\n']) {
+        if !current.is_empty() && current.len() + sentence.len() > max_chars {
+            parts.push(std::mem::take(&mut current));
+        }
+        if sentence.len() > max_chars {
+            // A single overlong sentence is split between words
+            for word in sentence.split_inclusive(' ') {
+                if !current.is_empty() && current.len() + word.len() > max_chars {
+                    parts.push(std::mem::take(&mut current));
+                }
+                current.push_str(word);
+            }
+        } else {
+            current.push_str(sentence);
+        }
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl TTSEngine for CloudTtsEngine {
+    async fn speak(&mut self, text: &str, speed: f32, volume: f32) -> Result<()> {
+        let sh = self
+            ._stream_handle
+            .as_ref()
+            .ok_or_else(|| anyhow!("Audio output handle not initialized"))?;
+
+        if let Some(ref old_sink) = self.sink {
+            old_sink.stop();
+        }
+        self.is_cancelled.store(false, Ordering::SeqCst);
+
+        let (sink, queue_output) = Sink::new();
+        sink.set_volume(volume);
+        sh.0.mixer().add(queue_output);
+
+        let parts = split_for_requests(text, MAX_REQUEST_CHARS);
+        info!(
+            "{} TTS: streaming {} characters in {} request(s)",
+            self.service,
+            text.len(),
+            parts.len()
+        );
+        let result = async {
+            for part in &parts {
+                if self.is_cancelled.load(Ordering::SeqCst) {
+                    break;
+                }
+                self.stream_part(&sink, part, speed).await?;
+            }
+            Ok(())
+        }
+        .await;
+        // Keep the sink even on failure, so audio that already arrived plays out
+        self.sink = Some(sink);
+        result
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.is_cancelled.store(true, Ordering::SeqCst);
+        if let Some(ref sink) = self.sink {
+            sink.stop();
+        }
+        Ok(())
+    }
+
+    fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.is_cancelled.clone()
+    }
+
+    fn is_playing(&self) -> bool {
+        self.sink.as_ref().is_some_and(|sink| !sink.empty())
+    }
+}
+
+/// Voices a cloud service offers: OpenAI's fixed set, or the voices in the
+/// ElevenLabs account
+pub async fn list_voices(service: &str, api_key: Option<String>) -> Result<Vec<TtsVoice>> {
+    if service != "elevenlabs" {
+        return Ok(OPENAI_VOICES
+            .iter()
+            .map(|voice| TtsVoice {
+                id: voice.to_string(),
+                name: voice[..1].to_uppercase() + &voice[1..],
+                language: String::new(),
+            })
+            .collect());
+    }
+
+    let api_key = api_key.ok_or_else(|| anyhow!("No API key set for elevenlabs"))?;
+    let response = reqwest::Client::new()
+        .get(format!("{}/voices", ELEVENLABS_URL))
+        .header("xi-api-key", api_key)
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(anyhow!("ElevenLabs API error {}: {}", status, body));
+    }
+    let json: serde_json::Value = serde_json::from_str(&body)?;
+    Ok(parse_elevenlabs_voices(&json))
+}
+
+fn parse_elevenlabs_voices(json: &serde_json::Value) -> Vec<TtsVoice> {
+    json.get("voices")
+        .and_then(|v| v.as_array())
+        .map(|voices| {
+            voices
+                .iter()
+                .filter_map(|voice| {
+                    Some(TtsVoice {
+                        id: voice.get("voice_id")?.as_str()?.to_string(),
+                        name: voice.get("name")?.as_str()?.to_string(),
+                        language: voice
+                            .pointer("/labels/language")
+                            .and_then(|l| l.as_str())
+                            .unwrap_or_default()
+                            .to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcm_to_samples_keeps_partial_sample() {
+        let mut pending = vec![0x00, 0x40, 0xff];
+        let samples = pcm_to_samples(&mut pending);
+        assert_eq!(samples.len(), 1);
+        assert!((samples[0] - 0.5).abs() < 0.001);
+        assert_eq!(pending, vec![0xff]);
+
+        pending.push(0x7f);
+        assert_eq!(pcm_to_samples(&mut pending), vec![1.0]);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_split_for_requests() {
+        assert_eq!(split_for_requests("Short text.", 100), vec!["Short text."]);
+
+        let parts = split_for_requests("One two. Three four. Five six.", 20);
+        assert_eq!(parts, vec!["One two. Three four.", "Five six."]);
+
+        let parts = split_for_requests("aaaa bbbb cccc dddd", 10);
+        assert!(parts.iter().all(|p| p.len() <= 10));
+        assert_eq!(parts.join(" "), "aaaa bbbb cccc dddd");
+    }
+
+    #[test]
+    fn test_parse_elevenlabs_voices() {
+        let json = serde_json::json!({
+            "voices": [
+                { "voice_id": "abc", "name": "Rachel", "labels": { "accent": "american" } },
+                { "voice_id": "def", "name": "Mateo", "labels": { "language": "es" } },
+                { "name": "Broken" }
+            ]
+        });
+        let voices = parse_elevenlabs_voices(&json);
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[0].id, "abc");
+        assert_eq!(voices[1].language, "es");
+    }
+}
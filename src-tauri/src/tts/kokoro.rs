@@ -1,4 +1,4 @@
-use crate::tts::TTSEngine;
+use crate::tts::{SendWrapper, TTSEngine};
 use anyhow::Result;
 use kokorox::tts::koko::TTSKoko;
 use log::{error, info};
@@ -16,10 +16,6 @@ pub struct KokoroEngine {
     is_cancelled: Arc<AtomicBool>,
 }
 
-struct SendWrapper<T>(T);
-unsafe impl<T> Send for SendWrapper<T> {}
-unsafe impl<T> Sync for SendWrapper<T> {}
-
 /// Split text into sentences for streaming playback
 fn split_into_sentences(text: &str) -> Vec<String> {
     let mut sentences = Vec::new();
@@ -268,6 +264,10 @@ impl TTSEngine for KokoroEngine {
         Ok(())
     }
 
+    fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.is_cancelled.clone()
+    }
+
     fn is_playing(&self) -> bool {
         if let Some(ref sink) = self.sink {
             !sink.empty()
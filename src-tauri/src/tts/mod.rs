@@ -1,8 +1,83 @@
+pub mod cloud;
 pub mod kokoro;
+pub mod system;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 #[async_trait::async_trait]
 pub trait TTSEngine: Send + Sync {
     async fn speak(&mut self, text: &str, speed: f32, volume: f32) -> anyhow::Result<()>;
     async fn stop(&self) -> anyhow::Result<()>;
     fn is_playing(&self) -> bool;
+    /// Flag that stops an ongoing `speak` between sentences once set. It's shared so
+    /// speech can be cancelled while `speak` still holds the engine.
+    fn cancel_flag(&self) -> Arc<AtomicBool>;
+}
+
+/// Lets engines hold rodio's output stream, which isn't `Send` on every platform
+pub(crate) struct SendWrapper<T>(pub T);
+unsafe impl<T> Send for SendWrapper<T> {}
+unsafe impl<T> Sync for SendWrapper<T> {}
+
+/// A voice a TTS backend offers
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct TtsVoice {
+    /// Passed back to the engine to select the voice
+    pub id: String,
+    pub name: String,
+    /// Language or locale code, such as `en_US` or `en-us`; empty when the voice
+    /// speaks several
+    pub language: String,
+}
+
+static CODE_BLOCK: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)```.*?```").unwrap());
+static IMAGE: Lazy<Regex> = Lazy::new(|| Regex::new(r"!\[[^\]]*\]\([^)]*\)").unwrap());
+static LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\([^)]*\)").unwrap());
+static INLINE_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"`([^`]*)`").unwrap());
+static RULE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^[ \t]*[-|: \t]{3,}$").unwrap());
+static LINE_MARKER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[ \t]*(#{1,6}[ \t]+|>[ \t]?|[-*+][ \t]+)").unwrap());
+static EMPHASIS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\*\*|__|\*|~~").unwrap());
+static BLANK_LINES: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n[ \t]*\n(\s*\n)+").unwrap());
+
+/// Plain text to read out for a Markdown reply: code blocks and images are
+/// skipped, links read as their text and formatting marks are dropped
+pub fn speakable_text(markdown: &str) -> String {
+    let text = CODE_BLOCK.replace_all(markdown, "");
+    let text = IMAGE.replace_all(&text, "");
+    let text = LINK.replace_all(&text, "$1");
+    let text = INLINE_CODE.replace_all(&text, "$1");
+    let text = RULE.replace_all(&text, "");
+    let text = LINE_MARKER.replace_all(&text, "");
+    let text = EMPHASIS.replace_all(&text, "");
+    let text = text.replace('|', " ");
+    BLANK_LINES.replace_all(&text, "\n\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speakable_text() {
+        let markdown = "## Summary\n\nUse **`cargo test`** to run the [test suite](https://example.com).\n\n```rust\nfn main() {}\n```\n\n- First *item*\n- Second item\n\n> Quoted\n\n---\n\n![Chart](asset://chart.png)";
+        assert_eq!(
+            speakable_text(markdown),
+            "Summary\n\nUse cargo test to run the test suite.\n\nFirst item\nSecond item\n\nQuoted"
+        );
+    }
+
+    #[test]
+    fn test_speakable_text_table() {
+        let markdown = "| Name | Size |\n| --- | --- |\n| a.txt | 2 KB |";
+        let text = speakable_text(markdown);
+        assert!(!text.contains('|'));
+        assert!(!text.contains("---"));
+        assert!(text.contains("a.txt"));
+    }
 }
@@ -0,0 +1,311 @@
+//! Speech through the operating system's own synthesizer, for users who haven't
+//! downloaded the Kokoro model.
+//!
+//! macOS speaks with `say` (the AVSpeechSynthesizer voices), Windows with SAPI
+//! through PowerShell's System.Speech and Linux with espeak-ng. Text is written to
+//! the process's stdin, so nothing in it is ever interpreted by a shell.
+
+use crate::tts::{TTSEngine, TtsVoice};
+use anyhow::{anyhow, Context, Result};
+use log::{debug, info};
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Words per minute the system voices speak at 1.0x
+const BASE_WORDS_PER_MINUTE: f32 = 180.0;
+
+pub struct SystemEngine {
+    /// Voice id from `list_voices`, or the system default
+    voice: Option<String>,
+    child: Mutex<Option<Child>>,
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl SystemEngine {
+    pub fn new(voice: Option<String>) -> Self {
+        Self {
+            voice,
+            child: Mutex::new(None),
+            is_cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn kill(&self) {
+        if let Some(mut child) = self.child.lock().unwrap().take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// The command that speaks whatever is written to its stdin
+#[cfg(target_os = "macos")]
+fn speech_command(voice: Option<&str>, speed: f32, _volume: f32) -> Command {
+    let mut command = Command::new("say");
+    command.arg("-r").arg(words_per_minute(speed).to_string());
+    if let Some(voice) = voice {
+        command.arg("-v").arg(voice);
+    }
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn speech_command(voice: Option<&str>, speed: f32, volume: f32) -> Command {
+    // SAPI's rate runs from -10 to 10, roughly doubling or halving at the ends
+    let rate = ((speed.max(0.1).log2()) * 10.0).round().clamp(-10.0, 10.0) as i32;
+    let select_voice = voice
+        .map(|v| format!("$s.SelectVoice('{}'); ", v.replace('\'', "''")))
+        .unwrap_or_default();
+    let script = format!(
+        "Add-Type -AssemblyName System.Speech; \
+         $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+         {}$s.Rate = {}; $s.Volume = {}; \
+         $s.Speak([Console]::In.ReadToEnd())",
+        select_voice,
+        rate,
+        (volume.clamp(0.0, 1.0) * 100.0).round() as i32
+    );
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn speech_command(voice: Option<&str>, speed: f32, volume: f32) -> Command {
+    let mut command = Command::new("espeak-ng");
+    command
+        .arg("-s")
+        .arg(words_per_minute(speed).to_string())
+        // espeak-ng's amplitude runs from 0 to 200, with 100 as normal
+        .arg("-a")
+        .arg(((volume.clamp(0.0, 1.0) * 100.0).round() as u32).to_string())
+        .arg("--stdin");
+    if let Some(voice) = voice {
+        command.arg("-v").arg(voice);
+    }
+    command
+}
+
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn words_per_minute(speed: f32) -> u32 {
+    (BASE_WORDS_PER_MINUTE * speed.clamp(0.25, 4.0)).round() as u32
+}
+
+/// Text to send to the synthesizer; `say` has no volume flag, so the volume is set
+/// with an embedded speech command
+fn speech_input(text: &str, volume: f32) -> String {
+    if cfg!(target_os = "macos") && volume < 1.0 {
+        format!("[[volm {:.2}]] {}", volume.clamp(0.0, 1.0), text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[async_trait::async_trait]
+impl TTSEngine for SystemEngine {
+    async fn speak(&mut self, text: &str, speed: f32, volume: f32) -> Result<()> {
+        self.kill();
+        self.is_cancelled.store(false, Ordering::SeqCst);
+
+        info!("System speech: {} characters", text.len());
+        let mut child = speech_command(self.voice.as_deref(), speed, volume)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("Failed to start the system speech synthesizer")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // Written from a thread since espeak-ng reads long text as it speaks.
+            // Dropping stdin closes it, which tells the synthesizer the text is complete
+            let input = speech_input(text, volume);
+            std::thread::spawn(move || {
+                if let Err(e) = stdin.write_all(input.as_bytes()) {
+                    debug!("Writing to the system speech synthesizer stopped: {}", e);
+                }
+            });
+        }
+        *self.child.lock().unwrap() = Some(child);
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.is_cancelled.store(true, Ordering::SeqCst);
+        self.kill();
+        Ok(())
+    }
+
+    fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.is_cancelled.clone()
+    }
+
+    fn is_playing(&self) -> bool {
+        let mut child = self.child.lock().unwrap();
+        match child.as_mut().map(|c| c.try_wait()) {
+            Some(Ok(None)) => true,
+            Some(Ok(Some(status))) => {
+                debug!("System speech exited with {}", status);
+                *child = None;
+                false
+            }
+            Some(Err(_)) | None => false,
+        }
+    }
+}
+
+impl Drop for SystemEngine {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+/// Voices the system synthesizer offers
+pub fn list_voices() -> Result<Vec<TtsVoice>> {
+    let output = voices_command()
+        .output()
+        .context("Failed to run the system speech synthesizer")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Listing system voices failed with status: {}",
+            output.status
+        ));
+    }
+    Ok(parse_voices(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(target_os = "macos")]
+fn voices_command() -> Command {
+    let mut command = Command::new("say");
+    command.args(["-v", "?"]);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn voices_command() -> Command {
+    let mut command = Command::new("powershell");
+    command.args([
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        "Add-Type -AssemblyName System.Speech; \
+         (New-Object System.Speech.Synthesis.SpeechSynthesizer).GetInstalledVoices() | \
+         ForEach-Object { $_.VoiceInfo.Name + '|' + $_.VoiceInfo.Culture.Name }",
+    ]);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn voices_command() -> Command {
+    let mut command = Command::new("espeak-ng");
+    command.arg("--voices");
+    command
+}
+
+/// `say -v ?` lines look like `Eddy (English (US))   en_US    # Hello! My name is Eddy.`
+fn parse_say_voices(output: &str) -> Vec<TtsVoice> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (voice, _sample) = line.split_once('#').unwrap_or((line, ""));
+            let (name, language) = voice.trim().rsplit_once(char::is_whitespace)?;
+            let name = name.trim();
+            (!name.is_empty()).then(|| TtsVoice {
+                id: name.to_string(),
+                name: name.to_string(),
+                language: language.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// One `Name|Culture` line per voice, as printed by the PowerShell listing
+fn parse_sapi_voices(output: &str) -> Vec<TtsVoice> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, language) = line.trim().split_once('|')?;
+            Some(TtsVoice {
+                id: name.to_string(),
+                name: name.to_string(),
+                language: language.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// `espeak-ng --voices` prints a header, then
+/// `Pty Language Age/Gender VoiceName File Other Languages` columns
+fn parse_espeak_voices(output: &str) -> Vec<TtsVoice> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let _priority = columns.next()?;
+            let language = columns.next()?;
+            let _age_gender = columns.next()?;
+            let name = columns.next()?;
+            Some(TtsVoice {
+                id: language.to_string(),
+                name: name.replace('_', " "),
+                language: language.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_voices(output: &str) -> Vec<TtsVoice> {
+    if cfg!(target_os = "macos") {
+        parse_say_voices(output)
+    } else if cfg!(target_os = "windows") {
+        parse_sapi_voices(output)
+    } else {
+        parse_espeak_voices(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_say_voices() {
+        let output = "Albert              en_US    # Hello! My name is Albert.\n\
+                      Eddy (English (US)) en_US    # Hello! My name is Eddy.\n\
+                      Amélie              fr_CA    # Bonjour, je m’appelle Amélie.\n";
+        let voices = parse_say_voices(output);
+        assert_eq!(voices.len(), 3);
+        assert_eq!(voices[1].name, "Eddy (English (US))");
+        assert_eq!(voices[1].language, "en_US");
+        assert_eq!(voices[2].id, "Amélie");
+    }
+
+    #[test]
+    fn test_parse_sapi_voices() {
+        let voices =
+            parse_sapi_voices("Microsoft David Desktop|en-US\r\nMicrosoft Hedda Desktop|de-DE\r\n");
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[0].name, "Microsoft David Desktop");
+        assert_eq!(voices[1].language, "de-DE");
+    }
+
+    #[test]
+    fn test_parse_espeak_voices() {
+        let output = "Pty Language       Age/Gender VoiceName          File                 Other Languages\n \
+                      5  af              --/M      Afrikaans          gmw/af\n \
+                      5  en-us           --/M      English_(America)  gmw/en-US            (en 3)\n";
+        let voices = parse_espeak_voices(output);
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[1].id, "en-us");
+        assert_eq!(voices[1].name, "English (America)");
+    }
+
+    #[test]
+    fn test_words_per_minute() {
+        assert_eq!(words_per_minute(1.0), 180);
+        assert_eq!(words_per_minute(2.0), 360);
+        assert_eq!(words_per_minute(0.0), 45);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::events::RecordingMode;
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::tts::TTSManager;
 use crate::ManagedToggleState;
@@ -18,6 +19,111 @@ pub fn cancel_current_operation(app: &AppHandle) {
     let bt = std::backtrace::Backtrace::force_capture();
     info!("Initiating operation cancellation... Backtrace:\n{}", bt);
 
+    discard_recording(app);
+
+    // Stop any ongoing TTS
+    let tts_manager = app.state::<Arc<TTSManager>>();
+    let tts_manager_cloned = tts_manager.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = tts_manager_cloned.stop().await;
+    });
+
+    // Update tray icon and hide overlay
+    change_tray_icon(app, crate::tray::TrayIconState::Idle);
+    hide_recording_overlay(app);
+
+    info!("Operation cancellation completed - returned to idle state");
+}
+
+/// Cancel a recording in which no speech was detected, with its own sound and
+/// overlay message so it isn't mistaken for an empty transcription
+pub fn cancel_for_no_speech(app: &AppHandle) {
+    discard_recording(app);
+
+    change_tray_icon(app, crate::tray::TrayIconState::Idle);
+    crate::audio_feedback::play_feedback_sound(app, crate::audio_feedback::SoundType::NoSpeech);
+    show_no_speech_overlay(app);
+
+    info!("Recording cancelled - no speech detected");
+}
+
+/// End a follow-up recording nothing was said in, without the no-speech warning
+pub fn end_follow_up(app: &AppHandle) {
+    discard_recording(app);
+
+    change_tray_icon(app, crate::tray::TrayIconState::Idle);
+    hide_recording_overlay(app);
+
+    info!("Stopped listening for a follow-up command");
+}
+
+/// Stop a toggled-on recording that has gone silent and transcribe it, the same way
+/// a second tap of its shortcut would
+pub fn auto_stop_recording(app: &AppHandle, binding_id: &str) {
+    info!("Auto-stopping recording for '{}' after silence", binding_id);
+    stop_toggled_recording(app, binding_id);
+}
+
+/// Stop a toggled-on recording and transcribe it, as a second tap of its shortcut
+/// would
+pub fn stop_toggled_recording(app: &AppHandle, binding_id: &str) {
+    let toggle_state_manager = app.state::<ManagedToggleState>();
+    if let Ok(mut states) = toggle_state_manager.lock() {
+        states.active_toggles.insert(binding_id.to_string(), false);
+    }
+
+    #[cfg(target_os = "macos")]
+    crate::key_listener::force_reset_state();
+
+    if let Some(action) = crate::actions::ACTION_MAP.get(binding_id) {
+        action.stop(app, binding_id, "");
+    }
+}
+
+/// Whether a binding's recording is toggled on
+pub fn is_toggle_active(app: &AppHandle, binding_id: &str) -> bool {
+    let toggle_state_manager = app.state::<ManagedToggleState>();
+    let active = toggle_state_manager
+        .lock()
+        .map(|states| states.active_toggles.get(binding_id).copied())
+        .ok()
+        .flatten();
+    active.unwrap_or(false)
+}
+
+/// Start a binding as if its shortcut had been tapped, e.g. when the wake word is
+/// heard. It stops on silence or on the next tap.
+pub fn start_hands_free(app: &AppHandle, binding_id: &str) {
+    let Some(action) = crate::actions::ACTION_MAP.get(binding_id) else {
+        warn!("No action defined in ACTION_MAP for binding ID '{binding_id}'");
+        return;
+    };
+
+    let toggle_state_manager = app.state::<ManagedToggleState>();
+    {
+        let Ok(mut states) = toggle_state_manager.lock() else {
+            warn!("Failed to lock toggle state manager");
+            return;
+        };
+        let active = states
+            .active_toggles
+            .entry(binding_id.to_string())
+            .or_insert(false);
+        if *active {
+            return;
+        }
+        *active = true;
+    }
+
+    if !action.start(app, binding_id, "") {
+        if let Ok(mut states) = toggle_state_manager.lock() {
+            states.active_toggles.insert(binding_id.to_string(), false);
+        }
+    }
+}
+
+/// Reset shortcut toggle states and throw away the current recording
+fn discard_recording(app: &AppHandle) {
     // First, reset all shortcut toggle states.
     // This is critical for non-push-to-talk mode where shortcuts toggle on/off
     let toggle_state_manager = app.state::<ManagedToggleState>();
@@ -34,19 +140,6 @@ pub fn cancel_current_operation(app: &AppHandle) {
     // Cancel any ongoing recording
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.cancel_recording();
-
-    // Stop any ongoing TTS
-    let tts_manager = app.state::<Arc<TTSManager>>();
-    let tts_manager_cloned = tts_manager.inner().clone();
-    tauri::async_runtime::spawn(async move {
-        let _ = tts_manager_cloned.stop().await;
-    });
-
-    // Update tray icon and hide overlay
-    change_tray_icon(app, crate::tray::TrayIconState::Idle);
-    hide_recording_overlay(app);
-
-    info!("Operation cancellation completed - returned to idle state");
 }
 
 /// Pause the current recording operation without discarding audio.
@@ -87,11 +180,11 @@ pub fn resume_current_operation(app: &AppHandle) -> Option<String> {
         if is_coherent {
             show_ramble_recording_overlay(app);
             // Re-emit mode so buttons reappear
-            crate::overlay::emit_mode_determined(app, "refining");
+            crate::overlay::emit_mode_determined(app, RecordingMode::Refining);
         } else {
             show_recording_overlay(app);
             // Re-emit mode if it was already known (otherwise it stays optimistic)
-            crate::overlay::emit_mode_determined(app, "hold");
+            crate::overlay::emit_mode_determined(app, RecordingMode::Hold);
         }
 
         info!(
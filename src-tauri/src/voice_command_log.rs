@@ -0,0 +1,199 @@
+//! Persistent log of voice command runs, for finding out why a command fired (or
+//! didn't).
+//!
+//! Each step of a voice command is logged with what was said, which command it
+//! matched and how, the script that ran and the result. Commands run one at a time,
+//! so the step being processed is tracked in a single slot that the matching and
+//! script-running code add to as they go. Runs are kept in their own store file,
+//! newest last, up to `MAX_RUNS`.
+
+use crate::voice_commands::CommandResult;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+const LOG_STORE: &str = "voice_command_history.json";
+
+/// Number of runs kept before the oldest are dropped
+const MAX_RUNS: usize = 500;
+
+/// Longest script or output kept per run, in characters
+const MAX_TEXT_CHARS: usize = 2_000;
+
+/// How a voice command was picked
+#[derive(Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandMatchSource {
+    /// A trigger phrase matched without asking the LLM
+    Phrase,
+    /// The LLM chose the command or came up with the action itself
+    Llm,
+}
+
+/// How a voice command run ended
+#[derive(Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandOutcome {
+    /// The command's output was pasted
+    Pasted,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+pub struct VoiceCommandRun {
+    /// Unix timestamp in milliseconds
+    pub timestamp: i64,
+    /// What was said for this step
+    pub transcription: String,
+    pub matched_by: Option<CommandMatchSource>,
+    pub command_id: Option<String>,
+    pub command_name: Option<String>,
+    /// The script or shell command that ran, with placeholders filled in
+    pub script: Option<String>,
+    pub outcome: CommandOutcome,
+    /// Pasted text or error message
+    pub output: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// The step being processed and when it started
+static CURRENT: Mutex<Option<(VoiceCommandRun, Instant)>> = Mutex::new(None);
+
+fn truncate(text: &str) -> String {
+    match text.char_indices().nth(MAX_TEXT_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+fn update(change: impl FnOnce(&mut VoiceCommandRun)) {
+    if let Ok(mut current) = CURRENT.lock() {
+        if let Some((run, _)) = current.as_mut() {
+            change(run);
+        }
+    }
+}
+
+/// Start logging a step of a voice command
+pub fn begin(transcription: &str) {
+    let run = VoiceCommandRun {
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        transcription: transcription.to_string(),
+        matched_by: None,
+        command_id: None,
+        command_name: None,
+        script: None,
+        outcome: CommandOutcome::Failed,
+        output: None,
+        duration_ms: 0,
+    };
+    if let Ok(mut current) = CURRENT.lock() {
+        *current = Some((run, Instant::now()));
+    }
+}
+
+/// Note the command the step matched. `command` is None when the LLM acted without
+/// picking one of the configured commands.
+pub fn note_match(source: CommandMatchSource, command: Option<(&str, &str)>) {
+    update(|run| {
+        run.matched_by = Some(source);
+        run.command_id = command.map(|(id, _)| id.to_string());
+        run.command_name = command.map(|(_, name)| name.to_string());
+    });
+}
+
+/// Note the script about to run
+pub fn note_script(script: &str) {
+    update(|run| run.script = Some(truncate(script)));
+}
+
+/// Finish logging the step with its result and save it
+pub fn finish(app: &AppHandle, result: &Result<CommandResult, String>) {
+    let Some((mut run, started)) = CURRENT.lock().ok().and_then(|mut c| c.take()) else {
+        return;
+    };
+    run.duration_ms = started.elapsed().as_millis() as u64;
+    let (outcome, output) = match result {
+        Ok(CommandResult::PasteOutput(text)) => (CommandOutcome::Pasted, Some(text.as_str())),
+        Ok(CommandResult::Success) => (CommandOutcome::Succeeded, None),
+        Ok(CommandResult::Error(message)) | Err(message) => {
+            (CommandOutcome::Failed, Some(message.as_str()))
+        }
+    };
+    run.outcome = outcome;
+    run.output = output.map(truncate);
+
+    let mut runs = runs(app);
+    push_run(&mut runs, run);
+    match app.store(LOG_STORE) {
+        Ok(store) => store.set("runs", serde_json::json!(runs)),
+        Err(e) => warn!("Failed to save voice command history: {}", e),
+    }
+}
+
+fn push_run(runs: &mut Vec<VoiceCommandRun>, run: VoiceCommandRun) {
+    runs.push(run);
+    if runs.len() > MAX_RUNS {
+        runs.drain(..runs.len() - MAX_RUNS);
+    }
+}
+
+/// Logged runs, oldest first
+pub fn runs(app: &AppHandle) -> Vec<VoiceCommandRun> {
+    app.store(LOG_STORE)
+        .ok()
+        .and_then(|store| store.get("runs"))
+        .and_then(|runs| serde_json::from_value(runs).ok())
+        .unwrap_or_default()
+}
+
+pub fn clear(app: &AppHandle) {
+    if let Ok(store) = app.store(LOG_STORE) {
+        store.delete("runs");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(transcription: &str) -> VoiceCommandRun {
+        VoiceCommandRun {
+            timestamp: 0,
+            transcription: transcription.to_string(),
+            matched_by: Some(CommandMatchSource::Phrase),
+            command_id: Some("open_app".to_string()),
+            command_name: Some("Open Application".to_string()),
+            script: None,
+            outcome: CommandOutcome::Succeeded,
+            output: None,
+            duration_ms: 12,
+        }
+    }
+
+    #[test]
+    fn test_push_run_drops_oldest() {
+        let mut runs = Vec::new();
+        for i in 0..MAX_RUNS + 3 {
+            push_run(&mut runs, run(&i.to_string()));
+        }
+        assert_eq!(runs.len(), MAX_RUNS);
+        assert_eq!(runs[0].transcription, "3");
+        assert_eq!(
+            runs.last().unwrap().transcription,
+            (MAX_RUNS + 2).to_string()
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("short"), "short");
+        let long = "é".repeat(MAX_TEXT_CHARS + 10);
+        assert_eq!(truncate(&long).chars().count(), MAX_TEXT_CHARS + 1);
+    }
+}
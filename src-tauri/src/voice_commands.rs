@@ -3,11 +3,61 @@
 //! This module handles executing voice commands, including:
 //! - Running shell commands
 //! - Running AppleScript
+//! - Offline matching of trigger phrases, so common commands skip the LLM
+//! - Splitting "and then" chains into steps that run in order
+//! - Typed parameters of custom commands, taken from `{name}` slots in trigger
+//!   phrases or extracted by the LLM, and substituted into the script
 //! - LLM-based command interpretation for inferable commands
 
-use crate::settings::{ScriptType, VoiceCommand};
+use crate::settings::{
+    CommandParameter, ParameterType, ScriptType, VoiceCommand, VoiceCommandType,
+};
 use log::{debug, error, info};
+use std::collections::HashMap;
 use std::process::Command;
+use strsim::normalized_levenshtein;
+
+/// Built-in commands whose handlers only need the words after the trigger phrase,
+/// so they can run without asking the LLM
+const LOCAL_BUILTINS: &[&str] = &[
+    "open_app",
+    "web_search",
+    "print",
+    "switch_to_app",
+    "maximize_window",
+    "window_left_half",
+    "window_right_half",
+    "set_timer",
+    "list_timers",
+    "cancel_timer",
+    "set_prompt_mode",
+    "enable_refinement",
+    "disable_refinement",
+    "use_prompt",
+    "switch_project",
+    "fix_selection",
+    "code_comment",
+    "doc_comment",
+];
+
+/// Local built-ins that take no argument, so they only match when nothing follows
+/// the trigger phrase
+const NO_ARGUMENT_BUILTINS: &[&str] = &[
+    "maximize_window",
+    "window_left_half",
+    "window_right_half",
+    "list_timers",
+    "enable_refinement",
+    "disable_refinement",
+    "fix_selection",
+];
+
+/// Local built-ins that match with or without an argument
+const OPTIONAL_ARGUMENT_BUILTINS: &[&str] = &["cancel_timer"];
+
+/// How closely each spoken word must match its trigger phrase word (0 to 1), which
+/// tolerates small transcription slips like "opn" for "open"
+const MIN_WORD_SIMILARITY: f64 = 0.75;
 
 /// Result of executing a voice command
 #[derive(Debug)]
@@ -20,15 +70,342 @@ pub enum CommandResult {
     Error(String),
 }
 
+/// A command recognized locally from its trigger phrase
+#[derive(Debug)]
+pub struct IntentMatch<'a> {
+    pub command: &'a VoiceCommand,
+    /// What was said after the trigger phrase, e.g. "Chrome" in "open Chrome"
+    pub argument: String,
+    /// Values of the command's parameters, by name
+    pub parameters: HashMap<String, String>,
+    /// How closely the spoken words matched the phrase, from 0 to 1
+    pub score: f64,
+}
+
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+        .to_lowercase()
+}
+
+/// A word of a trigger phrase: literal text, or a `{name}` slot for a parameter
+enum PhraseWord {
+    Literal(String),
+    Slot(String),
+}
+
+fn parse_phrase(phrase: &str) -> Vec<PhraseWord> {
+    phrase
+        .split_whitespace()
+        .filter_map(|word| {
+            if let Some(name) = word.strip_prefix('{').and_then(|w| w.strip_suffix('}')) {
+                return Some(PhraseWord::Slot(name.to_string()));
+            }
+            let word = normalize_word(word);
+            (!word.is_empty()).then_some(PhraseWord::Literal(word))
+        })
+        .collect()
+}
+
+struct PhraseMatch {
+    /// How closely each literal word matched
+    similarities: Vec<f64>,
+    /// Spoken text filling each slot, by parameter name
+    slots: Vec<(String, String)>,
+    /// Number of spoken words the phrase took up
+    consumed: usize,
+}
+
+/// Match the start of what was said against a trigger phrase. A slot takes the
+/// words up to the phrase's next literal word, or everything left if it ends the
+/// phrase.
+fn match_phrase(phrase: &[PhraseWord], spoken: &[(&str, String)]) -> Option<PhraseMatch> {
+    let mut similarities = Vec::new();
+    let mut slots = Vec::new();
+    let mut pos = 0;
+
+    for (i, word) in phrase.iter().enumerate() {
+        match word {
+            PhraseWord::Literal(expected) => {
+                let (_, heard) = spoken.get(pos)?;
+                let similarity = normalized_levenshtein(expected, heard);
+                if similarity < MIN_WORD_SIMILARITY {
+                    return None;
+                }
+                similarities.push(similarity);
+                pos += 1;
+            }
+            PhraseWord::Slot(name) => {
+                let end = match phrase.get(i + 1) {
+                    Some(PhraseWord::Literal(next)) => (pos + 1..spoken.len()).find(|&j| {
+                        normalized_levenshtein(next, &spoken[j].1) >= MIN_WORD_SIMILARITY
+                    })?,
+                    // Two slots in a row: the first takes a single word
+                    Some(PhraseWord::Slot(_)) => pos + 1,
+                    None => spoken.len(),
+                };
+                if end <= pos || end > spoken.len() {
+                    return None;
+                }
+                let value = spoken[pos..end]
+                    .iter()
+                    .map(|(word, _)| *word)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                slots.push((name.clone(), value));
+                pos = end;
+            }
+        }
+    }
+
+    Some(PhraseMatch {
+        similarities,
+        slots,
+        consumed: pos,
+    })
+}
+
+/// Typed values for a command's parameters, from the phrase's slots or, for a
+/// command with a single parameter and no slot, from the argument. None if one is
+/// missing or doesn't fit its type, so the LLM gets a chance instead.
+fn resolve_parameters(
+    command: &VoiceCommand,
+    slots: Vec<(String, String)>,
+    argument: &str,
+) -> Option<HashMap<String, String>> {
+    let mut raw: HashMap<String, String> = slots.into_iter().collect();
+    if let [parameter] = command.parameters.as_slice() {
+        if raw.is_empty() && !argument.is_empty() {
+            raw.insert(parameter.name.clone(), argument.to_string());
+        }
+    }
+
+    command
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let value = coerce_parameter(parameter, raw.get(&parameter.name)?)?;
+            Some((parameter.name.clone(), value))
+        })
+        .collect()
+}
+
+/// Check a spoken value against its parameter's type: numbers are normalized
+/// ("50%" becomes "50") and enum values snap to the closest option
+pub fn coerce_parameter(parameter: &CommandParameter, value: &str) -> Option<String> {
+    let value = value.trim().trim_end_matches(['.', ',', '!', '?']);
+    if value.is_empty() {
+        return None;
+    }
+
+    match parameter.param_type {
+        ParameterType::String => Some(value.to_string()),
+        ParameterType::Number => {
+            let number: f64 = value.trim_end_matches('%').replace(',', "").parse().ok()?;
+            Some(number.to_string())
+        }
+        ParameterType::Enum => {
+            let heard = value.to_lowercase();
+            parameter
+                .options
+                .iter()
+                .map(|option| {
+                    (
+                        option,
+                        normalized_levenshtein(&option.to_lowercase(), &heard),
+                    )
+                })
+                .filter(|(_, similarity)| *similarity >= MIN_WORD_SIMILARITY)
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(option, _)| option.clone())
+        }
+    }
+}
+
+/// Parameter values from the LLM's `parameters` object, checked like spoken ones
+pub fn parameters_from_llm(
+    command: &VoiceCommand,
+    values: Option<&serde_json::Value>,
+) -> Result<HashMap<String, String>, String> {
+    command
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let raw = match values.and_then(|v| v.get(&parameter.name)) {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(serde_json::Value::Number(n)) => n.to_string(),
+                _ => String::new(),
+            };
+            coerce_parameter(parameter, &raw)
+                .map(|value| (parameter.name.clone(), value))
+                .ok_or_else(|| {
+                    format!(
+                        "Couldn't tell the {} for '{}'",
+                        parameter.name, command.name
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Check a command's parameter definitions before it's saved
+pub fn validate_parameters(command: &VoiceCommand) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for parameter in &command.parameters {
+        let name = parameter.name.as_str();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(format!(
+                "Parameter name '{}' may only use letters, digits and underscores",
+                name
+            ));
+        }
+        if name == "selection" || name == "transcription" {
+            return Err(format!(
+                "'{}' is reserved for the built-in placeholder",
+                name
+            ));
+        }
+        if seen.contains(&name) {
+            return Err(format!("Parameter '{}' is defined twice", name));
+        }
+        if parameter.param_type == ParameterType::Enum && parameter.options.is_empty() {
+            return Err(format!("Parameter '{}' needs at least one option", name));
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+/// Match a spoken command to a trigger phrase without the LLM. The utterance must
+/// start with a phrase of a custom command or of a built-in that runs locally, and
+/// whatever follows becomes the argument. Built-ins need an argument ("open" alone
+/// says nothing), so those are left to the LLM.
+pub fn match_intent<'a>(
+    commands: &'a [VoiceCommand],
+    transcription: &str,
+) -> Option<IntentMatch<'a>> {
+    let spoken: Vec<(&str, String)> = transcription
+        .split_whitespace()
+        .map(|word| (word, normalize_word(word)))
+        .filter(|(_, normalized)| !normalized.is_empty())
+        .collect();
+
+    let mut best: Option<(IntentMatch<'a>, usize)> = None;
+    for command in commands {
+        let is_builtin = command.command_type != VoiceCommandType::Custom;
+        if is_builtin && !LOCAL_BUILTINS.contains(&command.id.as_str()) {
+            continue;
+        }
+
+        for phrase in &command.phrases {
+            let phrase_words = parse_phrase(phrase);
+            let Some(matched) = match_phrase(&phrase_words, &spoken) else {
+                continue;
+            };
+            if matched.similarities.is_empty() {
+                continue;
+            }
+            let score =
+                matched.similarities.iter().sum::<f64>() / matched.similarities.len() as f64;
+
+            let argument = spoken[matched.consumed..]
+                .iter()
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end_matches(['.', ',', '!', '?'])
+                .to_string();
+            // A built-in's slot stands for its argument, so its phrase can go on
+            // after it, as in "switch to {mode} mode"
+            let argument = match matched.slots.first() {
+                Some((_, value)) if is_builtin => value.clone(),
+                _ => argument,
+            };
+            let id = command.id.as_str();
+            let argument_fits = if OPTIONAL_ARGUMENT_BUILTINS.contains(&id) {
+                true
+            } else {
+                argument.is_empty() == NO_ARGUMENT_BUILTINS.contains(&id)
+            };
+            if is_builtin && !argument_fits {
+                continue;
+            }
+            let Some(parameters) = resolve_parameters(command, matched.slots, &argument) else {
+                continue;
+            };
+
+            // The longer phrase wins a tie, e.g. "search for" over "search"
+            let better = best.as_ref().map_or(true, |(current, words)| {
+                score > current.score || (score == current.score && phrase_words.len() > *words)
+            });
+            if better {
+                best = Some((
+                    IntentMatch {
+                        command,
+                        argument,
+                        parameters,
+                        score,
+                    },
+                    phrase_words.len(),
+                ));
+            }
+        }
+    }
+
+    best.map(|(intent, _)| intent)
+}
+
+/// Split a chained command like "open terminal and then type git status" into its
+/// steps, in the order they should run. Steps are joined by "and then", or by "then"
+/// after a comma or full stop, so a plain "then" inside a step's text is left alone.
+pub fn split_steps(transcription: &str) -> Vec<String> {
+    let words: Vec<&str> = transcription.split_whitespace().collect();
+    let mut steps = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+
+    let mut finish_step = |current: &mut Vec<&str>| {
+        let step = current.join(" ");
+        let step = step.trim_end_matches([',', ';', '.']).trim();
+        if !step.is_empty() {
+            steps.push(step.to_string());
+        }
+        current.clear();
+    };
+
+    let mut i = 0;
+    while i < words.len() {
+        let word = normalize_word(words[i]);
+        let and_then =
+            word == "and" && words.get(i + 1).map(|w| normalize_word(w)).as_deref() == Some("then");
+        let then_after_pause = word == "then"
+            && current
+                .last()
+                .is_some_and(|previous| previous.ends_with([',', ';', '.']));
+
+        if (and_then || then_after_pause) && !current.is_empty() {
+            finish_step(&mut current);
+            i += if and_then { 2 } else { 1 };
+            continue;
+        }
+
+        current.push(words[i]);
+        i += 1;
+    }
+    finish_step(&mut current);
+
+    steps
+}
+
 /// Execute a bespoke (user-defined script) command
 ///
 /// If the script contains `${selection}`, it will be replaced with the provided selection text.
 /// If the script contains `${transcription}`, it will be replaced with the spoken text.
+/// Each declared parameter's `${name}` is replaced with its value from `parameters`.
 /// The placeholders are properly escaped for the script type (shell or AppleScript).
 pub fn execute_bespoke_command(
     command: &VoiceCommand,
     selection: Option<&str>,
     transcription: Option<&str>,
+    parameters: &HashMap<String, String>,
 ) -> CommandResult {
     let script = match &command.script {
         Some(s) if !s.trim().is_empty() => s,
@@ -48,6 +425,25 @@ pub fn execute_bespoke_command(
     // Substitute placeholders with actual text (escaped appropriately)
     let mut processed_script = script.clone();
 
+    for parameter in &command.parameters {
+        let placeholder = format!("${{{}}}", parameter.name);
+        if !processed_script.contains(&placeholder) {
+            continue;
+        }
+        let Some(value) = parameters.get(&parameter.name) else {
+            return CommandResult::Error(format!(
+                "No value for '{}' in command '{}'",
+                parameter.name, command.name
+            ));
+        };
+        let escaped_value = match command.script_type {
+            ScriptType::Shell => escape_for_shell(value),
+            ScriptType::AppleScript => escape_for_applescript(value),
+        };
+        debug!("Substituting parameter {} = {:?}", parameter.name, value);
+        processed_script = processed_script.replace(&placeholder, &escaped_value);
+    }
+
     if processed_script.contains("${selection}") {
         let selection_text = selection.unwrap_or("");
         let escaped_selection = match command.script_type {
@@ -74,6 +470,7 @@ pub fn execute_bespoke_command(
         processed_script = processed_script.replace("${transcription}", &escaped_transcription);
     }
 
+    crate::voice_command_log::note_script(&processed_script);
     match command.script_type {
         ScriptType::Shell => execute_shell_script(&processed_script),
         ScriptType::AppleScript => execute_applescript(&processed_script),
@@ -165,6 +562,19 @@ fn execute_applescript(_script: &str) -> CommandResult {
     CommandResult::Error("AppleScript is only supported on macOS".to_string())
 }
 
+/// Describe a parameter for the LLM, e.g. "mode (one of: dark, light)"
+fn describe_parameter(parameter: &CommandParameter) -> String {
+    let kind = match parameter.param_type {
+        ParameterType::String => "text".to_string(),
+        ParameterType::Number => "number".to_string(),
+        ParameterType::Enum => format!("one of: {}", parameter.options.join(", ")),
+    };
+    match &parameter.description {
+        Some(description) => format!("{} ({}): {}", parameter.name, kind, description),
+        None => format!("{} ({})", parameter.name, kind),
+    }
+}
+
 /// Build the system prompt for LLM command interpretation
 pub fn build_command_prompt(commands: &[VoiceCommand], selection: Option<&str>) -> String {
     let mut prompt = String::from(
@@ -185,7 +595,12 @@ pub fn build_command_prompt(commands: &[VoiceCommand], selection: Option<&str>)
         if let Some(desc) = &cmd.description {
             prompt.push_str(desc);
         }
-        prompt.push_str(&format!(" [Trigger phrases: {}]\n", cmd.phrases.join(", ")));
+        prompt.push_str(&format!(" [Trigger phrases: {}]", cmd.phrases.join(", ")));
+        if !cmd.parameters.is_empty() {
+            let parameters: Vec<String> = cmd.parameters.iter().map(describe_parameter).collect();
+            prompt.push_str(&format!(" [Parameters: {}]", parameters.join("; ")));
+        }
+        prompt.push('\n');
     }
 
     prompt.push_str("\nCurrent context:\n");
@@ -196,7 +611,7 @@ pub fn build_command_prompt(commands: &[VoiceCommand], selection: Option<&str>)
 
 COMMAND TYPES:
 - "builtin" commands: Have native handlers. Just match and return the command ID.
-- "custom" commands: Have user-defined scripts. Just match and return the command ID.
+- "custom" commands: Have user-defined scripts. Just match and return the command ID. If the command lists parameters, also return each one's value, taken from what the user said, in "parameters".
 
 SHELL COMMANDS:
 If the user is asking you to do something that can be done with a shell command (e.g., "list all files in my home directory", "create a folder called test", "show disk space", "find all python files"), and it does NOT match any available command above, return execution_type "shell" with the actual shell command.
@@ -206,6 +621,7 @@ Respond with JSON:
   "matched_command": "command_id" or null,
   "execution_type": "builtin" | "custom" | "paste" | "shell" | "unknown",
   "command": "the shell command" (only for execution_type "shell"),
+  "parameters": {"name": "value"} (only for custom commands with parameters),
   "explanation": "brief explanation"
 }
 
@@ -237,3 +653,208 @@ IMPORTANT: Return ONLY raw JSON. No markdown code blocks."#,
 
     prompt
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(id: &str, command_type: VoiceCommandType, phrases: &[&str]) -> VoiceCommand {
+        VoiceCommand {
+            id: id.to_string(),
+            name: id.to_string(),
+            phrases: phrases.iter().map(|p| p.to_string()).collect(),
+            command_type,
+            description: None,
+            script_type: ScriptType::Shell,
+            script: Some("true".to_string()),
+            model_override: None,
+            is_builtin: command_type == VoiceCommandType::Builtin,
+            parameters: Vec::new(),
+        }
+    }
+
+    fn parameter(name: &str, param_type: ParameterType, options: &[&str]) -> CommandParameter {
+        CommandParameter {
+            name: name.to_string(),
+            param_type,
+            options: options.iter().map(|o| o.to_string()).collect(),
+            description: None,
+        }
+    }
+
+    fn commands() -> Vec<VoiceCommand> {
+        vec![
+            command("open_app", VoiceCommandType::Builtin, &["open", "launch"]),
+            command(
+                "web_search",
+                VoiceCommandType::Builtin,
+                &["search", "search for"],
+            ),
+            command("refactor_code", VoiceCommandType::Builtin, &["refactor"]),
+            command(
+                "maximize_window",
+                VoiceCommandType::Builtin,
+                &["maximize window"],
+            ),
+            command("lock", VoiceCommandType::Custom, &["lock screen"]),
+        ]
+    }
+
+    #[test]
+    fn test_match_intent_extracts_argument() {
+        let commands = commands();
+        let intent = match_intent(&commands, "Open Chrome.").unwrap();
+        assert_eq!(intent.command.id, "open_app");
+        assert_eq!(intent.argument, "Chrome");
+        assert_eq!(intent.score, 1.0);
+
+        let intent = match_intent(&commands, "search for rust lifetimes").unwrap();
+        assert_eq!(intent.command.id, "web_search");
+        assert_eq!(intent.argument, "rust lifetimes");
+    }
+
+    #[test]
+    fn test_match_intent_tolerates_slips() {
+        let commands = commands();
+        let intent = match_intent(&commands, "lunch safari").unwrap();
+        assert_eq!(intent.command.id, "open_app");
+        assert_eq!(intent.argument, "safari");
+
+        let intent = match_intent(&commands, "Lock the screen").map(|i| i.command.id.clone());
+        assert_eq!(intent, None);
+        let intent = match_intent(&commands, "lock screen").unwrap();
+        assert_eq!(intent.command.id, "lock");
+        assert!(intent.argument.is_empty());
+    }
+
+    #[test]
+    fn test_match_intent_fills_parameters() {
+        let mut volume = command(
+            "volume",
+            VoiceCommandType::Custom,
+            &["set volume to {level}"],
+        );
+        volume.parameters = vec![parameter("level", ParameterType::Number, &[])];
+        let mut theme = command(
+            "theme",
+            VoiceCommandType::Custom,
+            &["switch to {mode} mode"],
+        );
+        theme.parameters = vec![parameter("mode", ParameterType::Enum, &["dark", "light"])];
+        let mut note = command("note", VoiceCommandType::Custom, &["take a note"]);
+        note.parameters = vec![parameter("text", ParameterType::String, &[])];
+        let commands = vec![volume, theme, note];
+
+        let intent = match_intent(&commands, "Set volume to 40%.").unwrap();
+        assert_eq!(intent.command.id, "volume");
+        assert_eq!(intent.parameters["level"], "40");
+
+        let intent = match_intent(&commands, "switch to Dark mode please").unwrap();
+        assert_eq!(intent.parameters["mode"], "dark");
+        assert_eq!(intent.argument, "please");
+
+        // A single parameter without a slot takes the rest of the utterance
+        let intent = match_intent(&commands, "take a note buy milk").unwrap();
+        assert_eq!(intent.parameters["text"], "buy milk");
+
+        // Values that don't fit are left to the LLM
+        assert!(match_intent(&commands, "set volume to loud").is_none());
+        assert!(match_intent(&commands, "switch to sepia mode").is_none());
+        assert!(match_intent(&commands, "take a note").is_none());
+    }
+
+    #[test]
+    fn test_parameters_from_llm() {
+        let mut volume = command("volume", VoiceCommandType::Custom, &["set volume"]);
+        volume.parameters = vec![parameter("level", ParameterType::Number, &[])];
+
+        let json = serde_json::json!({ "level": 30 });
+        assert_eq!(
+            parameters_from_llm(&volume, Some(&json)).unwrap()["level"],
+            "30"
+        );
+        let json = serde_json::json!({ "level": "thirty" });
+        assert!(parameters_from_llm(&volume, Some(&json)).is_err());
+        assert!(parameters_from_llm(&volume, None).is_err());
+    }
+
+    #[test]
+    fn test_validate_parameters() {
+        let mut cmd = command("cmd", VoiceCommandType::Custom, &["do it"]);
+        cmd.parameters = vec![parameter("level", ParameterType::Number, &[])];
+        assert!(validate_parameters(&cmd).is_ok());
+
+        cmd.parameters = vec![parameter("mode", ParameterType::Enum, &[])];
+        assert!(validate_parameters(&cmd).is_err());
+        cmd.parameters = vec![parameter("selection", ParameterType::String, &[])];
+        assert!(validate_parameters(&cmd).is_err());
+        cmd.parameters = vec![parameter("my level", ParameterType::String, &[])];
+        assert!(validate_parameters(&cmd).is_err());
+    }
+
+    #[test]
+    fn test_split_steps() {
+        assert_eq!(
+            split_steps("Open terminal and then type git status."),
+            vec!["Open terminal", "type git status"]
+        );
+        assert_eq!(
+            split_steps("open safari, then search for rust, and then lock screen"),
+            vec!["open safari", "search for rust", "lock screen"]
+        );
+        // "then" without a pause is part of the step
+        assert_eq!(
+            split_steps("search for then and now"),
+            vec!["search for then and now"]
+        );
+        assert_eq!(
+            split_steps("and then open chrome"),
+            vec!["and then open chrome"]
+        );
+        assert!(split_steps("  ").is_empty());
+    }
+
+    #[test]
+    fn test_match_intent_leaves_the_rest_to_the_llm() {
+        let commands = commands();
+        // Not at the start of the utterance
+        assert!(match_intent(&commands, "could you open chrome").is_none());
+        // Built-ins need an argument
+        assert!(match_intent(&commands, "open").is_none());
+        // Built-ins that need the LLM
+        assert!(match_intent(&commands, "refactor this function").is_none());
+    }
+
+    #[test]
+    fn test_match_intent_without_argument() {
+        let commands = commands();
+        let intent = match_intent(&commands, "Maximize window.").unwrap();
+        assert_eq!(intent.command.id, "maximize_window");
+        assert!(intent.argument.is_empty());
+        // Anything after the phrase might change what's meant
+        assert!(match_intent(&commands, "maximize window on the other screen").is_none());
+    }
+
+    #[test]
+    fn test_match_intent_builtin_slot() {
+        let mut commands = commands();
+        commands.push(command(
+            "switch_to_app",
+            VoiceCommandType::Builtin,
+            &["switch to"],
+        ));
+        commands.push(command(
+            "set_prompt_mode",
+            VoiceCommandType::Builtin,
+            &["switch to {mode} mode"],
+        ));
+
+        let intent = match_intent(&commands, "Switch to writing mode.").unwrap();
+        assert_eq!(intent.command.id, "set_prompt_mode");
+        assert_eq!(intent.argument, "writing");
+
+        let intent = match_intent(&commands, "switch to slack").unwrap();
+        assert_eq!(intent.command.id, "switch_to_app");
+        assert_eq!(intent.argument, "slack");
+    }
+}
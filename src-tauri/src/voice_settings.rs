@@ -0,0 +1,195 @@
+//! Built-in voice commands that change Ramble's own settings, e.g. "switch to writing
+//! mode", "disable refinement" or "use the raw prompt".
+//!
+//! Each change goes through the same settings command the UI uses, and the result
+//! is confirmed with a notification since there's nothing to paste.
+
+use crate::settings::{self, LLMPrompt, PromptMode};
+use log::{info, warn};
+use strsim::normalized_levenshtein;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+/// How closely a spoken prompt name must match a saved one (0 to 1)
+const MIN_PROMPT_SIMILARITY: f64 = 0.7;
+
+/// Prompt names that mean "no prompt at all", i.e. paste the transcription as is
+const RAW_PROMPT_NAMES: &[&str] = &["raw", "none", "no", "plain", "original"];
+
+/// The prompt mode a spoken name refers to. Besides the mode names themselves this
+/// accepts the old category names ("writing", "email", ...), mapped the same way
+/// stored settings are migrated.
+pub fn parse_prompt_mode(text: &str) -> Option<PromptMode> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .find_map(|word| match word.as_str() {
+            "dynamic" | "auto" | "automatic" => Some(PromptMode::Dynamic),
+            "low" | "light" | "casual" | "chat" | "conversation" => Some(PromptMode::Low),
+            "medium" | "standard" | "email" | "development" | "code" => Some(PromptMode::Medium),
+            "high" | "heavy" | "writing" => Some(PromptMode::High),
+            _ => None,
+        })
+}
+
+/// What a spoken prompt name refers to
+#[derive(Debug)]
+pub enum PromptChoice<'a> {
+    /// Turn refinement off and paste the raw transcription
+    Raw,
+    Prompt(&'a LLMPrompt),
+}
+
+/// Find the saved refinement prompt with the closest name, ignoring a leading "the"
+/// and a trailing "prompt"
+pub fn find_prompt<'a>(prompts: &'a [LLMPrompt], name: &str) -> Option<PromptChoice<'a>> {
+    let name = name
+        .trim()
+        .trim_end_matches(['.', ',', '!', '?'])
+        .to_lowercase();
+    let name = name.strip_prefix("the ").unwrap_or(&name);
+    let name = name.strip_suffix(" prompt").unwrap_or(name).trim();
+    if name.is_empty() {
+        return None;
+    }
+    if RAW_PROMPT_NAMES.contains(&name) {
+        return Some(PromptChoice::Raw);
+    }
+
+    prompts
+        .iter()
+        .map(|prompt| {
+            let saved = prompt.name.to_lowercase();
+            // Saying just the start of a long name, like "ramble" for "Ramble to
+            // Coherent", counts as a full match
+            let similarity = if saved.starts_with(name) {
+                1.0
+            } else {
+                normalized_levenshtein(&saved, name)
+            };
+            (prompt, similarity)
+        })
+        .filter(|(_, similarity)| *similarity >= MIN_PROMPT_SIMILARITY)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(prompt, _)| PromptChoice::Prompt(prompt))
+}
+
+fn confirm(app: &AppHandle, body: &str) {
+    info!("{}", body);
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Ramble")
+        .body(body)
+        .show()
+    {
+        warn!("Failed to show settings notification: {}", e);
+    }
+}
+
+/// Switch the prompt mode, as named in `text`
+pub fn set_prompt_mode(app: &AppHandle, text: &str) -> Result<(), String> {
+    let mode = parse_prompt_mode(text)
+        .ok_or_else(|| format!("\"{}\" isn't a prompt mode", text.trim()))?;
+    crate::shortcut::change_prompt_mode_setting(app.clone(), mode).map_err(|e| e.message)?;
+    let name = match mode {
+        PromptMode::Dynamic => "dynamic",
+        PromptMode::Low => "low",
+        PromptMode::Medium => "medium",
+        PromptMode::High => "high",
+    };
+    confirm(app, &format!("Switched to {} prompt mode", name));
+    Ok(())
+}
+
+/// Turn LLM refinement on or off
+pub fn set_refinement(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    crate::shortcut::change_post_process_enabled_setting(app.clone(), enabled)
+        .map_err(|e| e.message)?;
+    crate::events::emit_settings_changed(app, "coherent_enabled", enabled);
+    confirm(
+        app,
+        if enabled {
+            "Refinement is on"
+        } else {
+            "Refinement is off"
+        },
+    );
+    Ok(())
+}
+
+/// Select the refinement prompt named in `text`, turning refinement back on if it
+/// was off. The "raw" prompt turns refinement off instead.
+pub fn use_prompt(app: &AppHandle, text: &str) -> Result<(), String> {
+    let settings = settings::get_settings(app);
+    let prompt = match find_prompt(&settings.coherent_prompts, text) {
+        Some(PromptChoice::Raw) => return set_refinement(app, false),
+        Some(PromptChoice::Prompt(prompt)) => prompt.clone(),
+        None => return Err(format!("No prompt is called \"{}\"", text.trim())),
+    };
+
+    crate::shortcut::set_post_process_selected_prompt(app.clone(), prompt.id)
+        .map_err(|e| e.message)?;
+    if !settings.coherent_enabled {
+        crate::shortcut::change_post_process_enabled_setting(app.clone(), true)
+            .map_err(|e| e.message)?;
+        crate::events::emit_settings_changed(app, "coherent_enabled", true);
+    }
+    confirm(app, &format!("Using the \"{}\" prompt", prompt.name));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt(id: &str, name: &str) -> LLMPrompt {
+        LLMPrompt {
+            id: id.to_string(),
+            name: name.to_string(),
+            prompt: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_prompt_mode() {
+        assert_eq!(parse_prompt_mode("writing"), Some(PromptMode::High));
+        assert_eq!(parse_prompt_mode("Medium."), Some(PromptMode::Medium));
+        assert_eq!(
+            parse_prompt_mode("switch to automatic mode"),
+            Some(PromptMode::Dynamic)
+        );
+        assert_eq!(parse_prompt_mode("casual"), Some(PromptMode::Low));
+        assert_eq!(parse_prompt_mode("purple"), None);
+    }
+
+    /// Id of the chosen prompt, or "raw"
+    fn choose(prompts: &[LLMPrompt], name: &str) -> Option<String> {
+        find_prompt(prompts, name).map(|choice| match choice {
+            PromptChoice::Raw => "raw".to_string(),
+            PromptChoice::Prompt(prompt) => prompt.id.clone(),
+        })
+    }
+
+    #[test]
+    fn test_find_prompt() {
+        let prompts = vec![
+            prompt("default_improve_transcriptions", "Improve Transcriptions"),
+            prompt("ramble_to_coherent", "Ramble to Coherent"),
+        ];
+
+        assert_eq!(choose(&prompts, "the raw prompt").as_deref(), Some("raw"));
+        assert_eq!(
+            choose(&prompts, "ramble").as_deref(),
+            Some("ramble_to_coherent")
+        );
+        assert_eq!(
+            choose(&prompts, "improve transcription").as_deref(),
+            Some("default_improve_transcriptions")
+        );
+        assert_eq!(choose(&prompts, "the email prompt"), None);
+        assert_eq!(choose(&prompts, ""), None);
+    }
+}
@@ -0,0 +1,248 @@
+//! Moving, resizing and focusing windows for the built-in window voice commands.
+//!
+//! Everything acts on the frontmost window of the app the user is in. macOS goes
+//! through System Events with AppleScript (which needs accessibility access, already
+//! granted for pasting), Windows through the Win32 window APIs, and Linux through
+//! `wmctrl` and `xdotool`, which have to be installed and only work on X11.
+
+/// Where to put the frontmost window, within the screen area not taken by the menu
+/// bar, dock or taskbar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Placement {
+    Maximized,
+    LeftHalf,
+    RightHalf,
+}
+
+impl Placement {
+    /// Horizontal offset and width of the placement, as fractions of the screen width
+    fn horizontal_span(self) -> (f64, f64) {
+        match self {
+            Placement::Maximized => (0.0, 1.0),
+            Placement::LeftHalf => (0.0, 0.5),
+            Placement::RightHalf => (0.5, 0.5),
+        }
+    }
+
+    /// Position and size of the placement within a work area
+    #[cfg_attr(target_os = "macos", allow(dead_code))]
+    fn frame(self, left: i32, top: i32, width: i32, height: i32) -> (i32, i32, i32, i32) {
+        let (offset, fraction) = self.horizontal_span();
+        (
+            left + (width as f64 * offset).round() as i32,
+            top,
+            (width as f64 * fraction).round() as i32,
+            height,
+        )
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn run_applescript(script: &str) -> Result<(), String> {
+    let output = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .output()
+        .map_err(|e| format!("Failed to run osascript: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn place_front_window(placement: Placement) -> Result<(), String> {
+    let (offset, fraction) = placement.horizontal_span();
+    // visibleFrame leaves out the menu bar and dock, but counts from the bottom left
+    let script = format!(
+        r#"use framework "AppKit"
+use scripting additions
+set screenFrame to current application's NSScreen's mainScreen()'s frame()
+set {{{{visibleX, visibleY}}, {{visibleWidth, visibleHeight}}}} to current application's NSScreen's mainScreen()'s visibleFrame()
+set screenHeight to item 2 of item 2 of screenFrame
+set topY to screenHeight - (visibleY + visibleHeight)
+tell application "System Events"
+    tell front window of (first application process whose frontmost is true)
+        set position to {{(visibleX + visibleWidth * {offset}) as integer, topY as integer}}
+        set size to {{(visibleWidth * {fraction}) as integer, visibleHeight as integer}}
+    end tell
+end tell"#
+    );
+    run_applescript(&script)
+}
+
+#[cfg(target_os = "macos")]
+pub fn switch_to_app(name: &str) -> Result<(), String> {
+    let name = name.replace('\\', "\\\\").replace('"', "\\\"");
+    run_applescript(&format!("tell application \"{}\" to activate", name))
+}
+
+#[cfg(target_os = "windows")]
+pub fn place_front_window(placement: Placement) -> Result<(), String> {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, SetWindowPos, ShowWindow, SystemParametersInfoW, SPI_GETWORKAREA,
+        SWP_NOZORDER, SW_MAXIMIZE, SW_RESTORE, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_invalid() {
+            return Err("No window is focused".to_string());
+        }
+        if placement == Placement::Maximized {
+            let _ = ShowWindow(hwnd, SW_MAXIMIZE);
+            return Ok(());
+        }
+
+        let mut work_area = RECT::default();
+        SystemParametersInfoW(
+            SPI_GETWORKAREA,
+            0,
+            Some(&mut work_area as *mut RECT as *mut _),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        )
+        .map_err(|e| format!("Failed to get the screen's work area: {}", e))?;
+        let (x, y, width, height) = placement.frame(
+            work_area.left,
+            work_area.top,
+            work_area.right - work_area.left,
+            work_area.bottom - work_area.top,
+        );
+
+        // A maximized window ignores being moved until it's restored
+        let _ = ShowWindow(hwnd, SW_RESTORE);
+        SetWindowPos(hwnd, None, x, y, width, height, SWP_NOZORDER)
+            .map_err(|e| format!("Failed to move the window: {}", e))
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn switch_to_app(name: &str) -> Result<(), String> {
+    use windows::core::BOOL;
+    use windows::Win32::Foundation::{HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        EnumWindows, GetWindowTextW, IsIconic, IsWindowVisible, SetForegroundWindow, ShowWindow,
+        SW_RESTORE,
+    };
+
+    struct Search {
+        name: String,
+        found: Option<HWND>,
+    }
+
+    unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let search = &mut *(lparam.0 as *mut Search);
+        if !IsWindowVisible(hwnd).as_bool() {
+            return true.into();
+        }
+        let mut title = [0u16; 512];
+        let len = GetWindowTextW(hwnd, &mut title);
+        let title = String::from_utf16_lossy(&title[..len.max(0) as usize]);
+        if title.to_lowercase().contains(&search.name) {
+            search.found = Some(hwnd);
+            return false.into();
+        }
+        true.into()
+    }
+
+    let mut search = Search {
+        name: name.to_lowercase(),
+        found: None,
+    };
+    unsafe {
+        // Stopping early makes EnumWindows report an error, so only `found` matters
+        let _ = EnumWindows(Some(visit), LPARAM(&mut search as *mut Search as isize));
+        let Some(hwnd) = search.found else {
+            return Err(format!("No open window matches \"{}\"", name));
+        };
+        if IsIconic(hwnd).as_bool() {
+            let _ = ShowWindow(hwnd, SW_RESTORE);
+        }
+        if SetForegroundWindow(hwnd).as_bool() {
+            Ok(())
+        } else {
+            Err(format!("Couldn't bring \"{}\" to the front", name))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {} (is it installed?): {}", program, e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn place_front_window(placement: Placement) -> Result<(), String> {
+    if placement == Placement::Maximized {
+        run(
+            "wmctrl",
+            &["-r", ":ACTIVE:", "-b", "add,maximized_vert,maximized_horz"],
+        )?;
+        return Ok(());
+    }
+
+    let geometry = run("xdotool", &["getdisplaygeometry"])?;
+    let mut dimensions = geometry.split_whitespace().map(str::parse::<i32>);
+    let (Some(Ok(width)), Some(Ok(height))) = (dimensions.next(), dimensions.next()) else {
+        return Err(format!("Unexpected display geometry: {}", geometry));
+    };
+    let (x, y, width, height) = placement.frame(0, 0, width, height);
+
+    run(
+        "wmctrl",
+        &[
+            "-r",
+            ":ACTIVE:",
+            "-b",
+            "remove,maximized_vert,maximized_horz",
+        ],
+    )?;
+    run(
+        "wmctrl",
+        &[
+            "-r",
+            ":ACTIVE:",
+            "-e",
+            &format!("0,{},{},{},{}", x, y, width, height),
+        ],
+    )?;
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+pub fn switch_to_app(name: &str) -> Result<(), String> {
+    run("wmctrl", &["-a", name])
+        .map(|_| ())
+        .map_err(|_| format!("No open window matches \"{}\"", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placement_frame() {
+        assert_eq!(
+            Placement::Maximized.frame(0, 25, 1440, 875),
+            (0, 25, 1440, 875)
+        );
+        assert_eq!(
+            Placement::LeftHalf.frame(0, 25, 1440, 875),
+            (0, 25, 720, 875)
+        );
+        assert_eq!(
+            Placement::RightHalf.frame(100, 0, 1921, 1040),
+            (1061, 0, 961, 1040)
+        );
+    }
+}
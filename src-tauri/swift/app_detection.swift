@@ -1,4 +1,5 @@
 import AppKit
+import MediaPlayer
 
 // MARK: - Get Frontmost Application
 
@@ -78,3 +79,135 @@ public func getInstalledApplicationsJson() -> UnsafeMutablePointer<CChar>? {
     
     return strdup(jsonString)
 }
+
+// MARK: - Accessibility Announcements
+
+/// Ask VoiceOver to read a message out, e.g. that recording started.
+/// Ignored when no screen reader is running.
+@_cdecl("post_accessibility_announcement")
+public func postAccessibilityAnnouncement(_ message: UnsafePointer<CChar>) {
+    let text = String(cString: message)
+    DispatchQueue.main.async {
+        NSAccessibility.post(
+            element: NSApp as Any,
+            notification: .announcementRequested,
+            userInfo: [
+                .announcement: text,
+                .priority: NSAccessibilityPriorityLevel.high.rawValue,
+            ]
+        )
+    }
+}
+
+// MARK: - Media Key Tap
+
+private var mediaKeyTap: CFMachPort?
+private var mediaKeyRunLoopSource: CFRunLoopSource?
+private var mediaKeyCallback: (@convention(c) (Bool) -> Void)?
+private var remoteCommandTargets: [(MPRemoteCommand, Any)] = []
+
+/// NX_KEYTYPE_PLAY, sent by the keyboard's play/pause key
+private let playPauseKeyCode = 16
+
+/// Take the play/pause commands that Bluetooth headset buttons send through the
+/// remote command center. Those only go to the app that's playing, so Ramble
+/// claims that role while listening. A button press is reported as a press
+/// followed right away by a release.
+private func startRemoteCommands() {
+    if !remoteCommandTargets.isEmpty {
+        return
+    }
+    let center = MPRemoteCommandCenter.shared()
+    for command in [center.playCommand, center.pauseCommand, center.togglePlayPauseCommand] {
+        command.isEnabled = true
+        let target = command.addTarget { _ in
+            guard let callback = mediaKeyCallback else {
+                return .commandFailed
+            }
+            callback(true)
+            callback(false)
+            return .success
+        }
+        remoteCommandTargets.append((command, target))
+    }
+    let nowPlaying = MPNowPlayingInfoCenter.default()
+    nowPlaying.nowPlayingInfo = [MPMediaItemPropertyTitle: "Ramble"]
+    nowPlaying.playbackState = .paused
+}
+
+private func stopRemoteCommands() {
+    for (command, target) in remoteCommandTargets {
+        command.removeTarget(target)
+    }
+    remoteCommandTargets.removeAll()
+    let nowPlaying = MPNowPlayingInfoCenter.default()
+    nowPlaying.nowPlayingInfo = nil
+    nowPlaying.playbackState = .stopped
+}
+
+/// Intercept the play/pause media key so it doesn't also reach the music player,
+/// and take headset play/pause buttons. `callback` is called with true when the
+/// key is pressed and false when it's released. Returns false if the tap couldn't
+/// be created (no accessibility permission).
+@_cdecl("start_media_key_tap")
+public func startMediaKeyTap(_ callback: @escaping @convention(c) (Bool) -> Void) -> Bool {
+    mediaKeyCallback = callback
+    startRemoteCommands()
+    if mediaKeyTap != nil {
+        return true
+    }
+
+    // NX_SYSDEFINED events carry the media keys
+    let mask = CGEventMask(1 << 14)
+    guard let tap = CGEvent.tapCreate(
+        tap: .cgSessionEventTap,
+        place: .headInsertEventTap,
+        options: .defaultTap,
+        eventsOfInterest: mask,
+        callback: { _, type, event, _ in
+            if type == .tapDisabledByTimeout || type == .tapDisabledByUserInput {
+                if let tap = mediaKeyTap {
+                    CGEvent.tapEnable(tap: tap, enable: true)
+                }
+                return Unmanaged.passUnretained(event)
+            }
+            // Subtype 8 is an auxiliary (media) key
+            guard let nsEvent = NSEvent(cgEvent: event), nsEvent.subtype.rawValue == 8 else {
+                return Unmanaged.passUnretained(event)
+            }
+            let keyCode = (nsEvent.data1 & 0xFFFF0000) >> 16
+            let keyState = (nsEvent.data1 & 0xFF00) >> 8
+            guard keyCode == playPauseKeyCode else {
+                return Unmanaged.passUnretained(event)
+            }
+            mediaKeyCallback?(keyState == 0xA)
+            return nil
+        },
+        userInfo: nil
+    ) else {
+        return false
+    }
+
+    let source = CFMachPortCreateRunLoopSource(kCFAllocatorDefault, tap, 0)
+    CFRunLoopAddSource(CFRunLoopGetMain(), source, .commonModes)
+    CGEvent.tapEnable(tap: tap, enable: true)
+    mediaKeyTap = tap
+    mediaKeyRunLoopSource = source
+    return true
+}
+
+/// Stop intercepting the play/pause media key and headset buttons
+@_cdecl("stop_media_key_tap")
+public func stopMediaKeyTap() {
+    stopRemoteCommands()
+    guard let tap = mediaKeyTap else {
+        return
+    }
+    CGEvent.tapEnable(tap: tap, enable: false)
+    if let source = mediaKeyRunLoopSource {
+        CFRunLoopRemoveSource(CFRunLoopGetMain(), source, .commonModes)
+    }
+    mediaKeyTap = nil
+    mediaKeyRunLoopSource = nil
+    mediaKeyCallback = nil
+}
@@ -1,6 +1,8 @@
 #ifndef app_detection_bridge_h
 #define app_detection_bridge_h
 
+#include <stdbool.h>
+
 // C-compatible function declarations for Swift app detection bridge
 
 #ifdef __cplusplus
@@ -25,6 +27,17 @@ void free_string(char *ptr);
 // Caller must free with free_string()
 char *get_installed_applications_json(void);
 
+// Ask VoiceOver to read a message out
+void post_accessibility_announcement(const char *message);
+
+// Intercept the play/pause media key and headset play/pause buttons, calling
+// back with true on press and false on release. Returns false without
+// accessibility permission.
+bool start_media_key_tap(void (*callback)(bool pressed));
+
+// Stop intercepting the play/pause media key and headset buttons
+void stop_media_key_tap(void);
+
 #ifdef __cplusplus
 }
 #endif
@@ -7,6 +7,7 @@ import Onboarding from "./components/onboarding";
 import { Sidebar, SidebarSection, SECTIONS_CONFIG } from "./components/Sidebar";
 import { useSettings } from "./hooks/useSettings";
 import { commands } from "@/bindings";
+import { listen } from "@tauri-apps/api/event";
 
 const renderSettingsContent = (section: SidebarSection) => {
   const ActiveComponent =
@@ -24,6 +25,16 @@ function App() {
     checkOnboardingStatus();
   }, []);
 
+  // The backend opens a section, e.g. History for a result too long to paste
+  useEffect(() => {
+    const unlisten = listen<SidebarSection>("show-section", (event) => {
+      if (event.payload in SECTIONS_CONFIG) setCurrentSection(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Handle keyboard shortcuts for debug mode toggle
   useEffect(() => {
     const handleKeyDown = (event: KeyboardEvent) => {
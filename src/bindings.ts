@@ -5,7 +5,7 @@
 
 
 export const commands = {
-async changeBinding(id: string, binding: string) : Promise<Result<BindingResponse, string>> {
+async changeBinding(id: string, binding: string) : Promise<Result<BindingResponse, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_binding", { id, binding }) };
 } catch (e) {
@@ -13,7 +13,7 @@ async changeBinding(id: string, binding: string) : Promise<Result<BindingRespons
     else return { status: "error", error: e  as any };
 }
 },
-async resetBinding(id: string) : Promise<Result<BindingResponse, string>> {
+async resetBinding(id: string) : Promise<Result<BindingResponse, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("reset_binding", { id }) };
 } catch (e) {
@@ -21,7 +21,7 @@ async resetBinding(id: string) : Promise<Result<BindingResponse, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changePttSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changePttSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ptt_setting", { enabled }) };
 } catch (e) {
@@ -29,7 +29,7 @@ async changePttSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeAudioFeedbackSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeAudioFeedbackSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_audio_feedback_setting", { enabled }) };
 } catch (e) {
@@ -37,7 +37,7 @@ async changeAudioFeedbackSetting(enabled: boolean) : Promise<Result<null, string
     else return { status: "error", error: e  as any };
 }
 },
-async changeAudioFeedbackVolumeSetting(volume: number) : Promise<Result<null, string>> {
+async changeAudioFeedbackVolumeSetting(volume: number) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_audio_feedback_volume_setting", { volume }) };
 } catch (e) {
@@ -45,7 +45,7 @@ async changeAudioFeedbackVolumeSetting(volume: number) : Promise<Result<null, st
     else return { status: "error", error: e  as any };
 }
 },
-async changeSoundThemeSetting(theme: string) : Promise<Result<null, string>> {
+async changeSoundThemeSetting(theme: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_sound_theme_setting", { theme }) };
 } catch (e) {
@@ -53,7 +53,7 @@ async changeSoundThemeSetting(theme: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeStartHiddenSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeStartHiddenSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_start_hidden_setting", { enabled }) };
 } catch (e) {
@@ -61,7 +61,7 @@ async changeStartHiddenSetting(enabled: boolean) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
-async changeAutostartSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeAutostartSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_autostart_setting", { enabled }) };
 } catch (e) {
@@ -69,7 +69,7 @@ async changeAutostartSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeTranslateToEnglishSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeTranslateToEnglishSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_translate_to_english_setting", { enabled }) };
 } catch (e) {
@@ -77,7 +77,63 @@ async changeTranslateToEnglishSetting(enabled: boolean) : Promise<Result<null, s
     else return { status: "error", error: e  as any };
 }
 },
-async changeSelectedLanguageSetting(language: string) : Promise<Result<null, string>> {
+async changeCodeSwitchingSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_code_switching_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeShowEditsSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_show_edits_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeVerifyRefinementSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_verify_refinement_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeDayContextEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_day_context_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeChapteringMinWordsSetting(words: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_chaptering_min_words_setting", { words }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeHistoryTitlesEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_history_titles_enabled_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeRefineClipboardPasteSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_refine_clipboard_paste_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeSelectedLanguageSetting(language: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_selected_language_setting", { language }) };
 } catch (e) {
@@ -85,7 +141,7 @@ async changeSelectedLanguageSetting(language: string) : Promise<Result<null, str
     else return { status: "error", error: e  as any };
 }
 },
-async changeOverlayPositionSetting(position: string) : Promise<Result<null, string>> {
+async changeOverlayPositionSetting(position: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_overlay_position_setting", { position }) };
 } catch (e) {
@@ -93,7 +149,7 @@ async changeOverlayPositionSetting(position: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
-async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_debug_mode_setting", { enabled }) };
 } catch (e) {
@@ -101,7 +157,7 @@ async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeWordCorrectionThresholdSetting(threshold: number) : Promise<Result<null, string>> {
+async changeWordCorrectionThresholdSetting(threshold: number) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_word_correction_threshold_setting", { threshold }) };
 } catch (e) {
@@ -109,7 +165,7 @@ async changeWordCorrectionThresholdSetting(threshold: number) : Promise<Result<n
     else return { status: "error", error: e  as any };
 }
 },
-async changeQuickChatInitialPromptSetting(prompt: string) : Promise<Result<null, string>> {
+async changeQuickChatInitialPromptSetting(prompt: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_quick_chat_initial_prompt_setting", { prompt }) };
 } catch (e) {
@@ -117,7 +173,7 @@ async changeQuickChatInitialPromptSetting(prompt: string) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async changePasteMethodSetting(method: string) : Promise<Result<null, string>> {
+async changePasteMethodSetting(method: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_paste_method_setting", { method }) };
 } catch (e) {
@@ -125,7 +181,7 @@ async changePasteMethodSetting(method: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, string>> {
+async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_clipboard_handling_setting", { handling }) };
 } catch (e) {
@@ -133,7 +189,7 @@ async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, st
     else return { status: "error", error: e  as any };
 }
 },
-async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_enabled_setting", { enabled }) };
 } catch (e) {
@@ -141,7 +197,7 @@ async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, s
     else return { status: "error", error: e  as any };
 }
 },
-async changePostProcessBaseUrlSetting(providerId: string, baseUrl: string) : Promise<Result<null, string>> {
+async changePostProcessBaseUrlSetting(providerId: string, baseUrl: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_base_url_setting", { providerId, baseUrl }) };
 } catch (e) {
@@ -149,7 +205,7 @@ async changePostProcessBaseUrlSetting(providerId: string, baseUrl: string) : Pro
     else return { status: "error", error: e  as any };
 }
 },
-async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promise<Result<null, string>> {
+async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_api_key_setting", { providerId, apiKey }) };
 } catch (e) {
@@ -157,7 +213,7 @@ async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promi
     else return { status: "error", error: e  as any };
 }
 },
-async changePostProcessModelSetting(providerId: string, model: string) : Promise<Result<null, string>> {
+async changePostProcessModelSetting(providerId: string, model: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_model_setting", { providerId, model }) };
 } catch (e) {
@@ -165,7 +221,7 @@ async changePostProcessModelSetting(providerId: string, model: string) : Promise
     else return { status: "error", error: e  as any };
 }
 },
-async setPostProcessProvider(providerId: string) : Promise<Result<null, string>> {
+async setPostProcessProvider(providerId: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("set_post_process_provider", { providerId }) };
 } catch (e) {
@@ -173,7 +229,7 @@ async setPostProcessProvider(providerId: string) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
-async fetchPostProcessModels(providerId: string) : Promise<Result<string[], string>> {
+async fetchPostProcessModels(providerId: string) : Promise<Result<string[], AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("fetch_post_process_models", { providerId }) };
 } catch (e) {
@@ -181,7 +237,7 @@ async fetchPostProcessModels(providerId: string) : Promise<Result<string[], stri
     else return { status: "error", error: e  as any };
 }
 },
-async addPostProcessPrompt(name: string, prompt: string) : Promise<Result<LLMPrompt, string>> {
+async addPostProcessPrompt(name: string, prompt: string) : Promise<Result<LLMPrompt, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("add_post_process_prompt", { name, prompt }) };
 } catch (e) {
@@ -189,7 +245,7 @@ async addPostProcessPrompt(name: string, prompt: string) : Promise<Result<LLMPro
     else return { status: "error", error: e  as any };
 }
 },
-async updatePostProcessPrompt(id: string, name: string, prompt: string) : Promise<Result<null, string>> {
+async updatePostProcessPrompt(id: string, name: string, prompt: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_post_process_prompt", { id, name, prompt }) };
 } catch (e) {
@@ -197,7 +253,7 @@ async updatePostProcessPrompt(id: string, name: string, prompt: string) : Promis
     else return { status: "error", error: e  as any };
 }
 },
-async deletePostProcessPrompt(id: string) : Promise<Result<null, string>> {
+async deletePostProcessPrompt(id: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_post_process_prompt", { id }) };
 } catch (e) {
@@ -205,7 +261,7 @@ async deletePostProcessPrompt(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, string>> {
+async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("set_post_process_selected_prompt", { id }) };
 } catch (e) {
@@ -213,7 +269,7 @@ async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
+async updateCustomWords(words: string[]) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_custom_words", { words }) };
 } catch (e) {
@@ -221,11 +277,19 @@ async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async updateCasedTerms(terms: string[]) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_cased_terms", { terms }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Temporarily unregister a binding while the user is editing it in the UI.
  * This avoids firing the action while keys are being recorded.
  */
-async suspendBinding(id: string) : Promise<Result<null, string>> {
+async suspendBinding(id: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("suspend_binding", { id }) };
 } catch (e) {
@@ -236,7 +300,7 @@ async suspendBinding(id: string) : Promise<Result<null, string>> {
 /**
  * Re-register the binding after the user has finished editing.
  */
-async resumeBinding(id: string) : Promise<Result<null, string>> {
+async resumeBinding(id: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("resume_binding", { id }) };
 } catch (e) {
@@ -244,7 +308,56 @@ async resumeBinding(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeMuteWhileRecordingSetting(enabled: boolean) : Promise<Result<null, string>> {
+/**
+ * Change the keyboard layout used for shortcut key labels and re-register all bindings
+ */
+async changeKeyboardLayoutSetting(layout: KeyboardLayout) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_keyboard_layout_setting", { layout }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Convert a shortcut recorded from physical key positions into the labels
+ * shown on the user's keyboard, so the stored binding reads as labeled.
+ */
+async shortcutToLayoutLabels(shortcut: string) : Promise<string> {
+    return await TAURI_INVOKE("shortcut_to_layout_labels", { shortcut });
+},
+/**
+ * Recent shortcut events and how each was resolved, oldest first
+ */
+async getShortcutEventLog() : Promise<ShortcutEvent[]> {
+    return await TAURI_INVOKE("get_shortcut_event_log");
+},
+async clearShortcutEventLog() : Promise<void> {
+    return await TAURI_INVOKE("clear_shortcut_event_log");
+},
+async getShortcutsPauseState() : Promise<ShortcutsPauseState> {
+    return await TAURI_INVOKE("get_shortcuts_pause_state");
+},
+/**
+ * Pause or resume all shortcuts. `minutes` overrides the configured pause duration.
+ */
+async setShortcutsPaused(paused: boolean, minutes: number | null) : Promise<Result<ShortcutsPauseState, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_shortcuts_paused", { paused, minutes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeShortcutsPauseMinutesSetting(minutes: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_shortcuts_pause_minutes_setting", { minutes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeMuteWhileRecordingSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_mute_while_recording_setting", { enabled }) };
 } catch (e) {
@@ -252,7 +365,15 @@ async changeMuteWhileRecordingSetting(enabled: boolean) : Promise<Result<null, s
     else return { status: "error", error: e  as any };
 }
 },
-async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeLiveTranscriptionSetting(enabled: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_live_transcription_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_append_trailing_space_setting", { enabled }) };
 } catch (e) {
@@ -260,7 +381,7 @@ async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async changeAppLanguageSetting(language: string) : Promise<Result<null, string>> {
+async changeAppLanguageSetting(language: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_app_language_setting", { language }) };
 } catch (e) {
@@ -268,7 +389,7 @@ async changeAppLanguageSetting(language: string) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
-async changeRambleEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeRambleEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_enabled_setting", { enabled }) };
 } catch (e) {
@@ -276,7 +397,7 @@ async changeRambleEnabledSetting(enabled: boolean) : Promise<Result<null, string
     else return { status: "error", error: e  as any };
 }
 },
-async changeLlmProviderSetting(providerId: string) : Promise<Result<null, string>> {
+async changeLlmProviderSetting(providerId: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_llm_provider_setting", { providerId }) };
 } catch (e) {
@@ -284,7 +405,7 @@ async changeLlmProviderSetting(providerId: string) : Promise<Result<null, string
     else return { status: "error", error: e  as any };
 }
 },
-async changeRambleProviderSetting(providerId: string) : Promise<Result<null, string>> {
+async changeRambleProviderSetting(providerId: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_provider_setting", { providerId }) };
 } catch (e) {
@@ -292,7 +413,7 @@ async changeRambleProviderSetting(providerId: string) : Promise<Result<null, str
     else return { status: "error", error: e  as any };
 }
 },
-async changeRambleModelSetting(model: string) : Promise<Result<null, string>> {
+async changeRambleModelSetting(model: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_model_setting", { model }) };
 } catch (e) {
@@ -300,7 +421,7 @@ async changeRambleModelSetting(model: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async changeRamblePromptSetting(prompt: string) : Promise<Result<null, string>> {
+async changeRamblePromptSetting(prompt: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_prompt_setting", { prompt }) };
 } catch (e) {
@@ -308,7 +429,7 @@ async changeRamblePromptSetting(prompt: string) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
-async changeRambleUseVisionModelSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeRambleUseVisionModelSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_use_vision_model_setting", { enabled }) };
 } catch (e) {
@@ -316,7 +437,7 @@ async changeRambleUseVisionModelSetting(enabled: boolean) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async changeRambleVisionModelSetting(model: string) : Promise<Result<null, string>> {
+async changeRambleVisionModelSetting(model: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_ramble_vision_model_setting", { model }) };
 } catch (e) {
@@ -324,7 +445,7 @@ async changeRambleVisionModelSetting(model: string) : Promise<Result<null, strin
     else return { status: "error", error: e  as any };
 }
 },
-async changeContextChatPromptSetting(prompt: string) : Promise<Result<null, string>> {
+async changeContextChatPromptSetting(prompt: string) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_context_chat_prompt_setting", { prompt }) };
 } catch (e) {
@@ -332,7 +453,7 @@ async changeContextChatPromptSetting(prompt: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
-async changeSystemPromptFileSetting(path: string | null) : Promise<Result<null, string>> {
+async changeSystemPromptFileSetting(path: string | null) : Promise<Result<null, AppError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_system_prompt_file_setting", { path }) };
 } catch (e) {
@@ -340,977 +461,2057 @@ async changeSystemPromptFileSetting(path: string | null) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async resetRamblePromptToDefault() : Promise<Result<string, string>> {
+async changeLocalLlmEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("reset_ramble_prompt_to_default") };
+    return { status: "ok", data: await TAURI_INVOKE("change_local_llm_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeHoldThresholdSetting(thresholdMs: number) : Promise<Result<null, string>> {
+async changeLocalLlmModelPathSetting(path: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_hold_threshold_setting", { thresholdMs }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_local_llm_model_path_setting", { path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeClipboardContentCutoffSetting(cutoff: number) : Promise<Result<null, string>> {
+async resetRamblePromptToDefault() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_clipboard_content_cutoff_setting", { cutoff }) };
+    return { status: "ok", data: await TAURI_INVOKE("reset_ramble_prompt_to_default") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeHoldThresholdSetting(thresholdMs: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_update_checks_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_hold_threshold_setting", { thresholdMs }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changePromptModeSetting(mode: PromptMode) : Promise<Result<null, string>> {
+async changeDoublePressCancelSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_prompt_mode_setting", { mode }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_double_press_cancel_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updatePromptCategory(id: string, prompt: string) : Promise<Result<null, string>> {
+async changeDoublePressCancelWindowSetting(windowMs: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category", { id, prompt }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_double_press_cancel_window_setting", { windowMs }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async resetPromptCategoryToDefault(id: string) : Promise<Result<string, string>> {
+async changeNoSpeechTimeoutSetting(seconds: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("reset_prompt_category_to_default", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_no_speech_timeout_setting", { seconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeDefaultCategorySetting(categoryId: string) : Promise<Result<null, string>> {
+/**
+ * Set how many seconds of silence end a toggle-mode recording for a binding (0 = off)
+ */
+async changeBindingAutoStopSetting(id: string, seconds: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_default_category_setting", { categoryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_binding_auto_stop_setting", { id, seconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Create a new custom prompt category
- */
-async addPromptCategory(name: string, icon: string, prompt: string) : Promise<Result<PromptCategory, string>> {
+async changeBindingTapHoldModeSetting(id: string, mode: TapHoldMode) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_prompt_category", { name, icon, prompt }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_binding_tap_hold_mode_setting", { id, mode }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Delete a custom prompt category
- */
-async deletePromptCategory(id: string) : Promise<Result<null, string>> {
+async changeDailySpendLimitSetting(limit: number | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_prompt_category", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_daily_spend_limit_setting", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Update a category's name and icon (not prompt - use update_prompt_category for that)
- */
-async updatePromptCategoryDetails(id: string, name: string, icon: string) : Promise<Result<null, string>> {
+async changePerRequestSpendLimitSetting(limit: number | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_details", { id, name, icon }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_per_request_spend_limit_setting", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Update a category's model override (None = use default coherent model)
- */
-async updatePromptCategoryModelOverride(id: string, modelId: string | null) : Promise<Result<null, string>> {
+async changeNoiseSuppressionSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_model_override", { id, modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_noise_suppression_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeVoiceCommandsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeAccessibilityAnnouncementsSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_enabled_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_accessibility_announcements_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeVoiceCommandDefaultModelSetting(model: string) : Promise<Result<null, string>> {
+async changeBatterySaverEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_default_model_setting", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_battery_saver_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async resetVoiceCommandsToDefault() : Promise<Result<VoiceCommand[], string>> {
+async changeBatterySaverThresholdSetting(threshold: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("reset_voice_commands_to_default") };
+    return { status: "ok", data: await TAURI_INVOKE("change_battery_saver_threshold_setting", { threshold }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async addVoiceCommand(command: VoiceCommand) : Promise<Result<VoiceCommand[], string>> {
+async changeMediaKeyTriggerSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_voice_command", { command }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_media_key_trigger_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateVoiceCommand(command: VoiceCommand) : Promise<Result<VoiceCommand[], string>> {
+async changeMediaKeyBindingSetting(binding: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_voice_command", { command }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_media_key_binding_setting", { binding }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteVoiceCommand(commandId: string) : Promise<Result<VoiceCommand[], string>> {
+async changeRemoteTriggerEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_voice_command", { commandId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_trigger_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeFillerWordFilterSetting(pattern: string | null) : Promise<Result<null, string>> {
+async changeRemoteTriggerPortSetting(port: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_filler_word_filter_setting", { pattern }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_trigger_port_setting", { port }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeCollapseRepeatedWordsSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeRemoteTriggerLanAccessSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_collapse_repeated_words_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_remote_trigger_lan_access_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeUnknownCommandTemplateSetting(template: string) : Promise<Result<null, string>> {
+async changeSyncFolderSetting(path: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_unknown_command_template_setting", { template }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_sync_folder_setting", { path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeUnknownCommandTerminalSetting(terminal: string) : Promise<Result<null, string>> {
+async changeSyncPassphraseSetting(passphrase: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_unknown_command_terminal_setting", { terminal }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_sync_passphrase_setting", { passphrase }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async triggerUpdateCheck() : Promise<Result<null, string>> {
+async changeClipboardContentCutoffSetting(cutoff: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("trigger_update_check") };
+    return { status: "ok", data: await TAURI_INVOKE("change_clipboard_content_cutoff_setting", { cutoff }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelOperation() : Promise<void> {
-    await TAURI_INVOKE("cancel_operation");
-},
-async pauseOperation() : Promise<boolean> {
-    return await TAURI_INVOKE("pause_operation");
-},
-async resumeOperation() : Promise<boolean> {
-    return await TAURI_INVOKE("resume_operation");
-},
-async getAppDirPath() : Promise<Result<string, string>> {
+async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_dir_path") };
+    return { status: "ok", data: await TAURI_INVOKE("change_update_checks_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAppSettings() : Promise<Result<AppSettings, string>> {
+async changePromptModeSetting(mode: PromptMode) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("change_prompt_mode_setting", { mode }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getDefaultSettings() : Promise<Result<AppSettings, string>> {
+async updatePromptCategory(id: string, prompt: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_default_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category", { id, prompt }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getLogDirPath() : Promise<Result<string, string>> {
+async resetPromptCategoryToDefault(id: string) : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_log_dir_path") };
+    return { status: "ok", data: await TAURI_INVOKE("reset_prompt_category_to_default", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setLogLevel(level: LogLevel) : Promise<Result<null, string>> {
+async changeDefaultCategorySetting(categoryId: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_log_level", { level }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_default_category_setting", { categoryId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openRecordingsFolder() : Promise<Result<null, string>> {
+async changeRecordDetectedAppsSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
+    return { status: "ok", data: await TAURI_INVOKE("change_record_detected_apps_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openLogDir() : Promise<Result<null, string>> {
+/**
+ * Set the apps kept out of the detected apps history, dropping any already in it
+ */
+async changeDetectedAppsExcludedSetting(bundleIds: string[]) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_log_dir") };
+    return { status: "ok", data: await TAURI_INVOKE("change_detected_apps_excluded_setting", { bundleIds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openAppDataDir() : Promise<Result<null, string>> {
+/**
+ * Create a new custom prompt category
+ */
+async addPromptCategory(name: string, icon: string, prompt: string) : Promise<Result<PromptCategory, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
+    return { status: "ok", data: await TAURI_INVOKE("add_prompt_category", { name, icon, prompt }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Opens a URL in the system's default browser
+ * Delete a custom prompt category
  */
-async openExternalUrl(url: string) : Promise<Result<null, string>> {
+async deletePromptCategory(id: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_external_url", { url }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_prompt_category", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
+/**
+ * Create a custom copy of an existing category (built-in or custom) under a new name
+ */
+async duplicatePromptCategory(id: string, newName: string) : Promise<Result<PromptCategory, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
+    return { status: "ok", data: await TAURI_INVOKE("duplicate_prompt_category", { id, newName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, string>> {
+/**
+ * Get the starter templates available for new prompt categories
+ */
+async getPromptCategoryTemplates() : Promise<PromptCategoryTemplate[]> {
+    return await TAURI_INVOKE("get_prompt_category_templates");
+},
+/**
+ * Create a new custom prompt category from a starter template
+ */
+async addPromptCategoryFromTemplate(templateId: string) : Promise<Result<PromptCategory, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_prompt_category_from_template", { templateId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async downloadModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Validate a prompt template, returning warnings the editor can show before saving
+ */
+async validatePrompt(prompt: string) : Promise<PromptWarning[]> {
+    return await TAURI_INVOKE("validate_prompt", { prompt });
+},
+/**
+ * Run one refinement of `sample_text` with `prompt_text` outside the dictation flow.
+ * Falls back to the default coherent model when no model is given.
+ */
+async previewRefinement(promptText: string, sampleText: string, modelId: string | null) : Promise<Result<RefinementPreview, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("preview_refinement", { promptText, sampleText, modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Update a category's name and icon (not prompt - use update_prompt_category for that)
+ */
+async updatePromptCategoryDetails(id: string, name: string, icon: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_details", { id, name, icon }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelDownload(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Update a category's model override (None = use default coherent model)
+ */
+async updatePromptCategoryModelOverride(id: string, modelId: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_model_override", { id, modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setActiveModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Update a category's transcription language (None = use the global language)
+ */
+async updatePromptCategoryLanguage(id: string, language: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_language", { id, language }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getCurrentModel() : Promise<Result<string, string>> {
+/**
+ * Turn topic headings and a table of contents for long refinements on or off for a
+ * category
+ */
+async updatePromptCategoryChaptering(id: string, enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+    return { status: "ok", data: await TAURI_INVOKE("update_prompt_category_chaptering", { id, enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+async changeVoiceCommandsEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async isModelLoading() : Promise<Result<boolean, string>> {
+async changeVoiceCommandDefaultModelSetting(model: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_default_model_setting", { model }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+/**
+ * Set how long to keep listening for a follow-up after a voice command (0 = off)
+ */
+async changeVoiceCommandFollowUpSetting(seconds: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_command_follow_up_setting", { seconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+async resetVoiceCommandsToDefault() : Promise<Result<VoiceCommand[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+    return { status: "ok", data: await TAURI_INVOKE("reset_voice_commands_to_default") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getRecommendedFirstModel() : Promise<Result<string, string>> {
+async addVoiceCommand(command: VoiceCommand) : Promise<Result<VoiceCommand[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_recommended_first_model") };
+    return { status: "ok", data: await TAURI_INVOKE("add_voice_command", { command }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+async updateVoiceCommand(command: VoiceCommand) : Promise<Result<VoiceCommand[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_voice_command", { command }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getMicrophoneMode() : Promise<Result<boolean, string>> {
+async deleteVoiceCommand(commandId: string) : Promise<Result<VoiceCommand[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_voice_command", { commandId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+/**
+ * Logged voice command runs with what matched and what ran, oldest first
+ */
+async getVoiceCommandHistory() : Promise<VoiceCommandRun[]> {
+    return await TAURI_INVOKE("get_voice_command_history");
+},
+async clearVoiceCommandHistory() : Promise<void> {
+    return await TAURI_INVOKE("clear_voice_command_history");
+},
+async addReminder(reminder: Reminder) : Promise<Result<Reminder[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+    return { status: "ok", data: await TAURI_INVOKE("add_reminder", { reminder }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
+async updateReminder(reminder: Reminder) : Promise<Result<Reminder[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_reminder", { reminder }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSelectedMicrophone() : Promise<Result<string, string>> {
+async deleteReminder(reminderId: string) : Promise<Result<Reminder[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_selected_microphone") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_reminder", { reminderId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableOutputDevices() : Promise<Result<AudioDevice[], string>> {
+async addProject(project: Project) : Promise<Result<Project[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
+    return { status: "ok", data: await TAURI_INVOKE("add_project", { project }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setSelectedOutputDevice(deviceName: string) : Promise<Result<null, string>> {
+async updateProject(project: Project) : Promise<Result<Project[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_selected_output_device", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_project", { project }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSelectedOutputDevice() : Promise<Result<string, string>> {
+async deleteProject(projectId: string) : Promise<Result<Project[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_selected_output_device") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_project", { projectId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async playTestSound(soundType: string) : Promise<void> {
-    await TAURI_INVOKE("play_test_sound", { soundType });
-},
-async checkCustomSounds() : Promise<CustomSounds> {
-    return await TAURI_INVOKE("check_custom_sounds");
-},
-async setClamshellMicrophone(deviceName: string) : Promise<Result<null, string>> {
+async setActiveProject(projectId: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_clamshell_microphone", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_active_project", { projectId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getClamshellMicrophone() : Promise<Result<string, string>> {
+async changeFillerWordFilterSetting(pattern: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_clamshell_microphone") };
+    return { status: "ok", data: await TAURI_INVOKE("change_filler_word_filter_setting", { pattern }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async isRecording() : Promise<boolean> {
-    return await TAURI_INVOKE("is_recording");
-},
-async addContextImage(base64: string) : Promise<Result<null, string>> {
+async changeCollapseRepeatedWordsSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_context_image", { base64 }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_collapse_repeated_words_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async copyLastVoiceInteraction() : Promise<Result<null, string>> {
+async changeFocusModeSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("copy_last_voice_interaction") };
+    return { status: "ok", data: await TAURI_INVOKE("change_focus_mode_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
-    await TAURI_INVOKE("set_model_unload_timeout", { timeout });
-},
-async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
+async changeHideWhenPresentingSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
+    return { status: "ok", data: await TAURI_INVOKE("change_hide_when_presenting_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async unloadModelManually() : Promise<Result<null, string>> {
+async changeChatReadAloudSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+    return { status: "ok", data: await TAURI_INVOKE("change_chat_read_aloud_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
+/**
+ * Choose the text-to-speech engine; the next speech loads it
+ */
+async changeTtsBackendSetting(backend: TtsBackend) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_backend_setting", { backend }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
+async changeTtsSystemVoiceSetting(voice: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_system_voice_setting", { voice }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
+/**
+ * Choose the voice for a cloud TTS service
+ */
+async changeTtsCloudVoiceSetting(service: string, voice: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_cloud_voice_setting", { service, voice }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
+async changeMaxPasteCharsSetting(maxChars: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_max_paste_chars_setting", { maxChars }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
+/**
+ * Set how results pasted into a terminal are changed
+ */
+async changeTerminalPasteModeSetting(mode: TerminalPasteMode) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_terminal_paste_mode_setting", { mode }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, string>> {
+/**
+ * Set the apps that results are never pasted into
+ */
+async changePasteBlockedAppsSetting(apps: string[]) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_paste_blocked_apps_setting", { apps }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Checks if the Mac is a laptop by detecting battery presence
- * 
- * This uses pmset to check for battery information.
- * Returns true if a battery is detected (laptop), false otherwise (desktop)
- */
-async isLaptop() : Promise<Result<boolean, string>> {
+async changeUnknownCommandTemplateSetting(template: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("is_laptop") };
+    return { status: "ok", data: await TAURI_INVOKE("change_unknown_command_template_setting", { template }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get the list of known applications with suggested categories
- */
-async getKnownApplications() : Promise<KnownApp[]> {
-    return await TAURI_INVOKE("get_known_applications");
+async changeUnknownCommandTerminalSetting(terminal: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_unknown_command_terminal_setting", { terminal }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get the list of installed applications on the system
- */
-async getInstalledApplications() : Promise<InstalledApp[]> {
-    return await TAURI_INVOKE("get_installed_applications");
+async triggerUpdateCheck() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("trigger_update_check") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get current user-defined app-to-category mappings
- */
-async getAppCategoryMappings() : Promise<AppCategoryMapping[]> {
-    return await TAURI_INVOKE("get_app_category_mappings");
+async cancelOperation() : Promise<void> {
+    await TAURI_INVOKE("cancel_operation");
 },
-/**
- * Set or update an app-to-category mapping
- */
-async setAppCategoryMapping(bundleId: string, displayName: string, categoryId: string) : Promise<Result<null, string>> {
+async pauseOperation() : Promise<boolean> {
+    return await TAURI_INVOKE("pause_operation");
+},
+async resumeOperation() : Promise<boolean> {
+    return await TAURI_INVOKE("resume_operation");
+},
+async getAppDirPath() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_app_category_mapping", { bundleId, displayName, categoryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_app_dir_path") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Remove an app-to-category mapping
- */
-async removeAppCategoryMapping(bundleId: string) : Promise<Result<null, string>> {
+async getAppSettings() : Promise<Result<AppSettings, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("remove_app_category_mapping", { bundleId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Send a chat completion request to the configured LLM provider
- * 
- * # Arguments
- * * `model_id` - Optional model ID to use. Falls back to `default_chat_model_id` if not provided.
- * * `enable_grounding` - Whether to enable web search grounding (supported for Gemini and Anthropic)
- */
-async chatCompletion(messages: ChatMessage[], modelId: string | null, enableGrounding: boolean) : Promise<Result<ChatResponse, string>> {
+async getDefaultSettings() : Promise<Result<AppSettings, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("chat_completion", { messages, modelId, enableGrounding }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_default_settings") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Opens a new chat window, optionally with initial context
- */
-async openChatWindow(context: string | null) : Promise<Result<string, string>> {
+async getLogDirPath() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_chat_window", { context }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_log_dir_path") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Opens a new chat window with initial messages (for forking conversations)
- */
-async openChatWindowWithMessages(messages: ForkMessage[]) : Promise<Result<string, string>> {
+async setLogLevel(level: LogLevel) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_chat_window_with_messages", { messages }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_log_level", { level }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Opens a new chat window loading a saved chat from database
- */
-async openSavedChat(chatId: number) : Promise<Result<string, string>> {
+async openRecordingsFolder() : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_saved_chat", { chatId }) };
+    return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openLogDir() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_log_dir") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openAppDataDir() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Command to capture a screenshot or region, hiding all app windows first
+ * Opens a URL in the system's default browser
  */
-async captureScreenMode(region: boolean) : Promise<Result<string, string>> {
+async openExternalUrl(url: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("capture_screen_mode", { region }) };
+    return { status: "ok", data: await TAURI_INVOKE("open_external_url", { url }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async captureRegionCommand(x: number, y: number, width: number, height: number) : Promise<Result<string, string>> {
+async getAvailableModels() : Promise<Result<ModelInfo[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("capture_region_command", { x, y, width, height }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openClippingTool() : Promise<Result<null, string>> {
+async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_clipping_tool") };
+    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async restoreAppVisibility() : Promise<Result<null, string>> {
+async downloadModel(modelId: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("restore_app_visibility") };
+    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Retrieves and clears any pending clip attachment
- * Called by ChatWindow to get captured images
- */
-async getPendingClip() : Promise<string | null> {
-    return await TAURI_INVOKE("get_pending_clip");
+async deleteModel(modelId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get all configured LLM providers, deduplicated by ID
- */
-async getLlmProviders() : Promise<LLMProvider[]> {
-    return await TAURI_INVOKE("get_llm_providers");
+async cancelDownload(modelId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get all configured LLM models with their provider info, deduplicated by (provider_id, model_id)
- */
-async getLlmModels() : Promise<LLMModel[]> {
-    return await TAURI_INVOKE("get_llm_models");
+async setActiveModel(modelId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Update an LLM provider's API key
- */
-async updateProviderApiKey(providerId: string, apiKey: string) : Promise<Result<null, string>> {
+async getCurrentModel() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_provider_api_key", { providerId, apiKey }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Save (create or update) an LLM provider
- */
-async saveLlmProvider(provider: LLMProvider) : Promise<Result<LLMProvider, string>> {
+async getTranscriptionModelStatus() : Promise<Result<string | null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_llm_provider", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Delete an LLM provider (any provider can be deleted)
- */
-async deleteLlmProvider(providerId: string) : Promise<Result<null, string>> {
+async isModelLoading() : Promise<Result<boolean, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_llm_provider", { providerId }) };
+    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Save (create or update) an LLM model
+ * Whether a local model can be run, i.e. llama-server is bundled or installed
  */
-async saveLlmModel(model: LLMModel) : Promise<Result<LLMModel, string>> {
+async isLocalLlmAvailable() : Promise<boolean> {
+    return await TAURI_INVOKE("is_local_llm_available");
+},
+async hasAnyModelsAvailable() : Promise<Result<boolean, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_llm_model", { model }) };
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Delete an LLM model
- */
-async deleteLlmModel(modelId: string) : Promise<Result<null, string>> {
+async hasAnyModelsOrDownloads() : Promise<Result<boolean, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_llm_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Set the default model for a specific feature
- */
-async setDefaultModel(feature: string, modelId: string | null) : Promise<Result<null, string>> {
+async getRecommendedFirstModel() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_default_model", { feature, modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_recommended_first_model") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get default model IDs for all features
+ * Set (or clear, with an empty key) the API key for a cloud transcription service.
+ * Cloud models become available as soon as their service has a key.
  */
-async getDefaultModels() : Promise<DefaultModels> {
-    return await TAURI_INVOKE("get_default_models");
+async setSttApiKey(service: string, apiKey: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_stt_api_key", { service, apiKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Get the OpenAI reasoning effort setting
- */
-async getOpenaiReasoningEffort() : Promise<string> {
-    return await TAURI_INVOKE("get_openai_reasoning_effort");
+async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 },
-/**
- * Set the OpenAI reasoning effort setting
- * Valid values: "none", "low", "medium", "high", "xhigh"
- */
-async setOpenaiReasoningEffort(effort: string) : Promise<Result<null, string>> {
+async getMicrophoneMode() : Promise<Result<boolean, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_openai_reasoning_effort", { effort }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Refresh models for ALL configured providers with API keys or OAuth
- * Returns the complete updated list of models
+ * Whether the wake word's models are installed, so it can be turned on
  */
-async refreshAllModels() : Promise<Result<LLMModel[], string>> {
+async isWakeWordAvailable() : Promise<boolean> {
+    return await TAURI_INVOKE("is_wake_word_available");
+},
+async changeWakeWordEnabledSetting(enabled: boolean) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("refresh_all_models") };
+    return { status: "ok", data: await TAURI_INVOKE("change_wake_word_enabled_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveChat(title: string | null, messages: ChatMessage[]) : Promise<Result<number, string>> {
+async changeWakeWordBindingSetting(binding: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_chat", { title, messages }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_wake_word_binding_setting", { binding }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateChat(id: number, messages: ChatMessage[]) : Promise<Result<null, string>> {
+async changeWakeWordThresholdSetting(threshold: number) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_chat", { id, messages }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_wake_word_threshold_setting", { threshold }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getChat(id: number) : Promise<Result<SavedChat | null, string>> {
+async changeWakeWordModelPathSetting(path: string | null) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_chat", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_wake_word_model_path_setting", { path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async listSavedChats() : Promise<Result<ChatSummary[], string>> {
+async getAvailableMicrophones() : Promise<Result<AudioDevice[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("list_saved_chats") };
+    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteSavedChat(id: number) : Promise<Result<null, string>> {
+async setSelectedMicrophone(deviceName: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_saved_chat", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async generateChatTitle(userMessage: string, assistantResponse: string) : Promise<Result<string, string>> {
+async getSelectedMicrophone() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("generate_chat_title", { userMessage, assistantResponse }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_microphone") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateChatTitle(id: number, title: string) : Promise<Result<null, string>> {
+async getAvailableOutputDevices() : Promise<Result<AudioDevice[], AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_chat_title", { id, title }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async speakText(text: string) : Promise<Result<null, string>> {
+async setSelectedOutputDevice(deviceName: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("speak_text", { text }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_output_device", { deviceName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async stopTts() : Promise<Result<null, string>> {
+async getSelectedOutputDevice() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("stop_tts") };
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_output_device") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Start the OAuth flow for a provider
- * 
- * Returns the authorization URL to open in the browser.
- */
-async oauthStartAuth(provider: string) : Promise<Result<AuthStartResult, string>> {
+async playTestSound(soundType: string) : Promise<void> {
+    await TAURI_INVOKE("play_test_sound", { soundType });
+},
+async checkCustomSounds() : Promise<CustomSounds> {
+    return await TAURI_INVOKE("check_custom_sounds");
+},
+async setClamshellMicrophone(deviceName: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_start_auth", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_clamshell_microphone", { deviceName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Wait for and complete the OAuth callback
- * 
- * This should be called after oauth_start_auth. It waits for the callback,
- * exchanges the code for tokens, and stores them securely.
- */
-async oauthAwaitCallback(provider: string, state: string) : Promise<Result<AuthResult, string>> {
+async getClamshellMicrophone() : Promise<Result<string, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_await_callback", { provider, state }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_clamshell_microphone") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get OAuth status for a provider
- */
-async oauthGetStatus(provider: string) : Promise<Result<OAuthStatus, string>> {
+async isRecording() : Promise<boolean> {
+    return await TAURI_INVOKE("is_recording");
+},
+async addContextImage(base64: string) : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_get_status", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_context_image", { base64 }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Log out from OAuth for a provider
- */
-async oauthLogout(provider: string) : Promise<Result<null, string>> {
+async copyLastVoiceInteraction() : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_logout", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("copy_last_voice_interaction") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Refresh the OAuth token for a provider
- */
-async oauthRefreshToken(provider: string) : Promise<Result<boolean, string>> {
+async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
+    await TAURI_INVOKE("set_model_unload_timeout", { timeout });
+},
+async getModelLoadStatus() : Promise<Result<ModelLoadStatus, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_refresh_token", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get current access token for a provider (for making API calls)
- */
-async oauthGetAccessToken(provider: string) : Promise<Result<string | null, string>> {
+async unloadModelManually() : Promise<Result<null, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_get_access_token", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get request headers for making authenticated API calls
+ * Transcribe an existing audio file (e.g. a voice memo) and save it to history,
+ * optionally refining it with the coherent prompt
  */
-async oauthGetRequestHeaders(provider: string) : Promise<Result<Partial<{ [key in string]: string }>, string>> {
+async transcribeAudioFile(path: string, refine: boolean) : Promise<Result<ImportedTranscription, AppError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("oauth_get_request_headers", { provider }) };
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_audio_file", { path, refine }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Check if OAuth is supported for a provider ID
- */
-async oauthSupportsProvider(providerId: string) : Promise<boolean> {
-    return await TAURI_INVOKE("oauth_supports_provider", { providerId });
+async getHistoryEntries() : Promise<Result<HistoryEntry[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
+},
+async toggleHistoryEntrySaved(id: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
-
-/** user-defined events **/
+},
+async getAudioFilePath(fileName: string) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Word timings of a history entry's transcription, for highlighting during playback.
+ * Empty for entries transcribed before timings were recorded.
+ */
+async getHistoryWordTimings(id: number) : Promise<Result<WordTiming[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_word_timings", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * How a history entry's refinement differs from its raw transcription, for the
+ * track-changes view. None unless the entry was refined with show edits enabled.
+ */
+async getHistoryEdits(id: number) : Promise<Result<EditAlignment | null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_edits", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Write the entries in `range` to `path`, oldest first. Returns how many were exported.
+ */
+async exportHistory(format: HistoryExportFormat, range: HistoryExportRange, path: string) : Promise<Result<number, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_history", { format, range, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * A snippet of one entry for bug reports or team chats, with emails, phone numbers
+ * and similar details redacted when `redact` is set. Written to `path` when one is
+ * given and copied to the clipboard otherwise. Returns the snippet.
+ */
+async shareHistoryEntry(id: number, redact: boolean, format: ShareFormat, path: string | null) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("share_history_entry", { id, redact, format, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteHistoryEntry(id: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Summarize an entry's transcription in `style` and store the summary with it,
+ * replacing any earlier one. Meant for long recordings like meetings and lectures.
+ */
+async summarizeHistoryEntry(id: number, style: SummaryStyle) : Promise<Result<EntrySummary, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("summarize_history_entry", { id, style }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * History entries closest in meaning to `query`, closest first. Entries not yet
+ * embedded are embedded first, which takes a while on the first search.
+ */
+async semanticSearchHistory(query: string, limit: number | null) : Promise<Result<SemanticMatch[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("semantic_search_history", { query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateHistoryLimit(limit: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if the Mac is a laptop by detecting battery presence
+ * 
+ * This uses pmset to check for battery information.
+ * Returns true if a battery is detected (laptop), false otherwise (desktop)
+ */
+async isLaptop() : Promise<Result<boolean, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_laptop") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Whether the battery saver is on right now, for the settings page
+ */
+async isBatterySaverActive() : Promise<Result<boolean, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_battery_saver_active") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Open pairing and get the code a device must send, replacing any earlier code
+ */
+async startRemotePairing() : Promise<Result<RemotePairing, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_remote_pairing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelRemotePairing() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_remote_pairing") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Forget a paired device, so its token stops working
+ */
+async removeRemoteDevice(id: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_remote_device", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async syncSettingsNow() : Promise<Result<SyncReport, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_settings_now") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get the list of known applications with suggested categories
+ */
+async getKnownApplications() : Promise<KnownApp[]> {
+    return await TAURI_INVOKE("get_known_applications");
+},
+/**
+ * Get the list of installed applications on the system
+ */
+async getInstalledApplications() : Promise<InstalledApp[]> {
+    return await TAURI_INVOKE("get_installed_applications");
+},
+/**
+ * Get current user-defined app-to-category mappings
+ */
+async getAppCategoryMappings() : Promise<AppCategoryMapping[]> {
+    return await TAURI_INVOKE("get_app_category_mappings");
+},
+/**
+ * Set or update an app-to-category mapping
+ */
+async setAppCategoryMapping(bundleId: string, displayName: string, categoryId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_app_category_mapping", { bundleId, displayName, categoryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove an app-to-category mapping
+ */
+async removeAppCategoryMapping(bundleId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_app_category_mapping", { bundleId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Write the app-category mappings, and optionally the detected apps history, to
+ * `path` as JSON. Returns how many mappings were exported.
+ */
+async exportAppMappings(path: string, includeDetectedApps: boolean) : Promise<Result<number, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_app_mappings", { path, includeDetectedApps }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merge mappings from a file written by `export_app_mappings`. Imported mappings
+ * replace existing ones for the same app; mappings to categories that don't
+ * exist here are skipped.
+ */
+async importAppMappings(path: string, includeDetectedApps: boolean) : Promise<Result<AppMappingsImportResult, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_app_mappings", { path, includeDetectedApps }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Forget every app recorded while dictating. Mappings are kept.
+ */
+async clearDetectedAppsHistory() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_detected_apps_history") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Categories and app mappings suggested from the apps installed on this computer,
+ * for the first-run setup. Nothing is saved until `apply_suggested_setup`.
+ */
+async generateSuggestedSetup() : Promise<SuggestedSetup> {
+    return await TAURI_INVOKE("generate_suggested_setup");
+},
+/**
+ * Save a setup from `generate_suggested_setup`, skipping anything that was added
+ * since it was generated
+ */
+async applySuggestedSetup(setup: SuggestedSetup) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("apply_suggested_setup", { setup }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Write voice commands to `path` as a pack named `name`. With no `command_ids`,
+ * every custom command is exported; built-in commands never are. Returns how many
+ * commands were exported.
+ */
+async exportVoiceCommandPack(path: string, name: string, commandIds: string[]) : Promise<Result<number, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_voice_command_pack", { path, name, commandIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Show what importing the pack at `path` with `conflict` would do, with the script
+ * of every command, so the user can check them before confirming the import
+ */
+async previewVoiceCommandPack(path: string, conflict: PackConflict) : Promise<Result<PackPreview, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("preview_voice_command_pack", { path, conflict }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merge commands from a pack written by `export_voice_command_pack`, resolving
+ * commands that share an id or trigger phrase with an existing one by `conflict`.
+ * `confirmation` comes from `preview_voice_command_pack`, so only a pack whose
+ * scripts were shown is imported.
+ */
+async importVoiceCommandPack(path: string, conflict: PackConflict, confirmation: string) : Promise<Result<PackImportResult, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_voice_command_pack", { path, conflict, confirmation }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Send a chat completion request to the configured LLM provider
+ * 
+ * # Arguments
+ * * `model_id` - Optional model ID to use. Falls back to `default_chat_model_id` if not provided.
+ * * `enable_grounding` - Whether to enable web search grounding (supported for Gemini and Anthropic)
+ * * `enable_tools` - Whether the model may call the tools in `chat_tools` (shell commands,
+ * web fetch, reading the selection). Not supported for Codex.
+ * * `stream_id` - If set, response text is emitted as `chat-chunk` events tagged with it
+ * while it streams in. Native Anthropic and Codex requests aren't streamed, nor are
+ * OpenAI-compatible requests with tools.
+ */
+async chatCompletion(messages: ChatMessage[], modelId: string | null, enableGrounding: boolean, enableTools: boolean, streamId: string | null) : Promise<Result<ChatResponse, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("chat_completion", { messages, modelId, enableGrounding, enableTools, streamId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Read a local text, Markdown or PDF file to attach to a chat message. The text is
+ * extracted and split into chunks here, and sent with the message's `files`.
+ */
+async attachChatFile(path: string) : Promise<Result<FileAttachment, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("attach_chat_file", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Generate images from a prompt with an image model
+ * 
+ * # Arguments
+ * * `model_id` - Optional model ID to use. Falls back to `default_chat_model_id` if not provided.
+ * * `images` - Base64 PNG images to edit or draw from (Gemini only)
+ */
+async generateImage(prompt: string, modelId: string | null, images: string[] | null) : Promise<Result<GeneratedImages, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("generate_image", { prompt, modelId, images }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens a new chat window, optionally with initial context
+ */
+async openChatWindow(context: string | null) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_chat_window", { context }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens a new chat window loading a saved chat from database
+ */
+async openSavedChat(chatId: number) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_saved_chat", { chatId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Command to capture a screenshot or region, hiding all app windows first
+ */
+async captureScreenMode(region: boolean) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("capture_screen_mode", { region }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Capture a screen region into the pending clip queue. With `keep_open` the clipping
+ * overlay comes back afterwards so more regions can be captured in the same session.
+ */
+async captureRegionCommand(x: number, y: number, width: number, height: number, keepOpen: boolean) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("capture_region_command", { x, y, width, height, keepOpen }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openClippingTool() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_clipping_tool") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async restoreAppVisibility() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_app_visibility") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves and clears all pending clip attachments, oldest first
+ * Called by ChatWindow to get captured images
+ */
+async getPendingClips() : Promise<string[]> {
+    return await TAURI_INVOKE("get_pending_clips");
+},
+/**
+ * Recent region and full-screen captures, newest first
+ */
+async listCaptures() : Promise<Result<CaptureEntry[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_captures") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * A saved capture as a base64 PNG, to attach it to a chat without recapturing
+ */
+async loadCapture(id: number) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_capture", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete a saved capture and its files
+ */
+async deleteCapture(id: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_capture", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get all configured LLM providers, deduplicated by ID
+ */
+async getLlmProviders() : Promise<LLMProvider[]> {
+    return await TAURI_INVOKE("get_llm_providers");
+},
+/**
+ * Get all configured LLM models with their provider info, deduplicated by (provider_id, model_id)
+ */
+async getLlmModels() : Promise<LLMModel[]> {
+    return await TAURI_INVOKE("get_llm_models");
+},
+/**
+ * Update an LLM provider's API key
+ */
+async updateProviderApiKey(providerId: string, apiKey: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_provider_api_key", { providerId, apiKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save (create or update) an LLM provider
+ */
+async saveLlmProvider(provider: LLMProvider) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_llm_provider", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete an LLM provider (any provider can be deleted)
+ */
+async deleteLlmProvider(providerId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_llm_provider", { providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save (create or update) a named credential for a provider. Saving the active
+ * credential applies the change immediately.
+ */
+async saveProviderCredential(providerId: string, credential: ProviderCredential) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_provider_credential", { providerId, credential }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete a saved credential. Deleting the active one keeps its key in use until
+ * another credential is selected.
+ */
+async deleteProviderCredential(providerId: string, credentialId: string) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_provider_credential", { providerId, credentialId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Switch a provider to one of its saved credentials
+ */
+async setActiveProviderCredential(providerId: string, credentialId: string) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_active_provider_credential", { providerId, credentialId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace a provider's custom request headers. Blank names are dropped.
+ */
+async updateProviderHeaders(providerId: string, headers: Partial<{ [key in string]: string }>) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_provider_headers", { providerId, headers }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Mark a provider as free so its calls don't count toward the spend limits
+ */
+async setProviderFree(providerId: string, free: boolean) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_provider_free", { providerId, free }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Latest health check results for self-hosted providers
+ */
+async getProviderHealth() : Promise<ProviderHealth[]> {
+    return await TAURI_INVOKE("get_provider_health");
+},
+/**
+ * Probe a self-hosted provider now, e.g. after the user starts its server
+ */
+async checkProviderHealth(providerId: string) : Promise<Result<ProviderHealth, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_provider_health", { providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Scan localhost for LM Studio, llama.cpp and Ollama servers that aren't providers yet
+ */
+async discoverLocalProviders() : Promise<DiscoveredServer[]> {
+    return await TAURI_INVOKE("discover_local_providers");
+},
+/**
+ * Create a provider for a discovered local server, with all of its models enabled
+ */
+async addDiscoveredProvider(server: DiscoveredServer) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_discovered_provider", { server }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save (create or update) an LLM model
+ */
+async saveLlmModel(model: LLMModel) : Promise<Result<LLMModel, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_llm_model", { model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set or clear a model's OpenRouter fallback models and provider preferences
+ */
+async setModelOpenrouterRouting(modelId: string, routing: OpenRouterRouting | null) : Promise<Result<LLMModel, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_model_openrouter_routing", { modelId, routing }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set or clear a model's Gemini thinking budget and safety threshold
+ */
+async setModelGeminiOptions(modelId: string, options: GeminiOptions | null) : Promise<Result<LLMModel, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_model_gemini_options", { modelId, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set or clear a model's reasoning effort, thinking budget and trace hiding
+ */
+async setModelReasoning(modelId: string, reasoning: ReasoningOptions | null) : Promise<Result<LLMModel, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_model_reasoning", { modelId, reasoning }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete an LLM model
+ */
+async deleteLlmModel(modelId: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_llm_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the default model for a specific feature
+ */
+async setDefaultModel(feature: string, modelId: string | null) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_default_model", { feature, modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the models refinement falls back to, in order, when the coherent model's
+ * provider is rate limited, overloaded or down
+ */
+async setCoherentFallbackModels(modelIds: string[]) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_coherent_fallback_models", { modelIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get default model IDs for all features
+ */
+async getDefaultModels() : Promise<DefaultModels> {
+    return await TAURI_INVOKE("get_default_models");
+},
+/**
+ * Get the OpenAI reasoning effort setting
+ */
+async getOpenaiReasoningEffort() : Promise<string> {
+    return await TAURI_INVOKE("get_openai_reasoning_effort");
+},
+/**
+ * Set the OpenAI reasoning effort setting
+ * Valid values: "none", "low", "medium", "high", "xhigh"
+ */
+async setOpenaiReasoningEffort(effort: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_openai_reasoning_effort", { effort }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get the curated provider presets available for one-click setup
+ */
+async getProviderPresets() : Promise<ProviderPreset[]> {
+    return await TAURI_INVOKE("get_provider_presets");
+},
+/**
+ * Create (or update) a provider from a preset along with its default models.
+ * Existing models are left untouched so user-toggled `enabled` state is preserved.
+ */
+async applyProviderPreset(presetId: string, apiKey: string) : Promise<Result<LLMProvider, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("apply_provider_preset", { presetId, apiKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List soft-deleted items, most recently deleted first
+ */
+async listDeletedItems() : Promise<DeletedItem[]> {
+    return await TAURI_INVOKE("list_deleted_items");
+},
+/**
+ * Estimated LLM spend today and this month, from the usage ledger
+ */
+async getUsageSummary() : Promise<Result<UsageSummary, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_usage_summary") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tokens and estimated cost per provider/model for a month ("YYYY-MM", default
+ * the current one)
+ */
+async getUsageReport(month: string | null) : Promise<Result<UsageReport, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_usage_report", { month }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restore a soft-deleted item by its trash entry ID
+ */
+async restoreItem(id: string) : Promise<Result<DeletedItem, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_item", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Permanently delete a single item from the trash
+ */
+async purgeDeletedItem(id: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("purge_deleted_item", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Permanently delete everything in the trash
+ */
+async emptyTrash() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("empty_trash") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set how many days deleted items are kept, purging anything now past the new limit
+ */
+async changeTrashRetentionDaysSetting(days: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_trash_retention_days_setting", { days }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Refresh models for ALL configured providers with API keys or OAuth
+ * Returns the complete updated list of models
+ */
+async refreshAllModels() : Promise<Result<LLMModel[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("refresh_all_models") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveChat(title: string | null, messages: ChatMessage[], modelId: string | null) : Promise<Result<number, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_chat", { title, messages, modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateChat(id: number, messages: ChatMessage[], modelId: string | null) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_chat", { id, messages, modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getChat(id: number) : Promise<Result<SavedChat | null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_chat", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listSavedChats() : Promise<Result<ChatSummary[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_saved_chats") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteSavedChat(id: number) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_saved_chat", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save the start of a conversation as a new chat and open it in a window, so
+ * the fork outlives the window it came from
+ */
+async forkChat(title: string | null, messages: ChatMessage[], modelId: string | null) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fork_chat", { title, messages, modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Title a new chat from its first exchange, with the title model when one is set
+ * and the chat model otherwise. Falls back to a title made from the user's message
+ * when the model can't be reached.
+ */
+async generateChatTitle(userMessage: string, assistantResponse: string) : Promise<Result<string, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("generate_chat_title", { userMessage, assistantResponse }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateChatTitle(id: number, title: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_chat_title", { id, title }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Read text aloud, such as a chat reply; Markdown is reduced to plain text first
+ */
+async speakText(text: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("speak_text", { text }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopTts() : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_tts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Voices the operating system's synthesizer offers, for the system backend
+ */
+async listSystemVoices() : Promise<Result<TtsVoice[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_system_voices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Voices a cloud TTS service offers. ElevenLabs lists the voices in the account,
+ * so it needs the service's API key
+ */
+async listCloudTtsVoices(service: string) : Promise<Result<TtsVoice[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_cloud_tts_voices", { service }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set (or clear, with an empty key) the API key for a cloud TTS service
+ */
+async setTtsApiKey(service: string, apiKey: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_tts_api_key", { service, apiKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Start the OAuth flow for a provider
+ * 
+ * Returns the authorization URL to open in the browser.
+ */
+async oauthStartAuth(provider: string) : Promise<Result<AuthStartResult, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_start_auth", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Wait for and complete the OAuth callback
+ * 
+ * This should be called after oauth_start_auth. It waits for the callback,
+ * exchanges the code for tokens, and stores them securely.
+ */
+async oauthAwaitCallback(provider: string, state: string) : Promise<Result<AuthResult, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_await_callback", { provider, state }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get OAuth status for a provider
+ */
+async oauthGetStatus(provider: string) : Promise<Result<OAuthStatus, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_get_status", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Log out from OAuth for a provider
+ */
+async oauthLogout(provider: string) : Promise<Result<null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_logout", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Refresh the OAuth token for a provider
+ */
+async oauthRefreshToken(provider: string) : Promise<Result<boolean, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_refresh_token", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get current access token for a provider (for making API calls)
+ */
+async oauthGetAccessToken(provider: string) : Promise<Result<string | null, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_get_access_token", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get request headers for making authenticated API calls
+ */
+async oauthGetRequestHeaders(provider: string) : Promise<Result<Partial<{ [key in string]: string }>, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_get_request_headers", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check if OAuth is supported for a provider ID
+ */
+async oauthSupportsProvider(providerId: string) : Promise<boolean> {
+    return await TAURI_INVOKE("oauth_supports_provider", { providerId });
+},
+/**
+ * Find Gemini CLI / Codex CLI sign-ins whose tokens can be reused
+ */
+async oauthDetectCliCredentials() : Promise<Result<CliCredentials[], AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_detect_cli_credentials") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Import a CLI's tokens for a provider instead of running the browser flow
+ */
+async oauthImportCliCredentials(provider: string) : Promise<Result<AuthResult, AppError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("oauth_import_cli_credentials", { provider }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
 
 
+export const events = __makeEvents__<{
+appLanguageChanged: AppLanguageChanged,
+batterySaverChanged: BatterySaverChanged,
+categoryDetected: CategoryDetected,
+focusModeChanged: FocusModeChanged,
+modeDetermined: ModeDetermined,
+promptModeChanged: PromptModeChanged,
+refinementProgress: RefinementProgress,
+remoteDevicePaired: RemoteDevicePaired,
+settingsChanged: SettingsChanged,
+settingsSynced: SettingsSynced,
+visionCaptured: VisionCaptured
+}>({
+appLanguageChanged: "app-language-changed",
+batterySaverChanged: "battery-saver-changed",
+categoryDetected: "category-detected",
+focusModeChanged: "focus-mode-changed",
+modeDetermined: "mode-determined",
+promptModeChanged: "prompt-mode-changed",
+refinementProgress: "refinement-progress",
+remoteDevicePaired: "remote-device-paired",
+settingsChanged: "settings-changed",
+settingsSynced: "settings-synced",
+visionCaptured: "vision-captured"
+})
 
 /** user-defined constants **/
 
@@ -1319,312 +2520,1480 @@ async oauthSupportsProvider(providerId: string) : Promise<boolean> {
 /** user-defined types **/
 
 /**
- * Maps an application to a category
+ * Maps an application to a category
+ */
+export type AppCategoryMapping = { bundle_identifier: string; display_name: string; category_id: string }
+/**
+ * A failed command: a code for the frontend to act on, the message to show and,
+ * where there's an obvious fix, a hint telling the user what to do
+ */
+export type AppError = { code: ErrorCode; message: string; hint: string | null; 
+/**
+ * Whether trying the same request again may succeed
+ */
+retryable: boolean }
+/**
+ * What an import changed
+ */
+/**
+ * The app language was changed, so every window (including the overlay) switches to it
+ */
+export type AppLanguageChanged = string
+export type AppMappingsImportResult = { 
+/**
+ * Mappings added or updated
+ */
+mappings: number; 
+/**
+ * Detected apps added to the history
+ */
+detected_apps: number; 
+/**
+ * Apps whose mapping was skipped because its category doesn't exist here
+ */
+skipped: string[] }
+export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; 
+/**
+ * Detect the language of each phrase separately, for speech that mixes languages
+ */
+code_switching?: boolean; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; 
+/**
+ * Terms whose exact casing is enforced after transcription and refinement
+ */
+cased_terms?: string[]; 
+/**
+ * Ask the refiner to cite substantial removals and keep a raw-to-refined diff
+ */
+show_edits?: boolean; 
+/**
+ * Check refinements for names and numbers that weren't in the transcription
+ */
+verify_refinement?: boolean; 
+/**
+ * Give the refiner today's earlier dictations so names and references stay
+ * consistent across the day
+ */
+day_context_enabled?: boolean; 
+/**
+ * Words a refinement needs before categories with chaptering get topic headings
+ */
+chaptering_min_words?: number; 
+/**
+ * Give new history entries a short title describing what was said
+ */
+history_titles_enabled?: boolean; 
+/**
+ * Paste the refined clipboard text; when off, the refinement replaces the
+ * clipboard content instead
+ */
+refine_clipboard_paste?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; 
+/**
+ * All configured LLM providers (OpenAI, Anthropic, OpenRouter, custom)
+ */
+llm_providers?: LLMProvider[]; 
+/**
+ * All configured models across all providers
+ */
+llm_models?: LLMModel[]; 
+/**
+ * Default model ID for chat windows
+ */
+default_chat_model_id?: string | null; 
+/**
+ * Default model ID for coherent/ramble mode
+ */
+default_coherent_model_id?: string | null; 
+/**
+ * Models to retry refinement with, in order, when the coherent model's provider
+ * is rate limited, overloaded or down
+ */
+coherent_fallback_model_ids?: string[]; 
+/**
+ * Refine with the embedded llama.cpp model when no coherent model is configured
+ * or every provider fails
+ */
+local_llm_enabled?: boolean; 
+/**
+ * GGUF file for the embedded model
+ */
+local_llm_model_path?: string | null; 
+/**
+ * Default model ID for voice commands
+ */
+default_voice_model_id?: string | null; 
+/**
+ * Model for history entry and chat titles. History titles are made from the
+ * text itself when unset, and chats fall back to the chat model.
+ */
+default_title_model_id?: string | null; 
+/**
+ * OpenAI OAuth reasoning effort level (none, low, medium, high, xhigh)
+ */
+openai_reasoning_effort?: string; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; 
+/**
+ * Prompts for coherent mode (transforms rambling speech to clean text)
+ */
+coherent_prompts?: LLMPrompt[]; coherent_selected_prompt_id?: string | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; 
+/**
+ * Whether coherent mode (LLM refinement) is enabled
+ */
+coherent_enabled?: boolean; 
+/**
+ * Whether to use vision model when screenshots are available
+ */
+coherent_use_vision?: boolean; 
+/**
+ * Threshold in milliseconds for tap vs hold detection (smart PTT)
+ */
+hold_threshold_ms?: number; 
+/**
+ * Current prompt mode (Dynamic, Low, Medium, High)
+ */
+prompt_mode?: PromptMode; 
+/**
+ * Prompt categories (built-in + user-defined)
+ */
+prompt_categories?: PromptCategory[]; 
+/**
+ * Application to category mappings
+ */
+app_category_mappings?: AppCategoryMapping[]; 
+/**
+ * History of detected applications (for dropdown suggestions)
+ */
+detected_apps_history?: DetectedApp[]; 
+/**
+ * Whether apps detected during dictation are added to `detected_apps_history`
+ */
+record_detected_apps?: boolean; 
+/**
+ * Bundle identifiers never added to `detected_apps_history` (e.g. private browsers)
+ */
+detected_apps_excluded?: string[]; 
+/**
+ * Default category for apps not in known_apps or user mappings
+ */
+default_category_id?: string; 
+/**
+ * Whether voice commands are enabled
+ */
+voice_commands_enabled?: boolean; 
+/**
+ * Default model for voice command execution
+ */
+voice_command_default_model?: string; 
+/**
+ * Seconds to keep listening for a follow-up after a voice command completes
+ * (0 = off)
+ */
+voice_command_follow_up_seconds?: number; 
+/**
+ * User-defined voice commands
+ */
+voice_commands?: VoiceCommand[]; 
+/**
+ * Scheduled dictation reminders
+ */
+reminders?: Reminder[]; 
+/**
+ * Running voice timers, soonest first
+ */
+timers?: VoiceTimer[]; 
+/**
+ * Projects that can be switched to by voice
+ */
+projects?: Project[]; 
+/**
+ * Project whose vocabulary is in use and whose name fills `${project}`
+ */
+active_project_id?: string | null; 
+/**
+ * Distraction-free dictation: raw output only, no sounds, no chat windows and
+ * no LLM calls, with just a small dot in the overlay while recording
+ */
+focus_mode?: boolean; 
+/**
+ * Hide the recording overlay and chat windows while another app is full screen
+ * or the screen is being shared
+ */
+hide_when_presenting?: boolean; 
+/**
+ * Apps (bundle identifiers or names) that results are never pasted into; the
+ * result stays in history and a notification is shown instead
+ */
+paste_blocked_apps?: string[]; 
+/**
+ * Longest result that's pasted; longer ones are held in history for review.
+ * 0 disables the limit
+ */
+max_paste_chars?: number; 
+/**
+ * How results pasted into a known terminal app are changed
+ */
+terminal_paste_mode?: TerminalPasteMode; tts_enabled?: boolean; tts_selected_model?: string | null; tts_speed?: number; tts_volume?: number; tts_backend?: TtsBackend; 
+/**
+ * Voice for the system backend, by id from `list_system_voices`; `None` uses
+ * the system default
+ */
+tts_system_voice?: string | null; 
+/**
+ * API keys for cloud TTS, keyed by service ("openai", "elevenlabs")
+ */
+tts_api_keys?: Partial<{ [key in string]: string }>; 
+/**
+ * Chosen voice for each cloud TTS service, by voice id
+ */
+tts_cloud_voices?: Partial<{ [key in string]: string }>; 
+/**
+ * Read chat replies aloud as they arrive
+ */
+chat_read_aloud?: boolean; filler_word_filter?: string | null; 
+/**
+ * Whether to collapse repeated words (e.g., "I I I am" → "I am")
+ */
+collapse_repeated_words?: boolean; 
+/**
+ * Customizable initial prompt for the quick chat
+ */
+quick_chat_initial_prompt?: string; 
+/**
+ * Whether to launch CLI agent for unknown commands (instead of showing error)
+ */
+unknown_command_agent_enabled?: boolean; 
+/**
+ * Template for the CLI command to run. Supports ${prompt} placeholder.
+ * Example: `claude -p "${prompt}"` or `gemini "${prompt}"`
+ */
+unknown_command_template?: string; 
+/**
+ * Terminal application to use (iTerm, Terminal, Warp)
+ */
+unknown_command_terminal?: string; 
+/**
+ * Maximum characters to include from clipboard content in ${clipboard} variable
+ * 0 = no cutoff (include all), other values = limit to N characters
+ */
+clipboard_content_cutoff?: number; 
+/**
+ * Prompt for the context chat mode
+ */
+context_chat_prompt?: string; 
+/**
+ * The last response from a voice interaction (Context Chat)
+ */
+last_voice_interaction?: string | null; 
+/**
+ * Default model ID for context chat mode
+ */
+default_context_chat_model_id?: string | null; 
+/**
+ * Path to a system prompt file that will be injected into all LLM calls
+ */
+system_prompt_file?: string | null; 
+/**
+ * Soft-deleted prompts, categories, voice commands and app mappings
+ */
+deleted_items?: DeletedItem[]; 
+/**
+ * Days to keep deleted items before they are purged (0 = purge immediately)
+ */
+trash_retention_days?: number; 
+/**
+ * Keyboard layout for shortcut key labels (Auto = detect from the system).
+ * Settings saved before this existed have QWERTY key names in their bindings,
+ * so they default to QWERTY rather than Auto.
+ */
+keyboard_layout?: KeyboardLayout; 
+/**
+ * Decode speech while still recording and emit `transcription-partial` events
+ */
+live_transcription?: boolean; 
+/**
+ * Minutes before paused shortcuts are re-enabled automatically (0 = until resumed)
+ */
+shortcuts_pause_minutes?: number; 
+/**
+ * API keys for cloud transcription models, keyed by service ("openai", "deepgram")
+ */
+stt_api_keys?: Partial<{ [key in string]: string }>; 
+/**
+ * Cancel a toggle recording when its key is pressed twice in quick succession
+ */
+double_press_cancel?: boolean; 
+/**
+ * Maximum time between the two presses of a double-press cancel
+ */
+double_press_cancel_window_ms?: number; 
+/**
+ * Cancel a recording if no speech is detected within this many seconds (0 = never)
+ */
+no_speech_timeout_seconds?: number; 
+/**
+ * Block cloud refinement once today's estimated spend reaches this many USD
+ */
+daily_spend_limit_usd?: number | null; 
+/**
+ * Block a single cloud refinement whose estimated cost exceeds this many USD
+ */
+per_request_spend_limit_usd?: number | null; 
+/**
+ * Run the microphone through RNNoise before VAD and transcription
+ */
+noise_suppression?: boolean; 
+/**
+ * Listen for the wake word on the always-on microphone
+ */
+wake_word_enabled?: boolean; 
+/**
+ * Binding started when the wake word is heard, `transcribe` or `voice_command`
+ */
+wake_word_binding?: string; 
+/**
+ * Classifier score, from 0 to 1, above which the wake word counts as heard
+ */
+wake_word_threshold?: number; 
+/**
+ * openWakeWord classifier for a custom phrase, instead of the bundled "Hey Ramble"
+ */
+wake_word_model_path?: string | null; 
+/**
+ * Have the screen reader announce recording, refining and paste state changes
+ */
+accessibility_announcements?: boolean; 
+/**
+ * Save power on battery below `battery_saver_threshold`: a smaller model, no
+ * always-on microphone and longer model unload timeouts
+ */
+battery_saver_enabled?: boolean; 
+/**
+ * Battery percentage at or below which the battery saver kicks in
+ */
+battery_saver_threshold?: number; 
+/**
+ * Take over the play/pause key, including Bluetooth headset buttons, to start
+ * and stop `media_key_binding`
+ */
+media_key_trigger_enabled?: boolean; 
+/**
+ * Binding the play/pause key triggers, one of `MEDIA_KEY_BINDINGS`
+ */
+media_key_binding?: string; 
+/**
+ * Accept start/stop requests from paired devices
+ */
+remote_trigger_enabled?: boolean; 
+/**
+ * Port the remote trigger endpoint listens on
+ */
+remote_trigger_port?: number; 
+/**
+ * Listen on the local network rather than only on this computer. Requests are
+ * plain HTTP, so anyone on the network can see tokens and try pairing codes.
+ */
+remote_trigger_lan_access?: boolean; 
+/**
+ * Devices paired with the remote trigger endpoint
+ */
+remote_trigger_devices?: PairedDevice[]; 
+/**
+ * Folder (e.g. in iCloud Drive or Dropbox) that settings are synced through
+ */
+sync_folder?: string | null; 
+/**
+ * Whether the sync passphrase is saved in the OS keychain. The passphrase
+ * itself is never written to the settings.
+ */
+sync_passphrase_saved?: boolean }
+export type AudioDevice = { index: string; name: string; is_default: boolean }
+/**
+ * Authentication method for LLM providers
+ */
+export type AuthMethod = 
+/**
+ * API key authentication (default)
+ */
+"api_key" | 
+/**
+ * OAuth 2.0 authentication (supported by Google and OpenAI)
+ */
+"oauth"
+/**
+ * Result of completing the OAuth flow
+ */
+export type AuthResult = { 
+/**
+ * Whether authentication was successful
+ */
+success: boolean; 
+/**
+ * User's email (if available)
+ */
+email: string | null; 
+/**
+ * Error message (if authentication failed)
+ */
+error: string | null }
+/**
+ * Result of starting the OAuth flow
+ */
+export type AuthStartResult = { 
+/**
+ * The URL to open in the browser for user authentication
+ */
+auth_url: string; 
+/**
+ * The state parameter for CSRF protection
+ */
+state: string }
+/**
+ * The battery saver turned on or off
+ */
+export type BatterySaverChanged = { active: boolean; 
+/**
+ * Battery charge when it changed, if known
+ */
+battery_percent: number | null }
+export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
+/**
+ * A saved screen capture
+ */
+export type CaptureEntry = { id: number; 
+/**
+ * Unix seconds
+ */
+timestamp: number; 
+/**
+ * "region" or "screen"
+ */
+kind: string; 
+/**
+ * App in front when the capture was taken, if known
+ */
+source_app: string | null; width: number; height: number; 
+/**
+ * Full-size PNG
+ */
+path: string; 
+/**
+ * Small PNG preview for the browser
+ */
+thumbnail_path: string }
+/**
+ * The prompt category chosen for the current refinement, shown as the overlay icon
+ */
+export type CategoryDetected = string
+export type ChatMessage = { role: string; content: string; images: string[] | null; 
+/**
+ * Text extracted from attached files, see `attach_chat_file`
+ */
+files?: FileAttachment[] | null }
+export type ChatResponse = { content: string; grounding_metadata: GroundingMetadata | null; 
+/**
+ * Token usage, if reported by the provider
+ */
+usage: TokenUsage | null; 
+/**
+ * Tools the model called before answering, in order
+ */
+tool_calls: ToolCallRecord[] }
+export type ChatSummary = { id: number; title: string; created_at: number; updated_at: number; message_count: number; model_id: string | null; 
+/**
+ * Images attached across all messages
+ */
+attachment_count: number }
+/**
+ * CLI credentials found on disk that can be imported
+ */
+export type CliCredentials = { provider: OAuthProvider; 
+/**
+ * File the credentials were read from
+ */
+source_path: string; email: string | null; 
+/**
+ * Access token expiration (Unix seconds)
+ */
+expires_at: number; 
+/**
+ * Importing rotates the refresh token, so the CLI has to sign in again
+ */
+signs_out_cli: boolean }
+export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
+/**
+ * How a voice command was picked
+ */
+export type CommandMatchSource = 
+/**
+ * A trigger phrase matched without asking the LLM
+ */
+"phrase" | 
+/**
+ * The LLM chose the command or came up with the action itself
+ */
+"llm"
+/**
+ * How a voice command run ended
+ */
+export type CommandOutcome = 
+/**
+ * The command's output was pasted
+ */
+"pasted" | "succeeded" | "failed"
+/**
+ * A named parameter of a custom voice command, e.g. `level` in "set volume to {level}"
+ */
+export type CommandParameter = { 
+/**
+ * Name used in trigger phrases as `{name}` and in the script as `${name}`
+ */
+name: string; param_type?: ParameterType; 
+/**
+ * Allowed values (enum parameters)
+ */
+options?: string[]; 
+/**
+ * What the value means, to help the LLM extract it
+ */
+description?: string | null }
+export type CustomSounds = { start: boolean; stop: boolean }
+export type DefaultModels = { chat: string | null; coherent: string | null; voice: string | null; context_chat: string | null; title: string | null }
+/**
+ * An entry in the trash, kept until restored or purged after the retention period
+ */
+export type DeletedItem = { 
+/**
+ * Unique identifier of the trash entry (not the item's own ID)
+ */
+id: string; 
+/**
+ * Display name of the deleted item
+ */
+name: string; 
+/**
+ * Unix timestamp (seconds) when the item was deleted
+ */
+deleted_at: number; item: TrashedItem }
+/**
+ * Detected app info (for tracking history)
+ */
+export type DetectedApp = { bundle_identifier: string; display_name: string; last_seen: number }
+/**
+ * An OpenAI-compatible server found on a well-known localhost port
+ */
+export type DiscoveredServer = { 
+/**
+ * Suggested provider ID (e.g., "lmstudio")
+ */
+id: string; name: string; base_url: string; models: string[] }
+/**
+ * Stored with a history entry when it was refined in show-edits mode
+ */
+export type EditAlignment = { 
+/**
+ * Word-level diff from the raw transcription to the refinement
+ */
+spans: EditSpan[]; 
+/**
+ * Substantial removals or changes as cited by the refiner
+ */
+notes: string[] }
+export type EditKind = "equal" | "insert" | "delete"
+export type EditSpan = { kind: EditKind; text: string }
+export type EngineType = "Whisper" | "Parakeet" | "TTS" | 
+/**
+ * OpenAI's hosted transcription API
+ */
+"OpenAiCloud" | 
+/**
+ * Deepgram's hosted transcription API
+ */
+"DeepgramCloud"
+/**
+ * A summary stored alongside a history entry
+ */
+export type EntrySummary = { style: SummaryStyle; text: string; 
+/**
+ * Model identifier the summary was written by
+ */
+model: string; 
+/**
+ * Unix timestamp of when the summary was written
+ */
+created_at: number }
+/**
+ * What kind of failure an [`AppError`] is
+ */
+export type ErrorCode = 
+/**
+ * The request itself was wrong: an empty name, an out-of-range value
+ */
+"invalid_input" | 
+/**
+ * The prompt, model, chat or other item asked for doesn't exist
+ */
+"not_found" | 
+/**
+ * Something has to be set up first: an API key, a downloaded model
+ */
+"not_configured" | 
+/**
+ * The provider rejected the credentials
+ */
+"unauthorized" | 
+/**
+ * The provider is rate limiting or out of quota
+ */
+"rate_limited" | 
+/**
+ * The provider or a local server couldn't be reached
+ */
+"network" | 
+/**
+ * The provider is overloaded or failed on its side
+ */
+"unavailable" | 
+/**
+ * Anything else
+ */
+"internal"
+/**
+ * Gemini generation options, sent as `generationConfig.thinkingConfig` and
+ * `safetySettings`
+ */
+/**
+ * A local file attached to a chat message, as extracted text
+ */
+export type FileAttachment = { 
+/**
+ * File name without the directory
+ */
+name: string; 
+/**
+ * The text, split into parts of at most a few thousand characters
+ */
+chunks: string[]; 
+/**
+ * Whether the end of the document was dropped for being too long
+ */
+truncated: boolean }
+/**
+ * Focus mode was turned on or off, possibly from the tray menu
+ */
+export type FocusModeChanged = boolean
+export type GeminiOptions = { 
+/**
+ * Tokens the model may spend thinking: 0 turns thinking off, -1 lets the model
+ * decide. Unset keeps the model's default.
+ */
+thinking_budget?: number | null; 
+/**
+ * Block threshold for every harm category, e.g. "BLOCK_ONLY_HIGH".
+ * Unset keeps Google's default filtering.
+ */
+safety_threshold?: string | null }
+export type GeneratedImages = { 
+/**
+ * Paths of the saved image files
+ */
+paths: string[]; 
+/**
+ * Text the model returned alongside the images, if any
+ */
+text: string | null }
+export type GroundingChunk = { uri: string | null; title: string | null }
+export type GroundingMetadata = { search_entry_point: string | null; chunks: GroundingChunk[] }
+export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; 
+/**
+ * Short description of what was said, generated after transcription
+ */
+generated_title: string | null; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; transcription_status: string; transcription_error: string | null; 
+/**
+ * Computed from the texts when the entry is loaded
+ */
+stats: TextStats; 
+/**
+ * Names and numbers in the refinement that weren't in the transcription
+ */
+invented_terms: string[]; 
+/**
+ * Summary of the transcription, if one was asked for
+ */
+summary: EntrySummary | null }
+export type HistoryExportFormat = "markdown" | "json" | "csv"
+/**
+ * Which entries to export. Bounds are unix timestamps in seconds and inclusive.
+ */
+export type HistoryExportRange = { from?: number | null; to?: number | null; 
+/**
+ * Only export starred entries
+ */
+saved_only?: boolean }
+/**
+ * Result of transcribing an imported audio file
+ */
+export type ImportedTranscription = { 
+/**
+ * History entry the transcription was saved to
+ */
+history_id: number; text: string; 
+/**
+ * Coherent refinement of `text`, when requested and it succeeded
+ */
+refined_text: string | null }
+/**
+ * Information about an installed application (from JSON)
+ */
+export type InstalledApp = { bundle_id: string; name: string }
+/**
+ * Keyboard layout used to interpret character keys in shortcut strings
+ */
+export type KeyboardLayout = "auto" | "qwerty" | "azerty" | "qwertz" | "dvorak"
+/**
+ * A known application with suggested category
+ */
+export type KnownApp = { bundle_id: string; name: string; suggested_category: string }
+/**
+ * Model configuration for a specific provider
+ */
+export type LLMModel = { 
+/**
+ * Unique identifier (UUID string)
+ */
+id: string; 
+/**
+ * Provider ID this model belongs to
+ */
+provider_id: string; 
+/**
+ * Model identifier sent to API (e.g., "gpt-4o", "anthropic/claude-3-opus")
+ */
+model_id: string; 
+/**
+ * User-friendly display name
+ */
+display_name: string; 
+/**
+ * Whether this model supports vision/image inputs
+ */
+supports_vision?: boolean; 
+/**
+ * Whether this model is enabled and should appear in model selectors
+ */
+enabled?: boolean; 
+/**
+ * Price in USD per million prompt tokens, overriding the built-in pricing table
+ */
+input_price_per_million?: number | null; 
+/**
+ * Price in USD per million completion tokens, overriding the built-in pricing table
+ */
+output_price_per_million?: number | null; 
+/**
+ * OpenRouter fallback models and provider preferences, only sent to OpenRouter
+ */
+openrouter_routing?: OpenRouterRouting | null; 
+/**
+ * Thinking budget and safety settings, only sent through the native Gemini API
+ */
+gemini_options?: GeminiOptions | null; 
+/**
+ * Effort and thinking budget for reasoning models
+ */
+reasoning?: ReasoningOptions | null }
+export type LLMPrompt = { id: string; name: string; prompt: string }
+/**
+ * Unified LLM provider configuration
+ * Supports OpenAI, Anthropic, Gemini, OpenRouter, and custom enterprise proxies
+ */
+export type LLMProvider = { 
+/**
+ * Unique identifier (UUID string)
+ */
+id: string; 
+/**
+ * Display name (e.g., "OpenAI", "OpenRouter", "My Proxy")
+ */
+name: string; 
+/**
+ * API base URL (editable for custom endpoints)
+ */
+base_url: string; 
+/**
+ * User's API key for this provider
+ */
+api_key?: string; 
+/**
+ * Whether this provider supports vision/image inputs
+ */
+supports_vision?: boolean; 
+/**
+ * Whether this is a user-added custom provider vs preset
+ */
+is_custom?: boolean; 
+/**
+ * Authentication method (API key or OAuth)
+ */
+auth_method?: AuthMethod; 
+/**
+ * Whether this provider supports OAuth authentication
+ */
+supports_oauth?: boolean; 
+/**
+ * Saved API key/endpoint pairs the user can switch between
+ */
+credentials?: ProviderCredential[]; 
+/**
+ * Credential currently copied into `api_key` and `base_url`, if any
+ */
+active_credential_id?: string | null; 
+/**
+ * Extra headers sent with every request, e.g. `x-org-id` for a gateway.
+ * `{api_key}` in a value is replaced with the API key.
+ */
+custom_headers?: Partial<{ [key in string]: string }>; 
+/**
+ * Count this provider as free toward the spend limits, e.g. a self-hosted
+ * server elsewhere on the network
+ */
+free?: boolean }
+export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
+/**
+ * The current recording's mode was determined
+ */
+export type ModeDetermined = RecordingMode
+export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number }
+export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
+export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+/**
+ * Supported OAuth providers
+ */
+export type OAuthProvider = "google" | "openai"
+/**
+ * OAuth status for a provider
+ */
+export type OAuthStatus = { 
+/**
+ * Whether the user is authenticated
+ */
+authenticated: boolean; 
+/**
+ * User's email (if available and authenticated)
+ */
+email: string | null; 
+/**
+ * Token expiration timestamp (Unix seconds)
+ */
+expires_at: number | null }
+/**
+ * OpenRouter's reliability options for a model, sent as extra request body fields
+ */
+export type OpenRouterRouting = { 
+/**
+ * Models to fall back to, in order, if this one fails (OpenRouter's `models`)
+ */
+fallback_models?: string[]; 
+/**
+ * Upstream providers to try first, in order (`provider.order`)
+ */
+provider_order?: string[]; 
+/**
+ * Whether OpenRouter may route to providers outside `provider_order`
+ */
+allow_fallbacks?: boolean; 
+/**
+ * Prefer the cheapest ("price"), fastest ("throughput") or quickest to
+ * respond ("latency") provider instead of OpenRouter's load balancing
+ */
+sort?: string | null }
+export type OverlayPosition = "none" | "top" | "bottom"
+/**
+ * What importing one command of a pack does
+ */
+export type PackCommandOutcome = 
+/**
+ * Added without a conflict
+ */
+"add" | 
+/**
+ * Replaces an existing command
+ */
+"replace" | 
+/**
+ * Added under a new name next to the command it conflicts with
+ */
+"rename" | 
+/**
+ * Not imported
+ */
+"skip"
+/**
+ * A command of a pack, with the script it would run and what importing it does
+ */
+export type PackCommandPreview = { name: string; phrases: string[]; script_type: ScriptType; script: string | null; outcome: PackCommandOutcome; 
+/**
+ * Name of the existing command it conflicts with
+ */
+conflicts_with: string | null }
+/**
+ * What to do with an imported command whose id or a trigger phrase is already used
+ */
+export type PackConflict = 
+/**
+ * Keep the existing command and drop the imported one
+ */
+"skip" | 
+/**
+ * Replace the existing command with the imported one. Built-in commands are
+ * never replaced.
+ */
+"replace" | 
+/**
+ * Add the imported command under a new id and name
+ */
+"keep_both"
+/**
+ * What an import changed
+ */
+export type PackImportResult = { 
+/**
+ * Commands added without a conflict
+ */
+added: number; 
+/**
+ * Existing commands replaced by imported ones
+ */
+replaced: number; 
+/**
+ * Commands added under a new name next to the one they conflicted with
+ */
+renamed: number; 
+/**
+ * Names of commands that weren't imported
+ */
+skipped: string[] }
+/**
+ * A pack's commands as they would be imported, shown before anything is merged
+ */
+export type PackPreview = { name: string; commands: PackCommandPreview[]; 
+/**
+ * Passed to `import_voice_command_pack` to confirm the import. It covers the
+ * file and the conflict choice, so a pack changed after the preview isn't
+ * imported.
+ */
+confirmation: string }
+/**
+ * A companion device allowed to start and stop recordings over the network
+ */
+export type PairedDevice = { id: string; 
+/**
+ * Name the device gave when pairing, e.g. "Apple Watch"
+ */
+name: string; 
+/**
+ * SHA-256 of the device's access token, hex encoded
+ */
+token_hash: string; 
+/**
+ * Unix timestamp of when the device was paired
+ */
+paired_at: number }
+/**
+ * Type of a custom command's parameter
+ */
+export type ParameterType = "string" | "number" | 
+/**
+ * One of `CommandParameter::options`
+ */
+"enum"
+export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
+/**
+ * A project to dictate for, switched by voice ("switch to project Atlas")
+ */
+export type Project = { id: string; name: string; 
+/**
+ * Prompt mode to switch to when the project is activated
+ */
+prompt_mode?: PromptMode | null; 
+/**
+ * Category to make the default when the project is activated
+ */
+default_category_id?: string | null; 
+/**
+ * Words recognized while the project is active, on top of the custom words
+ */
+custom_words?: string[] }
+/**
+ * A prompt category that groups applications and defines processing style
+ */
+export type PromptCategory = { id: string; name: string; icon: string; prompt: string; is_builtin: boolean; 
+/**
+ * Optional model override for this category (None = use default coherent model)
+ */
+model_override?: string | null; 
+/**
+ * Transcription language forced while this category is active, overriding
+ * `selected_language` (None = use the global language)
+ */
+language?: string | null; 
+/**
+ * Add topic headings and a table of contents to long refinements
+ */
+chaptering?: boolean }
+/**
+ * Starter template for creating a new prompt category
+ */
+export type PromptCategoryTemplate = { id: string; name: string; icon: string; 
+/**
+ * Short description shown in the template picker
+ */
+description: string; prompt: string }
+/**
+ * Prompt mode selection - Dynamic auto-detects based on app, others are explicit processing levels
+ */
+export type PromptMode = "dynamic" | 
+/**
+ * Low processing: minimal intervention, just grammar and filler removal
  */
-export type AppCategoryMapping = { bundle_identifier: string; display_name: string; category_id: string }
-export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; 
+"low" | 
 /**
- * All configured LLM providers (OpenAI, Anthropic, OpenRouter, custom)
+ * Medium processing: standard polish, formatting, and structure
  */
-llm_providers?: LLMProvider[]; 
+"medium" | 
 /**
- * All configured models across all providers
+ * High processing: intent extraction, aggressive restructuring
  */
-llm_models?: LLMModel[]; 
+"high"
 /**
- * Default model ID for chat windows
+ * The prompt mode was changed from the tray menu
  */
-default_chat_model_id?: string | null; 
+export type PromptModeChanged = PromptMode
 /**
- * Default model ID for coherent/ramble mode
+ * A problem found in a prompt template, surfaced in the editor before saving
  */
-default_coherent_model_id?: string | null; 
+export type PromptWarning = { kind: PromptWarningKind; message: string; 
 /**
- * Default model ID for voice commands
+ * Character offset in the prompt the warning refers to, if any
  */
-default_voice_model_id?: string | null; 
+position: number | null }
+export type PromptWarningKind = "unknown_variable" | "deprecated_variable" | "missing_output" | "unbalanced_braces" | "excessive_length"
 /**
- * OpenAI OAuth reasoning effort level (none, low, medium, high, xhigh)
+ * A named API key for a provider, e.g. "Personal" or "Work gateway"
  */
-openai_reasoning_effort?: string; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; 
+export type ProviderCredential = { 
 /**
- * Prompts for coherent mode (transforms rambling speech to clean text)
+ * Unique identifier (UUID string)
  */
-coherent_prompts?: LLMPrompt[]; coherent_selected_prompt_id?: string | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; 
+id: string; 
 /**
- * Whether coherent mode (LLM refinement) is enabled
+ * Display name
  */
-coherent_enabled?: boolean; 
+name: string; api_key: string; 
 /**
- * Whether to use vision model when screenshots are available
+ * Endpoint to use with this key (a company gateway may differ from the provider's)
  */
-coherent_use_vision?: boolean; 
+base_url: string }
 /**
- * Threshold in milliseconds for tap vs hold detection (smart PTT)
+ * Latest health check result for a provider
  */
-hold_threshold_ms?: number; 
+export type ProviderHealth = { provider_id: string; 
 /**
- * Current prompt mode (Dynamic, Low, Medium, High)
+ * Whether the server answered the model listing
  */
-prompt_mode?: PromptMode; 
+reachable: boolean; 
 /**
- * Prompt categories (built-in + user-defined)
+ * Models the server reported as available
  */
-prompt_categories?: PromptCategory[]; 
+models: string[]; error: string | null; 
 /**
- * Application to category mappings
+ * Unix timestamp of the check
  */
-app_category_mappings?: AppCategoryMapping[]; 
+checked_at: number }
 /**
- * History of detected applications (for dropdown suggestions)
+ * A curated OpenAI-compatible provider with a sensible set of default models
  */
-detected_apps_history?: DetectedApp[]; 
+export type ProviderPreset = { 
 /**
- * Default category for apps not in known_apps or user mappings
+ * Provider ID created by this preset (e.g., "groq")
  */
-default_category_id?: string; 
+id: string; name: string; base_url: string; 
 /**
- * Whether voice commands are enabled
+ * Whether an API key is required (false for local servers like Ollama)
  */
-voice_commands_enabled?: boolean; 
+requires_api_key: boolean; supports_vision: boolean; models: ProviderPresetModel[] }
+export type ProviderPresetModel = { model_id: string; display_name: string; supports_vision: boolean }
 /**
- * Default model for voice command execution
+ * Reasoning controls for o-series, Claude thinking and Gemini thinking models
  */
-voice_command_default_model?: string; 
+export type ReasoningOptions = { 
 /**
- * User-defined voice commands
+ * "minimal", "low", "medium" or "high", for OpenAI-style `reasoning_effort`
  */
-voice_commands?: VoiceCommand[]; tts_enabled?: boolean; tts_selected_model?: string | null; tts_speed?: number; tts_volume?: number; filler_word_filter?: string | null; 
+effort?: string | null; 
 /**
- * Whether to collapse repeated words (e.g., "I I I am" → "I am")
+ * Tokens the model may spend thinking, for Claude and Gemini
  */
-collapse_repeated_words?: boolean; 
+budget_tokens?: number | null; 
 /**
- * Customizable initial prompt for the quick chat
+ * Keep the reasoning trace out of chat responses (and ask OpenRouter not to send
+ * it). Traces are always stripped from refined text before it's pasted.
  */
-quick_chat_initial_prompt?: string; 
+hide_reasoning?: boolean }
 /**
- * Whether to launch CLI agent for unknown commands (instead of showing error)
+ * How the current recording was started, once the key has been held or released
  */
-unknown_command_agent_enabled?: boolean; 
+export type RecordingMode = 
 /**
- * Template for the CLI command to run. Supports ${prompt} placeholder.
- * Example: `claude -p "${prompt}"` or `gemini "${prompt}"`
+ * Push-to-talk: recording stops when the key is released
  */
-unknown_command_template?: string; 
+"hold" | 
 /**
- * Terminal application to use (iTerm, Terminal, Warp)
+ * A quick press: recording continues and is refined when stopped
  */
-unknown_command_terminal?: string; 
+"refining"
+export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
 /**
- * Maximum characters to include from clipboard content in ${clipboard} variable
- * 0 = no cutoff (include all), other values = limit to N characters
+ * Result of a single test-run of a prompt
  */
-clipboard_content_cutoff?: number; 
+export type RefinementPreview = { output: string; 
 /**
- * Prompt for the context chat mode
+ * Model identifier the request was sent to
  */
-context_chat_prompt?: string; 
+model: string; 
 /**
- * The last response from a voice interaction (Context Chat)
+ * Token usage, if reported by the provider
  */
-last_voice_interaction?: string | null; 
+usage: TokenUsage | null; duration_ms: number }
 /**
- * Default model ID for context chat mode
+ * A piece of the refiner's answer arrived while it streams in
  */
-default_context_chat_model_id?: string | null; 
+export type RefinementProgress = { delta: string; 
 /**
- * Path to a system prompt file that will be injected into all LLM calls
+ * Characters received so far
  */
-system_prompt_file?: string | null }
-export type AudioDevice = { index: string; name: string; is_default: boolean }
+chars: number }
 /**
- * Authentication method for LLM providers
+ * A scheduled prompt to dictate something, e.g. a standup at 9:25 on weekdays
  */
-export type AuthMethod = 
+export type Reminder = { id: string; 
 /**
- * API key authentication (default)
+ * Shown in the notification, e.g. "Dictate your standup"
  */
-"api_key" | 
+label: string; 
 /**
- * OAuth 2.0 authentication (supported by Google and OpenAI)
+ * Local time of day as "HH:MM"
  */
-"oauth"
+time: string; 
 /**
- * Result of completing the OAuth flow
+ * ISO weekdays to repeat on (1 = Monday … 7 = Sunday), every day if empty.
+ * Ignored for one-off reminders.
  */
-export type AuthResult = { 
+days?: number[]; 
 /**
- * Whether authentication was successful
+ * "YYYY-MM-DD" for a one-off reminder, which disables itself once shown
  */
-success: boolean; 
+date?: string | null; 
 /**
- * User's email (if available)
+ * Prompt category the next dictation uses after this reminder
  */
-email: string | null; 
+category_id?: string | null; enabled?: boolean }
 /**
- * Error message (if authentication failed)
+ * A companion device paired with the remote trigger endpoint
  */
-error: string | null }
+export type RemoteDevicePaired = { id: string; name: string }
 /**
- * Result of starting the OAuth flow
+ * A pairing code for the settings page to show, with where to send it
  */
-export type AuthStartResult = { 
+export type RemotePairing = { code: string; 
 /**
- * The URL to open in the browser for user authentication
+ * Addresses the endpoint can be reached on, this computer's local network
+ * addresses with LAN access on
  */
-auth_url: string; 
+addresses: string[]; port: number; expires_in_secs: number }
+export type SavedChat = { id: number; title: string; created_at: number; updated_at: number; messages: ChatMessage[]; 
 /**
- * The state parameter for CSRF protection
+ * Model the chat was last used with
  */
-state: string }
-export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
-export type ChatMessage = { role: string; content: string; images: string[] | null }
-export type ChatResponse = { content: string; grounding_metadata: GroundingMetadata | null }
-export type ChatSummary = { id: number; title: string; created_at: number; updated_at: number; message_count: number }
-export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
-export type CustomSounds = { start: boolean; stop: boolean }
-export type DefaultModels = { chat: string | null; coherent: string | null; voice: string | null; context_chat: string | null }
+model_id: string | null }
 /**
- * Detected app info (for tracking history)
+ * Script type for bespoke commands
  */
-export type DetectedApp = { bundle_identifier: string; display_name: string; last_seen: number }
-export type EngineType = "Whisper" | "Parakeet" | "TTS"
+export type ScriptType = "shell" | "apple_script"
 /**
- * Message structure for forking conversations
+ * A history entry found by a semantic search
  */
-export type ForkMessage = { role: string; content: string }
-export type GroundingChunk = { uri: string | null; title: string | null }
-export type GroundingMetadata = { search_entry_point: string | null; chunks: GroundingChunk[] }
-export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; transcription_status: string; transcription_error: string | null }
+export type SemanticMatch = { entry: HistoryEntry; 
 /**
- * Information about an installed application (from JSON)
+ * Cosine similarity to the query, higher is closer
  */
-export type InstalledApp = { bundle_id: string; name: string }
+score: number }
 /**
- * A known application with suggested category
+ * A setting was changed by the backend, so open settings views should reload it.
+ * Only toggles are announced this way
  */
-export type KnownApp = { bundle_id: string; name: string; suggested_category: string }
+export type SettingsChanged = { 
 /**
- * Model configuration for a specific provider
+ * The setting's field name in `AppSettings`
  */
-export type LLMModel = { 
+setting: string; value: boolean }
 /**
- * Unique identifier (UUID string)
+ * A settings sync pulled, pushed or found conflicting settings
  */
-id: string; 
+export type SettingsSynced = SyncReport
+export type ShareFormat = "text" | "html"
+export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string; 
 /**
- * Provider ID this model belongs to
+ * Stop a toggle-mode recording after this many seconds of silence following
+ * speech, as if the shortcut were tapped again (0 = disabled)
  */
-provider_id: string; 
+auto_stop_silence_seconds?: number; 
 /**
- * Model identifier sent to API (e.g., "gpt-4o", "anthropic/claude-3-opus")
+ * Which recordings started with this shortcut are refined: taps, holds, all or
+ * none
  */
-model_id: string; 
+tap_hold_mode?: TapHoldMode }
+export type ShortcutEvent = { 
 /**
- * User-friendly display name
+ * Unix timestamp in milliseconds
  */
-display_name: string; 
+timestamp: number; source: ShortcutEventSource; binding_id: string; shortcut: string; 
 /**
- * Whether this model supports vision/image inputs
+ * True for key press, false for key release
  */
-supports_vision?: boolean; 
+pressed: boolean; resolution: ShortcutEventResolution; 
 /**
- * Whether this model is enabled and should appear in model selectors
+ * Why the event was resolved this way, e.g. "not recording"
  */
-enabled?: boolean }
-export type LLMPrompt = { id: string; name: string; prompt: string }
+detail: string | null }
 /**
- * Unified LLM provider configuration
- * Supports OpenAI, Anthropic, Gemini, OpenRouter, and custom enterprise proxies
+ * What happened to a shortcut event
  */
-export type LLMProvider = { 
+export type ShortcutEventResolution = 
 /**
- * Unique identifier (UUID string)
+ * The binding's action was triggered
  */
-id: string; 
+"matched" | 
 /**
- * Display name (e.g., "OpenAI", "OpenRouter", "My Proxy")
+ * The binding matched but was ignored in the current state
  */
-name: string; 
+"suppressed" | 
 /**
- * API base URL (editable for custom endpoints)
+ * A repeated press of a key that is already held down
  */
-base_url: string; 
+"duplicate_filtered" | 
 /**
- * User's API key for this provider
+ * The binding's action refused to start (e.g. microphone unavailable)
  */
-api_key?: string; 
+"start_failed"
 /**
- * Whether this provider supports vision/image inputs
+ * Where a shortcut event came from
  */
-supports_vision?: boolean; 
+export type ShortcutEventSource = 
 /**
- * Whether this is a user-added custom provider vs preset
+ * Registered through the global shortcut plugin
  */
-is_custom?: boolean; 
+"global_shortcut" | 
 /**
- * Authentication method (API key or OAuth)
+ * Matched by the low-level key listener (raw modifier bindings)
  */
-auth_method?: AuthMethod; 
+"raw_key"
+export type ShortcutsPauseState = { paused: boolean; 
 /**
- * Whether this provider supports OAuth authentication
+ * Unix timestamp (seconds) when shortcuts are re-enabled automatically, if any
  */
-supports_oauth?: boolean }
-export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
-export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number }
-export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
-export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+resume_at: number | null }
+export type SoundTheme = "marimba" | "pop" | "custom"
 /**
- * OAuth status for a provider
+ * Categories and app mappings suggested for this computer
  */
-export type OAuthStatus = { 
+export type SuggestedSetup = { 
 /**
- * Whether the user is authenticated
+ * New categories for the kinds of apps that are installed
  */
-authenticated: boolean; 
+categories: PromptCategory[]; 
 /**
- * User's email (if available and authenticated)
+ * Mappings for installed apps that aren't mapped yet
  */
-email: string | null; 
+app_mappings: AppCategoryMapping[] }
 /**
- * Token expiration timestamp (Unix seconds)
+ * What kind of summary to write
  */
-expires_at: number | null }
-export type OverlayPosition = "none" | "top" | "bottom"
-export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
+export type SummaryStyle = 
 /**
- * A prompt category that groups applications and defines processing style
+ * The main points as a bulleted list
  */
-export type PromptCategory = { id: string; name: string; icon: string; prompt: string; is_builtin: boolean; 
+"bullets" | 
 /**
- * Optional model override for this category (None = use default coherent model)
+ * Tasks, owners and deadlines that were agreed on
  */
-model_override?: string | null }
+"action_items" | 
 /**
- * Prompt mode selection - Dynamic auto-detects based on app, others are explicit processing levels
+ * Two or three sentences
  */
-export type PromptMode = "dynamic" | 
+"tldr"
 /**
- * Low processing: minimal intervention, just grammar and filler removal
+ * What a sync did
  */
-"low" | 
+export type SyncReport = { 
 /**
- * Medium processing: standard polish, formatting, and structure
+ * Settings taken from the sync folder
  */
-"medium" | 
+pulled: string[]; 
 /**
- * High processing: intent extraction, aggressive restructuring
+ * Whether this computer's settings were written to the folder
  */
-"high"
-export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
-export type SavedChat = { id: number; title: string; created_at: number; updated_at: number; messages: ChatMessage[] }
+pushed: boolean; 
 /**
- * Script type for bespoke commands
+ * Settings changed on this computer and elsewhere; this computer's value was kept
  */
-export type ScriptType = "shell" | "apple_script"
-export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
-export type SoundTheme = "marimba" | "pop" | "custom"
+conflicts: string[] }
+/**
+ * Whether a tap or a hold of a recording shortcut refines the transcription
+ */
+export type TapHoldMode = 
+/**
+ * A tap refines and a hold pastes the raw transcription (default)
+ */
+"tap_refines" | 
+/**
+ * A hold refines and a tap pastes the raw transcription
+ */
+"hold_refines" | 
+/**
+ * Taps and holds both refine
+ */
+"always_refined" | 
+/**
+ * Taps and holds both paste the raw transcription
+ */
+"always_raw"
+/**
+ * How results pasted into a terminal are changed, so a line break the refiner added
+ * doesn't run a dictated command before it's been read
+ */
+export type TerminalPasteMode = 
+/**
+ * Pasted as is
+ */
+"off" | 
+/**
+ * Joined onto one line, without line breaks before or after
+ */
+"strip_newlines" | 
+/**
+ * Joined onto one line and quoted as a single shell argument
+ */
+"shell_escape"
+export type TextStats = { 
+/**
+ * Words in the final text (the refinement if there is one)
+ */
+word_count: number; 
+/**
+ * Estimated time to read the final text, in seconds
+ */
+reading_time_seconds: number; 
+/**
+ * Words in the raw transcription
+ */
+raw_word_count: number; raw_chars: number; 
+/**
+ * Characters in the refinement, None if the text wasn't refined
+ */
+refined_chars: number | null; 
+/**
+ * How much longer (positive) or shorter (negative) the refinement is than the
+ * raw transcription, in percent of its characters
+ */
+size_delta_percent: number | null }
+/**
+ * Token counts reported by the provider for a single completion
+ */
+export type TokenUsage = { prompt_tokens: number; completion_tokens: number; total_tokens: number }
+/**
+ * A tool call made while answering, shown alongside the response
+ */
+export type ToolCallRecord = { name: string; 
+/**
+ * The call's arguments as JSON
+ */
+arguments: string; 
+/**
+ * What was sent back to the model
+ */
+output: string }
+/**
+ * A deleted item and the data needed to restore it
+ */
+export type TrashedItem = { kind: "coherent_prompt"; data: LLMPrompt } | { kind: "prompt_category"; data: { category: PromptCategory; 
+/**
+ * App mappings that pointed at the category when it was deleted
+ */
+app_mappings: AppCategoryMapping[] } } | { kind: "voice_command"; data: VoiceCommand } | { kind: "app_mapping"; data: AppCategoryMapping }
+/**
+ * Usage per provider/model for one calendar month, most expensive first
+ */
+/**
+ * Which engine reads text aloud
+ */
+export type TtsBackend = 
+/**
+ * Kokoro once its model is downloaded, the system voice until then
+ */
+"auto" | 
+/**
+ * The local Kokoro model
+ */
+"kokoro" | 
+/**
+ * The operating system's synthesizer: `say` on macOS, SAPI on Windows,
+ * espeak-ng on Linux
+ */
+"system" | 
+/**
+ * OpenAI's hosted speech API
+ */
+"openai" | 
+/**
+ * ElevenLabs' hosted speech API
+ */
+"elevenlabs"
+/**
+ * A voice a TTS backend offers
+ */
+export type TtsVoice = { 
+/**
+ * Passed back to the engine to select the voice
+ */
+id: string; name: string; 
+/**
+ * Language or locale code, such as `en_US` or `en-us`; empty when the voice
+ * speaks several
+ */
+language: string }
+export type UsageReport = { 
+/**
+ * "YYYY-MM"
+ */
+month: string; rows: UsageReportRow[]; total_cost_usd: number }
+/**
+ * Usage of one provider/model over a month
+ */
+export type UsageReportRow = { provider_id: string; model_id: string; requests: number; prompt_tokens: number; completion_tokens: number; cost_usd: number; 
+/**
+ * Requests whose model had no known price, so their cost isn't counted
+ */
+unpriced_requests: number }
+/**
+ * Spend so far, for display next to the spend limits
+ */
+export type UsageSummary = { today_cost_usd: number; today_requests: number; month_cost_usd: number; 
+/**
+ * Requests this month whose model had no known price, so their cost isn't counted
+ */
+month_unpriced_requests: number }
+/**
+ * A screenshot was added to the current recording's context
+ */
+export type VisionCaptured = null
 /**
  * A voice command definition
  */
@@ -1664,7 +4033,29 @@ model_override?: string | null;
 /**
  * Whether this is a built-in command
  */
-is_builtin?: boolean }
+is_builtin?: boolean; 
+/**
+ * Named values the script takes as `${name}`, extracted from what was said
+ * (custom commands)
+ */
+parameters?: CommandParameter[] }
+export type VoiceCommandRun = { 
+/**
+ * Unix timestamp in milliseconds
+ */
+timestamp: number; 
+/**
+ * What was said for this step
+ */
+transcription: string; matched_by: CommandMatchSource | null; command_id: string | null; command_name: string | null; 
+/**
+ * The script or shell command that ran, with placeholders filled in
+ */
+script: string | null; outcome: CommandOutcome; 
+/**
+ * Pasted text or error message
+ */
+output: string | null; duration_ms: number }
 /**
  * Type of voice command
  */
@@ -1677,6 +4068,26 @@ export type VoiceCommandType =
  * User-defined script (shell or AppleScript)
  */
 "custom"
+/**
+ * A countdown started by voice, e.g. "remind me in 20 minutes to check the oven"
+ */
+export type VoiceTimer = { id: string; 
+/**
+ * What to be reminded of, if anything was said
+ */
+label?: string | null; 
+/**
+ * Length of the timer as asked for
+ */
+duration_secs: number; 
+/**
+ * Unix timestamp of when the timer runs out
+ */
+fires_at: number }
+/**
+ * When a recognised word was spoken, in seconds from the start of the audio
+ */
+export type WordTiming = { word: string; start: number; end: number }
 
 /** tauri-specta globals **/
 
@@ -38,45 +38,14 @@ class ErrorBoundary extends React.Component<
   }
 }
 
-// Get initial context and messages from window URL params if provided
+// Get initial context from window URL params if provided
 const urlParams = new URLSearchParams(window.location.search);
 const initialContext = urlParams.get("context") || undefined;
 
-// Parse initial messages for forked conversations
-let initialMessages: Array<{ role: string; content: string }> | undefined;
-const messagesParam = urlParams.get("messages");
-if (messagesParam) {
-  try {
-    // URLSearchParams.get() already decodes the URL parameter, so no need for decodeURIComponent
-    console.log(
-      "main.tsx: Parsing messages parameter",
-      messagesParam.length,
-      "chars",
-    );
-    initialMessages = JSON.parse(messagesParam);
-    console.log(
-      "main.tsx: Successfully parsed",
-      initialMessages?.length,
-      "messages",
-    );
-  } catch (e) {
-    console.error("main.tsx: Failed to parse initial messages parameter:", e);
-    console.error("Raw parameter preview:", messagesParam.substring(0, 100));
-  }
-}
-
-console.log("main.tsx: Rendering ChatWindow with:", {
-  hasContext: !!initialContext,
-  messagesCount: initialMessages?.length || 0,
-});
-
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
     <ErrorBoundary>
-      <ChatWindow
-        initialContext={initialContext}
-        initialMessages={initialMessages}
-      />
+      <ChatWindow initialContext={initialContext} />
     </ErrorBoundary>
   </React.StrictMode>,
 );
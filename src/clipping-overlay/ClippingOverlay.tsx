@@ -3,6 +3,13 @@ import { commands } from "@/bindings";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { listen } from "@tauri-apps/api/event";
 
+interface Region {
+  left: number;
+  top: number;
+  width: number;
+  height: number;
+}
+
 export const ClippingOverlay: React.FC = () => {
   const [startPos, setStartPos] = useState<{ x: number; y: number } | null>(
     null,
@@ -12,11 +19,14 @@ export const ClippingOverlay: React.FC = () => {
   );
   const [isSelecting, setIsSelecting] = useState(false);
   const [isCapturing, setIsCapturing] = useState(false);
+  // Regions already captured in this session (shift-drag keeps the overlay up)
+  const [capturedRegions, setCapturedRegions] = useState<Region[]>([]);
   const containerRef = useRef<HTMLDivElement>(null);
 
   const handleCapture = async (
     start: { x: number; y: number },
     end: { x: number; y: number },
+    keepOpen: boolean,
   ) => {
     if (isCapturing) return;
 
@@ -25,8 +35,12 @@ export const ClippingOverlay: React.FC = () => {
     const width = Math.abs(start.x - end.x);
     const height = Math.abs(start.y - end.y);
 
-    // If selection is too small, just cancel
+    // If selection is too small, just cancel (or ignore it mid-session)
     if (width <= 5 || height <= 5) {
+      if (keepOpen || capturedRegions.length > 0) {
+        resetState();
+        return;
+      }
       try {
         await commands.restoreAppVisibility();
       } catch {}
@@ -45,22 +59,33 @@ export const ClippingOverlay: React.FC = () => {
         Math.round(y),
         Math.round(width),
         Math.round(height),
+        keepOpen,
       );
       console.log("Capture result:", result.status);
+      if (keepOpen) {
+        if (result.status === "ok") {
+          setCapturedRegions((prev) => [
+            ...prev,
+            { left: x, top: y, width, height },
+          ]);
+        }
+        resetState();
+        return;
+      }
     } catch (err) {
       console.error("Failed to capture region:", err);
       // Restore visibility on error
       try {
         await commands.restoreAppVisibility();
       } catch {}
-    } finally {
-      // ALWAYS reset state and close window
-      setIsCapturing(false);
-      try {
-        await getCurrentWebviewWindow().close();
-      } catch (e) {
-        console.error("Failed to close clipping window:", e);
-      }
+    }
+
+    // Single capture (or failure): reset state and close window
+    setIsCapturing(false);
+    try {
+      await getCurrentWebviewWindow().close();
+    } catch (e) {
+      console.error("Failed to close clipping window:", e);
     }
   };
 
@@ -78,6 +103,7 @@ export const ClippingOverlay: React.FC = () => {
 
     // Reset state on component mount
     resetState();
+    setCapturedRegions([]);
 
     // Set up focus listener to reset state when window is shown
     const unlistenFocusPromise = win.onFocusChanged(({ payload: focused }) => {
@@ -105,7 +131,7 @@ export const ClippingOverlay: React.FC = () => {
 
     const handleKeyDown = async (e: KeyboardEvent) => {
       if (e.key === "Escape" && !isCapturing) {
-        console.log("ClippingOverlay: Escape pressed, canceling");
+        console.log("ClippingOverlay: Escape pressed, ending session");
         await commands.restoreAppVisibility();
         await win.close();
       }
@@ -126,7 +152,7 @@ export const ClippingOverlay: React.FC = () => {
       setIsSelecting(false);
       const lastPos = { x: e.screenX, y: e.screenY };
       if (startPos) {
-        handleCapture(startPos, lastPos);
+        handleCapture(startPos, lastPos, e.shiftKey);
       } else {
         commands
           .restoreAppVisibility()
@@ -141,7 +167,7 @@ export const ClippingOverlay: React.FC = () => {
       window.removeEventListener("mousemove", onMouseMove);
       window.removeEventListener("mouseup", onMouseUp);
     };
-  }, [isSelecting, isCapturing, startPos]);
+  }, [isSelecting, isCapturing, startPos, capturedRegions]);
 
   const handleMouseDown = (e: React.MouseEvent) => {
     if (e.button !== 0 || isCapturing) return; // Only left click and not while capturing
@@ -173,6 +199,16 @@ export const ClippingOverlay: React.FC = () => {
           <p className="text-xs opacity-90 mt-1">
             Drag to select a region. Release to capture. Esc to cancel.
           </p>
+          <p className="text-xs opacity-90">
+            Hold Shift while releasing to keep capturing more regions.
+          </p>
+          {capturedRegions.length > 0 && (
+            <p className="text-xs text-blue-300 mt-1">
+              {capturedRegions.length} region
+              {capturedRegions.length === 1 ? "" : "s"} captured. Esc to
+              finish.
+            </p>
+          )}
         </div>
       ) : (
         <div className="absolute inset-0 flex items-center justify-center">
@@ -185,6 +221,15 @@ export const ClippingOverlay: React.FC = () => {
         </div>
       )}
 
+      {!isCapturing &&
+        capturedRegions.map((region, i) => (
+          <div
+            key={i}
+            className="pointer-events-none absolute border-2 border-dashed border-blue-300/70"
+            style={region}
+          />
+        ))}
+
       {selectionRect && !isCapturing && (
         <div
           className="pointer-events-none absolute border-2 border-blue-500 bg-blue-500/5 shadow-[0_0_0_9999px_rgba(0,0,0,0.5)]"
@@ -0,0 +1,101 @@
+import React, { useEffect, useState } from "react";
+import { convertFileSrc } from "@tauri-apps/api/core";
+import { XIcon } from "lucide-react";
+import { CaptureEntry, commands } from "@/bindings";
+import { formatError } from "@/lib/utils/errors";
+
+interface CaptureHistoryProps {
+  onAttach: (base64: string) => void;
+  onClose: () => void;
+}
+
+/** Recent captures, so a clip taken for one chat can be attached to another */
+export const CaptureHistory: React.FC<CaptureHistoryProps> = ({
+  onAttach,
+  onClose,
+}) => {
+  const [captures, setCaptures] = useState<CaptureEntry[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    commands.listCaptures().then((result) => {
+      if (result.status === "ok") {
+        setCaptures(result.data);
+      } else {
+        setError(formatError(result.error));
+      }
+    });
+  }, []);
+
+  const handleAttach = async (id: number) => {
+    const result = await commands.loadCapture(id);
+    if (result.status === "ok") {
+      onAttach(result.data);
+      onClose();
+    } else {
+      setError(formatError(result.error));
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    const result = await commands.deleteCapture(id);
+    if (result.status === "ok") {
+      setCaptures((prev) => prev?.filter((c) => c.id !== id) ?? null);
+    } else {
+      setError(formatError(result.error));
+    }
+  };
+
+  return (
+    <div className="absolute bottom-full left-0 z-20 mb-2 w-80 rounded-lg border border-[var(--color-text)]/20 bg-[var(--color-background)] p-2 shadow-lg">
+      <div className="mb-2 flex items-center justify-between px-1">
+        <span className="text-xs font-medium text-[var(--color-text)]/70">
+          Recent captures
+        </span>
+        <button
+          onClick={onClose}
+          className="rounded p-0.5 text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10"
+        >
+          <XIcon className="h-3 w-3" />
+        </button>
+      </div>
+      {error && <p className="px-1 text-xs text-red-400">{error}</p>}
+      {captures && captures.length === 0 && (
+        <p className="px-1 py-2 text-xs text-[var(--color-text)]/50">
+          No captures yet
+        </p>
+      )}
+      <div className="grid max-h-64 grid-cols-3 gap-2 overflow-y-auto">
+        {captures?.map((capture) => (
+          <div
+            key={capture.id}
+            className="group relative overflow-hidden rounded border border-[var(--color-text)]/20"
+            title={[
+              new Date(capture.timestamp * 1000).toLocaleString(),
+              capture.source_app,
+            ]
+              .filter(Boolean)
+              .join(" · ")}
+          >
+            <button
+              onClick={() => handleAttach(capture.id)}
+              className="block h-16 w-full"
+            >
+              <img
+                src={convertFileSrc(capture.thumbnail_path, "asset")}
+                alt={`${capture.kind} capture`}
+                className="h-full w-full object-cover"
+              />
+            </button>
+            <button
+              onClick={() => handleDelete(capture.id)}
+              className="absolute right-0.5 top-0.5 hidden rounded-full bg-black/50 p-0.5 text-white hover:bg-black/70 group-hover:block"
+            >
+              <XIcon className="h-3 w-3" />
+            </button>
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+};
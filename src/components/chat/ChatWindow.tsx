@@ -5,7 +5,16 @@ import {
   type ChatModelAdapter,
 } from "@assistant-ui/react";
 import { Thread } from "./Thread";
-import { commands, LLMModel, LLMProvider, ChatMessage } from "@/bindings";
+import {
+  commands,
+  LLMModel,
+  LLMProvider,
+  ChatMessage,
+  ChatResponse,
+  FileAttachment,
+  GeneratedImages,
+  Result,
+} from "@/bindings";
 import {
   XIcon,
   ChevronDownIcon,
@@ -14,9 +23,69 @@ import {
   CheckIcon,
 } from "lucide-react";
 import { cn } from "@/lib/utils/cn";
+import { formatError } from "@/lib/utils/errors";
 import { useThreadRuntime, useThread } from "@assistant-ui/react";
 import { useChatPersistence } from "../../hooks/useChatPersistence";
 import { getCurrentWindow } from "@tauri-apps/api/window";
+import { listen } from "@tauri-apps/api/event";
+import { convertFileSrc } from "@tauri-apps/api/core";
+
+/** Messages starting with this generate an image instead of a text reply */
+const IMAGE_COMMAND = "/image ";
+
+/** Markdown showing generated images, followed by any text the model added */
+const imageMarkdown = (result: GeneratedImages) =>
+  [
+    ...result.paths.map(
+      (path) => `![Generated image](${convertFileSrc(path, "asset")})`,
+    ),
+    result.text ?? "",
+  ]
+    .filter(Boolean)
+    .join("\n\n");
+
+/**
+ * Run a chat completion, yielding the response text received so far as it
+ * streams in, and returning the final response
+ */
+async function* streamChatCompletion(
+  messages: ChatMessage[],
+  modelId: string | null,
+  enableGrounding: boolean,
+  enableTools: boolean,
+): AsyncGenerator<string, Result<ChatResponse, string>> {
+  const streamId = crypto.randomUUID();
+  let streamed = "";
+  let done = false;
+  let wake: (() => void) | null = null;
+
+  const unlisten = await listen<{ stream_id: string; delta: string }>(
+    "chat-chunk",
+    (event) => {
+      if (event.payload.stream_id !== streamId) return;
+      streamed += event.payload.delta;
+      wake?.();
+    },
+  );
+  const request = commands
+    .chatCompletion(messages, modelId, enableGrounding, enableTools, streamId)
+    .finally(() => {
+      done = true;
+      wake?.();
+    });
+
+  try {
+    while (!done) {
+      await new Promise<void>((resolve) => {
+        wake = resolve;
+      });
+      if (!done && streamed) yield streamed;
+    }
+  } finally {
+    unlisten();
+  }
+  return await request;
+}
 
 // Component to copy entire chat as markdown (must be inside AssistantRuntimeProvider)
 const CopyAllHeader: React.FC = () => {
@@ -79,13 +148,18 @@ const CopyAllHeader: React.FC = () => {
 
 interface ChatWindowProps {
   initialContext?: string;
-  initialMessages?: Array<{ role: string; content: string }>;
   onClose?: () => void;
 }
 
-// Component to load initial messages into the thread (for forked conversations)
+/** A stored message restored into the thread, with its base64 images */
+type InitialMessage = Pick<
+  ChatMessage,
+  "role" | "content" | "images" | "files"
+>;
+
+// Component to load initial messages into the thread (for saved and forked chats)
 const InitialMessageLoader: React.FC<{
-  messages?: Array<{ role: string; content: string }>;
+  messages?: InitialMessage[];
 }> = ({ messages }) => {
   const runtime = useThreadRuntime();
   const [loaded, setLoaded] = React.useState(false);
@@ -106,7 +180,15 @@ const InitialMessageLoader: React.FC<{
             role: (msg.role === "user" ? "user" : "assistant") as
               | "user"
               | "assistant",
-            content: [{ type: "text" as const, text: msg.content || "" }],
+            content: [
+              { type: "text" as const, text: msg.content || "" },
+              ...(msg.role === "user" ? (msg.images ?? []) : []).map(
+                (image) => ({
+                  type: "image" as const,
+                  image: `data:image/png;base64,${image}`,
+                }),
+              ),
+            ],
             createdAt: new Date(),
             status: { type: "complete" as const },
             // Add metadata with unstable_state to satisfy @assistant-ui/react
@@ -139,20 +221,26 @@ import { ModelsDropdown } from "../ui/ModelsDropdown";
 
 export const ChatWindow: React.FC<ChatWindowProps> = ({
   initialContext,
-  initialMessages,
   onClose,
 }) => {
   const [models, setModels] = useState<LLMModel[]>([]);
   const [providers, setProviders] = useState<LLMProvider[]>([]);
   const [selectedModelId, setSelectedModelId] = useState<string | null>(null);
   const [currentInitialMessages, setCurrentInitialMessages] = useState<
-    Array<{ role: string; content: string }> | undefined
-  >(initialMessages);
+    InitialMessage[] | undefined
+  >();
   const [isLoading, setIsLoading] = useState(true);
   const [initialPrompt, setInitialPrompt] = useState("");
   const [attachments, setAttachments] = useState<string[]>([]);
+  const [files, setFiles] = useState<FileAttachment[]>([]);
+  // Files sent with each user message, by thread message ID, so they're included
+  // again in later requests of the conversation
+  const messageFilesRef = useRef(new Map<string, FileAttachment[]>());
   const [chatIdState, setChatIdState] = useState<number | null>(null);
   const [webSearchEnabled, setWebSearchEnabled] = useState(true); // Default to enabled for Gemini
+  const [toolsEnabled, setToolsEnabled] = useState(false);
+  const [readAloud, setReadAloud] = useState(false);
+  const [isSpeaking, setIsSpeaking] = useState(false);
   const { saveChat, updateChat, getChat, generateTitle, updateChatTitle } =
     useChatPersistence();
 
@@ -163,7 +251,7 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
         // Check for chatId in URL
         const urlParams = new URLSearchParams(window.location.search);
         const urlChatId = urlParams.get("chatId");
-        let messagesToLoad = initialMessages;
+        let savedModelId: string | null = null;
 
         if (urlChatId) {
           const id = parseInt(urlChatId);
@@ -171,11 +259,18 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
             const savedChat = await getChat(id);
             if (savedChat) {
               setChatIdState(id);
-              const msgs = savedChat.messages.map((msg) => ({
-                role: msg.role,
-                content: msg.content,
-              }));
-              setCurrentInitialMessages(msgs);
+              savedModelId = savedChat.model_id;
+              // The system prompt is rebuilt for every request
+              const restored = savedChat.messages.filter(
+                (msg) => msg.role !== "system",
+              );
+              // Restored messages get IDs by position (see InitialMessageLoader)
+              restored.forEach((msg, index) => {
+                if (msg.files?.length) {
+                  messageFilesRef.current.set(`forked-${index}`, msg.files);
+                }
+              });
+              setCurrentInitialMessages(restored);
               console.log("ChatWindow: Loaded saved chat", id);
               // Set window title to the saved chat's title
               if (savedChat.title && savedChat.title !== "New Chat") {
@@ -213,10 +308,14 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
 
         if (settingsResult.status === "ok") {
           setInitialPrompt(settingsResult.data.quick_chat_initial_prompt || "");
+          setReadAloud(settingsResult.data.chat_read_aloud ?? false);
         }
 
-        // Use default chat model if available and enabled
-        if (defaultModelsResult.chat) {
+        // Reopened chats keep their model, otherwise use the default chat
+        // model if available and enabled
+        if (savedModelId && enabledModels.some((m) => m.id === savedModelId)) {
+          setSelectedModelId(savedModelId);
+        } else if (defaultModelsResult.chat) {
           const isEnabled = enabledModels.some(
             (m) => m.id === defaultModelsResult.chat,
           );
@@ -240,32 +339,43 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
     // Poll for pending clips instead of using events (events are unreliable across windows)
     const pollInterval = setInterval(async () => {
       try {
-        const pendingClip = await commands.getPendingClip();
-        if (pendingClip) {
-          console.log(
-            "ChatWindow: Got pending clip",
-            pendingClip.length,
-            "chars",
-          );
-          setAttachments((prev) => [...prev, pendingClip]);
+        const pendingClips = await commands.getPendingClips();
+        if (pendingClips.length > 0) {
+          console.log("ChatWindow: Got", pendingClips.length, "pending clip(s)");
+          setAttachments((prev) => [...prev, ...pendingClips]);
         }
       } catch (err) {
         // Ignore errors during polling
       }
     }, 500);
 
+    // Speech started from any window, so the stop control shows wherever it plays
+    const unlistenPlayback = listen<boolean>("tts-playback", (event) =>
+      setIsSpeaking(event.payload),
+    );
+
     return () => {
       clearInterval(pollInterval);
+      unlistenPlayback.then((unlisten) => unlisten());
     };
   }, []);
 
+  const handleReadAloudChange = async (enabled: boolean) => {
+    setReadAloud(enabled);
+    await commands.changeChatReadAloudSetting(enabled);
+    if (!enabled && isSpeaking) await commands.stopTts();
+  };
+
   // Stabilize adapter and accessories with refs
   const attachmentsRef = useRef(attachments);
+  const filesRef = useRef(files);
   const selectedModelIdRef = useRef(selectedModelId);
   const initialPromptRef = useRef(initialPrompt);
   const initialContextRef = useRef(initialContext);
   const chatIdStateRef = useRef(chatIdState);
   const webSearchEnabledRef = useRef(webSearchEnabled);
+  const toolsEnabledRef = useRef(toolsEnabled);
+  const readAloudRef = useRef(readAloud);
 
   // Compute selected model's provider_id
   const selectedModelProviderId = selectedModelId
@@ -275,19 +385,25 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
 
   useEffect(() => {
     attachmentsRef.current = attachments;
+    filesRef.current = files;
     selectedModelIdRef.current = selectedModelId;
     initialPromptRef.current = initialPrompt;
     initialContextRef.current = initialContext;
     chatIdStateRef.current = chatIdState;
     webSearchEnabledRef.current = webSearchEnabled;
+    toolsEnabledRef.current = toolsEnabled;
+    readAloudRef.current = readAloud;
     selectedModelProviderIdRef.current = selectedModelProviderId;
   }, [
     attachments,
+    files,
     selectedModelId,
     initialPrompt,
     initialContext,
     chatIdState,
     webSearchEnabled,
+    toolsEnabled,
+    readAloud,
     selectedModelProviderId,
   ]);
 
@@ -314,6 +430,11 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
           images: null,
         });
 
+        const newest = messages[messages.length - 1];
+        if (newest?.role === "user" && filesRef.current.length > 0) {
+          messageFilesRef.current.set(newest.id, filesRef.current);
+        }
+
         const formattedMessages = [
           ...allMessages,
           ...messages.map((msg, index) => ({
@@ -331,9 +452,42 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
               msg.role === "user" && index === messages.length - 1
                 ? attachmentsRef.current
                 : null,
+            files:
+              msg.role === "user"
+                ? (messageFilesRef.current.get(msg.id) ?? null)
+                : null,
           })),
         ];
 
+        const lastMessage = formattedMessages[formattedMessages.length - 1];
+        if (
+          lastMessage.role === "user" &&
+          lastMessage.content.startsWith(IMAGE_COMMAND)
+        ) {
+          let prompt = lastMessage.content.slice(IMAGE_COMMAND.length).trim();
+          if (initialContextRef.current) {
+            prompt += `\n\nContext:\n${initialContextRef.current}`;
+          }
+          const result = await commands.generateImage(
+            prompt,
+            selectedModelIdRef.current,
+            lastMessage.images || null,
+          );
+          if (result.status === "ok") setAttachments([]);
+          yield {
+            content: [
+              {
+                type: "text" as const,
+                text:
+                  result.status === "ok"
+                    ? imageMarkdown(result.data)
+                    : `Error: ${formatError(result.error)}`,
+              },
+            ],
+          };
+          return;
+        }
+
         try {
           // Use web search if enabled AND provider supports it (Gemini, Gemini OAuth, or Anthropic)
           const enableGrounding =
@@ -341,16 +495,34 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
             (selectedModelProviderIdRef.current === "gemini" ||
               selectedModelProviderIdRef.current === "gemini_oauth" ||
               selectedModelProviderIdRef.current === "anthropic");
-          const response = await commands.chatCompletion(
+          const stream = streamChatCompletion(
             formattedMessages as any,
             selectedModelIdRef.current,
             enableGrounding,
+            // Codex requests don't support tools
+            toolsEnabledRef.current &&
+              selectedModelProviderIdRef.current !== "openai_oauth",
           );
+          let next = await stream.next();
+          while (!next.done) {
+            yield { content: [{ type: "text" as const, text: next.value }] };
+            next = await stream.next();
+          }
+          const response = next.value;
 
           if (response.status === "ok") {
             setAttachments([]);
+            setFiles([]);
             const assistantContent = response.data.content;
 
+            if (readAloudRef.current) {
+              commands.speakText(assistantContent).then((result) => {
+                if (result.status === "error") {
+                  console.error("Failed to read reply aloud:", result.error);
+                }
+              });
+            }
+
             // Auto-save logic
             setTimeout(async () => {
               try {
@@ -358,6 +530,7 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
                   role: msg.role,
                   content: msg.content,
                   images: msg.images || null,
+                  files: msg.files || null,
                 }));
 
                 // Add the new assistant message
@@ -365,13 +538,22 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
                   role: "assistant",
                   content: assistantContent,
                   images: null,
+                  files: null,
                 });
 
                 if (chatIdStateRef.current) {
-                  await updateChat(chatIdStateRef.current, currentMessages);
+                  await updateChat(
+                    chatIdStateRef.current,
+                    currentMessages,
+                    selectedModelIdRef.current,
+                  );
                 } else {
                   // New chat - save and generate title
-                  const newId = await saveChat(currentMessages, "New Chat");
+                  const newId = await saveChat(
+                    currentMessages,
+                    "New Chat",
+                    selectedModelIdRef.current,
+                  );
                   setChatIdState(newId);
 
                   // Generate title after first user msg + assistant resp
@@ -411,14 +593,19 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
               metadata: {
                 custom: {
                   groundingMetadata: response.data.grounding_metadata,
+                  toolCalls: response.data.tool_calls,
                 },
               },
             };
           } else {
             yield {
               content: [
-                { type: "text" as const, text: `Error: ${response.error}` },
+                {
+                  type: "text" as const,
+                  text: `Error: ${formatError(response.error)}`,
+                },
               ],
+              metadata: { custom: { error: response.error } },
             };
           }
         } catch (error) {
@@ -461,12 +648,19 @@ export const ChatWindow: React.FC<ChatWindowProps> = ({
           <Thread
             attachments={attachments}
             setAttachments={setAttachments}
+            files={files}
+            setFiles={setFiles}
             selectedModelId={selectedModelId}
             setSelectedModelId={setSelectedModelId}
             models={models}
             isLoading={isLoading}
             webSearchEnabled={webSearchEnabled}
             setWebSearchEnabled={setWebSearchEnabled}
+            toolsEnabled={toolsEnabled}
+            setToolsEnabled={setToolsEnabled}
+            readAloud={readAloud}
+            setReadAloud={handleReadAloudChange}
+            isSpeaking={isSpeaking}
             selectedModelProviderId={selectedModelProviderId}
           />
         </AssistantRuntimeProvider>
@@ -9,7 +9,12 @@ import {
 } from "@assistant-ui/react";
 import Markdown from "react-markdown";
 import remarkGfm from "remark-gfm";
-import { GroundingMetadata } from "@/bindings";
+import {
+  AppError,
+  FileAttachment,
+  GroundingMetadata,
+  ToolCallRecord,
+} from "@/bindings";
 import { useState } from "react";
 import {
   ArrowUpIcon,
@@ -24,10 +29,15 @@ import {
   Loader2Icon,
   GitForkIcon,
   GlobeIcon,
+  WrenchIcon,
+  Volume2Icon,
+  VolumeXIcon,
+  SquareIcon,
 } from "lucide-react";
 import React, { useEffect } from "react";
 import type { FC } from "react";
 import { cn } from "@/lib/utils/cn";
+import { formatError } from "@/lib/utils/errors";
 import { Button } from "@/components/ui/Button";
 
 import { ModelsDropdown } from "@/components/ui/ModelsDropdown";
@@ -37,55 +47,81 @@ import { Mermaid } from "./Mermaid";
 interface ThreadProps {
   attachments: string[];
   setAttachments: React.Dispatch<React.SetStateAction<string[]>>;
+  files: FileAttachment[];
+  setFiles: React.Dispatch<React.SetStateAction<FileAttachment[]>>;
   selectedModelId: string | null;
   setSelectedModelId: (id: string | null) => void;
   models: any[];
   isLoading: boolean;
   webSearchEnabled: boolean;
   setWebSearchEnabled: (enabled: boolean) => void;
+  toolsEnabled: boolean;
+  setToolsEnabled: (enabled: boolean) => void;
+  readAloud: boolean;
+  setReadAloud: (enabled: boolean) => void;
+  isSpeaking: boolean;
   selectedModelProviderId: string | null;
 }
 
+/** The chat's model, for actions deep in the message tree like forking */
+const SelectedModelContext = React.createContext<string | null>(null);
+
 export const Thread: FC<ThreadProps> = ({
   attachments,
   setAttachments,
+  files,
+  setFiles,
   selectedModelId,
   setSelectedModelId,
   models,
   isLoading,
   webSearchEnabled,
   setWebSearchEnabled,
+  toolsEnabled,
+  setToolsEnabled,
+  readAloud,
+  setReadAloud,
+  isSpeaking,
   selectedModelProviderId,
 }) => {
   return (
-    <ThreadPrimitive.Root className="aui-root flex h-full flex-col bg-[var(--color-background)]">
-      <ThreadPrimitive.Viewport className="flex flex-1 flex-col overflow-y-auto scroll-smooth px-4 pt-4 pb-[170px]">
-        <ThreadPrimitive.Empty>
-          <ThreadWelcome />
-        </ThreadPrimitive.Empty>
-
-        <ThreadPrimitive.Messages
-          components={{
-            UserMessage,
-            AssistantMessage,
-          }}
-        />
-      </ThreadPrimitive.Viewport>
-
-      <div className="absolute bottom-0 left-0 right-0 mx-auto w-full max-w-[900px] px-4 pb-4 bg-gradient-to-t from-[var(--color-background)] via-[var(--color-background)] to-transparent pt-8 pointer-events-none">
-        <Composer
-          attachments={attachments}
-          setAttachments={setAttachments}
-          selectedModelId={selectedModelId}
-          setSelectedModelId={setSelectedModelId}
-          models={models}
-          isLoading={isLoading}
-          webSearchEnabled={webSearchEnabled}
-          setWebSearchEnabled={setWebSearchEnabled}
-          selectedModelProviderId={selectedModelProviderId}
-        />
-      </div>
-    </ThreadPrimitive.Root>
+    <SelectedModelContext.Provider value={selectedModelId}>
+      <ThreadPrimitive.Root className="aui-root flex h-full flex-col bg-[var(--color-background)]">
+        <ThreadPrimitive.Viewport className="flex flex-1 flex-col overflow-y-auto scroll-smooth px-4 pt-4 pb-[170px]">
+          <ThreadPrimitive.Empty>
+            <ThreadWelcome />
+          </ThreadPrimitive.Empty>
+
+          <ThreadPrimitive.Messages
+            components={{
+              UserMessage,
+              AssistantMessage,
+            }}
+          />
+        </ThreadPrimitive.Viewport>
+
+        <div className="absolute bottom-0 left-0 right-0 mx-auto w-full max-w-[900px] px-4 pb-4 bg-gradient-to-t from-[var(--color-background)] via-[var(--color-background)] to-transparent pt-8 pointer-events-none">
+          <Composer
+            attachments={attachments}
+            setAttachments={setAttachments}
+            files={files}
+            setFiles={setFiles}
+            selectedModelId={selectedModelId}
+            setSelectedModelId={setSelectedModelId}
+            models={models}
+            isLoading={isLoading}
+            webSearchEnabled={webSearchEnabled}
+            setWebSearchEnabled={setWebSearchEnabled}
+            toolsEnabled={toolsEnabled}
+            setToolsEnabled={setToolsEnabled}
+            readAloud={readAloud}
+            setReadAloud={setReadAloud}
+            isSpeaking={isSpeaking}
+            selectedModelProviderId={selectedModelProviderId}
+          />
+        </div>
+      </ThreadPrimitive.Root>
+    </SelectedModelContext.Provider>
   );
 };
 
@@ -104,21 +140,37 @@ const ThreadWelcome: FC = () => {
   );
 };
 
-import { CameraIcon } from "lucide-react";
+import {
+  CameraIcon,
+  FileTextIcon,
+  HistoryIcon,
+  PaperclipIcon,
+} from "lucide-react";
 import { commands } from "@/bindings";
+import { open } from "@tauri-apps/plugin-dialog";
+import { getCurrentWindow } from "@tauri-apps/api/window";
+import { CaptureHistory } from "./CaptureHistory";
 
 const Composer: FC<ThreadProps> = ({
   attachments,
   setAttachments,
+  files,
+  setFiles,
   selectedModelId,
   setSelectedModelId,
   models,
   isLoading,
   webSearchEnabled,
   setWebSearchEnabled,
+  toolsEnabled,
+  setToolsEnabled,
+  readAloud,
+  setReadAloud,
+  isSpeaking,
   selectedModelProviderId,
 }) => {
   console.log("Composer rendering with attachments:", attachments.length);
+  const [showCaptureHistory, setShowCaptureHistory] = useState(false);
 
   // Reinforce focus on mount
   useEffect(() => {
@@ -150,10 +202,37 @@ const Composer: FC<ThreadProps> = ({
     selectedModelProviderId === "gemini_oauth" ||
     selectedModelProviderId === "anthropic";
 
+  // Codex requests don't support tool calling
+  const areToolsSupported = selectedModelProviderId !== "openai_oauth";
+
   const removeAttachment = (index: number) => {
     setAttachments((prev) => prev.filter((_, i) => i !== index));
   };
 
+  const handleAttachFile = async () => {
+    const selected = await open({
+      multiple: true,
+      filters: [
+        { name: "Documents", extensions: ["txt", "md", "markdown", "pdf"] },
+      ],
+    });
+    if (!selected) return;
+    const paths = Array.isArray(selected) ? selected : [selected];
+    for (const path of paths) {
+      const result = await commands.attachChatFile(path);
+      if (result.status === "ok") {
+        setFiles((prev) => [...prev, result.data]);
+      } else {
+        console.error("Failed to attach file:", result.error);
+        alert(formatError(result.error));
+      }
+    }
+  };
+
+  const removeFile = (index: number) => {
+    setFiles((prev) => prev.filter((_, i) => i !== index));
+  };
+
   return (
     <div className="flex w-full flex-col gap-2 pointer-events-auto">
       {/* Attachment Previews */}
@@ -180,6 +259,37 @@ const Composer: FC<ThreadProps> = ({
         </div>
       )}
 
+      {/* Attached files */}
+      {files.length > 0 && (
+        <div className="flex flex-wrap gap-2">
+          {files.map((file, index) => (
+            <div
+              key={index}
+              className="flex items-center gap-1.5 rounded-full border border-[var(--color-text)]/20 bg-[var(--color-text)]/5 px-2 py-1 text-xs text-[var(--color-text)]"
+              title={
+                file.truncated
+                  ? "Only the beginning of this file will be sent"
+                  : undefined
+              }
+            >
+              <FileTextIcon className="h-3 w-3" />
+              <span className="max-w-[200px] truncate">{file.name}</span>
+              {file.chunks.length > 1 && (
+                <span className="text-[var(--color-text)]/50">
+                  {file.chunks.length} parts
+                </span>
+              )}
+              <button
+                onClick={() => removeFile(index)}
+                className="rounded-full p-0.5 hover:bg-[var(--color-text)]/10"
+              >
+                <XIcon className="h-3 w-3" />
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+
       <ComposerPrimitive.Root className="flex w-full flex-col gap-2 rounded-xl border border-[var(--color-text)]/20 bg-[var(--color-background)] p-3 shadow-sm focus-within:border-[var(--color-logo-primary)]/50 transition-colors">
         <ComposerPrimitive.Input
           placeholder="Ask a question about the screen..."
@@ -188,7 +298,7 @@ const Composer: FC<ThreadProps> = ({
         />
 
         <div className="flex items-center justify-between border-t border-[var(--color-text)]/10 pt-2 mt-auto">
-          <div className="flex items-center gap-1">
+          <div className="relative flex items-center gap-1">
             <button
               onClick={handleScreenshot}
               className="rounded p-2 text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10 hover:text-[var(--color-text)] transition-colors"
@@ -196,6 +306,28 @@ const Composer: FC<ThreadProps> = ({
             >
               <CameraIcon className="h-4 w-4" />
             </button>
+            <button
+              onClick={handleAttachFile}
+              className="rounded p-2 text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10 hover:text-[var(--color-text)] transition-colors"
+              title="Attach File (txt, md, pdf)"
+            >
+              <PaperclipIcon className="h-4 w-4" />
+            </button>
+            <button
+              onClick={() => setShowCaptureHistory(!showCaptureHistory)}
+              className="rounded p-2 text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10 hover:text-[var(--color-text)] transition-colors"
+              title="Recent Captures"
+            >
+              <HistoryIcon className="h-4 w-4" />
+            </button>
+            {showCaptureHistory && (
+              <CaptureHistory
+                onAttach={(base64) =>
+                  setAttachments((prev) => [...prev, base64])
+                }
+                onClose={() => setShowCaptureHistory(false)}
+              />
+            )}
             {isWebSearchSupported && (
               <button
                 onClick={() => setWebSearchEnabled(!webSearchEnabled)}
@@ -212,6 +344,49 @@ const Composer: FC<ThreadProps> = ({
                 <GlobeIcon className="h-4 w-4" />
               </button>
             )}
+            {areToolsSupported && (
+              <button
+                onClick={() => setToolsEnabled(!toolsEnabled)}
+                className={cn(
+                  "rounded p-2 transition-colors",
+                  toolsEnabled
+                    ? "bg-[var(--color-logo-primary)]/20 text-[var(--color-logo-primary)] hover:bg-[var(--color-logo-primary)]/30"
+                    : "text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10 hover:text-[var(--color-text)]",
+                )}
+                title={
+                  toolsEnabled
+                    ? "Tools Enabled (shell, web fetch, selection)"
+                    : "Enable Tools (shell, web fetch, selection)"
+                }
+              >
+                <WrenchIcon className="h-4 w-4" />
+              </button>
+            )}
+            <button
+              onClick={() => setReadAloud(!readAloud)}
+              className={cn(
+                "rounded p-2 transition-colors",
+                readAloud
+                  ? "bg-[var(--color-logo-primary)]/20 text-[var(--color-logo-primary)] hover:bg-[var(--color-logo-primary)]/30"
+                  : "text-[var(--color-text)]/50 hover:bg-[var(--color-text)]/10 hover:text-[var(--color-text)]",
+              )}
+              title={readAloud ? "Reading Replies Aloud" : "Read Replies Aloud"}
+            >
+              {readAloud ? (
+                <Volume2Icon className="h-4 w-4" />
+              ) : (
+                <VolumeXIcon className="h-4 w-4" />
+              )}
+            </button>
+            {isSpeaking && (
+              <button
+                onClick={() => commands.stopTts()}
+                className="rounded p-2 text-[var(--color-logo-primary)] hover:bg-[var(--color-text)]/10 transition-colors"
+                title="Stop Speaking"
+              >
+                <SquareIcon className="h-4 w-4 fill-current" />
+              </button>
+            )}
 
             <div className="mx-2 h-4 w-[1px] bg-[var(--color-text)]/10" />
 
@@ -251,11 +426,16 @@ const Composer: FC<ThreadProps> = ({
   );
 };
 
+/** Images restored with a saved chat */
+const UserImage: FC<{ image: string }> = ({ image }) => (
+  <img src={image} alt="Attachment" className="mt-2 max-h-48 rounded" />
+);
+
 const UserMessage: FC = () => {
   return (
     <MessagePrimitive.Root className="mb-4 flex justify-end">
       <div className="max-w-[900px] rounded-lg bg-[var(--color-logo-primary)] px-4 py-2 text-white">
-        <MessagePrimitive.Content />
+        <MessagePrimitive.Content components={{ Image: UserImage }} />
       </div>
     </MessagePrimitive.Root>
   );
@@ -267,6 +447,12 @@ const AssistantMessage: FC = () => {
   const groundingMetadata = message.metadata?.custom?.groundingMetadata as
     | GroundingMetadata
     | undefined;
+  // @ts-ignore - types might be slightly off with custom metadata
+  const toolCalls = message.metadata?.custom?.toolCalls as
+    | ToolCallRecord[]
+    | undefined;
+  // @ts-ignore - types might be slightly off with custom metadata
+  const error = message.metadata?.custom?.error as AppError | undefined;
 
   return (
     <MessagePrimitive.Root className="mb-4 flex flex-col group">
@@ -338,10 +524,23 @@ const AssistantMessage: FC = () => {
             </div>
           )}
         </div>
+        {error?.retryable && (
+          <ActionBarPrimitive.Reload asChild>
+            <button className="mt-2 flex items-center gap-1.5 rounded-full border border-[var(--color-text)]/10 px-3 py-1 text-xs text-[var(--color-text)]/70 hover:bg-[var(--color-text)]/5 hover:text-[var(--color-text)]">
+              <RefreshCwIcon className="h-3 w-3" />
+              <span>Retry</span>
+            </button>
+          </ActionBarPrimitive.Reload>
+        )}
         <div className="mt-1 flex items-center justify-between min-h-[32px] opacity-0 pointer-events-none group-hover:opacity-100 group-hover:pointer-events-auto transition-opacity duration-200">
           <div className="flex-1">
             <AssistantActionBar />
           </div>
+          {toolCalls && toolCalls.length > 0 && (
+            <div className="flex-shrink-0">
+              <ToolCalls calls={toolCalls} />
+            </div>
+          )}
           {groundingMetadata &&
             (groundingMetadata.chunks?.length > 0 ||
               (groundingMetadata.search_entry_point &&
@@ -356,6 +555,47 @@ const AssistantMessage: FC = () => {
   );
 };
 
+const ToolCalls: FC<{ calls: ToolCallRecord[] }> = ({ calls }) => {
+  const [isOpen, setIsOpen] = useState(false);
+
+  return (
+    <div className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        className="flex items-center gap-1.5 rounded-full border border-[var(--color-text)]/10 px-2 py-0.5 text-xs text-[var(--color-text)]/60 hover:bg-[var(--color-text)]/5 hover:text-[var(--color-text)]"
+      >
+        <WrenchIcon className="h-3 w-3" />
+        <span>
+          {calls.length} Tool Call{calls.length === 1 ? "" : "s"}
+        </span>
+        <ChevronDownIcon
+          className={cn("h-3 w-3 transition-transform", isOpen && "rotate-180")}
+        />
+      </button>
+      {isOpen && (
+        <div className="absolute bottom-full right-0 mb-2 z-50 flex max-h-[400px] w-96 flex-col gap-2 overflow-y-auto rounded-lg border border-[var(--color-text)]/20 bg-[var(--color-background)] p-3 shadow-xl">
+          <div className="mb-1 flex items-center justify-between border-b border-[var(--color-text)]/10 pb-1">
+            <span className="text-xs font-semibold">Tool Calls</span>
+            <button onClick={() => setIsOpen(false)}>
+              <XIcon className="h-3 w-3" />
+            </button>
+          </div>
+          {calls.map((call, i) => (
+            <div key={i} className="flex flex-col gap-1 text-xs">
+              <span className="font-mono font-semibold">
+                {call.name} {call.arguments}
+              </span>
+              <pre className="max-h-32 overflow-auto whitespace-pre-wrap rounded bg-black/10 p-2 text-[var(--color-text)]/70">
+                {call.output}
+              </pre>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
+
 const SearchGrounding: FC<{ metadata: GroundingMetadata }> = ({ metadata }) => {
   const [isOpen, setIsOpen] = useState(false);
 
@@ -500,6 +740,7 @@ const SearchGrounding: FC<{ metadata: GroundingMetadata }> = ({ metadata }) => {
 const AssistantActionBar: FC = () => {
   const thread = useThread();
   const message = useMessage();
+  const selectedModelId = React.useContext(SelectedModelContext);
 
   const handleFork = async () => {
     // Find the index of the current message
@@ -511,24 +752,54 @@ const AssistantActionBar: FC = () => {
     const forkedMessages = messages
       .slice(0, messageIndex + 1)
       .filter((msg) => msg.role === "user" || msg.role === "assistant")
-      .map((msg) => ({
-        role: msg.role,
-        content: msg.content
+      .map((msg) => {
+        const images = msg.content
           .filter(
-            (part): part is { type: "text"; text: string } =>
-              part.type === "text",
+            (part): part is { type: "image"; image: string } =>
+              part.type === "image",
           )
-          .map((part) => part.text)
-          .join(""),
-      }));
+          .map((part) => part.image.replace(/^data:[^,]*;base64,/, ""));
+        return {
+          role: msg.role,
+          content: msg.content
+            .filter(
+              (part): part is { type: "text"; text: string } =>
+                part.type === "text",
+            )
+            .map((part) => part.text)
+            .join(""),
+          images: images.length > 0 ? images : null,
+        };
+      });
 
     try {
-      await commands.openChatWindowWithMessages(forkedMessages);
+      const title = await getCurrentWindow().title();
+      const result = await commands.forkChat(
+        title && title !== "Ramble Chat" ? title : null,
+        forkedMessages,
+        selectedModelId,
+      );
+      if (result.status === "error") {
+        console.error("Failed to fork conversation:", result.error);
+      }
     } catch (error) {
       console.error("Failed to fork conversation:", error);
     }
   };
 
+  const handleSpeak = async () => {
+    const text = message.content
+      .filter(
+        (part): part is { type: "text"; text: string } => part.type === "text",
+      )
+      .map((part) => part.text)
+      .join("");
+    const result = await commands.speakText(text);
+    if (result.status === "error") {
+      console.error("Failed to read message aloud:", result.error);
+    }
+  };
+
   return (
     <ActionBarPrimitive.Root
       // hideWhenRunning // Disabled to prevent layout shift
@@ -547,6 +818,13 @@ const AssistantActionBar: FC = () => {
       >
         <GitForkIcon className="h-4 w-4" />
       </button>
+      <button
+        onClick={handleSpeak}
+        className="rounded p-1 hover:bg-[var(--color-text)]/10 active:scale-95 active:bg-[var(--color-text)]/20 transition-all"
+        title="Read aloud"
+      >
+        <Volume2Icon className="h-4 w-4" />
+      </button>
       <ActionBarPrimitive.Reload asChild>
         <button className="rounded p-1 hover:bg-[var(--color-text)]/10 active:scale-95 active:bg-[var(--color-text)]/20 transition-all">
           <RefreshCwIcon className="h-4 w-4" />
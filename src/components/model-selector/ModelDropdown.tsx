@@ -35,8 +35,13 @@ const ModelDropdown: React.FC<ModelDropdownProps> = ({
   onError,
 }) => {
   const { t } = useTranslation();
+  // Cloud models have nothing to download and are enabled by adding an API key
+  const isCloudModel = (m: ModelInfo) =>
+    m.engine_type === "OpenAiCloud" || m.engine_type === "DeepgramCloud";
   const availableModels = models.filter((m) => m.is_downloaded);
-  const downloadableModels = models.filter((m) => !m.is_downloaded);
+  const downloadableModels = models.filter(
+    (m) => !m.is_downloaded && !isCloudModel(m),
+  );
   const isFirstRun = availableModels.length === 0 && models.length > 0;
 
   const handleDeleteClick = async (e: React.MouseEvent, modelId: string) => {
@@ -118,7 +123,7 @@ const ModelDropdown: React.FC<ModelDropdownProps> = ({
                       {t("modelSelector.active")}
                     </div>
                   )}
-                  {currentModelId !== model.id && (
+                  {currentModelId !== model.id && !isCloudModel(model) && (
                     <button
                       onClick={(e) => handleDeleteClick(e, model.id)}
                       className="text-red-400 hover:text-red-300 p-1 hover:bg-red-500/10 rounded transition-colors"
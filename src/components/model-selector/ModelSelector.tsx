@@ -6,6 +6,7 @@ import { getTranslatedModelName } from "../../lib/utils/modelTranslation";
 import ModelStatusButton from "./ModelStatusButton";
 import ModelDropdown from "./ModelDropdown";
 import DownloadProgressDisplay from "./DownloadProgressDisplay";
+import { formatError } from "@/lib/utils/errors";
 
 interface ModelStateEvent {
   event_type: string;
@@ -293,7 +294,7 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({ onError }) => {
       setShowModelDropdown(false);
       const result = await commands.setActiveModel(modelId);
       if (result.status === "error") {
-        const errorMsg = result.error;
+        const errorMsg = formatError(result.error);
         setModelError(errorMsg);
         setModelStatus("error");
         onError?.(errorMsg);
@@ -311,7 +312,7 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({ onError }) => {
       setModelError(null);
       const result = await commands.downloadModel(modelId);
       if (result.status === "error") {
-        const errorMsg = result.error;
+        const errorMsg = formatError(result.error);
         setModelError(errorMsg);
         setModelStatus("error");
         onError?.(errorMsg);
@@ -1,8 +1,13 @@
 import React, { useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
-import { commands, type ModelInfo } from "@/bindings";
+import {
+  commands,
+  type ModelInfo,
+  type SuggestedSetup,
+} from "@/bindings";
 import ModelCard from "./ModelCard";
 import RambleTextLogo from "../icons/RambleTextLogo";
+import { formatError } from "@/lib/utils/errors";
 
 interface OnboardingProps {
   onModelSelected: () => void;
@@ -13,9 +18,16 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
   const [availableModels, setAvailableModels] = useState<ModelInfo[]>([]);
   const [downloading, setDownloading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [suggestedSetup, setSuggestedSetup] = useState<SuggestedSetup | null>(
+    null,
+  );
 
   useEffect(() => {
     loadModels();
+    commands
+      .generateSuggestedSetup()
+      .then(setSuggestedSetup)
+      .catch((err) => console.error("Failed to suggest a setup:", err));
   }, []);
 
   const loadModels = async () => {
@@ -23,7 +35,14 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
       const result = await commands.getAvailableModels();
       if (result.status === "ok") {
         // Only show downloadable models for onboarding
-        setAvailableModels(result.data.filter((m) => !m.is_downloaded));
+        setAvailableModels(
+          result.data.filter(
+            (m) =>
+              !m.is_downloaded &&
+              m.engine_type !== "OpenAiCloud" &&
+              m.engine_type !== "DeepgramCloud",
+          ),
+        );
       } else {
         setError(t("onboarding.errors.loadModels"));
       }
@@ -37,6 +56,14 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
     setDownloading(true);
     setError(null);
 
+    // Tailor categories to the installed apps before the first dictation
+    if (suggestedSetup) {
+      const applied = await commands.applySuggestedSetup(suggestedSetup);
+      if (applied.status === "error") {
+        console.error("Failed to apply suggested setup:", applied.error);
+      }
+    }
+
     // Immediately transition to main app - download will continue in footer
     onModelSelected();
 
@@ -44,7 +71,11 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
       const result = await commands.downloadModel(modelId);
       if (result.status === "error") {
         console.error("Download failed:", result.error);
-        setError(t("onboarding.errors.downloadModel", { error: result.error }));
+        setError(
+          t("onboarding.errors.downloadModel", {
+            error: formatError(result.error),
+          }),
+        );
         setDownloading(false);
       }
     } catch (err) {
@@ -65,6 +96,16 @@ const Onboarding: React.FC<OnboardingProps> = ({ onModelSelected }) => {
         <p className="text-text/70 max-w-md font-medium mx-auto">
           {t("onboarding.subtitle")}
         </p>
+        {suggestedSetup && suggestedSetup.categories.length > 0 && (
+          <p className="text-xs text-mid-gray max-w-md text-center mx-auto">
+            {t("onboarding.suggestedSetup", {
+              categories: suggestedSetup.categories
+                .map((c) => c.name)
+                .join(", "),
+              count: suggestedSetup.app_mappings.length,
+            })}
+          </p>
+        )}
       </div>
 
       <div className="max-w-[600px] w-full mx-auto text-center flex-1 flex flex-col min-h-0">
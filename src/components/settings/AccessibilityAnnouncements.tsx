@@ -0,0 +1,32 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface AccessibilityAnnouncementsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const AccessibilityAnnouncements: React.FC<AccessibilityAnnouncementsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("accessibility_announcements") ?? true;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) =>
+          updateSetting("accessibility_announcements", value)
+        }
+        isUpdating={isUpdating("accessibility_announcements")}
+        label={t("settings.sound.accessibilityAnnouncements.label")}
+        description={t("settings.sound.accessibilityAnnouncements.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
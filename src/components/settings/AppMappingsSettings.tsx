@@ -1,6 +1,15 @@
 import React, { useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
-import { ChevronDown, ChevronRight, Trash2, Plus } from "lucide-react";
+import {
+  ChevronDown,
+  ChevronRight,
+  Trash2,
+  Plus,
+  Download,
+  Upload,
+  X,
+} from "lucide-react";
+import { open, save } from "@tauri-apps/plugin-dialog";
 import {
   commands,
   AppCategoryMapping,
@@ -10,6 +19,7 @@ import {
 
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "@/lib/utils/errors";
 
 // Type for apps we can display in the dropdown
 interface AppOption {
@@ -37,6 +47,9 @@ export const AppMappingsSettings: React.FC = () => {
   const [selectedApp, setSelectedApp] = useState<AppOption | null>(null);
   const [selectedCategory, setSelectedCategory] = useState("medium");
   const [isAdding, setIsAdding] = useState(false);
+  const [includeDetectedApps, setIncludeDetectedApps] = useState(false);
+  const [transferStatus, setTransferStatus] = useState<string | null>(null);
+  const [newExclusion, setNewExclusion] = useState("");
 
   // Get current mappings from settings
   const mappings: AppCategoryMapping[] =
@@ -46,6 +59,8 @@ export const AppMappingsSettings: React.FC = () => {
   const promptCategories: PromptCategory[] = settings?.prompt_categories ?? [];
   const defaultCategoryId: string =
     (settings as any)?.default_category_id ?? "medium";
+  const recordDetectedApps = settings?.record_detected_apps ?? true;
+  const excludedApps: string[] = settings?.detected_apps_excluded ?? [];
 
   const handleDefaultCategoryChange = async (categoryId: string) => {
     try {
@@ -178,6 +193,75 @@ export const AppMappingsSettings: React.FC = () => {
     }
   };
 
+  const handleExport = async () => {
+    const path = await save({
+      defaultPath: "ramble-app-mappings.json",
+      filters: [{ name: "JSON", extensions: ["json"] }],
+    });
+    if (!path) return;
+
+    const result = await commands.exportAppMappings(path, includeDetectedApps);
+    setTransferStatus(
+      result.status === "ok"
+        ? t("settings.ramble.appMappings.exported", {
+            defaultValue: "Exported {{count}} mappings",
+            count: result.data,
+          })
+        : formatError(result.error),
+    );
+  };
+
+  const handleImport = async () => {
+    const path = await open({
+      multiple: false,
+      filters: [{ name: "JSON", extensions: ["json"] }],
+    });
+    if (!path) return;
+
+    const result = await commands.importAppMappings(path, includeDetectedApps);
+    if (result.status === "error") {
+      setTransferStatus(formatError(result.error));
+      return;
+    }
+    await refreshSettings();
+    const { mappings, skipped } = result.data;
+    setTransferStatus(
+      t("settings.ramble.appMappings.imported", {
+        defaultValue: "Imported {{count}} mappings",
+        count: mappings,
+      }) +
+        (skipped.length > 0
+          ? ` · ${t("settings.ramble.appMappings.skipped", {
+              defaultValue: "skipped (unknown category): {{apps}}",
+              apps: skipped.join(", "),
+            })}`
+          : ""),
+    );
+  };
+
+  const handleRecordDetectedAppsChange = async (enabled: boolean) => {
+    await commands.changeRecordDetectedAppsSetting(enabled);
+    await refreshSettings();
+  };
+
+  const updateExcludedApps = async (bundleIds: string[]) => {
+    await commands.changeDetectedAppsExcludedSetting(bundleIds);
+    await refreshSettings();
+  };
+
+  const handleAddExclusion = async () => {
+    const bundleId = newExclusion.trim();
+    if (!bundleId || excludedApps.includes(bundleId)) return;
+    await updateExcludedApps([...excludedApps, bundleId]);
+    setNewExclusion("");
+  };
+
+  const handleClearDetectedApps = async () => {
+    await commands.clearDetectedAppsHistory();
+    await refreshSettings();
+    setAvailableApps([]);
+  };
+
   // Filter apps based on search and exclude already mapped apps
   const filteredApps = availableApps.filter((app) => {
     const isMapped = mappings.some((m) => m.bundle_identifier === app.bundleId);
@@ -414,6 +498,129 @@ export const AppMappingsSettings: React.FC = () => {
               )}
             </p>
           </div>
+
+          {/* Export / import */}
+          <div className="space-y-2">
+            <h4 className="text-xs font-medium text-mid-gray uppercase tracking-wide">
+              {t("settings.ramble.appMappings.share", "Share Mappings")}
+            </h4>
+            <div className="flex items-center gap-2">
+              <label className="flex items-center gap-2 text-sm text-mid-gray flex-1">
+                <input
+                  type="checkbox"
+                  checked={includeDetectedApps}
+                  onChange={(e) => setIncludeDetectedApps(e.target.checked)}
+                  className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+                />
+                {t(
+                  "settings.ramble.appMappings.includeDetected",
+                  "Include detected apps",
+                )}
+              </label>
+              <button
+                onClick={handleExport}
+                className="flex items-center gap-1 px-3 py-1.5 border border-mid-gray/30 rounded-lg text-sm hover:bg-mid-gray/10 transition-colors"
+              >
+                <Download className="h-4 w-4" />
+                {t("settings.ramble.appMappings.export", "Export")}
+              </button>
+              <button
+                onClick={handleImport}
+                className="flex items-center gap-1 px-3 py-1.5 border border-mid-gray/30 rounded-lg text-sm hover:bg-mid-gray/10 transition-colors"
+              >
+                <Upload className="h-4 w-4" />
+                {t("settings.ramble.appMappings.import", "Import")}
+              </button>
+            </div>
+            {transferStatus && (
+              <p className="text-xs text-mid-gray">{transferStatus}</p>
+            )}
+          </div>
+
+          {/* Detected apps privacy */}
+          <div className="space-y-2">
+            <h4 className="text-xs font-medium text-mid-gray uppercase tracking-wide">
+              {t("settings.ramble.appMappings.privacy", "Detected Apps")}
+            </h4>
+            <div className="flex items-center gap-2">
+              <label className="flex items-center gap-2 text-sm text-mid-gray flex-1">
+                <input
+                  type="checkbox"
+                  checked={recordDetectedApps}
+                  onChange={(e) =>
+                    handleRecordDetectedAppsChange(e.target.checked)
+                  }
+                  className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+                />
+                {t(
+                  "settings.ramble.appMappings.recordDetected",
+                  "Remember apps you dictate into",
+                )}
+              </label>
+              <button
+                onClick={handleClearDetectedApps}
+                disabled={detectedApps.length === 0}
+                className="px-3 py-1.5 border border-mid-gray/30 rounded-lg text-sm hover:bg-mid-gray/10 transition-colors disabled:opacity-50"
+              >
+                {t("settings.ramble.appMappings.clearDetected", {
+                  defaultValue: "Clear history ({{count}})",
+                  count: detectedApps.length,
+                })}
+              </button>
+            </div>
+            <div className="flex flex-wrap items-center gap-1">
+              {excludedApps.map((bundleId) => (
+                <span
+                  key={bundleId}
+                  className="flex items-center gap-1 text-xs bg-mid-gray/10 px-2 py-1 rounded"
+                >
+                  {bundleId}
+                  <button
+                    onClick={() =>
+                      updateExcludedApps(
+                        excludedApps.filter((id) => id !== bundleId),
+                      )
+                    }
+                    className="text-mid-gray hover:text-red-500"
+                  >
+                    <X className="h-3 w-3" />
+                  </button>
+                </span>
+              ))}
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="text"
+                list="detected-app-ids"
+                value={newExclusion}
+                onChange={(e) => setNewExclusion(e.target.value)}
+                onKeyDown={(e) => e.key === "Enter" && handleAddExclusion()}
+                placeholder={t(
+                  "settings.ramble.appMappings.excludePlaceholder",
+                  "Never remember this app (bundle ID)",
+                )}
+                className="flex-1 px-3 py-1.5 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+              />
+              <datalist id="detected-app-ids">
+                {detectedApps.map((app) => (
+                  <option
+                    key={app.bundle_identifier}
+                    value={app.bundle_identifier}
+                  >
+                    {app.display_name}
+                  </option>
+                ))}
+              </datalist>
+              <button
+                onClick={handleAddExclusion}
+                disabled={!newExclusion.trim()}
+                className="flex items-center gap-1 px-3 py-1.5 border border-mid-gray/30 rounded-lg text-sm hover:bg-mid-gray/10 transition-colors disabled:opacity-50"
+              >
+                <Plus className="h-4 w-4" />
+                {t("settings.ramble.appMappings.exclude", "Exclude")}
+              </button>
+            </div>
+          </div>
         </div>
       )}
     </SettingsGroup>
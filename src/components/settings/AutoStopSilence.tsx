@@ -0,0 +1,69 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface AutoStopSilenceProps {
+  shortcutId: string;
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const AutoStopSilence: React.FC<AutoStopSilenceProps> = ({
+  shortcutId,
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const [isUpdating, setIsUpdating] = useState(false);
+
+  const binding = settings?.bindings?.[shortcutId];
+  if (!binding) return null;
+
+  const seconds = binding.auto_stop_silence_seconds ?? 0;
+
+  const handleChange = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const value = parseInt(event.target.value, 10);
+    if (isNaN(value) || value < 0 || value > 60) return;
+    setIsUpdating(true);
+    const result = await commands.changeBindingAutoStopSetting(
+      shortcutId,
+      value,
+    );
+    if (result.status === "error") {
+      console.error("Failed to update auto-stop:", result.error);
+    }
+    await refreshSettings();
+    setIsUpdating(false);
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.general.autoStopSilence.title", {
+        name: binding.name,
+      })}
+      description={t("settings.general.autoStopSilence.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="horizontal"
+    >
+      <div className="flex items-center space-x-2">
+        <Input
+          type="number"
+          min="0"
+          max="60"
+          value={seconds}
+          onChange={handleChange}
+          disabled={isUpdating}
+          className="w-20"
+        />
+        <span className="text-sm text-text">
+          {t("settings.general.autoStopSilence.seconds")}
+        </span>
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,75 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Slider } from "../ui/Slider";
+import { useSettings } from "../../hooks/useSettings";
+import { commands, events } from "@/bindings";
+
+interface BatterySaverProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const BatterySaver: React.FC<BatterySaverProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [isActive, setIsActive] = useState(false);
+
+    const enabled = getSetting("battery_saver_enabled") ?? true;
+    const threshold = getSetting("battery_saver_threshold") ?? 20;
+
+    useEffect(() => {
+      commands.isBatterySaverActive().then((result) => {
+        if (result.status === "ok") {
+          setIsActive(result.data);
+        }
+      });
+
+      const unlisten = events.batterySaverChanged.listen((event) => {
+        setIsActive(event.payload.active);
+      });
+
+      return () => {
+        unlisten.then((fn) => fn());
+      };
+    }, []);
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => updateSetting("battery_saver_enabled", value)}
+          isUpdating={isUpdating("battery_saver_enabled")}
+          label={t("settings.advanced.batterySaver.label")}
+          description={
+            isActive
+              ? t("settings.advanced.batterySaver.active")
+              : t("settings.advanced.batterySaver.description")
+          }
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <Slider
+            value={threshold}
+            onChange={(value) =>
+              updateSetting("battery_saver_threshold", Math.round(value))
+            }
+            min={5}
+            max={50}
+            step={5}
+            disabled={isUpdating("battery_saver_threshold")}
+            label={t("settings.advanced.batterySaver.threshold.title")}
+            description={t(
+              "settings.advanced.batterySaver.threshold.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(value) => `${Math.round(value)}%`}
+          />
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,117 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface CasedTermsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const CasedTerms: React.FC<CasedTermsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [newTerm, setNewTerm] = useState("");
+    const casedTerms = getSetting("cased_terms") || [];
+
+    const handleAddTerm = () => {
+      const trimmedTerm = newTerm.trim();
+      const sanitizedTerm = trimmedTerm.replace(/[<>"'&]/g, "");
+      if (
+        sanitizedTerm &&
+        sanitizedTerm.length <= 50 &&
+        !casedTerms.some(
+          (term) => term.toLowerCase() === sanitizedTerm.toLowerCase(),
+        )
+      ) {
+        updateSetting("cased_terms", [...casedTerms, sanitizedTerm]);
+        setNewTerm("");
+      }
+    };
+
+    const handleRemoveTerm = (termToRemove: string) => {
+      updateSetting(
+        "cased_terms",
+        casedTerms.filter((term) => term !== termToRemove),
+      );
+    };
+
+    const handleKeyPress = (e: React.KeyboardEvent) => {
+      if (e.key === "Enter") {
+        e.preventDefault();
+        handleAddTerm();
+      }
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.casedTerms.title")}
+          description={t("settings.advanced.casedTerms.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="text"
+              className="max-w-40"
+              value={newTerm}
+              onChange={(e) => setNewTerm(e.target.value)}
+              onKeyDown={handleKeyPress}
+              placeholder={t("settings.advanced.casedTerms.placeholder")}
+              variant="compact"
+              disabled={isUpdating("cased_terms")}
+            />
+            <Button
+              onClick={handleAddTerm}
+              disabled={
+                !newTerm.trim() ||
+                newTerm.trim().length > 50 ||
+                isUpdating("cased_terms")
+              }
+              variant="primary"
+              size="md"
+            >
+              {t("settings.advanced.casedTerms.add")}
+            </Button>
+          </div>
+        </SettingContainer>
+        {casedTerms.length > 0 && (
+          <div
+            className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} flex flex-wrap gap-1`}
+          >
+            {casedTerms.map((term) => (
+              <Button
+                key={term}
+                onClick={() => handleRemoveTerm(term)}
+                disabled={isUpdating("cased_terms")}
+                variant="secondary"
+                size="sm"
+                className="inline-flex items-center gap-1 cursor-pointer"
+                aria-label={t("settings.advanced.casedTerms.remove", { term })}
+              >
+                <span>{term}</span>
+                <svg
+                  className="w-3 h-3"
+                  fill="none"
+                  stroke="currentColor"
+                  viewBox="0 0 24 24"
+                >
+                  <path
+                    strokeLinecap="round"
+                    strokeLinejoin="round"
+                    strokeWidth={2}
+                    d="M6 18L18 6M6 6l12 12"
+                  />
+                </svg>
+              </Button>
+            ))}
+          </div>
+        )}
+      </>
+    );
+  },
+);
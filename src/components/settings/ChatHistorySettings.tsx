@@ -8,6 +8,8 @@ import {
   Search,
   Calendar,
   MessageCircle,
+  Paperclip,
+  Bot,
 } from "lucide-react";
 import { cn } from "@/lib/utils/cn";
 
@@ -92,6 +94,18 @@ export const ChatHistorySettings: React.FC = () => {
                       <MessageSquare className="h-3 w-3" />
                       {chat.message_count} messages
                     </div>
+                    {chat.attachment_count > 0 && (
+                      <div className="flex items-center gap-1.5 text-xs text-[var(--color-text)]/40">
+                        <Paperclip className="h-3 w-3" />
+                        {chat.attachment_count} attachments
+                      </div>
+                    )}
+                    {chat.model_id && (
+                      <div className="flex items-center gap-1.5 text-xs text-[var(--color-text)]/40 truncate">
+                        <Bot className="h-3 w-3" />
+                        {chat.model_id}
+                      </div>
+                    )}
                   </div>
                 </div>
               </div>
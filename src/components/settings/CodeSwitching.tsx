@@ -0,0 +1,30 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface CodeSwitchingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const CodeSwitching: React.FC<CodeSwitchingProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const codeSwitching = getSetting("code_switching") || false;
+
+    return (
+      <ToggleSwitch
+        checked={codeSwitching}
+        onChange={(enabled) => updateSetting("code_switching", enabled)}
+        isUpdating={isUpdating("code_switching")}
+        label={t("settings.advanced.codeSwitching.label")}
+        description={t("settings.advanced.codeSwitching.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
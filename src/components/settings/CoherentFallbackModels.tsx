@@ -0,0 +1,71 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { X } from "lucide-react";
+import { commands } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { ModelsDropdown } from "../ui/ModelsDropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+
+/** Models refinement retries with when the coherent model's provider fails */
+export const CoherentFallbackModels: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const fallbackIds = settings?.coherent_fallback_model_ids ?? [];
+  const models = settings?.llm_models ?? [];
+
+  const save = async (modelIds: string[]) => {
+    const result = await commands.setCoherentFallbackModels(modelIds);
+    if (result.status === "error") {
+      console.error("Failed to set fallback models:", result.error);
+    }
+    await refreshSettings();
+  };
+
+  const modelName = (id: string) =>
+    models.find((m) => m.id === id)?.display_name ?? id;
+
+  return (
+    <SettingContainer
+      title={t("settings.llmProvider.fallbackModels.title", "Fallback Models")}
+      description={t(
+        "settings.llmProvider.fallbackModels.description",
+        "If the refinement model is rate limited or its provider is down, retry with these models in order.",
+      )}
+      descriptionMode="tooltip"
+      layout="stacked"
+      grouped={true}
+    >
+      <div className="space-y-2">
+        {fallbackIds.map((id, index) => (
+          <div
+            key={id}
+            className="flex items-center justify-between px-3 py-1.5 bg-mid-gray/5 rounded-lg text-sm"
+          >
+            <span>
+              {index + 1}. {modelName(id)}
+            </span>
+            <button
+              onClick={() => save(fallbackIds.filter((f) => f !== id))}
+              className="p-1 text-mid-gray hover:text-red-500 transition-colors"
+            >
+              <X className="h-3.5 w-3.5" />
+            </button>
+          </div>
+        ))}
+        <ModelsDropdown
+          selectedValue={null}
+          onSelect={(modelId) => {
+            if (modelId && !fallbackIds.includes(modelId)) {
+              save([...fallbackIds, modelId]);
+            }
+          }}
+          placeholder={t(
+            "settings.llmProvider.fallbackModels.add",
+            "Add fallback model",
+          )}
+          className="min-w-[280px]"
+        />
+      </div>
+    </SettingContainer>
+  );
+};
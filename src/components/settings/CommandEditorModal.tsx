@@ -1,12 +1,14 @@
 import React, { useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
-import { X, Terminal } from "lucide-react";
+import { X, Terminal, Plus, Trash2 } from "lucide-react";
 
 import {
   commands as rawCommands,
   VoiceCommand,
   VoiceCommandType,
   ScriptType,
+  CommandParameter,
+  ParameterType,
 } from "@/bindings";
 
 // Type assertion for new commands
@@ -39,6 +41,7 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
   const [description, setDescription] = useState("");
   const [scriptType, setScriptType] = useState<ScriptType>("shell");
   const [script, setScript] = useState("");
+  const [parameters, setParameters] = useState<CommandParameter[]>([]);
   const [isSaving, setIsSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
@@ -51,6 +54,7 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
       setDescription(command.description || "");
       setScriptType(command.script_type || "shell");
       setScript(command.script || "");
+      setParameters(command.parameters || []);
     } else {
       // Reset for new command
       setName("");
@@ -59,6 +63,7 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
       setDescription("");
       setScriptType("shell");
       setScript("");
+      setParameters([]);
     }
     setError(null);
   }, [command, isOpen]);
@@ -77,6 +82,10 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
       setError("Script is required for custom commands");
       return;
     }
+    if (parameters.some((p) => !p.name.trim())) {
+      setError("Every parameter needs a name");
+      return;
+    }
 
     setIsSaving(true);
     setError(null);
@@ -95,6 +104,13 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
         script: commandType === "custom" ? script.trim() : null,
         model_override: null, // Removed - using centralized model
         is_builtin: false,
+        parameters:
+          commandType === "custom"
+            ? parameters.map((p) => ({
+                ...p,
+                options: (p.options ?? []).map((o) => o.trim()).filter((o) => o),
+              }))
+            : [],
       };
 
       if (isEditing) {
@@ -112,6 +128,26 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
     }
   };
 
+  const updateParameter = (
+    index: number,
+    changes: Partial<CommandParameter>,
+  ) => {
+    setParameters((current) =>
+      current.map((p, i) => (i === index ? { ...p, ...changes } : p)),
+    );
+  };
+
+  const addParameter = () => {
+    setParameters((current) => [
+      ...current,
+      { name: "", param_type: "string", options: [], description: null },
+    ]);
+  };
+
+  const removeParameter = (index: number) => {
+    setParameters((current) => current.filter((_, i) => i !== index));
+  };
+
   if (!isOpen) return null;
 
   return (
@@ -265,6 +301,87 @@ export const CommandEditorModal: React.FC<CommandEditorModalProps> = ({
               />
             </div>
           </>
+
+          {/* Parameters */}
+          <div>
+            <div className="flex items-center justify-between mb-1">
+              <label className="block text-sm font-medium">
+                {t("commandEditor.parameters", "Parameters")}{" "}
+                <span className="text-mid-gray">(optional)</span>
+              </label>
+              <button
+                onClick={addParameter}
+                className="flex items-center gap-1 text-xs text-logo-primary hover:text-logo-primary/80"
+              >
+                <Plus className="w-3 h-3" />
+                {t("commandEditor.addParameter", "Add parameter")}
+              </button>
+            </div>
+            <div className="space-y-2">
+              {parameters.map((parameter, index) => (
+                <div key={index} className="flex items-center gap-2">
+                  <input
+                    type="text"
+                    value={parameter.name}
+                    onChange={(e) =>
+                      updateParameter(index, { name: e.target.value.trim() })
+                    }
+                    placeholder={t("commandEditor.parameterName", "name")}
+                    className="w-28 px-2 py-1 bg-black/20 border border-mid-gray/30 rounded-lg focus:outline-none focus:border-logo-primary font-mono text-sm"
+                  />
+                  <select
+                    value={parameter.param_type ?? "string"}
+                    onChange={(e) =>
+                      updateParameter(index, {
+                        param_type: e.target.value as ParameterType,
+                      })
+                    }
+                    className="px-2 py-1 bg-black/20 border border-mid-gray/30 rounded-lg focus:outline-none focus:border-logo-primary text-sm"
+                  >
+                    <option value="string">
+                      {t("commandEditor.parameterTypes.string", "Text")}
+                    </option>
+                    <option value="number">
+                      {t("commandEditor.parameterTypes.number", "Number")}
+                    </option>
+                    <option value="enum">
+                      {t("commandEditor.parameterTypes.enum", "One of")}
+                    </option>
+                  </select>
+                  {parameter.param_type === "enum" && (
+                    <input
+                      type="text"
+                      value={(parameter.options ?? []).join(", ")}
+                      onChange={(e) =>
+                        updateParameter(index, {
+                          options: e.target.value
+                            .split(",")
+                            .map((o) => o.trimStart()),
+                        })
+                      }
+                      placeholder={t(
+                        "commandEditor.parameterOptions",
+                        "dark, light",
+                      )}
+                      className="flex-1 min-w-0 px-2 py-1 bg-black/20 border border-mid-gray/30 rounded-lg focus:outline-none focus:border-logo-primary text-sm"
+                    />
+                  )}
+                  <button
+                    onClick={() => removeParameter(index)}
+                    className="ml-auto p-1 text-mid-gray hover:text-red-400 transition-colors"
+                  >
+                    <Trash2 className="w-4 h-4" />
+                  </button>
+                </div>
+              ))}
+            </div>
+            <p className="text-xs text-mid-gray mt-1">
+              {t(
+                "commandEditor.parametersHint",
+                "Use {name} in a trigger phrase to mark where the value is said, and ${name} in the script where it goes.",
+              )}
+            </p>
+          </div>
         </div>
 
         {/* Footer */}
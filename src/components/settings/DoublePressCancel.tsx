@@ -0,0 +1,50 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { commands } from "@/bindings";
+
+interface DoublePressCancelProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const DoublePressCancel: React.FC<DoublePressCancelProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { settings, refreshSettings } = useSettings();
+    const [isUpdating, setIsUpdating] = useState(false);
+
+    const isEnabled = settings?.double_press_cancel ?? false;
+
+    const handleToggle = async (enabled: boolean) => {
+      setIsUpdating(true);
+      try {
+        const result = await commands.changeDoublePressCancelSetting(enabled);
+        if (result.status === "ok") {
+          await refreshSettings();
+        }
+      } finally {
+        setIsUpdating(false);
+      }
+    };
+
+    return (
+      <ToggleSwitch
+        checked={isEnabled}
+        onChange={handleToggle}
+        disabled={isUpdating}
+        label={t(
+          "settings.general.doublePressCancel.title",
+          "Double-Press to Cancel",
+        )}
+        description={t(
+          "settings.general.doublePressCancel.description",
+          "Pressing the recording shortcut twice in quick succession cancels the recording, like Escape.",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
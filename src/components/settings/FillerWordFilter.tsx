@@ -5,6 +5,7 @@ import { Input } from "../ui/Input";
 import { SettingContainer } from "../ui/SettingContainer";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
 import { commands } from "@/bindings";
+import { formatError } from "@/lib/utils/errors";
 
 interface FillerWordFilterProps {
   descriptionMode?: "inline" | "tooltip";
@@ -30,7 +31,7 @@ export const FillerWordFilter: React.FC<FillerWordFilterProps> = React.memo(
         const newPattern = enabled ? DEFAULT_PATTERN : null;
         const result = await commands.changeFillerWordFilterSetting(newPattern);
         if (result.status === "error") {
-          setError(result.error);
+          setError(formatError(result.error));
         } else {
           await refreshSettings();
         }
@@ -52,7 +53,7 @@ export const FillerWordFilter: React.FC<FillerWordFilterProps> = React.memo(
           newPattern || null,
         );
         if (result.status === "error") {
-          setError(result.error);
+          setError(formatError(result.error));
         } else {
           await refreshSettings();
         }
@@ -70,7 +71,7 @@ export const FillerWordFilter: React.FC<FillerWordFilterProps> = React.memo(
         const result =
           await commands.changeFillerWordFilterSetting(DEFAULT_PATTERN);
         if (result.status === "error") {
-          setError(result.error);
+          setError(formatError(result.error));
         } else {
           await refreshSettings();
         }
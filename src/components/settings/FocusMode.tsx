@@ -0,0 +1,58 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { commands, events } from "@/bindings";
+
+interface FocusModeProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const FocusMode: React.FC<FocusModeProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { settings, refreshSettings } = useSettings();
+    const [isUpdating, setIsUpdating] = useState(false);
+
+    const isEnabled = settings?.focus_mode ?? false;
+
+    // The tray menu can toggle focus mode too
+    useEffect(() => {
+      const unlisten = events.focusModeChanged.listen(() => {
+        refreshSettings();
+      });
+
+      return () => {
+        unlisten.then((fn) => fn());
+      };
+    }, [refreshSettings]);
+
+    const handleToggle = async (enabled: boolean) => {
+      setIsUpdating(true);
+      try {
+        const result = await commands.changeFocusModeSetting(enabled);
+        if (result.status === "ok") {
+          await refreshSettings();
+        }
+      } finally {
+        setIsUpdating(false);
+      }
+    };
+
+    return (
+      <ToggleSwitch
+        checked={isEnabled}
+        onChange={handleToggle}
+        disabled={isUpdating}
+        label={t("settings.general.focusMode.title", "Focus Mode")}
+        description={t(
+          "settings.general.focusMode.description",
+          "Distraction-free dictation: always pastes raw text, mutes sounds, shows only a small dot while recording, and turns off chat windows, voice commands and context chat so no LLM is called.",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
@@ -0,0 +1,56 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface FollowUpListeningProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+/** How long to keep listening for another command after one completes */
+export const FollowUpListening: React.FC<FollowUpListeningProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+
+  const seconds = getSetting("voice_command_follow_up_seconds") ?? 0;
+
+  const handleChange = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value) && value >= 0 && value <= 60) {
+      updateSetting("voice_command_follow_up_seconds", value);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.voiceCommands.followUp.title", "Follow-up Listening")}
+      description={t(
+        "settings.voiceCommands.followUp.description",
+        "After a command completes, keep listening this many seconds for another one so you don't have to press the shortcut again. Set to 0 to turn off.",
+      )}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="horizontal"
+    >
+      <div className="flex items-center space-x-2">
+        <Input
+          type="number"
+          min="0"
+          max="60"
+          value={seconds}
+          onChange={handleChange}
+          disabled={isUpdating("voice_command_follow_up_seconds")}
+          className="w-20"
+        />
+        <span className="text-sm text-text">
+          {t("settings.voiceCommands.followUp.seconds", "seconds")}
+        </span>
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,109 @@
+import React, { useEffect, useState } from "react";
+import { commands, LLMModel } from "@/bindings";
+import { Button } from "../ui/Button";
+import { formatError } from "@/lib/utils/errors";
+
+interface GeminiModelOptionsProps {
+  models: LLMModel[];
+}
+
+const SAFETY_OPTIONS = [
+  { value: "", label: "Default safety filtering" },
+  { value: "BLOCK_LOW_AND_ABOVE", label: "Block low and above" },
+  { value: "BLOCK_MEDIUM_AND_ABOVE", label: "Block medium and above" },
+  { value: "BLOCK_ONLY_HIGH", label: "Block only high" },
+  { value: "BLOCK_NONE", label: "Block none" },
+  { value: "OFF", label: "Off" },
+];
+
+const inputClass =
+  "w-full px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+/** Gemini thinking budget and safety threshold, per model */
+export const GeminiModelOptions: React.FC<GeminiModelOptionsProps> = ({
+  models,
+}) => {
+  const [modelList, setModelList] = useState(models);
+  const [modelId, setModelId] = useState(models[0]?.id ?? "");
+  const [thinkingBudget, setThinkingBudget] = useState("");
+  const [safety, setSafety] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const model = modelList.find((m) => m.id === modelId);
+
+  useEffect(() => {
+    const options = model?.gemini_options;
+    setThinkingBudget(options?.thinking_budget?.toString() ?? "");
+    setSafety(options?.safety_threshold ?? "");
+    setStatus(null);
+  }, [modelId]);
+
+  if (modelList.length === 0) return null;
+
+  const handleSave = async () => {
+    const budget = thinkingBudget.trim();
+    if (budget && !/^-?\d+$/.test(budget)) {
+      setStatus("Thinking budget must be a whole number");
+      return;
+    }
+    const isDefault = !budget && !safety;
+    const result = await commands.setModelGeminiOptions(
+      modelId,
+      isDefault
+        ? null
+        : {
+            thinking_budget: budget ? parseInt(budget, 10) : null,
+            safety_threshold: safety || null,
+          },
+    );
+    if (result.status === "error") {
+      setStatus(formatError(result.error));
+      return;
+    }
+    setModelList(
+      modelList.map((m) => (m.id === result.data.id ? result.data : m)),
+    );
+    setStatus("Saved");
+  };
+
+  return (
+    <div className="space-y-2 border-t border-mid-gray/10 pt-4">
+      <label className="text-sm font-medium">Gemini Options</label>
+      <select
+        value={modelId}
+        onChange={(e) => setModelId(e.target.value)}
+        className={inputClass}
+      >
+        {modelList.map((m) => (
+          <option key={m.id} value={m.id}>
+            {m.display_name}
+          </option>
+        ))}
+      </select>
+      <input
+        type="text"
+        value={thinkingBudget}
+        onChange={(e) => setThinkingBudget(e.target.value)}
+        placeholder="Thinking budget in tokens (0 = off, -1 = dynamic)"
+        className={inputClass}
+      />
+      <select
+        value={safety}
+        onChange={(e) => setSafety(e.target.value)}
+        className={inputClass}
+      >
+        {SAFETY_OPTIONS.map((option) => (
+          <option key={option.value} value={option.value}>
+            {option.label}
+          </option>
+        ))}
+      </select>
+      <div className="flex items-center gap-2">
+        <Button onClick={handleSave} variant="secondary">
+          Save Options
+        </Button>
+        {status && <span className="text-xs text-mid-gray">{status}</span>}
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,49 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { commands } from "@/bindings";
+
+interface HideWhenPresentingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const HideWhenPresenting: React.FC<HideWhenPresentingProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { settings, refreshSettings } = useSettings();
+    const [isUpdating, setIsUpdating] = useState(false);
+
+    const isEnabled = settings?.hide_when_presenting ?? false;
+
+    const handleToggle = async (enabled: boolean) => {
+      setIsUpdating(true);
+      try {
+        const result = await commands.changeHideWhenPresentingSetting(enabled);
+        if (result.status === "ok") {
+          await refreshSettings();
+        }
+      } finally {
+        setIsUpdating(false);
+      }
+    };
+
+    return (
+      <ToggleSwitch
+        checked={isEnabled}
+        onChange={handleToggle}
+        disabled={isUpdating}
+        label={t(
+          "settings.advanced.hideWhenPresenting.title",
+          "Hide While Presenting",
+        )}
+        description={t(
+          "settings.advanced.hideWhenPresenting.description",
+          "Hide the recording overlay and chat windows while another app is full screen or your screen is being shared (Zoom, Teams, Meet and other browser calls), and bring them back afterwards.",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
@@ -1,7 +1,15 @@
 import React, { useState, useEffect } from "react";
 import { useTranslation } from "react-i18next";
 import { Plus, Trash2, X, Settings2, RefreshCcw } from "lucide-react";
-import { commands, LLMProvider, LLMModel, DefaultModels } from "@/bindings";
+import { listen } from "@tauri-apps/api/event";
+import {
+  commands,
+  LLMProvider,
+  LLMModel,
+  DefaultModels,
+  DiscoveredServer,
+  ProviderHealth,
+} from "@/bindings";
 
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { SettingContainer } from "../ui/SettingContainer";
@@ -9,7 +17,16 @@ import { Button } from "../ui/Button";
 import { ModelsDropdown } from "../ui/ModelsDropdown";
 import { useSettings } from "../../hooks/useSettings";
 import { ProviderAuth, OAuthStatusBadge } from "./ProviderAuth";
+import { ProviderCredentials } from "./ProviderCredentials";
+import { ProviderHeaders } from "./ProviderHeaders";
+import { ProviderFree } from "./ProviderFree";
+import { OpenRouterRouting } from "./OpenRouterRouting";
+import { GeminiModelOptions } from "./GeminiModelOptions";
+import { ReasoningOptions } from "./ReasoningOptions";
+import { CoherentFallbackModels } from "./CoherentFallbackModels";
+import { LocalLlmSettings } from "./LocalLlmSettings";
 import { useOAuth } from "../../hooks/useOAuth";
+import { formatError } from "@/lib/utils/errors";
 
 // Known provider presets (models are fetched dynamically via API)
 // API key providers and OAuth providers are separate entries
@@ -69,6 +86,8 @@ interface ProviderDialogProps {
     enabledModelIds: Set<string>,
   ) => void;
   onDelete?: () => void;
+  /** Called after the provider's active credential is switched */
+  onCredentialChange?: () => void;
   existingProviders: LLMProvider[];
 }
 
@@ -80,6 +99,7 @@ const ProviderDialog: React.FC<ProviderDialogProps> = ({
   onClose,
   onSave,
   onDelete,
+  onCredentialChange,
   existingProviders,
 }) => {
   const [providerType, setProviderType] = useState<"preset" | "custom">(
@@ -233,7 +253,7 @@ const ProviderDialog: React.FC<ProviderDialogProps> = ({
         }
         setSelectedModels(newSelectedModels);
       } else {
-        setFetchError(result.error);
+        setFetchError(formatError(result.error));
       }
     } catch (error) {
       setFetchError(String(error));
@@ -453,6 +473,39 @@ const ProviderDialog: React.FC<ProviderDialogProps> = ({
                 fixedAuthMethod={providerType === "preset"}
               />
 
+              {mode === "edit" && provider && authMethod === "api_key" && (
+                <ProviderCredentials
+                  provider={provider}
+                  apiKey={apiKey}
+                  onChange={(updated) => {
+                    setApiKey(updated.api_key || "");
+                    onCredentialChange?.();
+                  }}
+                />
+              )}
+
+              {mode === "edit" && provider && (
+                <ProviderHeaders provider={provider} />
+              )}
+
+              {mode === "edit" &&
+                provider &&
+                (provider.id === "openrouter" ||
+                  provider.base_url.includes("openrouter.ai")) && (
+                  <OpenRouterRouting models={providerModels} />
+                )}
+
+              {mode === "edit" && provider && (
+                <ReasoningOptions models={providerModels} />
+              )}
+
+              {mode === "edit" &&
+                provider &&
+                (provider.id === "gemini" ||
+                  provider.id === "gemini_oauth") && (
+                  <GeminiModelOptions models={providerModels} />
+                )}
+
               {/* Models List */}
               {(selectedPreset || mode === "edit") && (
                 <div className="space-y-2 border-t border-mid-gray/10 pt-4">
@@ -563,6 +616,38 @@ const ProviderDialog: React.FC<ProviderDialogProps> = ({
                 />
               </div>
 
+              {mode === "edit" && provider && (
+                <ProviderCredentials
+                  provider={provider}
+                  apiKey={apiKey}
+                  baseUrl={customUrl}
+                  onChange={(updated) => {
+                    setApiKey(updated.api_key || "");
+                    setCustomUrl(updated.base_url);
+                    onCredentialChange?.();
+                  }}
+                />
+              )}
+
+              {mode === "edit" && provider && (
+                <ProviderHeaders provider={provider} />
+              )}
+
+              {mode === "edit" && provider && (
+                <ProviderFree provider={provider} />
+              )}
+
+              {mode === "edit" &&
+                provider &&
+                (provider.id === "openrouter" ||
+                  provider.base_url.includes("openrouter.ai")) && (
+                  <OpenRouterRouting models={providerModels} />
+                )}
+
+              {mode === "edit" && provider && (
+                <ReasoningOptions models={providerModels} />
+              )}
+
               <div className="space-y-2">
                 <div className="flex items-center justify-between">
                   <label className="text-sm font-medium">
@@ -679,6 +764,44 @@ export const LLMProviderSettings: React.FC = () => {
 
   const [isLoading, setIsLoading] = useState(false);
 
+  // Health of self-hosted providers, kept current by the background monitor
+  const [health, setHealth] = useState<Record<string, ProviderHealth>>({});
+
+  useEffect(() => {
+    commands.getProviderHealth().then((results) => {
+      setHealth(Object.fromEntries(results.map((h) => [h.provider_id, h])));
+    });
+    const unlisten = listen<ProviderHealth>(
+      "provider-health-changed",
+      (event) => {
+        setHealth((prev) => ({
+          ...prev,
+          [event.payload.provider_id]: event.payload,
+        }));
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Local servers running on well-known ports that aren't providers yet
+  const [discovered, setDiscovered] = useState<DiscoveredServer[]>([]);
+
+  useEffect(() => {
+    commands.discoverLocalProviders().then(setDiscovered);
+  }, []);
+
+  const handleAddDiscovered = async (server: DiscoveredServer) => {
+    const result = await commands.addDiscoveredProvider(server);
+    if (result.status === "error") {
+      console.error("Failed to add discovered provider:", result.error);
+      return;
+    }
+    setDiscovered((prev) => prev.filter((s) => s.id !== server.id));
+    await refreshSettings();
+  };
+
   // Dialog state
   const [dialogMode, setDialogMode] = useState<"add" | "edit">("add");
   const [dialogOpen, setDialogOpen] = useState(false);
@@ -791,6 +914,30 @@ export const LLMProviderSettings: React.FC = () => {
         </div>
 
         <div className="px-4 pb-4 space-y-2">
+          {/* Local servers found on localhost, offered as one-click providers */}
+          {discovered.map((server) => (
+            <div
+              key={server.id}
+              className="flex items-center gap-3 w-full p-3 bg-logo-primary/5 rounded-lg border border-logo-primary/20"
+            >
+              <div className="flex-1 min-w-0">
+                <p className="text-sm font-medium">
+                  {server.name} detected
+                </p>
+                <p className="text-xs text-mid-gray truncate">
+                  {server.base_url} · {server.models.length} model
+                  {server.models.length !== 1 ? "s" : ""}
+                </p>
+              </div>
+              <Button
+                onClick={() => handleAddDiscovered(server)}
+                variant="secondary"
+              >
+                Add
+              </Button>
+            </div>
+          ))}
+
           {/* Provider rows - show providers with API keys OR OAuth auth */}
           {providers
             .filter((p) => {
@@ -811,6 +958,7 @@ export const LLMProviderSettings: React.FC = () => {
                 supports_oauth?: boolean;
               };
               const usesOAuth = extProvider.auth_method === "oauth";
+              const providerHealth = health[provider.id];
 
               return (
                 <button
@@ -838,6 +986,19 @@ export const LLMProviderSettings: React.FC = () => {
                     </span>
                   )}
 
+                  {providerHealth && (
+                    <span
+                      className={`text-xs px-2 py-0.5 rounded ${
+                        providerHealth.reachable
+                          ? "text-green-600 bg-green-100 dark:bg-green-900/30"
+                          : "text-red-600 bg-red-100 dark:bg-red-900/30"
+                      }`}
+                      title={providerHealth.error ?? undefined}
+                    >
+                      {providerHealth.reachable ? "Running" : "Offline"}
+                    </span>
+                  )}
+
                   <span className="text-xs text-mid-gray">
                     {enabledCount} model{enabledCount !== 1 ? "s" : ""}
                   </span>
@@ -887,6 +1048,8 @@ export const LLMProviderSettings: React.FC = () => {
             className="min-w-[280px]"
           />
         </SettingContainer>
+        <CoherentFallbackModels />
+        <LocalLlmSettings />
       </SettingsGroup>
 
       {/* Provider Dialog */}
@@ -900,6 +1063,7 @@ export const LLMProviderSettings: React.FC = () => {
         onClose={() => setDialogOpen(false)}
         onSave={handleSaveProvider}
         onDelete={handleDeleteProvider}
+        onCredentialChange={refreshSettings}
         existingProviders={providers}
       />
     </div>
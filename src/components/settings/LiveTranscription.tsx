@@ -0,0 +1,30 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface LiveTranscriptionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const LiveTranscription: React.FC<LiveTranscriptionProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("live_transcription") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("live_transcription", value)}
+        isUpdating={isUpdating("live_transcription")}
+        label={t("settings.sound.liveTranscription.label")}
+        description={t("settings.sound.liveTranscription.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  },
+);
@@ -0,0 +1,87 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { commands } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Button } from "../ui/Button";
+
+/** The local model llama.cpp's server runs for offline refinement */
+export const LocalLlmSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const [available, setAvailable] = useState(false);
+
+  useEffect(() => {
+    commands.isLocalLlmAvailable().then(setAvailable);
+  }, []);
+
+  const enabled = getSetting("local_llm_enabled") ?? false;
+  const modelPath = getSetting("local_llm_model_path") ?? null;
+  const fileName = modelPath ? modelPath.split(/[\\/]/).pop() : null;
+
+  const handleSelectFile = async () => {
+    const selected = await open({
+      multiple: false,
+      directory: false,
+      filters: [{ name: "GGUF model", extensions: ["gguf"] }],
+    });
+    if (selected && typeof selected === "string") {
+      await updateSetting("local_llm_model_path", selected);
+    }
+  };
+
+  // Without a bundled or installed llama-server there's nothing to run the model
+  if (!available) {
+    return null;
+  }
+
+  return (
+    <>
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("local_llm_enabled", value)}
+        isUpdating={isUpdating("local_llm_enabled")}
+        label={t("settings.llmProvider.localLlm.label", "Local Model")}
+        description={t(
+          "settings.llmProvider.localLlm.description",
+          "Refine with a model running on this computer when no refinement model is set or every provider fails. Works offline, best for short dictations.",
+        )}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      {enabled && (
+        <SettingContainer
+          title={t("settings.llmProvider.localLlm.file", "Local Model File")}
+          description={t(
+            "settings.llmProvider.localLlm.fileDescription",
+            "A GGUF model file, e.g. a small instruction-tuned Llama, Qwen or Gemma.",
+          )}
+          descriptionMode="tooltip"
+          layout="stacked"
+          grouped={true}
+        >
+          <div className="flex items-center gap-2">
+            <span className="flex-1 min-w-0 truncate text-sm font-mono">
+              {fileName ??
+                t("settings.llmProvider.localLlm.notSet", "No file selected")}
+            </span>
+            <Button onClick={handleSelectFile} variant="secondary" size="sm">
+              {t("common.change")}
+            </Button>
+            {modelPath && (
+              <Button
+                onClick={() => updateSetting("local_llm_model_path", null)}
+                variant="secondary"
+                size="sm"
+              >
+                {t("common.clear")}
+              </Button>
+            )}
+          </div>
+        </SettingContainer>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface MaxPasteLengthProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const MaxPasteLength: React.FC<MaxPasteLengthProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+
+  const maxPasteChars = getSetting("max_paste_chars") ?? 5000;
+
+  const handleChange = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value) && value >= 0) {
+      updateSetting("max_paste_chars", value);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.maxPasteLength.title")}
+      description={t("settings.advanced.maxPasteLength.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="horizontal"
+    >
+      <div className="flex items-center space-x-2">
+        <Input
+          type="number"
+          min="0"
+          step="500"
+          value={maxPasteChars}
+          onChange={handleChange}
+          disabled={isUpdating("max_paste_chars")}
+          className="w-24"
+        />
+        <span className="text-sm text-text">
+          {t("settings.advanced.maxPasteLength.characters")}
+        </span>
+      </div>
+    </SettingContainer>
+  );
+};
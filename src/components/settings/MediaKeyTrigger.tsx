@@ -0,0 +1,65 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface MediaKeyTriggerProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const MediaKeyTrigger: React.FC<MediaKeyTriggerProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("media_key_trigger_enabled") ?? false;
+    const binding = getSetting("media_key_binding") ?? "transcribe";
+
+    const bindingOptions = [
+      {
+        value: "transcribe",
+        label: t("settings.general.mediaKeyTrigger.bindings.transcribe"),
+      },
+      {
+        value: "voice_command",
+        label: t("settings.general.mediaKeyTrigger.bindings.voiceCommand"),
+      },
+    ];
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) =>
+            updateSetting("media_key_trigger_enabled", value)
+          }
+          isUpdating={isUpdating("media_key_trigger_enabled")}
+          label={t("settings.general.mediaKeyTrigger.label")}
+          description={t("settings.general.mediaKeyTrigger.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <SettingContainer
+            title={t("settings.general.mediaKeyTrigger.binding.title")}
+            description={t(
+              "settings.general.mediaKeyTrigger.binding.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          >
+            <Dropdown
+              options={bindingOptions}
+              selectedValue={binding}
+              onSelect={(value) => updateSetting("media_key_binding", value)}
+              disabled={isUpdating("media_key_binding")}
+            />
+          </SettingContainer>
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface NoSpeechTimeoutProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const NoSpeechTimeout: React.FC<NoSpeechTimeoutProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+
+  const timeout = getSetting("no_speech_timeout_seconds") ?? 0;
+
+  const handleChange = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value) && value >= 0 && value <= 120) {
+      updateSetting("no_speech_timeout_seconds", value);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.noSpeechTimeout.title")}
+      description={t("settings.advanced.noSpeechTimeout.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="horizontal"
+    >
+      <div className="flex items-center space-x-2">
+        <Input
+          type="number"
+          min="0"
+          max="120"
+          value={timeout}
+          onChange={handleChange}
+          disabled={isUpdating("no_speech_timeout_seconds")}
+          className="w-20"
+        />
+        <span className="text-sm text-text">
+          {t("settings.advanced.noSpeechTimeout.seconds")}
+        </span>
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,138 @@
+import React, { useEffect, useState } from "react";
+import { commands, LLMModel } from "@/bindings";
+import { Button } from "../ui/Button";
+import { formatError } from "@/lib/utils/errors";
+
+interface OpenRouterRoutingProps {
+  models: LLMModel[];
+}
+
+const SORT_OPTIONS = [
+  { value: "", label: "Load balanced (default)" },
+  { value: "price", label: "Lowest price" },
+  { value: "throughput", label: "Highest throughput" },
+  { value: "latency", label: "Lowest latency" },
+];
+
+const splitList = (value: string) =>
+  value
+    .split(",")
+    .map((item) => item.trim())
+    .filter(Boolean);
+
+const inputClass =
+  "w-full px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+/** OpenRouter fallback models and provider preferences, per model */
+export const OpenRouterRouting: React.FC<OpenRouterRoutingProps> = ({
+  models,
+}) => {
+  const [modelList, setModelList] = useState(models);
+  const [modelId, setModelId] = useState(models[0]?.id ?? "");
+  const [fallbacks, setFallbacks] = useState("");
+  const [order, setOrder] = useState("");
+  const [allowFallbacks, setAllowFallbacks] = useState(true);
+  const [sort, setSort] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const model = modelList.find((m) => m.id === modelId);
+
+  useEffect(() => {
+    const routing = model?.openrouter_routing;
+    setFallbacks((routing?.fallback_models ?? []).join(", "));
+    setOrder((routing?.provider_order ?? []).join(", "));
+    setAllowFallbacks(routing?.allow_fallbacks ?? true);
+    setSort(routing?.sort ?? "");
+    setStatus(null);
+  }, [modelId]);
+
+  if (modelList.length === 0) return null;
+
+  const handleSave = async () => {
+    const fallbackModels = splitList(fallbacks);
+    const providerOrder = splitList(order);
+    const isDefault =
+      fallbackModels.length === 0 &&
+      providerOrder.length === 0 &&
+      allowFallbacks &&
+      !sort;
+    const result = await commands.setModelOpenrouterRouting(
+      modelId,
+      isDefault
+        ? null
+        : {
+            fallback_models: fallbackModels,
+            provider_order: providerOrder,
+            allow_fallbacks: allowFallbacks,
+            sort: sort || null,
+          },
+    );
+    if (result.status === "error") {
+      setStatus(formatError(result.error));
+      return;
+    }
+    setModelList(
+      modelList.map((m) => (m.id === result.data.id ? result.data : m)),
+    );
+    setStatus("Saved");
+  };
+
+  return (
+    <div className="space-y-2 border-t border-mid-gray/10 pt-4">
+      <label className="text-sm font-medium">OpenRouter Routing</label>
+      <select
+        value={modelId}
+        onChange={(e) => setModelId(e.target.value)}
+        className={inputClass}
+      >
+        {modelList.map((m) => (
+          <option key={m.id} value={m.id}>
+            {m.display_name}
+          </option>
+        ))}
+      </select>
+      <input
+        type="text"
+        value={fallbacks}
+        onChange={(e) => setFallbacks(e.target.value)}
+        placeholder="Fallback models, e.g. anthropic/claude-3.5-haiku"
+        className={inputClass}
+      />
+      <input
+        type="text"
+        value={order}
+        onChange={(e) => setOrder(e.target.value)}
+        placeholder="Preferred providers in order, e.g. Together, Fireworks"
+        className={inputClass}
+      />
+      <div className="flex items-center gap-2">
+        <select
+          value={sort}
+          onChange={(e) => setSort(e.target.value)}
+          className={inputClass}
+        >
+          {SORT_OPTIONS.map((option) => (
+            <option key={option.value} value={option.value}>
+              {option.label}
+            </option>
+          ))}
+        </select>
+        <label className="flex items-center gap-2 text-sm shrink-0">
+          <input
+            type="checkbox"
+            checked={allowFallbacks}
+            onChange={(e) => setAllowFallbacks(e.target.checked)}
+            className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+          />
+          Other providers
+        </label>
+      </div>
+      <div className="flex items-center gap-2">
+        <Button onClick={handleSave} variant="secondary">
+          Save Routing
+        </Button>
+        {status && <span className="text-xs text-mid-gray">{status}</span>}
+      </div>
+    </div>
+  );
+};
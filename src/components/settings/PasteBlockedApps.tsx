@@ -0,0 +1,113 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface PasteBlockedAppsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PasteBlockedApps: React.FC<PasteBlockedAppsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [newApp, setNewApp] = useState("");
+    const blockedApps = getSetting("paste_blocked_apps") || [];
+
+    const handleAddApp = () => {
+      const trimmedApp = newApp.trim();
+      if (
+        trimmedApp &&
+        !blockedApps.some(
+          (app) => app.toLowerCase() === trimmedApp.toLowerCase(),
+        )
+      ) {
+        updateSetting("paste_blocked_apps", [...blockedApps, trimmedApp]);
+        setNewApp("");
+      }
+    };
+
+    const handleRemoveApp = (appToRemove: string) => {
+      updateSetting(
+        "paste_blocked_apps",
+        blockedApps.filter((app) => app !== appToRemove),
+      );
+    };
+
+    const handleKeyPress = (e: React.KeyboardEvent) => {
+      if (e.key === "Enter") {
+        e.preventDefault();
+        handleAddApp();
+      }
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.pasteBlockedApps.title")}
+          description={t("settings.advanced.pasteBlockedApps.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="text"
+              className="max-w-48"
+              value={newApp}
+              onChange={(e) => setNewApp(e.target.value)}
+              onKeyDown={handleKeyPress}
+              placeholder={t("settings.advanced.pasteBlockedApps.placeholder")}
+              variant="compact"
+              disabled={isUpdating("paste_blocked_apps")}
+            />
+            <Button
+              onClick={handleAddApp}
+              disabled={!newApp.trim() || isUpdating("paste_blocked_apps")}
+              variant="primary"
+              size="md"
+            >
+              {t("settings.advanced.pasteBlockedApps.add")}
+            </Button>
+          </div>
+        </SettingContainer>
+        {blockedApps.length > 0 && (
+          <div
+            className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} flex flex-wrap gap-1`}
+          >
+            {blockedApps.map((app) => (
+              <Button
+                key={app}
+                onClick={() => handleRemoveApp(app)}
+                disabled={isUpdating("paste_blocked_apps")}
+                variant="secondary"
+                size="sm"
+                className="inline-flex items-center gap-1 cursor-pointer"
+                aria-label={t("settings.advanced.pasteBlockedApps.remove", {
+                  app,
+                })}
+              >
+                <span>{app}</span>
+                <svg
+                  className="w-3 h-3"
+                  fill="none"
+                  stroke="currentColor"
+                  viewBox="0 0 24 24"
+                >
+                  <path
+                    strokeLinecap="round"
+                    strokeLinejoin="round"
+                    strokeWidth={2}
+                    d="M6 18L18 6M6 6l12 12"
+                  />
+                </svg>
+              </Button>
+            ))}
+          </div>
+        )}
+      </>
+    );
+  },
+);
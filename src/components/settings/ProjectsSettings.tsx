@@ -0,0 +1,198 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Plus, Trash2 } from "lucide-react";
+import {
+  AppError,
+  commands,
+  Project,
+  PromptCategory,
+  PromptMode,
+} from "@/bindings";
+
+import { SettingsGroup } from "../ui/SettingsGroup";
+import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "@/lib/utils/errors";
+
+const PROMPT_MODES: PromptMode[] = ["dynamic", "low", "medium", "high"];
+
+const inputClass =
+  "px-2 py-1.5 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+const parseWords = (text: string) =>
+  text
+    .split(",")
+    .map((word) => word.trim())
+    .filter((word) => word.length > 0);
+
+/** Projects switched by voice, each with a prompt mode, category and vocabulary */
+export const ProjectsSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const projects: Project[] = settings?.projects ?? [];
+  const categories: PromptCategory[] = settings?.prompt_categories ?? [];
+  const activeId = settings?.active_project_id ?? null;
+
+  const [name, setName] = useState("");
+  const [promptMode, setPromptMode] = useState("");
+  const [categoryId, setCategoryId] = useState("");
+  const [words, setWords] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  const save = async (
+    action: Promise<
+      { status: "ok"; data: unknown } | { status: "error"; error: AppError }
+    >,
+  ) => {
+    const result = await action;
+    if (result.status === "error") {
+      setError(formatError(result.error));
+      return false;
+    }
+    setError(null);
+    await refreshSettings();
+    return true;
+  };
+
+  const handleAdd = async () => {
+    const added = await save(
+      commands.addProject({
+        id: `project_${Date.now()}`,
+        name: name.trim(),
+        prompt_mode: (promptMode as PromptMode) || null,
+        default_category_id: categoryId || null,
+        custom_words: parseWords(words),
+      }),
+    );
+    if (added) {
+      setName("");
+      setWords("");
+    }
+  };
+
+  const categoryName = (id: string) =>
+    categories.find((c) => c.id === id)?.name ?? id;
+
+  const describe = (project: Project) =>
+    [
+      project.prompt_mode,
+      project.default_category_id &&
+        categoryName(project.default_category_id),
+      (project.custom_words ?? []).join(", "),
+    ]
+      .filter(Boolean)
+      .join(" · ");
+
+  return (
+    <SettingsGroup title={t("settings.projects.title", "Projects")}>
+      <div className="px-4 py-3 space-y-3">
+        <p className="text-xs text-mid-gray">
+          {t(
+            "settings.projects.description",
+            'Say "switch to project" and its name to apply its prompt mode, default category and vocabulary. The active project\'s name is available to prompts as ${project}.',
+          )}
+        </p>
+
+        {projects.map((project) => (
+          <div
+            key={project.id}
+            className="flex items-center justify-between gap-2 text-sm"
+          >
+            <div className="min-w-0">
+              <p className="truncate font-medium">{project.name}</p>
+              <p className="text-xs text-mid-gray truncate">
+                {describe(project)}
+              </p>
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="radio"
+                name="active-project"
+                checked={project.id === activeId}
+                onChange={() => save(commands.setActiveProject(project.id))}
+                title={t("settings.projects.active", "Active project")}
+                className="border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+              />
+              <button
+                onClick={() => save(commands.deleteProject(project.id))}
+                className="p-1 text-mid-gray hover:text-red-400"
+                title={t("settings.projects.delete", "Delete project")}
+              >
+                <Trash2 className="h-4 w-4" />
+              </button>
+            </div>
+          </div>
+        ))}
+
+        {activeId && (
+          <button
+            onClick={() => save(commands.setActiveProject(null))}
+            className="text-xs text-mid-gray hover:text-logo-primary"
+          >
+            {t("settings.projects.leave", "Leave the active project")}
+          </button>
+        )}
+
+        <div className="space-y-2 border-t border-mid-gray/10 pt-3">
+          <div className="flex items-center gap-2">
+            <input
+              type="text"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder={t("settings.projects.namePlaceholder", "Atlas")}
+              className={`${inputClass} flex-1`}
+            />
+            <select
+              value={promptMode}
+              onChange={(e) => setPromptMode(e.target.value)}
+              className={inputClass}
+            >
+              <option value="">
+                {t("settings.projects.keepMode", "Keep prompt mode")}
+              </option>
+              {PROMPT_MODES.map((mode) => (
+                <option key={mode} value={mode}>
+                  {mode}
+                </option>
+              ))}
+            </select>
+          </div>
+          <input
+            type="text"
+            value={words}
+            onChange={(e) => setWords(e.target.value)}
+            placeholder={t(
+              "settings.projects.wordsPlaceholder",
+              "Project vocabulary, separated by commas",
+            )}
+            className={`${inputClass} w-full`}
+          />
+          <div className="flex items-center gap-2">
+            <select
+              value={categoryId}
+              onChange={(e) => setCategoryId(e.target.value)}
+              className={`${inputClass} flex-1`}
+            >
+              <option value="">
+                {t("settings.projects.keepCategory", "Keep default category")}
+              </option>
+              {categories.map((category) => (
+                <option key={category.id} value={category.id}>
+                  {category.name}
+                </option>
+              ))}
+            </select>
+            <button
+              onClick={handleAdd}
+              disabled={!name.trim()}
+              className="flex items-center gap-1 px-3 py-1.5 bg-logo-primary text-white rounded-lg text-sm hover:bg-logo-primary/90 transition-colors disabled:opacity-50"
+            >
+              <Plus className="h-4 w-4" />
+              {t("settings.projects.add", "Add")}
+            </button>
+          </div>
+          {error && <p className="text-xs text-red-400">{error}</p>}
+        </div>
+      </div>
+    </SettingsGroup>
+  );
+};
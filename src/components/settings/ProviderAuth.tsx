@@ -34,7 +34,9 @@ export const ProviderAuth: React.FC<ProviderAuthProps> = ({
     isLoading,
     error,
     isAuthenticating,
+    cliCredentials,
     startAuth,
+    importCliCredentials,
     logout,
     refreshToken,
   } = useOAuth(providerId);
@@ -182,26 +184,46 @@ export const ProviderAuth: React.FC<ProviderAuthProps> = ({
                 </Button>
               </>
             ) : (
-              <Button
-                onClick={() => startAuth()}
-                disabled={isAuthenticating}
-                variant="primary"
-                className="flex items-center gap-2"
-              >
-                {isAuthenticating ? (
-                  <>
-                    <RefreshCw className="h-4 w-4 animate-spin" />
-                    Signing in...
-                  </>
-                ) : (
-                  <>
-                    <LogIn className="h-4 w-4" />
-                    Sign in with {getProviderDisplayName(providerId)}
-                  </>
+              <>
+                <Button
+                  onClick={() => startAuth()}
+                  disabled={isAuthenticating}
+                  variant="primary"
+                  className="flex items-center gap-2"
+                >
+                  {isAuthenticating ? (
+                    <>
+                      <RefreshCw className="h-4 w-4 animate-spin" />
+                      Signing in...
+                    </>
+                  ) : (
+                    <>
+                      <LogIn className="h-4 w-4" />
+                      Sign in with {getProviderDisplayName(providerId)}
+                    </>
+                  )}
+                </Button>
+                {cliCredentials && (
+                  <Button
+                    onClick={() => importCliCredentials()}
+                    disabled={isAuthenticating}
+                    variant="secondary"
+                    className="flex items-center gap-2"
+                    title={cliCredentials.source_path}
+                  >
+                    Use {getCliName(cliCredentials.provider)} sign-in
+                    {cliCredentials.email ? ` (${cliCredentials.email})` : ""}
+                  </Button>
                 )}
-              </Button>
+              </>
             )}
           </div>
+          {!status?.authenticated && cliCredentials?.signs_out_cli && (
+            <p className="text-xs text-mid-gray">
+              The {getCliName(cliCredentials.provider)} will have to sign in
+              again after its sign-in is used here.
+            </p>
+          )}
         </div>
       ) : (
         /* API Key Input */
@@ -234,6 +256,13 @@ function getProviderDisplayName(providerId: string): string {
   return providerId;
 }
 
+/**
+ * Name of the CLI whose credentials can be imported for an OAuth provider
+ */
+function getCliName(provider: "google" | "openai"): string {
+  return provider === "google" ? "Gemini CLI" : "Codex CLI";
+}
+
 /**
  * Simple OAuth status indicator component
  */
@@ -0,0 +1,120 @@
+import React, { useState } from "react";
+import { Trash2 } from "lucide-react";
+import { commands, LLMProvider, Result } from "@/bindings";
+import { Button } from "../ui/Button";
+import { formatError } from "@/lib/utils/errors";
+
+interface ProviderCredentialsProps {
+  provider: LLMProvider;
+  /** Key currently entered in the dialog, saved when adding a credential */
+  apiKey: string;
+  /** Endpoint entered in the dialog; defaults to the provider's current one */
+  baseUrl?: string;
+  /** Called with the updated provider after the active credential changes */
+  onChange: (provider: LLMProvider) => void;
+}
+
+/** Named API keys for a provider, so switching e.g. personal/work keys is one click */
+export const ProviderCredentials: React.FC<ProviderCredentialsProps> = ({
+  provider: initialProvider,
+  apiKey,
+  baseUrl,
+  onChange,
+}) => {
+  const [provider, setProvider] = useState(initialProvider);
+  const [newName, setNewName] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  const credentials = provider.credentials ?? [];
+  const activeId = provider.active_credential_id ?? "";
+
+  const apply = (result: Result<LLMProvider, string>, notify: boolean) => {
+    if (result.status === "error") {
+      setError(formatError(result.error));
+      return;
+    }
+    setError(null);
+    setProvider(result.data);
+    if (notify) onChange(result.data);
+  };
+
+  const handleSelect = async (credentialId: string) => {
+    if (!credentialId) return;
+    apply(
+      await commands.setActiveProviderCredential(provider.id, credentialId),
+      true,
+    );
+  };
+
+  const handleSaveCurrent = async () => {
+    const id = crypto.randomUUID();
+    const saved = await commands.saveProviderCredential(provider.id, {
+      id,
+      name: newName.trim(),
+      api_key: apiKey,
+      base_url: baseUrl ?? provider.base_url,
+    });
+    if (saved.status === "error") {
+      apply(saved, false);
+      return;
+    }
+    // The key being saved is the one in use, so make it the active credential
+    apply(await commands.setActiveProviderCredential(provider.id, id), true);
+    setNewName("");
+  };
+
+  const handleDelete = async () => {
+    if (!activeId) return;
+    apply(
+      await commands.deleteProviderCredential(provider.id, activeId),
+      false,
+    );
+  };
+
+  return (
+    <div className="space-y-2">
+      <label className="text-sm font-medium">Saved Keys</label>
+      {credentials.length > 0 && (
+        <div className="flex items-center gap-2">
+          <select
+            value={activeId}
+            onChange={(e) => handleSelect(e.target.value)}
+            className="flex-1 px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+          >
+            {!activeId && <option value="">Unsaved key</option>}
+            {credentials.map((c) => (
+              <option key={c.id} value={c.id}>
+                {c.name}
+              </option>
+            ))}
+          </select>
+          <button
+            onClick={handleDelete}
+            disabled={!activeId}
+            className="p-2 text-mid-gray hover:text-red-500 transition-colors disabled:opacity-50"
+            title="Delete saved key"
+          >
+            <Trash2 className="h-4 w-4" />
+          </button>
+        </div>
+      )}
+      <div className="flex items-center gap-2">
+        <input
+          type="text"
+          value={newName}
+          onChange={(e) => setNewName(e.target.value)}
+          placeholder="Name for the current key, e.g. Work"
+          className="flex-1 px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+        />
+        <Button
+          onClick={handleSaveCurrent}
+          disabled={!newName.trim() || !apiKey.trim()}
+          variant="secondary"
+        >
+          Save Key
+        </Button>
+      </div>
+      {error && <p className="text-xs text-red-500">{error}</p>}
+    </div>
+  );
+};
@@ -0,0 +1,43 @@
+import React, { useState } from "react";
+import { commands, LLMProvider } from "@/bindings";
+import { formatError } from "@/lib/utils/errors";
+
+interface ProviderFreeProps {
+  provider: LLMProvider;
+}
+
+/** Lets a self-hosted server on another machine skip the spend limits */
+export const ProviderFree: React.FC<ProviderFreeProps> = ({ provider }) => {
+  const [free, setFree] = useState(provider.free ?? false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = async (value: boolean) => {
+    setFree(value);
+    const result = await commands.setProviderFree(provider.id, value);
+    if (result.status === "error") {
+      setFree(!value);
+      setError(formatError(result.error));
+      return;
+    }
+    setError(null);
+  };
+
+  return (
+    <div className="space-y-1">
+      <label className="flex items-center gap-2 text-sm cursor-pointer">
+        <input
+          type="checkbox"
+          checked={free}
+          onChange={(e) => handleChange(e.target.checked)}
+          className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+        />
+        <span className="font-medium">Free to use</span>
+      </label>
+      <p className="text-xs text-mid-gray">
+        Don't count this provider toward the spend limits. Only servers on this
+        computer are free otherwise.
+      </p>
+      {error && <p className="text-xs text-red-500">{error}</p>}
+    </div>
+  );
+};
@@ -0,0 +1,95 @@
+import React, { useState } from "react";
+import { Plus, Trash2 } from "lucide-react";
+import { commands, LLMProvider } from "@/bindings";
+import { Button } from "../ui/Button";
+import { formatError } from "@/lib/utils/errors";
+
+interface ProviderHeadersProps {
+  provider: LLMProvider;
+}
+
+type HeaderRow = { name: string; value: string };
+
+const toRows = (provider: LLMProvider): HeaderRow[] =>
+  Object.entries(provider.custom_headers ?? {}).map(([name, value]) => ({
+    name,
+    value: value ?? "",
+  }));
+
+/** Extra request headers for gateways that need e.g. an org ID or own auth */
+export const ProviderHeaders: React.FC<ProviderHeadersProps> = ({
+  provider,
+}) => {
+  const [rows, setRows] = useState<HeaderRow[]>(() => toRows(provider));
+  const [saved, setSaved] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const updateRow = (index: number, row: Partial<HeaderRow>) => {
+    setRows(rows.map((r, i) => (i === index ? { ...r, ...row } : r)));
+    setSaved(false);
+  };
+
+  const removeRow = (index: number) => {
+    setRows(rows.filter((_, i) => i !== index));
+    setSaved(false);
+  };
+
+  const handleSave = async () => {
+    const headers = Object.fromEntries(rows.map((r) => [r.name, r.value]));
+    const result = await commands.updateProviderHeaders(provider.id, headers);
+    if (result.status === "error") {
+      setError(formatError(result.error));
+      return;
+    }
+    setError(null);
+    setRows(toRows(result.data));
+    setSaved(true);
+  };
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between">
+        <label className="text-sm font-medium">Custom Headers</label>
+        <button
+          onClick={() => setRows([...rows, { name: "", value: "" }])}
+          className="p-1 text-mid-gray hover:text-logo-primary transition-colors"
+          title="Add header"
+        >
+          <Plus className="h-4 w-4" />
+        </button>
+      </div>
+      {rows.map((row, index) => (
+        <div key={index} className="flex items-center gap-2">
+          <input
+            type="text"
+            value={row.name}
+            onChange={(e) => updateRow(index, { name: e.target.value })}
+            placeholder="x-org-id"
+            className="w-40 px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+          />
+          <input
+            type="text"
+            value={row.value}
+            onChange={(e) => updateRow(index, { value: e.target.value })}
+            placeholder="Value, {api_key} is replaced with the key"
+            className="flex-1 px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+          />
+          <button
+            onClick={() => removeRow(index)}
+            className="p-2 text-mid-gray hover:text-red-500 transition-colors"
+            title="Remove header"
+          >
+            <Trash2 className="h-4 w-4" />
+          </button>
+        </div>
+      ))}
+      <div className="flex items-center gap-2">
+        <Button onClick={handleSave} variant="secondary">
+          Save Headers
+        </Button>
+        {saved && <span className="text-xs text-mid-gray">Saved</span>}
+      </div>
+      {error && <p className="text-xs text-red-500">{error}</p>}
+    </div>
+  );
+};
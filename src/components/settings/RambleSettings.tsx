@@ -18,10 +18,15 @@ import {
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { SettingContainer } from "../ui/SettingContainer";
 import { ModelsDropdown } from "../ui/ModelsDropdown";
+import { LANGUAGES } from "../../lib/constants/languages";
 
 import { useSettings } from "../../hooks/useSettings";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { RambleShortcut } from "./RambleShortcut";
 import { AppMappingsSettings } from "./AppMappingsSettings";
+import { SpendLimits } from "./SpendLimits";
+import { RemindersSettings } from "./RemindersSettings";
+import { ProjectsSettings } from "./ProjectsSettings";
 
 export const RambleSettings: React.FC = () => {
   const { t } = useTranslation();
@@ -60,6 +65,18 @@ export const RambleSettings: React.FC = () => {
     }
   };
 
+  const handleTitleModelChange = async (modelId: string | null) => {
+    setIsUpdating(true);
+    try {
+      await commands.setDefaultModel("title", modelId);
+      await refreshSettings();
+    } catch (error) {
+      console.error("Failed to change title model:", error);
+    } finally {
+      setIsUpdating(false);
+    }
+  };
+
   const handlePromptModeChange = async (mode: PromptMode) => {
     try {
       await commands.changePromptModeSetting(mode);
@@ -171,6 +188,28 @@ export const RambleSettings: React.FC = () => {
             </li>
           </ul>
         </div>
+        <RambleShortcut shortcutId="refine_clipboard" grouped={true} />
+        <ToggleSwitch
+          checked={settings?.refine_clipboard_paste ?? true}
+          onChange={async (enabled) => {
+            try {
+              await commands.changeRefineClipboardPasteSetting(enabled);
+              await refreshSettings();
+            } catch (error) {
+              console.error("Failed to update clipboard refinement:", error);
+            }
+          }}
+          label={t(
+            "settings.ramble.refineClipboardPaste.title",
+            "Paste Refined Clipboard",
+          )}
+          description={t(
+            "settings.ramble.refineClipboardPaste.description",
+            "Paste the result of Refine Clipboard where you're typing. When off, the refined text replaces what you copied.",
+          )}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.ramble.llm.title", "AI Model")}>
@@ -241,6 +280,148 @@ export const RambleSettings: React.FC = () => {
             </option>
           </select>
         </SettingContainer>
+
+        <SpendLimits descriptionMode="tooltip" grouped={true} />
+
+        <ToggleSwitch
+          checked={settings?.show_edits ?? false}
+          onChange={async (enabled) => {
+            try {
+              await commands.changeShowEditsSetting(enabled);
+              await refreshSettings();
+            } catch (error) {
+              console.error("Failed to update show edits:", error);
+            }
+          }}
+          label={t("settings.ramble.showEdits.title", "Show Edits")}
+          description={t(
+            "settings.ramble.showEdits.description",
+            "Ask the AI to cite content it removes, and keep a track-changes view of each refinement in History.",
+          )}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+
+        <ToggleSwitch
+          checked={settings?.verify_refinement ?? false}
+          onChange={async (enabled) => {
+            try {
+              await commands.changeVerifyRefinementSetting(enabled);
+              await refreshSettings();
+            } catch (error) {
+              console.error("Failed to update refinement check:", error);
+            }
+          }}
+          label={t(
+            "settings.ramble.verifyRefinement.title",
+            "Flag Added Content",
+          )}
+          description={t(
+            "settings.ramble.verifyRefinement.description",
+            "Warn when a refinement contains names or numbers that weren't in what you said, and flag the entry in History.",
+          )}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+
+        <ToggleSwitch
+          checked={settings?.day_context_enabled ?? false}
+          onChange={async (enabled) => {
+            try {
+              await commands.changeDayContextEnabledSetting(enabled);
+              await refreshSettings();
+            } catch (error) {
+              console.error("Failed to update day context:", error);
+            }
+          }}
+          label={t("settings.ramble.dayContext.title", "Remember Today")}
+          description={t(
+            "settings.ramble.dayContext.description",
+            "Send today's earlier dictations along with each refinement so names and things you keep referring to are spelled and expanded the same way all day. Starts over at midnight.",
+          )}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+
+        <SettingContainer
+          title={t(
+            "settings.ramble.chapteringMinWords.title",
+            "Chapter Long Refinements",
+          )}
+          description={t(
+            "settings.ramble.chapteringMinWords.description",
+            "How long a refinement must be before categories with chapters turned on add topic headings and a table of contents.",
+          )}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <select
+            value={settings?.chaptering_min_words ?? 1500}
+            onChange={async (e) => {
+              const words = parseInt(e.target.value, 10);
+              const result = await commands.changeChapteringMinWordsSetting(
+                words,
+              );
+              if (result.status === "error") {
+                console.error("Failed to update chapter length:", result.error);
+              }
+              await refreshSettings();
+            }}
+            className="px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary min-w-[160px]"
+          >
+            {[750, 1500, 3000, 6000].map((words) => (
+              <option key={words} value={words}>
+                {t("settings.ramble.chapteringMinWords.words", {
+                  defaultValue: "{{count}}+ words",
+                  count: words,
+                })}
+              </option>
+            ))}
+          </select>
+        </SettingContainer>
+
+        <ToggleSwitch
+          checked={settings?.history_titles_enabled ?? true}
+          onChange={async (enabled) => {
+            try {
+              await commands.changeHistoryTitlesEnabledSetting(enabled);
+              await refreshSettings();
+            } catch (error) {
+              console.error("Failed to update history titles:", error);
+            }
+          }}
+          label={t("settings.ramble.historyTitles.title", "Title History")}
+          description={t(
+            "settings.ramble.historyTitles.description",
+            "Give each new history entry a short title saying what it's about.",
+          )}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+
+        <SettingContainer
+          title={t("settings.ramble.titleModel.title", "Title Model")}
+          description={t(
+            "settings.ramble.titleModel.description",
+            "A cheap, fast model for history and chat titles. Without one, history titles are made from the first sentence and chats use the chat model.",
+          )}
+          descriptionMode="tooltip"
+          layout="horizontal"
+          grouped={true}
+        >
+          <ModelsDropdown
+            selectedValue={settings?.default_title_model_id ?? null}
+            onSelect={handleTitleModelChange}
+            disabled={isUpdating}
+            allowDefault={true}
+            defaultLabel={t(
+              "settings.ramble.titleModel.none",
+              "None (from the text)",
+            )}
+            className="min-w-[280px]"
+          />
+        </SettingContainer>
       </SettingsGroup>
 
       <SettingsGroup
@@ -340,6 +521,29 @@ export const RambleSettings: React.FC = () => {
                   "Current prompt category",
                 )}
               </span>
+              <code className="bg-mid-gray/10 px-1 rounded">
+                {"${project}"}
+              </code>
+              <span>
+                {t("settings.ramble.variables.project", "Active project name")}
+              </span>
+              <code className="bg-mid-gray/10 px-1 rounded">{"${date}"}</code>
+              <span>
+                {t("settings.ramble.variables.date", "Current date")}
+              </span>
+              <code className="bg-mid-gray/10 px-1 rounded">{"${time}"}</code>
+              <span>
+                {t("settings.ramble.variables.time", "Current time")}
+              </span>
+              <code className="bg-mid-gray/10 px-1 rounded">
+                {"${window_title}"}
+              </code>
+              <span>
+                {t(
+                  "settings.ramble.variables.windowTitle",
+                  "Focused window title",
+                )}
+              </span>
             </div>
           </div>
         </div>
@@ -406,6 +610,74 @@ export const RambleSettings: React.FC = () => {
                   />
                 </div>
 
+                {/* Transcription Language */}
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-mid-gray">
+                    {t(
+                      "settings.ramble.categories.language",
+                      "Transcription Language",
+                    )}
+                  </span>
+                  <select
+                    value={category.language ?? ""}
+                    onChange={async (e) => {
+                      const language = e.target.value || null;
+                      const result =
+                        await commands.updatePromptCategoryLanguage(
+                          category.id,
+                          language,
+                        );
+                      if (result.status === "error") {
+                        console.error(
+                          "Failed to update category language:",
+                          result.error,
+                        );
+                      }
+                      await refreshSettings();
+                    }}
+                    className="min-w-[240px] px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+                  >
+                    <option value="">
+                      {t(
+                        "settings.ramble.categories.useGlobalLanguage",
+                        "Default (use global language)",
+                      )}
+                    </option>
+                    {LANGUAGES.filter((l) => l.value !== "auto").map((l) => (
+                      <option key={l.value} value={l.value}>
+                        {l.label}
+                      </option>
+                    ))}
+                  </select>
+                </div>
+
+                {/* Chaptering */}
+                <label className="flex items-center justify-between gap-2 text-sm text-mid-gray">
+                  {t(
+                    "settings.ramble.categories.chaptering",
+                    "Add chapters and a table of contents to long refinements",
+                  )}
+                  <input
+                    type="checkbox"
+                    checked={category.chaptering ?? false}
+                    onChange={async (e) => {
+                      const result =
+                        await commands.updatePromptCategoryChaptering(
+                          category.id,
+                          e.target.checked,
+                        );
+                      if (result.status === "error") {
+                        console.error(
+                          "Failed to update category chaptering:",
+                          result.error,
+                        );
+                      }
+                      await refreshSettings();
+                    }}
+                    className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+                  />
+                </label>
+
                 <textarea
                   value={categoryPrompts[category.id] ?? ""}
                   onChange={(e) =>
@@ -516,6 +788,9 @@ export const RambleSettings: React.FC = () => {
 
       {/* App Mappings Section (only visible when Dynamic mode is selected) */}
       {settings?.prompt_mode === "dynamic" && <AppMappingsSettings />}
+
+      <RemindersSettings />
+      <ProjectsSettings />
     </div>
   );
 };
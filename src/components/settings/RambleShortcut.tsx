@@ -131,8 +131,11 @@ export const RambleShortcut: React.FC<RambleShortcutProps> = ({
       // If no keys are pressed anymore, commit the shortcut
       const updatedKeyPressed = keyPressed.filter((k) => k !== key);
       if (updatedKeyPressed.length === 0 && recordedKeys.length > 0) {
-        // Create the shortcut string from all recorded keys
-        const newShortcut = recordedKeys.join("+");
+        // Create the shortcut string from all recorded keys, using the labels
+        // of the active keyboard layout (keys are recorded by physical position)
+        const newShortcut = await commands.shortcutToLayoutLabels(
+          recordedKeys.join("+"),
+        );
 
         if (editingShortcutId && bindings[editingShortcutId]) {
           try {
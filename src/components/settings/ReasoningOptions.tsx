@@ -0,0 +1,122 @@
+import React, { useEffect, useState } from "react";
+import { commands, LLMModel } from "@/bindings";
+import { Button } from "../ui/Button";
+import { formatError } from "@/lib/utils/errors";
+
+interface ReasoningOptionsProps {
+  models: LLMModel[];
+}
+
+const EFFORT_OPTIONS = [
+  { value: "", label: "Default effort" },
+  { value: "minimal", label: "Minimal effort" },
+  { value: "low", label: "Low effort" },
+  { value: "medium", label: "Medium effort" },
+  { value: "high", label: "High effort" },
+];
+
+const inputClass =
+  "w-full px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+/** Reasoning effort, thinking budget and trace hiding, per model */
+export const ReasoningOptions: React.FC<ReasoningOptionsProps> = ({
+  models,
+}) => {
+  const [modelList, setModelList] = useState(models);
+  const [modelId, setModelId] = useState(models[0]?.id ?? "");
+  const [effort, setEffort] = useState("");
+  const [budget, setBudget] = useState("");
+  const [hideReasoning, setHideReasoning] = useState(true);
+  const [status, setStatus] = useState<string | null>(null);
+
+  const model = modelList.find((m) => m.id === modelId);
+
+  useEffect(() => {
+    const reasoning = model?.reasoning;
+    setEffort(reasoning?.effort ?? "");
+    setBudget(reasoning?.budget_tokens?.toString() ?? "");
+    setHideReasoning(reasoning?.hide_reasoning ?? true);
+    setStatus(null);
+  }, [modelId]);
+
+  if (modelList.length === 0) return null;
+
+  const handleSave = async () => {
+    const budgetTokens = budget.trim();
+    if (budgetTokens && !/^\d+$/.test(budgetTokens)) {
+      setStatus("Thinking budget must be a number of tokens");
+      return;
+    }
+    const isDefault = !effort && !budgetTokens && hideReasoning;
+    const result = await commands.setModelReasoning(
+      modelId,
+      isDefault
+        ? null
+        : {
+            effort: effort || null,
+            budget_tokens: budgetTokens ? parseInt(budgetTokens, 10) : null,
+            hide_reasoning: hideReasoning,
+          },
+    );
+    if (result.status === "error") {
+      setStatus(formatError(result.error));
+      return;
+    }
+    setModelList(
+      modelList.map((m) => (m.id === result.data.id ? result.data : m)),
+    );
+    setStatus("Saved");
+  };
+
+  return (
+    <div className="space-y-2 border-t border-mid-gray/10 pt-4">
+      <label className="text-sm font-medium">Reasoning</label>
+      <select
+        value={modelId}
+        onChange={(e) => setModelId(e.target.value)}
+        className={inputClass}
+      >
+        {modelList.map((m) => (
+          <option key={m.id} value={m.id}>
+            {m.display_name}
+          </option>
+        ))}
+      </select>
+      <div className="flex items-center gap-2">
+        <select
+          value={effort}
+          onChange={(e) => setEffort(e.target.value)}
+          className={inputClass}
+        >
+          {EFFORT_OPTIONS.map((option) => (
+            <option key={option.value} value={option.value}>
+              {option.label}
+            </option>
+          ))}
+        </select>
+        <input
+          type="text"
+          value={budget}
+          onChange={(e) => setBudget(e.target.value)}
+          placeholder="Thinking budget (tokens)"
+          className={inputClass}
+        />
+      </div>
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={hideReasoning}
+          onChange={(e) => setHideReasoning(e.target.checked)}
+          className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+        />
+        Hide reasoning in chat
+      </label>
+      <div className="flex items-center gap-2">
+        <Button onClick={handleSave} variant="secondary">
+          Save Reasoning
+        </Button>
+        {status && <span className="text-xs text-mid-gray">{status}</span>}
+      </div>
+    </div>
+  );
+};
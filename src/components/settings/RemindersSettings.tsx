@@ -0,0 +1,208 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Plus, Trash2 } from "lucide-react";
+import { commands, PromptCategory, Reminder } from "@/bindings";
+
+import { SettingsGroup } from "../ui/SettingsGroup";
+import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "@/lib/utils/errors";
+
+const WEEKDAYS = [
+  { day: 1, label: "Mon" },
+  { day: 2, label: "Tue" },
+  { day: 3, label: "Wed" },
+  { day: 4, label: "Thu" },
+  { day: 5, label: "Fri" },
+  { day: 6, label: "Sat" },
+  { day: 7, label: "Sun" },
+];
+
+const inputClass =
+  "px-2 py-1.5 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+/** Scheduled notifications to dictate something, optionally with a category */
+export const RemindersSettings: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const reminders: Reminder[] = settings?.reminders ?? [];
+  const categories: PromptCategory[] = settings?.prompt_categories ?? [];
+
+  const [label, setLabel] = useState("");
+  const [time, setTime] = useState("09:00");
+  const [days, setDays] = useState<number[]>([1, 2, 3, 4, 5]);
+  const [date, setDate] = useState("");
+  const [categoryId, setCategoryId] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  const save = async (
+    action: Promise<
+      { status: "ok"; data: Reminder[] } | { status: "error"; error: string }
+    >,
+  ) => {
+    const result = await action;
+    if (result.status === "error") {
+      setError(formatError(result.error));
+      return false;
+    }
+    setError(null);
+    await refreshSettings();
+    return true;
+  };
+
+  const handleAdd = async () => {
+    const added = await save(
+      commands.addReminder({
+        id: `reminder_${Date.now()}`,
+        label: label.trim(),
+        time,
+        days: date ? [] : days,
+        date: date || null,
+        category_id: categoryId || null,
+        enabled: true,
+      }),
+    );
+    if (added) setLabel("");
+  };
+
+  const toggleDay = (day: number) => {
+    setDays((prev) =>
+      prev.includes(day) ? prev.filter((d) => d !== day) : [...prev, day],
+    );
+  };
+
+  const categoryName = (id: string) =>
+    categories.find((c) => c.id === id)?.name ?? id;
+
+  const describe = (reminder: Reminder) => {
+    if (reminder.date) return `${reminder.date} ${reminder.time}`;
+    const reminderDays = reminder.days ?? [];
+    const dayNames =
+      reminderDays.length === 0
+        ? t("settings.reminders.everyDay", "Every day")
+        : WEEKDAYS.filter((w) => reminderDays.includes(w.day))
+            .map((w) => w.label)
+            .join(", ");
+    return `${dayNames} ${reminder.time}`;
+  };
+
+  return (
+    <SettingsGroup title={t("settings.reminders.title", "Reminders")}>
+      <div className="px-4 py-3 space-y-3">
+        <p className="text-xs text-mid-gray">
+          {t(
+            "settings.reminders.description",
+            "Get a notification when it's time to dictate. A reminder with a category makes your next dictation use it.",
+          )}
+        </p>
+
+        {reminders.map((reminder) => (
+          <div
+            key={reminder.id}
+            className="flex items-center justify-between gap-2 text-sm"
+          >
+            <div className="min-w-0">
+              <p className="truncate font-medium">{reminder.label}</p>
+              <p className="text-xs text-mid-gray">
+                {describe(reminder)}
+                {reminder.category_id &&
+                  ` · ${categoryName(reminder.category_id)}`}
+              </p>
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="checkbox"
+                checked={reminder.enabled ?? true}
+                onChange={(e) =>
+                  save(
+                    commands.updateReminder({
+                      ...reminder,
+                      enabled: e.target.checked,
+                    }),
+                  )
+                }
+                title={t("settings.reminders.enabled", "Enabled")}
+                className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+              />
+              <button
+                onClick={() => save(commands.deleteReminder(reminder.id))}
+                className="p-1 text-mid-gray hover:text-red-400"
+                title={t("settings.reminders.delete", "Delete reminder")}
+              >
+                <Trash2 className="h-4 w-4" />
+              </button>
+            </div>
+          </div>
+        ))}
+
+        <div className="space-y-2 border-t border-mid-gray/10 pt-3">
+          <div className="flex items-center gap-2">
+            <input
+              type="text"
+              value={label}
+              onChange={(e) => setLabel(e.target.value)}
+              placeholder={t(
+                "settings.reminders.labelPlaceholder",
+                "Dictate your standup",
+              )}
+              className={`${inputClass} flex-1`}
+            />
+            <input
+              type="time"
+              value={time}
+              onChange={(e) => setTime(e.target.value)}
+              className={inputClass}
+            />
+          </div>
+          <div className="flex flex-wrap items-center gap-1">
+            {WEEKDAYS.map((w) => (
+              <button
+                key={w.day}
+                onClick={() => toggleDay(w.day)}
+                disabled={!!date}
+                className={`px-2 py-1 rounded text-xs border transition-colors disabled:opacity-40 ${
+                  days.includes(w.day)
+                    ? "bg-logo-primary/20 border-logo-primary/50"
+                    : "border-mid-gray/30"
+                }`}
+              >
+                {w.label}
+              </button>
+            ))}
+            <input
+              type="date"
+              value={date}
+              onChange={(e) => setDate(e.target.value)}
+              title={t("settings.reminders.once", "Only once, on this date")}
+              className={`${inputClass} ml-auto`}
+            />
+          </div>
+          <div className="flex items-center gap-2">
+            <select
+              value={categoryId}
+              onChange={(e) => setCategoryId(e.target.value)}
+              className={`${inputClass} flex-1`}
+            >
+              <option value="">
+                {t("settings.reminders.noCategory", "Detected category")}
+              </option>
+              {categories.map((category) => (
+                <option key={category.id} value={category.id}>
+                  {category.name}
+                </option>
+              ))}
+            </select>
+            <button
+              onClick={handleAdd}
+              disabled={!label.trim()}
+              className="flex items-center gap-1 px-3 py-1.5 bg-logo-primary text-white rounded-lg text-sm hover:bg-logo-primary/90 transition-colors disabled:opacity-50"
+            >
+              <Plus className="h-4 w-4" />
+              {t("settings.reminders.add", "Add")}
+            </button>
+          </div>
+          {error && <p className="text-xs text-red-400">{error}</p>}
+        </div>
+      </div>
+    </SettingsGroup>
+  );
+};
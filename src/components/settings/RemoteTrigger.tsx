@@ -0,0 +1,198 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { useSettings } from "../../hooks/useSettings";
+import { commands, events, type RemotePairing } from "@/bindings";
+
+interface RemoteTriggerProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const RemoteTrigger: React.FC<RemoteTriggerProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating, refreshSettings } =
+      useSettings();
+    const [pairing, setPairing] = useState<RemotePairing | null>(null);
+    const [portInput, setPortInput] = useState<string | null>(null);
+
+    const enabled = getSetting("remote_trigger_enabled") ?? false;
+    const port = getSetting("remote_trigger_port") ?? 47613;
+    const lanAccess = getSetting("remote_trigger_lan_access") ?? false;
+    const devices = getSetting("remote_trigger_devices") ?? [];
+
+    useEffect(() => {
+      const unlisten = events.remoteDevicePaired.listen((event) => {
+        setPairing(null);
+        refreshSettings();
+        toast.success(
+          t("settings.general.remoteTrigger.paired", {
+            name: event.payload.name,
+          }),
+        );
+      });
+
+      return () => {
+        unlisten.then((fn) => fn());
+      };
+    }, [refreshSettings, t]);
+
+    useEffect(() => {
+      if (!pairing) return;
+      const timeout = setTimeout(
+        () => setPairing(null),
+        pairing.expires_in_secs * 1000,
+      );
+      return () => clearTimeout(timeout);
+    }, [pairing]);
+
+    const handleStartPairing = async () => {
+      const result = await commands.startRemotePairing();
+      if (result.status === "ok") {
+        setPairing(result.data);
+      } else {
+        toast.error(result.error.message);
+      }
+    };
+
+    const handleCancelPairing = async () => {
+      await commands.cancelRemotePairing();
+      setPairing(null);
+    };
+
+    const handleRemoveDevice = async (id: string) => {
+      const result = await commands.removeRemoteDevice(id);
+      if (result.status === "error") {
+        toast.error(result.error.message);
+      }
+      await refreshSettings();
+    };
+
+    const handlePortCommit = () => {
+      if (portInput === null) return;
+      const value = parseInt(portInput, 10);
+      if (!Number.isNaN(value) && value !== port) {
+        updateSetting("remote_trigger_port", value);
+      }
+      setPortInput(null);
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => {
+            setPairing(null);
+            updateSetting("remote_trigger_enabled", value);
+          }}
+          isUpdating={isUpdating("remote_trigger_enabled")}
+          label={t("settings.general.remoteTrigger.label")}
+          description={t("settings.general.remoteTrigger.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <>
+            <SettingContainer
+              title={t("settings.general.remoteTrigger.port.title")}
+              description={t("settings.general.remoteTrigger.port.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            >
+              <Input
+                type="number"
+                className="w-24"
+                min={1024}
+                max={65535}
+                value={portInput ?? String(port)}
+                onChange={(e) => setPortInput(e.target.value)}
+                onBlur={handlePortCommit}
+                onKeyDown={(e) => e.key === "Enter" && handlePortCommit()}
+                variant="compact"
+                disabled={isUpdating("remote_trigger_port")}
+              />
+            </SettingContainer>
+            <ToggleSwitch
+              checked={lanAccess}
+              onChange={(value) => {
+                setPairing(null);
+                updateSetting("remote_trigger_lan_access", value);
+              }}
+              isUpdating={isUpdating("remote_trigger_lan_access")}
+              label={t("settings.general.remoteTrigger.lanAccess.label")}
+              description={t(
+                "settings.general.remoteTrigger.lanAccess.description",
+              )}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            />
+            <SettingContainer
+              title={t("settings.general.remoteTrigger.pair.title")}
+              description={t("settings.general.remoteTrigger.pair.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            >
+              {pairing ? (
+                <Button variant="secondary" onClick={handleCancelPairing}>
+                  {t("settings.general.remoteTrigger.pair.cancel")}
+                </Button>
+              ) : (
+                <Button variant="primary" onClick={handleStartPairing}>
+                  {t("settings.general.remoteTrigger.pair.start")}
+                </Button>
+              )}
+            </SettingContainer>
+            {pairing && (
+              <div className="px-4 p-2 text-sm">
+                <p className="font-mono text-2xl tracking-widest">
+                  {pairing.code}
+                </p>
+                <p className="text-mid-gray">
+                  {t("settings.general.remoteTrigger.pair.instructions", {
+                    address:
+                      pairing.addresses.length > 0
+                        ? pairing.addresses
+                            .map((address) => `${address}:${pairing.port}`)
+                            .join(", ")
+                        : `localhost:${pairing.port}`,
+                  })}
+                </p>
+              </div>
+            )}
+            {devices.length > 0 && (
+              <div
+                className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} flex flex-col gap-1`}
+              >
+                {devices.map((device) => (
+                  <div
+                    key={device.id}
+                    className="flex items-center justify-between text-sm"
+                  >
+                    <span>
+                      {device.name}
+                      <span className="text-mid-gray ml-2">
+                        {new Date(device.paired_at * 1000).toLocaleDateString()}
+                      </span>
+                    </span>
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => handleRemoveDevice(device.id)}
+                    >
+                      {t("settings.general.remoteTrigger.remove")}
+                    </Button>
+                  </div>
+                ))}
+              </div>
+            )}
+          </>
+        )}
+      </>
+    );
+  },
+);
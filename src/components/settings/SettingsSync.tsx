@@ -0,0 +1,166 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { open } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
+import { commands, events, type SyncReport } from "@/bindings";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Button } from "../ui/Button";
+import { Input } from "../ui/Input";
+import { useSettings } from "../../hooks/useSettings";
+
+interface SettingsSyncProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const SettingsSync: React.FC<SettingsSyncProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const [passphrase, setPassphrase] = useState("");
+    const [isSyncing, setIsSyncing] = useState(false);
+    const [lastReport, setLastReport] = useState<SyncReport | null>(null);
+
+    const folder = getSetting("sync_folder");
+    const hasPassphrase = getSetting("sync_passphrase_saved") ?? false;
+
+    useEffect(() => {
+      const unlisten = events.settingsSynced.listen((event) => {
+        setLastReport(event.payload);
+      });
+
+      return () => {
+        unlisten.then((fn) => fn());
+      };
+    }, []);
+
+    const handleSelectFolder = async () => {
+      const selected = await open({ multiple: false, directory: true });
+      if (selected && typeof selected === "string") {
+        const result = await commands.changeSyncFolderSetting(selected);
+        if (result.status === "error") {
+          toast.error(result.error.message);
+        }
+        await refreshSettings();
+      }
+    };
+
+    const handleStopSync = async () => {
+      await commands.changeSyncFolderSetting(null);
+      await refreshSettings();
+    };
+
+    const handleSavePassphrase = async () => {
+      const result = await commands.changeSyncPassphraseSetting(passphrase);
+      if (result.status === "error") {
+        toast.error(result.error.message);
+        return;
+      }
+      setPassphrase("");
+      await refreshSettings();
+    };
+
+    const handleSyncNow = async () => {
+      setIsSyncing(true);
+      const result = await commands.syncSettingsNow();
+      setIsSyncing(false);
+      if (result.status === "ok") {
+        setLastReport(result.data);
+      } else {
+        toast.error(result.error.message);
+      }
+    };
+
+    const describeReport = (report: SyncReport) => {
+      if (report.conflicts.length > 0) {
+        return t("settings.advanced.sync.conflicts", {
+          settings: report.conflicts.join(", "),
+        });
+      }
+      if (report.pulled.length > 0) {
+        return t("settings.advanced.sync.pulled", {
+          count: report.pulled.length,
+        });
+      }
+      return report.pushed
+        ? t("settings.advanced.sync.pushed")
+        : t("settings.advanced.sync.upToDate");
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.sync.folder.title")}
+          description={t("settings.advanced.sync.folder.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="stacked"
+        >
+          <div className="flex items-center gap-2">
+            <span className="text-sm truncate flex-1">
+              {folder ?? t("settings.advanced.sync.folder.notSet")}
+            </span>
+            <Button variant="secondary" size="md" onClick={handleSelectFolder}>
+              {t("settings.advanced.sync.folder.choose")}
+            </Button>
+            {folder && (
+              <Button variant="ghost" size="md" onClick={handleStopSync}>
+                {t("settings.advanced.sync.folder.stop")}
+              </Button>
+            )}
+          </div>
+        </SettingContainer>
+        <SettingContainer
+          title={t("settings.advanced.sync.passphrase.title")}
+          description={t("settings.advanced.sync.passphrase.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="password"
+              className="max-w-48"
+              value={passphrase}
+              onChange={(e) => setPassphrase(e.target.value)}
+              placeholder={
+                hasPassphrase
+                  ? t("settings.advanced.sync.passphrase.set")
+                  : t("settings.advanced.sync.passphrase.placeholder")
+              }
+              variant="compact"
+            />
+            <Button
+              variant="primary"
+              size="md"
+              onClick={handleSavePassphrase}
+              disabled={passphrase.length === 0}
+            >
+              {t("settings.advanced.sync.passphrase.save")}
+            </Button>
+          </div>
+        </SettingContainer>
+        {folder && hasPassphrase && (
+          <SettingContainer
+            title={t("settings.advanced.sync.now.title")}
+            description={
+              lastReport
+                ? describeReport(lastReport)
+                : t("settings.advanced.sync.now.description")
+            }
+            descriptionMode="inline"
+            grouped={grouped}
+          >
+            <Button
+              variant="secondary"
+              size="md"
+              onClick={handleSyncNow}
+              disabled={isSyncing}
+            >
+              {t("settings.advanced.sync.now.button")}
+            </Button>
+          </SettingContainer>
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,173 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, UsageReport, UsageSummary } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+type LimitKey = "daily_spend_limit_usd" | "per_request_spend_limit_usd";
+
+interface LimitInputProps {
+  settingKey: LimitKey;
+}
+
+const LimitInput: React.FC<LimitInputProps> = ({ settingKey }) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const limit = getSetting(settingKey) ?? null;
+  const [draft, setDraft] = useState(limit === null ? "" : String(limit));
+
+  useEffect(() => {
+    setDraft(limit === null ? "" : String(limit));
+  }, [limit]);
+
+  const commit = () => {
+    const trimmed = draft.trim();
+    if (trimmed === "") {
+      if (limit !== null) updateSetting(settingKey, null);
+      return;
+    }
+    const value = parseFloat(trimmed);
+    if (!isNaN(value) && value > 0) {
+      if (value !== limit) updateSetting(settingKey, value);
+    } else {
+      setDraft(limit === null ? "" : String(limit));
+    }
+  };
+
+  return (
+    <div className="flex items-center space-x-2">
+      <span className="text-sm text-text">$</span>
+      <Input
+        type="number"
+        min="0"
+        step="0.01"
+        value={draft}
+        placeholder={t("settings.ramble.spendLimits.noLimit")}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={commit}
+        disabled={isUpdating(settingKey)}
+        className="w-28"
+      />
+    </div>
+  );
+};
+
+const UsageReportTable: React.FC<{ report: UsageReport }> = ({ report }) => {
+  const { t } = useTranslation();
+
+  return (
+    <div className="px-4 py-2 text-xs">
+      <div className="font-medium mb-1">
+        {t("settings.ramble.spendLimits.report.title")}
+      </div>
+      {report.rows.length === 0 ? (
+        <div className="text-mid-gray">
+          {t("settings.ramble.spendLimits.report.empty")}
+        </div>
+      ) : (
+        <table className="w-full text-left">
+          <thead className="text-mid-gray">
+            <tr>
+              <th className="font-normal">
+                {t("settings.ramble.spendLimits.report.model")}
+              </th>
+              <th className="font-normal text-right">
+                {t("settings.ramble.spendLimits.report.requests")}
+              </th>
+              <th className="font-normal text-right">
+                {t("settings.ramble.spendLimits.report.tokens")}
+              </th>
+              <th className="font-normal text-right">
+                {t("settings.ramble.spendLimits.report.cost")}
+              </th>
+            </tr>
+          </thead>
+          <tbody>
+            {report.rows.map((row) => (
+              <tr key={`${row.provider_id}/${row.model_id}`}>
+                <td className="truncate max-w-48">
+                  {row.provider_id} / {row.model_id}
+                </td>
+                <td className="text-right">{row.requests}</td>
+                <td className="text-right">
+                  {row.prompt_tokens.toLocaleString()} /{" "}
+                  {row.completion_tokens.toLocaleString()}
+                </td>
+                <td className="text-right">
+                  ${row.cost_usd.toFixed(4)}
+                  {row.unpriced_requests > 0 && (
+                    <span className="text-mid-gray">
+                      {" "}
+                      (
+                      {t("settings.ramble.spendLimits.report.unpriced", {
+                        count: row.unpriced_requests,
+                      })}
+                      )
+                    </span>
+                  )}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+};
+
+interface SpendLimitsProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const SpendLimits: React.FC<SpendLimitsProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [summary, setSummary] = useState<UsageSummary | null>(null);
+  const [report, setReport] = useState<UsageReport | null>(null);
+
+  useEffect(() => {
+    commands.getUsageSummary().then((result) => {
+      if (result.status === "ok") setSummary(result.data);
+    });
+    commands.getUsageReport(null).then((result) => {
+      if (result.status === "ok") setReport(result.data);
+    });
+  }, []);
+
+  return (
+    <>
+      <SettingContainer
+        title={t("settings.ramble.spendLimits.daily.title")}
+        description={t("settings.ramble.spendLimits.daily.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <LimitInput settingKey="daily_spend_limit_usd" />
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.ramble.spendLimits.perRequest.title")}
+        description={t("settings.ramble.spendLimits.perRequest.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <LimitInput settingKey="per_request_spend_limit_usd" />
+      </SettingContainer>
+      {summary && (
+        <div className="px-4 py-2 text-xs text-mid-gray">
+          {t("settings.ramble.spendLimits.summary", {
+            today: summary.today_cost_usd.toFixed(4),
+            requests: summary.today_requests,
+            month: summary.month_cost_usd.toFixed(4),
+          })}
+        </div>
+      )}
+      {report && <UsageReportTable report={report} />}
+    </>
+  );
+};
@@ -0,0 +1,77 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import type { TapHoldMode } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface TapHoldModeProps {
+  shortcutId: string;
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+/** Whether taps, holds, both or neither of a shortcut refine the recording */
+export const TapHoldModeSetting: React.FC<TapHoldModeProps> = ({
+  shortcutId,
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const [isUpdating, setIsUpdating] = useState(false);
+
+  const binding = settings?.bindings?.[shortcutId];
+  if (!binding) return null;
+
+  const options = [
+    {
+      value: "tap_refines",
+      label: t("settings.general.tapHoldMode.options.tapRefines"),
+    },
+    {
+      value: "hold_refines",
+      label: t("settings.general.tapHoldMode.options.holdRefines"),
+    },
+    {
+      value: "always_refined",
+      label: t("settings.general.tapHoldMode.options.alwaysRefined"),
+    },
+    {
+      value: "always_raw",
+      label: t("settings.general.tapHoldMode.options.alwaysRaw"),
+    },
+  ];
+
+  const handleSelect = async (value: string) => {
+    setIsUpdating(true);
+    const result = await commands.changeBindingTapHoldModeSetting(
+      shortcutId,
+      value as TapHoldMode,
+    );
+    if (result.status === "error") {
+      console.error("Failed to update tap/hold mode:", result.error);
+    }
+    await refreshSettings();
+    setIsUpdating(false);
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.general.tapHoldMode.title", {
+        name: binding.name,
+      })}
+      description={t("settings.general.tapHoldMode.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <Dropdown
+        options={options}
+        selectedValue={binding.tap_hold_mode ?? "tap_refines"}
+        onSelect={handleSelect}
+        disabled={isUpdating}
+      />
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,53 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { TerminalPasteMode } from "@/bindings";
+
+interface TerminalPasteModeProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const TerminalPasteModeSetting: React.FC<TerminalPasteModeProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const terminalPasteModeOptions = [
+      {
+        value: "off",
+        label: t("settings.advanced.terminalPasteMode.options.off"),
+      },
+      {
+        value: "strip_newlines",
+        label: t("settings.advanced.terminalPasteMode.options.stripNewlines"),
+      },
+      {
+        value: "shell_escape",
+        label: t("settings.advanced.terminalPasteMode.options.shellEscape"),
+      },
+    ];
+
+    const selectedMode = (getSetting("terminal_paste_mode") ||
+      "off") as TerminalPasteMode;
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.terminalPasteMode.title")}
+        description={t("settings.advanced.terminalPasteMode.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={terminalPasteModeOptions}
+          selectedValue={selectedMode}
+          onSelect={(value) =>
+            updateSetting("terminal_paste_mode", value as TerminalPasteMode)
+          }
+          disabled={isUpdating("terminal_paste_mode")}
+        />
+      </SettingContainer>
+    );
+  });
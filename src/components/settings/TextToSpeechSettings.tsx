@@ -7,15 +7,25 @@ import { SettingContainer } from "../ui/SettingContainer";
 import { ModelsDropdown } from "../ui/ModelsDropdown";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
 import { useSettings } from "../../hooks/useSettings";
-import { commands, ModelInfo } from "@/bindings";
+import { commands, ModelInfo, TtsBackend, TtsVoice } from "@/bindings";
+import { formatError } from "@/lib/utils/errors";
 import { RambleShortcut } from "./RambleShortcut";
 import { LanguageSelector } from "./LanguageSelector";
+import { ApiKeyField } from "./PostProcessingSettingsApi/ApiKeyField";
+
+/** Backends that synthesize speech through a hosted API */
+const CLOUD_BACKENDS: TtsBackend[] = ["openai", "elevenlabs"];
 
 export const TextToSpeechSettings: React.FC = () => {
   const { t } = useTranslation();
   const { settings, updateSetting, refreshSettings, isUpdating } =
     useSettings();
   const [availableModels, setAvailableModels] = useState<ModelInfo[]>([]);
+  const [systemVoices, setSystemVoices] = useState<TtsVoice[]>([]);
+  const [cloudVoices, setCloudVoices] = useState<TtsVoice[]>([]);
+  const [cloudVoicesError, setCloudVoicesError] = useState<string | null>(
+    null,
+  );
 
   useEffect(() => {
     const loadModels = async () => {
@@ -24,7 +34,16 @@ export const TextToSpeechSettings: React.FC = () => {
         setAvailableModels(result.data.filter((m) => m.engine_type === "TTS"));
       }
     };
+    const loadVoices = async () => {
+      const result = await commands.listSystemVoices();
+      if (result.status === "ok") {
+        setSystemVoices(result.data);
+      } else {
+        console.error("Failed to list system voices:", result.error);
+      }
+    };
     loadModels();
+    loadVoices();
   }, []);
 
   const handleModelDownload = async (modelId: string) => {
@@ -55,6 +74,54 @@ export const TextToSpeechSettings: React.FC = () => {
     (m) => m.id === ttsSelectedModelId,
   );
   const contextChatModelId = settings?.default_context_chat_model_id || null;
+  const ttsBackend = settings?.tts_backend ?? "auto";
+  const cloudService = CLOUD_BACKENDS.includes(ttsBackend) ? ttsBackend : null;
+  const cloudApiKey = cloudService
+    ? (settings?.tts_api_keys?.[cloudService] ?? "")
+    : "";
+
+  useEffect(() => {
+    if (!cloudService) return;
+    let cancelled = false;
+    const loadCloudVoices = async () => {
+      const result = await commands.listCloudTtsVoices(cloudService);
+      if (cancelled) return;
+      if (result.status === "ok") {
+        setCloudVoices(result.data);
+        setCloudVoicesError(null);
+      } else {
+        setCloudVoices([]);
+        setCloudVoicesError(formatError(result.error));
+      }
+    };
+    loadCloudVoices();
+    return () => {
+      cancelled = true;
+    };
+  }, [cloudService, cloudApiKey]);
+
+  const handleApiKeyChange = async (apiKey: string) => {
+    if (!cloudService) return;
+    const result = await commands.setTtsApiKey(cloudService, apiKey.trim());
+    if (result.status === "ok") {
+      await refreshSettings();
+    } else {
+      console.error("Failed to save speech API key:", result.error);
+    }
+  };
+
+  const handleCloudVoiceChange = async (voice: string) => {
+    if (!cloudService) return;
+    const result = await commands.changeTtsCloudVoiceSetting(
+      cloudService,
+      voice,
+    );
+    if (result.status === "ok") {
+      await refreshSettings();
+    } else {
+      console.error("Failed to change speech voice:", result.error);
+    }
+  };
 
   return (
     <div className="max-w-3xl w-full mx-auto space-y-6">
@@ -73,53 +140,150 @@ export const TextToSpeechSettings: React.FC = () => {
         />
 
         <SettingContainer
-          title="Speech Model"
-          description="Choose the AI voice model. Kokoro is recommended for natural sound."
+          title={t("settings.tts.backend.title")}
+          description={t("settings.tts.backend.description")}
           layout="horizontal"
           grouped={true}
         >
-          <div className="flex flex-col gap-2 min-w-[200px]">
+          <select
+            value={ttsBackend}
+            onChange={(e) =>
+              updateSetting("tts_backend", e.target.value as TtsBackend)
+            }
+            disabled={isUpdating("tts_backend")}
+            className="min-w-[200px] px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+          >
+            <option value="auto">{t("settings.tts.backend.auto")}</option>
+            <option value="kokoro">{t("settings.tts.backend.kokoro")}</option>
+            <option value="system">{t("settings.tts.backend.system")}</option>
+            <option value="openai">{t("settings.tts.backend.openai")}</option>
+            <option value="elevenlabs">
+              {t("settings.tts.backend.elevenlabs")}
+            </option>
+          </select>
+        </SettingContainer>
+
+        {cloudService && (
+          <>
+            <SettingContainer
+              title={t("settings.tts.apiKey.title")}
+              description={t("settings.tts.apiKey.description")}
+              descriptionMode="tooltip"
+              layout="horizontal"
+              grouped={true}
+            >
+              <ApiKeyField
+                value={cloudApiKey}
+                onBlur={handleApiKeyChange}
+                disabled={false}
+                placeholder={t("settings.tts.apiKey.placeholder")}
+              />
+            </SettingContainer>
+
+            <SettingContainer
+              title={t("settings.tts.cloudVoice.title")}
+              description={
+                cloudVoicesError ?? t("settings.tts.cloudVoice.description")
+              }
+              layout="horizontal"
+              grouped={true}
+            >
+              <select
+                value={settings?.tts_cloud_voices?.[cloudService] ?? ""}
+                onChange={(e) => handleCloudVoiceChange(e.target.value)}
+                disabled={cloudVoices.length === 0}
+                className="min-w-[200px] max-w-[280px] px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+              >
+                <option value="">
+                  {t("settings.tts.cloudVoice.default")}
+                </option>
+                {cloudVoices.map((voice) => (
+                  <option key={voice.id} value={voice.id}>
+                    {voice.language
+                      ? `${voice.name} (${voice.language})`
+                      : voice.name}
+                  </option>
+                ))}
+              </select>
+            </SettingContainer>
+          </>
+        )}
+
+        {(ttsBackend === "auto" || ttsBackend === "system") && (
+          <SettingContainer
+            title={t("settings.tts.systemVoice.title")}
+            description={t("settings.tts.systemVoice.description")}
+            layout="horizontal"
+            grouped={true}
+          >
             <select
-              value={ttsSelectedModelId}
+              value={settings?.tts_system_voice ?? ""}
               onChange={(e) =>
-                updateSetting("tts_selected_model", e.target.value)
+                updateSetting("tts_system_voice", e.target.value || null)
               }
-              className="px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
+              disabled={isUpdating("tts_system_voice")}
+              className="min-w-[200px] max-w-[280px] px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
             >
-              {availableModels.map((model) => (
-                <option key={model.id} value={model.id}>
-                  {model.name} ({model.size_mb}MB)
+              <option value="">{t("settings.tts.systemVoice.default")}</option>
+              {systemVoices.map((voice) => (
+                <option key={voice.id} value={voice.id}>
+                  {voice.name} ({voice.language})
                 </option>
               ))}
             </select>
+          </SettingContainer>
+        )}
 
-            {ttsSelectedModel && !ttsSelectedModel.is_downloaded && (
-              <button
-                onClick={() => handleModelDownload(ttsSelectedModel.id)}
-                disabled={ttsSelectedModel.is_downloading}
-                className="flex items-center justify-center gap-2 px-3 py-1.5 bg-logo-primary/10 text-logo-primary hover:bg-logo-primary/20 rounded-lg text-xs transition-colors disabled:opacity-50"
+        {(ttsBackend === "auto" || ttsBackend === "kokoro") && (
+          <SettingContainer
+            title="Speech Model"
+            description="Choose the AI voice model. Kokoro is recommended for natural sound."
+            layout="horizontal"
+            grouped={true}
+          >
+            <div className="flex flex-col gap-2 min-w-[200px]">
+              <select
+                value={ttsSelectedModelId}
+                onChange={(e) =>
+                  updateSetting("tts_selected_model", e.target.value)
+                }
+                className="px-3 py-2 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary"
               >
-                {ttsSelectedModel.is_downloading ? (
-                  <>
-                    <Loader2 className="h-3 w-3 animate-spin" />
-                    {t("common.downloading", "Downloading...")}
-                  </>
-                ) : (
-                  <>
-                    <Download className="h-3 w-3" />
-                    {t("settings.tts.download", "Download Model")}
-                  </>
-                )}
-              </button>
-            )}
-            {ttsSelectedModel?.is_downloaded && (
-              <div className="flex items-center gap-1 text-[10px] text-green-500 justify-end">
-                <Check className="h-3 w-3" />
-                {t("settings.tts.ready", "Ready for local use")}
-              </div>
-            )}
-          </div>
-        </SettingContainer>
+                {availableModels.map((model) => (
+                  <option key={model.id} value={model.id}>
+                    {model.name} ({model.size_mb}MB)
+                  </option>
+                ))}
+              </select>
+
+              {ttsSelectedModel && !ttsSelectedModel.is_downloaded && (
+                <button
+                  onClick={() => handleModelDownload(ttsSelectedModel.id)}
+                  disabled={ttsSelectedModel.is_downloading}
+                  className="flex items-center justify-center gap-2 px-3 py-1.5 bg-logo-primary/10 text-logo-primary hover:bg-logo-primary/20 rounded-lg text-xs transition-colors disabled:opacity-50"
+                >
+                  {ttsSelectedModel.is_downloading ? (
+                    <>
+                      <Loader2 className="h-3 w-3 animate-spin" />
+                      {t("common.downloading", "Downloading...")}
+                    </>
+                  ) : (
+                    <>
+                      <Download className="h-3 w-3" />
+                      {t("settings.tts.download", "Download Model")}
+                    </>
+                  )}
+                </button>
+              )}
+              {ttsSelectedModel?.is_downloaded && (
+                <div className="flex items-center gap-1 text-[10px] text-green-500 justify-end">
+                  <Check className="h-3 w-3" />
+                  {t("settings.tts.ready", "Ready for local use")}
+                </div>
+              )}
+            </div>
+          </SettingContainer>
+        )}
 
         <SettingContainer
           title="Speech Speed"
@@ -0,0 +1,275 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Download, Upload } from "lucide-react";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import {
+  commands,
+  PackCommandPreview,
+  PackConflict,
+  PackPreview,
+  VoiceCommand,
+} from "@/bindings";
+
+import { SettingsGroup } from "../ui/SettingsGroup";
+import { useSettings } from "../../hooks/useSettings";
+import { formatError } from "@/lib/utils/errors";
+
+const inputClass =
+  "px-2 py-1.5 bg-background border border-mid-gray/30 rounded-lg text-sm focus:outline-none focus:border-logo-primary";
+
+const buttonClass =
+  "flex items-center gap-1 px-3 py-1.5 bg-mid-gray/10 hover:bg-mid-gray/20 rounded-lg text-sm transition-colors";
+
+/** Share custom voice commands as JSON packs */
+export const VoiceCommandPacks: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const customCommands: VoiceCommand[] = (
+    settings?.voice_commands ?? []
+  ).filter((cmd) => !cmd.is_builtin && cmd.command_type === "custom");
+
+  const [packName, setPackName] = useState("");
+  const [selected, setSelected] = useState<string[]>([]);
+  const [conflict, setConflict] = useState<PackConflict>("skip");
+  const [status, setStatus] = useState<string | null>(null);
+  // A pack being imported, shown with its scripts until the import is confirmed
+  const [pending, setPending] = useState<{
+    path: string;
+    preview: PackPreview;
+  } | null>(null);
+
+  const toggleSelected = (id: string) =>
+    setSelected((ids) =>
+      ids.includes(id) ? ids.filter((other) => other !== id) : [...ids, id],
+    );
+
+  const handleExport = async () => {
+    const name = packName.trim() || "Voice commands";
+    const path = await save({
+      defaultPath: `${name.toLowerCase().replace(/\s+/g, "-")}.json`,
+      filters: [{ name: "JSON", extensions: ["json"] }],
+    });
+    if (!path) return;
+
+    const result = await commands.exportVoiceCommandPack(path, name, selected);
+    setStatus(
+      result.status === "ok"
+        ? t("settings.voiceCommands.packs.exported", {
+            defaultValue: "Exported {{count}} commands",
+            count: result.data,
+          })
+        : formatError(result.error),
+    );
+  };
+
+  const previewPack = async (path: string, conflictChoice: PackConflict) => {
+    const result = await commands.previewVoiceCommandPack(path, conflictChoice);
+    if (result.status === "error") {
+      setPending(null);
+      setStatus(formatError(result.error));
+      return;
+    }
+    setStatus(null);
+    setPending({ path, preview: result.data });
+  };
+
+  const handleImport = async () => {
+    const path = await open({
+      multiple: false,
+      filters: [{ name: "JSON", extensions: ["json"] }],
+    });
+    if (!path) return;
+    await previewPack(path, conflict);
+  };
+
+  const handleConflictChange = async (value: PackConflict) => {
+    setConflict(value);
+    if (pending) {
+      await previewPack(pending.path, value);
+    }
+  };
+
+  const handleConfirmImport = async () => {
+    if (!pending) return;
+    const result = await commands.importVoiceCommandPack(
+      pending.path,
+      conflict,
+      pending.preview.confirmation,
+    );
+    setPending(null);
+    if (result.status === "error") {
+      setStatus(formatError(result.error));
+      return;
+    }
+    await refreshSettings();
+    const { added, replaced, renamed, skipped } = result.data;
+    setStatus(
+      t("settings.voiceCommands.packs.imported", {
+        defaultValue:
+          "Added {{added}}, replaced {{replaced}}, renamed {{renamed}}",
+        added,
+        replaced,
+        renamed,
+      }) +
+        (skipped.length > 0
+          ? ` · ${t("settings.voiceCommands.packs.skipped", {
+              defaultValue: "skipped: {{names}}",
+              names: skipped.join(", "),
+            })}`
+          : ""),
+    );
+  };
+
+  const outcomeLabel = (command: PackCommandPreview) => {
+    switch (command.outcome) {
+      case "add":
+        return t("settings.voiceCommands.packs.outcomeAdd", "New");
+      case "replace":
+        return t("settings.voiceCommands.packs.outcomeReplace", {
+          defaultValue: "Replaces {{name}}",
+          name: command.conflicts_with,
+        });
+      case "rename":
+        return t("settings.voiceCommands.packs.outcomeRename", {
+          defaultValue: "Added next to {{name}}",
+          name: command.conflicts_with,
+        });
+      case "skip":
+        return t("settings.voiceCommands.packs.outcomeSkip", "Skipped");
+    }
+  };
+
+  return (
+    <SettingsGroup
+      title={t("settings.voiceCommands.packs.title", "Command Packs")}
+    >
+      <div className="px-4 py-3 space-y-3">
+        <p className="text-xs text-mid-gray">
+          {t(
+            "settings.voiceCommands.packs.description",
+            "Share custom commands with your team as a JSON pack. Export the commands you check, or all of them when none are checked.",
+          )}
+        </p>
+
+        {customCommands.length > 0 && (
+          <div className="space-y-1">
+            {customCommands.map((cmd) => (
+              <label key={cmd.id} className="flex items-center gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  checked={selected.includes(cmd.id)}
+                  onChange={() => toggleSelected(cmd.id)}
+                  className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+                />
+                {cmd.name}
+              </label>
+            ))}
+          </div>
+        )}
+
+        <div className="flex items-center gap-2">
+          <input
+            type="text"
+            value={packName}
+            onChange={(e) => setPackName(e.target.value)}
+            placeholder={t("settings.voiceCommands.packs.name", "Pack name")}
+            className={`${inputClass} flex-1`}
+          />
+          <button
+            onClick={handleExport}
+            disabled={customCommands.length === 0}
+            className={`${buttonClass} disabled:opacity-50`}
+          >
+            <Download className="h-4 w-4" />
+            {t("settings.voiceCommands.packs.export", "Export")}
+          </button>
+        </div>
+
+        <div className="flex items-center gap-2">
+          <select
+            value={conflict}
+            onChange={(e) =>
+              handleConflictChange(e.target.value as PackConflict)
+            }
+            className={`${inputClass} flex-1`}
+          >
+            <option value="skip">
+              {t(
+                "settings.voiceCommands.packs.conflictSkip",
+                "Keep my command on a conflict",
+              )}
+            </option>
+            <option value="replace">
+              {t(
+                "settings.voiceCommands.packs.conflictReplace",
+                "Replace my command on a conflict",
+              )}
+            </option>
+            <option value="keep_both">
+              {t(
+                "settings.voiceCommands.packs.conflictKeepBoth",
+                "Keep both on a conflict",
+              )}
+            </option>
+          </select>
+          <button onClick={handleImport} className={buttonClass}>
+            <Upload className="h-4 w-4" />
+            {t("settings.voiceCommands.packs.import", "Import")}
+          </button>
+        </div>
+
+        {pending && (
+          <div className="space-y-2 border border-mid-gray/30 rounded-lg p-3">
+            <p className="text-sm font-medium">
+              {t("settings.voiceCommands.packs.reviewTitle", {
+                defaultValue: "Review {{name}} before importing",
+                name: pending.preview.name,
+              })}
+            </p>
+            <p className="text-xs text-mid-gray">
+              {t(
+                "settings.voiceCommands.packs.reviewDescription",
+                "These scripts run on your computer when you say their phrases. Only import them if you trust where the pack came from.",
+              )}
+            </p>
+            {pending.preview.commands.map((command, index) => (
+              <div key={index} className="space-y-1">
+                <div className="flex items-center justify-between gap-2 text-sm">
+                  <span className="font-medium">{command.name}</span>
+                  <span className="text-xs text-mid-gray">
+                    {outcomeLabel(command)}
+                  </span>
+                </div>
+                <p className="text-xs text-mid-gray">
+                  {command.phrases.join(", ")}
+                </p>
+                {command.script && (
+                  <pre className="text-xs bg-mid-gray/10 rounded p-2 overflow-x-auto whitespace-pre-wrap">
+                    {command.script}
+                  </pre>
+                )}
+              </div>
+            ))}
+            <div className="flex justify-end gap-2">
+              <button onClick={() => setPending(null)} className={buttonClass}>
+                {t("settings.voiceCommands.packs.cancel", "Cancel")}
+              </button>
+              <button
+                onClick={handleConfirmImport}
+                disabled={pending.preview.commands.every(
+                  (command) => command.outcome === "skip",
+                )}
+                className={`${buttonClass} disabled:opacity-50`}
+              >
+                <Upload className="h-4 w-4" />
+                {t("settings.voiceCommands.packs.confirmImport", "Import")}
+              </button>
+            </div>
+          </div>
+        )}
+
+        {status && <p className="text-xs text-mid-gray">{status}</p>}
+      </div>
+    </SettingsGroup>
+  );
+};
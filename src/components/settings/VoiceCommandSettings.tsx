@@ -7,6 +7,8 @@ import { SettingContainer } from "../ui/SettingContainer";
 import { ModelsDropdown } from "../ui/ModelsDropdown";
 import { useSettings } from "../../hooks/useSettings";
 import { CommandEditorModal } from "./CommandEditorModal";
+import { FollowUpListening } from "./FollowUpListening";
+import { VoiceCommandPacks } from "./VoiceCommandPacks";
 import { VoiceCommand, commands, DefaultModels } from "@/bindings";
 
 export const VoiceCommandSettings: React.FC = () => {
@@ -126,6 +128,7 @@ export const VoiceCommandSettings: React.FC = () => {
             </li>
           </ul>
         </div>
+        <FollowUpListening grouped={true} />
       </SettingsGroup>
 
       {
@@ -346,6 +349,8 @@ export const VoiceCommandSettings: React.FC = () => {
         </SettingsGroup>
       )}
 
+      <VoiceCommandPacks />
+
       {/* Add Command Button */}
       {
         <div className="flex justify-center">
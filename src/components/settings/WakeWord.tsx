@@ -0,0 +1,94 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+import { useSettings } from "../../hooks/useSettings";
+import { commands } from "@/bindings";
+
+interface WakeWordProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const WakeWord: React.FC<WakeWordProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [available, setAvailable] = useState(false);
+
+    useEffect(() => {
+      commands.isWakeWordAvailable().then(setAvailable);
+    }, []);
+
+    const alwaysOn = getSetting("always_on_microphone") ?? false;
+    const enabled = getSetting("wake_word_enabled") ?? false;
+    const binding = getSetting("wake_word_binding") ?? "transcribe";
+    const threshold = getSetting("wake_word_threshold") ?? 0.5;
+
+    const bindingOptions = [
+      {
+        value: "transcribe",
+        label: t("settings.sound.wakeWord.bindings.transcribe"),
+      },
+      {
+        value: "voice_command",
+        label: t("settings.sound.wakeWord.bindings.voiceCommand"),
+      },
+    ];
+
+    // Hidden until the wake word's models are installed
+    if (!available) {
+      return null;
+    }
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) => updateSetting("wake_word_enabled", value)}
+          isUpdating={isUpdating("wake_word_enabled")}
+          disabled={!alwaysOn}
+          label={t("settings.sound.wakeWord.label")}
+          description={
+            alwaysOn
+              ? t("settings.sound.wakeWord.description")
+              : t("settings.sound.wakeWord.requiresAlwaysOn")
+          }
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {alwaysOn && enabled && (
+          <>
+            <SettingContainer
+              title={t("settings.sound.wakeWord.binding.title")}
+              description={t("settings.sound.wakeWord.binding.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            >
+              <Dropdown
+                options={bindingOptions}
+                selectedValue={binding}
+                onSelect={(value) => updateSetting("wake_word_binding", value)}
+                disabled={isUpdating("wake_word_binding")}
+              />
+            </SettingContainer>
+            <Slider
+              value={threshold}
+              onChange={(value) => updateSetting("wake_word_threshold", value)}
+              min={0.1}
+              max={0.9}
+              step={0.05}
+              disabled={isUpdating("wake_word_threshold")}
+              label={t("settings.sound.wakeWord.threshold.title")}
+              description={t("settings.sound.wakeWord.threshold.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            />
+          </>
+        )}
+      </>
+    );
+  },
+);
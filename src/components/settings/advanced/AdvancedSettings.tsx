@@ -1,9 +1,12 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
 import { ShowOverlay } from "../ShowOverlay";
+import { HideWhenPresenting } from "../HideWhenPresenting";
 import { TranslateToEnglish } from "../TranslateToEnglish";
+import { CodeSwitching } from "../CodeSwitching";
 import { ModelUnloadTimeoutSetting } from "../ModelUnloadTimeout";
 import { CustomWords } from "../CustomWords";
+import { CasedTerms } from "../CasedTerms";
 import { FillerWordFilter } from "../FillerWordFilter";
 import { CollapseRepeatedWords } from "../CollapseRepeatedWords";
 import { SettingsGroup } from "../../ui/SettingsGroup";
@@ -11,6 +14,12 @@ import { StartHidden } from "../StartHidden";
 import { AutostartToggle } from "../AutostartToggle";
 import { PasteMethodSetting } from "../PasteMethod";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
+import { PasteBlockedApps } from "../PasteBlockedApps";
+import { MaxPasteLength } from "../MaxPasteLength";
+import { TerminalPasteModeSetting } from "../TerminalPasteMode";
+import { NoSpeechTimeout } from "../NoSpeechTimeout";
+import { BatterySaver } from "../BatterySaver";
+import { SettingsSync } from "../SettingsSync";
 
 export const AdvancedSettings: React.FC = () => {
   const { t } = useTranslation();
@@ -20,14 +29,25 @@ export const AdvancedSettings: React.FC = () => {
         <StartHidden descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
+        <HideWhenPresenting descriptionMode="tooltip" grouped={true} />
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
         <ClipboardHandlingSetting descriptionMode="tooltip" grouped={true} />
+        <PasteBlockedApps descriptionMode="tooltip" grouped />
+        <MaxPasteLength descriptionMode="tooltip" grouped />
+        <TerminalPasteModeSetting descriptionMode="tooltip" grouped />
         <TranslateToEnglish descriptionMode="tooltip" grouped={true} />
+        <CodeSwitching descriptionMode="tooltip" grouped={true} />
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
+        <BatterySaver descriptionMode="tooltip" grouped={true} />
+        <NoSpeechTimeout descriptionMode="tooltip" grouped={true} />
         <CustomWords descriptionMode="tooltip" grouped />
+        <CasedTerms descriptionMode="tooltip" grouped />
         <FillerWordFilter descriptionMode="tooltip" grouped />
         <CollapseRepeatedWords descriptionMode="tooltip" grouped />
       </SettingsGroup>
+      <SettingsGroup title={t("settings.advanced.sync.title")}>
+        <SettingsSync descriptionMode="tooltip" grouped />
+      </SettingsGroup>
     </div>
   );
 };
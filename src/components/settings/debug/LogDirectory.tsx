@@ -3,6 +3,7 @@ import { useTranslation } from "react-i18next";
 import { commands } from "@/bindings";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { Button } from "../../ui/Button";
+import { formatError } from "@/lib/utils/errors";
 
 interface LogDirectoryProps {
   descriptionMode?: "tooltip" | "inline";
@@ -25,7 +26,7 @@ export const LogDirectory: React.FC<LogDirectoryProps> = ({
         if (result.status === "ok") {
           setLogDir(result.data);
         } else {
-          setError(result.error);
+          setError(formatError(result.error));
         }
       } catch (err) {
         const errorMessage =
@@ -9,6 +9,16 @@ import { useSettings } from "../../../hooks/useSettings";
 import { VolumeSlider } from "../VolumeSlider";
 import { LLMProviderSettings } from "../LLMProviderSettings";
 import { SystemPromptFile } from "../SystemPromptFile";
+import { DoublePressCancel } from "../DoublePressCancel";
+import { NoiseSuppression } from "../NoiseSuppression";
+import { LiveTranscription } from "../LiveTranscription";
+import { WakeWord } from "../WakeWord";
+import { AccessibilityAnnouncements } from "../AccessibilityAnnouncements";
+import { AutoStopSilence } from "../AutoStopSilence";
+import { TapHoldModeSetting } from "../TapHoldMode";
+import { FocusMode } from "../FocusMode";
+import { MediaKeyTrigger } from "../MediaKeyTrigger";
+import { RemoteTrigger } from "../RemoteTrigger";
 
 export const GeneralSettings: React.FC = () => {
   const { t } = useTranslation();
@@ -18,9 +28,35 @@ export const GeneralSettings: React.FC = () => {
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title={t("settings.general.title")}>
         <RambleShortcut shortcutId="transcribe" grouped={true} />
+        <AutoStopSilence
+          shortcutId="transcribe"
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+        <TapHoldModeSetting
+          shortcutId="transcribe"
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+        <RambleShortcut shortcutId="transcribe_system_audio" grouped={true} />
+        <AutoStopSilence
+          shortcutId="transcribe_system_audio"
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+        <TapHoldModeSetting
+          shortcutId="transcribe_system_audio"
+          descriptionMode="tooltip"
+          grouped={true}
+        />
         <RambleShortcut shortcutId="voice_command" grouped={true} />
         <RambleShortcut shortcutId="quick_chat" grouped={true} />
         <RambleShortcut shortcutId="pause_toggle" grouped={true} />
+        <RambleShortcut shortcutId="toggle_shortcuts" grouped={true} />
+        <DoublePressCancel descriptionMode="tooltip" grouped={true} />
+        <FocusMode descriptionMode="tooltip" grouped={true} />
+        <MediaKeyTrigger descriptionMode="tooltip" grouped={true} />
+        <RemoteTrigger descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
       <LLMProviderSettings />
       <SettingsGroup title={t("settings.general.systemPromptFile.groupTitle")}>
@@ -28,7 +64,11 @@ export const GeneralSettings: React.FC = () => {
       </SettingsGroup>
       <SettingsGroup title={t("settings.sound.title")}>
         <MicrophoneSelector descriptionMode="tooltip" grouped={true} />
+        <NoiseSuppression descriptionMode="tooltip" grouped={true} />
+        <LiveTranscription descriptionMode="tooltip" grouped={true} />
+        <WakeWord descriptionMode="tooltip" grouped={true} />
         <AudioFeedback descriptionMode="tooltip" grouped={true} />
+        <AccessibilityAnnouncements descriptionMode="tooltip" grouped={true} />
         <OutputDeviceSelector
           descriptionMode="tooltip"
           grouped={true}
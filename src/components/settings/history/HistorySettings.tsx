@@ -2,11 +2,33 @@ import React, { useState, useEffect, useCallback } from "react";
 import { useTranslation } from "react-i18next";
 import { AudioPlayer } from "../../ui/AudioPlayer";
 import { Button } from "../../ui/Button";
-import { Copy, Star, Check, Trash2, FolderOpen } from "lucide-react";
+import {
+  Copy,
+  Star,
+  Check,
+  Trash2,
+  FolderOpen,
+  FileAudio,
+  Download,
+  GitCompare,
+  Sparkles,
+  Search,
+  Share2,
+  X,
+} from "lucide-react";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { commands, type HistoryEntry } from "@/bindings";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import {
+  commands,
+  type EditAlignment,
+  type HistoryEntry,
+  type HistoryExportFormat,
+  type SemanticMatch,
+  type SummaryStyle,
+} from "@/bindings";
 import { formatDateTime } from "@/utils/dateFormat";
+import { formatError } from "@/lib/utils/errors";
 
 interface OpenRecordingsButtonProps {
   onClick: () => void;
@@ -29,10 +51,169 @@ const OpenRecordingsButton: React.FC<OpenRecordingsButtonProps> = ({
   </Button>
 );
 
+const EXPORT_EXTENSIONS: Record<HistoryExportFormat, string> = {
+  markdown: "md",
+  json: "json",
+  csv: "csv",
+};
+
+const DAY_SECONDS = 24 * 60 * 60;
+
+/** Saves the history (or its last days) as Markdown, JSON or CSV */
+const ExportHistoryButton: React.FC = () => {
+  const { t } = useTranslation();
+  const [format, setFormat] = useState<HistoryExportFormat>("markdown");
+  const [days, setDays] = useState(0);
+  const [exporting, setExporting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleExport = async () => {
+    const extension = EXPORT_EXTENSIONS[format];
+    const path = await save({
+      defaultPath: `ramble-history.${extension}`,
+      filters: [{ name: format.toUpperCase(), extensions: [extension] }],
+    });
+    if (!path) return;
+
+    setExporting(true);
+    setError(null);
+    const from =
+      days > 0 ? Math.floor(Date.now() / 1000) - days * DAY_SECONDS : null;
+    const result = await commands.exportHistory(
+      format,
+      { from, to: null, saved_only: false },
+      path,
+    );
+    if (result.status === "error") {
+      setError(formatError(result.error));
+    }
+    setExporting(false);
+  };
+
+  const selectClass =
+    "text-xs bg-mid-gray/10 border border-mid-gray/20 rounded px-1 py-1";
+
+  return (
+    <div className="flex items-center gap-2">
+      {error && (
+        <span className="text-xs text-red-500 max-w-48 truncate" title={error}>
+          {error}
+        </span>
+      )}
+      <select
+        value={days}
+        onChange={(e) => setDays(Number(e.target.value))}
+        className={selectClass}
+        title={t("settings.history.export.range")}
+      >
+        <option value={0}>{t("settings.history.export.all")}</option>
+        <option value={7}>
+          {t("settings.history.export.lastDays", { count: 7 })}
+        </option>
+        <option value={30}>
+          {t("settings.history.export.lastDays", { count: 30 })}
+        </option>
+      </select>
+      <select
+        value={format}
+        onChange={(e) => setFormat(e.target.value as HistoryExportFormat)}
+        className={selectClass}
+        title={t("settings.history.export.format")}
+      >
+        <option value="markdown">Markdown</option>
+        <option value="json">JSON</option>
+        <option value="csv">CSV</option>
+      </select>
+      <Button
+        onClick={handleExport}
+        variant="secondary"
+        size="sm"
+        className="flex items-center gap-2"
+        disabled={exporting}
+      >
+        <Download className="w-4 h-4" />
+        <span>
+          {exporting
+            ? t("settings.history.export.exporting")
+            : t("settings.history.export.button")}
+        </span>
+      </Button>
+    </div>
+  );
+};
+
+/** Picks an existing audio file (e.g. a voice memo) and transcribes it into history */
+const ImportAudioButton: React.FC = () => {
+  const { t } = useTranslation();
+  const [refine, setRefine] = useState(false);
+  const [importing, setImporting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleImport = async () => {
+    const selected = await open({
+      multiple: false,
+      directory: false,
+      filters: [
+        {
+          name: t("settings.history.import.filterName"),
+          extensions: ["wav", "mp3", "m4a", "flac", "ogg"],
+        },
+      ],
+    });
+    if (!selected || typeof selected !== "string") return;
+
+    setImporting(true);
+    setError(null);
+    // The new entry shows up through the history-updated event
+    const result = await commands.transcribeAudioFile(selected, refine);
+    if (result.status === "error") {
+      setError(formatError(result.error));
+    }
+    setImporting(false);
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      {error && (
+        <span className="text-xs text-red-500 max-w-48 truncate" title={error}>
+          {error}
+        </span>
+      )}
+      <label className="flex items-center gap-1 text-xs text-mid-gray cursor-pointer">
+        <input
+          type="checkbox"
+          checked={refine}
+          onChange={(e) => setRefine(e.target.checked)}
+          className="rounded border-mid-gray/30 text-logo-primary focus:ring-logo-primary"
+        />
+        {t("settings.history.import.refine")}
+      </label>
+      <Button
+        onClick={handleImport}
+        variant="secondary"
+        size="sm"
+        className="flex items-center gap-2"
+        disabled={importing}
+      >
+        <FileAudio className="w-4 h-4" />
+        <span>
+          {importing
+            ? t("settings.history.import.importing")
+            : t("settings.history.import.button")}
+        </span>
+      </Button>
+    </div>
+  );
+};
+
 export const HistorySettings: React.FC = () => {
   const { t } = useTranslation();
   const [historyEntries, setHistoryEntries] = useState<HistoryEntry[]>([]);
   const [loading, setLoading] = useState(true);
+  const [query, setQuery] = useState("");
+  const [matches, setMatches] = useState<SemanticMatch[] | null>(null);
+  const [searching, setSearching] = useState(false);
+  const [searchError, setSearchError] = useState<string | null>(null);
 
   const loadHistoryEntries = useCallback(async () => {
     try {
@@ -111,6 +292,25 @@ export const HistorySettings: React.FC = () => {
     }
   };
 
+  const handleSearch = async () => {
+    if (!query.trim()) return;
+    setSearching(true);
+    setSearchError(null);
+    const result = await commands.semanticSearchHistory(query, null);
+    if (result.status === "ok") {
+      setMatches(result.data);
+    } else {
+      setSearchError(formatError(result.error));
+    }
+    setSearching(false);
+  };
+
+  const clearSearch = () => {
+    setQuery("");
+    setMatches(null);
+    setSearchError(null);
+  };
+
   const openRecordingsFolder = async () => {
     try {
       await commands.openRecordingsFolder();
@@ -129,10 +329,13 @@ export const HistorySettings: React.FC = () => {
                 {t("settings.history.title")}
               </h2>
             </div>
-            <OpenRecordingsButton
-              onClick={openRecordingsFolder}
-              label={t("settings.history.openFolder")}
-            />
+            <div className="flex items-center gap-2">
+              <ImportAudioButton />
+              <OpenRecordingsButton
+                onClick={openRecordingsFolder}
+                label={t("settings.history.openFolder")}
+              />
+            </div>
           </div>
           <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
             <div className="px-4 py-3 text-center text-text/60">
@@ -154,10 +357,13 @@ export const HistorySettings: React.FC = () => {
                 {t("settings.history.title")}
               </h2>
             </div>
-            <OpenRecordingsButton
-              onClick={openRecordingsFolder}
-              label={t("settings.history.openFolder")}
-            />
+            <div className="flex items-center gap-2">
+              <ImportAudioButton />
+              <OpenRecordingsButton
+                onClick={openRecordingsFolder}
+                label={t("settings.history.openFolder")}
+              />
+            </div>
           </div>
           <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
             <div className="px-4 py-3 text-center text-text/60">
@@ -169,6 +375,10 @@ export const HistorySettings: React.FC = () => {
     );
   }
 
+  const visibleEntries = matches
+    ? matches.map((match) => match.entry)
+    : historyEntries;
+
   return (
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <div className="space-y-2">
@@ -178,14 +388,59 @@ export const HistorySettings: React.FC = () => {
               {t("settings.history.title")}
             </h2>
           </div>
-          <OpenRecordingsButton
-            onClick={openRecordingsFolder}
-            label={t("settings.history.openFolder")}
-          />
+          <div className="flex items-center gap-2">
+            <ExportHistoryButton />
+            <ImportAudioButton />
+            <OpenRecordingsButton
+              onClick={openRecordingsFolder}
+              label={t("settings.history.openFolder")}
+            />
+          </div>
+        </div>
+        <div className="px-4 flex items-center gap-2">
+          <div className="flex-1 flex items-center gap-2 px-2 py-1 bg-mid-gray/10 border border-mid-gray/20 rounded">
+            <Search className="w-4 h-4 text-mid-gray" />
+            <input
+              type="text"
+              value={query}
+              onChange={(e) => setQuery(e.target.value)}
+              onKeyDown={(e) => e.key === "Enter" && handleSearch()}
+              placeholder={t("settings.history.search.placeholder")}
+              className="flex-1 bg-transparent text-sm focus:outline-none"
+              disabled={searching}
+            />
+            {(query || matches) && (
+              <button
+                onClick={clearSearch}
+                className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer"
+                title={t("settings.history.search.clear")}
+              >
+                <X className="w-4 h-4" />
+              </button>
+            )}
+          </div>
+          {searching && (
+            <span className="text-xs text-mid-gray">
+              {t("settings.history.search.searching")}
+            </span>
+          )}
+          {searchError && (
+            <span
+              className="text-xs text-red-500 max-w-48 truncate"
+              title={searchError}
+            >
+              {searchError}
+            </span>
+          )}
         </div>
         <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
+          {matches && matches.length === 0 && (
+            <div className="px-4 py-3 text-center text-text/60">
+              {t("settings.history.search.noResults")}
+            </div>
+          )}
           <div className="divide-y divide-mid-gray/20">
-            {historyEntries.map((entry) => (
+            {visibleEntries.map((entry) => (
               <HistoryEntryComponent
                 key={entry.id}
                 entry={entry}
@@ -201,6 +456,40 @@ export const HistorySettings: React.FC = () => {
   );
 };
 
+const EDIT_SPAN_CLASSES = {
+  equal: "text-text/90",
+  insert: "text-green-400 bg-green-500/10 rounded px-0.5",
+  delete: "text-red-400/80 line-through bg-red-500/10 rounded px-0.5",
+};
+
+/** Track-changes view of a refinement against its raw transcription */
+const EditsView: React.FC<{ alignment: EditAlignment }> = ({ alignment }) => {
+  const { t } = useTranslation();
+  return (
+    <div className="flex flex-col gap-2">
+      <p className="text-sm leading-relaxed">
+        {alignment.spans.map((span, index) => (
+          <React.Fragment key={index}>
+            <span className={EDIT_SPAN_CLASSES[span.kind]}>{span.text}</span>{" "}
+          </React.Fragment>
+        ))}
+      </p>
+      {alignment.notes.length > 0 && (
+        <div className="text-xs text-mid-gray">
+          <p className="font-medium">{t("settings.history.edits.removed")}</p>
+          <ul className="list-disc list-inside">
+            {alignment.notes.map((note, index) => (
+              <li key={index}>{note}</li>
+            ))}
+          </ul>
+        </div>
+      )}
+    </div>
+  );
+};
+
+const SUMMARY_STYLES: SummaryStyle[] = ["bullets", "action_items", "tldr"];
+
 interface HistoryEntryProps {
   entry: HistoryEntry;
   onToggleSaved: () => void;
@@ -218,6 +507,12 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   const [audioUrl, setAudioUrl] = useState<string | null>(null);
   const [showCopiedOriginal, setShowCopiedOriginal] = useState(false);
   const [showCopiedRefined, setShowCopiedRefined] = useState(false);
+  const [edits, setEdits] = useState<EditAlignment | null>(null);
+  const [showEdits, setShowEdits] = useState(false);
+  const [summarizing, setSummarizing] = useState<SummaryStyle | null>(null);
+  const [summaryError, setSummaryError] = useState<string | null>(null);
+  const [showCopiedSummary, setShowCopiedSummary] = useState(false);
+  const [showShared, setShowShared] = useState(false);
 
   useEffect(() => {
     const loadAudio = async () => {
@@ -227,6 +522,15 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     loadAudio();
   }, [entry.file_name, getAudioUrl]);
 
+  useEffect(() => {
+    if (!entry.post_processed_text) return;
+    commands.getHistoryEdits(entry.id).then((result) => {
+      if (result.status === "ok") {
+        setEdits(result.data);
+      }
+    });
+  }, [entry.id, entry.post_processed_text]);
+
   const copyToClipboard = async (text: string) => {
     try {
       await navigator.clipboard.writeText(text);
@@ -255,6 +559,41 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     }
   };
 
+  const handleSummarize = async (style: SummaryStyle) => {
+    setSummarizing(style);
+    setSummaryError(null);
+    const result = await commands.summarizeHistoryEntry(entry.id, style);
+    setSummarizing(null);
+    if (result.status === "error") {
+      setSummaryError(formatError(result.error));
+    }
+  };
+
+  const handleCopySummary = async () => {
+    if (entry.summary) {
+      const success = await copyToClipboard(entry.summary.text);
+      if (success) {
+        setShowCopiedSummary(true);
+        setTimeout(() => setShowCopiedSummary(false), 2000);
+      }
+    }
+  };
+
+  const handleShare = async () => {
+    const result = await commands.shareHistoryEntry(
+      entry.id,
+      true,
+      "text",
+      null,
+    );
+    if (result.status === "ok") {
+      setShowShared(true);
+      setTimeout(() => setShowShared(false), 2000);
+    } else {
+      console.error("Failed to share entry:", result.error);
+    }
+  };
+
   const handleDeleteEntry = async () => {
     try {
       await deleteAudio(entry.id);
@@ -273,8 +612,19 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     <div className="px-4 py-2 pb-5 flex flex-col gap-3">
       {/* Header with date and action buttons */}
       <div className="flex justify-between items-center">
-        <div className="flex items-center gap-2">
-          <p className="text-sm font-medium">{formattedDate}</p>
+        <div className="flex items-center gap-2 min-w-0">
+          {entry.generated_title ? (
+            <>
+              <p className="text-sm font-medium truncate">
+                {entry.generated_title}
+              </p>
+              <p className="text-xs text-mid-gray flex-shrink-0">
+                {formattedDate}
+              </p>
+            </>
+          ) : (
+            <p className="text-sm font-medium">{formattedDate}</p>
+          )}
           {isFailed && (
             <span className="text-xs bg-red-500/20 text-red-400 px-2 py-0.5 rounded">
               {t("settings.history.failed")}
@@ -285,6 +635,16 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               {t("settings.history.processing")}
             </span>
           )}
+          {entry.invented_terms.length > 0 && (
+            <span
+              className="text-xs bg-yellow-500/20 text-yellow-400 px-2 py-0.5 rounded"
+              title={t("settings.history.invented.tooltip", {
+                terms: entry.invented_terms.join(", "),
+              })}
+            >
+              {t("settings.history.invented.badge")}
+            </span>
+          )}
         </div>
         <div className="flex items-center gap-1">
           <button
@@ -306,6 +666,17 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               fill={entry.saved ? "currentColor" : "none"}
             />
           </button>
+          <button
+            onClick={handleShare}
+            className="p-2 text-text/50 hover:text-logo-primary transition-colors cursor-pointer"
+            title={t("settings.history.share")}
+          >
+            {showShared ? (
+              <Check width={16} height={16} />
+            ) : (
+              <Share2 width={16} height={16} />
+            )}
+          </button>
           <button
             onClick={handleDeleteEntry}
             className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer"
@@ -340,21 +711,40 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
                   <span className="text-xs font-medium text-logo-primary/80 uppercase tracking-wide">
                     {t("settings.history.refined")}
                   </span>
-                  <button
-                    onClick={handleCopyRefined}
-                    className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer p-1"
-                    title={t("settings.history.copyRefined")}
-                  >
-                    {showCopiedRefined ? (
-                      <Check width={14} height={14} />
-                    ) : (
-                      <Copy width={14} height={14} />
+                  <div className="flex items-center">
+                    {edits && (
+                      <button
+                        onClick={() => setShowEdits(!showEdits)}
+                        className={`transition-colors cursor-pointer p-1 ${
+                          showEdits
+                            ? "text-logo-primary"
+                            : "text-text/50 hover:text-logo-primary"
+                        }`}
+                        title={t("settings.history.edits.toggle")}
+                      >
+                        <GitCompare width={14} height={14} />
+                      </button>
                     )}
-                  </button>
+                    <button
+                      onClick={handleCopyRefined}
+                      className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer p-1"
+                      title={t("settings.history.copyRefined")}
+                    >
+                      {showCopiedRefined ? (
+                        <Check width={14} height={14} />
+                      ) : (
+                        <Copy width={14} height={14} />
+                      )}
+                    </button>
+                  </div>
                 </div>
-                <p className="text-text/90 text-sm">
-                  {entry.post_processed_text}
-                </p>
+                {showEdits && edits ? (
+                  <EditsView alignment={edits} />
+                ) : (
+                  <p className="text-text/90 text-sm">
+                    {entry.post_processed_text}
+                  </p>
+                )}
               </div>
 
               {/* Original text - secondary/muted */}
@@ -399,6 +789,65 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               </button>
             </div>
           )}
+          {entry.summary && (
+            <div className="border-l-2 border-logo-primary/30 pl-3">
+              <div className="flex items-center justify-between mb-1">
+                <span className="text-xs font-medium text-logo-primary/80 uppercase tracking-wide">
+                  {t(`settings.history.summary.styles.${entry.summary.style}`)}
+                </span>
+                <button
+                  onClick={handleCopySummary}
+                  className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer p-1"
+                  title={t("settings.history.summary.copy")}
+                >
+                  {showCopiedSummary ? (
+                    <Check width={14} height={14} />
+                  ) : (
+                    <Copy width={14} height={14} />
+                  )}
+                </button>
+              </div>
+              <p className="text-text/90 text-sm whitespace-pre-wrap">
+                {entry.summary.text}
+              </p>
+            </div>
+          )}
+          <div className="flex flex-wrap items-center gap-1 text-xs">
+            <Sparkles width={12} height={12} className="text-mid-gray" />
+            <span className="text-mid-gray">
+              {t("settings.history.summary.label")}
+            </span>
+            {SUMMARY_STYLES.map((style) => (
+              <button
+                key={style}
+                onClick={() => handleSummarize(style)}
+                disabled={summarizing !== null}
+                className="px-2 py-0.5 rounded border border-mid-gray/30 hover:border-logo-primary transition-colors cursor-pointer disabled:opacity-50"
+              >
+                {summarizing === style
+                  ? t("settings.history.summary.working")
+                  : t(`settings.history.summary.styles.${style}`)}
+              </button>
+            ))}
+            {summaryError && (
+              <span className="text-red-400 truncate" title={summaryError}>
+                {summaryError}
+              </span>
+            )}
+          </div>
+          <p className="text-xs text-mid-gray">
+            {t("settings.history.stats.summary", {
+              words: entry.stats.word_count,
+              seconds: entry.stats.reading_time_seconds,
+            })}
+            {entry.stats.size_delta_percent !== null &&
+              ` · ${t("settings.history.stats.delta", {
+                delta:
+                  entry.stats.size_delta_percent > 0
+                    ? `+${entry.stats.size_delta_percent}`
+                    : entry.stats.size_delta_percent,
+              })}`}
+          </p>
         </>
       )}
 
@@ -18,7 +18,9 @@ export { AudioFeedback } from "./AudioFeedback";
 export { ShowOverlay } from "./ShowOverlay";
 export { RambleShortcut } from "./RambleShortcut";
 export { TranslateToEnglish } from "./TranslateToEnglish";
+export { CodeSwitching } from "./CodeSwitching";
 export { CustomWords } from "./CustomWords";
+export { CasedTerms } from "./CasedTerms";
 export { PostProcessingToggle } from "./PostProcessingToggle";
 export { PostProcessingSettingsApi } from "./PostProcessingSettingsApi";
 export { PostProcessingSettingsPrompts } from "./PostProcessingSettingsPrompts";
@@ -1,6 +1,7 @@
 import { useState, useEffect, useCallback } from "react";
 import { commands, ChatMessage, SavedChat, ChatSummary } from "@/bindings";
 import { listen } from "@tauri-apps/api/event";
+import { formatError } from "@/lib/utils/errors";
 
 export function useChatPersistence() {
   const [savedChats, setSavedChats] = useState<ChatSummary[]>([]);
@@ -33,24 +34,36 @@ export function useChatPersistence() {
     };
   }, [fetchChats]);
 
-  const saveChat = async (messages: ChatMessage[], title?: string) => {
-    const result = await commands.saveChat(title || null, messages);
+  const saveChat = async (
+    messages: ChatMessage[],
+    title?: string,
+    modelId?: string | null,
+  ) => {
+    const result = await commands.saveChat(
+      title || null,
+      messages,
+      modelId ?? null,
+    );
     if (result.status === "ok") {
       fetchChats();
       return result.data;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
-  const updateChat = async (id: number, messages: ChatMessage[]) => {
-    const result = await commands.updateChat(id, messages);
+  const updateChat = async (
+    id: number,
+    messages: ChatMessage[],
+    modelId?: string | null,
+  ) => {
+    const result = await commands.updateChat(id, messages, modelId ?? null);
     if (result.status === "ok") {
       // We don't necessarily need to fetch all chats again if only messages changed
       // but updated_at changes, so it's safer to fetch
       fetchChats();
       return;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   const updateChatTitle = async (id: number, title: string) => {
@@ -59,7 +72,7 @@ export function useChatPersistence() {
       fetchChats();
       return;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   const getChat = async (id: number): Promise<SavedChat | null> => {
@@ -67,7 +80,7 @@ export function useChatPersistence() {
     if (result.status === "ok") {
       return result.data;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   const deleteChat = async (id: number) => {
@@ -76,7 +89,7 @@ export function useChatPersistence() {
       fetchChats();
       return;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   const generateTitle = async (userMsg: string, assistantMsg: string) => {
@@ -84,7 +97,7 @@ export function useChatPersistence() {
     if (result.status === "ok") {
       return result.data;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   const openSavedChat = async (id: number) => {
@@ -92,7 +105,7 @@ export function useChatPersistence() {
     if (result.status === "ok") {
       return result.data;
     }
-    throw new Error(result.error);
+    throw new Error(formatError(result.error));
   };
 
   return {
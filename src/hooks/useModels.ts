@@ -1,6 +1,7 @@
 import { useEffect, useState } from "react";
 import { listen } from "@tauri-apps/api/event";
 import { commands, type ModelInfo } from "@/bindings";
+import { formatError } from "@/lib/utils/errors";
 
 interface DownloadProgress {
   model_id: string;
@@ -33,7 +34,7 @@ export const useModels = () => {
         setModels(result.data);
         setError(null);
       } else {
-        setError(`Failed to load models: ${result.error}`);
+        setError(`Failed to load models: ${formatError(result.error)}`);
       }
     } catch (err) {
       setError(`Failed to load models: ${err}`);
@@ -79,7 +80,7 @@ export const useModels = () => {
         setHasAnyModels(true);
         return true;
       } else {
-        setError(`Failed to switch to model: ${result.error}`);
+        setError(`Failed to switch to model: ${formatError(result.error)}`);
         return false;
       }
     } catch (err) {
@@ -96,7 +97,7 @@ export const useModels = () => {
       if (result.status === "ok") {
         return true;
       } else {
-        setError(`Failed to download model: ${result.error}`);
+        setError(`Failed to download model: ${formatError(result.error)}`);
         setDownloadingModels((prev) => {
           const next = new Set(prev);
           next.delete(modelId);
@@ -123,7 +124,7 @@ export const useModels = () => {
         await loadModels(); // Refresh the list
         return true;
       } else {
-        setError(`Failed to delete model: ${result.error}`);
+        setError(`Failed to delete model: ${formatError(result.error)}`);
         return false;
       }
     } catch (err) {
@@ -19,20 +19,42 @@ interface AuthResult {
   error: string | null;
 }
 
+/** Gemini CLI / Codex CLI sign-in found on disk */
+export interface CliCredentials {
+  provider: "google" | "openai";
+  source_path: string;
+  email: string | null;
+  expires_at: number;
+}
+
 interface UseOAuthReturn {
   // State
   status: OAuthStatus | null;
   isLoading: boolean;
   error: string | null;
   isAuthenticating: boolean;
+  /** Existing CLI sign-in that can be reused for this provider */
+  cliCredentials: CliCredentials | null;
 
   // Actions
   startAuth: () => Promise<void>;
+  importCliCredentials: () => Promise<void>;
   logout: () => Promise<void>;
   refreshToken: () => Promise<boolean>;
   refreshStatus: () => Promise<void>;
 }
 
+const oauthProviderKey = (providerId: string): string | null => {
+  const id = providerId.toLowerCase();
+  if (id === "google" || id === "gemini" || id === "gemini_oauth") {
+    return "google";
+  }
+  if (id === "openai" || id === "chatgpt" || id === "openai_oauth") {
+    return "openai";
+  }
+  return null;
+};
+
 /**
  * Hook for managing OAuth authentication for a specific provider
  *
@@ -44,6 +66,9 @@ export const useOAuth = (providerId: string): UseOAuthReturn => {
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
   const [isAuthenticating, setIsAuthenticating] = useState(false);
+  const [cliCredentials, setCliCredentials] = useState<CliCredentials | null>(
+    null,
+  );
 
   // Fetch initial status
   const refreshStatus = useCallback(async () => {
@@ -73,6 +98,42 @@ export const useOAuth = (providerId: string): UseOAuthReturn => {
     refreshStatus();
   }, [refreshStatus]);
 
+  // Look for a CLI sign-in that can be reused instead of the browser flow
+  useEffect(() => {
+    const key = oauthProviderKey(providerId);
+    if (!key) {
+      setCliCredentials(null);
+      return;
+    }
+    invoke<CliCredentials[]>("oauth_detect_cli_credentials")
+      .then((found) =>
+        setCliCredentials(found.find((c) => c.provider === key) ?? null),
+      )
+      .catch(() => setCliCredentials(null));
+  }, [providerId]);
+
+  // Import tokens from the detected CLI sign-in
+  const importCliCredentials = useCallback(async () => {
+    if (!providerId) return;
+
+    try {
+      setIsAuthenticating(true);
+      setError(null);
+      const result = await invoke<AuthResult>("oauth_import_cli_credentials", {
+        provider: providerId,
+      });
+      if (result.success) {
+        await refreshStatus();
+      } else {
+        setError(result.error || "Import failed");
+      }
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setIsAuthenticating(false);
+    }
+  }, [providerId, refreshStatus]);
+
   // Start OAuth flow
   const startAuth = useCallback(async () => {
     if (!providerId) {
@@ -161,7 +222,9 @@ export const useOAuth = (providerId: string): UseOAuthReturn => {
     isLoading,
     error,
     isAuthenticating,
+    cliCredentials,
     startAuth,
+    importCliCredentials,
     logout,
     refreshToken,
     refreshStatus,
@@ -2,7 +2,7 @@ import i18n from "i18next";
 import { initReactI18next } from "react-i18next";
 import { locale } from "@tauri-apps/plugin-os";
 import { LANGUAGE_METADATA } from "./languages";
-import { commands } from "@/bindings";
+import { commands, events } from "@/bindings";
 
 // Auto-discover translation files using Vite's glob import
 const localeModules = import.meta.glob<{ default: Record<string, unknown> }>(
@@ -95,4 +95,12 @@ export const syncLanguageFromSettings = async () => {
 // Run language sync on init
 syncLanguageFromSettings();
 
+// Follow language changes made in another window, e.g. settings for the overlay
+events.appLanguageChanged.listen(({ payload }) => {
+  const supported = getSupportedLanguage(payload);
+  if (supported && supported !== i18n.language) {
+    i18n.changeLanguage(supported);
+  }
+});
+
 export default i18n;
@@ -0,0 +1,12 @@
+import type { AppError } from "@/bindings";
+
+/** A failed command's message, followed by its hint when it has one */
+export const formatError = (error: AppError): string => {
+  if (!error.hint) {
+    return error.message;
+  }
+  const message = /[.!?]$/.test(error.message)
+    ? error.message
+    : `${error.message}.`;
+  return `${message} ${error.hint}`;
+};
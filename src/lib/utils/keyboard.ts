@@ -102,11 +102,18 @@ export const getKeyName = (
       ScrollLock: "scroll lock",
       Pause: "pause",
       ContextMenu: "menu",
-      NumpadMultiply: "numpad *",
-      NumpadAdd: "numpad +",
-      NumpadSubtract: "numpad -",
-      NumpadDecimal: "numpad .",
-      NumpadDivide: "numpad /",
+      // Numpad operators are spelled out since "+" separates keys in a shortcut
+      NumpadMultiply: "numpad multiply",
+      NumpadAdd: "numpad add",
+      NumpadSubtract: "numpad subtract",
+      NumpadDecimal: "numpad decimal",
+      NumpadDivide: "numpad divide",
+      NumpadEnter: "numpad enter",
+      NumpadEqual: "numpad equal",
+      MediaPlayPause: "media play pause",
+      MediaStop: "media stop",
+      MediaTrackNext: "media track next",
+      MediaTrackPrevious: "media track previous",
       NumLock: "num lock",
     };
 
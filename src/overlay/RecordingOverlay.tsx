@@ -1,4 +1,4 @@
-import { listen } from "@tauri-apps/api/event";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import React, { useEffect, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
 import {
@@ -10,13 +10,15 @@ import {
 } from "../components/icons";
 import { AlertCircle, X, Camera } from "lucide-react";
 import "./RecordingOverlay.css";
-import { commands } from "@/bindings";
+import { commands, events, type PromptMode } from "@/bindings";
 import { syncLanguageFromSettings } from "@/i18n";
 
 type OverlayState =
   | "recording"
   | "ramble_recording"
+  | "focus_recording"
   | "voice_command_recording"
+  | "voice_command_follow_up"
   | "context_chat_recording"
   | "transcribing"
   | "voice_command_transcribing"
@@ -26,11 +28,9 @@ type OverlayState =
   | "paused"
   | "ramble_paused"
   | "speaking"
+  | "no_speech"
   | "error";
 
-// Prompt mode type matches Rust PromptMode enum
-type PromptMode = "dynamic" | "low" | "medium" | "high";
-
 interface ErrorPayload {
   state: string;
   message: string;
@@ -68,10 +68,13 @@ const RecordingOverlay: React.FC = () => {
   const [promptMode, setPromptMode] = useState<PromptMode>("dynamic");
   // Detected category in Dynamic mode (from backend when refinement starts)
   const [detectedCategory, setDetectedCategory] = useState<string | null>(null);
+  // Characters of refined text streamed in so far
+  const [refinedChars, setRefinedChars] = useState(0);
 
   // Helper to determine if current state is voice command related (purple theme)
   const isVoiceCommandState =
     state === "voice_command_recording" ||
+    state === "voice_command_follow_up" ||
     state === "voice_command_transcribing" ||
     state === "processing_command";
 
@@ -87,12 +90,8 @@ const RecordingOverlay: React.FC = () => {
     const unlisteners: (() => void)[] = [];
 
     const setupEventListeners = async () => {
-      // Helper to safely register listeners
-      const register = async <T,>(
-        event: string,
-        handler: (event: { payload: T }) => void,
-      ) => {
-        const unlisten = await listen<T>(event, handler);
+      // Helper to safely keep listeners, dropping them if already unmounted
+      const track = (unlisten: UnlistenFn) => {
         if (!isMounted) {
           unlisten();
         } else {
@@ -100,10 +99,20 @@ const RecordingOverlay: React.FC = () => {
         }
       };
 
+      // Helper for events not yet defined in the backend's events module
+      const register = async <T,>(
+        event: string,
+        handler: (event: { payload: T }) => void,
+      ) => {
+        track(await listen<T>(event, handler));
+      };
+
       // Listen for vision-captured event to update counter
-      await register<void>("vision-captured", () => {
-        setContextParamsCount((prev) => prev + 1);
-      });
+      track(
+        await events.visionCaptured.listen(() => {
+          setContextParamsCount((prev) => prev + 1);
+        }),
+      );
 
       // Listen for show-overlay event from Rust
       await register<string>("show-overlay", async (event) => {
@@ -112,6 +121,7 @@ const RecordingOverlay: React.FC = () => {
         const overlayState = event.payload;
         console.log("[UI] show-overlay received:", overlayState);
         setState(overlayState as OverlayState);
+        setRefinedChars(0);
         setErrorMessage("");
         setIsVoiceCommandError(false);
         setIsVisible(true);
@@ -142,6 +152,7 @@ const RecordingOverlay: React.FC = () => {
         // Reset mode known state only when a NEW recording session starts
         if (
           overlayState === "recording" ||
+          overlayState === "focus_recording" ||
           overlayState === "voice_command_recording" ||
           overlayState === "context_chat_recording"
         ) {
@@ -161,16 +172,27 @@ const RecordingOverlay: React.FC = () => {
       });
 
       // Listen for prompt mode changes from tray menu
-      await register<PromptMode>("prompt-mode-changed", (event) => {
-        console.log("[UI] prompt-mode-changed received:", event.payload);
-        setPromptMode(event.payload);
-      });
+      track(
+        await events.promptModeChanged.listen((event) => {
+          console.log("[UI] prompt-mode-changed received:", event.payload);
+          setPromptMode(event.payload);
+        }),
+      );
 
       // Listen for detected category in Dynamic mode
-      await register<string>("category-detected", (event) => {
-        console.log("[UI] category-detected received:", event.payload);
-        setDetectedCategory(event.payload);
-      });
+      track(
+        await events.categoryDetected.listen((event) => {
+          console.log("[UI] category-detected received:", event.payload);
+          setDetectedCategory(event.payload);
+        }),
+      );
+
+      // Listen for streamed refinement output
+      track(
+        await events.refinementProgress.listen((event) => {
+          setRefinedChars(event.payload.chars);
+        }),
+      );
 
       // Listen for error overlay event from Rust
       await register<ErrorPayload>("show-overlay-error", async (event) => {
@@ -191,12 +213,14 @@ const RecordingOverlay: React.FC = () => {
       });
 
       // Listen for mode-determined event from Rust
-      await register<string>("mode-determined", (event) => {
-        const mode = event.payload;
-        console.log("[UI] mode-determined received:", mode);
-        setModeKnown(true);
-        setIsQuickPressMode(mode === "refining");
-      });
+      track(
+        await events.modeDetermined.listen((event) => {
+          const mode = event.payload;
+          console.log("[UI] mode-determined received:", mode);
+          setModeKnown(true);
+          setIsQuickPressMode(mode === "refining");
+        }),
+      );
 
       // Listen for mic-level updates
       await register<number[]>("mic-level", (event) => {
@@ -308,7 +332,10 @@ const RecordingOverlay: React.FC = () => {
       return <MicrophoneIcon color="#1e40af" />;
     };
 
-    if (state === "voice_command_recording") {
+    if (
+      state === "voice_command_recording" ||
+      state === "voice_command_follow_up"
+    ) {
       // Voice command recording - always purple microphone, no category icons
       return <MicrophoneIcon color="#a855f7" />;
     } else if (state === "context_chat_recording") {
@@ -342,6 +369,8 @@ const RecordingOverlay: React.FC = () => {
       );
     } else if (state === "error") {
       return <AlertCircle size={16} style={{ color: "#ff6b6b" }} />;
+    } else if (state === "no_speech") {
+      return <MicrophoneIcon color="#9ca3af" />;
     } else if (state === "paused" || state === "ramble_paused") {
       // In Refined paused mode, show category icon
       if (isQuickPressMode) {
@@ -356,6 +385,18 @@ const RecordingOverlay: React.FC = () => {
     }
   };
 
+  // Focus mode only shows a small dot while recording
+  if (state === "focus_recording") {
+    return (
+      <div
+        className={`focus-indicator ${isVisible ? "fade-in" : ""}`}
+        title={t("overlay.focusRecording", "Recording (focus mode)")}
+      >
+        <div className="focus-dot" />
+      </div>
+    );
+  }
+
   return (
     <>
       <div
@@ -385,6 +426,27 @@ const RecordingOverlay: React.FC = () => {
               </div>
             </div>
           )}
+          {state === "voice_command_follow_up" && (
+            <div className="stacked-content">
+              <div className="mode-label">
+                {t("overlay.followUp", "Listening")}
+              </div>
+              <div className="bars-container">
+                {levels.map((v, i) => (
+                  <div
+                    key={i}
+                    className="bar"
+                    style={{
+                      height: `${Math.min(14, 3 + Math.pow(v, 0.7) * 11)}px`,
+                      transition:
+                        "height 60ms ease-out, opacity 120ms ease-out",
+                      opacity: Math.max(0.3, v * 1.5),
+                    }}
+                  />
+                ))}
+              </div>
+            </div>
+          )}
           {isPaused && (
             <div className="stacked-content">
               <div className="mode-label paused-label">
@@ -404,7 +466,9 @@ const RecordingOverlay: React.FC = () => {
           {state === "making_coherent" && (
             <div className="stacked-content">
               <div className="mode-label refining-label">
-                {t("overlay.refining", "Refining")}
+                {refinedChars > 0
+                  ? t("overlay.refiningProgress", { chars: refinedChars })
+                  : t("overlay.refining", "Refining")}
               </div>
               <div className="refining-indicator">
                 <div className="refining-dot"></div>
@@ -438,6 +502,11 @@ const RecordingOverlay: React.FC = () => {
               </div>
             </div>
           )}
+          {state === "no_speech" && (
+            <div className="transcribing-text">
+              {t("overlay.noSpeech", "No speech detected")}
+            </div>
+          )}
 
           {state === "error" && (
             <div
@@ -1,7 +1,14 @@
 import { create } from "zustand";
 import { subscribeWithSelector } from "zustand/middleware";
-import type { AppSettings as Settings, AudioDevice } from "@/bindings";
-import { commands } from "@/bindings";
+import type {
+  AppSettings as Settings,
+  AudioDevice,
+  TerminalPasteMode,
+  TtsBackend,
+} from "@/bindings";
+import { commands, events } from "@/bindings";
+
+let listeningForChanges = false;
 
 interface SettingsStore {
   settings: Settings | null;
@@ -102,17 +109,33 @@ const settingUpdaters: {
     commands.updateRecordingRetentionPeriod(value as string),
   translate_to_english: (value) =>
     commands.changeTranslateToEnglishSetting(value as boolean),
+  code_switching: (value) =>
+    commands.changeCodeSwitchingSetting(value as boolean),
+  show_edits: (value) => commands.changeShowEditsSetting(value as boolean),
+  verify_refinement: (value) =>
+    commands.changeVerifyRefinementSetting(value as boolean),
   selected_language: (value) =>
     commands.changeSelectedLanguageSetting(value as string),
   overlay_position: (value) =>
     commands.changeOverlayPositionSetting(value as string),
   debug_mode: (value) => commands.changeDebugModeSetting(value as boolean),
   custom_words: (value) => commands.updateCustomWords(value as string[]),
+  cased_terms: (value) => commands.updateCasedTerms(value as string[]),
   word_correction_threshold: (value) =>
     commands.changeWordCorrectionThresholdSetting(value as number),
   paste_method: (value) => commands.changePasteMethodSetting(value as string),
   clipboard_handling: (value) =>
     commands.changeClipboardHandlingSetting(value as string),
+  max_paste_chars: (value) =>
+    commands.changeMaxPasteCharsSetting(value as number),
+  paste_blocked_apps: (value) =>
+    commands.changePasteBlockedAppsSetting(value as string[]),
+  terminal_paste_mode: (value) =>
+    commands.changeTerminalPasteModeSetting(value as TerminalPasteMode),
+  tts_backend: (value) =>
+    commands.changeTtsBackendSetting(value as TtsBackend),
+  tts_system_voice: (value) =>
+    commands.changeTtsSystemVoiceSetting(value as string | null),
   history_limit: (value) => commands.updateHistoryLimit(value as number),
   coherent_enabled: (value) =>
     commands.changePostProcessEnabledSetting(value as boolean),
@@ -126,6 +149,46 @@ const settingUpdaters: {
   app_language: (value) => commands.changeAppLanguageSetting(value as string),
   clipboard_content_cutoff: (value) =>
     commands.changeClipboardContentCutoffSetting(value as number),
+  no_speech_timeout_seconds: (value) =>
+    commands.changeNoSpeechTimeoutSetting(value as number),
+  voice_command_follow_up_seconds: (value) =>
+    commands.changeVoiceCommandFollowUpSetting(value as number),
+  daily_spend_limit_usd: (value) =>
+    commands.changeDailySpendLimitSetting(value as number | null),
+  per_request_spend_limit_usd: (value) =>
+    commands.changePerRequestSpendLimitSetting(value as number | null),
+  noise_suppression: (value) =>
+    commands.changeNoiseSuppressionSetting(value as boolean),
+  live_transcription: (value) =>
+    commands.changeLiveTranscriptionSetting(value as boolean),
+  accessibility_announcements: (value) =>
+    commands.changeAccessibilityAnnouncementsSetting(value as boolean),
+  battery_saver_enabled: (value) =>
+    commands.changeBatterySaverEnabledSetting(value as boolean),
+  battery_saver_threshold: (value) =>
+    commands.changeBatterySaverThresholdSetting(value as number),
+  media_key_trigger_enabled: (value) =>
+    commands.changeMediaKeyTriggerSetting(value as boolean),
+  media_key_binding: (value) =>
+    commands.changeMediaKeyBindingSetting(value as string),
+  remote_trigger_enabled: (value) =>
+    commands.changeRemoteTriggerEnabledSetting(value as boolean),
+  remote_trigger_port: (value) =>
+    commands.changeRemoteTriggerPortSetting(value as number),
+  remote_trigger_lan_access: (value) =>
+    commands.changeRemoteTriggerLanAccessSetting(value as boolean),
+  wake_word_enabled: (value) =>
+    commands.changeWakeWordEnabledSetting(value as boolean),
+  wake_word_binding: (value) =>
+    commands.changeWakeWordBindingSetting(value as string),
+  wake_word_threshold: (value) =>
+    commands.changeWakeWordThresholdSetting(value as number),
+  wake_word_model_path: (value) =>
+    commands.changeWakeWordModelPathSetting(value as string | null),
+  local_llm_enabled: (value) =>
+    commands.changeLocalLlmEnabledSetting(value as boolean),
+  local_llm_model_path: (value) =>
+    commands.changeLocalLlmModelPathSetting(value as string | null),
 };
 
 export const useSettingsStore = create<SettingsStore>()(
@@ -480,6 +543,20 @@ export const useSettingsStore = create<SettingsStore>()(
         refreshOutputDevices(),
         checkCustomSounds(),
       ]);
+
+      // Settings the backend changes itself are reloaded from disk. Several
+      // components can start initializing at once, so listen only once
+      if (!listeningForChanges) {
+        listeningForChanges = true;
+        events.settingsChanged.listen(() => {
+          refreshSettings();
+        });
+        events.settingsSynced.listen((event) => {
+          if (event.payload.pulled.length > 0) {
+            refreshSettings();
+          }
+        });
+      }
     },
   })),
 );